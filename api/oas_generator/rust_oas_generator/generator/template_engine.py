@@ -8,6 +8,7 @@ from parsed OpenAPI specifications.
 from __future__ import annotations
 
 from collections.abc import Callable
+import re
 from functools import partial
 from pathlib import Path
 from typing import Any
@@ -166,6 +167,10 @@ class OperationAnalyzer:
         first_content_type = next(iter(content.keys()))
         schema = content[first_content_type].get("schema", {})
 
+        # Binary bodies are raw bytes rather than base64 strings, regardless of their format
+        if first_content_type == "application/x-binary":
+            return "Vec<u8>"
+
         if "$ref" in schema:
             ref_name = schema["$ref"].split("/")[-1]
             type_name = rust_pascal_case(ref_name)
@@ -287,6 +292,102 @@ class TypeAnalyzer:
         return sorted(used_types)
 
 
+class FeatureAnalyzer:
+    """Analyzes which optional cargo features the operations and models are gated by."""
+
+    _TYPE_NAME_PATTERN = re.compile(r"\b[A-Z][A-Za-z0-9]*\b")
+
+    @staticmethod
+    def feature_cfg(features: frozenset[str] | set[str] | None) -> str:
+        """Get the cfg attribute enabling an item when any of the features is enabled."""
+        if not features:
+            return ""
+        predicates = [f'feature = "{feature}"' for feature in sorted(features)]
+        if len(predicates) == 1:
+            return f"#[cfg({predicates[0]})]"
+        return f"#[cfg(any({', '.join(predicates)}))]"
+
+    @classmethod
+    def operation_cfg(cls, operation: Operation) -> str:
+        """Get the cfg attribute gating an operation, empty if it is always available."""
+        return cls.feature_cfg({operation.feature} if operation.feature else None)
+
+    @staticmethod
+    def get_features(operations: list[Operation]) -> list[str]:
+        """Get the optional features gating any of the operations."""
+        return sorted({operation.feature for operation in operations if operation.feature})
+
+    @classmethod
+    def _type_names(cls, type_str: str | None) -> set[str]:
+        return set(cls._TYPE_NAME_PATTERN.findall(type_str)) if type_str else set()
+
+    @classmethod
+    def _operation_type_names(cls, operation: Operation) -> set[str]:
+        type_names = set(TypeAnalyzer.get_operation_used_types(operation))
+        type_names.update(cls._type_names(OperationAnalyzer.get_request_body_type(operation)))
+        return type_names
+
+    @classmethod
+    def _gate_types(cls, operations: list[Operation], dependencies: dict[str, set[str]]) -> dict[str, frozenset[str]]:
+        """Get the features of the types only reachable from feature gated operations.
+
+        Types reachable from any ungated operation, or from no operation at all, are left out.
+        """
+        ungated: set[str] = set()
+        gated: dict[str, set[str]] = {}
+        for operation in operations:
+            queue = list(cls._operation_type_names(operation))
+            visited: set[str] = set()
+            while queue:
+                type_name = queue.pop()
+                if type_name in visited:
+                    continue
+                visited.add(type_name)
+                if operation.feature:
+                    gated.setdefault(type_name, set()).add(operation.feature)
+                else:
+                    ungated.add(type_name)
+                queue.extend(dependencies.get(type_name, set()))
+        return {type_name: frozenset(features) for type_name, features in gated.items() if type_name not in ungated}
+
+    @classmethod
+    def get_schema_cfgs(cls, operations: list[Operation], schemas: dict[str, Schema]) -> dict[str, str]:
+        """Get the cfg attributes gating the models only used by feature gated operations."""
+        struct_names = {schema.rust_struct_name for schema in schemas.values()}
+        dependencies = {
+            schema.rust_struct_name: {
+                type_name
+                for prop in schema.properties
+                for type_name in cls._type_names(prop.rust_type)
+                if type_name in struct_names
+            }
+            | (cls._type_names(schema.underlying_rust_type) & struct_names)
+            for schema in schemas.values()
+        }
+        gated_types = cls._gate_types(operations, dependencies)
+        return {
+            schema_name: cls.feature_cfg(gated_types[schema.rust_struct_name])
+            for schema_name, schema in schemas.items()
+            if schema.rust_struct_name in gated_types
+        }
+
+    @classmethod
+    def get_client_imports(cls, operations: list[Operation]) -> list[tuple[str, list[str]]]:
+        """Group the model types imported by the client by the cfg attribute gating them."""
+        gated_types = cls._gate_types(operations, {})
+        used_types: set[str] = set()
+        for operation in operations:
+            used_types.update(TypeAnalyzer.get_operation_used_types(operation))
+            request_body_type = OperationAnalyzer.get_request_body_type(operation)
+            if request_body_type and TypeAnalyzer.should_import_request_body_type(request_body_type):
+                used_types.add(request_body_type)
+
+        groups: dict[str, list[str]] = {}
+        for type_name in sorted(used_types):
+            groups.setdefault(cls.feature_cfg(gated_types.get(type_name)), []).append(type_name)
+        return sorted(groups.items())
+
+
 class RustTemplateEngine:
     """Template engine for generating Rust code."""
 
@@ -332,6 +433,7 @@ class RustTemplateEngine:
         op_analyzer = OperationAnalyzer()
         resp_analyzer = ResponseAnalyzer()
         type_analyzer = TypeAnalyzer()
+        feature_analyzer = FeatureAnalyzer()
 
         globals_map: dict[str, Any] = {
             # Parameter enum analysis
@@ -362,6 +464,11 @@ class RustTemplateEngine:
             "is_request_body_required": lambda op: bool(op.request_body and op.request_body.get("required", False)),
             "should_import_request_body_type": type_analyzer.should_import_request_body_type,
             "is_empty_request_body": lambda op: self._is_empty_request_body(op),
+            # Feature gating
+            "operation_cfg": feature_analyzer.operation_cfg,
+            "get_features": feature_analyzer.get_features,
+            "feature_cfg": feature_analyzer.feature_cfg,
+            "get_client_imports": feature_analyzer.get_client_imports,
             # Client type detection
             "get_client_type": lambda spec: self._detect_client_type_from_spec(spec),
         }
@@ -463,6 +570,7 @@ class RustCodeGenerator:
             "schemas": spec.schemas,
             "content_types": spec.content_types,
             "custom_description": custom_description,
+            "schema_cfgs": FeatureAnalyzer.get_schema_cfgs(spec.operations, spec.schemas),
         }
 
         files = {}
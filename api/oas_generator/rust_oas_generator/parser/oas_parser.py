@@ -61,6 +61,11 @@ _HTTP_METHODS: Final = frozenset({"get", "post", "put", "delete", "patch", "head
 # Content types that indicate msgpack support
 _MSGPACK_CONTENT_TYPES: Final = frozenset({"application/msgpack", "application/x-binary"})
 
+# Optional cargo features gating endpoints that most consumers don't need
+PARTICIPATION_FEATURE: Final = "participation"
+ADMIN_FEATURE: Final = "admin"
+EXPERIMENTAL_FEATURE: Final = "experimental"
+
 
 def _select_integer_rust_type(schema: dict[str, Any]) -> str:
     """Select appropriate Rust integer type based on schema constraints.
@@ -97,6 +102,19 @@ def _select_integer_rust_type(schema: dict[str, Any]) -> str:
     return "u64"
 
 
+def feature_for_tags(tags: list[str]) -> str | None:
+    """Get the optional cargo feature gating an operation with the given tags, if any.
+
+    Experimental endpoints are gated by `experimental`, private participation key endpoints by
+    `participation` and any other private (node administration) endpoints by `admin`.
+    """
+    if "experimental" in tags:
+        return EXPERIMENTAL_FEATURE
+    if "private" in tags:
+        return PARTICIPATION_FEATURE if "participating" in tags else ADMIN_FEATURE
+    return None
+
+
 def _extract_ref_name(ref_string: str) -> str:
     """Extract the reference name from an OpenAPI $ref string.
 
@@ -219,12 +237,10 @@ def detect_msgpack_support_for_operation(operation_data: dict[str, Any]) -> bool
     if any(ct in content for ct in _MSGPACK_CONTENT_TYPES):
         return True
 
-    # Check for binary format in request body
+    # Binary request bodies are sent as raw bytes, whether the spec declares them as
+    # `format: binary` or `format: byte`
     if "application/x-binary" in content:
-        binary_content = content["application/x-binary"]
-        schema = binary_content.get("schema", {})
-        if schema.get("format") == "binary":
-            return True
+        return True
 
     # Check response content types
     responses = operation_data.get("responses", {})
@@ -355,6 +371,7 @@ class Operation:
     tags: list[str]
     rust_function_name: str = field(init=False)
     rust_error_enum: str = field(init=False)
+    feature: str | None = field(init=False)
     supports_msgpack: bool = False
     request_body_supports_msgpack: bool = False
     request_body_supports_text_plain: bool = False
@@ -370,6 +387,7 @@ class Operation:
                 break
         self.rust_function_name = rust_snake_case(self.operation_id)
         self.rust_error_enum = f"{rust_pascal_case(self.operation_id)}Error"
+        self.feature = feature_for_tags(self.tags)
 
 
 @dataclass
@@ -389,6 +407,7 @@ class Property:
     rust_type_with_msgpack: str = field(init=False)
     is_msgpack_field: bool = field(init=False)
     is_signed_transaction: bool = field(init=False)
+    is_block: bool = field(init=False)
 
     def __post_init__(self) -> None:
         # Check for field name override from vendor extension
@@ -402,7 +421,14 @@ class Property:
         if self._has_bytes_base64_extension():
             self.is_base64_encoded = True
 
-        if self.is_base64_encoded:
+        self.is_block = any(
+            "x-algokit-block" in ext_name and ext_value for ext_name, ext_value in self.vendor_extensions
+        )
+
+        if self.is_block:
+            # Decoded into the typed block of algokit_transact rather than a loose JSON value
+            self.rust_type_with_msgpack = "AlgokitBlock"
+        elif self.is_base64_encoded:
             self.rust_type_with_msgpack = "Vec<u8>"
         elif self.items and self.items.is_base64_encoded and self.rust_type.startswith("Vec<"):
             self.rust_type_with_msgpack = "Vec<Vec<u8>>"
@@ -450,6 +476,7 @@ class Schema:
     has_required_fields: bool = field(init=False)
     implements_algokit_msgpack: bool = field(init=False)
     has_signed_transaction_fields: bool = field(init=False)
+    has_block_fields: bool = field(init=False)
     # For non-object schemas (e.g. top-level arrays) we capture the underlying rust type
     underlying_rust_type: str | None = None
     # For string enum schemas
@@ -466,6 +493,7 @@ class Schema:
         )
         self.has_required_fields = len(self.required_fields) > 0
         self.has_signed_transaction_fields = any(prop.is_signed_transaction for prop in self.properties)
+        self.has_block_fields = any(prop.is_block for prop in self.properties)
         self.is_string_enum = self.schema_type == "string" and len(self.enum_values) > 0
 
 
@@ -760,13 +788,7 @@ class OASParser:
         if "application/msgpack" in content:
             return True
 
-        if "application/x-binary" in content:
-            binary_content = content["application/x-binary"]
-            schema = binary_content.get("schema", {})
-            format_value: str | None = schema.get("format")
-            return format_value == "binary"
-
-        return False
+        return "application/x-binary" in content
 
     def _check_request_body_text_plain_support(
         self,
@@ -0,0 +1,54 @@
+"""
+Test feature gating of operations and models in the generated clients.
+
+This test validates that endpoints most consumers don't need are gated behind optional cargo
+features, while everything needed to submit transactions stays available by default.
+"""
+
+from pathlib import Path
+
+import pytest
+
+from rust_oas_generator.generator.template_engine import FeatureAnalyzer
+from rust_oas_generator.parser.oas_parser import OASParser, ParsedSpec, feature_for_tags
+
+
+class TestFeatureGating:
+    """Test class for feature gating validation."""
+
+    @pytest.fixture
+    def parsed_spec(self) -> ParsedSpec:
+        """Parse the algod OAS spec."""
+        spec_path = Path(__file__).parent.parent.parent / "specs" / "algod.oas3.json"
+        if not spec_path.exists():
+            pytest.skip("algod.oas3.json not found")
+
+        return OASParser().parse_file(spec_path)
+
+    def test_feature_for_tags(self) -> None:
+        """Test that operation tags map to the expected features."""
+        assert feature_for_tags(["public", "nonparticipating"]) is None
+        assert feature_for_tags(["public", "participating"]) is None
+        assert feature_for_tags(["public", "experimental"]) == "experimental"
+        assert feature_for_tags(["private", "participating"]) == "participation"
+        assert feature_for_tags(["private", "nonparticipating"]) == "admin"
+        assert feature_for_tags([]) is None
+
+    def test_transaction_submission_is_not_gated(self, parsed_spec: ParsedSpec) -> None:
+        """Test that the operations needed to submit transactions are always available."""
+        operations = {op.rust_function_name: op for op in parsed_spec.operations}
+
+        for name in ["transaction_params", "raw_transaction", "pending_transaction_information", "simulate_transaction"]:
+            assert operations[name].feature is None, f"{name} should not be feature gated"
+
+        assert operations["get_participation_keys"].feature == "participation"
+        assert operations["shutdown_node"].feature == "admin"
+        assert operations["raw_transaction_async"].feature == "experimental"
+
+    def test_models_gated_only_when_unused_by_ungated_operations(self, parsed_spec: ParsedSpec) -> None:
+        """Test that models are only gated when every operation using them is gated."""
+        schema_cfgs = FeatureAnalyzer.get_schema_cfgs(parsed_spec.operations, parsed_spec.schemas)
+
+        assert schema_cfgs["ParticipationKey"] == '#[cfg(feature = "participation")]'
+        assert "PendingTransactionResponse" not in schema_cfgs
+        assert "Account" not in schema_cfgs
@@ -9,6 +9,7 @@ from pathlib import Path
 
 import pytest
 
+from rust_oas_generator.generator.template_engine import OperationAnalyzer
 from rust_oas_generator.parser.oas_parser import OASParser, ParsedSpec
 
 # Constants for test thresholds
@@ -57,6 +58,19 @@ class TestMsgpackImplementation:
 
         assert len(msgpack_request_ops) > 0, "Should find operations with msgpack request bodies"
 
+    def test_binary_request_bodies_are_raw_bytes(self, parsed_spec: tuple[ParsedSpec, OASParser]) -> None:
+        """Test that application/x-binary request bodies are sent as raw bytes, whatever their format."""
+        spec, parser = parsed_spec
+
+        binary_ops = {
+            op.operation_id: op for op in spec.operations if op.operation_id in ("RawTransaction", "TealDisassemble")
+        }
+
+        assert set(binary_ops) == {"RawTransaction", "TealDisassemble"}
+        for operation in binary_ops.values():
+            assert operation.request_body_supports_msgpack
+            assert OperationAnalyzer.get_request_body_type(operation) == "Vec<u8>"
+
     def test_root_msgpack_schemas_identified(self, parsed_spec: tuple[ParsedSpec, OASParser]) -> None:
         """Test that root msgpack schemas are correctly identified."""
         spec, parser = parsed_spec
@@ -138,6 +152,17 @@ class TestMsgpackImplementation:
                             "should implement msgpack"
                         )
 
+    def test_block_is_typed(self, parsed_spec: tuple[ParsedSpec, OASParser]) -> None:
+        """Test that the block of a block response is decoded into the typed algokit_transact block."""
+        spec, parser = parsed_spec
+
+        get_block = spec.schemas["GetBlock"]
+        block = next(prop for prop in get_block.properties if prop.name == "block")
+
+        assert get_block.has_block_fields
+        assert block.is_block
+        assert block.rust_type_with_msgpack == "AlgokitBlock"
+
     def test_no_false_positives(self, parsed_spec: tuple[ParsedSpec, OASParser]) -> None:
         """Test that schemas not related to msgpack don't unnecessarily implement it."""
         spec, parser = parsed_spec
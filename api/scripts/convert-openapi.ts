@@ -981,6 +981,13 @@ async function processAlgodSpec() {
         targetValue: true,
         removeSource: true,
       },
+      {
+        sourceProperty: "x-algorand-format",
+        sourceValue: "BlockHeader",
+        targetProperty: "x-algokit-block",
+        targetValue: true,
+        removeSource: true,
+      },
     ],
     msgpackOnlyEndpoints: [
       // Align with Go and JS SDKs that hardcode these to msgpack
@@ -8,7 +8,7 @@
  * Generated by: Rust OpenAPI Generator
  */
 
-use algokit_http_client::{HttpClient, HttpMethod};
+use algokit_http_client::{HttpClient, HttpMethod, HttpResponse};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -68,8 +68,11 @@ pub async fn account_assets_information(
             Some(query_params),
             body,
             Some(headers),
+            None,
         )
         .await
+        // Other clients than the default one may return non-2xx responses
+        .and_then(HttpResponse::error_for_status)
         .map_err(|e| Error::Http { source: e })?;
 
     let content_type = response
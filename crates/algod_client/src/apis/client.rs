@@ -12,18 +12,28 @@
 
 use super::Error;
 use super::parameter_enums::*;
+#[cfg(feature = "experimental")]
+use crate::models::AccountAssetsInformation;
+#[cfg(feature = "admin")]
+use crate::models::{AbortCatchup, DebugSettingsProf, StartCatchup, UnknownJsonValue};
 use crate::models::{
-    AbortCatchup, Account, AccountApplicationInformation, AccountAssetInformation,
-    AccountAssetsInformation, AddParticipationKey, Application, Asset, Box, DebugSettingsProf,
+    Account, AccountApplicationInformation, AccountAssetInformation, Application, Asset, Box,
     DryrunRequest, ErrorResponse, Genesis, GetApplicationBoxes, GetBlock, GetBlockHash,
     GetBlockLogs, GetBlockTimeStampOffset, GetBlockTxids, GetPendingTransactions,
     GetPendingTransactionsByAddress, GetStatus, GetSupply, GetSyncRound,
     GetTransactionGroupLedgerStateDeltasForRound, LedgerStateDelta, LightBlockHeaderProof,
-    ParticipationKey, PendingTransactionResponse, RawTransaction, SimulateRequest,
-    SimulateTransaction, StartCatchup, StateProof, TealCompile, TealDisassemble, TealDryrun,
-    TransactionParams, TransactionProof, UnknownJsonValue, Version, WaitForBlock,
+    PendingTransactionResponse, RawTransaction, SimulateRequest, SimulateTransaction, StateProof,
+    TealCompile, TealDisassemble, TealDryrun, TransactionParams, TransactionProof, Version,
+    WaitForBlock,
 };
-use algokit_http_client::{DefaultHttpClient, HttpClient};
+#[cfg(feature = "participation")]
+use crate::models::{AddParticipationKey, ParticipationKey};
+#[cfg(any(
+    feature = "default_client",
+    all(feature = "wasm_client", target_arch = "wasm32")
+))]
+use algokit_http_client::DefaultHttpClient;
+use algokit_http_client::HttpClient;
 use std::sync::Arc;
 
 /// The main Algod API client.
@@ -31,6 +41,8 @@ use std::sync::Arc;
 /// This client provides convenient access to all Algod API endpoints.
 /// It wraps the lower-level endpoint functions with a more ergonomic interface.
 /// All methods return a unified `Error` type that can represent any endpoint error.
+///
+/// A call is cancelled by dropping its future, e.g. on a timeout, which cancels its request.
 #[derive(Clone)]
 #[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Object))]
 pub struct AlgodClient {
@@ -46,7 +58,10 @@ impl AlgodClient {
     }
 
     /// Create a new AlgodClient for Algorand TestNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn testnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new(
@@ -56,7 +71,10 @@ impl AlgodClient {
     }
 
     /// Create a new AlgodClient for Algorand MainNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn mainnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new(
@@ -66,7 +84,10 @@ impl AlgodClient {
     }
 
     /// Create a new AlgodClient for a local localnet environment.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn localnet() -> Self {
         let http_client = Arc::new(
@@ -121,31 +142,6 @@ impl AlgodClient {
         result
     }
 
-    /// Retrieves the current settings for blocking and mutex profiles
-    pub async fn get_debug_settings_prof(&self) -> Result<DebugSettingsProf, Error> {
-        let result =
-            super::get_debug_settings_prof::get_debug_settings_prof(self.http_client.as_ref())
-                .await;
-
-        result
-    }
-
-    /// Enables blocking and mutex profiles, and returns the old settings
-    pub async fn put_debug_settings_prof(&self) -> Result<DebugSettingsProf, Error> {
-        let result =
-            super::put_debug_settings_prof::put_debug_settings_prof(self.http_client.as_ref())
-                .await;
-
-        result
-    }
-
-    /// Gets the merged config file.
-    pub async fn get_config(&self) -> Result<String, Error> {
-        let result = super::get_config::get_config(self.http_client.as_ref()).await;
-
-        result
-    }
-
     /// Get account information.
     pub async fn account_information(
         &self,
@@ -278,106 +274,6 @@ impl AlgodClient {
         result
     }
 
-    /// Return a list of participation keys
-    pub async fn get_participation_keys(&self) -> Result<Vec<ParticipationKey>, Error> {
-        let result =
-            super::get_participation_keys::get_participation_keys(self.http_client.as_ref()).await;
-
-        result
-    }
-
-    /// Add a participation key to the node
-    pub async fn add_participation_key(
-        &self,
-        request: Vec<u8>,
-    ) -> Result<AddParticipationKey, Error> {
-        let result =
-            super::add_participation_key::add_participation_key(self.http_client.as_ref(), request)
-                .await;
-
-        result
-    }
-
-    /// Generate and install participation keys to the node.
-    pub async fn generate_participation_keys(
-        &self,
-        address: &str,
-        dilution: Option<u64>,
-        first: u64,
-        last: u64,
-    ) -> Result<String, Error> {
-        let result = super::generate_participation_keys::generate_participation_keys(
-            self.http_client.as_ref(),
-            address,
-            dilution,
-            first,
-            last,
-        )
-        .await;
-
-        result
-    }
-
-    /// Get participation key info given a participation ID
-    pub async fn get_participation_key_by_id(
-        &self,
-        participation_id: &str,
-    ) -> Result<ParticipationKey, Error> {
-        let result = super::get_participation_key_by_id::get_participation_key_by_id(
-            self.http_client.as_ref(),
-            participation_id,
-        )
-        .await;
-
-        result
-    }
-
-    /// Append state proof keys to a participation key
-    pub async fn append_keys(
-        &self,
-        request: Vec<u8>,
-        participation_id: &str,
-    ) -> Result<ParticipationKey, Error> {
-        let result =
-            super::append_keys::append_keys(self.http_client.as_ref(), request, participation_id)
-                .await;
-
-        result
-    }
-
-    /// Delete a given participation key by ID
-    pub async fn delete_participation_key_by_id(
-        &self,
-        participation_id: &str,
-    ) -> Result<(), Error> {
-        let result = super::delete_participation_key_by_id::delete_participation_key_by_id(
-            self.http_client.as_ref(),
-            participation_id,
-        )
-        .await;
-
-        result
-    }
-
-    /// Special management endpoint to shutdown the node. Optionally provide a timeout parameter to indicate that the node should begin shutting down after a number of seconds.
-    pub async fn shutdown_node(&self, timeout: Option<u64>) -> Result<UnknownJsonValue, Error> {
-        let result = super::shutdown_node::shutdown_node(self.http_client.as_ref(), timeout).await;
-
-        #[cfg(feature = "ffi_uniffi")]
-        {
-            result.map(|v| {
-                serde_json::to_string(&v).map_err(|e| Error::Serde {
-                    message: e.to_string(),
-                })
-            })?
-        }
-
-        #[cfg(not(feature = "ffi_uniffi"))]
-        {
-            result
-        }
-    }
-
     /// Gets the current node status.
     pub async fn get_status(&self) -> Result<GetStatus, Error> {
         let result = super::get_status::get_status(self.http_client.as_ref()).await;
@@ -400,15 +296,6 @@ impl AlgodClient {
         result
     }
 
-    /// Fast track for broadcasting a raw transaction or transaction group to the network through the tx handler without performing most of the checks and reporting detailed errors. Should be only used for development and performance testing.
-    pub async fn raw_transaction_async(&self, request: Vec<u8>) -> Result<(), Error> {
-        let result =
-            super::raw_transaction_async::raw_transaction_async(self.http_client.as_ref(), request)
-                .await;
-
-        result
-    }
-
     /// Simulates a raw transaction or transaction group as it would be evaluated on the network. The simulation will use blockchain state from the latest committed round.
     pub async fn simulate_transaction(
         &self,
@@ -602,13 +489,96 @@ impl AlgodClient {
     }
 
     /// Disassemble program bytes into the TEAL source code.
-    pub async fn teal_disassemble(&self, request: String) -> Result<TealDisassemble, Error> {
+    pub async fn teal_disassemble(&self, request: Vec<u8>) -> Result<TealDisassemble, Error> {
         let result =
             super::teal_disassemble::teal_disassemble(self.http_client.as_ref(), request).await;
 
         result
     }
 
+    /// Provide debugging information for a transaction (or group).
+    pub async fn teal_dryrun(&self, request: Option<DryrunRequest>) -> Result<TealDryrun, Error> {
+        let result = super::teal_dryrun::teal_dryrun(self.http_client.as_ref(), request).await;
+
+        result
+    }
+
+    /// Returns the timestamp offset. Timestamp offsets can only be set in dev mode.
+    pub async fn get_block_time_stamp_offset(&self) -> Result<GetBlockTimeStampOffset, Error> {
+        let result = super::get_block_time_stamp_offset::get_block_time_stamp_offset(
+            self.http_client.as_ref(),
+        )
+        .await;
+
+        result
+    }
+
+    /// Given a timestamp offset in seconds, adds the offset to every subsequent block header's timestamp.
+    pub async fn set_block_time_stamp_offset(&self, offset: u64) -> Result<(), Error> {
+        let result = super::set_block_time_stamp_offset::set_block_time_stamp_offset(
+            self.http_client.as_ref(),
+            offset,
+        )
+        .await;
+
+        result
+    }
+}
+
+#[cfg(not(feature = "ffi_uniffi"))]
+impl AlgodClient {}
+
+#[cfg_attr(feature = "ffi_uniffi", uniffi::export)]
+#[cfg(feature = "ffi_uniffi")]
+impl AlgodClient {}
+
+#[cfg(feature = "admin")]
+#[cfg_attr(feature = "ffi_uniffi", uniffi::export)]
+impl AlgodClient {
+    /// Retrieves the current settings for blocking and mutex profiles
+    pub async fn get_debug_settings_prof(&self) -> Result<DebugSettingsProf, Error> {
+        let result =
+            super::get_debug_settings_prof::get_debug_settings_prof(self.http_client.as_ref())
+                .await;
+
+        result
+    }
+
+    /// Enables blocking and mutex profiles, and returns the old settings
+    pub async fn put_debug_settings_prof(&self) -> Result<DebugSettingsProf, Error> {
+        let result =
+            super::put_debug_settings_prof::put_debug_settings_prof(self.http_client.as_ref())
+                .await;
+
+        result
+    }
+
+    /// Gets the merged config file.
+    pub async fn get_config(&self) -> Result<String, Error> {
+        let result = super::get_config::get_config(self.http_client.as_ref()).await;
+
+        result
+    }
+
+    /// Special management endpoint to shutdown the node. Optionally provide a timeout parameter to indicate that the node should begin shutting down after a number of seconds.
+    pub async fn shutdown_node(&self, timeout: Option<u64>) -> Result<UnknownJsonValue, Error> {
+        let result = super::shutdown_node::shutdown_node(self.http_client.as_ref(), timeout).await;
+
+        #[cfg(feature = "ffi_uniffi")]
+        {
+            result.map(|v| {
+                serde_json::to_string(&v).map_err(|e| Error::Serde {
+                    message: e.to_string(),
+                })
+            })?
+        }
+
+        #[cfg(not(feature = "ffi_uniffi"))]
+        {
+            result
+        }
+    }
+
     /// Starts a catchpoint catchup.
     pub async fn start_catchup(
         &self,
@@ -628,10 +598,16 @@ impl AlgodClient {
 
         result
     }
+}
 
-    /// Provide debugging information for a transaction (or group).
-    pub async fn teal_dryrun(&self, request: Option<DryrunRequest>) -> Result<TealDryrun, Error> {
-        let result = super::teal_dryrun::teal_dryrun(self.http_client.as_ref(), request).await;
+#[cfg(feature = "experimental")]
+#[cfg_attr(feature = "ffi_uniffi", uniffi::export)]
+impl AlgodClient {
+    /// Fast track for broadcasting a raw transaction or transaction group to the network through the tx handler without performing most of the checks and reporting detailed errors. Should be only used for development and performance testing.
+    pub async fn raw_transaction_async(&self, request: Vec<u8>) -> Result<(), Error> {
+        let result =
+            super::raw_transaction_async::raw_transaction_async(self.http_client.as_ref(), request)
+                .await;
 
         result
     }
@@ -642,29 +618,9 @@ impl AlgodClient {
 
         result
     }
-
-    /// Returns the timestamp offset. Timestamp offsets can only be set in dev mode.
-    pub async fn get_block_time_stamp_offset(&self) -> Result<GetBlockTimeStampOffset, Error> {
-        let result = super::get_block_time_stamp_offset::get_block_time_stamp_offset(
-            self.http_client.as_ref(),
-        )
-        .await;
-
-        result
-    }
-
-    /// Given a timestamp offset in seconds, adds the offset to every subsequent block header's timestamp.
-    pub async fn set_block_time_stamp_offset(&self, offset: u64) -> Result<(), Error> {
-        let result = super::set_block_time_stamp_offset::set_block_time_stamp_offset(
-            self.http_client.as_ref(),
-            offset,
-        )
-        .await;
-
-        result
-    }
 }
 
+#[cfg(feature = "experimental")]
 #[cfg(not(feature = "ffi_uniffi"))]
 impl AlgodClient {
     /// Get a list of assets held by an account, inclusive of asset params.
@@ -686,6 +642,7 @@ impl AlgodClient {
     }
 }
 
+#[cfg(feature = "experimental")]
 #[cfg_attr(feature = "ffi_uniffi", uniffi::export)]
 #[cfg(feature = "ffi_uniffi")]
 impl AlgodClient {
@@ -707,3 +664,88 @@ impl AlgodClient {
         result
     }
 }
+
+#[cfg(feature = "participation")]
+#[cfg_attr(feature = "ffi_uniffi", uniffi::export)]
+impl AlgodClient {
+    /// Return a list of participation keys
+    pub async fn get_participation_keys(&self) -> Result<Vec<ParticipationKey>, Error> {
+        let result =
+            super::get_participation_keys::get_participation_keys(self.http_client.as_ref()).await;
+
+        result
+    }
+
+    /// Add a participation key to the node
+    pub async fn add_participation_key(
+        &self,
+        request: Vec<u8>,
+    ) -> Result<AddParticipationKey, Error> {
+        let result =
+            super::add_participation_key::add_participation_key(self.http_client.as_ref(), request)
+                .await;
+
+        result
+    }
+
+    /// Generate and install participation keys to the node.
+    pub async fn generate_participation_keys(
+        &self,
+        address: &str,
+        dilution: Option<u64>,
+        first: u64,
+        last: u64,
+    ) -> Result<String, Error> {
+        let result = super::generate_participation_keys::generate_participation_keys(
+            self.http_client.as_ref(),
+            address,
+            dilution,
+            first,
+            last,
+        )
+        .await;
+
+        result
+    }
+
+    /// Get participation key info given a participation ID
+    pub async fn get_participation_key_by_id(
+        &self,
+        participation_id: &str,
+    ) -> Result<ParticipationKey, Error> {
+        let result = super::get_participation_key_by_id::get_participation_key_by_id(
+            self.http_client.as_ref(),
+            participation_id,
+        )
+        .await;
+
+        result
+    }
+
+    /// Append state proof keys to a participation key
+    pub async fn append_keys(
+        &self,
+        request: Vec<u8>,
+        participation_id: &str,
+    ) -> Result<ParticipationKey, Error> {
+        let result =
+            super::append_keys::append_keys(self.http_client.as_ref(), request, participation_id)
+                .await;
+
+        result
+    }
+
+    /// Delete a given participation key by ID
+    pub async fn delete_participation_key_by_id(
+        &self,
+        participation_id: &str,
+    ) -> Result<(), Error> {
+        let result = super::delete_participation_key_by_id::delete_participation_key_by_id(
+            self.http_client.as_ref(),
+            participation_id,
+        )
+        .await;
+
+        result
+    }
+}
@@ -15,15 +15,22 @@ pub mod client;
 pub mod parameter_enums;
 
 // Individual endpoint modules
+#[cfg(feature = "admin")]
 pub mod abort_catchup;
 pub mod account_application_information;
 pub mod account_asset_information;
+#[cfg(feature = "experimental")]
 pub mod account_assets_information;
 pub mod account_information;
+#[cfg(feature = "participation")]
 pub mod add_participation_key;
+#[cfg(feature = "participation")]
 pub mod append_keys;
+#[cfg(feature = "participation")]
 pub mod delete_participation_key_by_id;
+#[cfg(feature = "experimental")]
 pub mod experimental_check;
+#[cfg(feature = "participation")]
 pub mod generate_participation_keys;
 pub mod get_application_box_by_name;
 pub mod get_application_boxes;
@@ -34,13 +41,17 @@ pub mod get_block_hash;
 pub mod get_block_logs;
 pub mod get_block_time_stamp_offset;
 pub mod get_block_txids;
+#[cfg(feature = "admin")]
 pub mod get_config;
+#[cfg(feature = "admin")]
 pub mod get_debug_settings_prof;
 pub mod get_genesis;
 pub mod get_ledger_state_delta;
 pub mod get_ledger_state_delta_for_transaction_group;
 pub mod get_light_block_header_proof;
+#[cfg(feature = "participation")]
 pub mod get_participation_key_by_id;
+#[cfg(feature = "participation")]
 pub mod get_participation_keys;
 pub mod get_pending_transactions;
 pub mod get_pending_transactions_by_address;
@@ -55,13 +66,17 @@ pub mod get_version;
 pub mod health_check;
 pub mod metrics;
 pub mod pending_transaction_information;
+#[cfg(feature = "admin")]
 pub mod put_debug_settings_prof;
 pub mod raw_transaction;
+#[cfg(feature = "experimental")]
 pub mod raw_transaction_async;
 pub mod set_block_time_stamp_offset;
 pub mod set_sync_round;
+#[cfg(feature = "admin")]
 pub mod shutdown_node;
 pub mod simulate_transaction;
+#[cfg(feature = "admin")]
 pub mod start_catchup;
 pub mod swagger_json;
 pub mod teal_compile;
@@ -89,16 +104,20 @@ pub enum AlgodApiError {
     SwaggerJson { error: swagger_json::SwaggerJsonError },
     #[snafu(display("Get_version error: {error:?}"))]
     GetVersion { error: get_version::GetVersionError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Get_debug_settings_prof error: {error:?}"))]
     GetDebugSettingsProf { error: get_debug_settings_prof::GetDebugSettingsProfError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Put_debug_settings_prof error: {error:?}"))]
     PutDebugSettingsProf { error: put_debug_settings_prof::PutDebugSettingsProfError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Get_config error: {error:?}"))]
     GetConfig { error: get_config::GetConfigError },
     #[snafu(display("Account_information error: {error:?}"))]
     AccountInformation { error: account_information::AccountInformationError },
     #[snafu(display("Account_asset_information error: {error:?}"))]
     AccountAssetInformation { error: account_asset_information::AccountAssetInformationError },
+    #[cfg(feature = "experimental")]
     #[snafu(display("Account_assets_information error: {error:?}"))]
     AccountAssetsInformation { error: account_assets_information::AccountAssetsInformationError },
     #[snafu(display("Account_application_information error: {error:?}"))]
@@ -117,18 +136,25 @@ pub enum AlgodApiError {
     GetBlockLogs { error: get_block_logs::GetBlockLogsError },
     #[snafu(display("Get_supply error: {error:?}"))]
     GetSupply { error: get_supply::GetSupplyError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Get_participation_keys error: {error:?}"))]
     GetParticipationKeys { error: get_participation_keys::GetParticipationKeysError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Add_participation_key error: {error:?}"))]
     AddParticipationKey { error: add_participation_key::AddParticipationKeyError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Generate_participation_keys error: {error:?}"))]
     GenerateParticipationKeys { error: generate_participation_keys::GenerateParticipationKeysError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Get_participation_key_by_id error: {error:?}"))]
     GetParticipationKeyById { error: get_participation_key_by_id::GetParticipationKeyByIdError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Append_keys error: {error:?}"))]
     AppendKeys { error: append_keys::AppendKeysError },
+    #[cfg(feature = "participation")]
     #[snafu(display("Delete_participation_key_by_id error: {error:?}"))]
     DeleteParticipationKeyById { error: delete_participation_key_by_id::DeleteParticipationKeyByIdError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Shutdown_node error: {error:?}"))]
     ShutdownNode { error: shutdown_node::ShutdownNodeError },
     #[snafu(display("Get_status error: {error:?}"))]
@@ -137,6 +163,7 @@ pub enum AlgodApiError {
     WaitForBlock { error: wait_for_block::WaitForBlockError },
     #[snafu(display("Raw_transaction error: {error:?}"))]
     RawTransaction { error: raw_transaction::RawTransactionError },
+    #[cfg(feature = "experimental")]
     #[snafu(display("Raw_transaction_async error: {error:?}"))]
     RawTransactionAsync { error: raw_transaction_async::RawTransactionAsyncError },
     #[snafu(display("Simulate_transaction error: {error:?}"))]
@@ -175,12 +202,15 @@ pub enum AlgodApiError {
     TealCompile { error: teal_compile::TealCompileError },
     #[snafu(display("Teal_disassemble error: {error:?}"))]
     TealDisassemble { error: teal_disassemble::TealDisassembleError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Start_catchup error: {error:?}"))]
     StartCatchup { error: start_catchup::StartCatchupError },
+    #[cfg(feature = "admin")]
     #[snafu(display("Abort_catchup error: {error:?}"))]
     AbortCatchup { error: abort_catchup::AbortCatchupError },
     #[snafu(display("Teal_dryrun error: {error:?}"))]
     TealDryrun { error: teal_dryrun::TealDryrunError },
+    #[cfg(feature = "experimental")]
     #[snafu(display("Experimental_check error: {error:?}"))]
     ExperimentalCheck { error: experimental_check::ExperimentalCheckError },
     #[snafu(display("Get_block_time_stamp_offset error: {error:?}"))]
@@ -227,18 +257,21 @@ impl From<get_version::GetVersionError> for AlgodApiError {
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<get_debug_settings_prof::GetDebugSettingsProfError> for AlgodApiError {
     fn from(err: get_debug_settings_prof::GetDebugSettingsProfError) -> Self {
         AlgodApiError::GetDebugSettingsProf { error: err }
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<put_debug_settings_prof::PutDebugSettingsProfError> for AlgodApiError {
     fn from(err: put_debug_settings_prof::PutDebugSettingsProfError) -> Self {
         AlgodApiError::PutDebugSettingsProf { error: err }
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<get_config::GetConfigError> for AlgodApiError {
     fn from(err: get_config::GetConfigError) -> Self {
         AlgodApiError::GetConfig { error: err }
@@ -257,6 +290,7 @@ impl From<account_asset_information::AccountAssetInformationError> for AlgodApiE
     }
 }
 
+#[cfg(feature = "experimental")]
 impl From<account_assets_information::AccountAssetsInformationError> for AlgodApiError {
     fn from(err: account_assets_information::AccountAssetsInformationError) -> Self {
         AlgodApiError::AccountAssetsInformation { error: err }
@@ -315,42 +349,49 @@ impl From<get_supply::GetSupplyError> for AlgodApiError {
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<get_participation_keys::GetParticipationKeysError> for AlgodApiError {
     fn from(err: get_participation_keys::GetParticipationKeysError) -> Self {
         AlgodApiError::GetParticipationKeys { error: err }
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<add_participation_key::AddParticipationKeyError> for AlgodApiError {
     fn from(err: add_participation_key::AddParticipationKeyError) -> Self {
         AlgodApiError::AddParticipationKey { error: err }
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<generate_participation_keys::GenerateParticipationKeysError> for AlgodApiError {
     fn from(err: generate_participation_keys::GenerateParticipationKeysError) -> Self {
         AlgodApiError::GenerateParticipationKeys { error: err }
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<get_participation_key_by_id::GetParticipationKeyByIdError> for AlgodApiError {
     fn from(err: get_participation_key_by_id::GetParticipationKeyByIdError) -> Self {
         AlgodApiError::GetParticipationKeyById { error: err }
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<append_keys::AppendKeysError> for AlgodApiError {
     fn from(err: append_keys::AppendKeysError) -> Self {
         AlgodApiError::AppendKeys { error: err }
     }
 }
 
+#[cfg(feature = "participation")]
 impl From<delete_participation_key_by_id::DeleteParticipationKeyByIdError> for AlgodApiError {
     fn from(err: delete_participation_key_by_id::DeleteParticipationKeyByIdError) -> Self {
         AlgodApiError::DeleteParticipationKeyById { error: err }
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<shutdown_node::ShutdownNodeError> for AlgodApiError {
     fn from(err: shutdown_node::ShutdownNodeError) -> Self {
         AlgodApiError::ShutdownNode { error: err }
@@ -375,6 +416,7 @@ impl From<raw_transaction::RawTransactionError> for AlgodApiError {
     }
 }
 
+#[cfg(feature = "experimental")]
 impl From<raw_transaction_async::RawTransactionAsyncError> for AlgodApiError {
     fn from(err: raw_transaction_async::RawTransactionAsyncError) -> Self {
         AlgodApiError::RawTransactionAsync { error: err }
@@ -493,12 +535,14 @@ impl From<teal_disassemble::TealDisassembleError> for AlgodApiError {
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<start_catchup::StartCatchupError> for AlgodApiError {
     fn from(err: start_catchup::StartCatchupError) -> Self {
         AlgodApiError::StartCatchup { error: err }
     }
 }
 
+#[cfg(feature = "admin")]
 impl From<abort_catchup::AbortCatchupError> for AlgodApiError {
     fn from(err: abort_catchup::AbortCatchupError) -> Self {
         AlgodApiError::AbortCatchup { error: err }
@@ -511,6 +555,7 @@ impl From<teal_dryrun::TealDryrunError> for AlgodApiError {
     }
 }
 
+#[cfg(feature = "experimental")]
 impl From<experimental_check::ExperimentalCheckError> for AlgodApiError {
     fn from(err: experimental_check::ExperimentalCheckError) -> Self {
         AlgodApiError::ExperimentalCheck { error: err }
@@ -576,19 +621,26 @@ pub use client::AlgodClient;
 pub use parameter_enums::*;
 
 // Re-export all endpoint functions
+#[cfg(feature = "admin")]
 pub use abort_catchup::{AbortCatchupError, abort_catchup};
 pub use account_application_information::{
     AccountApplicationInformationError, account_application_information,
 };
 pub use account_asset_information::{AccountAssetInformationError, account_asset_information};
+#[cfg(feature = "experimental")]
 pub use account_assets_information::{AccountAssetsInformationError, account_assets_information};
 pub use account_information::{AccountInformationError, account_information};
+#[cfg(feature = "participation")]
 pub use add_participation_key::{AddParticipationKeyError, add_participation_key};
+#[cfg(feature = "participation")]
 pub use append_keys::{AppendKeysError, append_keys};
+#[cfg(feature = "participation")]
 pub use delete_participation_key_by_id::{
     DeleteParticipationKeyByIdError, delete_participation_key_by_id,
 };
+#[cfg(feature = "experimental")]
 pub use experimental_check::{ExperimentalCheckError, experimental_check};
+#[cfg(feature = "participation")]
 pub use generate_participation_keys::{
     GenerateParticipationKeysError, generate_participation_keys,
 };
@@ -601,7 +653,9 @@ pub use get_block_hash::{GetBlockHashError, get_block_hash};
 pub use get_block_logs::{GetBlockLogsError, get_block_logs};
 pub use get_block_time_stamp_offset::{GetBlockTimeStampOffsetError, get_block_time_stamp_offset};
 pub use get_block_txids::{GetBlockTxidsError, get_block_txids};
+#[cfg(feature = "admin")]
 pub use get_config::{GetConfigError, get_config};
+#[cfg(feature = "admin")]
 pub use get_debug_settings_prof::{GetDebugSettingsProfError, get_debug_settings_prof};
 pub use get_genesis::{GetGenesisError, get_genesis};
 pub use get_ledger_state_delta::{GetLedgerStateDeltaError, get_ledger_state_delta};
@@ -611,7 +665,9 @@ pub use get_ledger_state_delta_for_transaction_group::{
 pub use get_light_block_header_proof::{
     GetLightBlockHeaderProofError, get_light_block_header_proof,
 };
+#[cfg(feature = "participation")]
 pub use get_participation_key_by_id::{GetParticipationKeyByIdError, get_participation_key_by_id};
+#[cfg(feature = "participation")]
 pub use get_participation_keys::{GetParticipationKeysError, get_participation_keys};
 pub use get_pending_transactions::{GetPendingTransactionsError, get_pending_transactions};
 pub use get_pending_transactions_by_address::{
@@ -633,13 +689,17 @@ pub use metrics::{MetricsError, metrics};
 pub use pending_transaction_information::{
     PendingTransactionInformationError, pending_transaction_information,
 };
+#[cfg(feature = "admin")]
 pub use put_debug_settings_prof::{PutDebugSettingsProfError, put_debug_settings_prof};
 pub use raw_transaction::{RawTransactionError, raw_transaction};
+#[cfg(feature = "experimental")]
 pub use raw_transaction_async::{RawTransactionAsyncError, raw_transaction_async};
 pub use set_block_time_stamp_offset::{SetBlockTimeStampOffsetError, set_block_time_stamp_offset};
 pub use set_sync_round::{SetSyncRoundError, set_sync_round};
+#[cfg(feature = "admin")]
 pub use shutdown_node::{ShutdownNodeError, shutdown_node};
 pub use simulate_transaction::{SimulateTransactionError, simulate_transaction};
+#[cfg(feature = "admin")]
 pub use start_catchup::{StartCatchupError, start_catchup};
 pub use swagger_json::{SwaggerJsonError, swagger_json};
 pub use teal_compile::{TealCompileError, teal_compile};
@@ -8,7 +8,7 @@
  * Generated by: Rust OpenAPI Generator
  */
 
-use algokit_http_client::{HttpClient, HttpMethod};
+use algokit_http_client::{HttpClient, HttpMethod, HttpResponse};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -37,7 +37,7 @@ pub enum TealDisassembleError {
 /// Given the program bytes, return the TEAL source code in plain text. This endpoint is only enabled when a node's configuration file sets EnableDeveloperAPI to true.
 pub async fn teal_disassemble(
     http_client: &dyn HttpClient,
-    request: String,
+    request: Vec<u8>,
 ) -> Result<TealDisassemble, Error> {
     let p_request = request;
 
@@ -52,9 +52,7 @@ pub async fn teal_disassemble(
     );
     headers.insert("Accept".to_string(), "application/msgpack".to_string());
 
-    let body = Some(serde_json::to_vec(&p_request).map_err(|e| Error::Serde {
-        message: e.to_string(),
-    })?);
+    let body = Some(p_request);
 
     let response = http_client
         .request(
@@ -63,8 +61,11 @@ pub async fn teal_disassemble(
             Some(query_params),
             body,
             Some(headers),
+            None,
         )
         .await
+        // Other clients than the default one may return non-2xx responses
+        .and_then(HttpResponse::error_for_status)
         .map_err(|e| Error::Http { source: e })?;
 
     let content_type = response
@@ -17,6 +17,11 @@ use serde::{Deserialize, Serialize};
 use algokit_transact_ffi::SignedTransaction as AlgokitSignedTransaction;
 
 use algokit_transact::AlgorandMsgpack;
+#[cfg(not(feature = "ffi_uniffi"))]
+use algokit_transact::Block as AlgokitBlock;
+
+#[cfg(feature = "ffi_uniffi")]
+use crate::models::UnknownJsonValue as AlgokitBlock;
 
 use crate::models::UnknownJsonValue;
 
@@ -26,7 +31,7 @@ use crate::models::UnknownJsonValue;
 pub struct GetBlock {
     /// Block header data.
     #[serde(rename = "block")]
-    pub block: UnknownJsonValue,
+    pub block: AlgokitBlock,
     /// Optional certificate object. This is only included when the format is set to message pack.
     #[serde(rename = "cert", skip_serializing_if = "Option::is_none")]
     pub cert: Option<UnknownJsonValue>,
@@ -38,7 +43,7 @@ impl AlgorandMsgpack for GetBlock {
 
 impl GetBlock {
     /// Constructor for GetBlock
-    pub fn new(block: UnknownJsonValue) -> GetBlock {
+    pub fn new(block: AlgokitBlock) -> GetBlock {
         GetBlock { block, cert: None }
     }
 
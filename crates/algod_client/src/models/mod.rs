@@ -24,7 +24,9 @@ pub mod ledger_state_delta_for_transaction_group;
 pub use self::ledger_state_delta_for_transaction_group::LedgerStateDeltaForTransactionGroup;
 pub mod account;
 pub use self::account::Account;
+#[cfg(feature = "experimental")]
 pub mod account_asset_holding;
+#[cfg(feature = "experimental")]
 pub use self::account_asset_holding::AccountAssetHolding;
 pub mod account_participation;
 pub use self::account_participation::AccountParticipation;
@@ -42,7 +44,9 @@ pub mod application_state_schema;
 pub use self::application_state_schema::ApplicationStateSchema;
 pub mod application_local_state;
 pub use self::application_local_state::ApplicationLocalState;
+#[cfg(feature = "participation")]
 pub mod participation_key;
+#[cfg(feature = "participation")]
 pub use self::participation_key::ParticipationKey;
 pub mod teal_key_value_store;
 pub use self::teal_key_value_store::TealKeyValueStore;
@@ -90,7 +94,9 @@ pub mod box_reference;
 pub use self::box_reference::BoxReference;
 pub mod version;
 pub use self::version::Version;
+#[cfg(feature = "admin")]
 pub mod debug_settings_prof;
+#[cfg(feature = "admin")]
 pub use self::debug_settings_prof::DebugSettingsProf;
 pub mod build_version;
 pub use self::build_version::BuildVersion;
@@ -130,7 +136,9 @@ pub mod transaction_proof;
 pub use self::transaction_proof::TransactionProof;
 pub mod account_asset_information;
 pub use self::account_asset_information::AccountAssetInformation;
+#[cfg(feature = "experimental")]
 pub mod account_assets_information;
+#[cfg(feature = "experimental")]
 pub use self::account_assets_information::AccountAssetsInformation;
 pub mod account_application_information;
 pub use self::account_application_information::AccountApplicationInformation;
@@ -146,7 +154,9 @@ pub mod get_block_logs;
 pub use self::get_block_logs::GetBlockLogs;
 pub mod get_supply;
 pub use self::get_supply::GetSupply;
+#[cfg(feature = "participation")]
 pub mod add_participation_key;
+#[cfg(feature = "participation")]
 pub use self::add_participation_key::AddParticipationKey;
 pub mod get_status;
 pub use self::get_status::GetStatus;
@@ -170,9 +180,13 @@ pub mod teal_compile;
 pub use self::teal_compile::TealCompile;
 pub mod teal_disassemble;
 pub use self::teal_disassemble::TealDisassemble;
+#[cfg(feature = "admin")]
 pub mod start_catchup;
+#[cfg(feature = "admin")]
 pub use self::start_catchup::StartCatchup;
+#[cfg(feature = "admin")]
 pub mod abort_catchup;
+#[cfg(feature = "admin")]
 pub use self::abort_catchup::AbortCatchup;
 pub mod teal_dryrun;
 pub use self::teal_dryrun::TealDryrun;
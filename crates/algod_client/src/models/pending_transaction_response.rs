@@ -116,6 +116,7 @@ impl Default for PendingTransactionResponse {
                 signature: None,
                 auth_address: None,
                 multisignature: None,
+                logic_signature: None,
             },
         }
     }
@@ -1,10 +1,11 @@
 use crate::DefaultValueSource;
 use crate::abi_type::ABIType;
+use crate::abi_type::BitSize;
 use crate::abi_value::ABIValue;
-use crate::constants::VOID_RETURN_TYPE;
+use crate::constants::{ARGS_TUPLE_PACKING_THRESHOLD, MAX_UNPACKED_METHOD_ARGS, VOID_RETURN_TYPE};
 use crate::error::ABIError;
 use sha2::{Digest, Sha512_256};
-use std::fmt::Display;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::FromStr;
 
 /// Represents a transaction type that can be used as an ABI method argument.
@@ -228,6 +229,61 @@ impl ABIMethod {
     }
 }
 
+/// Write an ABI type for display, using the names of ARC-56 structs instead of their tuple types.
+fn fmt_type_with_struct_names(f: &mut Formatter<'_>, abi_type: &ABIType) -> FmtResult {
+    match abi_type {
+        ABIType::Struct(struct_type) => write!(f, "{}", struct_type.name),
+        ABIType::StaticArray(child_type, length) => {
+            fmt_type_with_struct_names(f, child_type)?;
+            write!(f, "[{}]", length)
+        }
+        ABIType::DynamicArray(child_type) => {
+            fmt_type_with_struct_names(f, child_type)?;
+            write!(f, "[]")
+        }
+        ABIType::Tuple(child_types) => {
+            write!(f, "(")?;
+            for (index, child_type) in child_types.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ",")?;
+                }
+                fmt_type_with_struct_names(f, child_type)?;
+            }
+            write!(f, ")")
+        }
+        _ => write!(f, "{}", abi_type),
+    }
+}
+
+/// Human-readable representation for logging and debugging, e.g.
+/// `swap(amount: uint64, order: Order) -> uint64`.
+///
+/// Unlike [`ABIMethod::signature`], argument names are included and struct types are shown by
+/// their name.
+impl Display for ABIMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}(", self.name)?;
+        for (index, arg) in self.args.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            if let Some(name) = &arg.name {
+                write!(f, "{}: ", name)?;
+            }
+            match &arg.arg_type {
+                ABIMethodArgType::Value(abi_type) => fmt_type_with_struct_names(f, abi_type)?,
+                ABIMethodArgType::Transaction(tx_type) => write!(f, "{}", tx_type)?,
+                ABIMethodArgType::Reference(ref_type) => write!(f, "{}", ref_type)?,
+            }
+        }
+        write!(f, ") -> ")?;
+        match &self.returns {
+            Some(return_type) => fmt_type_with_struct_names(f, return_type),
+            None => write!(f, "{}", VOID_RETURN_TYPE),
+        }
+    }
+}
+
 /// Default value information for ABI method arguments.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ABIDefaultValue {
@@ -389,6 +445,125 @@ pub struct ABIReturn {
     pub decode_error: Option<ABIError>,
 }
 
+/// Two methods of a contract whose distinct signatures hash to the same selector, so an app
+/// routing on selectors can't tell calls to them apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodSelectorCollision {
+    pub selector: Vec<u8>,
+    /// The index and signature of the method declared first
+    pub first: (usize, String),
+    /// The index and signature of the method declared later
+    pub second: (usize, String),
+}
+
+impl Display for MethodSelectorCollision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Methods {} and {} have the same selector 0x",
+            self.first.1, self.second.1
+        )?;
+        for byte in &self.selector {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the methods of an ARC-4 contract description whose selectors collide.
+///
+/// Methods declared more than once with the same signature are not reported, nor are methods
+/// whose signature is invalid.
+pub fn find_selector_collisions(methods: &[ABIMethod]) -> Vec<MethodSelectorCollision> {
+    let mut seen: Vec<(Vec<u8>, usize, String)> = Vec::new();
+    let mut collisions = Vec::new();
+
+    for (index, method) in methods.iter().enumerate() {
+        let (Ok(signature), Ok(selector)) = (method.signature(), method.selector()) else {
+            continue;
+        };
+        let colliding = seen
+            .iter()
+            .find(|(seen_selector, _, seen_signature)| {
+                *seen_selector == selector && *seen_signature != signature
+            })
+            .map(|(_, first_index, first_signature)| (*first_index, first_signature.clone()));
+        if let Some(first) = colliding {
+            collisions.push(MethodSelectorCollision {
+                selector: selector.clone(),
+                first,
+                second: (index, signature.clone()),
+            });
+        }
+        seen.push((selector, index, signature));
+    }
+
+    collisions
+}
+
+/// Encodes the arguments of an ABI method call into app call args as defined in ARC-4.
+///
+/// The method selector is returned as the first arg. Reference arguments are encoded as `uint8`
+/// indexes into their reference array, so the caller provides the resolved index as a uint value.
+/// Transaction arguments are not encoded in the app args and must be omitted from `args`.
+/// Methods with more than 15 arguments have the 15th argument onwards packed into a single tuple,
+/// so 15 arguments are still encoded individually.
+///
+/// # Arguments
+/// * `method` - The method being called.
+/// * `args` - One value for each non-transaction argument of the method, in order.
+///
+/// # Returns
+/// The encoded app args if successful, or an [`ABIError`] if encoding fails.
+pub fn encode_method_args(method: &ABIMethod, args: &[ABIValue]) -> Result<Vec<Vec<u8>>, ABIError> {
+    let abi_types = method
+        .args
+        .iter()
+        .filter_map(|arg| match &arg.arg_type {
+            ABIMethodArgType::Value(abi_type) => Some(abi_type.clone()),
+            ABIMethodArgType::Reference(_) => Some(ABIType::Uint(
+                BitSize::new(8).expect("8 should always be a valid BitSize"),
+            )),
+            ABIMethodArgType::Transaction(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if abi_types.len() != args.len() {
+        return Err(ABIError::EncodingError {
+            message: format!(
+                "Method {} expects {} non-transaction arguments, got {}",
+                method.name,
+                abi_types.len(),
+                args.len()
+            ),
+        });
+    }
+
+    let mut encoded_args = vec![method.selector()?];
+
+    let packed = abi_types.len() > MAX_UNPACKED_METHOD_ARGS;
+    let individual_count = if packed {
+        ARGS_TUPLE_PACKING_THRESHOLD
+    } else {
+        abi_types.len()
+    };
+
+    for (abi_type, value) in abi_types[..individual_count]
+        .iter()
+        .zip(&args[..individual_count])
+    {
+        encoded_args.push(abi_type.encode(value)?);
+    }
+
+    if packed {
+        let tuple_type = ABIType::Tuple(abi_types[ARGS_TUPLE_PACKING_THRESHOLD..].to_vec());
+        let tuple_value = ABIValue::Array(args[ARGS_TUPLE_PACKING_THRESHOLD..].to_vec());
+        encoded_args.push(tuple_type.encode(&tuple_value)?);
+    }
+
+    Ok(encoded_args)
+}
+
 /// Find the matching closing parenthesis for an opening parenthesis.
 fn find_matching_closing_paren(s: &str, open_pos: usize) -> Result<usize, ABIError> {
     let chars: Vec<char> = s.chars().collect();
@@ -551,6 +726,58 @@ mod tests {
         assert!(parse_tuple_content(input).is_err());
     }
 
+    #[test]
+    fn encode_method_args_with_reference_and_transaction_args() {
+        let method = ABIMethod::from_str("transfer(pay,account,uint64)bool").unwrap();
+
+        let encoded =
+            encode_method_args(&method, &[ABIValue::from(1u8), ABIValue::from(1000u64)]).unwrap();
+
+        assert_eq!(
+            encoded,
+            vec![
+                method.selector().unwrap(),
+                vec![1],
+                1000u64.to_be_bytes().to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_method_args_packs_args_beyond_fifteenth_into_tuple() {
+        let signature = format!("many({})void", vec!["uint8"; 17].join(","));
+        let method = ABIMethod::from_str(&signature).unwrap();
+        let args = (0u8..17).map(ABIValue::from).collect::<Vec<_>>();
+
+        let encoded = encode_method_args(&method, &args).unwrap();
+
+        assert_eq!(encoded.len(), 16);
+        assert_eq!(encoded[14], vec![13]);
+        assert_eq!(encoded[15], vec![14, 15, 16]);
+    }
+
+    #[test]
+    fn encode_method_args_encodes_fifteen_args_individually() {
+        let signature = format!("many({})void", vec!["uint8"; 15].join(","));
+        let method = ABIMethod::from_str(&signature).unwrap();
+        let args = (0u8..15).map(ABIValue::from).collect::<Vec<_>>();
+
+        let encoded = encode_method_args(&method, &args).unwrap();
+
+        assert_eq!(encoded.len(), 16);
+        assert_eq!(encoded[14], vec![13]);
+        assert_eq!(encoded[15], vec![14]);
+    }
+
+    #[test]
+    fn encode_method_args_invalid_arg_count() {
+        let method = ABIMethod::from_str("add(uint64,uint64)uint64").unwrap();
+
+        let result = encode_method_args(&method, &[ABIValue::from(1u64)]);
+
+        assert!(matches!(result, Err(ABIError::EncodingError { .. })));
+    }
+
     // Method argument type predicates
     #[test]
     fn method_arg_type_predicates() {
@@ -562,4 +789,15 @@ mod tests {
         assert!(!ref_arg.is_transaction() && ref_arg.is_reference() && !ref_arg.is_value_type());
         assert!(!val_arg.is_transaction() && !val_arg.is_reference() && val_arg.is_value_type());
     }
+
+    #[test]
+    fn test_method_display() {
+        let method =
+            ABIMethod::from_str("transfer(pay,account,(uint64,string)[],byte[4])void").unwrap();
+
+        assert_eq!(
+            method.to_string(),
+            "transfer(arg0: pay, arg1: account, arg2: (uint64,string)[], arg3: byte[4]) -> void"
+        );
+    }
 }
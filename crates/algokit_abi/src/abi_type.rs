@@ -1,11 +1,15 @@
 use crate::{
     ABIError, ABIValue, StructField,
     constants::{
-        ALGORAND_PUBLIC_KEY_BYTE_LENGTH, BITS_PER_BYTE, MAX_BIT_SIZE, MAX_PRECISION,
-        STATIC_ARRAY_REGEX, UFIXED_REGEX,
+        ALGORAND_PUBLIC_KEY_BYTE_LENGTH, BITS_PER_BYTE, LENGTH_ENCODE_BYTE_SIZE, MAX_BIT_SIZE,
+        MAX_PRECISION, STATIC_ARRAY_REGEX, UFIXED_REGEX,
+    },
+    types::{
+        collections::{r#struct::ABIStruct, tuple::find_bool_sequence_end},
+        primitives::address::encode_address,
     },
-    types::collections::{r#struct::ABIStruct, tuple::find_bool_sequence_end},
 };
+use num_bigint::BigUint;
 use std::{
     collections::HashMap,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -166,6 +170,97 @@ impl ABIType {
         }
     }
 
+    /// Returns the canonical zero value of this ABI type.
+    ///
+    /// Numbers are zero, booleans are false, strings and dynamic arrays are empty, addresses are
+    /// the zero address and static arrays, tuples and structs are filled with the zero values
+    /// of their child types.
+    ///
+    /// # Returns
+    /// The zero [`ABIValue`], which always encodes successfully with this type.
+    pub fn default_value(&self) -> ABIValue {
+        match self {
+            ABIType::Uint(_) | ABIType::UFixed(_, _) | ABIType::AVMUint64 => {
+                ABIValue::Uint(BigUint::ZERO)
+            }
+            ABIType::Address => {
+                ABIValue::Address(encode_address(&[0u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH]))
+            }
+            ABIType::String | ABIType::AVMString => ABIValue::String(String::new()),
+            ABIType::Bool => ABIValue::Bool(false),
+            ABIType::Byte => ABIValue::Byte(0),
+            ABIType::AVMBytes => ABIValue::Bytes(Vec::new()),
+            ABIType::Tuple(child_types) => {
+                ABIValue::Array(child_types.iter().map(ABIType::default_value).collect())
+            }
+            ABIType::StaticArray(child_type, size) => {
+                ABIValue::Array(vec![child_type.default_value(); *size])
+            }
+            ABIType::DynamicArray(_) => ABIValue::Array(Vec::new()),
+            ABIType::Struct(struct_type) => struct_type.default_value(),
+        }
+    }
+
+    /// Returns the encoded length in bytes of this ABI type if it is static.
+    ///
+    /// # Returns
+    /// The number of bytes every value of this type encodes to, or `None` if the type is dynamic.
+    pub fn static_byte_len(&self) -> Option<usize> {
+        if self.is_dynamic() {
+            return None;
+        }
+        Self::get_size(self).ok()
+    }
+
+    /// Returns the minimum encoded length in bytes of this ABI type.
+    ///
+    /// For static types this is the same as [`ABIType::static_byte_len`]. For dynamic types this
+    /// is the length of the smallest possible encoding, e.g. the length prefix of an empty string
+    /// or dynamic array, plus the offsets of any dynamic children.
+    ///
+    /// # Returns
+    /// The minimum number of bytes a value of this type encodes to.
+    pub fn min_byte_len(&self) -> usize {
+        if let Some(static_byte_len) = self.static_byte_len() {
+            return static_byte_len;
+        }
+
+        match self {
+            ABIType::String | ABIType::DynamicArray(_) => LENGTH_ENCODE_BYTE_SIZE,
+            ABIType::AVMBytes | ABIType::AVMString => 0,
+            ABIType::StaticArray(child_type, size) => {
+                (LENGTH_ENCODE_BYTE_SIZE + child_type.min_byte_len()) * size
+            }
+            ABIType::Tuple(child_types) => {
+                let mut len = 0;
+                let mut i = 0;
+                while i < child_types.len() {
+                    let child_type = &child_types[i];
+                    match child_type {
+                        ABIType::Bool => {
+                            let sequence_end_index = find_bool_sequence_end(child_types, i);
+                            let bool_count = sequence_end_index - i + 1;
+
+                            len += bool_count.div_ceil(BITS_PER_BYTE as usize);
+                            i = sequence_end_index + 1;
+                        }
+                        _ if child_type.is_dynamic() => {
+                            len += LENGTH_ENCODE_BYTE_SIZE + child_type.min_byte_len();
+                            i += 1;
+                        }
+                        _ => {
+                            len += child_type.min_byte_len();
+                            i += 1;
+                        }
+                    }
+                }
+                len
+            }
+            ABIType::Struct(struct_type) => struct_type.to_tuple_type().min_byte_len(),
+            _ => 0,
+        }
+    }
+
     pub(crate) fn is_dynamic(&self) -> bool {
         match self {
             ABIType::StaticArray(child_type, _) => child_type.is_dynamic(),
@@ -617,4 +712,97 @@ mod tests {
         let decoded = abi_type.decode(&encoded).expect("Failed to decode");
         assert_eq!(decoded, abi_value);
     }
+
+    #[rstest]
+    #[case("uint64", ABIValue::Uint(BigUint::from(0u8)), &[0, 0, 0, 0, 0, 0, 0, 0])]
+    #[case("ufixed16x2", ABIValue::Uint(BigUint::from(0u8)), &[0, 0])]
+    #[case("bool", ABIValue::Bool(false), &[0])]
+    #[case("byte", ABIValue::Byte(0), &[0])]
+    #[case("string", ABIValue::String(String::new()), &[0, 0])]
+    #[case("uint8[]", ABIValue::Array(vec![]), &[0, 0])]
+    #[case(
+        "bool[2]",
+        ABIValue::Array(vec![ABIValue::Bool(false), ABIValue::Bool(false)]),
+        &[0]
+    )]
+    #[case(
+        "address",
+        ABIValue::Address("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ".to_string()),
+        &[0; 32]
+    )]
+    #[case(
+        "(uint8,(string,bool))",
+        ABIValue::Array(vec![
+            ABIValue::Uint(BigUint::from(0u8)),
+            ABIValue::Array(vec![ABIValue::String(String::new()), ABIValue::Bool(false)]),
+        ]),
+        &[0, 0, 3, 0, 3, 0, 0, 0]
+    )]
+    fn should_produce_default_value(
+        #[case] type_str: &str,
+        #[case] expected_value: ABIValue,
+        #[case] expected_encoded_value: &[u8],
+    ) {
+        let abi_type = ABIType::from_str(type_str).unwrap();
+        let default_value = abi_type.default_value();
+        assert_eq!(default_value, expected_value);
+        assert_eq!(
+            abi_type.encode(&default_value).unwrap(),
+            expected_encoded_value
+        );
+    }
+
+    #[rstest]
+    #[case("uint64", Some(8), 8)]
+    #[case("ufixed128x10", Some(16), 16)]
+    #[case("address", Some(32), 32)]
+    #[case("bool[10]", Some(2), 2)]
+    #[case("(bool,byte,bool,bool)", Some(3), 3)]
+    #[case("(uint8,byte[4])[2]", Some(10), 10)]
+    #[case("string", None, 2)]
+    #[case("uint8[]", None, 2)]
+    #[case("string[3]", None, 12)]
+    #[case("(bool,string,bool)", None, 6)]
+    #[case("(uint8,(string,bool))", None, 8)]
+    fn should_compute_byte_len(
+        #[case] type_str: &str,
+        #[case] expected_static_byte_len: Option<usize>,
+        #[case] expected_min_byte_len: usize,
+    ) {
+        let abi_type = ABIType::from_str(type_str).unwrap();
+        assert_eq!(abi_type.static_byte_len(), expected_static_byte_len);
+        assert_eq!(abi_type.min_byte_len(), expected_min_byte_len);
+        assert_eq!(
+            abi_type.encode(&abi_type.default_value()).unwrap().len(),
+            expected_min_byte_len
+        );
+    }
+
+    #[test]
+    fn should_produce_default_struct_value() {
+        let structs = HashMap::from([(
+            "Point".to_string(),
+            vec![
+                StructField {
+                    name: "x".to_string(),
+                    field_type: crate::arc56_contract::StructFieldType::Value("uint64".to_string()),
+                },
+                StructField {
+                    name: "label".to_string(),
+                    field_type: crate::arc56_contract::StructFieldType::Value("string".to_string()),
+                },
+            ],
+        )]);
+        let abi_type = ABIType::from_struct("Point", &structs).unwrap();
+
+        let default_value = abi_type.default_value();
+        assert_eq!(
+            default_value,
+            ABIValue::Struct(HashMap::from([
+                ("x".to_string(), ABIValue::Uint(BigUint::from(0u8))),
+                ("label".to_string(), ABIValue::String(String::new())),
+            ]))
+        );
+        assert!(abi_type.encode(&default_value).is_ok());
+    }
 }
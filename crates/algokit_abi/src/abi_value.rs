@@ -1,7 +1,11 @@
 use num_bigint::BigUint;
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::hash::{Hash, Hasher};
 
+/// The number of bytes shown when displaying byte arrays, longer arrays are truncated.
+const DISPLAY_BYTES_LIMIT: usize = 32;
+
 /// Represents a value that can be encoded or decoded as an ABI type.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ABIValue {
@@ -154,6 +158,70 @@ impl Hash for ABIValue {
     }
 }
 
+/// Write `bytes` as hex, truncated to [`DISPLAY_BYTES_LIMIT`] bytes with the full length noted.
+fn fmt_bytes(f: &mut Formatter<'_>, bytes: &[u8]) -> FmtResult {
+    let shown = &bytes[..bytes.len().min(DISPLAY_BYTES_LIMIT)];
+    write!(f, "0x")?;
+    for byte in shown {
+        write!(f, "{:02x}", byte)?;
+    }
+    if shown.len() < bytes.len() {
+        write!(f, "…({} bytes)", bytes.len())?;
+    }
+    Ok(())
+}
+
+/// Human-readable representation for logging and debugging.
+///
+/// Strings are quoted, byte arrays are shown as (truncated) hex, addresses in their base32 form
+/// and struct fields in alphabetical order.
+impl Display for ABIValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ABIValue::Bool(b) => write!(f, "{}", b),
+            ABIValue::Uint(u) => write!(f, "{}", u),
+            ABIValue::String(s) => write!(f, "{:?}", s),
+            ABIValue::Byte(b) => write!(f, "{}", b),
+            ABIValue::Address(addr) => write!(f, "{}", addr),
+            ABIValue::Bytes(bytes) => fmt_bytes(f, bytes),
+            ABIValue::Array(values)
+                if !values.is_empty() && values.iter().all(|v| matches!(v, ABIValue::Byte(_))) =>
+            {
+                let bytes = values
+                    .iter()
+                    .filter_map(|v| match v {
+                        ABIValue::Byte(b) => Some(*b),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                fmt_bytes(f, &bytes)
+            }
+            ABIValue::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            ABIValue::Struct(fields) => {
+                let mut fields = fields.iter().collect::<Vec<_>>();
+                fields.sort_by_key(|(name, _)| *name);
+                write!(f, "{{")?;
+                for (index, (name, value)) in fields.into_iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -245,4 +313,40 @@ mod tests {
         let value2 = ABIValue::from(struct_map.clone());
         assert_eq!(value2, ABIValue::Struct(struct_map));
     }
+
+    #[rstest]
+    #[case(ABIValue::from(true), "true")]
+    #[case(ABIValue::from(42u64), "42")]
+    #[case(ABIValue::from("say \"hi\""), r#""say \"hi\"""#)]
+    #[case(ABIValue::Bytes(vec![0xde, 0xad]), "0xdead")]
+    #[case(ABIValue::Array(vec![ABIValue::Byte(1), ABIValue::Byte(255)]), "0x01ff")]
+    #[case(ABIValue::Array(vec![]), "[]")]
+    #[case(
+        ABIValue::Array(vec![ABIValue::from(1u8), ABIValue::from("a")]),
+        r#"[1, "a"]"#
+    )]
+    #[case(
+        ABIValue::from_address("MO2H6ZU47Q36GJ6GVHUKGEBEQINN7ZWVACMWZQGIYUOE3RBSRVYHV4ACJI"),
+        "MO2H6ZU47Q36GJ6GVHUKGEBEQINN7ZWVACMWZQGIYUOE3RBSRVYHV4ACJI"
+    )]
+    #[case(
+        ABIValue::Struct(HashMap::from([
+            ("b".to_string(), ABIValue::from(2u8)),
+            ("a".to_string(), ABIValue::Struct(HashMap::from([("c".to_string(), ABIValue::from(false))]))),
+        ])),
+        "{a: {c: false}, b: 2}"
+    )]
+    fn test_display(#[case] value: ABIValue, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_truncates_long_bytes() {
+        let value = ABIValue::Bytes(vec![0xab; 100]);
+
+        assert_eq!(
+            value.to_string(),
+            format!("0x{}…(100 bytes)", "ab".repeat(32))
+        );
+    }
 }
@@ -0,0 +1,220 @@
+//! Random [`ABIValue`] generation for property testing, built on [proptest](https://docs.rs/proptest).
+//!
+//! The generated values are always valid for the requested [`ABIType`] and are in the canonical
+//! form produced by decoding, so `decode(encode(value)) == value` holds for every generated value.
+use crate::constants::ALGORAND_PUBLIC_KEY_BYTE_LENGTH;
+use crate::types::collections::r#struct::{StructField, StructFieldType};
+use crate::{ABIType, ABIValue};
+use num_bigint::BigUint;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+/// Size bounds used when generating values for dynamically sized ABI types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ABIValueBounds {
+    /// The maximum number of elements generated for a dynamic array.
+    pub max_array_length: usize,
+    /// The maximum number of characters generated for a string.
+    pub max_string_length: usize,
+    /// The maximum number of bytes generated for an AVM byte array.
+    pub max_bytes_length: usize,
+}
+
+impl Default for ABIValueBounds {
+    fn default() -> Self {
+        Self {
+            max_array_length: 8,
+            max_string_length: 32,
+            max_bytes_length: 64,
+        }
+    }
+}
+
+/// Create a proptest strategy producing valid values of the given ABI type.
+///
+/// # Arguments
+/// * `abi_type` - The type of the values to generate.
+/// * `bounds` - The size bounds for dynamically sized types.
+pub fn abi_value_strategy(abi_type: &ABIType, bounds: &ABIValueBounds) -> BoxedStrategy<ABIValue> {
+    match abi_type {
+        ABIType::Uint(bit_size) | ABIType::UFixed(bit_size, _) => {
+            uint_strategy(bit_size.value() as usize)
+        }
+        ABIType::AVMUint64 => uint_strategy(64),
+        ABIType::Address => any::<[u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH]>()
+            .prop_map(|public_key| {
+                ABIType::Address
+                    .decode(&public_key)
+                    .expect("any 32 bytes are a valid address")
+            })
+            .boxed(),
+        ABIType::Bool => any::<bool>().prop_map(ABIValue::Bool).boxed(),
+        ABIType::Byte => any::<u8>().prop_map(ABIValue::Byte).boxed(),
+        ABIType::String | ABIType::AVMString => vec(any::<char>(), 0..=bounds.max_string_length)
+            .prop_map(|chars| ABIValue::String(chars.into_iter().collect()))
+            .boxed(),
+        ABIType::AVMBytes => vec(any::<u8>(), 0..=bounds.max_bytes_length)
+            .prop_map(ABIValue::Bytes)
+            .boxed(),
+        ABIType::Tuple(child_types) => {
+            let strategies: Vec<_> = child_types
+                .iter()
+                .map(|child_type| abi_value_strategy(child_type, bounds))
+                .collect();
+            strategies.prop_map(ABIValue::Array).boxed()
+        }
+        ABIType::StaticArray(child_type, size) => {
+            vec(abi_value_strategy(child_type, bounds), *size)
+                .prop_map(ABIValue::Array)
+                .boxed()
+        }
+        ABIType::DynamicArray(child_type) => vec(
+            abi_value_strategy(child_type, bounds),
+            0..=bounds.max_array_length,
+        )
+        .prop_map(ABIValue::Array)
+        .boxed(),
+        ABIType::Struct(struct_type) => struct_strategy(&struct_type.fields, bounds),
+    }
+}
+
+/// Generate `count` values of the given ABI type from a fixed seed.
+///
+/// The same seed always produces the same values, which makes this suitable for
+/// generating fuzz corpora that can be shared with other SDKs.
+pub fn generate_abi_values(
+    abi_type: &ABIType,
+    bounds: &ABIValueBounds,
+    count: usize,
+    seed: [u8; 32],
+) -> Vec<ABIValue> {
+    let strategy = abi_value_strategy(abi_type, bounds);
+    let mut runner = TestRunner::new_with_rng(
+        Config::default(),
+        TestRng::from_seed(RngAlgorithm::ChaCha, &seed),
+    );
+
+    (0..count)
+        .map(|_| {
+            strategy
+                .new_tree(&mut runner)
+                .expect("ABI value strategies never reject")
+                .current()
+        })
+        .collect()
+}
+
+fn uint_strategy(bit_size: usize) -> BoxedStrategy<ABIValue> {
+    vec(any::<u8>(), bit_size / 8)
+        .prop_map(|bytes| ABIValue::Uint(BigUint::from_bytes_be(&bytes)))
+        .boxed()
+}
+
+fn struct_strategy(fields: &[StructField], bounds: &ABIValueBounds) -> BoxedStrategy<ABIValue> {
+    let strategies: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let name = field.name.clone();
+            let value_strategy = match &field.field_type {
+                StructFieldType::Type(field_type) => abi_value_strategy(field_type, bounds),
+                StructFieldType::Fields(nested_fields) => struct_strategy(nested_fields, bounds),
+            };
+            (Just(name), value_strategy)
+        })
+        .collect();
+
+    strategies
+        .prop_map(|entries| ABIValue::Struct(entries.into_iter().collect()))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::collections::r#struct::ABIStruct;
+    use std::str::FromStr;
+
+    const NESTED_TYPE: &str = "(bool[],(string,address),byte[4],ufixed64x2[])";
+
+    fn bounds() -> ABIValueBounds {
+        ABIValueBounds::default()
+    }
+
+    fn strategy_for(type_str: &str) -> BoxedStrategy<ABIValue> {
+        abi_value_strategy(&ABIType::from_str(type_str).unwrap(), &bounds())
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip_uint(value in strategy_for("uint256")) {
+            let abi_type = ABIType::from_str("uint256").unwrap();
+            prop_assert_eq!(abi_type.decode(&abi_type.encode(&value).unwrap()).unwrap(), value);
+        }
+
+        #[test]
+        fn test_round_trip_nested(value in strategy_for(NESTED_TYPE)) {
+            let abi_type = ABIType::from_str(NESTED_TYPE).unwrap();
+            prop_assert_eq!(abi_type.decode(&abi_type.encode(&value).unwrap()).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_generate_abi_values_is_deterministic() {
+        let abi_type = ABIType::from_str("(uint64,string,bool[3])").unwrap();
+        let first = generate_abi_values(&abi_type, &bounds(), 10, [7u8; 32]);
+        let second = generate_abi_values(&abi_type, &bounds(), 10, [7u8; 32]);
+
+        assert_eq!(first.len(), 10);
+        assert_eq!(first, second);
+        for value in &first {
+            assert_eq!(
+                abi_type.decode(&abi_type.encode(value).unwrap()).unwrap(),
+                *value
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_struct() {
+        let abi_type = ABIType::Struct(ABIStruct {
+            name: "Outer".to_string(),
+            fields: vec![
+                StructField {
+                    name: "id".to_string(),
+                    field_type: StructFieldType::Type(ABIType::from_str("uint64").unwrap()),
+                },
+                StructField {
+                    name: "inner".to_string(),
+                    field_type: StructFieldType::Fields(vec![StructField {
+                        name: "names".to_string(),
+                        field_type: StructFieldType::Type(ABIType::from_str("string[]").unwrap()),
+                    }]),
+                },
+            ],
+        });
+
+        for value in generate_abi_values(&abi_type, &bounds(), 20, [3u8; 32]) {
+            assert_eq!(
+                abi_type.decode(&abi_type.encode(&value).unwrap()).unwrap(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_dynamic_bounds_are_respected() {
+        let abi_type = ABIType::from_str("uint8[]").unwrap();
+        let bounds = ABIValueBounds {
+            max_array_length: 2,
+            ..ABIValueBounds::default()
+        };
+
+        for value in generate_abi_values(&abi_type, &bounds, 50, [1u8; 32]) {
+            match value {
+                ABIValue::Array(items) => assert!(items.len() <= 2),
+                _ => panic!("expected an array"),
+            }
+        }
+    }
+}
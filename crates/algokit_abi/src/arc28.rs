@@ -0,0 +1,218 @@
+//! Decoding of [ARC-28](https://arc.algorand.foundation/ARCs/arc-0028) events from app logs.
+use crate::abi_type::ABIType;
+use crate::abi_value::ABIValue;
+use crate::arc56_contract::{Arc56Contract, Event};
+use crate::error::ABIError;
+use sha2::{Digest, Sha512_256};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The length of the selector prefixing an ARC-28 event log.
+pub const EVENT_SELECTOR_LENGTH: usize = 4;
+
+/// An argument of a [`DecodedEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEventArg {
+    pub name: Option<String>,
+    pub value: ABIValue,
+}
+
+/// An ARC-28 event decoded from an app log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+    pub name: String,
+    /// The event signature, e.g. `Swapped(uint64,uint64)`
+    pub signature: String,
+    pub args: Vec<DecodedEventArg>,
+}
+
+impl DecodedEvent {
+    /// Returns the value of the argument with the given name.
+    pub fn arg(&self, name: &str) -> Option<&ABIValue> {
+        self.args
+            .iter()
+            .find(|arg| arg.name.as_deref() == Some(name))
+            .map(|arg| &arg.value)
+    }
+}
+
+impl Event {
+    /// Returns the event signature, e.g. `Swapped(uint64,uint64)`.
+    pub fn signature(&self) -> String {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.arg_type.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{}({})", self.name, args)
+    }
+
+    /// Returns the selector prefixing the logs of this event.
+    pub fn selector(&self) -> [u8; EVENT_SELECTOR_LENGTH] {
+        let hash = Sha512_256::digest(self.signature().as_bytes());
+        let mut selector = [0u8; EVENT_SELECTOR_LENGTH];
+        selector.copy_from_slice(&hash[..EVENT_SELECTOR_LENGTH]);
+        selector
+    }
+}
+
+impl Arc56Contract {
+    /// Returns the events of the app and its methods, deduplicated by signature.
+    pub fn all_events(&self) -> Vec<&Event> {
+        let method_events = self
+            .methods
+            .iter()
+            .filter_map(|method| method.events.as_ref())
+            .flatten();
+        let mut signatures = Vec::new();
+        self.events
+            .iter()
+            .flatten()
+            .chain(method_events)
+            .filter(|event| {
+                let signature = event.signature();
+                let is_new = !signatures.contains(&signature);
+                signatures.push(signature);
+                is_new
+            })
+            .collect()
+    }
+
+    /// Decodes a log into an ARC-28 event of this app.
+    ///
+    /// Returns `None` if the log doesn't start with the selector of a known event.
+    pub fn decode_event(&self, log: &[u8]) -> Result<Option<DecodedEvent>, ABIError> {
+        let Some(selector) = log.get(..EVENT_SELECTOR_LENGTH) else {
+            return Ok(None);
+        };
+        let Some(event) = self
+            .all_events()
+            .into_iter()
+            .find(|event| event.selector() == selector)
+        else {
+            return Ok(None);
+        };
+
+        self.decode_event_args(event, &log[EVENT_SELECTOR_LENGTH..])
+            .map(Some)
+    }
+
+    /// Decodes all logs that are ARC-28 events of this app, skipping any other logs.
+    pub fn decode_events(&self, logs: &[Vec<u8>]) -> Result<Vec<DecodedEvent>, ABIError> {
+        let events = self
+            .all_events()
+            .into_iter()
+            .map(|event| (event.selector(), event))
+            .collect::<HashMap<_, _>>();
+
+        logs.iter()
+            .filter_map(|log| {
+                let selector: [u8; EVENT_SELECTOR_LENGTH] =
+                    log.get(..EVENT_SELECTOR_LENGTH)?.try_into().ok()?;
+                let event = events.get(&selector)?;
+                Some(self.decode_event_args(event, &log[EVENT_SELECTOR_LENGTH..]))
+            })
+            .collect()
+    }
+
+    fn decode_event_args(&self, event: &Event, data: &[u8]) -> Result<DecodedEvent, ABIError> {
+        let arg_types = event
+            .args
+            .iter()
+            .map(|arg| match &arg.struct_name {
+                Some(struct_name) => ABIType::from_struct(struct_name, &self.structs),
+                None => ABIType::from_str(&arg.arg_type),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let values = match ABIType::Tuple(arg_types).decode(data) {
+            Ok(ABIValue::Array(values)) => values,
+            result => {
+                return Err(ABIError::DecodingError {
+                    message: format!(
+                        "Failed to decode event {}: {}",
+                        event.signature(),
+                        result.err().map(|e| e.to_string()).unwrap_or_default()
+                    ),
+                });
+            }
+        };
+
+        Ok(DecodedEvent {
+            name: event.name.clone(),
+            signature: event.signature(),
+            args: event
+                .args
+                .iter()
+                .zip(values)
+                .map(|(arg, value)| DecodedEventArg {
+                    name: arg.name.clone(),
+                    value,
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arc56_contract::EventArg;
+    use num_bigint::BigUint;
+
+    fn swapped_event() -> Event {
+        Event {
+            name: "Swapped".to_string(),
+            desc: None,
+            args: ["a", "b"]
+                .into_iter()
+                .map(|name| EventArg {
+                    arg_type: "uint64".to_string(),
+                    desc: None,
+                    name: Some(name.to_string()),
+                    struct_name: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_event_signature_and_selector() {
+        let event = swapped_event();
+
+        assert_eq!(event.signature(), "Swapped(uint64,uint64)");
+        assert_eq!(event.selector(), [0x1c, 0xcb, 0xd9, 0x25]);
+    }
+
+    #[test]
+    fn test_decode_event_args() {
+        let event = swapped_event();
+        let args = ABIType::from_str("(uint64,uint64)")
+            .unwrap()
+            .encode(&ABIValue::Array(vec![
+                ABIValue::from(1u64),
+                ABIValue::from(2u64),
+            ]))
+            .unwrap();
+        let log = [event.selector().to_vec(), args].concat();
+        let contract = Arc56Contract {
+            events: Some(vec![event]),
+            ..serde_json::from_str(
+                r#"{"arcs":[],"bareActions":{"call":[],"create":[]},"methods":[],"name":"Test","state":{"keys":{"global":{},"local":{},"box":{}},"maps":{"global":{},"local":{},"box":{}},"schema":{"global":{"ints":0,"bytes":0},"local":{"ints":0,"bytes":0}}},"structs":{}}"#,
+            )
+            .unwrap()
+        };
+
+        let events = contract
+            .decode_events(&[b"not an event".to_vec(), log])
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "Swapped");
+        assert_eq!(
+            events[0].arg("b"),
+            Some(&ABIValue::Uint(BigUint::from(2u64)))
+        );
+    }
+}
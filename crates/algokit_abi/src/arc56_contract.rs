@@ -1,10 +1,14 @@
-use crate::abi_method::{ABIDefaultValue, ABIMethod, ABIMethodArg, ABIMethodArgType};
+use crate::abi_method::{
+    ABIDefaultValue, ABIMethod, ABIMethodArg, ABIMethodArgType, MethodSelectorCollision,
+    find_selector_collisions,
+};
 use crate::abi_type::ABIType;
 use crate::constants::VOID_RETURN_TYPE;
 use crate::error::ABIError;
 use base64::{Engine as _, engine::general_purpose};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::str::FromStr;
 
 /// An ABI-encoded type string
@@ -538,58 +542,144 @@ impl Arc56Contract {
 
     /// Get a method by name or signature
     pub fn get_method(&self, method_name_or_signature: &str) -> Result<&Method, ABIError> {
-        if !method_name_or_signature.contains('(') {
-            // Filter by method name
+        self.find_method(method_name_or_signature)
+    }
+
+    /// Find a method by plain name, full signature or signature without the return type.
+    ///
+    /// A plain name (e.g. `add`) only resolves when there is a single method with that name,
+    /// otherwise the error lists the candidate signatures so the caller can pick one.
+    /// A signature without the return type (e.g. `add(uint64,uint64)`) resolves the same way
+    /// against the method's arguments, and a full signature (e.g. `add(uint64,uint64)uint64`)
+    /// must match exactly.
+    pub fn find_method(&self, method_name_or_signature: &str) -> Result<&Method, ABIError> {
+        let Some(args_start) = method_name_or_signature.find('(') else {
             let methods: Vec<&Method> = self
                 .methods
                 .iter()
                 .filter(|m| m.name == method_name_or_signature)
                 .collect();
-
-            if methods.is_empty() {
-                return Err(ABIError::ValidationError {
-                    message: format!(
-                        "Unable to find method {} in {} app",
-                        method_name_or_signature, self.name
-                    ),
-                });
-            }
-
-            if methods.len() > 1 {
-                let signatures: Result<Vec<String>, ABIError> =
-                    methods.iter().map(|m| m.signature()).collect();
-                let signatures = signatures?;
-                return Err(ABIError::ValidationError {
+            return match methods.as_slice() {
+                [method] => Ok(method),
+                [] => Err(self.method_not_found_error(method_name_or_signature, None)),
+                _ => Err(ABIError::ValidationError {
                     message: format!(
                         "Received a call to method {} in contract {}, but this resolved to multiple methods; \
                      please pass in an ABI signature instead: {}",
                         method_name_or_signature,
                         self.name,
-                        signatures.join(", ")
+                        Self::method_signatures(&methods)?.join(", ")
                     ),
-                });
-            }
+                }),
+            };
+        };
 
-            Ok(methods[0])
-        } else {
-            // Find by signature
-            self.methods
+        let name = &method_name_or_signature[..args_start];
+        let named_methods: Vec<&Method> = self.methods.iter().filter(|m| m.name == name).collect();
+
+        if let Some(method) = named_methods.iter().find(|m| {
+            m.signature()
+                .is_ok_and(|sig| sig == method_name_or_signature)
+        }) {
+            return Ok(method);
+        }
+
+        // Allow the return type to be omitted, as long as the arguments are unambiguous
+        if method_name_or_signature.ends_with(')') {
+            let methods: Vec<&Method> = named_methods
                 .iter()
-                .find(|m| {
-                    m.signature()
-                        .is_ok_and(|sig| sig == method_name_or_signature)
-                })
-                .ok_or_else(|| ABIError::ValidationError {
-                    message: format!(
-                        "Unable to find method {} in {} app",
-                        method_name_or_signature, self.name
-                    ),
+                .copied()
+                .filter(|m| {
+                    m.signature().is_ok_and(|sig| {
+                        sig == format!("{}{}", method_name_or_signature, m.returns.return_type)
+                    })
                 })
+                .collect();
+            match methods.as_slice() {
+                [method] => return Ok(method),
+                [] => {}
+                _ => {
+                    return Err(ABIError::ValidationError {
+                        message: format!(
+                            "Received a call to method {} in contract {}, but this resolved to multiple methods; \
+                         please pass in a full ABI signature instead: {}",
+                            method_name_or_signature,
+                            self.name,
+                            Self::method_signatures(&methods)?.join(", ")
+                        ),
+                    });
+                }
+            }
+        }
+
+        Err(self.method_not_found_error(method_name_or_signature, Some(&named_methods)))
+    }
+
+    /// Find a method by its 4-byte ARC-4 selector.
+    pub fn find_method_by_selector(&self, selector: &[u8]) -> Result<&Method, ABIError> {
+        for method in &self.methods {
+            if self.to_abi_method(method)?.selector()? == selector {
+                return Ok(method);
+            }
+        }
+        Err(ABIError::ValidationError {
+            message: format!(
+                "Unable to find method with selector 0x{} in {} app",
+                selector.iter().fold(String::new(), |mut hex, b| {
+                    let _ = write!(hex, "{:02x}", b);
+                    hex
+                }),
+                self.name
+            ),
+        })
+    }
+
+    /// Find the methods whose signatures hash to the same selector, which breaks routing calls
+    /// to them. The indexes of the collisions are into [`Arc56Contract::methods`].
+    pub fn method_selector_collisions(&self) -> Vec<MethodSelectorCollision> {
+        let (indexes, abi_methods): (Vec<_>, Vec<_>) = self
+            .methods
+            .iter()
+            .enumerate()
+            .filter_map(|(index, method)| Some((index, self.to_abi_method(method).ok()?)))
+            .unzip();
+
+        find_selector_collisions(&abi_methods)
+            .into_iter()
+            .map(|collision| MethodSelectorCollision {
+                first: (indexes[collision.first.0], collision.first.1),
+                second: (indexes[collision.second.0], collision.second.1),
+                ..collision
+            })
+            .collect()
+    }
+
+    fn method_signatures(methods: &[&Method]) -> Result<Vec<String>, ABIError> {
+        methods.iter().map(|m| m.signature()).collect()
+    }
+
+    fn method_not_found_error(
+        &self,
+        method_name_or_signature: &str,
+        candidates: Option<&[&Method]>,
+    ) -> ABIError {
+        let mut message = format!(
+            "Unable to find method {} in {} app",
+            method_name_or_signature, self.name
+        );
+        if let Some(candidates) = candidates.filter(|c| !c.is_empty()) {
+            if let Ok(signatures) = Self::method_signatures(candidates) {
+                message.push_str(&format!(
+                    "; methods with the same name: {}",
+                    signatures.join(", ")
+                ));
+            }
         }
+        ABIError::ValidationError { message }
     }
 
     /// Build an ABIMethod from an ARC-56 Method
-    fn to_abi_method(&self, method: &Method) -> Result<ABIMethod, ABIError> {
+    pub(crate) fn to_abi_method(&self, method: &Method) -> Result<ABIMethod, ABIError> {
         // Resolve argument types
         let args: Result<Vec<ABIMethodArg>, ABIError> = method
             .args
@@ -800,7 +890,7 @@ impl Arc56Contract {
             .collect()
     }
 
-    fn resolve_storage_type(&self, type_str: &str) -> Result<ABIType, ABIError> {
+    pub(crate) fn resolve_storage_type(&self, type_str: &str) -> Result<ABIType, ABIError> {
         if self.structs.contains_key(type_str) {
             ABIType::from_struct(type_str, &self.structs)
         } else {
@@ -0,0 +1,382 @@
+use crate::arc56_contract::{
+    Arc56Contract, Method, StateSchema, StorageKey, StorageMap, StructField, StructFieldType,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+/// Whether a part of the spec was added, removed or changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A single difference between two ARC-56 app specs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecChange {
+    pub kind: SpecChangeKind,
+    /// The location of the change within the spec, e.g. `methods.add(uint64,uint64)uint64`.
+    pub path: String,
+    /// A description of the change.
+    pub message: String,
+    /// Whether existing clients of the app may break, or the app can't be updated in place.
+    pub breaking: bool,
+}
+
+impl Display for SpecChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let breaking = if self.breaking {
+            "breaking"
+        } else {
+            "non-breaking"
+        };
+        write!(f, "{} change at {}: {}", breaking, self.path, self.message)
+    }
+}
+
+/// The differences between two ARC-56 app specs, see [`Arc56Contract::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecDiff {
+    /// All of the changes, grouped by methods, bare actions, state and structs.
+    pub changes: Vec<SpecChange>,
+}
+
+impl SpecDiff {
+    /// Returns `true` if the specs are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if any of the changes is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.breaking_changes().next().is_some()
+    }
+
+    /// Returns the changes that may break existing clients of the app.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SpecChange> {
+        self.changes.iter().filter(|c| c.breaking)
+    }
+
+    /// Returns the changes that are backwards compatible.
+    pub fn non_breaking_changes(&self) -> impl Iterator<Item = &SpecChange> {
+        self.changes.iter().filter(|c| !c.breaking)
+    }
+
+    fn push(
+        &mut self,
+        kind: SpecChangeKind,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        breaking: bool,
+    ) {
+        self.changes.push(SpecChange {
+            kind,
+            path: path.into(),
+            message: message.into(),
+            breaking,
+        });
+    }
+}
+
+impl Arc56Contract {
+    /// Compares this app spec with a newer version of it.
+    ///
+    /// Removing or changing methods, bare actions, state keys and struct layouts is breaking, as
+    /// is growing the state schema, which can't be done by updating the app. Additions and
+    /// schema reductions are non-breaking.
+    pub fn diff(&self, other: &Arc56Contract) -> SpecDiff {
+        let mut diff = SpecDiff::default();
+
+        diff_methods(&self.methods, &other.methods, &mut diff);
+        diff_actions(
+            "bareActions.call",
+            &self.bare_actions.call,
+            &other.bare_actions.call,
+            &mut diff,
+        );
+        diff_actions(
+            "bareActions.create",
+            &self.bare_actions.create,
+            &other.bare_actions.create,
+            &mut diff,
+        );
+
+        let (schema, other_schema) = (&self.state.schema, &other.state.schema);
+        diff_schema(
+            "state.schema.global",
+            &schema.global_state,
+            &other_schema.global_state,
+            &mut diff,
+        );
+        diff_schema(
+            "state.schema.local",
+            &schema.local_state,
+            &other_schema.local_state,
+            &mut diff,
+        );
+
+        let (keys, other_keys) = (&self.state.keys, &other.state.keys);
+        diff_storage(
+            "state.keys.global",
+            &keys.global_state,
+            &other_keys.global_state,
+            storage_key_layout,
+            &mut diff,
+        );
+        diff_storage(
+            "state.keys.local",
+            &keys.local_state,
+            &other_keys.local_state,
+            storage_key_layout,
+            &mut diff,
+        );
+        diff_storage(
+            "state.keys.box",
+            &keys.box_keys,
+            &other_keys.box_keys,
+            storage_key_layout,
+            &mut diff,
+        );
+
+        let (maps, other_maps) = (&self.state.maps, &other.state.maps);
+        diff_storage(
+            "state.maps.global",
+            &maps.global_state,
+            &other_maps.global_state,
+            storage_map_layout,
+            &mut diff,
+        );
+        diff_storage(
+            "state.maps.local",
+            &maps.local_state,
+            &other_maps.local_state,
+            storage_map_layout,
+            &mut diff,
+        );
+        diff_storage(
+            "state.maps.box",
+            &maps.box_maps,
+            &other_maps.box_maps,
+            storage_map_layout,
+            &mut diff,
+        );
+
+        diff_storage(
+            "structs",
+            &self.structs,
+            &other.structs,
+            |fields| struct_layout(fields),
+            &mut diff,
+        );
+
+        diff
+    }
+}
+
+fn method_signature(method: &Method) -> String {
+    method.signature().unwrap_or_else(|_| method.name.clone())
+}
+
+fn diff_methods(methods: &[Method], other_methods: &[Method], diff: &mut SpecDiff) {
+    let by_signature = |methods: &[Method]| {
+        methods
+            .iter()
+            .map(|m| (method_signature(m), m.clone()))
+            .collect::<HashMap<_, _>>()
+    };
+    let (old, new) = (by_signature(methods), by_signature(other_methods));
+
+    for method in methods {
+        let signature = method_signature(method);
+        let path = format!("methods.{}", signature);
+        let Some(other) = new.get(&signature) else {
+            let replacements = other_methods
+                .iter()
+                .filter(|m| m.name == method.name && !old.contains_key(&method_signature(m)))
+                .map(method_signature)
+                .collect::<Vec<_>>();
+            if replacements.is_empty() {
+                diff.push(SpecChangeKind::Removed, path, "Method was removed", true);
+            } else {
+                diff.push(
+                    SpecChangeKind::Changed,
+                    path,
+                    format!("Method signature changed to {}", replacements.join(", ")),
+                    true,
+                );
+            }
+            continue;
+        };
+
+        diff_actions(
+            &format!("{}.actions.call", path),
+            &method.actions.call,
+            &other.actions.call,
+            diff,
+        );
+        diff_actions(
+            &format!("{}.actions.create", path),
+            &method.actions.create,
+            &other.actions.create,
+            diff,
+        );
+
+        for (index, (arg, other_arg)) in method.args.iter().zip(&other.args).enumerate() {
+            let arg_path = format!("{}.args[{}]", path, index);
+            if arg.struct_name != other_arg.struct_name {
+                diff.push(
+                    SpecChangeKind::Changed,
+                    &arg_path,
+                    format!(
+                        "Argument struct changed from {:?} to {:?}",
+                        arg.struct_name, other_arg.struct_name
+                    ),
+                    true,
+                );
+            }
+            match (&arg.default_value, &other_arg.default_value) {
+                (Some(_), None) => diff.push(
+                    SpecChangeKind::Removed,
+                    &arg_path,
+                    "Argument default value was removed",
+                    true,
+                ),
+                (None, Some(_)) => diff.push(
+                    SpecChangeKind::Added,
+                    &arg_path,
+                    "Argument default value was added",
+                    false,
+                ),
+                _ => {}
+            }
+        }
+
+        if method.returns.struct_name != other.returns.struct_name {
+            diff.push(
+                SpecChangeKind::Changed,
+                format!("{}.returns", path),
+                format!(
+                    "Return struct changed from {:?} to {:?}",
+                    method.returns.struct_name, other.returns.struct_name
+                ),
+                true,
+            );
+        }
+    }
+
+    for method in other_methods {
+        let signature = method_signature(method);
+        let renamed = methods
+            .iter()
+            .any(|m| m.name == method.name && !new.contains_key(&method_signature(m)));
+        if !old.contains_key(&signature) && !renamed {
+            diff.push(
+                SpecChangeKind::Added,
+                format!("methods.{}", signature),
+                "Method was added",
+                false,
+            );
+        }
+    }
+}
+
+fn diff_actions<T: PartialEq + Debug>(
+    path: &str,
+    actions: &[T],
+    other_actions: &[T],
+    diff: &mut SpecDiff,
+) {
+    for action in actions.iter().filter(|a| !other_actions.contains(a)) {
+        diff.push(
+            SpecChangeKind::Removed,
+            path,
+            format!("{:?} is no longer allowed", action),
+            true,
+        );
+    }
+    for action in other_actions.iter().filter(|a| !actions.contains(a)) {
+        diff.push(
+            SpecChangeKind::Added,
+            path,
+            format!("{:?} is now allowed", action),
+            false,
+        );
+    }
+}
+
+fn diff_schema(path: &str, schema: &StateSchema, other: &StateSchema, diff: &mut SpecDiff) {
+    for (name, value, other_value) in [
+        ("ints", schema.ints, other.ints),
+        ("bytes", schema.bytes, other.bytes),
+    ] {
+        if value != other_value {
+            diff.push(
+                SpecChangeKind::Changed,
+                format!("{}.{}", path, name),
+                format!("Schema {} changed from {} to {}", name, value, other_value),
+                other_value > value,
+            );
+        }
+    }
+}
+
+fn diff_storage<T>(
+    path: &str,
+    items: &HashMap<String, T>,
+    other_items: &HashMap<String, T>,
+    layout: impl Fn(&T) -> String,
+    diff: &mut SpecDiff,
+) {
+    let names = items
+        .keys()
+        .chain(other_items.keys())
+        .collect::<BTreeSet<_>>();
+
+    for name in names {
+        let item_path = format!("{}.{}", path, name);
+        match (items.get(name), other_items.get(name)) {
+            (Some(_), None) => diff.push(SpecChangeKind::Removed, item_path, "Was removed", true),
+            (None, Some(_)) => diff.push(SpecChangeKind::Added, item_path, "Was added", false),
+            (Some(item), Some(other_item)) => {
+                let (layout, other_layout) = (layout(item), layout(other_item));
+                if layout != other_layout {
+                    diff.push(
+                        SpecChangeKind::Changed,
+                        item_path,
+                        format!("Changed from {} to {}", layout, other_layout),
+                        true,
+                    );
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn storage_key_layout(key: &StorageKey) -> String {
+    format!(
+        "key {} of type {} with value type {}",
+        key.key, key.key_type, key.value_type
+    )
+}
+
+fn storage_map_layout(map: &StorageMap) -> String {
+    format!(
+        "map with prefix {} of key type {} and value type {}",
+        map.prefix.as_deref().unwrap_or_default(),
+        map.key_type,
+        map.value_type
+    )
+}
+
+fn struct_layout(fields: &[StructField]) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| match &field.field_type {
+            StructFieldType::Value(value_type) => format!("{}: {}", field.name, value_type),
+            StructFieldType::Nested(nested) => format!("{}: {}", field.name, struct_layout(nested)),
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(", "))
+}
@@ -0,0 +1,594 @@
+use crate::abi_method::ABIMethodArgType;
+use crate::abi_type::ABIType;
+use crate::arc56_contract::{
+    Arc56Contract, DefaultValueSource, Event, MethodArg, StorageKey, StorageMap, StructField,
+    StructFieldType,
+};
+use crate::constants::{HASH_BYTES_LENGTH, VOID_RETURN_TYPE};
+use base64::{Engine as _, engine::general_purpose};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The severity of an ARC-56 validation diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arc56DiagnosticSeverity {
+    /// The spec is usable, but something looks suspicious or inconsistent.
+    Warning,
+    /// The spec is invalid and calls relying on the affected part will fail.
+    Error,
+}
+
+/// A single issue found while validating an ARC-56 app spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arc56Diagnostic {
+    /// How severe the issue is.
+    pub severity: Arc56DiagnosticSeverity,
+    /// The location of the issue within the spec, e.g. `methods[2].args[0]`.
+    pub path: String,
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl Display for Arc56Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let severity = match self.severity {
+            Arc56DiagnosticSeverity::Warning => "warning",
+            Arc56DiagnosticSeverity::Error => "error",
+        };
+        write!(f, "{} at {}: {}", severity, self.path, self.message)
+    }
+}
+
+/// The outcome of validating an ARC-56 app spec.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Arc56ValidationReport {
+    /// All of the issues found, in the order they were detected.
+    pub diagnostics: Vec<Arc56Diagnostic>,
+}
+
+impl Arc56ValidationReport {
+    /// Returns `true` if no errors were found. Warnings do not make a spec invalid.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Returns the diagnostics with [`Arc56DiagnosticSeverity::Error`] severity.
+    pub fn errors(&self) -> impl Iterator<Item = &Arc56Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == Arc56DiagnosticSeverity::Error)
+    }
+
+    /// Returns the diagnostics with [`Arc56DiagnosticSeverity::Warning`] severity.
+    pub fn warnings(&self) -> impl Iterator<Item = &Arc56Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == Arc56DiagnosticSeverity::Warning)
+    }
+
+    fn error(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.diagnostics.push(Arc56Diagnostic {
+            severity: Arc56DiagnosticSeverity::Error,
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+
+    fn warning(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.diagnostics.push(Arc56Diagnostic {
+            severity: Arc56DiagnosticSeverity::Warning,
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+}
+
+impl Arc56Contract {
+    /// Validates the app spec up front, cross-checking methods, structs, state, networks and events.
+    ///
+    /// Rather than failing on the first problem, every issue found is collected into the returned
+    /// report so tooling can surface them all at once instead of failing lazily at call time.
+    pub fn validate(&self) -> Arc56ValidationReport {
+        let mut report = Arc56ValidationReport::default();
+
+        // Struct validation runs first, as the remaining checks can't resolve a cyclic struct
+        let valid_structs = self.validate_structs(&mut report);
+        self.validate_methods(&valid_structs, &mut report);
+        self.validate_state(&mut report);
+        self.validate_networks(&mut report);
+
+        if let Some(events) = &self.events {
+            for (index, event) in events.iter().enumerate() {
+                self.validate_event(
+                    event,
+                    &format!("events[{}]", index),
+                    &valid_structs,
+                    &mut report,
+                );
+            }
+        }
+
+        report
+    }
+
+    fn validate_structs(&self, report: &mut Arc56ValidationReport) -> HashSet<String> {
+        let mut valid_structs = HashSet::new();
+
+        let mut struct_names = self.structs.keys().collect::<Vec<_>>();
+        struct_names.sort();
+
+        for struct_name in struct_names {
+            let path = format!("structs.{}", struct_name);
+            if let Some(cycle) = self.find_struct_cycle(struct_name, &mut Vec::new()) {
+                report.error(
+                    &path,
+                    format!("Struct is recursive: {}", cycle.join(" -> ")),
+                );
+                continue;
+            }
+
+            let fields = &self.structs[struct_name];
+            if fields.is_empty() {
+                report.warning(&path, "Struct has no fields");
+            }
+
+            if self.validate_struct_fields(fields, &path, report) {
+                valid_structs.insert(struct_name.clone());
+            }
+        }
+
+        valid_structs
+    }
+
+    fn find_struct_cycle(
+        &self,
+        struct_name: &str,
+        visiting: &mut Vec<String>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = visiting.iter().position(|name| name == struct_name) {
+            let mut cycle = visiting[start..].to_vec();
+            cycle.push(struct_name.to_string());
+            return Some(cycle);
+        }
+
+        let fields = self.structs.get(struct_name)?;
+        visiting.push(struct_name.to_string());
+        let cycle = self.find_fields_cycle(fields, visiting);
+        visiting.pop();
+        cycle
+    }
+
+    fn find_fields_cycle(
+        &self,
+        fields: &[StructField],
+        visiting: &mut Vec<String>,
+    ) -> Option<Vec<String>> {
+        fields.iter().find_map(|field| match &field.field_type {
+            StructFieldType::Value(type_str) if self.structs.contains_key(type_str) => {
+                self.find_struct_cycle(type_str, visiting)
+            }
+            StructFieldType::Value(_) => None,
+            StructFieldType::Nested(nested_fields) => {
+                self.find_fields_cycle(nested_fields, visiting)
+            }
+        })
+    }
+
+    fn validate_struct_fields(
+        &self,
+        fields: &[StructField],
+        path: &str,
+        report: &mut Arc56ValidationReport,
+    ) -> bool {
+        let mut valid = true;
+        let mut field_names = HashSet::new();
+
+        for field in fields {
+            let field_path = format!("{}.{}", path, field.name);
+            if !field_names.insert(field.name.as_str()) {
+                report.error(&field_path, "Duplicate struct field name");
+                valid = false;
+            }
+
+            match &field.field_type {
+                StructFieldType::Value(type_str) => {
+                    if !self.structs.contains_key(type_str) {
+                        if let Err(e) = ABIType::from_str(type_str) {
+                            report.error(
+                                &field_path,
+                                format!("Invalid field type '{}': {}", type_str, e),
+                            );
+                            valid = false;
+                        }
+                    }
+                }
+                StructFieldType::Nested(nested_fields) => {
+                    valid &= self.validate_struct_fields(nested_fields, &field_path, report);
+                }
+            }
+        }
+
+        valid
+    }
+
+    fn validate_methods(
+        &self,
+        valid_structs: &HashSet<String>,
+        report: &mut Arc56ValidationReport,
+    ) {
+        let mut seen_signatures = HashMap::new();
+
+        for (index, method) in self.methods.iter().enumerate() {
+            let path = format!("methods[{}]", index);
+
+            if method.actions.call.is_empty() && method.actions.create.is_empty() {
+                report.warning(
+                    &path,
+                    format!("Method {} has no call or create actions", method.name),
+                );
+            }
+
+            let mut struct_refs_valid = true;
+            for (arg_index, arg) in method.args.iter().enumerate() {
+                struct_refs_valid &= self.validate_method_arg(
+                    arg,
+                    &format!("{}.args[{}]", path, arg_index),
+                    valid_structs,
+                    report,
+                );
+            }
+
+            if let Some(struct_name) = &method.returns.struct_name {
+                struct_refs_valid &= self.validate_struct_reference(
+                    struct_name,
+                    &method.returns.return_type,
+                    &format!("{}.returns", path),
+                    valid_structs,
+                    report,
+                );
+            }
+
+            if let Some(events) = &method.events {
+                for (event_index, event) in events.iter().enumerate() {
+                    let event_path = format!("{}.events[{}]", path, event_index);
+                    self.validate_event(event, &event_path, valid_structs, report);
+                    let declared = self.events.as_ref().is_some_and(|events| {
+                        events
+                            .iter()
+                            .any(|e| e.name == event.name && e.args.len() == event.args.len())
+                    });
+                    if !declared {
+                        report.warning(
+                            &event_path,
+                            format!(
+                                "Event {} is not declared in the contract level events",
+                                event.name
+                            ),
+                        );
+                    }
+                }
+            }
+
+            if !struct_refs_valid {
+                continue;
+            }
+
+            let abi_method = match self.to_abi_method(method) {
+                Ok(abi_method) => abi_method,
+                Err(e) => {
+                    report.error(&path, format!("Invalid method {}: {}", method.name, e));
+                    continue;
+                }
+            };
+
+            match abi_method.signature() {
+                Ok(signature) => {
+                    if let Some(first_path) =
+                        seen_signatures.insert(signature.clone(), path.clone())
+                    {
+                        report.error(
+                            &path,
+                            format!(
+                                "Method signature {} is also defined at {}",
+                                signature, first_path
+                            ),
+                        );
+                    }
+                }
+                Err(e) => report.error(&path, format!("Invalid method signature: {}", e)),
+            }
+        }
+
+        for collision in self.method_selector_collisions() {
+            report.error(
+                format!("methods[{}]", collision.second.0),
+                format!(
+                    "{}, also defined at methods[{}]",
+                    collision, collision.first.0
+                ),
+            );
+        }
+    }
+
+    fn validate_method_arg(
+        &self,
+        arg: &MethodArg,
+        path: &str,
+        valid_structs: &HashSet<String>,
+        report: &mut Arc56ValidationReport,
+    ) -> bool {
+        if let Some(struct_name) = &arg.struct_name {
+            if !self.validate_struct_reference(
+                struct_name,
+                &arg.arg_type,
+                path,
+                valid_structs,
+                report,
+            ) {
+                return false;
+            }
+        }
+
+        let arg_type = match ABIMethodArgType::from_str(&arg.arg_type) {
+            Ok(arg_type) => arg_type,
+            Err(e) => {
+                report.error(
+                    path,
+                    format!("Invalid argument type '{}': {}", arg.arg_type, e),
+                );
+                return true;
+            }
+        };
+
+        if let Some(default_value) = &arg.default_value {
+            let default_path = format!("{}.defaultValue", path);
+            if let ABIMethodArgType::Transaction(_) = arg_type {
+                report.error(
+                    &default_path,
+                    "Transaction arguments cannot have a default value",
+                );
+            }
+
+            match default_value.source {
+                DefaultValueSource::Method => {
+                    let resolves = self.methods.iter().any(|m| {
+                        m.name == default_value.data
+                            || m.signature().is_ok_and(|sig| sig == default_value.data)
+                    });
+                    if !resolves {
+                        report.error(
+                            &default_path,
+                            format!(
+                                "Default value method {} does not exist in the contract",
+                                default_value.data
+                            ),
+                        );
+                    }
+                }
+                DefaultValueSource::Global
+                | DefaultValueSource::Local
+                | DefaultValueSource::Box => {
+                    if general_purpose::STANDARD
+                        .decode(&default_value.data)
+                        .is_err()
+                    {
+                        report.error(
+                            &default_path,
+                            format!(
+                                "Default value key '{}' is not valid base64",
+                                default_value.data
+                            ),
+                        );
+                    }
+                }
+                DefaultValueSource::Literal => {}
+            }
+
+            if let Some(value_type) = &default_value.value_type {
+                if !self.structs.contains_key(value_type) {
+                    if let Err(e) = ABIType::from_str(value_type) {
+                        report.error(
+                            &default_path,
+                            format!("Invalid default value type '{}': {}", value_type, e),
+                        );
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn validate_struct_reference(
+        &self,
+        struct_name: &str,
+        declared_type: &str,
+        path: &str,
+        valid_structs: &HashSet<String>,
+        report: &mut Arc56ValidationReport,
+    ) -> bool {
+        if !self.structs.contains_key(struct_name) {
+            report.error(path, format!("Struct {} is not defined", struct_name));
+            return false;
+        }
+        if !valid_structs.contains(struct_name) {
+            // The struct definition itself has already been reported
+            return false;
+        }
+
+        if let Ok(struct_type) = ABIType::from_struct(struct_name, &self.structs) {
+            let struct_signature = struct_type.to_string();
+            if declared_type != VOID_RETURN_TYPE && struct_signature != declared_type {
+                report.error(
+                    path,
+                    format!(
+                        "Type '{}' does not match the definition of struct {} ({})",
+                        declared_type, struct_name, struct_signature
+                    ),
+                );
+            }
+        }
+
+        true
+    }
+
+    fn validate_state(&self, report: &mut Arc56ValidationReport) {
+        let keys = [
+            ("state.keys.global", &self.state.keys.global_state),
+            ("state.keys.local", &self.state.keys.local_state),
+            ("state.keys.box", &self.state.keys.box_keys),
+        ];
+        for (prefix, storage_keys) in keys {
+            for (name, storage_key) in sorted(storage_keys) {
+                self.validate_storage_key(storage_key, &format!("{}.{}", prefix, name), report);
+            }
+        }
+
+        let maps = [
+            ("state.maps.global", &self.state.maps.global_state),
+            ("state.maps.local", &self.state.maps.local_state),
+            ("state.maps.box", &self.state.maps.box_maps),
+        ];
+        for (prefix, storage_maps) in maps {
+            for (name, storage_map) in sorted(storage_maps) {
+                self.validate_storage_map(storage_map, &format!("{}.{}", prefix, name), report);
+            }
+        }
+
+        let schema = &self.state.schema;
+        let schema_checks = [
+            (
+                "state.schema.global",
+                self.state.keys.global_state.len(),
+                schema.global_state.ints + schema.global_state.bytes,
+            ),
+            (
+                "state.schema.local",
+                self.state.keys.local_state.len(),
+                schema.local_state.ints + schema.local_state.bytes,
+            ),
+        ];
+        for (path, key_count, allocated) in schema_checks {
+            if key_count > allocated as usize {
+                report.warning(
+                    path,
+                    format!(
+                        "{} state keys are declared but the schema only allocates {}",
+                        key_count, allocated
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_storage_key(
+        &self,
+        storage_key: &StorageKey,
+        path: &str,
+        report: &mut Arc56ValidationReport,
+    ) {
+        if general_purpose::STANDARD.decode(&storage_key.key).is_err() {
+            report.error(
+                path,
+                format!("Key '{}' is not valid base64", storage_key.key),
+            );
+        }
+        self.validate_storage_type(&storage_key.key_type, &format!("{}.keyType", path), report);
+        self.validate_storage_type(
+            &storage_key.value_type,
+            &format!("{}.valueType", path),
+            report,
+        );
+    }
+
+    fn validate_storage_map(
+        &self,
+        storage_map: &StorageMap,
+        path: &str,
+        report: &mut Arc56ValidationReport,
+    ) {
+        if let Some(prefix) = &storage_map.prefix {
+            if general_purpose::STANDARD.decode(prefix).is_err() {
+                report.error(path, format!("Prefix '{}' is not valid base64", prefix));
+            }
+        }
+        self.validate_storage_type(&storage_map.key_type, &format!("{}.keyType", path), report);
+        self.validate_storage_type(
+            &storage_map.value_type,
+            &format!("{}.valueType", path),
+            report,
+        );
+    }
+
+    fn validate_storage_type(
+        &self,
+        type_str: &str,
+        path: &str,
+        report: &mut Arc56ValidationReport,
+    ) {
+        // Struct definitions are validated separately
+        if self.structs.contains_key(type_str) {
+            return;
+        }
+        if let Err(e) = self.resolve_storage_type(type_str) {
+            report.error(path, e.to_string());
+        }
+    }
+
+    fn validate_networks(&self, report: &mut Arc56ValidationReport) {
+        let Some(networks) = &self.networks else {
+            return;
+        };
+
+        for (genesis_hash, network) in sorted(networks) {
+            let path = format!("networks.{}", genesis_hash);
+            if network.app_id == 0 {
+                report.error(&path, "App ID must not be 0");
+            }
+
+            let is_genesis_hash = general_purpose::STANDARD
+                .decode(genesis_hash)
+                .is_ok_and(|bytes| bytes.len() == HASH_BYTES_LENGTH);
+            if !is_genesis_hash {
+                report.warning(&path, "Network key is not a base64 encoded genesis hash");
+            }
+        }
+    }
+
+    fn validate_event(
+        &self,
+        event: &Event,
+        path: &str,
+        valid_structs: &HashSet<String>,
+        report: &mut Arc56ValidationReport,
+    ) {
+        if event.name.is_empty() {
+            report.error(path, "Event name cannot be empty");
+        }
+
+        for (index, arg) in event.args.iter().enumerate() {
+            let arg_path = format!("{}.args[{}]", path, index);
+            if let Some(struct_name) = &arg.struct_name {
+                self.validate_struct_reference(
+                    struct_name,
+                    &arg.arg_type,
+                    &arg_path,
+                    valid_structs,
+                    report,
+                );
+            } else if let Err(e) = ABIType::from_str(&arg.arg_type) {
+                report.error(
+                    &arg_path,
+                    format!("Invalid event argument type '{}': {}", arg.arg_type, e),
+                );
+            }
+        }
+    }
+}
+
+fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
@@ -0,0 +1,239 @@
+//! Conversion between Rust types and [`ABIValue`]s.
+//!
+//! [`AbiEncode`] and [`AbiDecode`] are implemented for the Rust primitives that have a natural
+//! ARC-4 counterpart and can be derived for structs and unit enums with the `derive` feature:
+//!
+//! ```ignore
+//! use algokit_abi::{AbiDecode, AbiEncode};
+//!
+//! #[derive(AbiEncode, AbiDecode)]
+//! struct Order {
+//!     id: u64,
+//!     note: String,
+//!     filled: bool,
+//! }
+//!
+//! // Encoded as the ARC-4 tuple (uint64,string,bool)
+//! let encoded = Order { id: 1, note: "test".to_string(), filled: false }.abi_encode()?;
+//! let decoded = Order::abi_decode(&encoded)?;
+//! ```
+//!
+//! Structs are encoded as tuples of their fields in declaration order and unit enums as the
+//! `uint8` index of their variant.
+use crate::{ABIError, ABIType, ABIValue, abi_type::BitSize};
+use num_bigint::BigUint;
+
+/// A Rust type that can be encoded as an ARC-4 value.
+pub trait AbiEncode {
+    /// The ABI type values of this type are encoded as.
+    fn abi_type() -> ABIType;
+
+    /// Convert this value to an [`ABIValue`] of [`AbiEncode::abi_type`].
+    fn to_abi_value(&self) -> ABIValue;
+
+    /// Encode this value to its ARC-4 byte representation.
+    fn abi_encode(&self) -> Result<Vec<u8>, ABIError> {
+        Self::abi_type().encode(&self.to_abi_value())
+    }
+}
+
+/// A Rust type that can be decoded from an ARC-4 value.
+pub trait AbiDecode: Sized {
+    /// Convert an [`ABIValue`] to this type.
+    ///
+    /// Struct values are accepted either as arrays (tuples) or as [`ABIValue::Struct`], e.g. as
+    /// returned when decoding ARC-56 structs.
+    fn from_abi_value(value: ABIValue) -> Result<Self, ABIError>;
+
+    /// Decode a value from its ARC-4 byte representation.
+    fn abi_decode(bytes: &[u8]) -> Result<Self, ABIError>
+    where
+        Self: AbiEncode,
+    {
+        Self::from_abi_value(Self::abi_type().decode(bytes)?)
+    }
+}
+
+/// Create the error returned when an [`ABIValue`] doesn't match the Rust type it's decoded to.
+#[doc(hidden)]
+pub fn unexpected_value(expected: &str, value: &ABIValue) -> ABIError {
+    ABIError::DecodingError {
+        message: format!("Expected {}, got {:?}", expected, value),
+    }
+}
+
+/// Take the element of a decoded tuple or struct value for the field at `index`.
+#[doc(hidden)]
+pub fn take_field(
+    value: &mut ABIValue,
+    index: usize,
+    name: Option<&str>,
+) -> Result<ABIValue, ABIError> {
+    match (value, name) {
+        (ABIValue::Array(values), _) => values
+            .get_mut(index)
+            .map(|value| std::mem::replace(value, ABIValue::Bool(false)))
+            .ok_or_else(|| ABIError::DecodingError {
+                message: format!("Missing tuple element at index {}", index),
+            }),
+        (ABIValue::Struct(fields), Some(name)) => {
+            fields.remove(name).ok_or_else(|| ABIError::DecodingError {
+                message: format!("Missing struct field {}", name),
+            })
+        }
+        (value, _) => Err(unexpected_value("a tuple", value)),
+    }
+}
+
+/// Check that a decoded tuple or struct value has the expected number of elements.
+#[doc(hidden)]
+pub fn check_field_count(value: &ABIValue, count: usize) -> Result<(), ABIError> {
+    let actual = match value {
+        ABIValue::Array(values) => values.len(),
+        ABIValue::Struct(fields) => fields.len(),
+        value => return Err(unexpected_value("a tuple", value)),
+    };
+    if actual != count {
+        return Err(ABIError::DecodingError {
+            message: format!("Expected a tuple of {} elements, got {}", count, actual),
+        });
+    }
+    Ok(())
+}
+
+macro_rules! impl_uint {
+    ($($ty:ty),*) => {
+        $(
+            impl AbiEncode for $ty {
+                fn abi_type() -> ABIType {
+                    ABIType::Uint(BitSize::new(<$ty>::BITS as u16).expect("valid bit size"))
+                }
+
+                fn to_abi_value(&self) -> ABIValue {
+                    ABIValue::Uint(BigUint::from(*self))
+                }
+            }
+
+            impl AbiDecode for $ty {
+                fn from_abi_value(value: ABIValue) -> Result<Self, ABIError> {
+                    match value {
+                        ABIValue::Uint(uint) => <$ty>::try_from(&uint).map_err(|_| {
+                            ABIError::DecodingError {
+                                message: format!(
+                                    "{} is out of range for {}",
+                                    uint,
+                                    stringify!($ty)
+                                ),
+                            }
+                        }),
+                        ABIValue::Byte(byte) => Ok(<$ty>::from(byte)),
+                        value => Err(unexpected_value("a uint", &value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_uint!(u8, u16, u32, u64, u128);
+
+impl AbiEncode for bool {
+    fn abi_type() -> ABIType {
+        ABIType::Bool
+    }
+
+    fn to_abi_value(&self) -> ABIValue {
+        ABIValue::Bool(*self)
+    }
+}
+
+impl AbiDecode for bool {
+    fn from_abi_value(value: ABIValue) -> Result<Self, ABIError> {
+        match value {
+            ABIValue::Bool(value) => Ok(value),
+            value => Err(unexpected_value("a bool", &value)),
+        }
+    }
+}
+
+impl AbiEncode for String {
+    fn abi_type() -> ABIType {
+        ABIType::String
+    }
+
+    fn to_abi_value(&self) -> ABIValue {
+        ABIValue::String(self.clone())
+    }
+}
+
+impl AbiDecode for String {
+    fn from_abi_value(value: ABIValue) -> Result<Self, ABIError> {
+        match value {
+            ABIValue::String(value) => Ok(value),
+            value => Err(unexpected_value("a string", &value)),
+        }
+    }
+}
+
+impl<T: AbiEncode> AbiEncode for Vec<T> {
+    fn abi_type() -> ABIType {
+        ABIType::DynamicArray(Box::new(T::abi_type()))
+    }
+
+    fn to_abi_value(&self) -> ABIValue {
+        ABIValue::Array(self.iter().map(AbiEncode::to_abi_value).collect())
+    }
+}
+
+impl<T: AbiDecode> AbiDecode for Vec<T> {
+    fn from_abi_value(value: ABIValue) -> Result<Self, ABIError> {
+        match value {
+            ABIValue::Array(values) => values.into_iter().map(T::from_abi_value).collect(),
+            value => Err(unexpected_value("an array", &value)),
+        }
+    }
+}
+
+impl<T: AbiEncode, const N: usize> AbiEncode for [T; N] {
+    fn abi_type() -> ABIType {
+        ABIType::StaticArray(Box::new(T::abi_type()), N)
+    }
+
+    fn to_abi_value(&self) -> ABIValue {
+        ABIValue::Array(self.iter().map(AbiEncode::to_abi_value).collect())
+    }
+}
+
+impl<T: AbiDecode, const N: usize> AbiDecode for [T; N] {
+    fn from_abi_value(value: ABIValue) -> Result<Self, ABIError> {
+        let values = Vec::<T>::from_abi_value(value)?;
+        let len = values.len();
+        values.try_into().map_err(|_| ABIError::DecodingError {
+            message: format!("Expected an array of {} elements, got {}", N, len),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_round_trip() {
+        let value: [Vec<u16>; 2] = [vec![1, 2], vec![]];
+        let encoded = value.abi_encode().unwrap();
+
+        assert_eq!(<[Vec<u16>; 2]>::abi_type().to_string(), "uint16[][2]");
+        assert_eq!(<[Vec<u16>; 2]>::abi_decode(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_uint_out_of_range() {
+        let result = u8::from_abi_value(ABIValue::Uint(BigUint::from(256u32)));
+
+        assert!(matches!(
+            result,
+            Err(ABIError::DecodingError { message }) if message.contains("out of range for u8")
+        ));
+    }
+}
@@ -29,3 +29,11 @@ pub static UFIXED_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 
 /// Constant for void return type in method signatures.
 pub const VOID_RETURN_TYPE: &str = "void";
+
+/// Number of method arguments (excluding the method selector) that are encoded individually
+/// before the remaining arguments are packed into a tuple, as defined in ARC-4.
+pub const ARGS_TUPLE_PACKING_THRESHOLD: usize = 14;
+
+/// Maximum number of method arguments (excluding the method selector) that are encoded
+/// individually without packing, as defined in ARC-4.
+pub const MAX_UNPACKED_METHOD_ARGS: usize = 15;
@@ -2,18 +2,34 @@
 pub mod abi_method;
 pub mod abi_type;
 pub mod abi_value;
+#[cfg(any(test, feature = "proptest"))]
+pub mod arbitrary;
+pub mod arc28;
 pub mod arc56_contract;
+pub mod arc56_diff;
+pub mod arc56_validation;
+pub mod codec;
 pub mod constants;
 pub mod error;
+pub mod references;
+pub mod source_map;
 pub mod types;
 pub mod utils;
 
 pub use abi_type::ABIType;
 pub use abi_value::ABIValue;
+#[cfg(feature = "derive")]
+pub use algokit_abi_derive::{AbiDecode, AbiEncode};
+pub use arc28::{DecodedEvent, DecodedEventArg};
 pub use arc56_contract::*;
+pub use arc56_diff::{SpecChange, SpecChangeKind, SpecDiff};
+pub use arc56_validation::{Arc56Diagnostic, Arc56DiagnosticSeverity, Arc56ValidationReport};
+pub use codec::{AbiDecode, AbiEncode};
 pub use error::ABIError;
+pub use references::ForeignReferences;
+pub use source_map::{ProgramSourceMap, SourceLocation};
 
 pub use abi_method::{
     ABIMethod, ABIMethodArg, ABIMethodArgType, ABIReferenceType, ABIReferenceValue, ABIReturn,
-    ABITransactionType,
+    ABITransactionType, MethodSelectorCollision, encode_method_args, find_selector_collisions,
 };
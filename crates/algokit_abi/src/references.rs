@@ -0,0 +1,194 @@
+//! Resolution of `account`, `asset` and `application` method arguments into the foreign
+//! reference arrays of an app call, as defined in [ARC-4](https://arc.algorand.foundation/ARCs/arc-0004#reference-types).
+//!
+//! Reference arguments aren't encoded in the app args directly. Instead the referenced item is
+//! added to the matching foreign array of the app call and the argument is encoded as a `uint8`
+//! index into that array. Index 0 of the accounts and apps arrays refers to the sender and the
+//! called app respectively, so those are never added to the arrays.
+use crate::{ABIError, ABIReferenceValue, ABIValue};
+use num_bigint::BigUint;
+
+/// The foreign reference arrays of an app call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForeignReferences {
+    /// The base32 addresses of the referenced accounts, excluding the sender.
+    pub accounts: Vec<String>,
+    /// The IDs of the referenced apps, excluding the called app.
+    pub apps: Vec<u64>,
+    /// The IDs of the referenced assets.
+    pub assets: Vec<u64>,
+}
+
+impl ForeignReferences {
+    /// Adds the items referenced by `references` to the foreign arrays, skipping items that are
+    /// already available to the app call.
+    ///
+    /// # Arguments
+    /// * `references` - The reference arguments of the method call.
+    /// * `sender` - The address of the sender of the app call.
+    /// * `app_id` - The ID of the called app.
+    pub fn add_references<'a>(
+        &mut self,
+        references: impl IntoIterator<Item = &'a ABIReferenceValue>,
+        sender: &str,
+        app_id: u64,
+    ) {
+        for reference in references {
+            match reference {
+                ABIReferenceValue::Account(address) => {
+                    if address != sender && !self.accounts.contains(address) {
+                        self.accounts.push(address.clone());
+                    }
+                }
+                ABIReferenceValue::Asset(asset_id) => {
+                    if !self.assets.contains(asset_id) {
+                        self.assets.push(*asset_id);
+                    }
+                }
+                ABIReferenceValue::Application(app_id_ref) => {
+                    if *app_id_ref != app_id && !self.apps.contains(app_id_ref) {
+                        self.apps.push(*app_id_ref);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the `uint8` index that encodes `reference` as a method argument.
+    ///
+    /// # Returns
+    /// The index if successful, or an [`ABIError`] if the referenced item isn't available to the
+    /// app call or its index doesn't fit in a `uint8`.
+    pub fn index_of(
+        &self,
+        reference: &ABIReferenceValue,
+        sender: &str,
+        app_id: u64,
+    ) -> Result<u8, ABIError> {
+        let (index, description) = match reference {
+            ABIReferenceValue::Account(address) => {
+                if address == sender {
+                    return Ok(0);
+                }
+                (
+                    self.accounts
+                        .iter()
+                        .position(|account| account == address)
+                        .map(|index| index + 1),
+                    format!("Account {}", address),
+                )
+            }
+            ABIReferenceValue::Asset(asset_id) => (
+                self.assets.iter().position(|asset| asset == asset_id),
+                format!("Asset {}", asset_id),
+            ),
+            ABIReferenceValue::Application(app_id_ref) => {
+                if *app_id_ref == app_id {
+                    return Ok(0);
+                }
+                (
+                    self.apps
+                        .iter()
+                        .position(|app| app == app_id_ref)
+                        .map(|index| index + 1),
+                    format!("Application {}", app_id_ref),
+                )
+            }
+        };
+
+        let index = index.ok_or_else(|| ABIError::EncodingError {
+            message: format!("{} not found in reference array", description),
+        })?;
+        u8::try_from(index).map_err(|_| ABIError::EncodingError {
+            message: format!(
+                "{} is at index {} of its reference array, which can't be encoded as a uint8",
+                description, index
+            ),
+        })
+    }
+
+    /// Adds the items referenced by `references` to the foreign arrays and returns the `uint8`
+    /// value encoding each reference, in order.
+    ///
+    /// The returned values take the place of the reference arguments when encoding the method
+    /// args with [`crate::encode_method_args`].
+    pub fn resolve(
+        &mut self,
+        references: &[ABIReferenceValue],
+        sender: &str,
+        app_id: u64,
+    ) -> Result<Vec<ABIValue>, ABIError> {
+        self.add_references(references, sender, app_id);
+        references
+            .iter()
+            .map(|reference| {
+                self.index_of(reference, sender, app_id)
+                    .map(|index| ABIValue::Uint(BigUint::from(index)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: &str = "MO2H6ZU47Q36GJ6GVHUKGEBEQINN7ZWVACMWZQGIYUOE3RBSRVYHV4ACJI";
+    const OTHER: &str = "WRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLRM";
+
+    #[test]
+    fn test_resolve_references() {
+        let mut foreign = ForeignReferences {
+            assets: vec![10],
+            ..Default::default()
+        };
+        let references = vec![
+            ABIReferenceValue::Account(SENDER.to_string()),
+            ABIReferenceValue::Account(OTHER.to_string()),
+            ABIReferenceValue::Asset(20),
+            ABIReferenceValue::Asset(10),
+            ABIReferenceValue::Application(1),
+            ABIReferenceValue::Application(2),
+            ABIReferenceValue::Account(OTHER.to_string()),
+        ];
+
+        let values = foreign.resolve(&references, SENDER, 1).unwrap();
+
+        assert_eq!(
+            values,
+            [0u8, 1, 1, 0, 0, 1, 1]
+                .into_iter()
+                .map(ABIValue::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            foreign,
+            ForeignReferences {
+                accounts: vec![OTHER.to_string()],
+                apps: vec![2],
+                assets: vec![10, 20],
+            }
+        );
+    }
+
+    #[test]
+    fn test_index_of_errors() {
+        let foreign = ForeignReferences {
+            assets: (0..300).collect(),
+            ..Default::default()
+        };
+
+        let missing = foreign
+            .index_of(&ABIReferenceValue::Application(5), SENDER, 1)
+            .unwrap_err();
+        assert_eq!(
+            missing.to_string(),
+            "ABI encoding failed: Application 5 not found in reference array"
+        );
+
+        let overflow = foreign
+            .index_of(&ABIReferenceValue::Asset(256), SENDER, 1)
+            .unwrap_err();
+        assert!(overflow.to_string().contains("can't be encoded as a uint8"));
+    }
+}
@@ -0,0 +1,359 @@
+use crate::arc56_contract::{PcOffsetMethod, ProgramSourceInfo, SourceInfo};
+use crate::error::ABIError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const BYTE_CBLOCK_OPCODE: u8 = 0x26; // bytecblock
+const INT_CBLOCK_OPCODE: u8 = 0x20; // intcblock
+
+const VLQ_BASE_SHIFT: u32 = 5;
+const VLQ_CONTINUATION_BIT: i64 = 1 << VLQ_BASE_SHIFT;
+const VLQ_BASE_MASK: i64 = VLQ_CONTINUATION_BIT - 1;
+
+/// The location in the TEAL source that a program counter maps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The 0-based line number in the source.
+    pub line: u64,
+    /// The 0-based column number in the source.
+    pub column: u64,
+    /// The index of the source in the source map `sources` list.
+    pub source_index: usize,
+}
+
+/// The raw (version 3) source map format returned by algod when compiling TEAL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RawProgramSourceMap {
+    version: u32,
+    #[serde(default)]
+    sources: Vec<String>,
+    #[serde(default)]
+    names: Vec<String>,
+    mappings: String,
+}
+
+/// A source map for a compiled TEAL program, mapping program counters to TEAL source locations.
+///
+/// Each `;` separated group in `mappings` corresponds to a program counter, and the
+/// VLQ encoded segments within it describe the source location of that program counter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawProgramSourceMap", into = "RawProgramSourceMap")]
+pub struct ProgramSourceMap {
+    /// The source map version, which is always 3.
+    pub version: u32,
+    /// The sources referenced by the mappings.
+    pub sources: Vec<String>,
+    /// The symbol names referenced by the mappings.
+    pub names: Vec<String>,
+    /// The raw VLQ encoded mappings.
+    pub mappings: String,
+    pc_to_location: HashMap<u64, SourceLocation>,
+}
+
+impl TryFrom<RawProgramSourceMap> for ProgramSourceMap {
+    type Error = ABIError;
+
+    fn try_from(raw: RawProgramSourceMap) -> Result<Self, Self::Error> {
+        if raw.version != 3 {
+            return Err(ABIError::DecodingError {
+                message: format!(
+                    "Only version 3 source maps are supported, got version {}",
+                    raw.version
+                ),
+            });
+        }
+
+        let mut pc_to_location = HashMap::new();
+        let mut source_index: i64 = 0;
+        let mut line: i64 = 0;
+        let mut column: i64 = 0;
+
+        for (pc, group) in raw.mappings.split(';').enumerate() {
+            let mut pc_location = None;
+            for segment in group.split(',').filter(|s| !s.is_empty()) {
+                let fields = decode_vlq_segment(segment)?;
+                if let Some(delta) = fields.get(1) {
+                    source_index += delta;
+                }
+                if let Some(delta) = fields.get(2) {
+                    line += delta;
+                }
+                if let Some(delta) = fields.get(3) {
+                    column += delta;
+                }
+
+                if source_index < 0 || line < 0 || column < 0 {
+                    return Err(ABIError::DecodingError {
+                        message: format!(
+                            "Source map segment '{}' resolves to a negative location",
+                            segment
+                        ),
+                    });
+                }
+
+                pc_location.get_or_insert(SourceLocation {
+                    line: line as u64,
+                    column: column as u64,
+                    source_index: source_index as usize,
+                });
+            }
+
+            if let Some(location) = pc_location {
+                pc_to_location.insert(pc as u64, location);
+            }
+        }
+
+        Ok(Self {
+            version: raw.version,
+            sources: raw.sources,
+            names: raw.names,
+            mappings: raw.mappings,
+            pc_to_location,
+        })
+    }
+}
+
+impl From<ProgramSourceMap> for RawProgramSourceMap {
+    fn from(source_map: ProgramSourceMap) -> Self {
+        Self {
+            version: source_map.version,
+            sources: source_map.sources,
+            names: source_map.names,
+            mappings: source_map.mappings,
+        }
+    }
+}
+
+impl ProgramSourceMap {
+    /// Parses a source map from its JSON representation.
+    pub fn from_json(json_str: &str) -> Result<Self, ABIError> {
+        serde_json::from_str(json_str).map_err(|e| ABIError::DecodingError {
+            message: format!("Failed to parse source map JSON: {}", e),
+        })
+    }
+
+    /// Parses a source map from an already deserialized JSON value, e.g. an algod compile response.
+    pub fn from_value(value: serde_json::Value) -> Result<Self, ABIError> {
+        serde_json::from_value(value).map_err(|e| ABIError::DecodingError {
+            message: format!("Failed to parse source map JSON: {}", e),
+        })
+    }
+
+    /// Returns the source location of the given program counter, if it is mapped.
+    pub fn get_location_for_pc(&self, pc: u64) -> Option<SourceLocation> {
+        self.pc_to_location.get(&pc).copied()
+    }
+
+    /// Returns the name of the source the given program counter maps to, if known.
+    pub fn get_source_for_pc(&self, pc: u64) -> Option<&str> {
+        let location = self.get_location_for_pc(pc)?;
+        self.sources.get(location.source_index).map(String::as_str)
+    }
+
+    /// Returns all program counters that map to the given 0-based source line, in ascending order.
+    pub fn get_pcs_for_line(&self, line: u64) -> Vec<u64> {
+        let mut pcs = self
+            .pc_to_location
+            .iter()
+            .filter(|(_, location)| location.line == line)
+            .map(|(pc, _)| *pc)
+            .collect::<Vec<_>>();
+        pcs.sort_unstable();
+        pcs
+    }
+}
+
+impl ProgramSourceInfo {
+    /// Returns the ARC-56 source info entry for the given program counter.
+    ///
+    /// When the program counters were recorded with the `cblocks` offset method, the compiled
+    /// `program` is used to strip the constant block offset from `pc` before the lookup.
+    /// If the program is not provided in that case, `pc` is used as is.
+    pub fn get_source_info_for_pc(&self, pc: u64, program: Option<&[u8]>) -> Option<&SourceInfo> {
+        let pc = match (&self.pc_offset_method, program) {
+            (PcOffsetMethod::Cblocks, Some(program)) => {
+                pc.saturating_sub(constant_block_offset(program) as u64)
+            }
+            _ => pc,
+        };
+
+        self.source_info
+            .iter()
+            .find(|source_info| source_info.pc.iter().any(|v| *v as u64 == pc))
+    }
+}
+
+/// Calculates the offset of the first instruction after the leading constant blocks
+/// (`intcblock` / `bytecblock`) of a compiled program.
+///
+/// This is the offset that needs to be removed from program counters when the ARC-56
+/// `pcOffsetMethod` is `cblocks`.
+pub fn constant_block_offset(program: &[u8]) -> usize {
+    if program.is_empty() {
+        return 0;
+    }
+
+    let len = program.len();
+    let mut offset = read_uvarint(program, 0).map_or(1, |(_, next)| next); // skip version
+    let mut block_end = None;
+
+    while offset < len {
+        let opcode = program[offset];
+        if opcode != BYTE_CBLOCK_OPCODE && opcode != INT_CBLOCK_OPCODE {
+            break;
+        }
+
+        let Some((count, mut i)) = read_uvarint(program, offset + 1) else {
+            break;
+        };
+        for _ in 0..count {
+            let Some((value, next)) = read_uvarint(program, i) else {
+                return block_end.unwrap_or(0);
+            };
+            i = if opcode == BYTE_CBLOCK_OPCODE {
+                next.saturating_add(value as usize).min(len)
+            } else {
+                next
+            };
+        }
+
+        offset = i;
+        block_end = Some(offset);
+    }
+
+    block_end.unwrap_or(0)
+}
+
+/// Reads an unsigned varint starting at `start`, returning the value and the index after it.
+fn read_uvarint(bytes: &[u8], start: usize) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for (i, byte) in bytes.iter().enumerate().skip(start).take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * (i - start));
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+fn decode_vlq_segment(segment: &str) -> Result<Vec<i64>, ABIError> {
+    let mut fields = Vec::new();
+    let mut value: i64 = 0;
+    let mut shift: u32 = 0;
+
+    for c in segment.chars() {
+        let digit = base64_digit(c).ok_or_else(|| ABIError::DecodingError {
+            message: format!("Invalid base64 VLQ character '{}' in source map", c),
+        })?;
+        if shift > 60 {
+            return Err(ABIError::DecodingError {
+                message: format!("Source map VLQ segment '{}' overflows", segment),
+            });
+        }
+
+        value += (digit & VLQ_BASE_MASK) << shift;
+        if digit & VLQ_CONTINUATION_BIT != 0 {
+            shift += VLQ_BASE_SHIFT;
+            continue;
+        }
+
+        let negative = value & 1 == 1;
+        value >>= 1;
+        fields.push(if negative { -value } else { value });
+        value = 0;
+        shift = 0;
+    }
+
+    if shift != 0 {
+        return Err(ABIError::DecodingError {
+            message: format!("Source map VLQ segment '{}' is truncated", segment),
+        });
+    }
+
+    Ok(fields)
+}
+
+fn base64_digit(c: char) -> Option<i64> {
+    let digit = match c {
+        'A'..='Z' => c as u8 - b'A',
+        'a'..='z' => c as u8 - b'a' + 26,
+        '0'..='9' => c as u8 - b'0' + 52,
+        '+' => 62,
+        '/' => 63,
+        _ => return None,
+    };
+    Some(digit as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("AAAA", vec![0, 0, 0, 0])]
+    #[case("AACA", vec![0, 0, 1, 0])]
+    #[case("AAEF", vec![0, 0, 2, -2])]
+    #[case("gBAAA", vec![16, 0, 0, 0])]
+    #[case("D", vec![-1])]
+    fn vlq_segment_decoding(#[case] segment: &str, #[case] expected: Vec<i64>) {
+        assert_eq!(decode_vlq_segment(segment).unwrap(), expected);
+    }
+
+    #[test]
+    fn vlq_segment_decoding_invalid() {
+        assert!(decode_vlq_segment("A!").is_err());
+        assert!(decode_vlq_segment("g").is_err());
+    }
+
+    #[test]
+    fn source_map_pc_lookup() {
+        let source_map = ProgramSourceMap::from_json(
+            r#"{"version":3,"sources":["approval.teal"],"names":[],"mappings":";AACA;;AAAA;AACA;AAAA"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(source_map.get_location_for_pc(0), None);
+        assert_eq!(
+            source_map.get_location_for_pc(1),
+            Some(SourceLocation {
+                line: 1,
+                column: 0,
+                source_index: 0
+            })
+        );
+        assert_eq!(source_map.get_location_for_pc(2), None);
+        assert_eq!(source_map.get_location_for_pc(4).map(|l| l.line), Some(2));
+        assert_eq!(source_map.get_location_for_pc(5).map(|l| l.line), Some(2));
+        assert_eq!(source_map.get_source_for_pc(1), Some("approval.teal"));
+        assert_eq!(source_map.get_pcs_for_line(1), vec![1, 3]);
+        assert_eq!(source_map.get_pcs_for_line(2), vec![4, 5]);
+    }
+
+    #[test]
+    fn source_map_round_trips_through_json() {
+        let json = r#"{"version":3,"sources":[],"names":[],"mappings":";AACA"}"#;
+        let source_map = ProgramSourceMap::from_json(json).unwrap();
+
+        let serialized = serde_json::to_string(&source_map).unwrap();
+
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn source_map_invalid_version() {
+        let result = ProgramSourceMap::from_json(r#"{"version":2,"mappings":""}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(vec![0x0a, 0x81, 0x01], 0)] // no constant blocks
+    #[case(vec![0x0a, 0x20, 0x02, 0x01, 0x80, 0x01, 0x81, 0x01], 6)] // intcblock 1 128
+    #[case(vec![0x0a, 0x26, 0x01, 0x02, 0xaa, 0xbb, 0x81, 0x01], 6)] // bytecblock 0xaabb
+    #[case(vec![0x0a, 0x20, 0x01, 0x01, 0x26, 0x01, 0x01, 0xff, 0x81, 0x01], 8)] // both blocks
+    #[case(vec![], 0)]
+    fn constant_block_offset_calculation(#[case] program: Vec<u8>, #[case] expected: usize) {
+        assert_eq!(constant_block_offset(&program), expected);
+    }
+}
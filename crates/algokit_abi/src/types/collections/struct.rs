@@ -102,6 +102,28 @@ impl ABIStruct {
         ABIType::Tuple(child_types)
     }
 
+    /// Get the zero value of the struct, with every field set to its default value
+    pub(crate) fn default_value(&self) -> ABIValue {
+        Self::fields_default_value(&self.fields)
+    }
+
+    fn fields_default_value(fields: &[StructField]) -> ABIValue {
+        ABIValue::Struct(
+            fields
+                .iter()
+                .map(|field| {
+                    let value = match &field.field_type {
+                        StructFieldType::Fields(nested_fields) => {
+                            Self::fields_default_value(nested_fields)
+                        }
+                        StructFieldType::Type(field_type) => field_type.default_value(),
+                    };
+                    (field.name.clone(), value)
+                })
+                .collect(),
+        )
+    }
+
     /// Encode struct value using tuple encoding
     pub(crate) fn encode(&self, value: &ABIValue) -> Result<Vec<u8>, ABIError> {
         match value {
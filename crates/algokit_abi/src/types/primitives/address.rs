@@ -63,17 +63,7 @@ impl ABIType {
                         ),
                     })?;
 
-                let mut buffer =
-                    [0u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH + ALGORAND_CHECKSUM_BYTE_LENGTH];
-                buffer[..ALGORAND_PUBLIC_KEY_BYTE_LENGTH].copy_from_slice(bytes);
-
-                let checksum = get_checksum(bytes);
-                buffer[ALGORAND_PUBLIC_KEY_BYTE_LENGTH..].copy_from_slice(&checksum);
-
-                let address_str =
-                    base32::encode(base32::Alphabet::Rfc4648 { padding: false }, &buffer);
-
-                Ok(ABIValue::Address(address_str))
+                Ok(ABIValue::Address(encode_address(bytes)))
             }
             _ => Err(ABIError::DecodingError {
                 message: "ABI type mismatch, expected address".to_string(),
@@ -82,6 +72,17 @@ impl ABIType {
     }
 }
 
+/// Encodes a public key as a base32 Algorand address string with checksum.
+pub(crate) fn encode_address(pub_key: &[u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH]) -> String {
+    let mut buffer = [0u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH + ALGORAND_CHECKSUM_BYTE_LENGTH];
+    buffer[..ALGORAND_PUBLIC_KEY_BYTE_LENGTH].copy_from_slice(pub_key);
+
+    let checksum = get_checksum(pub_key);
+    buffer[ALGORAND_PUBLIC_KEY_BYTE_LENGTH..].copy_from_slice(&checksum);
+
+    base32::encode(base32::Alphabet::Rfc4648 { padding: false }, &buffer)
+}
+
 fn get_checksum(
     pub_key: &[u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH],
 ) -> [u8; ALGORAND_CHECKSUM_BYTE_LENGTH] {
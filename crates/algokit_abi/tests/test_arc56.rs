@@ -1,3 +1,6 @@
+use algokit_abi::ABIMethod;
+use algokit_abi::Arc56DiagnosticSeverity;
+use algokit_abi::SpecChangeKind;
 use algokit_abi::arc56_contract::Arc56Contract;
 use algokit_test_artifacts::{
     arc56_struct_operations, complex_struct_test, constant_product_amm, nested_contract_calls,
@@ -5,6 +8,7 @@ use algokit_test_artifacts::{
     zero_coupon_bond,
 };
 use rstest::rstest;
+use std::str::FromStr;
 
 #[rstest]
 #[case(template_variables::APPLICATION_ARC56, "template_variables")]
@@ -113,3 +117,270 @@ fn test_arc56_state_keys_are_not_normalized() -> Result<(), Box<dyn std::error::
     insta::assert_json_snapshot!(exported_parsed);
     Ok(())
 }
+
+#[rstest]
+#[case(template_variables::APPLICATION_ARC56)]
+#[case(state_management_demo::APPLICATION_ARC56)]
+#[case(constant_product_amm::APPLICATION_ARC56)]
+#[case(nested_struct_storage::APPLICATION_ARC56)]
+#[case(arc56_struct_operations::APPLICATION_ARC56)]
+#[case(complex_struct_test::APPLICATION_ARC56)]
+#[case(zero_coupon_bond::APPLICATION_ARC56)]
+#[case(nfd::APPLICATION_ARC56)]
+#[case(reti::APPLICATION_ARC56)]
+#[case(void_return_test::APPLICATION_ARC56)]
+#[case(nested_contract_calls::APPLICATION_ARC56)]
+fn test_arc56_validate_artifacts(
+    #[case] artifact_content: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let arc56_contract = Arc56Contract::from_json(artifact_content)?;
+
+    let report = arc56_contract.validate();
+
+    let errors = report.errors().map(|d| d.to_string()).collect::<Vec<_>>();
+    assert!(report.is_valid(), "Unexpected errors: {:?}", errors);
+    Ok(())
+}
+
+#[test]
+fn test_arc56_validate_reports_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+    let mut arc56_contract = Arc56Contract::from_json(arc56_struct_operations::APPLICATION_ARC56)?;
+    let (method_index, arg_index) = arc56_contract
+        .methods
+        .iter()
+        .enumerate()
+        .find_map(|(method_index, method)| {
+            method
+                .args
+                .iter()
+                .position(|arg| arg.struct_name.is_some())
+                .map(|arg_index| (method_index, arg_index))
+        })
+        .expect("Artifact should reference a struct in a method arg");
+
+    arc56_contract.methods[method_index].args[arg_index].struct_name =
+        Some("MissingStruct".to_string());
+    arc56_contract.networks = Some(std::collections::HashMap::from([(
+        "not-a-genesis-hash".to_string(),
+        algokit_abi::Network { app_id: 0 },
+    )]));
+
+    let report = arc56_contract.validate();
+
+    assert!(!report.is_valid());
+    let diagnostics = report
+        .diagnostics
+        .iter()
+        .map(|d| (d.severity, d.path.as_str(), d.message.as_str()))
+        .collect::<Vec<_>>();
+    assert!(diagnostics.contains(&(
+        Arc56DiagnosticSeverity::Error,
+        format!("methods[{}].args[{}]", method_index, arg_index).as_str(),
+        "Struct MissingStruct is not defined",
+    )));
+    assert!(diagnostics.contains(&(
+        Arc56DiagnosticSeverity::Error,
+        "networks.not-a-genesis-hash",
+        "App ID must not be 0",
+    )));
+    assert!(diagnostics.contains(&(
+        Arc56DiagnosticSeverity::Warning,
+        "networks.not-a-genesis-hash",
+        "Network key is not a base64 encoded genesis hash",
+    )));
+    Ok(())
+}
+
+fn contract_with_overloaded_bootstrap() -> Result<Arc56Contract, Box<dyn std::error::Error>> {
+    let mut arc56_contract = Arc56Contract::from_json(constant_product_amm::APPLICATION_ARC56)?;
+    let mut overload = arc56_contract.get_method("bootstrap")?.clone();
+    overload.args.pop();
+    arc56_contract.methods.push(overload);
+    Ok(arc56_contract)
+}
+
+#[test]
+fn test_arc56_find_method_resolves_overloads() -> Result<(), Box<dyn std::error::Error>> {
+    let arc56_contract = contract_with_overloaded_bootstrap()?;
+
+    assert_eq!(arc56_contract.find_method("swap")?.name, "swap");
+    assert_eq!(
+        arc56_contract
+            .find_method("bootstrap(pay,asset)")?
+            .signature()?,
+        "bootstrap(pay,asset)uint64"
+    );
+    assert_eq!(
+        arc56_contract
+            .find_method("bootstrap(pay,asset,asset)uint64")?
+            .args
+            .len(),
+        3
+    );
+
+    let ambiguous = arc56_contract.find_method("bootstrap").unwrap_err();
+    assert!(ambiguous.to_string().contains(
+        "please pass in an ABI signature instead: bootstrap(pay,asset,asset)uint64, bootstrap(pay,asset)uint64"
+    ));
+
+    let not_found = arc56_contract
+        .find_method("bootstrap(pay)uint64")
+        .unwrap_err();
+    assert!(not_found.to_string().contains(
+        "methods with the same name: bootstrap(pay,asset,asset)uint64, bootstrap(pay,asset)uint64"
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_arc56_find_method_by_selector() -> Result<(), Box<dyn std::error::Error>> {
+    let arc56_contract = contract_with_overloaded_bootstrap()?;
+
+    for method in &arc56_contract.methods {
+        let selector = ABIMethod::from_str(&method.signature()?)?.selector()?;
+        assert_eq!(
+            arc56_contract
+                .find_method_by_selector(&selector)?
+                .signature()?,
+            method.signature()?
+        );
+    }
+    assert!(
+        arc56_contract
+            .find_method_by_selector(&[0, 0, 0, 0])
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_arc56_detects_method_selector_collisions() -> Result<(), Box<dyn std::error::Error>> {
+    // m8916()void and m12207()void share the selector 0x01c0f79c
+    let mut arc56_contract = Arc56Contract::from_json(constant_product_amm::APPLICATION_ARC56)?;
+    let first_index = arc56_contract.methods.len();
+    for name in ["m8916", "m12207"] {
+        let mut method = arc56_contract.get_method("swap")?.clone();
+        method.name = name.to_string();
+        method.args.clear();
+        method.returns.return_type = "void".to_string();
+        method.returns.struct_name = None;
+        arc56_contract.methods.push(method);
+    }
+
+    let collisions = arc56_contract.method_selector_collisions();
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(
+        collisions[0].first,
+        (first_index, "m8916()void".to_string())
+    );
+    assert_eq!(
+        collisions[0].second,
+        (first_index + 1, "m12207()void".to_string())
+    );
+    assert_eq!(collisions[0].selector, vec![0x01, 0xc0, 0xf7, 0x9c]);
+
+    let abi_collisions = algokit_abi::find_selector_collisions(&[
+        ABIMethod::from_str("m8916()void")?,
+        ABIMethod::from_str("m12207()void")?,
+    ]);
+    assert_eq!(abi_collisions.len(), 1);
+    assert_eq!(
+        (abi_collisions[0].first.0, abi_collisions[0].second.0),
+        (0, 1)
+    );
+
+    let report = arc56_contract.validate();
+    let collision_error = report
+        .errors()
+        .find(|d| d.path == format!("methods[{}]", first_index + 1))
+        .expect("The collision should be reported");
+    assert_eq!(
+        collision_error.message,
+        format!(
+            "Methods m8916()void and m12207()void have the same selector 0x01c0f79c, also defined at methods[{}]",
+            first_index
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_arc56_diff_identical_specs() -> Result<(), Box<dyn std::error::Error>> {
+    let arc56_contract = Arc56Contract::from_json(constant_product_amm::APPLICATION_ARC56)?;
+
+    assert!(arc56_contract.diff(&arc56_contract).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_arc56_diff_reports_breaking_changes() -> Result<(), Box<dyn std::error::Error>> {
+    let old = Arc56Contract::from_json(arc56_struct_operations::APPLICATION_ARC56)?;
+    let mut new = old.clone();
+    let foo = new.methods.iter().position(|m| m.name == "foo").unwrap();
+    new.methods[foo].args.pop();
+    new.state.schema.global_state.ints += 1;
+    let (struct_name, fields) = new.structs.iter_mut().next().unwrap();
+    let struct_name = struct_name.clone();
+    fields.pop();
+
+    let diff = old.diff(&new);
+
+    assert!(diff.is_breaking());
+    let changes = diff
+        .breaking_changes()
+        .map(|c| (c.kind, c.path.as_str()))
+        .collect::<Vec<_>>();
+    assert!(changes.contains(&(
+        SpecChangeKind::Changed,
+        format!("methods.{}", old.methods[foo].signature()?).as_str()
+    )));
+    assert!(changes.contains(&(SpecChangeKind::Changed, "state.schema.global.ints")));
+    assert!(changes.contains(&(
+        SpecChangeKind::Changed,
+        format!("structs.{}", struct_name).as_str()
+    )));
+    Ok(())
+}
+
+#[test]
+fn test_arc56_diff_reports_non_breaking_changes() -> Result<(), Box<dyn std::error::Error>> {
+    let old = Arc56Contract::from_json(constant_product_amm::APPLICATION_ARC56)?;
+    let mut new = old.clone();
+    let mut added = new.get_method("swap")?.clone();
+    added.name = "swap_exact".to_string();
+    let added_path = format!("methods.{}", added.signature()?);
+    new.methods.push(added);
+    new.state.schema.global_state.bytes = 0;
+
+    let diff = old.diff(&new);
+    assert!(!diff.is_breaking());
+    let changes = diff
+        .non_breaking_changes()
+        .map(|c| (c.kind, c.path.as_str()))
+        .collect::<Vec<_>>();
+    assert!(changes.contains(&(SpecChangeKind::Added, added_path.as_str())));
+    assert!(changes.contains(&(SpecChangeKind::Changed, "state.schema.global.bytes")));
+
+    let diff = new.diff(&old);
+    assert!(diff.is_breaking());
+    assert_eq!(
+        diff.breaking_changes()
+            .map(|c| (c.kind, c.path.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (SpecChangeKind::Removed, added_path.as_str()),
+            (SpecChangeKind::Changed, "state.schema.global.bytes")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_arc56_method_display_uses_struct_names() -> Result<(), Box<dyn std::error::Error>> {
+    let arc56_contract = Arc56Contract::from_json(arc56_struct_operations::APPLICATION_ARC56)?;
+
+    let method = arc56_contract.find_abi_method("foo")?;
+
+    assert_eq!(method.to_string(), "foo(inputs: Inputs) -> Outputs");
+    Ok(())
+}
@@ -0,0 +1,196 @@
+//! Derive macros for converting Rust types to and from ARC-4 values.
+//!
+//! The derives are re-exported by `algokit_abi` when its `derive` feature is enabled:
+//! * Structs (named, tuple and unit) are encoded as ARC-4 tuples of their fields in declaration
+//!   order. Every field type must implement the derived trait.
+//! * Enums with only unit variants are encoded as the `uint8` index of their variant.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DataEnum, DeriveInput, Error, Fields, Generics, Ident, Index, parse_macro_input,
+    parse_quote,
+};
+
+/// Derive `algokit_abi::AbiEncode` for a struct or unit enum.
+#[proc_macro_derive(AbiEncode)]
+pub fn derive_abi_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_abi_encode(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `algokit_abi::AbiDecode` for a struct or unit enum.
+#[proc_macro_derive(AbiDecode)]
+pub fn derive_abi_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_abi_decode(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn add_trait_bounds(mut generics: Generics, bound: TokenStream2) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn unit_variants(data: &DataEnum) -> Result<Vec<&Ident>, Error> {
+    if data.variants.len() > u8::MAX as usize + 1 {
+        return Err(Error::new_spanned(
+            &data.variants,
+            "enums with more than 256 variants can't be encoded as a uint8",
+        ));
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| match variant.fields {
+            Fields::Unit => Ok(&variant.ident),
+            _ => Err(Error::new_spanned(
+                variant,
+                "only enums with unit variants can be encoded as ARC-4 values",
+            )),
+        })
+        .collect()
+}
+
+fn expand_abi_encode(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone(), quote!(::algokit_abi::AbiEncode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (abi_type, to_abi_value) = match &input.data {
+        Data::Struct(data) => {
+            let field_types = data.fields.iter().map(|field| &field.ty);
+            let field_accessors = data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = Index::from(index);
+                        quote!(#index)
+                    }
+                });
+            (
+                quote! {
+                    ::algokit_abi::ABIType::Tuple(::std::vec![
+                        #(<#field_types as ::algokit_abi::AbiEncode>::abi_type()),*
+                    ])
+                },
+                quote! {
+                    ::algokit_abi::ABIValue::Array(::std::vec![
+                        #(::algokit_abi::AbiEncode::to_abi_value(&self.#field_accessors)),*
+                    ])
+                },
+            )
+        }
+        Data::Enum(data) => {
+            let variants = unit_variants(data)?;
+            let indexes = (0..variants.len()).map(|index| index as u8);
+            (
+                quote! {
+                    <u8 as ::algokit_abi::AbiEncode>::abi_type()
+                },
+                quote! {
+                    match self {
+                        #(Self::#variants => ::algokit_abi::ABIValue::from(#indexes)),*
+                    }
+                },
+            )
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "unions can't be encoded as ARC-4 values",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::algokit_abi::AbiEncode for #name #ty_generics #where_clause {
+            fn abi_type() -> ::algokit_abi::ABIType {
+                #abi_type
+            }
+
+            fn to_abi_value(&self) -> ::algokit_abi::ABIValue {
+                #to_abi_value
+            }
+        }
+    })
+}
+
+fn expand_abi_decode(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone(), quote!(::algokit_abi::AbiDecode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let value = format_ident!("value");
+
+    let from_abi_value = match &input.data {
+        Data::Struct(data) => {
+            let field_count = data.fields.len();
+            let field_values = data.fields.iter().enumerate().map(|(index, field)| {
+                let ty = &field.ty;
+                let field_name = match &field.ident {
+                    Some(ident) => {
+                        let ident = ident.to_string();
+                        quote!(::std::option::Option::Some(#ident))
+                    }
+                    None => quote!(::std::option::Option::None),
+                };
+                quote! {
+                    <#ty as ::algokit_abi::AbiDecode>::from_abi_value(
+                        ::algokit_abi::codec::take_field(&mut #value, #index, #field_name)?
+                    )?
+                }
+            });
+            let constructor = match &data.fields {
+                Fields::Named(fields) => {
+                    let idents = fields.named.iter().map(|field| &field.ident);
+                    quote!(Self { #(#idents: #field_values),* })
+                }
+                Fields::Unnamed(_) => quote!(Self(#(#field_values),*)),
+                Fields::Unit => quote!(Self),
+            };
+            quote! {
+                #[allow(unused_mut)]
+                let mut #value = #value;
+                ::algokit_abi::codec::check_field_count(&#value, #field_count)?;
+                ::std::result::Result::Ok(#constructor)
+            }
+        }
+        Data::Enum(data) => {
+            let variants = unit_variants(data)?;
+            let indexes = (0..variants.len()).map(|index| index as u8);
+            let enum_name = name.to_string();
+            quote! {
+                match <u8 as ::algokit_abi::AbiDecode>::from_abi_value(#value)? {
+                    #(#indexes => ::std::result::Result::Ok(Self::#variants),)*
+                    index => ::std::result::Result::Err(::algokit_abi::ABIError::DecodingError {
+                        message: ::std::format!("{} is not a valid variant index of {}", index, #enum_name),
+                    }),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "unions can't be decoded from ARC-4 values",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::algokit_abi::AbiDecode for #name #ty_generics #where_clause {
+            fn from_abi_value(
+                #value: ::algokit_abi::ABIValue,
+            ) -> ::std::result::Result<Self, ::algokit_abi::ABIError> {
+                #from_abi_value
+            }
+        }
+    })
+}
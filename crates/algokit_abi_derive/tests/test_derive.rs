@@ -0,0 +1,82 @@
+use algokit_abi::{ABIError, ABIType, ABIValue, AbiDecode, AbiEncode};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, AbiEncode, AbiDecode)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, PartialEq, AbiEncode, AbiDecode)]
+struct Order {
+    id: u64,
+    side: Side,
+    note: String,
+    filled: bool,
+    fills: Vec<Fill>,
+}
+
+#[derive(Debug, Clone, PartialEq, AbiEncode, AbiDecode)]
+struct Fill(u32, [u8; 2]);
+
+#[derive(Debug, Clone, PartialEq, AbiEncode, AbiDecode)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+fn order() -> Order {
+    Order {
+        id: 42,
+        side: Side::Sell,
+        note: "test".to_string(),
+        filled: true,
+        fills: vec![Fill(1, [2, 3]), Fill(4, [5, 6])],
+    }
+}
+
+#[test]
+fn test_derived_abi_type() {
+    assert_eq!(
+        Order::abi_type(),
+        ABIType::from_str("(uint64,uint8,string,bool,(uint32,uint8[2])[])").unwrap()
+    );
+    assert_eq!(
+        Wrapper::<Side>::abi_type(),
+        ABIType::from_str("(uint8)").unwrap()
+    );
+}
+
+#[test]
+fn test_derived_round_trip() {
+    let order = order();
+    let encoded = order.abi_encode().unwrap();
+
+    assert_eq!(
+        encoded,
+        Order::abi_type().encode(&order.to_abi_value()).unwrap()
+    );
+    assert_eq!(Order::abi_decode(&encoded).unwrap(), order);
+}
+
+#[test]
+fn test_decode_from_struct_value() {
+    let value = ABIValue::Struct(HashMap::from([("inner".to_string(), ABIValue::from(1u8))]));
+
+    assert_eq!(
+        Wrapper::<Side>::from_abi_value(value).unwrap(),
+        Wrapper { inner: Side::Sell }
+    );
+}
+
+#[test]
+fn test_decode_invalid_values() {
+    assert!(matches!(
+        Side::from_abi_value(ABIValue::from(2u8)),
+        Err(ABIError::DecodingError { message }) if message == "2 is not a valid variant index of Side"
+    ));
+    assert!(matches!(
+        Fill::from_abi_value(ABIValue::Array(vec![ABIValue::from(1u32)])),
+        Err(ABIError::DecodingError { message }) if message == "Expected a tuple of 2 elements, got 1"
+    ));
+}
@@ -0,0 +1,48 @@
+use algokit_abi::{ABIType, ABIValue};
+use algokit_transact::test_utils::AccountMother;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::str::FromStr;
+
+/// An array of tuples nesting dynamic types at several levels.
+const NESTED_TYPE: &str = "(uint64,string,(bool,uint16[]),address)[]";
+
+fn nested_value(length: usize) -> ABIValue {
+    ABIValue::Array(
+        (0..length)
+            .map(|i| {
+                ABIValue::Array(vec![
+                    ABIValue::from(i as u64),
+                    ABIValue::String(format!("element {i}")),
+                    ABIValue::Array(vec![
+                        ABIValue::Bool(i % 2 == 0),
+                        ABIValue::Array((0..8u16).map(ABIValue::from).collect()),
+                    ]),
+                    ABIValue::Address(AccountMother::account().address().to_string()),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn bench_nested(c: &mut Criterion) {
+    let abi_type = ABIType::from_str(NESTED_TYPE).unwrap();
+    let mut group = c.benchmark_group("abi_nested");
+    for length in [1, 32] {
+        let value = nested_value(length);
+        let encoded = abi_type.encode(&value).unwrap();
+
+        group.bench_function(format!("encode/{length}"), |b| {
+            b.iter(|| abi_type.encode(black_box(&value)).unwrap())
+        });
+        group.bench_function(format!("decode/{length}"), |b| {
+            b.iter(|| abi_type.decode(black_box(&encoded)).unwrap())
+        });
+    }
+    group.bench_function("parse_type", |b| {
+        b.iter(|| ABIType::from_str(black_box(NESTED_TYPE)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_nested);
+criterion_main!(benches);
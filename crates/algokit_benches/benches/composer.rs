@@ -0,0 +1,63 @@
+use algokit_abi::{ABIMethod, ABIValue};
+use algokit_benches::offline_algod_client;
+use algokit_transact::MAX_TX_GROUP_SIZE;
+use algokit_transact::test_utils::AccountMother;
+use algokit_utils::transactions::{
+    AppCallMethodCallParams, AppMethodCallArg, EmptySigner, ResourcePopulation,
+    TransactionComposer, TransactionComposerConfig, TransactionComposerParams,
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn method_call(method: &ABIMethod, i: u64) -> AppCallMethodCallParams {
+    AppCallMethodCallParams {
+        sender: AccountMother::account().address(),
+        app_id: 1234,
+        method: method.clone(),
+        args: vec![
+            AppMethodCallArg::ABIValue(ABIValue::from(i)),
+            AppMethodCallArg::ABIValue(ABIValue::String(format!("call {i}"))),
+            AppMethodCallArg::ABIValue(ABIValue::Array(vec![
+                ABIValue::Bool(true),
+                ABIValue::Array((0..8u16).map(ABIValue::from).collect()),
+            ])),
+        ],
+        ..Default::default()
+    }
+}
+
+/// Build a full group of app calls. Resource population and inner fee coverage are disabled, as
+/// they simulate the group on a node.
+fn bench_build_app_calls(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let algod_client = offline_algod_client();
+    let method = ABIMethod::from_str("update(uint64,string,(bool,uint16[]))void").unwrap();
+
+    c.bench_function("composer/build/16_app_calls", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                let mut composer = TransactionComposer::new(TransactionComposerParams {
+                    algod_client: algod_client.clone(),
+                    signer_getter: Arc::new(EmptySigner {}),
+                    composer_config: Some(TransactionComposerConfig {
+                        populate_app_call_resources: ResourcePopulation::Disabled,
+                        ..Default::default()
+                    }),
+                    suggested_params_cache: None,
+                });
+                for i in 0..MAX_TX_GROUP_SIZE as u64 {
+                    composer
+                        .add_app_call_method_call(method_call(&method, i))
+                        .unwrap();
+                }
+                composer.build().await.unwrap().len()
+            })
+        })
+    });
+}
+
+criterion_group!(benches, bench_build_app_calls);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use algokit_transact::test_utils::{
+    AppCallTransactionMother, TransactionGroupMother, TransactionMother,
+};
+use algokit_transact::{AlgorandMsgpack, Transaction, TransactionId, Transactions};
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+
+fn transactions() -> Vec<(&'static str, Transaction)> {
+    vec![
+        (
+            "payment",
+            TransactionMother::simple_payment().build().unwrap(),
+        ),
+        (
+            "app_call",
+            AppCallTransactionMother::app_call().build().unwrap(),
+        ),
+    ]
+}
+
+fn bench_encode_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transaction");
+    for (name, transaction) in transactions() {
+        let encoded = transaction.encode().unwrap();
+
+        group.bench_function(format!("encode/{name}"), |b| {
+            b.iter(|| black_box(&transaction).encode().unwrap())
+        });
+        group.bench_function(format!("decode/{name}"), |b| {
+            b.iter(|| Transaction::decode(black_box(&encoded)).unwrap())
+        });
+        group.bench_function(format!("id/{name}"), |b| {
+            b.iter(|| black_box(&transaction).id().unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_group_id(c: &mut Criterion) {
+    let mut group = c.benchmark_group("group");
+    for size in [2, 16] {
+        let transactions = TransactionGroupMother::group_of(size);
+        group.bench_function(format!("assign_group/{size}"), |b| {
+            b.iter_batched(
+                || transactions.clone(),
+                |transactions| transactions.assign_group().unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode_decode, bench_group_id);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! Shared fixtures for the benchmarks of the algokit crates.
+//!
+//! The benchmarks live in `benches/`, see the README for how to run them and compare against a
+//! baseline.
+
+use algod_client::AlgodClient;
+use algod_client::models::TransactionParams;
+use algokit_http_client::{HttpClient, HttpError, HttpMethod, HttpResponse, RequestOptions};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// An [`HttpClient`] that answers every request with the suggested params of a LocalNet, so
+/// benchmarks of the composer measure building rather than the network.
+pub struct SuggestedParamsHttpClient {
+    body: Vec<u8>,
+}
+
+impl SuggestedParamsHttpClient {
+    pub fn new() -> Self {
+        let params = TransactionParams {
+            consensus_version: "https://github.com/algorandfoundation/specs/tree/953304de35264fc3ef91bcd05c123242015eeaed".to_string(),
+            fee: 0,
+            genesis_hash: vec![1; 32],
+            genesis_id: "dockernet-v1".to_string(),
+            last_round: 1_000,
+            min_fee: 1_000,
+        };
+
+        Self {
+            body: serde_json::to_vec(&params).expect("Suggested params should serialize"),
+        }
+    }
+}
+
+impl Default for SuggestedParamsHttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl HttpClient for SuggestedParamsHttpClient {
+    async fn request(
+        &self,
+        _http_method: HttpMethod,
+        _path: String,
+        _query: Option<HashMap<String, String>>,
+        _body: Option<Vec<u8>>,
+        _headers: Option<HashMap<String, String>>,
+        _options: Option<RequestOptions>,
+    ) -> Result<HttpResponse, HttpError> {
+        Ok(HttpResponse {
+            status: 200,
+            body: self.body.clone(),
+            headers: HashMap::from([("content-type".to_string(), "application/json".to_string())]),
+        })
+    }
+}
+
+/// An [`AlgodClient`] backed by a [`SuggestedParamsHttpClient`].
+pub fn offline_algod_client() -> Arc<AlgodClient> {
+    Arc::new(AlgodClient::new(Arc::new(SuggestedParamsHttpClient::new())))
+}
@@ -0,0 +1,175 @@
+use crate::{DefaultHttpClient, HttpError, HttpRequest, HttpResponse};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A middleware of [`DefaultHttpClient`], which sees every request and its response, e.g. to log
+/// requests, rotate auth tokens, sign requests or record metrics.
+///
+/// A middleware may change the request before passing it on with [`Next::run`], change or replace
+/// the result, run the rest of the chain again, or answer without sending the request at all.
+///
+/// ```ignore
+/// struct ApiToken(String);
+///
+/// #[async_trait]
+/// impl HttpMiddleware for ApiToken {
+///     async fn handle(
+///         &self,
+///         mut request: HttpRequest,
+///         next: Next<'_>,
+///     ) -> Result<HttpResponse, HttpError> {
+///         request
+///             .headers
+///             .get_or_insert_with(HashMap::new)
+///             .insert("X-API-Key".to_string(), self.0.clone());
+///         next.run(request).await
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait HttpMiddleware: Send + Sync {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>)
+    -> Result<HttpResponse, HttpError>;
+}
+
+/// The rest of the middleware chain of a request, ending with sending it.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn HttpMiddleware>],
+    client: &'a DefaultHttpClient,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middlewares: &'a [Arc<dyn HttpMiddleware>],
+        client: &'a DefaultHttpClient,
+    ) -> Self {
+        Self {
+            middlewares,
+            client,
+        }
+    }
+
+    /// Pass the request to the next middleware, or send it if there is none.
+    pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(request, Next::new(rest, self.client))
+                    .await
+            }
+            None => self.client.send(request).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClient, HttpMethod};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Records the path of each request and the status of each result, under its name.
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl HttpMiddleware for Recorder {
+        async fn handle(
+            &self,
+            request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, HttpError> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} request {}", self.name, request.path));
+            let result = next.run(request).await;
+            self.log.lock().unwrap().push(format!(
+                "{} response {}",
+                self.name,
+                if result.is_ok() { "ok" } else { "error" }
+            ));
+            result
+        }
+    }
+
+    struct ApiToken(&'static str);
+
+    #[async_trait]
+    impl HttpMiddleware for ApiToken {
+        async fn handle(
+            &self,
+            mut request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, HttpError> {
+            request
+                .headers
+                .get_or_insert_with(HashMap::new)
+                .insert("X-API-Key".to_string(), self.0.to_string());
+            next.run(request).await
+        }
+    }
+
+    /// Answers with the request headers as the response headers, without sending the request.
+    struct Echo;
+
+    #[async_trait]
+    impl HttpMiddleware for Echo {
+        async fn handle(
+            &self,
+            request: HttpRequest,
+            _next: Next<'_>,
+        ) -> Result<HttpResponse, HttpError> {
+            Ok(HttpResponse {
+                status: 200,
+                body: Vec::new(),
+                headers: request.headers.unwrap_or_default(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middlewares_run_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = DefaultHttpClient::builder("http://localhost")
+            .middleware(Arc::new(Recorder {
+                name: "outer",
+                log: log.clone(),
+            }))
+            .middleware(Arc::new(ApiToken("secret")))
+            .middleware(Arc::new(Recorder {
+                name: "inner",
+                log: log.clone(),
+            }))
+            .middleware(Arc::new(Echo))
+            .build()
+            .unwrap();
+
+        let response = client
+            .request(
+                HttpMethod::Get,
+                "/health".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers["X-API-Key"], "secret");
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "outer request /health",
+                "inner request /health",
+                "inner response ok",
+                "outer response ok",
+            ]
+        );
+    }
+}
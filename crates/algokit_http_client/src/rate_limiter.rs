@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// A token bucket limiting the requests of [`DefaultHttpClient`](crate::DefaultHttpClient), e.g.
+/// to stay within the limits of a free tier API provider during batch operations.
+///
+/// Up to `burst` requests are sent at once, after which requests are sent at
+/// `requests_per_second`, in the order they were made. Every attempt of a retried request counts.
+///
+/// Clones share their bucket, so a limiter can be shared by the clients of one provider, e.g. of
+/// its algod and indexer APIs.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// A limiter starting with a full bucket. A `requests_per_second` of zero or less is treated
+    /// as one request per second, and a `burst` of zero as one.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        let requests_per_second = if requests_per_second > 0.0 {
+            requests_per_second
+        } else {
+            1.0
+        };
+        let burst = burst.max(1) as f64;
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: burst,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Wait until a request may be sent, taking a token from the bucket.
+    pub async fn acquire(&self) {
+        // Waiters hold the lock while sleeping, so the fair mutex queues them in order
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+        if bucket.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_second);
+            tokio::time::sleep(wait).await;
+            self.refill(&mut bucket);
+        }
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second).min(self.burst);
+        bucket.refilled_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_requests_beyond_the_burst_wait_for_tokens() {
+        let limiter = RateLimiter::new(10.0, 2);
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_millis(200));
+
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        let idle = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(idle.elapsed(), Duration::ZERO);
+        limiter.acquire().await;
+        assert_eq!(idle.elapsed(), Duration::from_millis(100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_clones_share_the_bucket() {
+        let limiter = RateLimiter::new(1.0, 1);
+        let shared = limiter.clone();
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        shared.acquire().await;
+
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,159 @@
+//! An [`HttpClient`] backed by the `fetch` API of browsers, web workers and Node.js, for WASM
+//! builds where reqwest's native client isn't available.
+
+use crate::{HttpClient, HttpError, HttpMethod, HttpResponse, RequestOptions};
+use async_trait::async_trait;
+use js_sys::{Array, Promise, Reflect, Uint8Array};
+use std::collections::HashMap;
+use std::time::Duration;
+use wasm_bindgen::{JsCast, JsValue, prelude::wasm_bindgen};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortSignal, Headers, Request, RequestInit, Response};
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `fetch`, which is the same function on the window, a worker and Node.js.
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_with_request(input: &Request) -> Promise;
+}
+
+pub struct FetchHttpClient {
+    base_url: String,
+    headers: HashMap<String, String>,
+    timeout: Option<Duration>,
+}
+
+impl FetchHttpClient {
+    pub fn new(base_url: &str) -> Self {
+        FetchHttpClient {
+            base_url: base_url.to_string(),
+            headers: HashMap::new(),
+            timeout: None,
+        }
+    }
+
+    pub fn with_header(
+        base_url: &str,
+        header_name: &str,
+        header_value: &str,
+    ) -> Result<Self, HttpError> {
+        let mut client = Self::new(base_url);
+        client
+            .headers
+            .insert(header_name.to_string(), header_value.to_string());
+        Ok(client)
+    }
+
+    /// The timeout of a whole request, unless overridden by the options of the request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn url(&self, path: &str, query: Option<&HashMap<String, String>>) -> String {
+        let mut url = format!("{}{}", self.base_url, path);
+        let query = query
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    js_sys::encode_uri_component(key),
+                    js_sys::encode_uri_component(value)
+                )
+            })
+            .collect::<Vec<_>>();
+        if !query.is_empty() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&query.join("&"));
+        }
+        url
+    }
+}
+
+#[async_trait(?Send)]
+impl HttpClient for FetchHttpClient {
+    async fn request(
+        &self,
+        method: HttpMethod,
+        path: String,
+        query: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        headers: Option<HashMap<String, String>>,
+        options: Option<RequestOptions>,
+    ) -> Result<HttpResponse, HttpError> {
+        let init = RequestInit::new();
+        init.set_method(method.as_str());
+
+        let request_headers = Headers::new().map_err(to_http_error)?;
+        for (name, value) in self.headers.iter().chain(headers.iter().flatten()) {
+            request_headers.set(name, value).map_err(to_http_error)?;
+        }
+        init.set_headers(&request_headers);
+
+        if let Some(body_data) = body {
+            init.set_body(&Uint8Array::from(body_data.as_slice()));
+        }
+
+        if let Some(timeout) = options.and_then(|options| options.timeout).or(self.timeout) {
+            init.set_signal(Some(&AbortSignal::timeout_with_f64(
+                timeout.as_millis() as f64
+            )));
+        }
+
+        let request = Request::new_with_str_and_init(&self.url(&path, query.as_ref()), &init)
+            .map_err(to_http_error)?;
+        let response: Response = JsFuture::from(fetch_with_request(&request))
+            .await
+            .map_err(to_http_error)?
+            .unchecked_into();
+
+        let status = response.status();
+        let response_headers = response_headers(&response.headers())?;
+        let buffer = JsFuture::from(response.array_buffer().map_err(to_http_error)?)
+            .await
+            .map_err(to_http_error)?;
+        let body = Uint8Array::new(&buffer).to_vec();
+
+        if !response.ok() {
+            return Err(HttpError::Status {
+                code: status,
+                body,
+                headers: response_headers,
+            });
+        }
+
+        Ok(HttpResponse {
+            status,
+            body,
+            headers: response_headers,
+        })
+    }
+}
+
+fn response_headers(headers: &Headers) -> Result<HashMap<String, String>, HttpError> {
+    let mut response_headers = HashMap::new();
+    for entry in headers.entries() {
+        let entry: Array = entry.map_err(to_http_error)?.unchecked_into();
+        if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+            response_headers.insert(name, value);
+        }
+    }
+    Ok(response_headers)
+}
+
+/// A rejection of `fetch` or an exception of the Web API, e.g. a `TypeError` of a failed
+/// connection or the `TimeoutError` of an expired timeout signal.
+fn to_http_error(error: JsValue) -> HttpError {
+    let name = Reflect::get(&error, &JsValue::from_str("name"))
+        .ok()
+        .and_then(|name| name.as_string());
+    let message = match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => error.as_string().unwrap_or_else(|| format!("{:?}", error)),
+    };
+    match name.as_deref() {
+        Some("TimeoutError") => HttpError::Timeout { message },
+        _ => HttpError::RequestError { message },
+    }
+}
@@ -5,7 +5,7 @@
 //! encoding 32 bytes of data and a 4-byte checksum.
 
 use crate::constants::Byte32;
-use crate::error::AlgoKitTransactError;
+use crate::error::{AddressError, AlgoKitTransactError};
 use crate::utils::{hash, pub_key_to_checksum};
 use crate::{
     ALGORAND_ADDRESS_LENGTH, ALGORAND_CHECKSUM_BYTE_LENGTH, ALGORAND_PUBLIC_KEY_BYTE_LENGTH,
@@ -60,39 +60,43 @@ impl FromStr for Address {
 
     /// Parses a 58-character base32 Algorand address string into an [`Address`] instance.
     ///
-    /// Returns an error if the string is not exactly 58 characters, is not valid base32,
-    /// or if the checksum does not match.
+    /// Returns an [`AddressError`] describing whether the string is not exactly 58 characters,
+    /// contains a character that isn't valid base32, or has a checksum that doesn't match.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != ALGORAND_ADDRESS_LENGTH {
-            return Err(AlgoKitTransactError::InvalidAddress {
-                message: "Algorand address must be exactly 58 characters".into(),
-            });
+        let length = s.chars().count();
+        if length != ALGORAND_ADDRESS_LENGTH {
+            return Err(AddressError::InvalidLength { length }.into());
         }
-        let decoded_address = base32::decode(base32::Alphabet::Rfc4648 { padding: false }, s)
-            .ok_or_else(|| AlgoKitTransactError::InvalidAddress {
-                message: "Invalid base32 encoding for Algorand address".into(),
-            })?;
+        if let Some((position, character)) = s
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !matches!(c, 'A'..='Z' | '2'..='7'))
+        {
+            return Err(AddressError::InvalidCharacter {
+                character,
+                position,
+            }
+            .into());
+        }
+        // All characters are in the base32 alphabet at this point, so decoding always succeeds
+        let decoded_address =
+            base32::decode(base32::Alphabet::Rfc4648 { padding: false }, s).unwrap_or_default();
 
         // Although this is called public key (and it actually is when the account is a `KeyPairAccount`),
         // it could be the digest of a hash when the address corresponds to a multisignature account or
         // logic signature account.
-        let pub_key: [u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH] = decoded_address
-            [..ALGORAND_PUBLIC_KEY_BYTE_LENGTH]
-            .try_into()
-            .map_err(|_| AlgoKitTransactError::InvalidAddress {
-                message: "Could not decode address into 32-byte public key".to_string(),
-            })?;
-        let checksum: [u8; ALGORAND_CHECKSUM_BYTE_LENGTH] = decoded_address
-            [ALGORAND_PUBLIC_KEY_BYTE_LENGTH..]
-            .try_into()
-            .map_err(|_| AlgoKitTransactError::InvalidAddress {
-                message: "Could not get 4-byte checksum from decoded address".to_string(),
-            })?;
-
-        if pub_key_to_checksum(&pub_key) != checksum {
-            return Err(AlgoKitTransactError::InvalidAddress {
-                message: "Checksum is invalid".to_string(),
-            });
+        let mut pub_key = [0u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH];
+        let mut checksum = [0u8; ALGORAND_CHECKSUM_BYTE_LENGTH];
+        pub_key.copy_from_slice(&decoded_address[..ALGORAND_PUBLIC_KEY_BYTE_LENGTH]);
+        checksum.copy_from_slice(&decoded_address[ALGORAND_PUBLIC_KEY_BYTE_LENGTH..]);
+
+        let expected = pub_key_to_checksum(&pub_key);
+        if expected != checksum {
+            return Err(AddressError::ChecksumMismatch {
+                expected,
+                actual: checksum,
+            }
+            .into());
         }
         Ok(Address(pub_key))
     }
@@ -119,4 +123,44 @@ mod tests {
             "WRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLRM"
         );
     }
+
+    fn parse_error(address: &str) -> AddressError {
+        match Address::from_str(address) {
+            Err(AlgoKitTransactError::InvalidAddress { source }) => source,
+            result => panic!("Expected an invalid address error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_diagnostics() {
+        assert_eq!(
+            parse_error("WRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLR"),
+            AddressError::InvalidLength { length: 57 }
+        );
+        assert_eq!(
+            parse_error("WRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLR1"),
+            AddressError::InvalidCharacter {
+                character: '1',
+                position: 57
+            }
+        );
+        assert_eq!(
+            parse_error("wRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLRM"),
+            AddressError::InvalidCharacter {
+                character: 'w',
+                position: 0
+            }
+        );
+
+        let expected = Address::from_app_id(&123).checksum();
+        let error = parse_error("WRBMNT66ECE2AOYKM76YVWIJMBW6Z3XCQZOKG5BL7NISAQC2LBGEKTZLRA");
+        assert!(matches!(
+            error,
+            AddressError::ChecksumMismatch { expected: e, actual } if e == expected && actual != expected
+        ));
+        assert!(error.to_string().starts_with(&format!(
+            "Algorand address checksum mismatch, expected {:02x}{:02x}{:02x}{:02x}",
+            expected[0], expected[1], expected[2], expected[3]
+        )));
+    }
 }
@@ -0,0 +1,589 @@
+//! Algorand block representation.
+//!
+//! This module provides the [`Block`] type, which decodes the msgpack encoded blocks of algod
+//! (`GET /v2/blocks/{round}?format=msgpack`) without going through JSON, keeping hashes, programs,
+//! logs and state keys as raw bytes. The transactions of a block reuse [`SignedTransaction`], with
+//! the apply data of their evaluation alongside.
+
+use crate::address::Address;
+use crate::constants::Byte32;
+use crate::error::AlgoKitTransactError;
+use crate::traits::{AlgorandMsgpack, TransactionId};
+use crate::transactions::SignedTransaction;
+use crate::utils::{is_empty_bytes32_opt, is_empty_string_opt, is_empty_vec_opt, is_zero_opt};
+use serde::{Deserialize, Serialize};
+use serde_with::{Bytes, serde_as};
+use std::collections::BTreeMap;
+
+/// A block: its header and the transactions it confirmed.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Block {
+    /// The round of the block.
+    #[serde(rename = "rnd")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub round: Option<u64>,
+
+    /// The hash of the previous block.
+    #[serde(rename = "prev")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub previous_block_hash: Option<Byte32>,
+
+    /// The sortition seed.
+    #[serde(rename = "seed")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub seed: Option<Byte32>,
+
+    /// The SHA-512/256 commitment to the transactions of the block.
+    #[serde(rename = "txn")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub transactions_root: Option<Byte32>,
+
+    /// The SHA-256 commitment to the transactions of the block.
+    #[serde(rename = "txn256")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub transactions_root_sha256: Option<Byte32>,
+
+    /// The time the block was proposed, in seconds since the epoch.
+    #[serde(rename = "ts")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+
+    /// The genesis ID of the network.
+    #[serde(rename = "gen")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub genesis_id: Option<String>,
+
+    /// The genesis hash of the network.
+    #[serde(rename = "gh")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub genesis_hash: Option<Byte32>,
+
+    /// The account which proposed the block.
+    #[serde(rename = "prp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub proposer: Option<Address>,
+
+    /// The fees collected by the transactions of the block, in microALGO.
+    #[serde(rename = "fc")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub fees_collected: Option<u64>,
+
+    /// The bonus paid to the proposer on top of the fees, in microALGO.
+    #[serde(rename = "bi")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub bonus: Option<u64>,
+
+    /// The payout to the proposer, in microALGO.
+    #[serde(rename = "pp")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub proposer_payout: Option<u64>,
+
+    /// The fee sink of the network.
+    #[serde(rename = "fees")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub fee_sink: Option<Address>,
+
+    /// The rewards pool of the network.
+    #[serde(rename = "rwd")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub rewards_pool: Option<Address>,
+
+    /// The number of rewards units earned by each full algo since genesis.
+    #[serde(rename = "earn")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub rewards_level: Option<u64>,
+
+    /// The number of new microALGO added to the rewards pool each round.
+    #[serde(rename = "rate")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub rewards_rate: Option<u64>,
+
+    /// The number of leftover microALGO after the distribution of rewards.
+    #[serde(rename = "frac")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub rewards_residue: Option<u64>,
+
+    /// The round at which the rewards rate is recalculated.
+    #[serde(rename = "rwcalr")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub rewards_calculation_round: Option<u64>,
+
+    /// The consensus protocol of the block.
+    #[serde(rename = "proto")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub current_protocol: Option<String>,
+
+    /// The next proposed protocol.
+    #[serde(rename = "nextproto")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub next_protocol: Option<String>,
+
+    /// The number of blocks which approved the next protocol.
+    #[serde(rename = "nextyes")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub next_protocol_approvals: Option<u64>,
+
+    /// The deadline round for the next protocol to be approved.
+    #[serde(rename = "nextbefore")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub next_protocol_vote_before: Option<u64>,
+
+    /// The round the next protocol is switched to, once approved.
+    #[serde(rename = "nextswitch")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub next_protocol_switch_on: Option<u64>,
+
+    /// The protocol upgrade proposed by the proposer.
+    #[serde(rename = "upgradeprop")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub upgrade_propose: Option<String>,
+
+    /// The delay of the proposed upgrade, in rounds.
+    #[serde(rename = "upgradedelay")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub upgrade_delay: Option<u64>,
+
+    /// Whether the proposer approves the pending upgrade.
+    #[serde(rename = "upgradeyes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub upgrade_approve: Option<bool>,
+
+    /// The number of transactions confirmed since genesis, including those of the block.
+    #[serde(rename = "tc")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub txn_counter: Option<u64>,
+
+    /// The accounts whose participation keys expired in the block.
+    #[serde(rename = "partupdrmv")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub expired_participation_accounts: Option<Vec<Address>>,
+
+    /// The accounts suspended for being absent from consensus in the block.
+    #[serde(rename = "partupdabs")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub absent_participation_accounts: Option<Vec<Address>>,
+
+    /// The transactions of the block, absent when only the header was requested.
+    #[serde(rename = "txns")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub transactions: Option<Vec<SignedTxnInBlock>>,
+}
+
+impl AlgorandMsgpack for Block {}
+
+impl Block {
+    /// The signed transactions of the block, with the genesis ID and hash the block omits from
+    /// them restored, e.g. to compute their IDs.
+    pub fn signed_transactions(&self) -> Vec<SignedTransaction> {
+        self.transactions
+            .iter()
+            .flatten()
+            .map(|txn| self.restore_genesis(txn))
+            .collect()
+    }
+
+    /// The transactions of the block with their IDs, in the order they were confirmed.
+    pub fn block_transactions(&self) -> Result<Vec<BlockTransaction>, AlgoKitTransactError> {
+        self.transactions
+            .iter()
+            .flatten()
+            .map(|txn| {
+                let signed_transaction = self.restore_genesis(txn);
+                Ok(BlockTransaction {
+                    id: signed_transaction.transaction.id()?,
+                    signed_transaction,
+                    apply_data: txn.apply_data.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn restore_genesis(&self, txn: &SignedTxnInBlock) -> SignedTransaction {
+        let mut signed_transaction = txn.signed_transaction.clone();
+        let header = signed_transaction.transaction.header_mut();
+        if txn.has_genesis_id {
+            header.genesis_id = self.genesis_id.clone();
+        }
+        // Protocols which require the genesis hash omit it without setting the flag
+        if txn.has_genesis_hash || header.genesis_hash.is_none() {
+            header.genesis_hash = self.genesis_hash;
+        }
+        signed_transaction
+    }
+}
+
+/// A transaction of a block, with the genesis restored and its ID.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BlockTransaction {
+    /// The ID of the transaction.
+    pub id: String,
+    pub signed_transaction: SignedTransaction,
+    pub apply_data: ApplyData,
+}
+
+/// A signed transaction as stored in a block: the genesis ID and hash are omitted, and flagged
+/// so they can be restored from the block header.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SignedTxnInBlock {
+    #[serde(flatten)]
+    pub signed_transaction: SignedTransaction,
+
+    #[serde(flatten)]
+    pub apply_data: ApplyData,
+
+    /// Whether the transaction had the genesis ID of the block.
+    #[serde(rename = "hgi")]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[serde(default)]
+    pub has_genesis_id: bool,
+
+    /// Whether the transaction had the genesis hash of the block.
+    #[serde(rename = "hgh")]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[serde(default)]
+    pub has_genesis_hash: bool,
+}
+
+/// A signed inner transaction, with the apply data of its evaluation.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SignedTxnWithApplyData {
+    #[serde(flatten)]
+    pub signed_transaction: SignedTransaction,
+
+    #[serde(flatten)]
+    pub apply_data: ApplyData,
+}
+
+/// The changes of applying a transaction to the ledger.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ApplyData {
+    /// The amount closed to the close remainder to account, in microALGO.
+    #[serde(rename = "ca")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub closing_amount: Option<u64>,
+
+    /// The amount of the asset closed to the close to account.
+    #[serde(rename = "aca")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub asset_closing_amount: Option<u64>,
+
+    /// The rewards applied to the sender, in microALGO.
+    #[serde(rename = "rs")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub sender_rewards: Option<u64>,
+
+    /// The rewards applied to the receiver, in microALGO.
+    #[serde(rename = "rr")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub receiver_rewards: Option<u64>,
+
+    /// The rewards applied to the close remainder to account, in microALGO.
+    #[serde(rename = "rc")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub close_rewards: Option<u64>,
+
+    /// The state changes, logs and inner transactions of an app call.
+    #[serde(rename = "dt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub eval_delta: Option<EvalDelta>,
+
+    /// The ID of the asset created by the transaction.
+    #[serde(rename = "caid")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub config_asset: Option<u64>,
+
+    /// The ID of the app created by the transaction.
+    #[serde(rename = "apid")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub application_id: Option<u64>,
+}
+
+/// The effects of evaluating an app call.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct EvalDelta {
+    /// The changes to the global state, by key.
+    #[serde(rename = "gd")]
+    #[serde_as(as = "Option<BTreeMap<Bytes, _>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub global_delta: Option<BTreeMap<Vec<u8>, ValueDelta>>,
+
+    /// The changes to local states, by the index of the account in the accounts of the call and
+    /// then by key.
+    #[serde(rename = "ld")]
+    #[serde_as(as = "Option<BTreeMap<_, BTreeMap<Bytes, _>>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub local_deltas: Option<BTreeMap<u64, BTreeMap<Vec<u8>, ValueDelta>>>,
+
+    /// The accounts referenced by the local deltas which aren't in the accounts of the call.
+    #[serde(rename = "sa")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub shared_accounts: Option<Vec<Address>>,
+
+    /// The logs of the call.
+    #[serde(rename = "lg")]
+    #[serde_as(as = "Option<Vec<Bytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub logs: Option<Vec<Vec<u8>>>,
+
+    /// The inner transactions of the call.
+    #[serde(rename = "itx")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub inner_transactions: Option<Vec<SignedTxnWithApplyData>>,
+}
+
+/// A change of a key of an app state.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ValueDelta {
+    /// The action: 1 sets bytes, 2 sets a uint and 3 deletes the key.
+    #[serde(rename = "at")]
+    #[serde(default)]
+    pub action: u64,
+
+    /// The bytes value set.
+    #[serde(rename = "bs")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+
+    /// The uint value set.
+    #[serde(rename = "ui")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub uint: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PaymentTransactionFields, Transaction, TransactionHeader};
+    use rmpv::Value;
+
+    const GENESIS_ID: &str = "testnet-v1.0";
+    const GENESIS_HASH: Byte32 = [7; 32];
+
+    fn payment() -> Transaction {
+        Transaction::Payment(PaymentTransactionFields {
+            header: TransactionHeader {
+                sender: Address([1; 32]),
+                fee: Some(1000),
+                first_valid: 1,
+                last_valid: 1001,
+                genesis_hash: Some(GENESIS_HASH),
+                genesis_id: Some(GENESIS_ID.to_string()),
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            receiver: Address([2; 32]),
+            amount: 1_000_000,
+            close_remainder_to: None,
+        })
+    }
+
+    fn map(entries: Vec<(&str, Value)>) -> Value {
+        Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (Value::from(key), value))
+                .collect(),
+        )
+    }
+
+    /// A block as algod encodes it, with the genesis of its transactions omitted.
+    fn encoded_block() -> Vec<u8> {
+        let txn = map(vec![
+            ("amt", Value::from(1_000_000)),
+            ("fee", Value::from(1000)),
+            ("fv", Value::from(1)),
+            ("lv", Value::from(1001)),
+            ("rcv", Value::Binary(vec![2; 32])),
+            ("snd", Value::Binary(vec![1; 32])),
+            ("type", Value::from("pay")),
+        ]);
+        let eval_delta = map(vec![
+            (
+                "gd",
+                Value::Map(vec![(
+                    Value::Binary(vec![0xff, 0x00]),
+                    map(vec![("at", Value::from(2)), ("ui", Value::from(5))]),
+                )]),
+            ),
+            ("lg", Value::Array(vec![Value::Binary(vec![0xde, 0xad])])),
+        ]);
+        let block = map(vec![
+            ("gen", Value::from(GENESIS_ID)),
+            ("gh", Value::Binary(GENESIS_HASH.to_vec())),
+            ("prev", Value::Binary(vec![3; 32])),
+            ("proto", Value::from("future")),
+            ("rnd", Value::from(42)),
+            ("tc", Value::from(1000)),
+            ("ts", Value::from(1_700_000_000)),
+            (
+                "txns",
+                Value::Array(vec![map(vec![
+                    ("dt", eval_delta),
+                    ("hgi", Value::from(true)),
+                    ("sig", Value::Binary(vec![9; 64])),
+                    ("txn", txn),
+                ])]),
+            ),
+        ]);
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, &block).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_decodes_block() {
+        let block = Block::decode(&encoded_block()).unwrap();
+
+        assert_eq!(block.round, Some(42));
+        assert_eq!(block.previous_block_hash, Some([3; 32]));
+        assert_eq!(block.current_protocol.as_deref(), Some("future"));
+        assert_eq!(block.txn_counter, Some(1000));
+
+        let transactions = block.transactions.as_ref().unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert!(transactions[0].has_genesis_id);
+        assert!(!transactions[0].has_genesis_hash);
+        assert_eq!(transactions[0].signed_transaction.signature, Some([9; 64]));
+        assert_eq!(
+            transactions[0]
+                .signed_transaction
+                .transaction
+                .header()
+                .genesis_hash,
+            None
+        );
+
+        let eval_delta = transactions[0].apply_data.eval_delta.as_ref().unwrap();
+        assert_eq!(
+            eval_delta.global_delta.as_ref().unwrap()[&vec![0xff, 0x00]],
+            ValueDelta {
+                action: 2,
+                bytes: None,
+                uint: Some(5),
+            }
+        );
+        assert_eq!(eval_delta.logs, Some(vec![vec![0xde, 0xad]]));
+    }
+
+    #[test]
+    fn test_decodes_state_keys_which_are_not_utf8() {
+        // Algod encodes state keys as msgpack strings of their raw bytes
+        let mut bytes = encoded_block();
+        let key = bytes
+            .windows(4)
+            .position(|window| window == [0xc4, 0x02, 0xff, 0x00])
+            .unwrap();
+        bytes.splice(key..key + 4, [0xa2, 0xff, 0x00]);
+
+        let block = Block::decode(&bytes).unwrap();
+
+        let eval_delta = block.transactions.unwrap()[0]
+            .apply_data
+            .eval_delta
+            .clone()
+            .unwrap();
+        assert!(
+            eval_delta
+                .global_delta
+                .unwrap()
+                .contains_key(&vec![0xff, 0x00])
+        );
+    }
+
+    #[test]
+    fn test_signed_transactions_restore_the_genesis() {
+        let block = Block::decode(&encoded_block()).unwrap();
+
+        let signed_transactions = block.signed_transactions();
+
+        assert_eq!(signed_transactions[0].transaction, payment());
+        assert_eq!(
+            signed_transactions[0].transaction.id().unwrap(),
+            payment().id().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_block_transactions_have_ids() {
+        let block = Block::decode(&encoded_block()).unwrap();
+
+        let block_transactions = block.block_transactions().unwrap();
+
+        assert_eq!(block_transactions.len(), 1);
+        assert_eq!(block_transactions[0].id, payment().id().unwrap());
+        assert_eq!(
+            block_transactions[0].signed_transaction.transaction,
+            payment()
+        );
+        assert!(block_transactions[0].apply_data.eval_delta.is_some());
+    }
+
+    #[test]
+    fn test_header_only_block_has_no_transactions() {
+        let header = map(vec![("rnd", Value::from(7))]);
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, &header).unwrap();
+
+        let block = Block::decode(&bytes).unwrap();
+
+        assert_eq!(block.round, Some(7));
+        assert!(block.signed_transactions().is_empty());
+    }
+}
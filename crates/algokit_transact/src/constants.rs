@@ -9,6 +9,7 @@ pub type Byte32 = [u8; 32];
 pub const MAX_TX_GROUP_SIZE: usize = 16;
 
 pub const MULTISIG_DOMAIN_SEPARATOR: &str = "MultisigAddr";
+pub const LOGIC_SIGNATURE_DOMAIN_SEPARATOR: &str = "Program";
 pub const EMPTY_SIGNATURE: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH] =
     [0; ALGORAND_SIGNATURE_BYTE_LENGTH];
 
@@ -24,6 +25,7 @@ pub const MAX_ACCOUNT_REFERENCES: usize = 4;
 pub const MAX_APP_REFERENCES: usize = 8;
 pub const MAX_ASSET_REFERENCES: usize = 8;
 pub const MAX_BOX_REFERENCES: usize = 8;
+pub const MAX_ACCESS_REFERENCES: usize = 16;
 
 // Application state schema limits
 pub const MAX_GLOBAL_STATE_KEYS: u32 = 64;
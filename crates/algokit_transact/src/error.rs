@@ -4,7 +4,9 @@
 //! transaction processing, including encoding/decoding errors, validation errors,
 //! and other transaction-related failures.
 
+use crate::constants::{ALGORAND_ADDRESS_LENGTH, ALGORAND_CHECKSUM_BYTE_LENGTH};
 use snafu::Snafu;
+use std::fmt::Write;
 
 /// Represents errors that can occur during Algorand transaction operations.
 ///
@@ -30,13 +32,54 @@ pub enum AlgoKitTransactError {
     #[snafu(display("{message}"))]
     InputError { message: String },
 
-    #[snafu(display("{message}"))]
-    InvalidAddress { message: String },
+    #[snafu(display("{source}"))]
+    InvalidAddress { source: AddressError },
 
     #[snafu(display("Invalid multisig signature: {message}"))]
     InvalidMultisigSignature { message: String },
 }
 
+/// Describes why an Algorand address string could not be parsed.
+///
+/// The variants carry enough detail for wallets to show users exactly what is wrong with an
+/// address they entered.
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+pub enum AddressError {
+    #[snafu(display(
+        "Algorand address must be exactly {} characters, got {length}",
+        ALGORAND_ADDRESS_LENGTH
+    ))]
+    InvalidLength { length: usize },
+
+    #[snafu(display(
+        "Invalid base32 character {character:?} at position {position} of Algorand address"
+    ))]
+    InvalidCharacter { character: char, position: usize },
+
+    #[snafu(display(
+        "Algorand address checksum mismatch, expected {} but got {}",
+        to_hex(expected),
+        to_hex(actual)
+    ))]
+    ChecksumMismatch {
+        expected: [u8; ALGORAND_CHECKSUM_BYTE_LENGTH],
+        actual: [u8; ALGORAND_CHECKSUM_BYTE_LENGTH],
+    },
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+impl From<AddressError> for AlgoKitTransactError {
+    fn from(source: AddressError) -> Self {
+        AlgoKitTransactError::InvalidAddress { source }
+    }
+}
+
 impl From<rmp_serde::encode::Error> for AlgoKitTransactError {
     fn from(source: rmp_serde::encode::Error) -> Self {
         AlgoKitTransactError::EncodingError { source }
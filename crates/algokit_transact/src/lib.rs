@@ -1,30 +1,39 @@
 mod address;
+mod block;
 pub mod constants;
 mod error;
 mod keypair_account;
+mod logic_signature;
 pub mod multisig;
+mod secret_key;
 mod traits;
 mod transactions;
 mod utils;
 
 // Re-export all the public items
 pub use address::Address;
+pub use block::{
+    ApplyData, Block, BlockTransaction, EvalDelta, SignedTxnInBlock, SignedTxnWithApplyData,
+    ValueDelta,
+};
 pub use constants::*;
-pub use error::AlgoKitTransactError;
+pub use error::{AddressError, AlgoKitTransactError};
 pub use keypair_account::KeyPairAccount;
+pub use logic_signature::LogicSignature;
 pub use multisig::*;
+pub use secret_key::SecretKey;
 pub use traits::{AlgorandMsgpack, EstimateTransactionSize, TransactionId, Transactions, Validate};
 pub use transactions::{
-    AppCallTransactionBuilder, AppCallTransactionFields, AssetConfigTransactionBuilder,
-    AssetConfigTransactionFields, AssetFreezeTransactionBuilder, AssetFreezeTransactionFields,
-    AssetTransferTransactionBuilder, AssetTransferTransactionFields, BoxReference,
-    FalconSignatureStruct, FalconVerifier, FeeParams, HashFactory, HeartbeatProof,
+    AccessReference, AppCallTransactionBuilder, AppCallTransactionFields,
+    AssetConfigTransactionBuilder, AssetConfigTransactionFields, AssetFreezeTransactionBuilder,
+    AssetFreezeTransactionFields, AssetTransferTransactionBuilder, AssetTransferTransactionFields,
+    BoxReference, FalconSignatureStruct, FalconVerifier, FeeParams, HashFactory, HeartbeatProof,
     HeartbeatProofBuilder, HeartbeatTransactionBuilder, HeartbeatTransactionFields,
-    KeyRegistrationTransactionBuilder, KeyRegistrationTransactionFields, MerkleArrayProof,
-    MerkleSignatureVerifier, OnApplicationComplete, Participant, PaymentTransactionBuilder,
-    PaymentTransactionFields, Reveal, SignedTransaction, SigslotCommit, StateProof,
-    StateProofMessage, StateProofTransactionBuilder, StateProofTransactionFields, StateSchema,
-    Transaction, TransactionHeader, TransactionHeaderBuilder,
+    HoldingReference, KeyRegistrationTransactionBuilder, KeyRegistrationTransactionFields,
+    LocalsReference, MerkleArrayProof, MerkleSignatureVerifier, OnApplicationComplete, Participant,
+    PaymentTransactionBuilder, PaymentTransactionFields, Reveal, SignedTransaction, SigslotCommit,
+    StateProof, StateProofMessage, StateProofTransactionBuilder, StateProofTransactionFields,
+    StateSchema, Transaction, TransactionHeader, TransactionHeaderBuilder,
 };
 
 #[cfg(feature = "test_utils")]
@@ -0,0 +1,105 @@
+//! Algorand logic signature representation.
+//!
+//! This module provides the [`LogicSignature`] type, which authorizes a transaction with a TEAL
+//! program rather than a key. A logic signature is either used as an escrow, where the sender is
+//! the address of the program itself, or delegated, where an account signs the program so that
+//! it may authorize transactions on the account's behalf.
+
+use crate::address::Address;
+use crate::utils::hash;
+use crate::{ALGORAND_SIGNATURE_BYTE_LENGTH, LOGIC_SIGNATURE_DOMAIN_SEPARATOR, MultisigSignature};
+use serde::{Deserialize, Serialize};
+use serde_with::{Bytes, serde_as};
+
+/// A logic signature: a compiled TEAL program, the arguments it is evaluated with and, when
+/// delegated, the signature of the delegating account over the program.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct LogicSignature {
+    /// The compiled program.
+    #[serde(rename = "l")]
+    #[serde_as(as = "Bytes")]
+    pub logic: Vec<u8>,
+
+    /// The arguments the program is evaluated with.
+    #[serde(rename = "arg")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<Vec<Bytes>>")]
+    #[serde(default)]
+    pub args: Option<Vec<Vec<u8>>>,
+
+    /// The signature of a delegating account over the program.
+    #[serde(rename = "sig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(default)]
+    pub signature: Option<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH]>,
+
+    /// The multisignature of a delegating multisig account over the program.
+    #[serde(rename = "msig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub multisignature: Option<MultisigSignature>,
+}
+
+impl LogicSignature {
+    /// Creates a new, not delegated, logic signature.
+    pub fn new(logic: Vec<u8>, args: Option<Vec<Vec<u8>>>) -> Self {
+        Self {
+            logic,
+            args,
+            signature: None,
+            multisignature: None,
+        }
+    }
+
+    /// Returns the bytes a delegating account signs: the domain separator followed by the program.
+    pub fn bytes_to_sign(&self) -> Vec<u8> {
+        let mut buffer =
+            Vec::with_capacity(LOGIC_SIGNATURE_DOMAIN_SEPARATOR.len() + self.logic.len());
+        buffer.extend_from_slice(LOGIC_SIGNATURE_DOMAIN_SEPARATOR.as_bytes());
+        buffer.extend_from_slice(&self.logic);
+        buffer
+    }
+
+    /// Returns the escrow address of the program, i.e. the hash of the program with the domain
+    /// separator.
+    pub fn address(&self) -> Address {
+        Address(hash(&self.bytes_to_sign()))
+    }
+
+    /// Returns whether the logic signature has been delegated by an account.
+    pub fn is_delegated(&self) -> bool {
+        self.signature.is_some() || self.multisignature.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlgorandMsgpack;
+
+    impl AlgorandMsgpack for LogicSignature {}
+
+    #[test]
+    fn test_address() {
+        // `#pragma version 2; int 1`
+        let logic_signature = LogicSignature::new(vec![0x02, 0x20, 0x01, 0x01, 0x22], None);
+        assert_eq!(
+            logic_signature.address().to_string(),
+            "YOE6C22GHCTKAN3HU4SE5PGIPN5UKXAJTXCQUPJ3KKF5HOAH646MKKCPDA"
+        );
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let logic_signature = LogicSignature {
+            signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+            ..LogicSignature::new(vec![0x06, 0x81, 0x01], Some(vec![vec![1, 2], vec![3]]))
+        };
+
+        let encoded = logic_signature.encode().unwrap();
+        assert_eq!(LogicSignature::decode(&encoded).unwrap(), logic_signature);
+        assert!(logic_signature.is_delegated());
+    }
+}
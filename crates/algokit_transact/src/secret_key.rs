@@ -0,0 +1,121 @@
+//! Secret key material for ed25519 keypair accounts.
+//!
+//! This module provides the [`SecretKey`] type, which wraps the 32-byte ed25519 seed a keypair
+//! account's signing key is derived from, so it isn't passed around as a plain byte array.
+
+use crate::constants::ALGORAND_SECRET_KEY_BYTE_LENGTH;
+use crate::error::AlgoKitTransactError;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The 32-byte ed25519 seed of a keypair account.
+///
+/// The bytes are zeroized when the key is dropped, compared in constant time and never included
+/// in the [`Debug`] output.
+#[derive(Clone)]
+pub struct SecretKey([u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]);
+
+impl SecretKey {
+    /// Creates a new [`SecretKey`] from a 32-byte ed25519 seed.
+    pub fn from_bytes(bytes: [u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]) -> Self {
+        SecretKey(bytes)
+    }
+
+    /// Returns the seed bytes.
+    ///
+    /// Avoid copying the returned bytes, as copies aren't zeroized.
+    pub fn as_bytes(&self) -> &[u8; ALGORAND_SECRET_KEY_BYTE_LENGTH] {
+        &self.0
+    }
+}
+
+impl From<[u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]> for SecretKey {
+    fn from(bytes: [u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]) -> Self {
+        SecretKey::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = AlgoKitTransactError;
+
+    /// Creates a [`SecretKey`] from a 32-byte seed, or a 64-byte secret key of which the first 32
+    /// bytes are the seed (followed by the public key).
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let seed = match bytes.len() {
+            ALGORAND_SECRET_KEY_BYTE_LENGTH => bytes,
+            len if len == ALGORAND_SECRET_KEY_BYTE_LENGTH * 2 => {
+                &bytes[..ALGORAND_SECRET_KEY_BYTE_LENGTH]
+            }
+            len => {
+                return Err(AlgoKitTransactError::InputError {
+                    message: format!(
+                        "Secret key must be {} or {} bytes, got {}",
+                        ALGORAND_SECRET_KEY_BYTE_LENGTH,
+                        ALGORAND_SECRET_KEY_BYTE_LENGTH * 2,
+                        len
+                    ),
+                });
+            }
+        };
+
+        let mut key = SecretKey([0u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]);
+        key.0.copy_from_slice(seed);
+        Ok(key)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "SecretKey(..)")
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_key_from_slice() {
+        let seed = [7u8; ALGORAND_SECRET_KEY_BYTE_LENGTH];
+        let mut secret_key = seed.to_vec();
+        secret_key.extend_from_slice(&[9u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]);
+
+        assert_eq!(
+            SecretKey::try_from(&seed[..]).unwrap(),
+            SecretKey::from(seed)
+        );
+        assert_eq!(
+            SecretKey::try_from(&secret_key[..]).unwrap(),
+            SecretKey::from(seed)
+        );
+        assert_ne!(
+            SecretKey::try_from(&secret_key[ALGORAND_SECRET_KEY_BYTE_LENGTH..]).unwrap(),
+            SecretKey::from(seed)
+        );
+        assert!(SecretKey::try_from(&seed[1..]).is_err());
+    }
+
+    #[test]
+    fn test_secret_key_debug_redacts_bytes() {
+        let secret_key = SecretKey::from([1u8; ALGORAND_SECRET_KEY_BYTE_LENGTH]);
+
+        assert_eq!(format!("{:?}", secret_key), "SecretKey(..)");
+    }
+}
@@ -7,8 +7,8 @@ mod state_proof;
 
 use crate::{
     ALGORAND_PUBLIC_KEY_BYTE_LENGTH, Address, AlgorandMsgpack, Byte32, EMPTY_SIGNATURE,
-    HASH_BYTES_LENGTH, KeyPairAccount, MultisigSignature, MultisigSubsignature, SignedTransaction,
-    Transaction, TransactionHeaderBuilder, TransactionId,
+    HASH_BYTES_LENGTH, KeyPairAccount, MultisigSignature, MultisigSubsignature, SecretKey,
+    SignedTransaction, Transaction, TransactionHeaderBuilder, TransactionId,
     test_utils::state_proof::StateProofTransactionMother,
     transactions::{AssetTransferTransactionBuilder, PaymentTransactionBuilder},
 };
@@ -232,11 +232,15 @@ impl AccountMother {
     }
 }
 
-const SIGNING_PRIVATE_KEY: Byte32 = [
+const SIGNING_PRIVATE_KEY_BYTES: Byte32 = [
     2, 205, 103, 33, 67, 14, 82, 196, 115, 196, 206, 254, 50, 110, 63, 182, 149, 229, 184, 216, 93,
     11, 13, 99, 69, 213, 218, 165, 134, 118, 47, 44,
 ];
 
+fn signing_private_key() -> SecretKey {
+    SecretKey::from(SIGNING_PRIVATE_KEY_BYTES)
+}
+
 pub struct TransactionGroupMother {}
 impl TransactionGroupMother {
     pub fn testnet_payment_group() -> Vec<Transaction> {
@@ -309,6 +313,7 @@ pub struct TransactionTestData {
     pub id: String,
     pub id_raw: Byte32,
     pub unsigned_bytes: Vec<u8>,
+    /// The seed the transaction is signed with, exported as plain bytes for the test vectors
     pub signing_private_key: Byte32,
     pub signed_bytes: Vec<u8>,
     pub rekeyed_sender_auth_address: Address,
@@ -318,8 +323,8 @@ pub struct TransactionTestData {
 }
 
 impl TransactionTestData {
-    pub fn new(transaction: Transaction, signing_private_key: Byte32) -> Self {
-        let signing_key: SigningKey = SigningKey::from_bytes(&signing_private_key);
+    pub fn new(transaction: Transaction, signing_private_key: &SecretKey) -> Self {
+        let signing_key: SigningKey = SigningKey::from_bytes(signing_private_key.as_bytes());
         let id: String = transaction.id().unwrap();
         let id_raw: [u8; HASH_BYTES_LENGTH] = transaction.id_raw().unwrap();
         let unsigned_bytes = transaction.encode().unwrap();
@@ -329,6 +334,7 @@ impl TransactionTestData {
             signature: Some(signature.to_bytes()),
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         };
         let signed_bytes = signed_txn.encode().unwrap();
 
@@ -340,6 +346,7 @@ impl TransactionTestData {
             signature: Some(signature.to_bytes()),
             auth_address: Some(rekeyed_sender_auth_address.clone()),
             multisignature: None,
+            logic_signature: None,
         };
         let rekeyed_sender_signed_bytes = signer_signed_txn.encode().unwrap();
 
@@ -370,6 +377,7 @@ impl TransactionTestData {
             signature: None,
             auth_address: None,
             multisignature: Some(multisig_signature),
+            logic_signature: None,
         };
         let multisig_signed_bytes = multisig_signed_txn.encode().unwrap();
 
@@ -378,7 +386,7 @@ impl TransactionTestData {
             id,
             id_raw,
             unsigned_bytes,
-            signing_private_key,
+            signing_private_key: *signing_private_key.as_bytes(),
             signed_bytes,
             rekeyed_sender_auth_address,
             rekeyed_sender_signed_bytes,
@@ -404,103 +412,103 @@ pub struct TestDataMother {}
 impl TestDataMother {
     pub fn simple_payment() -> TransactionTestData {
         let transaction = TransactionMother::simple_payment().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn simple_asset_transfer() -> TransactionTestData {
         let transaction = TransactionMother::simple_asset_transfer().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn opt_in_asset_transfer() -> TransactionTestData {
         let transaction = TransactionMother::opt_in_asset_transfer().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn app_create() -> TransactionTestData {
         let transaction = AppCallTransactionMother::app_create().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn app_update() -> TransactionTestData {
         let transaction = AppCallTransactionMother::app_update().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn app_delete() -> TransactionTestData {
         let transaction = AppCallTransactionMother::app_delete().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn app_call() -> TransactionTestData {
         let transaction = AppCallTransactionMother::app_call().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn asset_create() -> TransactionTestData {
         let transaction = AssetConfigTransactionMother::asset_create()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn asset_destroy() -> TransactionTestData {
         let transaction = AssetConfigTransactionMother::asset_destroy()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn asset_config() -> TransactionTestData {
         let transaction = AssetConfigTransactionMother::asset_config()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn online_key_registration() -> TransactionTestData {
         let transaction = KeyRegistrationTransactionMother::online_key_registration()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn offline_key_registration() -> TransactionTestData {
         let transaction = KeyRegistrationTransactionMother::offline_key_registration()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn non_participation_key_registration() -> TransactionTestData {
         let transaction = KeyRegistrationTransactionMother::non_participation_key_registration()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn heartbeat() -> TransactionTestData {
         let transaction = HeartbeatTransactionMother::heartbeat().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn state_proof() -> TransactionTestData {
         let transaction = StateProofTransactionMother::state_proof().build().unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn asset_freeze() -> TransactionTestData {
         let transaction = AssetFreezeTransactionMother::asset_freeze()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn asset_unfreeze() -> TransactionTestData {
         let transaction = AssetFreezeTransactionMother::asset_unfreeze()
             .build()
             .unwrap();
-        TransactionTestData::new(transaction, SIGNING_PRIVATE_KEY)
+        TransactionTestData::new(transaction, &signing_private_key())
     }
 
     pub fn export<F, T>(path: &std::path::Path, transform: Option<F>)
@@ -577,6 +585,7 @@ pub fn check_transaction_encoding(tx: &Transaction, expected_encoded_len: usize)
         signature: Some(EMPTY_SIGNATURE),
         auth_address: None,
         multisignature: None,
+        logic_signature: None,
     };
     let encoded_stx = signed_tx.encode().unwrap();
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -601,6 +610,7 @@ pub fn check_signed_transaction_encoding(
         signature: Some(EMPTY_SIGNATURE),
         auth_address: auth_account.map(|acc| acc.address()),
         multisignature: None,
+        logic_signature: None,
     };
     let encoded_stx = signed_tx.encode().unwrap();
     assert_eq!(encoded_stx.len(), expected_encoded_len);
@@ -630,6 +640,7 @@ pub fn check_multisigned_transaction_encoding(tx: &Transaction, expected_encoded
         signature: None,
         auth_address: None,
         multisignature,
+        logic_signature: None,
     };
     let encoded_stx = signed_tx.encode().unwrap();
     assert_eq!(encoded_stx.len(), expected_encoded_len);
@@ -643,6 +654,7 @@ pub fn check_transaction_id(tx: &Transaction, expected_tx_id: &str) {
         signature: Some(EMPTY_SIGNATURE),
         auth_address: None,
         multisignature: None,
+        logic_signature: None,
     };
 
     assert_eq!(tx.id().unwrap(), expected_tx_id);
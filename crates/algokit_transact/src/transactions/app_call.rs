@@ -7,14 +7,16 @@ use crate::traits::{MsgPackEmpty, Validate};
 use crate::transactions::common::{TransactionHeader, TransactionValidationError};
 use crate::utils::{is_empty_struct_opt, is_empty_vec_opt, is_zero, is_zero_opt};
 use crate::{
-    Address, MAX_ACCOUNT_REFERENCES, MAX_APP_ARGS, MAX_APP_REFERENCES, MAX_ARGS_SIZE,
-    MAX_ASSET_REFERENCES, MAX_BOX_REFERENCES, MAX_EXTRA_PROGRAM_PAGES, MAX_GLOBAL_STATE_KEYS,
-    MAX_LOCAL_STATE_KEYS, MAX_OVERALL_REFERENCES, PROGRAM_PAGE_SIZE, Transaction,
+    Address, MAX_ACCESS_REFERENCES, MAX_ACCOUNT_REFERENCES, MAX_APP_ARGS, MAX_APP_REFERENCES,
+    MAX_ARGS_SIZE, MAX_ASSET_REFERENCES, MAX_BOX_REFERENCES, MAX_EXTRA_PROGRAM_PAGES,
+    MAX_GLOBAL_STATE_KEYS, MAX_LOCAL_STATE_KEYS, MAX_OVERALL_REFERENCES, PROGRAM_PAGE_SIZE,
+    Transaction,
 };
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::{Bytes, serde_as, skip_serializing_none};
+use std::borrow::Cow;
 
 // Field name constants for validation error messages
 const FIELD_APPROVAL_PROGRAM: &str = "Approval program";
@@ -66,7 +68,7 @@ pub enum OnApplicationComplete {
 /// key/value storage for both global and local state.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
 pub struct StateSchema {
     /// Maximum number of integer values that may be stored.
     #[serde(rename = "nui")]
@@ -110,6 +112,106 @@ pub struct BoxReference {
     pub name: Vec<u8>,
 }
 
+/// A resource in the access list of an app call, which names the resources available to the
+/// program in place of the account, app, asset and box references.
+///
+/// The account and asset of a holding, the account and app of a local state and the app of a box
+/// must be in the same access list, unless they are the sender or the called app.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum AccessReference {
+    /// An account.
+    Address(Address),
+    /// An asset.
+    Asset(u64),
+    /// An app.
+    App(u64),
+    /// The holding of an asset by an account.
+    Holding(HoldingReference),
+    /// The local state of an account in an app.
+    Locals(LocalsReference),
+    /// A box. A box reference with an empty name and app ID 0 only adds to the box I/O budget.
+    Box(BoxReference),
+}
+
+/// References the holding of an asset by an account.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct HoldingReference {
+    /// Address of the account.
+    pub address: Address,
+    /// ID of the asset.
+    pub asset_id: u64,
+}
+
+/// References the local state of an account in an app.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LocalsReference {
+    /// Address of the account.
+    pub address: Address,
+    /// ID of the app.
+    /// A value of 0 indicates the current app.
+    pub app_id: u64,
+}
+
+/// The wire format of an access list entry, where the accounts, assets and apps of holdings,
+/// local states and boxes are 1-based positions in the access list.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct ResourceReference {
+    #[serde(rename = "d")]
+    address: Option<Address>,
+    #[serde(rename = "s")]
+    asset_id: Option<u64>,
+    #[serde(rename = "p")]
+    app_id: Option<u64>,
+    #[serde(rename = "h")]
+    holding: Option<HoldingResourceReference>,
+    #[serde(rename = "l")]
+    locals: Option<LocalsResourceReference>,
+    #[serde(rename = "b")]
+    box_reference: Option<BoxReference>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct HoldingResourceReference {
+    /// A value of 0 indicates the sender.
+    #[serde(rename = "d")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    address: u64,
+    #[serde(rename = "s")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    asset_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LocalsResourceReference {
+    /// A value of 0 indicates the sender.
+    #[serde(rename = "d")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    address: u64,
+    /// A value of 0 indicates the current app.
+    #[serde(rename = "p")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    app_id: u64,
+}
+
+/// The wire format of app call transactions, which adds the access list to the app call fields.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+struct AppCallWireFields<'a> {
+    #[serde(flatten)]
+    fields: Cow<'a, AppCallTransactionFields>,
+
+    #[serde(rename = "al")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    access_references: Option<Vec<ResourceReference>>,
+}
+
 /// Represents an app call transaction that interacts with Algorand Smart Contracts.
 ///
 /// App call transactions are used to create, update, delete, opt-in to,
@@ -238,6 +340,12 @@ pub struct AppCallTransactionFields {
     #[serde(default)]
     #[builder(default)]
     pub box_references: Option<Vec<BoxReference>>,
+
+    /// The resources available to the program, in place of the account, app, asset and box
+    /// references. Only supported by protocols with access lists.
+    #[serde(skip)]
+    #[builder(default)]
+    pub access_references: Option<Vec<AccessReference>>,
 }
 
 fn is_default_on_complete(on_complete: &OnApplicationComplete) -> bool {
@@ -246,8 +354,9 @@ fn is_default_on_complete(on_complete: &OnApplicationComplete) -> bool {
 
 /// Custom serializer for app call transactions.
 ///
-/// This serializer handles the special case of box references, where app IDs need to be
-/// transformed from actual app IDs to positional indices for wire format compatibility.
+/// This serializer handles the special case of box references and access references, where app
+/// IDs and other resources need to be transformed to positional indices for wire format
+/// compatibility.
 pub fn app_call_serializer<S>(
     fields: &AppCallTransactionFields,
     serializer: S,
@@ -255,6 +364,13 @@ pub fn app_call_serializer<S>(
 where
     S: serde::Serializer,
 {
+    let access_references = fields
+        .access_references
+        .as_ref()
+        .map(|access_references| encode_access_references(fields, access_references))
+        .transpose()
+        .map_err(serde::ser::Error::custom)?;
+
     // Transform box references if present
     if let Some(ref box_references) = fields.box_references {
         if !box_references.is_empty() {
@@ -292,12 +408,19 @@ where
             let mut fields: AppCallTransactionFields = fields.clone();
             fields.box_references = Some(box_references);
 
-            return fields.serialize(serializer);
+            return AppCallWireFields {
+                fields: Cow::Owned(fields),
+                access_references,
+            }
+            .serialize(serializer);
         }
     }
 
-    // No transformation needed, serialize directly
-    fields.serialize(serializer)
+    AppCallWireFields {
+        fields: Cow::Borrowed(fields),
+        access_references,
+    }
+    .serialize(serializer)
 }
 
 /// Custom deserializer for app call transactions.
@@ -308,7 +431,13 @@ pub fn app_call_deserializer<'de, D>(deserializer: D) -> Result<AppCallTransacti
 where
     D: serde::Deserializer<'de>,
 {
-    let mut fields = AppCallTransactionFields::deserialize(deserializer)?;
+    let wire_fields = AppCallWireFields::deserialize(deserializer)?;
+    let mut fields = wire_fields.fields.into_owned();
+    fields.access_references = wire_fields
+        .access_references
+        .map(|access_references| decode_access_references(&fields, access_references))
+        .transpose()
+        .map_err(serde::de::Error::custom)?;
 
     // Transform box references if present
     if let Some(ref box_references) = fields.box_references {
@@ -348,6 +477,167 @@ where
     Ok(fields)
 }
 
+/// Transform access references to the wire format, where the resources of holdings, local states
+/// and boxes are 1-based positions in the access list.
+fn encode_access_references(
+    fields: &AppCallTransactionFields,
+    access_references: &[AccessReference],
+) -> Result<Vec<ResourceReference>, String> {
+    let position = |resource: &AccessReference, description: String| {
+        access_references
+            .iter()
+            .position(|reference| reference == resource)
+            .map(|pos| (pos + 1) as u64)
+            .ok_or_else(|| format!("{} not found in access references.", description))
+    };
+    let address_index = |address: &Address| {
+        if *address == fields.header.sender {
+            Ok(0)
+        } else {
+            position(
+                &AccessReference::Address(address.clone()),
+                format!("Address {}", address),
+            )
+        }
+    };
+    let app_index = |app_id: u64| {
+        if app_id == 0 || app_id == fields.app_id {
+            Ok(0)
+        } else {
+            position(&AccessReference::App(app_id), format!("App id {}", app_id))
+        }
+    };
+
+    access_references
+        .iter()
+        .map(|reference| {
+            Ok(match reference {
+                AccessReference::Address(address) => ResourceReference {
+                    address: Some(address.clone()),
+                    ..Default::default()
+                },
+                AccessReference::Asset(asset_id) => ResourceReference {
+                    asset_id: Some(*asset_id),
+                    ..Default::default()
+                },
+                AccessReference::App(app_id) => ResourceReference {
+                    app_id: Some(*app_id),
+                    ..Default::default()
+                },
+                AccessReference::Holding(holding) => ResourceReference {
+                    holding: Some(HoldingResourceReference {
+                        address: address_index(&holding.address)?,
+                        asset_id: position(
+                            &AccessReference::Asset(holding.asset_id),
+                            format!("Asset id {}", holding.asset_id),
+                        )?,
+                    }),
+                    ..Default::default()
+                },
+                AccessReference::Locals(locals) => ResourceReference {
+                    locals: Some(LocalsResourceReference {
+                        address: address_index(&locals.address)?,
+                        app_id: app_index(locals.app_id)?,
+                    }),
+                    ..Default::default()
+                },
+                AccessReference::Box(box_ref) => {
+                    let app_id = app_index(box_ref.app_id)?;
+                    ResourceReference {
+                        // An empty box reference is omitted, leaving an empty entry
+                        box_reference: (app_id != 0 || !box_ref.name.is_empty()).then(|| {
+                            BoxReference {
+                                app_id,
+                                name: box_ref.name.clone(),
+                            }
+                        }),
+                        ..Default::default()
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+/// Transform access references from the wire format, resolving the positions of the resources of
+/// holdings, local states and boxes.
+fn decode_access_references(
+    fields: &AppCallTransactionFields,
+    resource_references: Vec<ResourceReference>,
+) -> Result<Vec<AccessReference>, String> {
+    let resolve = |index: u64| {
+        (index as usize)
+            .checked_sub(1)
+            .and_then(|index| resource_references.get(index))
+            .ok_or_else(|| format!("Cannot find access reference index {}.", index))
+    };
+    let resolve_address = |index: u64| {
+        if index == 0 {
+            return Ok(fields.header.sender.clone());
+        }
+        resolve(index)?
+            .address
+            .clone()
+            .ok_or_else(|| format!("Access reference {} is not an address.", index))
+    };
+    let resolve_app = |index: u64| {
+        if index == 0 {
+            return Ok(0);
+        }
+        resolve(index)?
+            .app_id
+            .ok_or_else(|| format!("Access reference {} is not an app.", index))
+    };
+
+    resource_references
+        .iter()
+        .map(|reference| {
+            let resources = [
+                reference.address.is_some(),
+                reference.asset_id.is_some(),
+                reference.app_id.is_some(),
+                reference.holding.is_some(),
+                reference.locals.is_some(),
+                reference.box_reference.is_some(),
+            ];
+            if resources.iter().filter(|&&is_set| is_set).count() > 1 {
+                return Err("Access reference must name a single resource.".to_string());
+            }
+
+            Ok(if let Some(address) = &reference.address {
+                AccessReference::Address(address.clone())
+            } else if let Some(asset_id) = reference.asset_id {
+                AccessReference::Asset(asset_id)
+            } else if let Some(app_id) = reference.app_id {
+                AccessReference::App(app_id)
+            } else if let Some(holding) = &reference.holding {
+                AccessReference::Holding(HoldingReference {
+                    address: resolve_address(holding.address)?,
+                    asset_id: resolve(holding.asset_id)?.asset_id.ok_or_else(|| {
+                        format!("Access reference {} is not an asset.", holding.asset_id)
+                    })?,
+                })
+            } else if let Some(locals) = &reference.locals {
+                AccessReference::Locals(LocalsReference {
+                    address: resolve_address(locals.address)?,
+                    app_id: resolve_app(locals.app_id)?,
+                })
+            } else if let Some(box_ref) = &reference.box_reference {
+                AccessReference::Box(BoxReference {
+                    app_id: resolve_app(box_ref.app_id)?,
+                    name: box_ref.name.clone(),
+                })
+            } else {
+                // An empty entry is an empty box reference
+                AccessReference::Box(BoxReference {
+                    app_id: 0,
+                    name: Vec::new(),
+                })
+            })
+        })
+        .collect()
+}
+
 impl AppCallTransactionFields {
     /// Validates that the app ID is not zero.
     fn validate_app_id_not_zero(&self, errors: &mut Vec<TransactionValidationError>) {
@@ -621,6 +911,8 @@ impl AppCallTransactionFields {
             }
         }
 
+        self.validate_access_references(errors);
+
         // Validate overall reference count
         let total_references = self.account_references.as_ref().map_or(0, |v| v.len())
             + self.app_references.as_ref().map_or(0, |v| v.len())
@@ -637,6 +929,41 @@ impl AppCallTransactionFields {
         }
     }
 
+    /// Validates the access list, which replaces the other references.
+    fn validate_access_references(&self, errors: &mut Vec<TransactionValidationError>) {
+        let Some(access_refs) = self
+            .access_references
+            .as_ref()
+            .filter(|refs| !refs.is_empty())
+        else {
+            return;
+        };
+
+        if access_refs.len() > MAX_ACCESS_REFERENCES {
+            errors.push(TransactionValidationError::FieldTooLong {
+                field: "Access references".to_string(),
+                actual: access_refs.len(),
+                max: MAX_ACCESS_REFERENCES,
+                unit: "refs".to_string(),
+            });
+        }
+
+        if !is_empty_vec_opt(&self.account_references)
+            || !is_empty_vec_opt(&self.app_references)
+            || !is_empty_vec_opt(&self.asset_references)
+            || !is_empty_vec_opt(&self.box_references)
+        {
+            errors.push(TransactionValidationError::ArbitraryConstraint(
+                "Access references cannot be combined with account, app, asset or box references"
+                    .to_string(),
+            ));
+        }
+
+        if let Err(message) = encode_access_references(self, access_refs) {
+            errors.push(TransactionValidationError::ArbitraryConstraint(message));
+        }
+    }
+
     /// Calculates the maximum allowed program size based on extra program pages.
     fn calculate_max_program_size(&self) -> usize {
         let extra_pages = self.extra_program_pages.unwrap_or(0) as usize;
@@ -887,6 +1214,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_access_references_encoding() {
+        let sender = TransactionHeaderMother::example().build().unwrap().sender;
+        let account = AccountMother::neil().address();
+        let access_references = vec![
+            AccessReference::Address(account.clone()),
+            AccessReference::Asset(1001),
+            AccessReference::App(54321),
+            AccessReference::Holding(HoldingReference {
+                address: account.clone(),
+                asset_id: 1001,
+            }),
+            AccessReference::Holding(HoldingReference {
+                address: sender.clone(),
+                asset_id: 1001,
+            }),
+            AccessReference::Locals(LocalsReference {
+                address: account,
+                app_id: 54321,
+            }),
+            AccessReference::Box(BoxReference {
+                app_id: 12345,
+                name: "b1".as_bytes().to_vec(),
+            }),
+            AccessReference::Box(BoxReference {
+                app_id: 0,
+                name: vec![],
+            }),
+        ];
+        let app_call_tx = AppCallTransactionMother::app_call_example()
+            .access_references(access_references.clone())
+            .build()
+            .unwrap();
+
+        let encoded = app_call_tx.encode().unwrap();
+        let value = rmpv::decode::read_value(&mut &encoded[2..]).unwrap();
+        let access_list = value
+            .as_map()
+            .unwrap()
+            .iter()
+            .find(|(key, _)| key.as_str() == Some("al"))
+            .map(|(_, access_list)| access_list.as_array().unwrap().clone())
+            .unwrap();
+        assert_eq!(access_list.len(), access_references.len());
+        assert_eq!(access_list[3].to_string(), r#"{"h": {"d": 1, "s": 2}}"#);
+        assert_eq!(access_list[4].to_string(), r#"{"h": {"s": 2}}"#);
+        assert_eq!(access_list[5].to_string(), r#"{"l": {"d": 1, "p": 3}}"#);
+        assert_eq!(access_list[7].to_string(), "{}");
+
+        let decoded = Transaction::decode(&encoded).unwrap();
+        if let Transaction::AppCall(decoded_app_call) = decoded {
+            let mut expected = access_references;
+            // The called app is encoded as the current app
+            expected[6] = AccessReference::Box(BoxReference {
+                app_id: 0,
+                name: "b1".as_bytes().to_vec(),
+            });
+            assert_eq!(decoded_app_call.access_references, Some(expected));
+        } else {
+            panic!("Expected AppCall transaction type");
+        }
+    }
+
+    #[test]
+    fn test_validate_access_references() {
+        let result = AppCallTransactionMother::app_call_example()
+            .access_references(vec![AccessReference::Holding(HoldingReference {
+                address: AccountMother::neil().address(),
+                asset_id: 1001,
+            })])
+            .app_references(vec![54321])
+            .build();
+
+        let error_message = result.unwrap_err().to_string();
+        assert!(
+            error_message.contains(
+                "Access references cannot be combined with account, app, asset or box references"
+            ),
+            "Expected combined references error, got: {}",
+            error_message
+        );
+        assert!(
+            error_message.contains("not found in access references"),
+            "Expected missing access reference error, got: {}",
+            error_message
+        );
+
+        let excessive_access_refs = (0..=MAX_ACCESS_REFERENCES as u64)
+            .map(AccessReference::Asset)
+            .collect::<Vec<_>>();
+        let result = AppCallTransactionMother::app_call_example()
+            .access_references(excessive_access_refs)
+            .build();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Access references cannot exceed 16 refs")
+        );
+    }
+
     #[test]
     fn test_app_call_empty_value_encoding() {
         let builder = &AppCallTransactionBuilder::default()
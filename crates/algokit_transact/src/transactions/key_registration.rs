@@ -413,6 +413,7 @@ mod tests {
             signature: Some(EMPTY_SIGNATURE),
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         };
         let encoded_stx = signed_tx.encode().unwrap();
         let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -444,6 +445,7 @@ mod tests {
             signature: Some(EMPTY_SIGNATURE),
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         };
         let encoded_stx = signed_tx.encode().unwrap();
         let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -476,6 +478,7 @@ mod tests {
             signature: Some(EMPTY_SIGNATURE),
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         };
 
         // Test that transaction ID can be generated
@@ -15,8 +15,8 @@ mod payment;
 pub mod state_proof;
 
 pub use app_call::{
-    AppCallTransactionBuilder, AppCallTransactionFields, BoxReference, OnApplicationComplete,
-    StateSchema,
+    AccessReference, AppCallTransactionBuilder, AppCallTransactionFields, BoxReference,
+    HoldingReference, LocalsReference, OnApplicationComplete, StateSchema,
 };
 use app_call::{app_call_deserializer, app_call_serializer};
 pub use asset_config::{
@@ -44,7 +44,7 @@ use crate::constants::{
 use crate::error::AlgoKitTransactError;
 use crate::traits::{AlgorandMsgpack, EstimateTransactionSize, TransactionId, Transactions};
 use crate::utils::{compute_group, is_zero_addr_opt};
-use crate::{Address, MultisigSignature};
+use crate::{Address, LogicSignature, MultisigSignature};
 use serde::{Deserialize, Serialize};
 use serde_with::{Bytes, serde_as};
 use std::any::Any;
@@ -194,6 +194,12 @@ pub struct SignedTransaction {
     #[serde(rename = "msig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub multisignature: Option<MultisigSignature>,
+
+    /// Optional logic signature authorizing the transaction.
+    #[serde(rename = "lsig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub logic_signature: Option<LogicSignature>,
 }
 
 impl AlgorandMsgpack for SignedTransaction {
@@ -360,6 +366,7 @@ mod transaction_tests {
             app_references: None,
             asset_references: None,
             box_references: None,
+            access_references: None,
         });
 
         // Test pattern matching for app call
@@ -415,6 +422,7 @@ mod transaction_tests {
                 app_references: None,
                 asset_references: None,
                 box_references: None,
+                access_references: None,
             }),
         ];
 
@@ -621,6 +629,7 @@ mod transaction_tests {
                 signature: Some(EMPTY_SIGNATURE),
                 auth_address: None,
                 multisignature: None,
+                logic_signature: None,
             })
             .collect::<Vec<SignedTransaction>>();
 
@@ -1,3 +1,4 @@
+mod logic_signature;
 mod multisig;
 pub mod transactions;
 
@@ -8,6 +9,7 @@ use algokit_transact::{
 use ffi_macros::{ffi_enum, ffi_func, ffi_record};
 use serde::{Deserialize, Serialize};
 
+pub use logic_signature::LogicSignature;
 pub use multisig::{MultisigSignature, MultisigSubsignature};
 pub use transactions::AppCallTransactionFields;
 pub use transactions::AssetConfigTransactionFields;
@@ -425,6 +427,9 @@ pub struct SignedTransaction {
 
     /// Optional multisig signature if the transaction is a multisig transaction.
     pub multisignature: Option<MultisigSignature>,
+
+    /// Optional logic signature if the transaction is authorized by a program.
+    pub logic_signature: Option<LogicSignature>,
 }
 
 impl From<algokit_transact::SignedTransaction> for SignedTransaction {
@@ -434,6 +439,7 @@ impl From<algokit_transact::SignedTransaction> for SignedTransaction {
             signature: signed_transaction.signature.map(|sig| sig.into()),
             auth_address: signed_transaction.auth_address.map(|addr| addr.as_str()),
             multisignature: signed_transaction.multisignature.map(Into::into),
+            logic_signature: signed_transaction.logic_signature.map(Into::into),
         }
     }
 }
@@ -462,6 +468,10 @@ impl TryFrom<SignedTransaction> for algokit_transact::SignedTransaction {
                 .multisignature
                 .map(TryInto::try_into)
                 .transpose()?,
+            logic_signature: signed_transaction
+                .logic_signature
+                .map(TryInto::try_into)
+                .transpose()?,
         })
     }
 }
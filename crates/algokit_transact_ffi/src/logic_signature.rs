@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Representation of an Algorand logic signature.
+#[ffi_record]
+pub struct LogicSignature {
+    /// The compiled program.
+    logic: Vec<u8>,
+    /// Optional arguments the program is evaluated with.
+    args: Option<Vec<Vec<u8>>>,
+    /// Optional signature of the delegating account over the program.
+    signature: Option<Vec<u8>>,
+    /// Optional multisignature of the delegating multisig account over the program.
+    multisignature: Option<MultisigSignature>,
+}
+
+impl From<algokit_transact::LogicSignature> for LogicSignature {
+    fn from(value: algokit_transact::LogicSignature) -> Self {
+        Self {
+            logic: value.logic,
+            args: value.args,
+            signature: value.signature.map(|sig| sig.to_vec()),
+            multisignature: value.multisignature.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<LogicSignature> for algokit_transact::LogicSignature {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(value: LogicSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            logic: value.logic,
+            args: value.args,
+            signature: value
+                .signature
+                .map(|sig| vec_to_array(&sig, "logic signature signature"))
+                .transpose()?,
+            multisignature: value.multisignature.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
@@ -206,6 +206,7 @@ mod tests {
                 )
                     .unwrap(),
             ),
+            logic_signature: None,
         };
         assert_eq!(
             observed_signed_txn.encode().unwrap(),
@@ -68,6 +68,10 @@ pub struct AppCallTransactionFields {
 
     /// The boxes that should be made available for the runtime of the program.
     box_references: Option<Vec<BoxReference>>,
+
+    /// The resources available to the program, in place of the account, app, asset and box
+    /// references. Only supported by protocols with access lists.
+    access_references: Option<Vec<AccessReference>>,
 }
 
 impl From<algokit_transact::AppCallTransactionFields> for AppCallTransactionFields {
@@ -89,6 +93,9 @@ impl From<algokit_transact::AppCallTransactionFields> for AppCallTransactionFiel
             box_references: tx
                 .box_references
                 .map(|boxes| boxes.into_iter().map(Into::into).collect()),
+            access_references: tx
+                .access_references
+                .map(|refs| refs.into_iter().map(Into::into).collect()),
         }
     }
 }
@@ -130,6 +137,14 @@ impl TryFrom<Transaction> for algokit_transact::AppCallTransactionFields {
             box_references: data
                 .box_references
                 .map(|boxes| boxes.into_iter().map(Into::into).collect()),
+            access_references: data
+                .access_references
+                .map(|refs| {
+                    refs.into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?,
         };
 
         transaction_fields
@@ -174,6 +189,74 @@ impl From<BoxReference> for algokit_transact::BoxReference {
     }
 }
 
+/// A resource in the access list of an app call.
+///
+/// The account and asset of a holding, the account and app of a local state and the app of a box
+/// must be in the same access list, unless they are the sender or the called app.
+#[ffi_enum]
+pub enum AccessReference {
+    /// An account.
+    Address { address: String },
+    /// An asset.
+    Asset { asset_id: u64 },
+    /// An app.
+    App { app_id: u64 },
+    /// The holding of an asset by an account.
+    Holding { address: String, asset_id: u64 },
+    /// The local state of an account in an app, where an app ID of 0 indicates the current app.
+    Locals { address: String, app_id: u64 },
+    /// A box. A box reference with an empty name and app ID 0 only adds to the box I/O budget.
+    Box { box_reference: BoxReference },
+}
+
+impl From<algokit_transact::AccessReference> for AccessReference {
+    fn from(value: algokit_transact::AccessReference) -> Self {
+        match value {
+            algokit_transact::AccessReference::Address(address) => Self::Address {
+                address: address.as_str(),
+            },
+            algokit_transact::AccessReference::Asset(asset_id) => Self::Asset { asset_id },
+            algokit_transact::AccessReference::App(app_id) => Self::App { app_id },
+            algokit_transact::AccessReference::Holding(holding) => Self::Holding {
+                address: holding.address.as_str(),
+                asset_id: holding.asset_id,
+            },
+            algokit_transact::AccessReference::Locals(locals) => Self::Locals {
+                address: locals.address.as_str(),
+                app_id: locals.app_id,
+            },
+            algokit_transact::AccessReference::Box(box_reference) => Self::Box {
+                box_reference: box_reference.into(),
+            },
+        }
+    }
+}
+
+impl TryFrom<AccessReference> for algokit_transact::AccessReference {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(val: AccessReference) -> Result<Self, Self::Error> {
+        Ok(match val {
+            AccessReference::Address { address } => Self::Address(address.parse()?),
+            AccessReference::Asset { asset_id } => Self::Asset(asset_id),
+            AccessReference::App { app_id } => Self::App(app_id),
+            AccessReference::Holding { address, asset_id } => {
+                Self::Holding(algokit_transact::HoldingReference {
+                    address: address.parse()?,
+                    asset_id,
+                })
+            }
+            AccessReference::Locals { address, app_id } => {
+                Self::Locals(algokit_transact::LocalsReference {
+                    address: address.parse()?,
+                    app_id,
+                })
+            }
+            AccessReference::Box { box_reference } => Self::Box(box_reference.into()),
+        })
+    }
+}
+
 /// On-completion actions for app transactions.
 ///
 /// These values define what additional actions occur with the transaction.
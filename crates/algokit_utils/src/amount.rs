@@ -0,0 +1,264 @@
+use snafu::Snafu;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// The number of microALGO in one ALGO.
+pub const MICRO_ALGOS_PER_ALGO: u64 = 1_000_000;
+
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum AmountError {
+    #[snafu(display("Invalid amount: {message}"))]
+    InvalidAmount { message: String },
+}
+
+/// An amount of ALGO, held as microALGO so that it can't be confused with a number of ALGO.
+///
+/// The arithmetic operators panic on overflow like those of `u64`; use the checked variants when
+/// the amounts are untrusted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlgoAmount {
+    micro_algos: u64,
+}
+
+impl AlgoAmount {
+    pub const ZERO: AlgoAmount = AlgoAmount { micro_algos: 0 };
+
+    /// An amount of whole ALGO.
+    ///
+    /// # Panics
+    /// If the amount doesn't fit in a `u64` of microALGO.
+    pub const fn algos(algos: u64) -> Self {
+        match algos.checked_mul(MICRO_ALGOS_PER_ALGO) {
+            Some(micro_algos) => Self { micro_algos },
+            None => panic!("ALGO amount overflows u64 microALGO"),
+        }
+    }
+
+    /// An amount of microALGO.
+    pub const fn micro_algos(micro_algos: u64) -> Self {
+        Self { micro_algos }
+    }
+
+    pub const fn as_micro_algos(&self) -> u64 {
+        self.micro_algos
+    }
+
+    /// The amount in ALGO, which loses precision for amounts above 2^53 microALGO.
+    pub fn as_algos(&self) -> f64 {
+        self.micro_algos as f64 / MICRO_ALGOS_PER_ALGO as f64
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.micro_algos
+            .checked_add(other.micro_algos)
+            .map(Self::micro_algos)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.micro_algos
+            .checked_sub(other.micro_algos)
+            .map(Self::micro_algos)
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::micro_algos(self.micro_algos.saturating_sub(other.micro_algos))
+    }
+}
+
+impl Add for AlgoAmount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::micro_algos(self.micro_algos + other.micro_algos)
+    }
+}
+
+impl AddAssign for AlgoAmount {
+    fn add_assign(&mut self, other: Self) {
+        self.micro_algos += other.micro_algos;
+    }
+}
+
+impl Sub for AlgoAmount {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::micro_algos(self.micro_algos - other.micro_algos)
+    }
+}
+
+impl SubAssign for AlgoAmount {
+    fn sub_assign(&mut self, other: Self) {
+        self.micro_algos -= other.micro_algos;
+    }
+}
+
+impl Mul<u64> for AlgoAmount {
+    type Output = Self;
+
+    fn mul(self, factor: u64) -> Self {
+        Self::micro_algos(self.micro_algos * factor)
+    }
+}
+
+impl Sum for AlgoAmount {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+/// Formats the amount in ALGO, e.g. `1.5 ALGO`.
+impl fmt::Display for AlgoAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ALGO", format_decimal(self.micro_algos, 6))
+    }
+}
+
+/// An amount of an asset in its base units, together with the number of decimals of the asset.
+///
+/// Asset transfers are made in base units; the decimals only define how the amount is displayed
+/// and parsed, e.g. 1,500,000 base units of an asset with 6 decimals are 1.5 units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssetAmount {
+    base_units: u64,
+    decimals: u8,
+}
+
+impl AssetAmount {
+    pub const ZERO: AssetAmount = AssetAmount::base_units(0);
+
+    /// An amount in base units, displayed as a whole number.
+    pub const fn base_units(base_units: u64) -> Self {
+        Self {
+            base_units,
+            decimals: 0,
+        }
+    }
+
+    /// An amount in base units of an asset with the given number of decimals.
+    pub const fn with_decimals(base_units: u64, decimals: u8) -> Self {
+        Self {
+            base_units,
+            decimals,
+        }
+    }
+
+    /// Parse a decimal amount, e.g. `"1.5"`, of an asset with the given number of decimals.
+    ///
+    /// Fails when the amount has more fractional digits than the asset has decimals, or doesn't
+    /// fit in a `u64` of base units.
+    pub fn parse(amount: &str, decimals: u8) -> Result<Self, AmountError> {
+        let invalid = |message: &str| AmountError::InvalidAmount {
+            message: format!("{amount}: {message}"),
+        };
+
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid("no digits"));
+        }
+        if !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("not a decimal number"));
+        }
+        if fraction.len() > decimals as usize {
+            return Err(invalid(&format!("more than {decimals} fractional digits")));
+        }
+
+        let digits = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+        let base_units = digits
+            .parse::<u64>()
+            .map_err(|_| invalid("overflows u64 base units"))?;
+
+        Ok(Self::with_decimals(base_units, decimals))
+    }
+
+    pub const fn as_base_units(&self) -> u64 {
+        self.base_units
+    }
+
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Adds amounts of the same asset, `None` when the decimals differ or on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        let base_units = self.base_units.checked_add(other.base_units)?;
+        Some(Self::with_decimals(base_units, self.decimals))
+    }
+
+    /// Subtracts amounts of the same asset, `None` when the decimals differ or on underflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        let base_units = self.base_units.checked_sub(other.base_units)?;
+        Some(Self::with_decimals(base_units, self.decimals))
+    }
+}
+
+/// Formats the amount in units of the asset, e.g. `1.5`.
+impl fmt::Display for AssetAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_decimal(self.base_units, self.decimals))
+    }
+}
+
+/// Format base units as a decimal number without trailing fractional zeros.
+fn format_decimal(base_units: u64, decimals: u8) -> String {
+    let digits = format!("{:0>width$}", base_units, width = decimals as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algo_amount() {
+        let amount = AlgoAmount::algos(1) + AlgoAmount::micro_algos(500_000);
+
+        assert_eq!(amount.as_micro_algos(), 1_500_000);
+        assert_eq!(amount.as_algos(), 1.5);
+        assert_eq!(amount.to_string(), "1.5 ALGO");
+        assert_eq!(AlgoAmount::micro_algos(1).to_string(), "0.000001 ALGO");
+        assert_eq!((AlgoAmount::algos(2) * 3).to_string(), "6 ALGO");
+        assert_eq!(
+            AlgoAmount::ZERO.checked_sub(AlgoAmount::micro_algos(1)),
+            None
+        );
+        assert_eq!(
+            [AlgoAmount::algos(1), AlgoAmount::algos(2)]
+                .into_iter()
+                .sum::<AlgoAmount>(),
+            AlgoAmount::algos(3)
+        );
+    }
+
+    #[test]
+    fn test_asset_amount() {
+        let amount = AssetAmount::parse("1.5", 6).unwrap();
+
+        assert_eq!(amount.as_base_units(), 1_500_000);
+        assert_eq!(amount.to_string(), "1.5");
+        assert_eq!(AssetAmount::parse("0", 2).unwrap().as_base_units(), 0);
+        assert_eq!(AssetAmount::parse(".25", 2).unwrap().as_base_units(), 25);
+        assert_eq!(AssetAmount::base_units(42).to_string(), "42");
+        assert!(AssetAmount::parse("1.234", 2).is_err());
+        assert!(AssetAmount::parse("1e3", 2).is_err());
+        assert!(AssetAmount::parse("18446744073709551616", 0).is_err());
+        assert_eq!(amount.checked_add(AssetAmount::with_decimals(1, 2)), None);
+    }
+}
@@ -0,0 +1,332 @@
+use super::state_accessor::map_prefix_bytes;
+use super::{AppClient, AppClientError};
+use crate::AlgoAmount;
+use crate::clients::AlgodErrorKind;
+use crate::clients::app_manager::AppManagerError;
+use crate::transactions::payment::box_min_balance;
+use algokit_abi::arc56_contract::{ABIStorageKey, ABIStorageMap};
+use algokit_abi::{ABIType, ABIValue};
+use base64::Engine;
+
+/// A box of a [`BoxMap`], with its decoded key and value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxMapEntry {
+    /// The key, decoded from the box name without the map prefix
+    pub key: ABIValue,
+    /// The decoded value
+    pub value: ABIValue,
+    /// The full box name, including the map prefix
+    pub name: Vec<u8>,
+    /// The size of the box value in bytes
+    pub size: u64,
+}
+
+impl BoxMapEntry {
+    /// The minimum balance the app account holds for this box.
+    pub fn min_balance(&self) -> AlgoAmount {
+        AlgoAmount::micro_algos(box_min_balance(self.name.len() as u64, self.size))
+    }
+}
+
+/// A page of [`BoxMapEntry`] in box name order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxMapPage {
+    pub entries: Vec<BoxMapEntry>,
+    /// The name of the last box of the page, to pass to [`BoxMap::page`] for the next page;
+    /// `None` on the last page
+    pub next: Option<Vec<u8>>,
+}
+
+/// The boxes of an app whose names share a prefix, with the rest of the name and the value
+/// decoded with ABI types.
+///
+/// Algod lists all box names of an app at once, so listing is always done in full; paging only
+/// limits the number of box values that are fetched.
+pub struct BoxMap<'app_client> {
+    client: &'app_client AppClient,
+    prefix: Vec<u8>,
+    key_type: ABIType,
+    value_type: ABIType,
+}
+
+impl<'app_client> BoxMap<'app_client> {
+    /// Create a box map of the boxes of the app whose names start with `prefix`.
+    pub fn new(
+        client: &'app_client AppClient,
+        prefix: Vec<u8>,
+        key_type: ABIType,
+        value_type: ABIType,
+    ) -> Self {
+        Self {
+            client,
+            prefix,
+            key_type,
+            value_type,
+        }
+    }
+
+    /// Create a box map from a map declared in the ARC-56 `state.maps.box`.
+    pub(crate) fn from_storage_map(
+        client: &'app_client AppClient,
+        storage_map: &ABIStorageMap,
+    ) -> Result<Self, AppClientError> {
+        Ok(Self::new(
+            client,
+            map_prefix_bytes(storage_map)?,
+            storage_map.key_type.clone(),
+            storage_map.value_type.clone(),
+        ))
+    }
+
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    pub fn key_type(&self) -> &ABIType {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &ABIType {
+        &self.value_type
+    }
+
+    /// The box name of `key`, i.e. the prefix followed by the ABI encoded key.
+    pub fn box_name(&self, key: &ABIValue) -> Result<Vec<u8>, AppClientError> {
+        let encoded_key = self
+            .key_type
+            .encode(key)
+            .map_err(|e| AppClientError::ABIError { source: e })?;
+        Ok([self.prefix.as_slice(), &encoded_key].concat())
+    }
+
+    /// The minimum balance the app account needs to hold for the box of `key` with a value of
+    /// `value_size` bytes, e.g. to fund the app account before the box is created.
+    pub fn min_balance(
+        &self,
+        key: &ABIValue,
+        value_size: u64,
+    ) -> Result<AlgoAmount, AppClientError> {
+        let name = self.box_name(key)?;
+        Ok(AlgoAmount::micro_algos(box_min_balance(
+            name.len() as u64,
+            value_size,
+        )))
+    }
+
+    /// The names of all boxes of the map, in order.
+    pub async fn box_names(&self) -> Result<Vec<Vec<u8>>, AppClientError> {
+        let mut names: Vec<Vec<u8>> = self
+            .client
+            .get_box_names()
+            .await?
+            .into_iter()
+            .map(|box_name| box_name.name_raw)
+            .filter(|name| name.starts_with(&self.prefix))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// The decoded keys of all boxes of the map, in box name order.
+    pub async fn keys(&self) -> Result<Vec<ABIValue>, AppClientError> {
+        self.box_names()
+            .await?
+            .iter()
+            .map(|name| self.decode_key(name))
+            .collect()
+    }
+
+    /// Get the value of `key`, or `None` when there is no box for it.
+    pub async fn get(&self, key: &ABIValue) -> Result<Option<ABIValue>, AppClientError> {
+        Ok(self.get_entry(key).await?.map(|entry| entry.value))
+    }
+
+    /// Get the entry of `key`, or `None` when there is no box for it.
+    pub async fn get_entry(&self, key: &ABIValue) -> Result<Option<BoxMapEntry>, AppClientError> {
+        let name = self.box_name(key)?;
+        match self.client.get_box_value(&name).await {
+            Ok(value) => Ok(Some(self.decode_entry(key.clone(), name, &value)?)),
+            Err(e) if is_box_not_found(&e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get all entries of the map, in box name order.
+    pub async fn entries(&self) -> Result<Vec<BoxMapEntry>, AppClientError> {
+        let names = self.box_names().await?;
+        self.fetch_entries(names).await
+    }
+
+    /// Get up to `limit` entries, in box name order, starting after the box named `after`.
+    ///
+    /// Pass `None` for the first page and the [`BoxMapPage::next`] of the previous page for the
+    /// following ones.
+    pub async fn page(
+        &self,
+        after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<BoxMapPage, AppClientError> {
+        if limit == 0 {
+            return Err(AppClientError::ValidationError {
+                message: "Box map page limit must be greater than zero".to_string(),
+            });
+        }
+
+        let names = self.box_names().await?;
+        let start = after.map_or(0, |after| {
+            names.partition_point(|name| name.as_slice() <= after)
+        });
+        let end = (start + limit).min(names.len());
+        let page_names = names[start..end].to_vec();
+        let next = (end < names.len()).then(|| names[end - 1].clone());
+
+        Ok(BoxMapPage {
+            entries: self.fetch_entries(page_names).await?,
+            next,
+        })
+    }
+
+    async fn fetch_entries(&self, names: Vec<Vec<u8>>) -> Result<Vec<BoxMapEntry>, AppClientError> {
+        let mut entries = Vec::with_capacity(names.len());
+        for name in names {
+            let key = self.decode_key(&name)?;
+            let value = self.client.get_box_value(&name).await?;
+            entries.push(self.decode_entry(key, name, &value)?);
+        }
+        Ok(entries)
+    }
+
+    fn decode_key(&self, name: &[u8]) -> Result<ABIValue, AppClientError> {
+        self.key_type
+            .decode(&name[self.prefix.len()..])
+            .map_err(|e| AppClientError::ABIError { source: e })
+    }
+
+    fn decode_entry(
+        &self,
+        key: ABIValue,
+        name: Vec<u8>,
+        value: &[u8],
+    ) -> Result<BoxMapEntry, AppClientError> {
+        Ok(BoxMapEntry {
+            key,
+            value: self
+                .value_type
+                .decode(value)
+                .map_err(|e| AppClientError::ABIError { source: e })?,
+            name,
+            size: value.len() as u64,
+        })
+    }
+}
+
+/// A single box of an app, with its value decoded with an ABI type.
+pub struct BoxRef<'app_client> {
+    client: &'app_client AppClient,
+    name: Vec<u8>,
+    value_type: ABIType,
+}
+
+impl<'app_client> BoxRef<'app_client> {
+    /// Create a reference to the box of the app named `name`.
+    pub fn new(client: &'app_client AppClient, name: Vec<u8>, value_type: ABIType) -> Self {
+        Self {
+            client,
+            name,
+            value_type,
+        }
+    }
+
+    /// Create a reference to a box declared in the ARC-56 `state.keys.box`.
+    pub(crate) fn from_storage_key(
+        client: &'app_client AppClient,
+        storage_key: &ABIStorageKey,
+    ) -> Result<Self, AppClientError> {
+        let name = base64::engine::general_purpose::STANDARD
+            .decode(&storage_key.key)
+            .map_err(|e| AppClientError::AppStateError {
+                message: format!("Failed to decode box key '{}': {}", storage_key.key, e),
+            })?;
+        Ok(Self::new(client, name, storage_key.value_type.clone()))
+    }
+
+    pub fn name(&self) -> &[u8] {
+        &self.name
+    }
+
+    pub fn value_type(&self) -> &ABIType {
+        &self.value_type
+    }
+
+    /// The minimum balance the app account needs to hold for the box with a value of
+    /// `value_size` bytes.
+    pub fn min_balance(&self, value_size: u64) -> AlgoAmount {
+        AlgoAmount::micro_algos(box_min_balance(self.name.len() as u64, value_size))
+    }
+
+    /// Get the raw value of the box, or `None` when it doesn't exist.
+    pub async fn get_raw(&self) -> Result<Option<Vec<u8>>, AppClientError> {
+        match self.client.get_box_value(&self.name).await {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if is_box_not_found(&e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the decoded value of the box, or `None` when it doesn't exist.
+    pub async fn get(&self) -> Result<Option<ABIValue>, AppClientError> {
+        self.get_raw()
+            .await?
+            .map(|value| {
+                self.value_type
+                    .decode(&value)
+                    .map_err(|e| AppClientError::ABIError { source: e })
+            })
+            .transpose()
+    }
+}
+
+/// Whether `error` is algod responding that the box doesn't exist.
+fn is_box_not_found(error: &AppClientError) -> bool {
+    match error {
+        AppClientError::AppManagerError {
+            source: AppManagerError::AlgodClientError { source },
+        } => AlgodErrorKind::from(source).is_not_found(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algod_client::apis::Error as AlgodError;
+    use algokit_http_client::HttpError;
+    use std::collections::HashMap;
+
+    fn algod_error(code: u16, message: &str) -> AppClientError {
+        AppClientError::AppManagerError {
+            source: AppManagerError::AlgodClientError {
+                source: AlgodError::Http {
+                    source: HttpError::Status {
+                        code,
+                        body: format!(r#"{{"message":"{message}"}}"#).into_bytes(),
+                        headers: HashMap::new(),
+                    },
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_is_box_not_found() {
+        assert!(is_box_not_found(&algod_error(404, "box not found")));
+        // A 404 in the message of another error isn't a missing box
+        assert!(!is_box_not_found(&algod_error(
+            500,
+            "failed to read box 404"
+        )));
+        assert!(!is_box_not_found(&AppClientError::Network {
+            message: "404".to_string(),
+        }));
+    }
+}
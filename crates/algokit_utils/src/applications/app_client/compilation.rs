@@ -4,6 +4,7 @@ use crate::{
     applications::app_client::types::CompilationParams,
     clients::app_manager::DeploymentMetadata,
     config::{AppCompiledEventData, EventData},
+    debugging::persist_compiled_programs,
 };
 
 use crate::clients::app_manager::{CompiledPrograms, CompiledTeal};
@@ -31,6 +32,11 @@ impl AppClient {
             Config::events()
                 .emit(EventType::AppCompiled, EventData::AppCompiled(event))
                 .await;
+
+            if let Some(project_root) = Config::project_root() {
+                let app_name = self.app_name.as_ref().unwrap_or(&self.app_spec.name);
+                persist_compiled_programs(&project_root, app_name, &approval, &clear);
+            }
         }
 
         Ok(CompiledPrograms { approval, clear })
@@ -2,10 +2,10 @@ use super::types::LogicError;
 use super::{AppClient, AppSourceMaps};
 use crate::AlgorandClient;
 use crate::{AppClientError, TransactionSenderError};
-use algokit_abi::{Arc56Contract, arc56_contract::PcOffsetMethod};
+use algokit_abi::{Arc56Contract, ProgramSourceMap};
+use base64::Engine;
 use lazy_static::lazy_static;
 use regex::Regex;
-use serde_json::Value as JsonValue;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct LogicErrorData {
@@ -21,8 +21,14 @@ lazy_static! {
     .unwrap();
     static ref INNER_LOGIC_ERROR_RE: Regex =
         Regex::new(r"inner tx (\d+) failed:.*?pc=([0-9]+)").unwrap();
+    static ref DETAILS_RE: Regex = Regex::new(r"Details: (?P<details>.*)").unwrap();
+    static ref PROGRAM_COST_RE: Regex =
+        Regex::new(r"program cost was (?P<cost>[0-9]+)").unwrap();
 }
 
+/// The number of TEAL lines shown before and after the failed line.
+const LOGIC_ERROR_CONTEXT: usize = 5;
+
 pub(crate) fn extract_logic_error_data(error_str: &str) -> Option<LogicErrorData> {
     let caps = LOGIC_ERROR_RE.captures(error_str)?;
     let pc = if let Some(inner) = INNER_LOGIC_ERROR_RE.captures(error_str) {
@@ -40,6 +46,14 @@ pub(crate) fn extract_logic_error_data(error_str: &str) -> Option<LogicErrorData
     })
 }
 
+/// A program disassembled to locate a failed pc when no source map is available.
+struct DisassembledProgram {
+    program: Vec<u8>,
+    teal: String,
+    /// The 1-based line of the failed pc in `teal`
+    line_no: u64,
+}
+
 pub(crate) struct LogicErrorContext<'logic_error_ctx> {
     pub app_id: u64,
     pub app_spec: &'logic_error_ctx Arc56Contract,
@@ -61,7 +75,10 @@ impl AppClient {
 
 impl LogicErrorContext<'_> {
     /// Create an enhanced LogicError from a transaction error, applying source maps if available.
-    pub(crate) fn expose_logic_error(
+    ///
+    /// When neither a source map nor the ARC-56 source is available, the TEAL around the failed
+    /// pc is recovered by disassembling the program with algod.
+    pub(crate) async fn expose_logic_error(
         &self,
         error_message: &str,
         is_clear_state_program: bool,
@@ -78,13 +95,23 @@ impl LogicErrorContext<'_> {
             program: None,
             source_map,
             transaction_id,
+            app_id: Self::extract_app_id(error_message)
+                .and_then(|app_id| app_id.parse().ok())
+                .or(Some(self.app_id)),
             pc: pc_opt,
             line_no: line_no_opt,
+            teal_line: None,
             lines: if listing.is_empty() {
                 None
             } else {
                 Some(listing.clone())
             },
+            details: DETAILS_RE
+                .captures(error_message)
+                .map(|caps| caps["details"].trim().to_string()),
+            program_cost: PROGRAM_COST_RE
+                .captures(error_message)
+                .and_then(|caps| caps["cost"].parse().ok()),
             traces: None,
             logic_error_str: Some(error_message.to_string()),
         };
@@ -110,24 +137,13 @@ impl LogicErrorContext<'_> {
                 &si_model.approval
             };
 
-            let mut arc56_pc = parsed_pc.unwrap_or(0);
-
-            if matches!(
-                program_source_info.pc_offset_method,
-                PcOffsetMethod::Cblocks
-            ) {
-                // Apply CBLOCKS offset only if compiled program bytes are available via cache
-                if let Some(bytes) = self.get_program_bytes(is_clear_state_program) {
-                    let offset = Self::get_constant_block_offset(&bytes);
-                    arc56_pc = arc56_pc.saturating_sub(offset as u64);
-                }
-            }
+            let arc56_pc = parsed_pc.unwrap_or(0);
 
             if arc56_pc > 0 {
-                if let Some(source_info) = program_source_info
-                    .source_info
-                    .iter()
-                    .find(|s| s.pc.iter().any(|v| *v as u64 == arc56_pc))
+                // The CBLOCKS offset is only applied if compiled program bytes are available via cache
+                let program_bytes = self.get_program_bytes(is_clear_state_program);
+                if let Some(source_info) =
+                    program_source_info.get_source_info_for_pc(arc56_pc, program_bytes.as_deref())
                 {
                     if let Some(em) = &source_info.error_message {
                         arc56_error_message = Some(em.clone());
@@ -146,7 +162,8 @@ impl LogicErrorContext<'_> {
             {
                 if let Some(teal_src) = self.decode_teal(is_clear_state_program) {
                     let center = arc56_line_no.unwrap();
-                    arc56_listing = Self::annotated_teal_snippet(&teal_src, center, 3);
+                    arc56_listing =
+                        Self::truncate_teal_source(&teal_src, center, LOGIC_ERROR_CONTEXT);
                 }
             }
         }
@@ -159,6 +176,16 @@ impl LogicErrorContext<'_> {
             listing = arc56_listing;
             logic.lines = Some(listing.clone());
         }
+        if let Some(line_no) = line_no_opt {
+            logic.teal_line = self
+                .decode_teal(is_clear_state_program)
+                .and_then(|teal_src| {
+                    teal_src
+                        .lines()
+                        .nth(line_no.saturating_sub(1) as usize)
+                        .map(|line| line.trim().to_string())
+                });
+        }
 
         if let Some(emsg) = arc56_error_message.or(msg_msg) {
             let app_id_from_msg = Self::extract_app_id(error_message);
@@ -173,9 +200,73 @@ impl LogicErrorContext<'_> {
             logic.message = runtime_msg.clone();
         }
 
+        if logic.lines.is_none() && self.get_source_map(is_clear_state_program).is_none() {
+            if let Some(pc) = parsed_pc {
+                if let Some(disassembled) = self
+                    .disassemble_for_pc(pc, logic.app_id, is_clear_state_program)
+                    .await
+                {
+                    logic.line_no = Some(disassembled.line_no);
+                    logic.teal_line = disassembled
+                        .teal
+                        .lines()
+                        .nth(disassembled.line_no.saturating_sub(1) as usize)
+                        .map(|line| line.trim().to_string());
+                    logic.lines = Some(Self::truncate_teal_source(
+                        &disassembled.teal,
+                        disassembled.line_no,
+                        LOGIC_ERROR_CONTEXT,
+                    ));
+                    logic.program = Some(disassembled.program);
+                }
+            }
+        }
+
         logic
     }
 
+    /// Disassemble the failed program and locate `pc` in the disassembly.
+    ///
+    /// The program is read from the compilation cache or the ARC-56 `byteCode`, falling back to
+    /// algod for a deployed app. The disassembly is compiled again for its source map, which is
+    /// only used when the recompiled bytes match the program so the pc resolves to the right
+    /// line. Any failure, e.g. algod not exposing the developer API, yields `None`.
+    async fn disassemble_for_pc(
+        &self,
+        pc: u64,
+        app_id: Option<u64>,
+        is_clear_state_program: bool,
+    ) -> Option<DisassembledProgram> {
+        let app_manager = self.algorand.app();
+        let program = match self.get_program_bytes(is_clear_state_program) {
+            Some(program) => program,
+            None => {
+                let app = app_manager
+                    .get_by_id(app_id.filter(|&app_id| app_id != 0)?)
+                    .await
+                    .ok()?;
+                if is_clear_state_program {
+                    app.clear_state_program
+                } else {
+                    app.approval_program
+                }
+            }
+        };
+
+        let teal = app_manager.disassemble_teal(&program).await.ok()?;
+        let compiled = app_manager.compile_teal(&teal).await.ok()?;
+        if compiled.compiled_base64_to_bytes != program {
+            return None;
+        }
+        let line_no = compiled.source_map?.get_location_for_pc(pc)?.line + 1;
+
+        Some(DisassembledProgram {
+            program,
+            teal,
+            line_no,
+        })
+    }
+
     /// Extract transaction id from an error string.
     fn extract_transaction_id(error_str: &str) -> Option<String> {
         let re = regex::Regex::new(r"transaction ([A-Z2-7]{52})").unwrap();
@@ -214,20 +305,23 @@ impl LogicErrorContext<'_> {
         None
     }
 
-    /// Map pc to TEAL line and extract a short snippet.
+    /// Map pc to a (1-based) TEAL line and extract a short snippet.
     fn apply_source_map(
         &self,
         pc: u64,
         is_clear_state_program: bool,
     ) -> Option<(u64, Vec<String>)> {
         let map = self.get_source_map(is_clear_state_program)?;
-        let line_no = Self::map_pc_to_line(map, pc)?;
-        let listing = Self::truncate_listing(map, line_no, 3);
+        let line_no = map.get_location_for_pc(pc)?.line + 1;
+        let listing = self
+            .decode_teal(is_clear_state_program)
+            .map(|teal_src| Self::truncate_teal_source(&teal_src, line_no, LOGIC_ERROR_CONTEXT))
+            .unwrap_or_default();
         Some((line_no, listing))
     }
 
     /// Get the selected program's source map.
-    fn get_source_map(&self, is_clear_state_program: bool) -> Option<&JsonValue> {
+    fn get_source_map(&self, is_clear_state_program: bool) -> Option<&ProgramSourceMap> {
         let maps = self.source_maps?;
         if is_clear_state_program {
             maps.clear_source_map.as_ref()
@@ -236,39 +330,6 @@ impl LogicErrorContext<'_> {
         }
     }
 
-    /// Map a program counter to a source line using the pc array.
-    fn map_pc_to_line(map: &JsonValue, pc: u64) -> Option<u64> {
-        let pcs = map.get("pc")?.as_array()?;
-        let mut best_line: Option<u64> = None;
-        for (i, entry) in pcs.iter().enumerate() {
-            if let Some(pc_val) = entry.as_u64() {
-                if pc_val == pc {
-                    return Some(i as u64 + 1);
-                }
-                if pc_val < pc {
-                    best_line = Some(i as u64 + 1);
-                }
-            }
-        }
-        best_line
-    }
-
-    /// Format a numbered snippet around a source line from a source map.
-    fn truncate_listing(map: &JsonValue, center_line: u64, context: usize) -> Vec<String> {
-        let mut lines: Vec<String> = Vec::new();
-        if let Some(source) = map.get("source").and_then(|s| s.as_str()) {
-            let src_lines: Vec<&str> = source.lines().collect();
-            let total = src_lines.len();
-            let center = center_line.saturating_sub(1) as usize;
-            let start = center.saturating_sub(context);
-            let end = (center + context + 1).min(total);
-            for (i, line) in src_lines.iter().enumerate().take(end).skip(start) {
-                lines.push(format!("{:>4} | {}", i + 1, line));
-            }
-        }
-        lines
-    }
-
     /// Format a numbered snippet around a source line from raw TEAL.
     fn truncate_teal_source(source: &str, center_line: u64, context: usize) -> Vec<String> {
         let mut lines: Vec<String> = Vec::new();
@@ -286,93 +347,30 @@ impl LogicErrorContext<'_> {
         lines
     }
 
-    /// Like truncate_teal_source but adds a subtle error marker on the center line.
-    fn annotated_teal_snippet(source: &str, center_line: u64, context: usize) -> Vec<String> {
-        let mut lines = Self::truncate_teal_source(source, center_line, context);
-        // Try to mark the line that equals center_line if present
-        let needle = format!("{:>4} |", center_line);
-        let mut marked = false;
-        for entry in &mut lines {
-            if entry.starts_with(&needle) {
-                *entry = format!("{}\t<-- Error", entry);
-                marked = true;
-                break;
-            }
-        }
-        // Fallback: mark middle line if exact match not found
-        if !marked && !lines.is_empty() {
-            let mid = lines.len() / 2;
-            lines[mid] = format!("{}\t<-- Error", lines[mid]);
-        }
-        lines
-    }
-
-    /// Calculate the offset after initial constant blocks in a TEAL program (CBLOCKS).
-    fn get_constant_block_offset(program: &[u8]) -> usize {
-        const BYTE_CBLOCK: u8 = 38; // bytecblock
-        const INT_CBLOCK: u8 = 32; // intcblock
-        if program.is_empty() {
-            return 0;
-        }
-        let mut i = 1; // skip version byte
-        let len = program.len();
-        let mut bytec_off: Option<usize> = None;
-        let mut intc_off: Option<usize> = None;
-
-        while i < len {
-            let op = program[i];
-            i += 1;
-            if op != BYTE_CBLOCK && op != INT_CBLOCK {
-                break;
-            }
-            if i >= len {
-                break;
-            }
-            let values_remaining = program[i] as usize;
-            i += 1;
-            for _ in 0..values_remaining {
-                if op == BYTE_CBLOCK {
-                    if i >= len {
-                        break;
-                    }
-                    let elem_len = program[i] as usize;
-                    i += 1 + elem_len.min(len.saturating_sub(i));
-                } else {
-                    while i < len {
-                        let b = program[i];
-                        i += 1;
-                        if (b & 0x80) == 0 {
-                            break;
-                        }
-                    }
-                }
-            }
-            let off = i;
-            if op == BYTE_CBLOCK {
-                bytec_off = Some(off)
-            } else {
-                intc_off = Some(off)
-            }
-
-            if i >= len {
-                break;
-            }
-            let next = program[i];
-            if next != BYTE_CBLOCK && next != INT_CBLOCK {
-                break;
-            }
-        }
-        bytec_off.or(intc_off).unwrap_or(0)
-    }
-
-    /// Try to get compiled program bytes for the app from the compilation cache.
+    /// Try to get compiled program bytes for the app from the compilation cache, falling back
+    /// to the ARC-56 `byteCode`, whose constant blocks have the same length unless byte slice
+    /// template variables were substituted.
     /// This avoids async calls; returns None if not available.
     fn get_program_bytes(&self, is_clear_state_program: bool) -> Option<Vec<u8>> {
-        let teal_src = self.decode_teal(is_clear_state_program)?;
-        self.algorand
-            .app()
-            .get_compilation_result(&teal_src)
-            .map(|c| c.compiled_base64_to_bytes)
+        let cached = self
+            .decode_teal(is_clear_state_program)
+            .and_then(|teal_src| {
+                self.algorand
+                    .app()
+                    .get_compilation_result(&teal_src)
+                    .map(|c| c.compiled_base64_to_bytes)
+            });
+        cached.or_else(|| {
+            let byte_code = self.app_spec.byte_code.as_ref()?;
+            let program = if is_clear_state_program {
+                &byte_code.clear
+            } else {
+                &byte_code.approval
+            };
+            base64::engine::general_purpose::STANDARD
+                .decode(program)
+                .ok()
+        })
     }
 
     /// Decode base64 TEAL source from the app spec.
@@ -395,7 +393,21 @@ impl LogicErrorContext<'_> {
 }
 
 impl AppClient {
-    pub(crate) fn transform_transaction_error(
+    /// Transform the error of a send result, see [`Self::transform_transaction_error`].
+    pub(crate) async fn transform_send_result<T>(
+        &self,
+        result: Result<T, TransactionSenderError>,
+        is_clear_state_program: bool,
+    ) -> Result<T, AppClientError> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(err) => Err(self
+                .transform_transaction_error(err, is_clear_state_program)
+                .await),
+        }
+    }
+
+    pub(crate) async fn transform_transaction_error(
         &self,
         err: TransactionSenderError,
         is_clear_state_program: bool,
@@ -421,8 +433,9 @@ impl AppClient {
                     source_maps: self.source_maps.as_ref(),
                 };
 
-                let logic_error =
-                    context.expose_logic_error(&error_message, is_clear_state_program);
+                let logic_error = context
+                    .expose_logic_error(&error_message, is_clear_state_program)
+                    .await;
                 AppClientError::LogicError {
                     message: logic_error.message.clone(),
                     logic: Box::new(logic_error),
@@ -432,3 +445,106 @@ impl AppClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR: &str = "transaction AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA: logic eval error: assert failed pc=885. Details: app=1001, pc=885, opcodes=intc_0 // 0; assert";
+
+    #[test]
+    fn test_extract_logic_error_data() {
+        let data = extract_logic_error_data(ERROR).unwrap();
+
+        assert_eq!(data.transaction_id, "A".repeat(52));
+        assert_eq!(data.message, "assert failed pc=885");
+        assert_eq!(data.pc, 885);
+        assert_eq!(
+            &DETAILS_RE.captures(ERROR).unwrap()["details"],
+            "app=1001, pc=885, opcodes=intc_0 // 0; assert"
+        );
+    }
+
+    #[test]
+    fn test_truncate_teal_source() {
+        let source = (1..=20)
+            .map(|i| format!("op{i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let lines = LogicErrorContext::truncate_teal_source(&source, 10, LOGIC_ERROR_CONTEXT);
+
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "   5 | op5");
+        assert_eq!(lines[10], "  15 | op15");
+    }
+
+    async fn expose(app_spec: &Arc56Contract, pc: u64, is_clear_state_program: bool) -> LogicError {
+        let algorand = AlgorandClient::default_localnet(None);
+        let context = LogicErrorContext {
+            app_id: 1001,
+            app_spec,
+            algorand: &algorand,
+            source_maps: None,
+        };
+        let error = format!(
+            "transaction {}: logic eval error: assert failed pc={pc}. Details: app=1001, pc={pc}",
+            "A".repeat(52)
+        );
+        context
+            .expose_logic_error(&error, is_clear_state_program)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_arc56_error_message() {
+        let app_spec =
+            Arc56Contract::from_json(algokit_test_artifacts::box_map_test::APPLICATION_ARC56)
+                .unwrap();
+
+        let logic = expose(&app_spec, 95, false).await;
+
+        assert!(logic.message.ends_with(
+            "in transaction AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA: this contract does not implement the given ABI method for create NoOp"
+        ));
+        assert_eq!(logic.line_no, Some(74));
+
+        // The clear program has no source info at that pc
+        let logic = expose(&app_spec, 95, true).await;
+        assert!(logic.message.ends_with("assert failed pc=95"));
+    }
+
+    #[tokio::test]
+    async fn test_arc56_error_message_with_cblocks_offset() {
+        let app_spec =
+            Arc56Contract::from_json(algokit_test_artifacts::extra_pages_test::APPLICATION_ARC56)
+                .unwrap();
+        let program = base64::engine::general_purpose::STANDARD
+            .decode(&app_spec.byte_code.as_ref().unwrap().approval)
+            .unwrap();
+        let offset = algokit_abi::source_map::constant_block_offset(&program) as u64;
+
+        let logic = expose(&app_spec, 103 + offset, false).await;
+
+        assert!(logic.message.ends_with(": Check app is updatable"));
+    }
+
+    #[test]
+    fn test_display() {
+        let logic = LogicError {
+            message: "assert failed pc=885".to_string(),
+            line_no: Some(3),
+            lines: Some(vec![
+                "   2 | intc_0 // 0".to_string(),
+                "   3 | assert".to_string(),
+            ]),
+            details: Some("app=1001, pc=885".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            logic.to_string(),
+            "assert failed pc=885. at:3. app=1001, pc=885\n   2 | intc_0 // 0\n   3 | assert\t<-- Error"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::transactions::composer::SimulateParams;
+use crate::transactions::{
+    ComposerTransaction, PaymentParams, TransactionComposer, TransactionComposerSendResult,
+    TransactionComposerSimulateResult,
+};
+use crate::{AppClientError, SendParams};
+use algokit_transact::{OnApplicationComplete, Transaction};
+
+use super::AppClient;
+use super::types::{AppClientBareCallParams, AppClientMethodCallParams};
+
+enum GroupEntry {
+    MethodCall(AppClientMethodCallParams, OnApplicationComplete),
+    BareCall(AppClientBareCallParams, OnApplicationComplete),
+    Transaction(ComposerTransaction),
+}
+
+/// A fluent builder of an atomic group of calls to the app, together with other transactions,
+/// e.g. `client.new_group().call(deposit).add_payment(payment).call(settle).send(None)`.
+///
+/// Calls are resolved against the app spec when the group is built, so calls can be chained
+/// without awaiting each of them.
+pub struct AppClientGroup<'app_client> {
+    client: &'app_client AppClient,
+    entries: Vec<GroupEntry>,
+}
+
+impl<'app_client> AppClientGroup<'app_client> {
+    pub(crate) fn new(client: &'app_client AppClient) -> Self {
+        Self {
+            client,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an ABI method call with NoOp on-complete action.
+    pub fn call(self, params: AppClientMethodCallParams) -> Self {
+        self.add(GroupEntry::MethodCall(params, OnApplicationComplete::NoOp))
+    }
+
+    /// Add an ABI method call with OptIn on-complete action.
+    pub fn opt_in(self, params: AppClientMethodCallParams) -> Self {
+        self.add(GroupEntry::MethodCall(params, OnApplicationComplete::OptIn))
+    }
+
+    /// Add an ABI method call with CloseOut on-complete action.
+    pub fn close_out(self, params: AppClientMethodCallParams) -> Self {
+        self.add(GroupEntry::MethodCall(
+            params,
+            OnApplicationComplete::CloseOut,
+        ))
+    }
+
+    /// Add a bare application call with the specified on-complete action.
+    pub fn bare_call(
+        self,
+        params: AppClientBareCallParams,
+        on_complete: Option<OnApplicationComplete>,
+    ) -> Self {
+        self.add(GroupEntry::BareCall(
+            params,
+            on_complete.unwrap_or(OnApplicationComplete::NoOp),
+        ))
+    }
+
+    /// Add a payment, e.g. to fund a call.
+    pub fn add_payment(self, params: PaymentParams) -> Self {
+        self.add_transaction(ComposerTransaction::Payment(params))
+    }
+
+    /// Add any other transaction, see [`TransactionComposer::add_composer_transaction`].
+    pub fn add_transaction(self, transaction: ComposerTransaction) -> Self {
+        self.add(GroupEntry::Transaction(transaction))
+    }
+
+    /// The number of transactions added, not counting transaction arguments of method calls.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn add(mut self, entry: GroupEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Resolve the calls and add the group to a new composer, e.g. to add further transactions
+    /// before sending.
+    pub async fn composer(&self) -> Result<TransactionComposer, AppClientError> {
+        let mut composer = self
+            .client
+            .algorand()
+            .new_composer(self.client.transaction_composer_config.clone());
+
+        for entry in &self.entries {
+            let added = match entry {
+                GroupEntry::MethodCall(params, on_complete) => {
+                    let method_params = self
+                        .client
+                        .params()
+                        .call(params.clone(), Some(*on_complete))
+                        .await?;
+                    composer.add_app_call_method_call(method_params)
+                }
+                GroupEntry::BareCall(params, on_complete) => {
+                    let bare_params = self
+                        .client
+                        .params()
+                        .bare()
+                        .call(params.clone(), Some(*on_complete))?;
+                    composer.add_app_call(bare_params)
+                }
+                GroupEntry::Transaction(transaction) => {
+                    composer.add_composer_transaction(transaction.clone())
+                }
+            };
+            added.map_err(|e| AppClientError::ComposerError { source: e })?;
+        }
+        Ok(composer)
+    }
+
+    /// Send the group, with the ARC-28 events of the calls to the app decoded.
+    pub async fn send(
+        self,
+        send_params: Option<SendParams>,
+    ) -> Result<TransactionComposerSendResult, AppClientError> {
+        let mut composer = self.composer().await?;
+        let send_result = composer.send(send_params).await.map_err(Into::into);
+        let mut result = self
+            .client
+            .transform_send_result(send_result, false)
+            .await?;
+
+        self.update_opted_in(&result);
+        self.client.populate_events(result.results.iter_mut());
+        Ok(result)
+    }
+
+    /// Simulate the group without sending it, with the ARC-28 events of the calls to the app
+    /// decoded.
+    pub async fn simulate(
+        self,
+        params: Option<SimulateParams>,
+    ) -> Result<TransactionComposerSimulateResult, AppClientError> {
+        let mut composer = self.composer().await?;
+        let mut result = composer
+            .simulate(params)
+            .await
+            .map_err(|e| AppClientError::ComposerError { source: e })?;
+
+        self.client.populate_events(result.results.iter_mut());
+        Ok(result)
+    }
+
+    /// Record the opt-ins and close-outs of the sent group in the client.
+    fn update_opted_in(&self, result: &TransactionComposerSendResult) {
+        for transaction_result in &result.results {
+            let Transaction::AppCall(fields) = &transaction_result.transaction else {
+                continue;
+            };
+            if fields.app_id != self.client.app_id() {
+                continue;
+            }
+            let sender = fields.header.sender.to_string();
+            match fields.on_complete {
+                OnApplicationComplete::OptIn => self.client.set_opted_in(&sender, true),
+                OnApplicationComplete::CloseOut => self.client.set_opted_in(&sender, false),
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,14 +1,16 @@
 use crate::applications::AppDeployer;
 use crate::clients::app_manager::{AppState, BoxName};
 use crate::clients::network_client::NetworkDetails;
+use crate::mbr::{MinBalanceParams, calculate_min_balance};
 use crate::transactions::{TransactionComposerConfig, TransactionSigner};
+use crate::{AlgoAmount, SendParams, SendResult};
 use crate::{AlgorandClient, clients::app_manager::BoxIdentifier};
-use crate::{SendParams, SendResult};
 use algokit_abi::{ABIType, ABIValue, Arc56Contract};
 use algokit_transact::Address;
-use std::collections::HashMap;
+use log::warn;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// A box value decoded according to an ABI type
 #[derive(Debug, Clone)]
@@ -23,23 +25,29 @@ pub struct BoxValue {
     pub name: BoxName,
     pub value: Vec<u8>,
 }
+mod box_map;
 mod compilation;
 mod error;
 pub(crate) mod error_transformation;
+mod group;
 mod params_builder;
 mod state_accessor;
+mod state_diff;
 mod transaction_builder;
 mod transaction_sender;
 mod types;
 mod utils;
+pub use box_map::{BoxMap, BoxMapEntry, BoxMapPage, BoxRef};
 pub use error::AppClientError;
+pub use group::AppClientGroup;
 use params_builder::ParamsBuilder;
 pub use state_accessor::StateAccessor;
+pub use state_diff::{DecodedAppCallStateDiff, DecodedAppStateChange};
 pub use transaction_builder::TransactionBuilder;
 pub use transaction_sender::TransactionSender;
 pub use types::{
     AppClientBareCallParams, AppClientMethodCallParams, AppClientParams, AppSourceMaps,
-    CompilationParams, FundAppAccountParams, LogicError,
+    CompilationParams, FundAppAccountParams, LocalStateOptIn, LogicError,
 };
 
 type BoxNameFilter = Box<dyn Fn(&BoxName) -> bool>;
@@ -54,11 +62,18 @@ pub struct AppClient {
     source_maps: Option<AppSourceMaps>,
     app_name: Option<String>,
     transaction_composer_config: Option<TransactionComposerConfig>,
+    /// Accounts known to be opted in to the app, to avoid repeated lookups
+    opted_in_accounts: Mutex<HashSet<String>>,
 }
 
 impl AppClient {
     /// Create a new client from parameters.
     pub fn new(params: AppClientParams) -> Self {
+        // Calls to methods with colliding selectors can't be routed reliably by the app
+        for collision in params.app_spec.method_selector_collisions() {
+            warn!("App spec {}: {}", params.app_spec.name, collision);
+        }
+
         Self {
             app_id: params.app_id,
             app_spec: params.app_spec,
@@ -68,13 +83,16 @@ impl AppClient {
             source_maps: params.source_maps,
             app_name: params.app_name,
             transaction_composer_config: params.transaction_composer_config,
+            opted_in_accounts: Mutex::new(HashSet::new()),
         }
     }
 
     /// Construct from the current network using app_spec.networks mapping.
     ///
-    /// Matches on either the network alias ("localnet", "testnet", "mainnet")
-    /// or the network's genesis hash present in the node's suggested params.
+    /// Matches on either the network's genesis hash present in the node's suggested params,
+    /// the name of a matching network registered with
+    /// [`NetworkRegistry`](crate::clients::NetworkRegistry) or the network alias
+    /// ("localnet", "testnet", "mainnet").
     pub async fn from_network(
         app_spec: Arc56Contract,
         algorand: Arc<AlgorandClient>,
@@ -176,6 +194,9 @@ impl AppClient {
 
     fn candidate_network_keys(network: &NetworkDetails) -> Vec<String> {
         let mut names = vec![network.genesis_hash.clone()];
+        if let Some(network_name) = &network.network_name {
+            names.push(network_name.clone());
+        }
         if network.is_localnet {
             names.push("localnet".to_string());
         }
@@ -221,6 +242,20 @@ impl AppClient {
         self.default_sender.as_ref()
     }
 
+    /// Get the sender for this client's transactions, which sponsors each group when the
+    /// composer config has a fee payer.
+    pub(crate) fn algorand_sender(&self) -> crate::transactions::TransactionSender {
+        let sender = self.algorand.send();
+        match self
+            .transaction_composer_config
+            .as_ref()
+            .and_then(|config| config.fee_payer.clone())
+        {
+            Some(fee_payer) => sender.with_fee_payer(fee_payer),
+            None => sender.clone(),
+        }
+    }
+
     /// Get the application's account address.
     pub fn app_address(&self) -> Address {
         Address::from_app_id(&self.app_id)
@@ -257,6 +292,24 @@ impl AppClient {
         })
     }
 
+    /// Get the minimum balance requirement of the application's account, given the assets, apps
+    /// and boxes it currently holds, e.g. to derive the amount of a [`Self::fund_app_account`].
+    pub async fn get_app_account_mbr(&self) -> Result<AlgoAmount, AppClientError> {
+        let account = self
+            .algorand
+            .client()
+            .algod()
+            .account_information(&self.app_address().to_string(), None, None)
+            .await
+            .map_err(|e| AppClientError::Network {
+                message: format!(
+                    "Failed to get account information of app {}: {}",
+                    self.app_id, e
+                ),
+            })?;
+        Ok(calculate_min_balance(&MinBalanceParams::from(&account)))
+    }
+
     /// Fund the application's account with Algos.
     pub async fn fund_app_account(
         &self,
@@ -287,6 +340,41 @@ impl AppClient {
             .map_err(|e| AppClientError::AppManagerError { source: e })
     }
 
+    /// Check whether an account is opted in to the application.
+    ///
+    /// Accounts found to be opted in are cached by the client, and are removed from the cache
+    /// when they close out or clear their state through the client.
+    pub async fn is_opted_in(&self, address: &str) -> Result<bool, AppClientError> {
+        if self.opted_in_accounts().contains(address) {
+            return Ok(true);
+        }
+
+        let opted_in = self
+            .algorand
+            .app()
+            .is_opted_in(self.app_id, address)
+            .await
+            .map_err(|e| AppClientError::AppManagerError { source: e })?;
+        if opted_in {
+            self.opted_in_accounts().insert(address.to_string());
+        }
+        Ok(opted_in)
+    }
+
+    fn opted_in_accounts(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.opted_in_accounts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn set_opted_in(&self, address: &str, opted_in: bool) {
+        if opted_in {
+            self.opted_in_accounts().insert(address.to_string());
+        } else {
+            self.opted_in_accounts().remove(address);
+        }
+    }
+
     /// Get all box names for the application.
     pub async fn get_box_names(&self) -> Result<Vec<BoxName>, AppClientError> {
         self.algorand
@@ -373,6 +461,10 @@ impl AppClient {
     pub fn send(&self) -> TransactionSender<'_> {
         TransactionSender { client: self }
     }
+    /// Start an atomic group of calls to this app, see [`AppClientGroup`].
+    pub fn new_group(&self) -> AppClientGroup<'_> {
+        AppClientGroup::new(self)
+    }
     /// Get a state accessor for reading application state with ABI decoding.
     pub fn state(&self) -> StateAccessor<'_> {
         StateAccessor::new(self)
@@ -227,22 +227,13 @@ impl<'app_client> ParamsBuilder<'app_client> {
     async fn resolve_args(
         &self,
         method: &ABIMethod,
-        provided: &Vec<AppMethodCallArg>,
+        provided: &[AppMethodCallArg],
         sender: &str,
     ) -> Result<Vec<AppMethodCallArg>, AppClientError> {
         let mut resolved: Vec<AppMethodCallArg> = Vec::with_capacity(method.args.len());
+        let provided = Self::with_omitted_defaults(method, provided)?;
 
-        if method.args.len() != provided.len() {
-            return Err(AppClientError::ValidationError {
-                message: format!(
-                    "The number of provided arguments is {} while the method expects {} arguments",
-                    provided.len(),
-                    method.args.len()
-                ),
-            });
-        }
-
-        for (index, (method_arg, provided_arg)) in method.args.iter().zip(provided).enumerate() {
+        for (index, (method_arg, provided_arg)) in method.args.iter().zip(&provided).enumerate() {
             let method_arg_name = method_arg
                 .name
                 .clone()
@@ -289,6 +280,43 @@ impl<'app_client> ParamsBuilder<'app_client> {
         Ok(resolved)
     }
 
+    /// Fill in trailing arguments left out of `provided` with [`AppMethodCallArg::DefaultValue`]
+    /// when they all have an ARC-56 default value, as the TypeScript and Python clients do.
+    ///
+    /// Omitted transaction arguments are passed through to be matched to the group by the
+    /// composer, see [`crate::transactions::TransactionComposer::add_app_call_method_call`].
+    fn with_omitted_defaults(
+        method: &ABIMethod,
+        provided: &[AppMethodCallArg],
+    ) -> Result<Vec<AppMethodCallArg>, AppClientError> {
+        let count_error = || AppClientError::ValidationError {
+            message: format!(
+                "The number of provided arguments is {} while the method expects {} arguments",
+                provided.len(),
+                method.args.len()
+            ),
+        };
+        let omitted = method.args.get(provided.len()..).ok_or_else(count_error)?;
+
+        if omitted.iter().all(|arg| arg.default_value.is_some()) {
+            return Ok(provided
+                .iter()
+                .cloned()
+                .chain(omitted.iter().map(|_| AppMethodCallArg::DefaultValue))
+                .collect());
+        }
+
+        let transaction_args = method
+            .args
+            .iter()
+            .filter(|arg| matches!(arg.arg_type, ABIMethodArgType::Transaction(_)))
+            .count();
+        if omitted.len() <= transaction_args {
+            return Ok(provided.to_vec());
+        }
+        Err(count_error())
+    }
+
     async fn resolve_state_value(
         &self,
         default: &ABIDefaultValue,
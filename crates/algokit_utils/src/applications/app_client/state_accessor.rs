@@ -1,3 +1,4 @@
+use super::box_map::{BoxMap, BoxRef};
 use super::{AppClient, AppClientError};
 use crate::clients::app_manager::AppState;
 use algokit_abi::arc56_contract::{ABIStorageKey, ABIStorageMap};
@@ -44,6 +45,50 @@ impl<'app_client> StateAccessor<'app_client> {
             client: self.client,
         }
     }
+
+    /// Shorthand for [`Self::global_state`].
+    pub fn global(&self) -> AppStateAccessor<'_> {
+        self.global_state()
+    }
+
+    /// Shorthand for [`Self::local_state`].
+    pub fn local(&self, address: &str) -> AppStateAccessor<'_> {
+        self.local_state(address)
+    }
+
+    /// Get a box map declared in the ARC-56 `state.maps.box`.
+    ///
+    /// The key prefix and the key and value ABI types come from the declaration, so values are
+    /// read by their ABI key and struct value types decode to [`ABIValue::Struct`].
+    pub fn box_map(&self, map_name: &str) -> Result<BoxMap<'app_client>, AppClientError> {
+        let storage_maps = self
+            .client
+            .app_spec
+            .get_box_abi_storage_maps()
+            .map_err(|e| AppClientError::ABIError { source: e })?;
+        let storage_map =
+            storage_maps
+                .get(map_name)
+                .ok_or_else(|| AppClientError::AppStateError {
+                    message: format!("Box map '{}' not found", map_name),
+                })?;
+        BoxMap::from_storage_map(self.client, storage_map)
+    }
+
+    /// Get a box declared in the ARC-56 `state.keys.box`.
+    pub fn box_ref(&self, name: &str) -> Result<BoxRef<'app_client>, AppClientError> {
+        let storage_keys = self
+            .client
+            .app_spec
+            .get_box_abi_storage_keys()
+            .map_err(|e| AppClientError::ABIError { source: e })?;
+        let storage_key = storage_keys
+            .get(name)
+            .ok_or_else(|| AppClientError::AppStateError {
+                message: format!("Box key '{}' not found", name),
+            })?;
+        BoxRef::from_storage_key(self.client, storage_key)
+    }
 }
 
 type GetStateResult = Result<HashMap<Vec<u8>, AppState>, AppClientError>;
@@ -178,15 +223,7 @@ impl<'provider> AppStateAccessor<'provider> {
                 .ok_or_else(|| AppClientError::AppStateError {
                     message: format!("{} state map '{}' not found", self.name, map_name),
                 })?;
-        let prefix_bytes = if let Some(prefix_b64) = &storage_map.prefix {
-            base64::engine::general_purpose::STANDARD
-                .decode(prefix_b64)
-                .map_err(|e| AppClientError::AppStateError {
-                    message: format!("Failed to decode map prefix: {}", e),
-                })?
-        } else {
-            Vec::new()
-        };
+        let prefix_bytes = map_prefix_bytes(storage_map)?;
 
         let mut result = HashMap::new();
         for (key, app_state) in state.iter() {
@@ -222,15 +259,7 @@ impl<'provider> AppStateAccessor<'provider> {
                     message: format!("{} state map '{}' not found", self.name, map_name),
                 })?;
 
-        let prefix_bytes = if let Some(prefix_b64) = &storage_map.prefix {
-            base64::engine::general_purpose::STANDARD
-                .decode(prefix_b64)
-                .map_err(|e| AppClientError::AppStateError {
-                    message: format!("Failed to decode map prefix: {}", e),
-                })?
-        } else {
-            Vec::new()
-        };
+        let prefix_bytes = map_prefix_bytes(storage_map)?;
         let encoded_key = storage_map
             .key_type
             .encode(&key)
@@ -309,51 +338,14 @@ impl BoxStateAccessor<'_> {
         &self,
         map_name: &str,
     ) -> Result<HashMap<ABIValue, ABIValue>, AppClientError> {
-        let storage_map_map = self
-            .client
-            .app_spec
-            .get_box_abi_storage_maps()
-            .map_err(|e| AppClientError::ABIError { source: e })?;
-        let storage_map =
-            storage_map_map
-                .get(map_name)
-                .ok_or_else(|| AppClientError::AppStateError {
-                    message: format!("Box map '{}' not found", map_name),
-                })?;
-
-        let prefix_bytes = if let Some(prefix_b64) = &storage_map.prefix {
-            base64::engine::general_purpose::STANDARD
-                .decode(prefix_b64)
-                .map_err(|e| AppClientError::AppStateError {
-                    message: format!("Failed to decode map prefix: {}", e),
-                })?
-        } else {
-            Vec::new()
-        };
-
-        let box_names = self.client.get_box_names().await?;
-        let box_names = box_names
-            .iter()
-            .filter(|box_name| box_name.name_raw.starts_with(&prefix_bytes))
-            .collect::<Vec<_>>();
-
-        let mut results: HashMap<ABIValue, ABIValue> = HashMap::new();
-        for box_name in box_names {
-            let tail = &box_name.name_raw[prefix_bytes.len()..];
-            let decoded_key = storage_map
-                .key_type
-                .decode(tail)
-                .map_err(|e| AppClientError::ABIError { source: e })?;
-
-            let box_value = self.client.get_box_value(&box_name.name_raw).await?;
-            let decoded_value = storage_map
-                .value_type
-                .decode(&box_value)
-                .map_err(|e| AppClientError::ABIError { source: e })?;
-            results.insert(decoded_key, decoded_value);
-        }
-
-        Ok(results)
+        let entries = StateAccessor::new(self.client)
+            .box_map(map_name)?
+            .entries()
+            .await?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| (entry.key, entry.value))
+            .collect())
     }
 
     /// Get a specific value from an ABI-defined box map by key.
@@ -362,45 +354,22 @@ impl BoxStateAccessor<'_> {
         map_name: &str,
         key: &ABIValue,
     ) -> Result<Option<ABIValue>, AppClientError> {
-        let storage_map_map = self
-            .client
-            .app_spec
-            .get_box_abi_storage_maps()
-            .map_err(|e| AppClientError::ABIError { source: e })?;
-        let storage_map =
-            storage_map_map
-                .get(map_name)
-                .ok_or_else(|| AppClientError::AppStateError {
-                    message: format!("Box map '{}' not found", map_name),
-                })?;
-
-        let prefix_bytes = if let Some(prefix_b64) = &storage_map.prefix {
-            base64::engine::general_purpose::STANDARD
-                .decode(prefix_b64)
-                .map_err(|e| AppClientError::AppStateError {
-                    message: format!("Failed to decode map prefix: {}", e),
-                })?
-        } else {
-            Vec::new()
-        };
-
-        let encoded_key = storage_map
-            .key_type
-            .encode(key)
-            .map_err(|e| AppClientError::ABIError { source: e })?;
-        let full_key = [prefix_bytes, encoded_key].concat();
-
-        let box_value = match self.client.get_box_value(&full_key).await {
-            Ok(val) => val,
-            Err(AppClientError::AppStateError { .. }) => return Ok(None),
-            Err(e) => return Err(e),
-        };
+        StateAccessor::new(self.client)
+            .box_map(map_name)?
+            .get(key)
+            .await
+    }
+}
 
-        let decoded = storage_map
-            .value_type
-            .decode(&box_value)
-            .map_err(|e| AppClientError::ABIError { source: e })?;
-        Ok(Some(decoded))
+/// The key prefix of a storage map, empty when the map has none.
+pub(super) fn map_prefix_bytes(storage_map: &ABIStorageMap) -> Result<Vec<u8>, AppClientError> {
+    match &storage_map.prefix {
+        Some(prefix_b64) => base64::engine::general_purpose::STANDARD
+            .decode(prefix_b64)
+            .map_err(|e| AppClientError::AppStateError {
+                message: format!("Failed to decode map prefix: {}", e),
+            }),
+        None => Ok(Vec::new()),
     }
 }
 
@@ -0,0 +1,107 @@
+use super::{AppClient, AppClientError};
+use crate::clients::state_layout::{RawStateValue, StateLayout};
+use crate::transactions::{AppCallStateDiff, AppStateChange, AppStateType, AppStateValue};
+use algokit_abi::ABIValue;
+
+/// A state change decoded according to the ARC-56 storage definitions of an app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAppStateChange {
+    pub state_type: AppStateType,
+    pub key: Vec<u8>,
+    /// The name of the matching storage key or map, if the key is described in the app spec
+    pub name: Option<String>,
+    /// The decoded map key, if the key belongs to a storage map
+    pub map_key: Option<ABIValue>,
+    pub before: Option<ABIValue>,
+    pub after: Option<ABIValue>,
+}
+
+/// The state changes made by an app call, decoded according to the app's ARC-56 spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAppCallStateDiff {
+    pub path: Vec<usize>,
+    pub app_id: u64,
+    pub changes: Vec<DecodedAppStateChange>,
+}
+
+impl AppClient {
+    /// Decode a simulated state diff using the storage keys and maps described in the app spec.
+    ///
+    /// Keys that aren't described in the app spec are returned undecoded, with uint values as
+    /// [`ABIValue::Uint`] and byte values as [`ABIValue::Bytes`]. Values that don't decode with
+    /// their declared type are an error, as the app spec doesn't describe the app.
+    pub fn decode_state_diff(
+        &self,
+        diff: &AppCallStateDiff,
+    ) -> Result<DecodedAppCallStateDiff, AppClientError> {
+        if diff.app_id != self.app_id {
+            return Err(AppClientError::ValidationError {
+                message: format!(
+                    "State diff is for app {} but this client is for app {}",
+                    diff.app_id, self.app_id
+                ),
+            });
+        }
+
+        let changes = diff
+            .changes
+            .iter()
+            .map(|change| self.decode_state_change(change))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DecodedAppCallStateDiff {
+            path: diff.path.clone(),
+            app_id: diff.app_id,
+            changes,
+        })
+    }
+
+    fn decode_state_change(
+        &self,
+        change: &AppStateChange,
+    ) -> Result<DecodedAppStateChange, AppClientError> {
+        let (storage_keys, storage_maps) = match change.state_type {
+            AppStateType::Global => (
+                self.app_spec.get_global_abi_storage_keys(),
+                self.app_spec.get_global_abi_storage_maps(),
+            ),
+            AppStateType::Local { .. } => (
+                self.app_spec.get_local_abi_storage_keys(),
+                self.app_spec.get_local_abi_storage_maps(),
+            ),
+            AppStateType::Box => (
+                self.app_spec.get_box_abi_storage_keys(),
+                self.app_spec.get_box_abi_storage_maps(),
+            ),
+        };
+        let to_error = |e| AppClientError::ABIError { source: e };
+        let layout = StateLayout::new(
+            storage_keys.map_err(to_error)?,
+            storage_maps.map_err(to_error)?,
+        )
+        .map_err(to_error)?;
+
+        let entry = layout.describe(&change.key);
+        let decode = |value: &Option<AppStateValue>| {
+            value
+                .as_ref()
+                .map(|value| {
+                    let value = match value {
+                        AppStateValue::Uint(uint) => RawStateValue::Uint(*uint),
+                        AppStateValue::Bytes(bytes) => RawStateValue::Bytes(bytes),
+                    };
+                    StateLayout::decode_value(entry.as_ref(), value).map_err(to_error)
+                })
+                .transpose()
+        };
+
+        Ok(DecodedAppStateChange {
+            state_type: change.state_type.clone(),
+            key: change.key.clone(),
+            name: entry.as_ref().map(|entry| entry.name.to_string()),
+            map_key: entry.as_ref().and_then(|entry| entry.map_key.clone()),
+            before: decode(&change.before)?,
+            after: decode(&change.after)?,
+        })
+    }
+}
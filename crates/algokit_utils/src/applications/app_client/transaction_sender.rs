@@ -2,11 +2,17 @@ use crate::applications::app_client::types::{
     AppClientUpdateMethodCallResult, AppClientUpdateResult,
 };
 use crate::transactions::SendResult;
-use crate::transactions::composer::SimulateParams;
-use crate::{AppClientError, SendAppMethodCallResult, SendParams};
-use algokit_transact::{MAX_SIMULATE_OPCODE_BUDGET, OnApplicationComplete};
+use crate::transactions::composer::{SimulateParams, TransactionResult};
+use crate::{
+    AppCallMethodCallParams, AppCallParams, AppClientError, SendAppMethodCallResult, SendParams,
+};
+use algokit_abi::CallOnApplicationComplete;
+use algokit_transact::{MAX_SIMULATE_OPCODE_BUDGET, OnApplicationComplete, Transaction};
+use log::warn;
 
-use super::types::{AppClientBareCallParams, AppClientMethodCallParams, CompilationParams};
+use super::types::{
+    AppClientBareCallParams, AppClientMethodCallParams, CompilationParams, LocalStateOptIn,
+};
 use super::{AppClient, FundAppAccountParams};
 
 pub struct TransactionSender<'app_client> {
@@ -38,68 +44,231 @@ impl<'app_client> TransactionSender<'app_client> {
             .get_method(&params.method)
             .map_err(|e| AppClientError::ABIError { source: e })?;
 
-        let mut method_params = self.client.params().call(params, on_complete).await?;
+        let local_state_opt_in = params.local_state_opt_in;
+        let method_params = self.client.params().call(params, on_complete).await?;
 
         if method_params.on_complete == OnApplicationComplete::NoOp
             && arc56_method.readonly == Some(true)
         {
-            let transaction_composer_config = self.client.transaction_composer_config.clone();
-
-            let mut composer = self
-                .client
-                .algorand()
-                .new_composer(transaction_composer_config.clone());
-
-            if transaction_composer_config
-                .clone()
-                .is_some_and(|c| c.cover_app_call_inner_transaction_fees)
-                && method_params.max_fee.is_some()
-            {
-                method_params.static_fee = method_params.max_fee;
-                method_params.extra_fee = None;
+            return self.simulate_readonly(method_params, None).await;
+        }
+
+        let opt_in = match local_state_opt_in {
+            Some(mode) => self.check_opted_in(&method_params, mode).await?,
+            None => None,
+        };
+        match opt_in {
+            Some(opt_in) => {
+                self.send_with_opt_in(opt_in, method_params, send_params)
+                    .await
+            }
+            None => {
+                let sender = method_params.sender.to_string();
+                let closes_out = method_params.on_complete == OnApplicationComplete::CloseOut;
+                let send_result = self
+                    .client
+                    .algorand_sender()
+                    .app_call_method_call(method_params, send_params)
+                    .await;
+                self.client
+                    .transform_send_result(send_result, false)
+                    .await
+                    .inspect(|_| {
+                        if closes_out {
+                            self.client.set_opted_in(&sender, false)
+                        }
+                    })
+                    .map(|result| self.with_events(result))
             }
+        }
+    }
+
+    /// Check the sender of a call is opted in to the app when the app has local state.
+    ///
+    /// Returns the opt-in call to prepend to the group when the sender isn't opted in and `mode`
+    /// is [`LocalStateOptIn::Auto`].
+    async fn check_opted_in(
+        &self,
+        method_params: &AppCallMethodCallParams,
+        mode: LocalStateOptIn,
+    ) -> Result<Option<AppCallParams>, AppClientError> {
+        let local_schema = &self.client.app_spec.state.schema.local_state;
+        let requires_local_state = local_schema.ints + local_schema.bytes > 0
+            && matches!(
+                method_params.on_complete,
+                OnApplicationComplete::NoOp | OnApplicationComplete::CloseOut
+            );
+        if !requires_local_state {
+            return Ok(None);
+        }
 
-            let _ = composer
-                .add_app_call_method_call(method_params)
-                .map_err(|e| AppClientError::ComposerError { source: e });
+        let sender = method_params.sender.to_string();
+        if self.client.is_opted_in(&sender).await? {
+            return Ok(None);
+        }
 
-            let simulate_params = SimulateParams {
-                allow_unnamed_resources: Some(
-                    transaction_composer_config
-                        .map(|c| c.populate_app_call_resources.is_enabled())
-                        .unwrap_or(true),
+        let method = method_params.method.signature().unwrap_or_default();
+        match mode {
+            LocalStateOptIn::Require => Err(AppClientError::ValidationError {
+                message: format!(
+                    "Account {} is not opted in to app {}, which has local state used by {}",
+                    sender, self.client.app_id, method
                 ),
-                skip_signatures: true,
-                extra_opcode_budget: Some(MAX_SIMULATE_OPCODE_BUDGET),
-                ..Default::default()
-            };
-
-            let simulate_results = composer
-                .simulate(Some(simulate_params))
-                .await
-                .map_err(|e| AppClientError::ComposerError { source: e })?;
-
-            let last_result = simulate_results
-                .results
-                .last()
-                .ok_or(AppClientError::ValidationError {
-                    message: "No transaction returned".to_string(),
-                })?
-                .clone();
-
-            Ok(SendAppMethodCallResult {
-                result: last_result,
-                group_results: simulate_results.results,
-                group: simulate_results.group,
-            })
-        } else {
-            self.client
-                .algorand
-                .send()
-                .app_call_method_call(method_params, send_params)
-                .await
-                .map_err(|e| self.client.transform_transaction_error(e, false))
+            }),
+            LocalStateOptIn::Auto => {
+                let bare_opt_in_allowed = self
+                    .client
+                    .app_spec
+                    .bare_actions
+                    .call
+                    .contains(&CallOnApplicationComplete::OptIn);
+                if !bare_opt_in_allowed {
+                    return Err(AppClientError::ValidationError {
+                        message: format!(
+                            "Account {} is not opted in to app {} and the app doesn't allow a bare opt-in, opt in before calling {}",
+                            sender, self.client.app_id, method
+                        ),
+                    });
+                }
+                let opt_in = self
+                    .client
+                    .params()
+                    .bare()
+                    .opt_in(AppClientBareCallParams {
+                        sender: Some(sender),
+                        signer: method_params.signer.clone(),
+                        ..Default::default()
+                    })?;
+                Ok(Some(opt_in))
+            }
+        }
+    }
+
+    async fn send_with_opt_in(
+        &self,
+        opt_in: AppCallParams,
+        method_params: AppCallMethodCallParams,
+        send_params: Option<SendParams>,
+    ) -> Result<SendAppMethodCallResult, AppClientError> {
+        let sender = opt_in.sender.to_string();
+        let mut composer = self
+            .client
+            .algorand()
+            .new_composer(self.client.transaction_composer_config.clone());
+        composer
+            .add_app_call(opt_in)
+            .and_then(|_| composer.add_app_call_method_call(method_params))
+            .map_err(|e| AppClientError::ComposerError { source: e })?;
+
+        let send_result = composer.send(send_params).await.map_err(Into::into);
+        let composer_results = self
+            .client
+            .transform_send_result(send_result, false)
+            .await?;
+        self.client.set_opted_in(&sender, true);
+
+        let result = composer_results
+            .results
+            .last()
+            .ok_or(AppClientError::ValidationError {
+                message: "No transaction returned".to_string(),
+            })?
+            .clone();
+
+        Ok(self.with_events(SendAppMethodCallResult {
+            result,
+            group_results: composer_results.results,
+            group: composer_results.group,
+        }))
+    }
+
+    /// Execute an ABI method call via simulate without submitting a transaction.
+    ///
+    /// Calls to methods marked `readonly` are routed here by [`TransactionSender::call`], this
+    /// can be used to read the return value of any NoOp method call without changing state.
+    /// Signatures are skipped, so no signer is needed for the sender.
+    ///
+    /// `simulate_params` override the defaults of the simulation, e.g. to simulate at an earlier
+    /// round or with a lower extra opcode budget than the maximum.
+    pub async fn call_readonly(
+        &self,
+        params: AppClientMethodCallParams,
+        simulate_params: Option<SimulateParams>,
+    ) -> Result<SendAppMethodCallResult, AppClientError> {
+        let method_params = self
+            .client
+            .params()
+            .call(params, Some(OnApplicationComplete::NoOp))
+            .await?;
+
+        self.simulate_readonly(method_params, simulate_params).await
+    }
+
+    async fn simulate_readonly(
+        &self,
+        mut method_params: AppCallMethodCallParams,
+        simulate_params: Option<SimulateParams>,
+    ) -> Result<SendAppMethodCallResult, AppClientError> {
+        let transaction_composer_config = self.client.transaction_composer_config.clone();
+
+        let mut composer = self
+            .client
+            .algorand()
+            .new_composer(transaction_composer_config.clone());
+
+        if transaction_composer_config
+            .clone()
+            .is_some_and(|c| c.cover_app_call_inner_transaction_fees)
+            && method_params.max_fee.is_some()
+        {
+            method_params.static_fee = method_params.max_fee;
+            method_params.extra_fee = None;
         }
+
+        composer
+            .add_app_call_method_call(method_params)
+            .map_err(|e| AppClientError::ComposerError { source: e })?;
+
+        let simulate_params = simulate_params.unwrap_or_default();
+        let simulate_params = SimulateParams {
+            allow_unnamed_resources: simulate_params.allow_unnamed_resources.or(Some(
+                transaction_composer_config
+                    .map(|c| c.populate_app_call_resources.is_enabled())
+                    .unwrap_or(true),
+            )),
+            skip_signatures: true,
+            extra_opcode_budget: simulate_params
+                .extra_opcode_budget
+                .or(Some(MAX_SIMULATE_OPCODE_BUDGET)),
+            ..simulate_params
+        };
+
+        let simulate_results = composer
+            .simulate(Some(simulate_params))
+            .await
+            .map_err(|e| AppClientError::ComposerError { source: e })?;
+
+        let last_result = simulate_results
+            .results
+            .last()
+            .ok_or(AppClientError::ValidationError {
+                message: "No transaction returned".to_string(),
+            })?
+            .clone();
+
+        Ok(self.with_events(SendAppMethodCallResult {
+            result: last_result,
+            group_results: simulate_results.results,
+            group: simulate_results.group,
+        }))
+    }
+
+    /// Populate the ARC-28 events of the calls to this app in the result.
+    fn with_events(&self, mut result: SendAppMethodCallResult) -> SendAppMethodCallResult {
+        self.client.populate_events(
+            std::iter::once(&mut result.result).chain(result.group_results.iter_mut()),
+        );
+        result
     }
 
     /// Execute an ABI method call with OptIn on-complete action.
@@ -110,12 +279,15 @@ impl<'app_client> TransactionSender<'app_client> {
     ) -> Result<SendAppMethodCallResult, AppClientError> {
         let method_params = self.client.params().opt_in(params).await?;
 
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call_method_call(method_params, send_params)
+            .await;
+        self.client
+            .transform_send_result(send_result, false)
             .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .map(|result| self.with_events(result))
     }
 
     /// Execute an ABI method call with CloseOut on-complete action.
@@ -125,13 +297,18 @@ impl<'app_client> TransactionSender<'app_client> {
         send_params: Option<SendParams>,
     ) -> Result<SendAppMethodCallResult, AppClientError> {
         let method_params = self.client.params().close_out(params).await?;
+        let sender = method_params.sender.to_string();
 
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call_method_call(method_params, send_params)
+            .await;
+        self.client
+            .transform_send_result(send_result, false)
             .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .inspect(|_| self.client.set_opted_in(&sender, false))
+            .map(|result| self.with_events(result))
     }
 
     /// Execute an ABI method call with Delete on-complete action.
@@ -142,12 +319,15 @@ impl<'app_client> TransactionSender<'app_client> {
     ) -> Result<SendAppMethodCallResult, AppClientError> {
         let delete_params = self.client.params().delete(params).await?;
 
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_delete_method_call(delete_params, send_params)
+            .await;
+        self.client
+            .transform_send_result(send_result, false)
             .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .map(|result| self.with_events(result))
     }
 
     /// Update the application using an ABI method call.
@@ -163,13 +343,17 @@ impl<'app_client> TransactionSender<'app_client> {
             .update(params, compilation_params)
             .await?;
 
-        let result = self
+        let send_result = self
             .client
-            .algorand()
-            .send()
+            .algorand_sender()
             .app_update_method_call(update_params, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))?;
+            .await;
+        let result = self
+            .client
+            .transform_send_result(send_result, false)
+            .await?;
+
+        let result = self.with_events(result);
 
         Ok(AppClientUpdateMethodCallResult {
             result: result.result,
@@ -187,12 +371,37 @@ impl<'app_client> TransactionSender<'app_client> {
     ) -> Result<SendResult, AppClientError> {
         let payment = self.client.params().fund_app_account(&params)?;
 
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .payment(payment, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .await;
+        self.client.transform_send_result(send_result, false).await
+    }
+}
+
+impl AppClient {
+    /// Decode the ARC-28 events of the calls to this app among `transaction_results`.
+    pub(crate) fn populate_events<'a>(
+        &self,
+        transaction_results: impl Iterator<Item = &'a mut TransactionResult>,
+    ) {
+        for transaction_result in transaction_results {
+            let is_app_call = matches!(
+                &transaction_result.transaction,
+                Transaction::AppCall(fields) if fields.app_id == self.app_id
+            );
+            if !is_app_call {
+                continue;
+            }
+            match transaction_result.decode_events(&self.app_spec) {
+                Ok(events) => transaction_result.events = events,
+                Err(e) => warn!(
+                    "Failed to decode events of transaction {}: {}",
+                    transaction_result.transaction_id, e
+                ),
+            }
+        }
     }
 }
 
@@ -205,12 +414,12 @@ impl BareTransactionSender<'_> {
         send_params: Option<SendParams>,
     ) -> Result<SendResult, AppClientError> {
         let params = self.client.params().bare().call(params, on_complete)?;
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call(params, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .await;
+        self.client.transform_send_result(send_result, false).await
     }
 
     /// Execute a bare application call with OptIn on-complete action.
@@ -220,12 +429,12 @@ impl BareTransactionSender<'_> {
         send_params: Option<SendParams>,
     ) -> Result<SendResult, AppClientError> {
         let app_call = self.client.params().bare().opt_in(params)?;
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call(app_call, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .await;
+        self.client.transform_send_result(send_result, false).await
     }
 
     /// Execute a bare application call with CloseOut on-complete action.
@@ -235,12 +444,16 @@ impl BareTransactionSender<'_> {
         send_params: Option<SendParams>,
     ) -> Result<SendResult, AppClientError> {
         let app_call = self.client.params().bare().close_out(params)?;
-        self.client
-            .algorand
-            .send()
+        let sender = app_call.sender.to_string();
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call(app_call, send_params)
+            .await;
+        self.client
+            .transform_send_result(send_result, false)
             .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .inspect(|_| self.client.set_opted_in(&sender, false))
     }
 
     /// Execute a bare application call with Delete on-complete action.
@@ -250,12 +463,12 @@ impl BareTransactionSender<'_> {
         send_params: Option<SendParams>,
     ) -> Result<SendResult, AppClientError> {
         let delete_params = self.client.params().bare().delete(params)?;
-        self.client
-            .algorand
-            .send()
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_delete(delete_params, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))
+            .await;
+        self.client.transform_send_result(send_result, false).await
     }
 
     /// Execute a bare application call with ClearState on-complete action.
@@ -265,12 +478,16 @@ impl BareTransactionSender<'_> {
         send_params: Option<SendParams>,
     ) -> Result<SendResult, AppClientError> {
         let app_call = self.client.params().bare().clear_state(params)?;
-        self.client
-            .algorand
-            .send()
+        let sender = app_call.sender.to_string();
+        let send_result = self
+            .client
+            .algorand_sender()
             .app_call(app_call, send_params)
+            .await;
+        self.client
+            .transform_send_result(send_result, true)
             .await
-            .map_err(|e| self.client.transform_transaction_error(e, true))
+            .inspect(|_| self.client.set_opted_in(&sender, false))
     }
 
     /// Update the application using a bare application call.
@@ -287,13 +504,15 @@ impl BareTransactionSender<'_> {
             .update(params, compilation_params)
             .await?;
 
-        let result = self
+        let send_result = self
             .client
-            .algorand()
-            .send()
+            .algorand_sender()
             .app_update(update_params, send_params)
-            .await
-            .map_err(|e| self.client.transform_transaction_error(e, false))?;
+            .await;
+        let result = self
+            .client
+            .transform_send_result(send_result, false)
+            .await?;
 
         Ok(AppClientUpdateResult {
             transaction: result.transaction,
@@ -1,3 +1,4 @@
+use crate::AlgoAmount;
 use crate::AlgorandClient;
 use crate::clients::app_manager::{CompiledPrograms, TealTemplateValue};
 use crate::transactions::TransactionComposerConfig;
@@ -5,7 +6,7 @@ use crate::transactions::TransactionResult;
 use crate::transactions::TransactionSigner;
 use crate::transactions::app_call::AppMethodCallArg;
 use algod_client::models::PendingTransactionResponse;
-use algokit_abi::Arc56Contract;
+use algokit_abi::{Arc56Contract, ProgramSourceMap};
 use algokit_transact::BoxReference;
 use algokit_transact::Byte32;
 use algokit_transact::Transaction;
@@ -16,8 +17,8 @@ use std::sync::Arc;
 /// Container for source maps captured during compilation/simulation.
 #[derive(Debug, Clone, Default)]
 pub struct AppSourceMaps {
-    pub approval_source_map: Option<serde_json::Value>,
-    pub clear_source_map: Option<serde_json::Value>,
+    pub approval_source_map: Option<ProgramSourceMap>,
+    pub clear_source_map: Option<ProgramSourceMap>,
 }
 
 /// Parameters required to construct an AppClient instance.
@@ -36,7 +37,7 @@ pub struct AppClientParams {
 /// Parameters for funding an application's account.
 #[derive(Debug, Clone, Default)]
 pub struct FundAppAccountParams {
-    pub amount: u64,
+    pub amount: AlgoAmount,
     pub sender: Option<String>,
     #[debug(skip)]
     pub signer: Option<Arc<dyn TransactionSigner>>,
@@ -73,6 +74,17 @@ pub struct AppClientMethodCallParams {
     pub app_references: Option<Vec<u64>>,
     pub asset_references: Option<Vec<u64>>,
     pub box_references: Option<Vec<BoxReference>>,
+    /// Verify the sender is opted in to the app before the call, when the app has local state.
+    pub local_state_opt_in: Option<LocalStateOptIn>,
+}
+
+/// How to handle a sender that isn't opted in to an app with local state before a method call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalStateOptIn {
+    /// Return an error without sending the call
+    Require,
+    /// Prepend a bare opt-in call to the group
+    Auto,
 }
 
 /// Parameters for bare (non-ABI) app call operations
@@ -102,45 +114,67 @@ pub struct AppClientBareCallParams {
 pub struct LogicError {
     pub message: String,
     pub program: Option<Vec<u8>>,
-    pub source_map: Option<serde_json::Value>,
+    pub source_map: Option<ProgramSourceMap>,
     pub transaction_id: Option<String>,
+    /// The ID of the app whose program failed
+    pub app_id: Option<u64>,
     pub pc: Option<u64>,
+    /// The 1-based TEAL line of the failed pc
     pub line_no: Option<u64>,
+    /// The TEAL source of the failed line
+    pub teal_line: Option<String>,
+    /// Numbered TEAL lines around the failed line
     pub lines: Option<Vec<String>>,
+    /// The details algod reports for the error, e.g. `app=1, pc=885, opcodes=...`
+    pub details: Option<String>,
+    /// The cost of the program when it ran out of opcode budget
+    pub program_cost: Option<u64>,
     pub traces: Option<Vec<serde_json::Value>>,
     /// Original logic error string if parsed
     pub logic_error_str: Option<String>,
 }
 
+/// Formats the error like the TypeScript utils, e.g.
+///
+/// ```text
+/// Runtime error when executing App (appId: 1) in transaction TXID: assert failed pc=885. at:469. app=1, pc=885, opcodes=...
+///  467 | error:
+///  468 |     intc_0 // 0
+///  469 |     assert    <-- Error
+/// ```
 impl std::fmt::Display for LogicError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let tx = self.transaction_id.as_deref().unwrap_or("N/A");
-        let pc = self
-            .pc
-            .map(|p| p.to_string())
-            .unwrap_or_else(|| "N/A".to_string());
-        let mut base = format!("Txn {} had error '{}' at PC {}", tx, self.message, pc);
-        if let Some(line) = self.line_no {
-            base.push_str(&format!(" and Source Line {}", line));
+        let line = self
+            .line_no
+            .map(|line| line.to_string())
+            .unwrap_or_else(|| "??".to_string());
+        write!(f, "{}. at:{}.", self.message, line)?;
+        if let Some(details) = &self.details {
+            write!(f, " {}", details)?;
+        }
+        if let Some(cost) = self.program_cost {
+            write!(f, "\nOpcode budget exceeded, program cost was {}", cost)?;
         }
-        writeln!(f, "{}", base)?;
         if let Some(trace) = self.annotated_trace() {
-            write!(f, "{}", trace)?;
+            write!(f, "\n{}", trace.trim_end())?;
         }
         Ok(())
     }
 }
 
 impl LogicError {
-    /// Build a simple annotated snippet string from stored lines and line number.
+    /// The stored TEAL lines, with the failed line marked.
     pub fn annotated_trace(&self) -> Option<String> {
         let lines = self.lines.as_ref()?;
-        let line_no = self.line_no? as usize;
+        let marker = self.line_no.map(|line_no| format!("{:>4} |", line_no));
         let mut out = String::new();
         for entry in lines {
             out.push_str(entry);
-            if entry.starts_with(&format!("{:>4} |", line_no)) {
-                out.push_str("\t<--- Error");
+            if marker
+                .as_ref()
+                .is_some_and(|marker| entry.starts_with(marker))
+            {
+                out.push_str("\t<-- Error");
             }
             out.push('\n');
         }
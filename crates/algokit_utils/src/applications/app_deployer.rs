@@ -1,18 +1,30 @@
+use crate::clients::AlgodErrorKind;
 use crate::clients::app_manager::{
-    AppInformation, AppManager, AppManagerError, CompiledPrograms, CompiledTeal,
+    AppInformation, AppManager, AppManagerError, AppState, CompiledPrograms, CompiledTeal,
     DeploymentMetadata, TealTemplateParams,
 };
-use crate::transactions::{TransactionResult, TransactionSender, TransactionSenderError};
+use crate::config::{
+    AppDeployOperation, AppDeployedEventData, Config, EventData, EventType, RetryEventData,
+    RetryOperation,
+};
+use crate::transactions::{
+    TransactionComposer, TransactionComposerSendResult, TransactionResult, TransactionSender,
+    TransactionSenderError,
+};
 use crate::{
     AppCreateMethodCallParams, AppCreateParams, AppDeleteMethodCallParams, AppDeleteParams,
     AppMethodCallArg, AppUpdateMethodCallParams, AppUpdateParams, ComposerError, SendParams,
-    create_transaction_params,
+    SimulateParams, create_transaction_params,
 };
-use algokit_transact::{Address, Byte32, OnApplicationComplete};
+use algod_client::models::PendingTransactionResponse;
+use algokit_abi::SpecDiff;
+use algokit_transact::{Address, Byte32, OnApplicationComplete, Transaction, TransactionId};
+use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose};
 use indexer_client::{IndexerClient, apis::Error as IndexerError};
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use snafu::Snafu;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -70,6 +82,40 @@ impl Default for OnUpdate {
     }
 }
 
+/// What to do when the account of an app that is being replaced holds funds, which are stranded
+/// once the app is deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FundsHandling {
+    /// Replace the app without checking its account
+    Ignore,
+    /// Log a warning when the account holds ALGO or assets (default)
+    Warn,
+    /// Fail the deployment before anything is sent when the account holds ALGO or assets
+    Fail,
+    /// Sweep the funds of the account to `receiver` with the delete call of the app.
+    ///
+    /// The deployer can't send the funds of an app account, so the delete call must close the
+    /// account with inner transactions: an asset transfer closing out each held asset and a
+    /// payment closing out the ALGO, all to `receiver`. Its fee has to cover them, e.g. with
+    /// an `extra_fee`. The delete call is simulated first, and the deployment fails before it
+    /// is sent if it wouldn't.
+    Sweep { receiver: Address },
+}
+
+impl Default for FundsHandling {
+    fn default() -> Self {
+        Self::Warn
+    }
+}
+
+/// The ALGO and assets held by the account of an app being replaced.
+#[derive(Debug, Clone, PartialEq)]
+struct StrandedFunds {
+    app_address: Address,
+    amount: u64,
+    asset_ids: Vec<u64>,
+}
+
 /// The deployment metadata for an application
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AppDeployMetadata {
@@ -78,11 +124,37 @@ pub struct AppDeployMetadata {
     /// The version of the application
     pub version: String,
     /// Whether the application is updatable
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub updatable: Option<bool>,
     /// Whether the application is deletable
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deletable: Option<bool>,
 }
 
+impl AppDeployMetadata {
+    /// The ARC-2 note of deploy transactions, `ALGOKIT_DEPLOYER:j{...}`.
+    ///
+    /// Unset flags are left out of the JSON, so that the note is byte for byte the one written
+    /// by the other AlgoKit Utils SDKs.
+    pub fn to_arc2_note(&self) -> Result<Vec<u8>, AppDeployError> {
+        let metadata_json =
+            serde_json::to_string(self).map_err(|e| AppDeployError::DeploymentFailed {
+                message: format!("Failed to serialize metadata: {}", e),
+            })?;
+        Ok(format!("{}:j{}", APP_DEPLOY_NOTE_PREFIX, metadata_json).into_bytes())
+    }
+
+    /// Parse the metadata from the ARC-2 note of a deploy transaction, e.g. a note returned by
+    /// indexer, or `None` when the note isn't a deploy note.
+    pub fn from_arc2_note(note: &[u8]) -> Option<Self> {
+        let note = std::str::from_utf8(note).ok()?;
+        let json = note
+            .strip_prefix(APP_DEPLOY_NOTE_PREFIX)?
+            .strip_prefix(":j")?;
+        serde_json::from_str(json).ok()
+    }
+}
+
 /// The metadata that can be collected about a deployed app
 #[derive(Debug, Clone)]
 pub struct AppMetadata {
@@ -232,6 +304,8 @@ pub struct AppDeployParams {
     pub on_schema_break: Option<OnSchemaBreak>,
     /// What action to perform if a TEAL code update is detected
     pub on_update: Option<OnUpdate>,
+    /// What to do when the app is replaced while its account holds funds
+    pub on_replace_funds: Option<FundsHandling>,
     /// Create transaction parameters to use if a create needs to be issued as part of deployment
     pub create_params: CreateParams,
     /// Update transaction parameters to use if an update needs to be issued as part of deployment
@@ -244,6 +318,9 @@ pub struct AppDeployParams {
     pub ignore_cache: Option<bool>,
     /// Send transaction parameters
     pub send_params: SendParams,
+    /// Optional diff between the app spec of the existing app and the one being deployed;
+    /// breaking changes are handled as a schema break when the app would be updated
+    pub spec_diff: Option<SpecDiff>,
 }
 
 /// The result of an app deployment operation
@@ -308,6 +385,30 @@ pub enum AppDeployError {
     DeploymentLookupFailed { message: String },
 }
 
+/// How to recover when sending an app create transaction fails without a definite outcome,
+/// e.g. when a CI job loses its connection to algod while waiting for confirmation.
+///
+/// The create transaction is sent with a lease derived from the app name, version and programs
+/// (unless a lease is given), and resent creates keep the validity window of the original, so
+/// only one of them can be confirmed. Before each retry the window is searched for every create
+/// sent so far. Definite rejections, e.g. an overspend or a failed logic eval, aren't retried.
+#[derive(Debug, Clone)]
+pub struct CreateRetryParams {
+    /// The maximum number of times to resend the create transaction
+    pub max_retries: u32,
+}
+
+/// Migrates the state of an app that is being replaced to the app that replaces it.
+///
+/// Without a migration, a replace creates the new app and deletes the old one in one atomic
+/// group. With a migration, the create is sent first, then [`AppMigration::migrate`] is called
+/// with both app IDs, and the old app is only deleted once the migration succeeded. A failed
+/// migration stops the deployment and leaves both apps in place.
+#[async_trait(?Send)]
+pub trait AppMigration {
+    async fn migrate(&self, old_app_id: u64, new_app_id: u64) -> Result<(), String>;
+}
+
 /// Allows management of deployment and deployment metadata of applications.
 #[derive(Clone)]
 pub struct AppDeployer {
@@ -315,6 +416,10 @@ pub struct AppDeployer {
     app_manager: AppManager,
     transaction_sender: TransactionSender,
     app_lookups: Arc<Mutex<HashMap<String, AppLookup>>>,
+    create_retry: Option<CreateRetryParams>,
+    migration: Option<Arc<dyn AppMigration>>,
+    creation_round_hints: Vec<u64>,
+    ignore_unresolved_apps: bool,
 }
 
 impl AppDeployer {
@@ -334,28 +439,110 @@ impl AppDeployer {
             app_manager,
             transaction_sender,
             app_lookups: Arc::new(Mutex::new(HashMap::new())),
+            create_retry: None,
+            migration: None,
+            creation_round_hints: Vec::new(),
+            ignore_unresolved_apps: false,
+        }
+    }
+
+    /// Create a deployer that retries app creates which fail without a definite outcome,
+    /// without ever creating the app twice.
+    pub fn with_create_retry(&self, create_retry: CreateRetryParams) -> Self {
+        Self {
+            create_retry: Some(create_retry),
+            ..self.clone()
+        }
+    }
+
+    /// Create a deployer that migrates the state of replaced apps with `migration`, see
+    /// [`AppMigration`].
+    pub fn with_migration(&self, migration: Arc<dyn AppMigration>) -> Self {
+        Self {
+            migration: Some(migration),
+            ..self.clone()
+        }
+    }
+
+    /// Create a deployer that, without an indexer, searches these rounds for the create
+    /// transactions of apps, see [`Self::get_creator_apps_by_name_from_algod`].
+    pub fn with_creation_round_hints(&self, creation_round_hints: Vec<u64>) -> Self {
+        Self {
+            creation_round_hints,
+            ..self.clone()
+        }
+    }
+
+    /// Create a deployer that, without an indexer, leaves out the apps of a creator whose
+    /// deployment metadata can't be found with algod rather than failing the lookup.
+    ///
+    /// Such an app may be the one being deployed, so deploying with this set can create a
+    /// duplicate of it.
+    pub fn with_unresolved_apps_ignored(&self, ignore_unresolved_apps: bool) -> Self {
+        Self {
+            ignore_unresolved_apps,
+            ..self.clone()
+        }
+    }
+
+    /// Create a deployer that shares this deployer's app lookup cache, but sends its
+    /// transactions with the given `TransactionSender`.
+    pub(crate) fn with_transaction_sender(&self, transaction_sender: TransactionSender) -> Self {
+        Self {
+            transaction_sender,
+            ..self.clone()
         }
     }
 
     pub async fn deploy(
         &mut self,
         deployment: AppDeployParams,
+    ) -> Result<AppDeployResult, AppDeployError> {
+        let result = self.deploy_app(deployment).await?;
+
+        let events = Config::events();
+        if events.has_listeners() {
+            let (app, operation) = match &result {
+                AppDeployResult::Create { app, .. } => (app, AppDeployOperation::Create),
+                AppDeployResult::Update { app, .. } => (app, AppDeployOperation::Update),
+                AppDeployResult::Replace { app, .. } => (app, AppDeployOperation::Replace),
+                AppDeployResult::Nothing { app } => (app, AppDeployOperation::Nothing),
+            };
+            let data = AppDeployedEventData {
+                app_name: app.name.clone(),
+                version: app.version.clone(),
+                app_id: app.app_id,
+                operation,
+            };
+            events
+                .emit(EventType::AppDeployed, EventData::AppDeployed(data))
+                .await;
+        }
+
+        Ok(result)
+    }
+
+    async fn deploy_app(
+        &mut self,
+        deployment: AppDeployParams,
     ) -> Result<AppDeployResult, AppDeployError> {
         let AppDeployParams {
             metadata,
             deploy_time_params,
             on_schema_break,
             on_update,
+            on_replace_funds,
             mut create_params,
             mut update_params,
             delete_params,
             existing_deployments,
             ignore_cache,
             send_params,
+            spec_diff,
         } = deployment;
 
         // Build deployment note using ARC2 format
-        let arc2_note = Self::build_deployment_note(&metadata)?;
+        let arc2_note = metadata.to_arc2_note()?;
         match &mut create_params {
             CreateParams::AppCreateCall(params) => {
                 params.note = Some(arc2_note.clone());
@@ -389,13 +576,6 @@ impl AppDeployer {
             }
         }
 
-        if existing_deployments.is_none() && self.indexer_client.is_none() {
-            return Err(AppDeployError::DeploymentFailed {
-                message: String::from(
-                    "Either indexer client or existing deployments must be provided",
-                ),
-            });
-        }
         // Compile TEAL code if needed and handle template replacement
         let compiled_programs = self
             .compile_app_programs(
@@ -461,11 +641,12 @@ impl AppDeployer {
             &existing_app,
             &compiled_programs.approval.compiled_base64_to_bytes,
             &compiled_programs.clear.compiled_base64_to_bytes,
-        )?;
+        )? || (is_update && Self::is_spec_break(spec_diff.as_ref()));
 
         if is_schema_break {
             self.handle_schema_break(
                 on_schema_break.unwrap_or_default(),
+                on_replace_funds.unwrap_or_default(),
                 existing_app_metadata,
                 &metadata,
                 &create_params,
@@ -477,6 +658,7 @@ impl AppDeployer {
         } else if is_update {
             self.handle_update(
                 on_update.unwrap_or_default(),
+                on_replace_funds.unwrap_or_default(),
                 existing_app_metadata,
                 &metadata,
                 &create_params,
@@ -494,7 +676,10 @@ impl AppDeployer {
         }
     }
 
-    /// Get apps created by a specific creator address
+    /// Get apps created by a specific creator address.
+    ///
+    /// Without an indexer the apps are looked up with algod only, see
+    /// [`Self::get_creator_apps_by_name_from_algod`].
     pub async fn get_creator_apps_by_name(
         &mut self,
         creator_address: &Address,
@@ -512,14 +697,12 @@ impl AppDeployer {
             }
         }
 
-        let indexer =
-            self.indexer_client
-                .as_ref()
-                .ok_or(AppDeployError::DeploymentLookupFailed {
-                    message: String::from(
-                        "No indexer client or existing deployments cache provided",
-                    ),
-                })?;
+        let Some(indexer) = self.indexer_client.as_ref() else {
+            let creation_round_hints = self.creation_round_hints.clone();
+            return self
+                .get_creator_apps_by_name_from_algod(creator_address, &creation_round_hints)
+                .await;
+        };
 
         // Query indexer for apps created by this address; localnet-only retry to allow catch-up
         let created_apps_response = indexer
@@ -607,10 +790,10 @@ impl AppDeployer {
 
                 if let Some(creation_txn) = app_creation_transaction {
                     if let Some(note) = &creation_txn.note {
-                        let creation_note = Self::parse_deploy_note(note);
+                        let creation_note = AppDeployMetadata::from_arc2_note(note);
                         let update_note = latest_app_update_transaction
                             .and_then(|t| t.note.as_ref())
-                            .and_then(|note| Self::parse_deploy_note(note.as_slice()));
+                            .and_then(|note| AppDeployMetadata::from_arc2_note(note));
 
                         if let Some(creation_metadata) = creation_note {
                             // Use update metadata if available, otherwise fall back to creation metadata
@@ -652,12 +835,114 @@ impl AppDeployer {
         Ok(lookup)
     }
 
-    fn build_deployment_note(metadata: &AppDeployMetadata) -> Result<Vec<u8>, AppDeployError> {
-        let metadata_json =
-            serde_json::to_string(&metadata).map_err(|e| AppDeployError::DeploymentFailed {
-                message: format!("Failed to serialize metadata: {}", e),
+    /// Get apps created by a specific creator address from algod, for setups without an
+    /// indexer, e.g. a LocalNet without indexer.
+    ///
+    /// Algod only knows the apps the creator currently has, so deleted apps aren't found. The
+    /// deployment metadata of an app is read from a deploy note in its global state, otherwise
+    /// from its create transaction when it was confirmed in one of `creation_round_hints` and
+    /// algod still has the transaction in its cache of recently confirmed transactions.
+    ///
+    /// An app without metadata may be the app being deployed, so the lookup fails when the
+    /// creator has one, unless the deployer was created with
+    /// [`Self::with_unresolved_apps_ignored`].
+    pub async fn get_creator_apps_by_name_from_algod(
+        &mut self,
+        creator_address: &Address,
+        creation_round_hints: &[u64],
+    ) -> Result<AppLookup, AppDeployError> {
+        let algod_client = self.app_manager.algod_client();
+        let creator_address_str = creator_address.to_string();
+        let lookup_failed = |message: String| AppDeployError::DeploymentLookupFailed { message };
+
+        let created_apps = algod_client
+            .account_information(&creator_address_str, None, None)
+            .await
+            .map_err(|e| lookup_failed(format!("Failed to get account information: {}", e)))?
+            .created_apps
+            .unwrap_or_default();
+
+        // The round and note of each app created by the creator in the hinted rounds
+        let mut create_notes = HashMap::new();
+        for &round in creation_round_hints {
+            let block = algod_client.get_block_txids(round).await.map_err(|e| {
+                lookup_failed(format!(
+                    "Failed to get transaction IDs of round {}: {}",
+                    round, e
+                ))
             })?;
-        Ok(format!("{}:j{}", APP_DEPLOY_NOTE_PREFIX, metadata_json).into_bytes())
+            for transaction_id in &block.block_txids {
+                let Ok(confirmation) = algod_client
+                    .pending_transaction_information(transaction_id)
+                    .await
+                else {
+                    continue;
+                };
+                let header = confirmation.txn.transaction.header();
+                if let (Some(app_id), Some(note)) = (confirmation.app_id, &header.note) {
+                    if header.sender == *creator_address {
+                        create_notes.insert(app_id, (round, note.clone()));
+                    }
+                }
+            }
+        }
+
+        let mut app_lookup = HashMap::new();
+        let mut unresolved_app_ids = Vec::new();
+        for app in created_apps {
+            let global_state =
+                AppManager::decode_app_state(&app.params.global_state.clone().unwrap_or_default())
+                    .map_err(|e| AppDeployError::AppManagerError { source: e })?;
+            let state_metadata = global_state.values().find_map(|state| match state {
+                AppState::Bytes(bytes) => AppDeployMetadata::from_arc2_note(&bytes.value_raw),
+                AppState::Uint(_) => None,
+            });
+            let create_note = create_notes.get(&app.id);
+            let created_round = create_note.map_or(0, |(round, _)| *round);
+            let Some(metadata) = state_metadata.or_else(|| {
+                create_note.and_then(|(_, note)| AppDeployMetadata::from_arc2_note(note))
+            }) else {
+                unresolved_app_ids.push(app.id);
+                continue;
+            };
+
+            app_lookup.insert(
+                metadata.name.clone(),
+                AppMetadata {
+                    app_id: app.id,
+                    app_address: Address::from_app_id(&app.id),
+                    created_round,
+                    updated_round: created_round,
+                    deleted: false,
+                    name: metadata.name.clone(),
+                    version: metadata.version.clone(),
+                    updatable: metadata.updatable,
+                    deletable: metadata.deletable,
+                    created_metadata: metadata,
+                },
+            );
+        }
+
+        if !unresolved_app_ids.is_empty() {
+            let message = format!(
+                "The deployment metadata of apps {:?} created by {} can't be found with algod, so any of them may be the app being deployed; use an indexer or creation round hints to find it",
+                unresolved_app_ids, creator_address
+            );
+            if !self.ignore_unresolved_apps {
+                return Err(lookup_failed(message));
+            }
+            warn!("{}", message);
+        }
+
+        let lookup = AppLookup {
+            creator: creator_address.clone(),
+            apps: app_lookup,
+        };
+        self.app_lookups
+            .lock()
+            .unwrap()
+            .insert(creator_address_str, lookup.clone());
+        Ok(lookup)
     }
 
     /// Compile app programs, applying template replacement only for TEAL variant
@@ -711,16 +996,6 @@ impl AppDeployer {
         Ok(CompiledPrograms { approval, clear })
     }
 
-    fn parse_deploy_note(note: &[u8]) -> Option<AppDeployMetadata> {
-        if let Ok(utf8_note) = std::str::from_utf8(note) {
-            if utf8_note.starts_with(&format!("{}:j", APP_DEPLOY_NOTE_PREFIX)) {
-                let json_part = &utf8_note[APP_DEPLOY_NOTE_PREFIX.len() + 2..];
-                return serde_json::from_str::<AppDeployMetadata>(json_part).ok();
-            }
-        }
-        None
-    }
-
     fn is_program_different(
         &self,
         approval_program: &[u8],
@@ -771,10 +1046,26 @@ impl AppDeployer {
             || extra_pages_break)
     }
 
+    fn is_spec_break(spec_diff: Option<&SpecDiff>) -> bool {
+        let Some(spec_diff) = spec_diff.filter(|diff| diff.is_breaking()) else {
+            return false;
+        };
+        warn!(
+            "Detected breaking app spec changes: {}",
+            spec_diff
+                .breaking_changes()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; ")
+        );
+        true
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn handle_schema_break(
         &mut self,
         on_schema_break: OnSchemaBreak,
+        on_replace_funds: FundsHandling,
         existing_app_metadata: &AppMetadata,
         metadata: &AppDeployMetadata,
         create_params: &CreateParams,
@@ -811,6 +1102,7 @@ impl AppDeployer {
                     );
                 }
                 self.replace_app(
+                    on_replace_funds,
                     existing_app_metadata,
                     metadata,
                     create_params,
@@ -827,6 +1119,7 @@ impl AppDeployer {
     async fn handle_update(
         &mut self,
         on_update: OnUpdate,
+        on_replace_funds: FundsHandling,
         existing_app_metadata: &AppMetadata,
         metadata: &AppDeployMetadata,
         create_params: &CreateParams,
@@ -879,6 +1172,7 @@ impl AppDeployer {
                     );
                 }
                 self.replace_app(
+                    on_replace_funds,
                     existing_app_metadata,
                     metadata,
                     create_params,
@@ -898,7 +1192,85 @@ impl AppDeployer {
         compiled_programs: CompiledPrograms,
         send_params: &SendParams,
     ) -> Result<AppDeployResult, AppDeployError> {
+        let composer_result = match self.create_retry.clone() {
+            Some(create_retry) => {
+                self.send_create_with_retry(
+                    metadata,
+                    create_params,
+                    &compiled_programs,
+                    send_params,
+                    &create_retry,
+                )
+                .await?
+            }
+            None => self
+                .create_app_composer(create_params, &compiled_programs, None, None)?
+                .send(Some(send_params.clone()))
+                .await
+                .map_err(|e| AppDeployError::ComposerError { source: e })?,
+        };
+
+        let create_transaction_index = composer_result.results.len() - 1;
+
+        // Extract results from the create transaction
+        let create_result = composer_result.results[create_transaction_index].clone();
+
+        let confirmation = create_result.confirmation.clone();
+        let app_id = confirmation
+            .app_id
+            .ok_or_else(|| AppDeployError::DeploymentFailed {
+                message: "App creation confirmation missing application-index".to_string(),
+            })?;
+
+        let app_address = Address::from_app_id(&app_id);
+        let confirmed_round =
+            confirmation
+                .confirmed_round
+                .ok_or_else(|| AppDeployError::DeploymentFailed {
+                    message: "App creation confirmation missing confirmed-round".to_string(),
+                })?;
+
+        let app_metadata = AppMetadata {
+            app_id,
+            app_address,
+            created_round: confirmed_round,
+            updated_round: confirmed_round,
+            created_metadata: metadata.clone(),
+            deleted: false,
+            name: metadata.name.clone(),
+            version: metadata.version.clone(),
+            updatable: metadata.updatable,
+            deletable: metadata.deletable,
+        };
+
+        let sender = match create_params {
+            CreateParams::AppCreateCall(params) => &params.sender,
+            CreateParams::AppCreateMethodCall(params) => &params.sender,
+        };
+
+        self.update_app_lookup(sender, &app_metadata);
+
+        Ok(AppDeployResult::Create {
+            app: app_metadata,
+            create_result,
+            group_results: composer_result.results,
+            group: composer_result.group,
+            compiled_programs,
+        })
+    }
+
+    fn create_app_composer(
+        &self,
+        create_params: &CreateParams,
+        compiled_programs: &CompiledPrograms,
+        lease: Option<[u8; 32]>,
+        validity: Option<(u64, u64)>,
+    ) -> Result<TransactionComposer, AppDeployError> {
         let mut composer = self.transaction_sender.new_composer(None);
+        let first_valid_round =
+            |round: Option<u64>| validity.map(|(first_valid, _)| first_valid).or(round);
+        let last_valid_round =
+            |round: Option<u64>| validity.map(|(_, last_valid)| last_valid).or(round);
 
         match create_params {
             CreateParams::AppCreateCall(params) => {
@@ -911,13 +1283,13 @@ impl AppDeployer {
                     signer: params.signer.clone(),
                     rekey_to: params.rekey_to.clone(),
                     note: params.note.clone(),
-                    lease: params.lease,
+                    lease: params.lease.or(lease),
                     static_fee: params.static_fee,
                     extra_fee: params.extra_fee,
                     max_fee: params.max_fee,
                     validity_window: params.validity_window,
-                    first_valid_round: params.first_valid_round,
-                    last_valid_round: params.last_valid_round,
+                    first_valid_round: first_valid_round(params.first_valid_round),
+                    last_valid_round: last_valid_round(params.last_valid_round),
                     on_complete: params.on_complete,
                     approval_program: compiled_programs.approval.compiled_base64_to_bytes.clone(),
                     clear_state_program: compiled_programs.clear.compiled_base64_to_bytes.clone(),
@@ -944,13 +1316,13 @@ impl AppDeployer {
                     signer: params.signer.clone(),
                     rekey_to: params.rekey_to.clone(),
                     note: params.note.clone(),
-                    lease: params.lease,
+                    lease: params.lease.or(lease),
                     static_fee: params.static_fee,
                     extra_fee: params.extra_fee,
                     max_fee: params.max_fee,
                     validity_window: params.validity_window,
-                    first_valid_round: params.first_valid_round,
-                    last_valid_round: params.last_valid_round,
+                    first_valid_round: first_valid_round(params.first_valid_round),
+                    last_valid_round: last_valid_round(params.last_valid_round),
                     on_complete: params.on_complete,
                     approval_program: compiled_programs.approval.compiled_base64_to_bytes.clone(),
                     clear_state_program: compiled_programs.clear.compiled_base64_to_bytes.clone(),
@@ -970,58 +1342,198 @@ impl AppDeployer {
             }
         };
 
-        let composer_result = composer
-            .send(Some(send_params.clone()))
-            .await
-            .map_err(|e| AppDeployError::ComposerError { source: e })?;
+        Ok(composer)
+    }
 
-        let create_transaction_index = composer_result.results.len() - 1;
+    /// Send the create transaction, resending it after failures that leave it unknown whether
+    /// it was confirmed.
+    ///
+    /// Every attempt is sent with the lease and validity window of the first, so at most one of
+    /// them can be confirmed. All attempts are searched for before each retry, and a new window
+    /// is only started once the previous one has passed without any of them being confirmed.
+    async fn send_create_with_retry(
+        &self,
+        metadata: &AppDeployMetadata,
+        create_params: &CreateParams,
+        compiled_programs: &CompiledPrograms,
+        send_params: &SendParams,
+        create_retry: &CreateRetryParams,
+    ) -> Result<TransactionComposerSendResult, AppDeployError> {
+        let lease = Self::create_lease(metadata, compiled_programs);
+        let mut attempts: Vec<Transaction> = Vec::new();
+        let mut retries = 0;
+        loop {
+            let validity = attempts
+                .first()
+                .map(|attempt| (attempt.header().first_valid, attempt.header().last_valid));
+            let mut composer =
+                self.create_app_composer(create_params, compiled_programs, Some(lease), validity)?;
+            let create_transaction = composer
+                .build()
+                .await
+                .map_err(|e| AppDeployError::ComposerError { source: e })?[0]
+                .transaction
+                .clone();
+            attempts.push(create_transaction);
+
+            let error = match composer.send(Some(send_params.clone())).await {
+                Ok(result) => return Ok(result),
+                Err(e) if Self::is_ambiguous_send_error(&e) => e,
+                // The lease is taken by an earlier attempt, unless it's the first attempt and
+                // another create of the same app and version was sent elsewhere
+                Err(e) if Self::is_lease_in_use(&e) && attempts.len() > 1 => e,
+                Err(e) => return Err(AppDeployError::ComposerError { source: e }),
+            };
+
+            let last_round = self
+                .app_manager
+                .algod_client()
+                .get_status()
+                .await
+                .map_err(|e| AppDeployError::DeploymentLookupFailed {
+                    message: format!("Failed to get status: {}", e),
+                })?
+                .last_round;
+            if let Some(result) = self.find_create_transaction(&attempts, last_round).await? {
+                info!(
+                    "App create transaction was confirmed despite the send error: {}",
+                    error
+                );
+                return Ok(result);
+            }
+            if retries >= create_retry.max_retries {
+                return Err(AppDeployError::ComposerError { source: error });
+            }
+            retries += 1;
+            // None of the attempts can be confirmed once their window has passed, so the next
+            // attempt may start a new one
+            if attempts[0].header().last_valid <= last_round {
+                attempts.clear();
+            }
+            warn!(
+                "Sending the app create transaction failed and it wasn't confirmed, retrying ({}/{}): {}",
+                retries, create_retry.max_retries, error
+            );
 
-        // Extract results from the create transaction
-        let create_result = composer_result.results[create_transaction_index].clone();
+            let events = Config::events();
+            if events.has_listeners() {
+                let data = RetryEventData {
+                    operation: RetryOperation::AppCreate,
+                    attempt: retries,
+                    max_retries: create_retry.max_retries,
+                    error: error.to_string(),
+                };
+                events.emit(EventType::Retry, EventData::Retry(data)).await;
+            }
+        }
+    }
 
-        let confirmation = create_result.confirmation.clone();
-        let app_id = confirmation
-            .app_id
-            .ok_or_else(|| AppDeployError::DeploymentFailed {
-                message: "App creation confirmation missing application-index".to_string(),
-            })?;
+    /// A lease unique to the app and version being created, so only one create for it can be
+    /// confirmed within a validity window.
+    fn create_lease(
+        metadata: &AppDeployMetadata,
+        compiled_programs: &CompiledPrograms,
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(APP_DEPLOY_NOTE_PREFIX.as_bytes());
+        hasher.update(metadata.name.as_bytes());
+        hasher.update(metadata.version.as_bytes());
+        hasher.update(&compiled_programs.approval.compiled_base64_to_bytes);
+        hasher.update(&compiled_programs.clear.compiled_base64_to_bytes);
+        hasher.finalize().into()
+    }
 
-        let app_address = Address::from_app_id(&app_id);
-        let confirmed_round =
-            confirmation
-                .confirmed_round
-                .ok_or_else(|| AppDeployError::DeploymentFailed {
-                    message: "App creation confirmation missing confirmed-round".to_string(),
-                })?;
+    /// Whether the transaction may have been confirmed even though sending it failed, i.e. the
+    /// outcome is unknown rather than a definite rejection.
+    fn is_ambiguous_send_error(error: &ComposerError) -> bool {
+        match error {
+            ComposerError::MaxWaitRoundExpired { .. } | ComposerError::StatusError { .. } => true,
+            // The submission may have reached the node before the connection failed
+            ComposerError::SubmitError { source } => source.is_transient(),
+            ComposerError::AlgodClientError { source } => {
+                AlgodErrorKind::from(source).is_transient()
+            }
+            _ => false,
+        }
+    }
 
-        let app_metadata = AppMetadata {
-            app_id,
-            app_address,
-            created_round: confirmed_round,
-            updated_round: confirmed_round,
-            created_metadata: metadata.clone(),
-            deleted: false,
-            name: metadata.name.clone(),
-            version: metadata.version.clone(),
-            updatable: metadata.updatable,
-            deletable: metadata.deletable,
-        };
+    fn is_lease_in_use(error: &ComposerError) -> bool {
+        matches!(error.algod_error(), Some(AlgodErrorKind::LeaseInUse { .. }))
+    }
 
-        let sender = match create_params {
-            CreateParams::AppCreateCall(params) => &params.sender,
-            CreateParams::AppCreateMethodCall(params) => &params.sender,
-        };
+    /// Search for the attempts to send a create transaction up to `last_round`, returning the
+    /// result of the one that was confirmed.
+    ///
+    /// Rounds that are no longer in the pending transaction cache of algod are searched by
+    /// transaction ID; a match there fails the deployment, as the created app can only be found
+    /// once the indexer catches up.
+    async fn find_create_transaction(
+        &self,
+        attempts: &[Transaction],
+        last_round: u64,
+    ) -> Result<Option<TransactionComposerSendResult>, AppDeployError> {
+        let algod_client = self.app_manager.algod_client();
+        let transaction_ids = attempts
+            .iter()
+            .map(|attempt| attempt.id())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppDeployError::DeploymentLookupFailed {
+                message: e.to_string(),
+            })?;
 
-        self.update_app_lookup(sender, &app_metadata);
+        for (transaction, transaction_id) in attempts.iter().zip(&transaction_ids) {
+            let Ok(confirmation) = algod_client
+                .pending_transaction_information(transaction_id)
+                .await
+            else {
+                continue;
+            };
+            if confirmation.confirmed_round.is_some() {
+                return Ok(Some(TransactionComposerSendResult {
+                    group: transaction.header().group,
+                    results: vec![TransactionResult {
+                        transaction: transaction.clone(),
+                        transaction_id: transaction_id.clone(),
+                        confirmation,
+                        abi_return: None,
+                        events: Vec::new(),
+                        params: None,
+                    }],
+                    fee_payment: None,
+                }));
+            }
+        }
 
-        Ok(AppDeployResult::Create {
-            app: app_metadata,
-            create_result,
-            group_results: composer_result.results,
-            group: composer_result.group,
-            compiled_programs,
-        })
+        let first_valid = attempts
+            .iter()
+            .map(|attempt| attempt.header().first_valid)
+            .min();
+        let last_valid = attempts
+            .iter()
+            .map(|attempt| attempt.header().last_valid)
+            .max();
+        let (Some(first_valid), Some(last_valid)) = (first_valid, last_valid) else {
+            return Ok(None);
+        };
+        for round in first_valid..=last_valid.min(last_round) {
+            let block = algod_client.get_block_txids(round).await.map_err(|e| {
+                AppDeployError::DeploymentLookupFailed {
+                    message: format!("Failed to get transaction IDs of round {}: {}", round, e),
+                }
+            })?;
+            if let Some(transaction_id) = transaction_ids
+                .iter()
+                .find(|transaction_id| block.block_txids.contains(transaction_id))
+            {
+                return Err(AppDeployError::DeploymentFailed {
+                    message: format!(
+                        "App create transaction {} was confirmed in round {}, but its result is no longer available; deploy again once the indexer has caught up",
+                        transaction_id, round
+                    ),
+                });
+            }
+        }
+        Ok(None)
     }
 
     async fn update_app(
@@ -1167,9 +1679,145 @@ impl AppDeployer {
         }
     }
 
+    /// Warn or fail, as set by `on_replace_funds`, when the account of the app being replaced
+    /// holds ALGO or assets, returning the funds the delete call has to sweep.
+    async fn check_replaced_app_funds(
+        &self,
+        on_replace_funds: &FundsHandling,
+        existing_app_metadata: &AppMetadata,
+    ) -> Result<Option<StrandedFunds>, AppDeployError> {
+        if *on_replace_funds == FundsHandling::Ignore {
+            return Ok(None);
+        }
+
+        let app_address = existing_app_metadata.app_address.to_string();
+        let account = self
+            .app_manager
+            .algod_client()
+            .account_information(&app_address, None, None)
+            .await
+            .map_err(|e| AppDeployError::DeploymentLookupFailed {
+                message: format!(
+                    "Failed to get account information of {}: {}",
+                    app_address, e
+                ),
+            })?;
+        let asset_ids: Vec<u64> = account
+            .assets
+            .iter()
+            .flatten()
+            .map(|holding| holding.asset_id)
+            .collect();
+        if account.amount == 0 && asset_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let message = format!(
+            "The account {} of app {} holds {} µALGO and {} assets, which are stranded once the app is replaced",
+            app_address,
+            existing_app_metadata.app_id,
+            account.amount,
+            asset_ids.len()
+        );
+        match on_replace_funds {
+            FundsHandling::Fail => Err(AppDeployError::DeploymentFailed { message }),
+            FundsHandling::Sweep { .. } => Ok(Some(StrandedFunds {
+                app_address: existing_app_metadata.app_address.clone(),
+                amount: account.amount,
+                asset_ids,
+            })),
+            _ => {
+                warn!("{}", message);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Simulate the group of `composer`, whose last transaction is the delete call of the replaced
+    /// app, to check the delete call sweeps `funds` to `receiver` before anything is sent.
+    async fn simulate_sweep(
+        composer: &mut TransactionComposer,
+        funds: &StrandedFunds,
+        receiver: &Address,
+    ) -> Result<(), AppDeployError> {
+        let result = composer
+            .simulate(Some(SimulateParams {
+                skip_signatures: true,
+                ..Default::default()
+            }))
+            .await
+            .map_err(|e| AppDeployError::ComposerError { source: e })?;
+        let delete_result =
+            result
+                .results
+                .last()
+                .ok_or_else(|| AppDeployError::DeploymentFailed {
+                    message: "Simulating the delete call returned no results".to_string(),
+                })?;
+        Self::check_swept_funds(funds, receiver, &delete_result.confirmation)
+    }
+
+    /// Check that the simulated delete call closes the funds of the app account to `receiver`
+    /// with its inner transactions.
+    fn check_swept_funds(
+        funds: &StrandedFunds,
+        receiver: &Address,
+        delete_confirmation: &PendingTransactionResponse,
+    ) -> Result<(), AppDeployError> {
+        fn inner_transactions(
+            confirmation: &PendingTransactionResponse,
+        ) -> Vec<&PendingTransactionResponse> {
+            confirmation
+                .inner_txns
+                .iter()
+                .flatten()
+                .flat_map(|inner| std::iter::once(inner).chain(inner_transactions(inner)))
+                .collect()
+        }
+        let inner_transactions = inner_transactions(delete_confirmation);
+        let closes = |closes_out: &dyn Fn(&Transaction) -> bool| {
+            inner_transactions.iter().any(|inner| {
+                inner.txn.transaction.header().sender == funds.app_address
+                    && closes_out(&inner.txn.transaction)
+            })
+        };
+
+        let mut unswept = Vec::new();
+        if funds.amount > 0
+            && !closes(&|transaction| {
+                matches!(transaction, Transaction::Payment(payment)
+                    if payment.close_remainder_to.as_ref() == Some(receiver))
+            })
+        {
+            unswept.push(format!("{} µALGO", funds.amount));
+        }
+        for asset_id in &funds.asset_ids {
+            if !closes(&|transaction| {
+                matches!(transaction, Transaction::AssetTransfer(transfer)
+                    if transfer.asset_id == *asset_id
+                        && transfer.close_remainder_to.as_ref() == Some(receiver))
+            }) {
+                unswept.push(format!("asset {}", asset_id));
+            }
+        }
+
+        if unswept.is_empty() {
+            return Ok(());
+        }
+        Err(AppDeployError::DeploymentFailed {
+            message: format!(
+                "The delete call doesn't close {} of the account {} to {}, which would be stranded",
+                unswept.join(", "),
+                funds.app_address,
+                receiver
+            ),
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn replace_app(
         &mut self,
+        on_replace_funds: FundsHandling,
         existing_app_metadata: &AppMetadata,
         metadata: &AppDeployMetadata,
         create_params: &CreateParams,
@@ -1177,6 +1825,10 @@ impl AppDeployer {
         compiled_programs: CompiledPrograms,
         send_params: &SendParams,
     ) -> Result<AppDeployResult, AppDeployError> {
+        let stranded_funds = self
+            .check_replaced_app_funds(&on_replace_funds, existing_app_metadata)
+            .await?;
+
         info!(
             "Deploying a new {} app; deploying app with version {}.",
             metadata.name, metadata.version
@@ -1186,9 +1838,142 @@ impl AppDeployer {
             metadata.name, existing_app_metadata.app_id
         );
 
-        let mut composer = self.transaction_sender.new_composer(None);
+        let (create_result, delete_result, group_results, group) = match self.migration.clone() {
+            None => {
+                let new_composer = || {
+                    let mut composer = self.transaction_sender.new_composer(None);
+                    Self::add_create(&mut composer, create_params, &compiled_programs)?;
+                    Self::add_delete(&mut composer, delete_params, existing_app_metadata.app_id)?;
+                    Ok::<_, AppDeployError>(composer)
+                };
+                if let (Some(funds), FundsHandling::Sweep { receiver }) =
+                    (&stranded_funds, &on_replace_funds)
+                {
+                    Self::simulate_sweep(&mut new_composer()?, funds, receiver).await?;
+                }
+
+                let result = new_composer()?
+                    .send(Some(send_params.clone()))
+                    .await
+                    .map_err(|e| AppDeployError::ComposerError { source: e })?;
 
-        // Add create transaction and track its index
+                // The create is the first and the delete the last transaction of the group
+                let create_result = result.results[0].clone();
+                let delete_result = result.results[result.results.len() - 1].clone();
+                (create_result, delete_result, result.results, result.group)
+            }
+            Some(migration) => {
+                let mut create_composer = self.transaction_sender.new_composer(None);
+                Self::add_create(&mut create_composer, create_params, &compiled_programs)?;
+                let create_result = create_composer
+                    .send(Some(send_params.clone()))
+                    .await
+                    .map_err(|e| AppDeployError::ComposerError { source: e })?
+                    .results
+                    .remove(0);
+
+                let new_app_id = create_result.confirmation.app_id.ok_or_else(|| {
+                    AppDeployError::DeploymentFailed {
+                        message: "App creation confirmation missing application-index".to_string(),
+                    }
+                })?;
+                info!(
+                    "Migrating app {} to app {} before deleting it",
+                    existing_app_metadata.app_id, new_app_id
+                );
+                migration
+                    .migrate(existing_app_metadata.app_id, new_app_id)
+                    .await
+                    .map_err(|message| AppDeployError::DeploymentFailed {
+                        message: format!(
+                            "Migrating app {} to app {} failed, neither app was deleted: {}",
+                            existing_app_metadata.app_id, new_app_id, message
+                        ),
+                    })?;
+
+                let new_delete_composer = || {
+                    let mut composer = self.transaction_sender.new_composer(None);
+                    Self::add_delete(&mut composer, delete_params, existing_app_metadata.app_id)?;
+                    Ok::<_, AppDeployError>(composer)
+                };
+                if let FundsHandling::Sweep { receiver } = &on_replace_funds {
+                    // The migration may have moved the funds of the old app
+                    let stranded_funds = self
+                        .check_replaced_app_funds(&on_replace_funds, existing_app_metadata)
+                        .await?;
+                    if let Some(funds) = stranded_funds {
+                        Self::simulate_sweep(&mut new_delete_composer()?, &funds, receiver)
+                            .await
+                            .map_err(|e| AppDeployError::DeploymentFailed {
+                                message: format!(
+                                    "App {} was created, but app {} wasn't deleted: {}",
+                                    new_app_id, existing_app_metadata.app_id, e
+                                ),
+                            })?;
+                    }
+                }
+                let delete_result = new_delete_composer()?
+                    .send(Some(send_params.clone()))
+                    .await
+                    .map_err(|e| AppDeployError::ComposerError { source: e })?
+                    .results
+                    .remove(0);
+
+                let group_results = vec![create_result.clone(), delete_result.clone()];
+                (create_result, delete_result, group_results, None)
+            }
+        };
+
+        // Get create confirmation
+        let create_confirmation = create_result.confirmation.clone();
+        let app_id =
+            create_confirmation
+                .app_id
+                .ok_or_else(|| AppDeployError::DeploymentFailed {
+                    message: "App creation confirmation missing application-index".to_string(),
+                })?;
+        let confirmed_round = create_confirmation.confirmed_round.ok_or_else(|| {
+            AppDeployError::DeploymentFailed {
+                message: "App creation confirmation missing confirmed-round".to_string(),
+            }
+        })?;
+        let app_address = Address::from_app_id(&app_id);
+
+        let app_metadata = AppMetadata {
+            app_id,
+            app_address,
+            created_round: confirmed_round,
+            updated_round: confirmed_round,
+            created_metadata: metadata.clone(),
+            deleted: false,
+            name: metadata.name.clone(),
+            version: metadata.version.clone(),
+            updatable: metadata.updatable,
+            deletable: metadata.deletable,
+        };
+
+        let sender = match create_params {
+            CreateParams::AppCreateCall(params) => &params.sender,
+            CreateParams::AppCreateMethodCall(params) => &params.sender,
+        };
+
+        self.update_app_lookup(sender, &app_metadata);
+
+        Ok(AppDeployResult::Replace {
+            app: app_metadata,
+            delete_result,
+            create_result,
+            group_results,
+            group,
+            compiled_programs,
+        })
+    }
+
+    fn add_create(
+        composer: &mut TransactionComposer,
+        create_params: &CreateParams,
+        compiled_programs: &CompiledPrograms,
+    ) -> Result<(), AppDeployError> {
         match create_params {
             CreateParams::AppCreateCall(params) => {
                 let computed_extra_pages = Self::calculate_extra_program_pages(
@@ -1257,11 +2042,15 @@ impl AppDeployer {
                     .add_app_create_method_call(app_create_method_params)
                     .map_err(|e| AppDeployError::ComposerError { source: e })?;
             }
-        };
-
-        let create_transaction_index = composer.count() - 1;
+        }
+        Ok(())
+    }
 
-        // Add delete transaction
+    fn add_delete(
+        composer: &mut TransactionComposer,
+        delete_params: &DeleteParams,
+        app_id: u64,
+    ) -> Result<(), AppDeployError> {
         match delete_params {
             DeleteParams::AppDeleteCall(params) => {
                 composer
@@ -1277,7 +2066,7 @@ impl AppDeployer {
                         validity_window: params.validity_window,
                         first_valid_round: params.first_valid_round,
                         last_valid_round: params.last_valid_round,
-                        app_id: existing_app_metadata.app_id,
+                        app_id,
                         args: params.args.clone(),
                         account_references: params.account_references.clone(),
                         app_references: params.app_references.clone(),
@@ -1300,7 +2089,7 @@ impl AppDeployer {
                         validity_window: params.validity_window,
                         first_valid_round: params.first_valid_round,
                         last_valid_round: params.last_valid_round,
-                        app_id: existing_app_metadata.app_id,
+                        app_id,
                         method: params.method.clone(),
                         args: params.args.clone(),
                         account_references: params.account_references.clone(),
@@ -1311,60 +2100,7 @@ impl AppDeployer {
                     .map_err(|e| AppDeployError::ComposerError { source: e })?;
             }
         }
-
-        let result = composer
-            .send(Some(send_params.clone()))
-            .await
-            .map_err(|e| AppDeployError::ComposerError { source: e })?;
-
-        // Extract create and delete results directly
-        let delete_transaction_index = result.results.len() - 1;
-        let create_result = result.results[create_transaction_index].clone();
-        let delete_result = result.results[delete_transaction_index].clone();
-
-        // Get create confirmation from the tracked index
-        let create_confirmation = create_result.confirmation.clone();
-        let app_id =
-            create_confirmation
-                .app_id
-                .ok_or_else(|| AppDeployError::DeploymentFailed {
-                    message: "App creation confirmation missing application-index".to_string(),
-                })?;
-        let confirmed_round = create_confirmation.confirmed_round.ok_or_else(|| {
-            AppDeployError::DeploymentFailed {
-                message: "App creation confirmation missing confirmed-round".to_string(),
-            }
-        })?;
-        let app_address = Address::from_app_id(&app_id);
-
-        let app_metadata = AppMetadata {
-            app_id,
-            app_address,
-            created_round: confirmed_round,
-            updated_round: confirmed_round,
-            created_metadata: metadata.clone(),
-            deleted: false,
-            name: metadata.name.clone(),
-            version: metadata.version.clone(),
-            updatable: metadata.updatable,
-            deletable: metadata.deletable,
-        };
-
-        let sender = match create_params {
-            CreateParams::AppCreateCall(params) => &params.sender,
-            CreateParams::AppCreateMethodCall(params) => &params.sender,
-        };
-
-        self.update_app_lookup(sender, &app_metadata);
-
-        Ok(AppDeployResult::Replace {
-            app: app_metadata,
-            delete_result,
-            create_result,
-            group_results: result.results,
-            group: result.group,
-            compiled_programs,
-        })
+        Ok(())
     }
 
     /// Calculate minimum number of extra program pages required to fit the programs.
@@ -1378,3 +2114,161 @@ impl AppDeployer {
         std::cmp::min(pages, algokit_transact::MAX_EXTRA_PROGRAM_PAGES)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_unknown_send_outcomes_are_ambiguous() {
+        let submit_error = |message: &str, status| ComposerError::SubmitError {
+            source: AlgodErrorKind::from_message(Some(status), message),
+        };
+
+        assert!(AppDeployer::is_ambiguous_send_error(&submit_error(
+            "upstream connect error",
+            503
+        )));
+        assert!(AppDeployer::is_ambiguous_send_error(
+            &ComposerError::MaxWaitRoundExpired {
+                message: "not confirmed".to_string(),
+            }
+        ));
+        assert!(!AppDeployer::is_ambiguous_send_error(&submit_error(
+            "transaction AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA: logic eval error: assert failed pc=12",
+            400
+        )));
+        assert!(!AppDeployer::is_ambiguous_send_error(&submit_error(
+            "txn dead: round 2000 outside of 1000--1999",
+            400
+        )));
+
+        let lease_in_use = submit_error(
+            "transaction AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA using an overlapping lease",
+            400,
+        );
+        assert!(!AppDeployer::is_ambiguous_send_error(&lease_in_use));
+        assert!(AppDeployer::is_lease_in_use(&lease_in_use));
+    }
+
+    fn metadata(updatable: Option<bool>, deletable: Option<bool>) -> AppDeployMetadata {
+        AppDeployMetadata {
+            name: "test".to_string(),
+            version: "1.0".to_string(),
+            updatable,
+            deletable,
+        }
+    }
+
+    #[test]
+    fn test_arc2_note_matches_other_sdks() {
+        assert_eq!(
+            metadata(Some(true), Some(false)).to_arc2_note().unwrap(),
+            br#"ALGOKIT_DEPLOYER:j{"name":"test","version":"1.0","updatable":true,"deletable":false}"#
+        );
+        assert_eq!(
+            metadata(None, None).to_arc2_note().unwrap(),
+            br#"ALGOKIT_DEPLOYER:j{"name":"test","version":"1.0"}"#
+        );
+    }
+
+    #[test]
+    fn test_arc2_note_parsing() {
+        let parsed = AppDeployMetadata::from_arc2_note(
+            br#"ALGOKIT_DEPLOYER:j{"name":"test","version":"1.0","deletable":true}"#,
+        );
+        assert_eq!(parsed, Some(metadata(None, Some(true))));
+
+        let metadata = metadata(Some(false), None);
+        assert_eq!(
+            AppDeployMetadata::from_arc2_note(&metadata.to_arc2_note().unwrap()),
+            Some(metadata)
+        );
+
+        assert_eq!(
+            AppDeployMetadata::from_arc2_note(b"ALGOKIT_DEPLOYER:u{\"name\":\"test\"}"),
+            None
+        );
+        assert_eq!(AppDeployMetadata::from_arc2_note(b"OTHER_DAPP:j{}"), None);
+        assert_eq!(AppDeployMetadata::from_arc2_note(&[0xff, 0xfe]), None);
+    }
+
+    fn inner(transaction: Transaction) -> PendingTransactionResponse {
+        PendingTransactionResponse::new(
+            String::new(),
+            algokit_transact::SignedTransaction {
+                transaction,
+                signature: None,
+                auth_address: None,
+                multisignature: None,
+                logic_signature: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_check_swept_funds() {
+        use algokit_transact::test_utils::{
+            AccountMother, TransactionHeaderMother, TransactionMother,
+        };
+
+        let app_address = Address::from_app_id(&1234);
+        let receiver = AccountMother::account().address();
+        let header = TransactionHeaderMother::simple_testnet()
+            .sender(app_address.clone())
+            .build()
+            .unwrap();
+        let close_algo = TransactionMother::simple_payment()
+            .header(header.clone())
+            .close_remainder_to(receiver.clone())
+            .build()
+            .unwrap();
+        let close_asset = TransactionMother::simple_asset_transfer()
+            .header(header)
+            .asset_id(42)
+            .close_remainder_to(receiver.clone())
+            .build()
+            .unwrap();
+        let funds = StrandedFunds {
+            app_address,
+            amount: 200_000,
+            asset_ids: vec![42],
+        };
+        let delete_confirmation = |inner_txns: Vec<PendingTransactionResponse>| {
+            let mut confirmation = inner(TransactionMother::simple_payment().build().unwrap());
+            confirmation.inner_txns = Some(inner_txns);
+            confirmation
+        };
+
+        assert!(
+            AppDeployer::check_swept_funds(
+                &funds,
+                &receiver,
+                &delete_confirmation(vec![inner(close_asset.clone()), inner(close_algo.clone())])
+            )
+            .is_ok()
+        );
+        let error = AppDeployer::check_swept_funds(
+            &funds,
+            &receiver,
+            &delete_confirmation(vec![inner(close_algo)]),
+        )
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("doesn't close asset 42 of the account")
+        );
+        let error = AppDeployer::check_swept_funds(
+            &funds,
+            &AccountMother::neil().address(),
+            &delete_confirmation(vec![inner(close_asset)]),
+        )
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("doesn't close 200000 µALGO, asset 42")
+        );
+    }
+}
@@ -1,15 +1,20 @@
 use crate::applications::app_client::error_transformation::LogicErrorContext;
 use crate::applications::app_client::{AppClientMethodCallParams, CompilationParams};
-use crate::applications::app_deployer::{AppLookup, OnSchemaBreak, OnUpdate};
+use crate::applications::app_deployer::{
+    AppLookup, AppMigration, CreateRetryParams, FundsHandling, OnSchemaBreak, OnUpdate,
+};
 use crate::applications::{
     AppDeployMetadata, AppDeployParams, AppDeployResult, CreateParams, DeleteParams, UpdateParams,
 };
+use crate::debugging::persist_compiled_programs;
 use crate::transactions::{
     TransactionComposerConfig, TransactionSigner, composer::SendParams as ComposerSendParams,
 };
-use crate::{AlgorandClient, AppClient, AppClientParams, AppSourceMaps, TransactionSenderError};
-use algokit_abi::Arc56Contract;
+use crate::{
+    AlgorandClient, AppClient, AppClientParams, AppSourceMaps, Config, TransactionSenderError,
+};
 use algokit_abi::arc56_contract::CallOnApplicationComplete;
+use algokit_abi::{Arc56Contract, ProgramSourceMap};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
@@ -40,8 +45,8 @@ pub struct AppFactory {
     version: String,
     default_sender: Option<String>,
     default_signer: Option<Arc<dyn TransactionSigner>>,
-    approval_source_map: Mutex<Option<serde_json::Value>>,
-    clear_source_map: Mutex<Option<serde_json::Value>>,
+    approval_source_map: Mutex<Option<ProgramSourceMap>>,
+    clear_source_map: Mutex<Option<ProgramSourceMap>>,
     compilation_params: Option<CompilationParams>,
     transaction_composer_config: Option<TransactionComposerConfig>,
 }
@@ -50,6 +55,8 @@ pub struct AppFactory {
 pub struct DeployArgs {
     pub on_update: Option<OnUpdate>,
     pub on_schema_break: Option<OnSchemaBreak>,
+    /// What to do when the app is replaced while its account holds funds
+    pub on_replace_funds: Option<FundsHandling>,
     pub create_params: Option<AppFactoryCreateMethodCallParams>,
     pub update_params: Option<AppClientMethodCallParams>,
     pub delete_params: Option<AppClientMethodCallParams>,
@@ -57,6 +64,12 @@ pub struct DeployArgs {
     pub ignore_cache: Option<bool>,
     pub app_name: Option<String>,
     pub send_params: Option<ComposerSendParams>,
+    /// The app spec of the currently deployed version, used to detect breaking spec changes
+    pub previous_app_spec: Option<Arc56Contract>,
+    /// Retry app creates that fail without a definite outcome, see [`CreateRetryParams`]
+    pub create_retry: Option<CreateRetryParams>,
+    /// Migrate the state of a replaced app before it is deleted, see [`AppMigration`]
+    pub migration: Option<Arc<dyn AppMigration>>,
 }
 
 impl AppFactory {
@@ -216,6 +229,20 @@ impl AppFactory {
         Ok(client)
     }
 
+    /// Get the sender for the factory's transactions, which sponsors each group when the
+    /// composer config has a fee payer.
+    pub(crate) fn algorand_sender(&self) -> crate::transactions::TransactionSender {
+        let sender = self.algorand.send();
+        match self
+            .transaction_composer_config
+            .as_ref()
+            .and_then(|config| config.fee_payer.clone())
+        {
+            Some(fee_payer) => sender.with_fee_payer(fee_payer),
+            None => sender.clone(),
+        }
+    }
+
     pub(crate) fn get_sender_address(
         &self,
         sender: &Option<String>,
@@ -235,8 +262,8 @@ impl AppFactory {
 
     pub(crate) fn update_source_maps(
         &self,
-        approval: Option<serde_json::Value>,
-        clear: Option<serde_json::Value>,
+        approval: Option<ProgramSourceMap>,
+        clear: Option<ProgramSourceMap>,
     ) {
         *self.approval_source_map.lock().unwrap() = approval;
         *self.clear_source_map.lock().unwrap() = clear;
@@ -284,8 +311,22 @@ impl AppFactory {
         Some(bare_allows || method_allows)
     }
 
+    /// Transform the error of a send result, see [`Self::handle_transaction_error`].
+    pub(crate) async fn handle_send_result<T>(
+        &self,
+        result: Result<T, TransactionSenderError>,
+        is_clear_state_program: bool,
+    ) -> Result<T, AppFactoryError> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(error) => Err(self
+                .handle_transaction_error(error, is_clear_state_program)
+                .await),
+        }
+    }
+
     /// Transform a transaction error using AppClient logic error exposure for factory flows.
-    pub(crate) fn handle_transaction_error(
+    pub(crate) async fn handle_transaction_error(
         &self,
         error: TransactionSenderError,
         is_clear_state_program: bool,
@@ -304,7 +345,9 @@ impl AppFactory {
             source_maps: source_maps.as_ref(),
         };
 
-        let logic_error = context.expose_logic_error(&error_str, is_clear_state_program);
+        let logic_error = context
+            .expose_logic_error(&error_str, is_clear_state_program)
+            .await;
         AppFactoryError::LogicError {
             message: logic_error.message.clone(),
             logic: Box::new(logic_error),
@@ -363,15 +406,29 @@ impl AppFactory {
             deploy_time_params: compilation_params.deploy_time_params,
             on_schema_break: args.on_schema_break,
             on_update: args.on_update,
+            on_replace_funds: args.on_replace_funds,
             create_params: create_deploy_params,
             update_params: update_deploy_params,
             delete_params: delete_deploy_params,
             existing_deployments: args.existing_deployments,
             ignore_cache: args.ignore_cache,
             send_params: args.send_params.unwrap_or_default(),
+            spec_diff: args
+                .previous_app_spec
+                .map(|previous| previous.diff(&self.app_spec)),
         };
 
-        let mut app_deployer = self.algorand.as_ref().app_deployer();
+        let mut app_deployer = self
+            .algorand
+            .as_ref()
+            .app_deployer()
+            .with_transaction_sender(self.algorand_sender());
+        if let Some(create_retry) = args.create_retry {
+            app_deployer = app_deployer.with_create_retry(create_retry);
+        }
+        if let Some(migration) = args.migration {
+            app_deployer = app_deployer.with_migration(migration);
+        }
 
         let deploy_result = app_deployer
             .deploy(deploy_params)
@@ -388,29 +445,35 @@ impl AppFactory {
         let app_client = self.get_app_client_by_id(app_id, None, None, None, None);
 
         // Extract and update source maps from the deploy result
-        let (approval_source_map, clear_source_map) = match &deploy_result {
+        let compiled_programs = match &deploy_result {
             AppDeployResult::Create {
                 compiled_programs, ..
-            } => (
-                compiled_programs.approval.source_map.clone(),
-                compiled_programs.clear.source_map.clone(),
-            ),
-            AppDeployResult::Update {
+            }
+            | AppDeployResult::Update {
                 compiled_programs, ..
-            } => (
-                compiled_programs.approval.source_map.clone(),
-                compiled_programs.clear.source_map.clone(),
-            ),
-            AppDeployResult::Replace {
+            }
+            | AppDeployResult::Replace {
                 compiled_programs, ..
-            } => (
-                compiled_programs.approval.source_map.clone(),
-                compiled_programs.clear.source_map.clone(),
-            ),
-            AppDeployResult::Nothing { .. } => (None, None),
+            } => Some(compiled_programs),
+            AppDeployResult::Nothing { .. } => None,
         };
 
-        self.update_source_maps(approval_source_map, clear_source_map);
+        if let (Some(compiled_programs), Some(project_root)) = (
+            compiled_programs,
+            Config::project_root().filter(|_| Config::debug()),
+        ) {
+            persist_compiled_programs(
+                &project_root,
+                &self.app_name,
+                &compiled_programs.approval,
+                &compiled_programs.clear,
+            );
+        }
+
+        self.update_source_maps(
+            compiled_programs.and_then(|programs| programs.approval.source_map.clone()),
+            compiled_programs.and_then(|programs| programs.clear.source_map.clone()),
+        );
 
         Ok((app_client, deploy_result))
     }
@@ -2,10 +2,13 @@ use super::AppFactory;
 use super::utils::{
     build_bare_create_params, build_create_method_call_params, merge_args_with_defaults,
 };
-use crate::applications::app_client::CompilationParams;
+use crate::applications::app_client::{
+    AppClientBareCallParams, AppClientMethodCallParams, CompilationParams,
+};
 use crate::applications::app_factory::{
     AppFactoryCreateMethodCallParams, AppFactoryCreateParams, AppFactoryError,
 };
+use crate::transactions::{AppCreateMethodCallParams, AppCreateParams, TransactionComposer};
 use algokit_transact::Transaction;
 use futures::TryFutureExt;
 
@@ -37,6 +40,86 @@ impl<'app_factory> TransactionBuilder<'app_factory> {
         params: AppFactoryCreateMethodCallParams,
         compilation_params: Option<CompilationParams>,
     ) -> Result<Vec<Transaction>, AppFactoryError> {
+        let create_params = self.create_params(params, compilation_params).await?;
+
+        self.factory
+            .algorand()
+            .create()
+            .app_create_method_call(create_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+            .await
+    }
+
+    /// Adds an app creation method call to `composer`, so that the app is created atomically
+    /// with the other transactions of the group, e.g. funding payments and setup calls.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if compilation fails, method lookup fails, or the
+    /// composer rejects the transaction.
+    pub async fn add_create_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        params: AppFactoryCreateMethodCallParams,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<(), AppFactoryError> {
+        let create_params = self.create_params(params, compilation_params).await?;
+        composer
+            .add_app_create_method_call(create_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    /// Adds an update method call of the app `app_id` to `composer`.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if compilation fails, method lookup fails, or the
+    /// composer rejects the transaction.
+    pub async fn add_update_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        app_id: u64,
+        params: AppClientMethodCallParams,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<(), AppFactoryError> {
+        let (update_params, _) = self
+            .factory
+            .get_app_client_by_id(app_id, None, None, None, None)
+            .params()
+            .update(params, compilation_params)
+            .await
+            .map_err(|e| AppFactoryError::AppClientError { source: e })?;
+        composer
+            .add_app_update_method_call(update_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    /// Adds a delete method call of the app `app_id` to `composer`.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if method lookup fails or the composer rejects the
+    /// transaction.
+    pub async fn add_delete_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        app_id: u64,
+        params: AppClientMethodCallParams,
+    ) -> Result<(), AppFactoryError> {
+        let delete_params = self
+            .factory
+            .get_app_client_by_id(app_id, None, None, None, None)
+            .params()
+            .delete(params)
+            .await
+            .map_err(|e| AppFactoryError::AppClientError { source: e })?;
+        composer
+            .add_app_delete_method_call(delete_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    async fn create_params(
+        &self,
+        params: AppFactoryCreateMethodCallParams,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<AppCreateMethodCallParams, AppFactoryError> {
         let compiled = self.factory.compile(compilation_params).await?;
         let method = self
             .factory
@@ -50,7 +133,7 @@ impl<'app_factory> TransactionBuilder<'app_factory> {
 
         let merged_args = merge_args_with_defaults(self.factory, &params.method, &params.args)?;
 
-        let create_params = build_create_method_call_params(
+        Ok(build_create_method_call_params(
             self.factory,
             sender,
             &params,
@@ -58,14 +141,7 @@ impl<'app_factory> TransactionBuilder<'app_factory> {
             merged_args,
             compiled.approval.compiled_base64_to_bytes,
             compiled.clear.compiled_base64_to_bytes,
-        );
-
-        self.factory
-            .algorand()
-            .create()
-            .app_create_method_call(create_params)
-            .map_err(|e| AppFactoryError::ComposerError { source: e })
-            .await
+        ))
     }
 }
 
@@ -79,6 +155,87 @@ impl BareTransactionBuilder<'_> {
         params: Option<AppFactoryCreateParams>,
         compilation_params: Option<CompilationParams>,
     ) -> Result<algokit_transact::Transaction, AppFactoryError> {
+        let create_params = self.create_params(params, compilation_params).await?;
+
+        self.factory
+            .algorand()
+            .create()
+            .app_create(create_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+            .await
+    }
+
+    /// Adds a bare app creation to `composer`, so that the app is created atomically with the
+    /// other transactions of the group.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if compilation fails, the sender address is invalid, or the
+    /// composer rejects the transaction.
+    pub async fn add_create_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        params: Option<AppFactoryCreateParams>,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<(), AppFactoryError> {
+        let create_params = self.create_params(params, compilation_params).await?;
+        composer
+            .add_app_create(create_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    /// Adds a bare update of the app `app_id` to `composer`.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if compilation fails or the composer rejects the
+    /// transaction.
+    pub async fn add_update_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        app_id: u64,
+        params: AppClientBareCallParams,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<(), AppFactoryError> {
+        let (update_params, _) = self
+            .factory
+            .get_app_client_by_id(app_id, None, None, None, None)
+            .params()
+            .bare()
+            .update(params, compilation_params)
+            .await
+            .map_err(|e| AppFactoryError::AppClientError { source: e })?;
+        composer
+            .add_app_update(update_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    /// Adds a bare delete of the app `app_id` to `composer`.
+    ///
+    /// # Errors
+    /// Returns [`AppFactoryError`] if the sender address is invalid or the composer rejects
+    /// the transaction.
+    pub fn add_delete_to_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        app_id: u64,
+        params: AppClientBareCallParams,
+    ) -> Result<(), AppFactoryError> {
+        let delete_params = self
+            .factory
+            .get_app_client_by_id(app_id, None, None, None, None)
+            .params()
+            .bare()
+            .delete(params)
+            .map_err(|e| AppFactoryError::AppClientError { source: e })?;
+        composer
+            .add_app_delete(delete_params)
+            .map_err(|e| AppFactoryError::ComposerError { source: e })
+    }
+
+    async fn create_params(
+        &self,
+        params: Option<AppFactoryCreateParams>,
+        compilation_params: Option<CompilationParams>,
+    ) -> Result<AppCreateParams, AppFactoryError> {
         let params = params.unwrap_or_default();
 
         let compiled = self.factory.compile(compilation_params).await?;
@@ -87,19 +244,12 @@ impl BareTransactionBuilder<'_> {
             .get_sender_address(&params.sender)
             .map_err(|message| AppFactoryError::ValidationError { message })?;
 
-        let create_params = build_bare_create_params(
+        Ok(build_bare_create_params(
             self.factory,
             sender,
             &params,
             compiled.approval.compiled_base64_to_bytes,
             compiled.clear.compiled_base64_to_bytes,
-        );
-
-        self.factory
-            .algorand()
-            .create()
-            .app_create(create_params)
-            .map_err(|e| AppFactoryError::ComposerError { source: e })
-            .await
+        ))
     }
 }
@@ -68,13 +68,12 @@ impl<'app_factory> TransactionSender<'app_factory> {
             clear_bytes.clone(),
         );
 
-        let result = self
+        let send_result = self
             .factory
-            .algorand()
-            .send()
+            .algorand_sender()
             .app_create_method_call(create_params, send_params)
-            .await
-            .map_err(|e| self.factory.handle_transaction_error(e, false))?;
+            .await;
+        let result = self.factory.handle_send_result(send_result, false).await?;
 
         let app_client = self.factory.get_app_client_by_id(
             result.app_id,
@@ -136,13 +135,12 @@ impl BareTransactionSender<'_> {
             compiled.clear.compiled_base64_to_bytes.clone(),
         );
 
-        let result = self
+        let send_result = self
             .factory
-            .algorand()
-            .send()
+            .algorand_sender()
             .app_create(create_params, send_params)
-            .await
-            .map_err(|e| self.factory.handle_transaction_error(e, false))?;
+            .await;
+        let result = self.factory.handle_send_result(send_result, false).await?;
 
         let app_id = result.app_id;
         let app_address = Address::from_app_id(&app_id);
@@ -5,7 +5,8 @@ pub mod app_factory;
 // Re-export commonly used client types
 pub use app_deployer::{
     AppDeployError, AppDeployMetadata, AppDeployParams, AppDeployResult, AppDeployer, AppLookup,
-    AppMetadata, AppProgram, CreateParams, DeleteParams, DeployAppCreateMethodCallParams,
-    DeployAppCreateParams, DeployAppDeleteMethodCallParams, DeployAppDeleteParams,
-    DeployAppUpdateMethodCallParams, DeployAppUpdateParams, OnSchemaBreak, OnUpdate, UpdateParams,
+    AppMetadata, AppMigration, AppProgram, CreateParams, CreateRetryParams, DeleteParams,
+    DeployAppCreateMethodCallParams, DeployAppCreateParams, DeployAppDeleteMethodCallParams,
+    DeployAppDeleteParams, DeployAppUpdateMethodCallParams, DeployAppUpdateParams, FundsHandling,
+    OnSchemaBreak, OnUpdate, UpdateParams,
 };
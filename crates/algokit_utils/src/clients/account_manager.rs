@@ -1,13 +1,48 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    env,
+    sync::{Arc, RwLock},
+};
 
-use algokit_transact::Address;
+use algod_client::models::PendingTransactionResponse;
+use algokit_transact::{
+    ALGORAND_SIGNATURE_BYTE_LENGTH, Address, AlgorandMsgpack, AssetTransferTransactionFields,
+    KeyPairAccount, MultisigSignature, PaymentTransactionFields, SecretKey, SignedTransaction,
+    Transaction, TransactionHeader,
+};
+use async_trait::async_trait;
+use base64::{Engine, prelude::BASE64_STANDARD};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use futures::stream::{self, Stream, StreamExt};
+use indexer_client::apis::parameter_enums::TxType;
+use indexer_client::models::Transaction as IndexerTransaction;
+use rand::rngs::OsRng;
 use snafu::Snafu;
 
+use crate::AlgoAmount;
+use crate::clients::client_manager::ClientManager;
+use crate::clients::dispenser_client::TestNetDispenserApiClient;
+use crate::clients::kmd_account_manager::KmdAccountManager;
+use crate::clients::logic_sig_account::LogicSigAccount;
+use crate::clients::mnemonic::{self, MnemonicError};
+use crate::clients::multisig_signer::MultisigSigner;
+use crate::transactions::{
+    EmptySigner, PaymentParams, SendParams, TransactionComposer, TransactionComposerParams,
+};
 use crate::{TransactionSigner, transactions::common::TransactionSignerGetter};
 
+/// The environment variable holding the mnemonic of the account that funds accounts in
+/// [`AccountManager::ensure_funded_from_environment`].
+pub const DISPENSER_MNEMONIC_ENV_VAR: &str = "DISPENSER_MNEMONIC";
+
+/// The environment variable holding the address of the funding account, when it has been rekeyed
+/// to the account of [`DISPENSER_MNEMONIC_ENV_VAR`].
+pub const DISPENSER_SENDER_ENV_VAR: &str = "DISPENSER_SENDER";
+
 pub struct AccountManager {
-    default_signer: Option<Arc<dyn TransactionSigner>>,
-    accounts: HashMap<Address, Arc<dyn TransactionSigner>>,
+    default_signer: RwLock<Option<Arc<dyn TransactionSigner>>>,
+    accounts: RwLock<HashMap<Address, Arc<dyn TransactionSigner>>>,
+    client_manager: Option<Arc<ClientManager>>,
 }
 
 impl Default for AccountManager {
@@ -16,20 +51,108 @@ impl Default for AccountManager {
     }
 }
 
+/// Parameters for ensuring an account is funded.
+#[derive(Debug, Clone, Default)]
+pub struct EnsureFundedParams {
+    /// The minimum amount of microALGO to fund, so that frequent small top ups are batched into
+    /// fewer, larger ones. Defaults to 0.
+    pub min_funding_increment: Option<u64>,
+    /// Note to attach to the funding transaction, when not funded by the TestNet Dispenser API
+    pub note: Option<Vec<u8>>,
+    pub send_params: Option<SendParams>,
+}
+
+/// The funding transaction sent to ensure an account is funded.
+#[derive(Debug, Clone)]
+pub struct EnsureFundedResult {
+    pub transaction_id: String,
+    /// The amount of microALGO the account was funded with
+    pub amount_funded: u64,
+    /// The funding transaction, not available when funded by the TestNet Dispenser API
+    pub transaction: Option<Transaction>,
+    /// The confirmation of the funding transaction, not available when funded by the TestNet
+    /// Dispenser API
+    pub confirmation: Option<PendingTransactionResponse>,
+}
+
+/// Parameters for rekeying an account.
+#[derive(Debug, Clone, Default)]
+pub struct RekeyAccountParams {
+    /// Note to attach to the rekey transaction
+    pub note: Option<Vec<u8>>,
+    pub send_params: Option<SendParams>,
+}
+
+/// The rekey transaction sent to rekey an account.
+#[derive(Debug, Clone)]
+pub struct RekeyAccountResult {
+    pub transaction_id: String,
+    pub transaction: Transaction,
+    pub confirmation: PendingTransactionResponse,
+}
+
+/// Filters of [`AccountManager::get_transaction_history`].
+#[derive(Debug, Clone, Default)]
+pub struct TransactionHistoryFilters {
+    pub tx_type: Option<TxType>,
+    pub asset_id: Option<u64>,
+    pub min_round: Option<u64>,
+    pub max_round: Option<u64>,
+    /// Only transactions whose note starts with these bytes
+    pub note_prefix: Option<Vec<u8>>,
+    /// The number of transactions fetched per indexer request, defaults to the indexer's limit
+    pub page_size: Option<u64>,
+}
+
+/// A transaction from the history of an account.
+#[derive(Debug, Clone)]
+pub struct HistoricalTransaction {
+    pub id: String,
+    pub confirmed_round: Option<u64>,
+    /// The time of the round the transaction was confirmed in, in seconds since the epoch
+    pub round_time: Option<u64>,
+    /// The transaction, for payments and asset transfers
+    pub transaction: Option<Transaction>,
+    /// The transaction as returned by the indexer, with all its fields and effects
+    pub indexer_transaction: IndexerTransaction,
+}
+
+impl From<IndexerTransaction> for HistoricalTransaction {
+    fn from(indexer_transaction: IndexerTransaction) -> Self {
+        Self {
+            id: indexer_transaction.id.clone().unwrap_or_default(),
+            confirmed_round: indexer_transaction.confirmed_round,
+            round_time: indexer_transaction.round_time,
+            transaction: to_transaction(&indexer_transaction),
+            indexer_transaction,
+        }
+    }
+}
+
 impl AccountManager {
     pub fn new() -> Self {
         Self {
-            default_signer: None,
-            accounts: HashMap::new(),
+            default_signer: RwLock::new(None),
+            accounts: RwLock::new(HashMap::new()),
+            client_manager: None,
         }
     }
 
-    pub fn set_default_signer(&mut self, default_signer: Arc<dyn TransactionSigner>) {
-        self.default_signer = Some(default_signer);
+    /// Create an account manager that can also fund accounts, using the clients of the
+    /// client manager.
+    pub fn with_client_manager(client_manager: Arc<ClientManager>) -> Self {
+        Self {
+            client_manager: Some(client_manager),
+            ..Self::new()
+        }
     }
 
-    pub fn set_signer(&mut self, sender: Address, signer: Arc<dyn TransactionSigner>) {
-        self.accounts.insert(sender, signer);
+    pub fn set_default_signer(&self, default_signer: Arc<dyn TransactionSigner>) {
+        *self.default_signer.write().unwrap() = Some(default_signer);
+    }
+
+    pub fn set_signer(&self, sender: Address, signer: Arc<dyn TransactionSigner>) {
+        self.accounts.write().unwrap().insert(sender, signer);
     }
 
     pub fn get_signer(
@@ -37,19 +160,541 @@ impl AccountManager {
         sender: Address,
     ) -> Result<Arc<dyn TransactionSigner>, AccountManagerError> {
         self.accounts
+            .read()
+            .unwrap()
             .get(&sender)
             .cloned()
-            .or(self.default_signer.clone())
+            .or_else(|| self.default_signer.read().unwrap().clone())
             .ok_or_else(|| AccountManagerError::SignerNotFound {
                 address: sender.to_string(),
             })
     }
+
+    /// Generate a new account and register it as the signer of its address.
+    pub fn random(&self) -> SigningAccount {
+        let account = SigningAccount::generate();
+        self.set_signer(account.address(), Arc::new(account.clone()));
+        account
+    }
+
+    /// Register the account of a 25 word mnemonic as the signer of its address.
+    pub fn from_mnemonic(&self, mnemonic: &str) -> Result<SigningAccount, AccountManagerError> {
+        let account = SigningAccount::from_mnemonic(mnemonic).map_err(|e| {
+            AccountManagerError::InvalidMnemonic {
+                message: e.to_string(),
+            }
+        })?;
+        self.set_signer(account.address(), Arc::new(account.clone()));
+        Ok(account)
+    }
+
+    /// Register the signer of `signing_account` as the signer of `sender`, an account that has
+    /// been rekeyed to `signing_account`.
+    ///
+    /// The signer of `signing_account` must already be registered.
+    pub fn rekeyed(
+        &self,
+        sender: Address,
+        signing_account: &Address,
+    ) -> Result<(), AccountManagerError> {
+        let signer = self.registered_signer(signing_account)?;
+        self.set_signer(sender, signer);
+        Ok(())
+    }
+
+    /// Register a logic signature account as the signer of its address, i.e. of the program's
+    /// escrow address or, when delegated, of the delegating account.
+    pub fn logicsig(&self, account: LogicSigAccount) -> Address {
+        let address = account.address();
+        self.set_signer(address.clone(), Arc::new(account));
+        address
+    }
+
+    /// Register a signer for the multisig account of the given participants, which signs with the
+    /// signers registered for the participants until the threshold is met.
+    ///
+    /// Fails unless enough participants have a registered signer to meet the threshold.
+    ///
+    /// # Returns
+    /// The address of the multisig account
+    pub fn multisig(
+        &self,
+        version: u8,
+        threshold: u8,
+        accounts: Vec<Address>,
+    ) -> Result<Address, AccountManagerError> {
+        let multisig =
+            MultisigSignature::from_participants(version, threshold, accounts).map_err(|e| {
+                AccountManagerError::InvalidMultisig {
+                    message: e.to_string(),
+                }
+            })?;
+
+        let mut signers: Vec<(Address, Arc<dyn TransactionSigner>)> = Vec::new();
+        let mut signed_weight = 0;
+        for participant in multisig.participants() {
+            if let Ok(signer) = self.registered_signer(&participant) {
+                signed_weight += 1;
+                if !signers.iter().any(|(address, _)| *address == participant) {
+                    signers.push((participant, signer));
+                }
+            }
+        }
+        if signed_weight < threshold as usize {
+            return Err(AccountManagerError::InvalidMultisig {
+                message: format!(
+                    "Only {} of the {} signatures required by the threshold can be made with the registered signers",
+                    signed_weight, threshold
+                ),
+            });
+        }
+
+        let signer = MultisigSigner::new(multisig, signers);
+        let address = signer.address();
+        self.set_signer(address.clone(), Arc::new(signer));
+        Ok(address)
+    }
+
+    /// Fund `account_to_fund` from `dispenser` when its available balance, i.e. its balance
+    /// above its minimum balance requirement, is below `min_spending_balance`.
+    ///
+    /// The signer of `dispenser` must be registered.
+    ///
+    /// # Returns
+    /// The funding transaction, or `None` when the account was already funded
+    pub async fn ensure_funded(
+        &self,
+        account_to_fund: &Address,
+        dispenser: &Address,
+        min_spending_balance: u64,
+        params: Option<EnsureFundedParams>,
+    ) -> Result<Option<EnsureFundedResult>, AccountManagerError> {
+        let params = params.unwrap_or_default();
+        let Some(amount) = self
+            .get_amount_to_fund(account_to_fund, min_spending_balance, &params)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let signer = self.get_signer(dispenser.clone())?;
+        let mut composer = self.new_composer()?;
+        composer
+            .add_payment(PaymentParams {
+                sender: dispenser.clone(),
+                signer: Some(signer),
+                receiver: account_to_fund.clone(),
+                amount: AlgoAmount::micro_algos(amount),
+                note: params.note,
+                ..Default::default()
+            })
+            .map_err(|e| AccountManagerError::FundingError {
+                message: e.to_string(),
+            })?;
+        let result = composer.send(params.send_params).await.map_err(|e| {
+            AccountManagerError::FundingError {
+                message: e.to_string(),
+            }
+        })?;
+        let funding =
+            result
+                .results
+                .into_iter()
+                .next()
+                .ok_or_else(|| AccountManagerError::FundingError {
+                    message: "No funding transaction was sent".to_string(),
+                })?;
+
+        Ok(Some(EnsureFundedResult {
+            transaction_id: funding.transaction_id,
+            amount_funded: amount,
+            transaction: Some(funding.transaction),
+            confirmation: Some(funding.confirmation),
+        }))
+    }
+
+    /// Rekey `account` to `rekey_to` with a zero payment to itself, then check with algod that
+    /// the account is rekeyed and register the signer of `rekey_to` as the signer of `account`,
+    /// so that later transactions of the account are signed by it.
+    ///
+    /// The signers of `account` and `rekey_to` must be registered. Rekeying an account to
+    /// itself removes its rekey.
+    pub async fn rekey_account(
+        &self,
+        account: &Address,
+        rekey_to: &Address,
+        params: Option<RekeyAccountParams>,
+    ) -> Result<RekeyAccountResult, AccountManagerError> {
+        let params = params.unwrap_or_default();
+        let signer = self.get_signer(account.clone())?;
+        let new_signer = self.registered_signer(rekey_to)?;
+
+        let rekey_failed = |message: String| AccountManagerError::RekeyFailed { message };
+        let mut composer = self.new_composer()?;
+        composer
+            .add_payment(PaymentParams {
+                sender: account.clone(),
+                signer: Some(signer),
+                rekey_to: Some(rekey_to.clone()),
+                receiver: account.clone(),
+                amount: AlgoAmount::ZERO,
+                note: params.note,
+                ..Default::default()
+            })
+            .map_err(|e| rekey_failed(e.to_string()))?;
+        let rekey = composer
+            .send(params.send_params)
+            .await
+            .map_err(|e| rekey_failed(e.to_string()))?
+            .results
+            .into_iter()
+            .next()
+            .ok_or_else(|| rekey_failed("No rekey transaction was sent".to_string()))?;
+
+        let auth_address = self
+            .client_manager()?
+            .algod()
+            .account_information(&account.to_string(), None, None)
+            .await
+            .map_err(|e| rekey_failed(format!("Failed to get account information: {}", e)))?
+            .auth_addr
+            .unwrap_or_else(|| account.to_string());
+        if auth_address != rekey_to.to_string() {
+            return Err(rekey_failed(format!(
+                "{} is authorized by {} after the rekey to {}",
+                account, auth_address, rekey_to
+            )));
+        }
+        self.set_signer(account.clone(), new_signer);
+
+        Ok(RekeyAccountResult {
+            transaction_id: rekey.transaction_id,
+            transaction: rekey.transaction,
+            confirmation: rekey.confirmation,
+        })
+    }
+
+    /// The transactions of an account from the indexer, newest first, fetched a page at a time
+    /// as the stream is polled.
+    ///
+    /// A failed request yields the error and ends the stream.
+    pub fn get_transaction_history(
+        &self,
+        address: &Address,
+        filters: TransactionHistoryFilters,
+    ) -> Result<
+        impl Stream<Item = Result<HistoricalTransaction, AccountManagerError>> + use<>,
+        AccountManagerError,
+    > {
+        let indexer =
+            self.client_manager()?
+                .indexer()
+                .map_err(|e| AccountManagerError::ClientError {
+                    message: e.to_string(),
+                })?;
+        let address = address.to_string();
+        let note_prefix = filters
+            .note_prefix
+            .as_ref()
+            .map(|note_prefix| BASE64_STANDARD.encode(note_prefix));
+
+        // `None` once the last page was fetched, otherwise the token of the next page
+        let pages = stream::unfold(Some(None::<String>), move |next| {
+            let indexer = indexer.clone();
+            let address = address.clone();
+            let note_prefix = note_prefix.clone();
+            let filters = filters.clone();
+            async move {
+                let page = indexer
+                    .lookup_account_transactions(
+                        filters.page_size,
+                        next?.as_deref(),
+                        note_prefix.as_deref(),
+                        filters.tx_type,
+                        None,
+                        None,
+                        None,
+                        filters.min_round,
+                        filters.max_round,
+                        filters.asset_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                        &address,
+                        None,
+                    )
+                    .await;
+                match page {
+                    Ok(page) => {
+                        let next = page
+                            .next_token
+                            .filter(|_| !page.transactions.is_empty())
+                            .map(Some);
+                        let transactions = page
+                            .transactions
+                            .into_iter()
+                            .map(|transaction| Ok(transaction.into()))
+                            .collect::<Vec<_>>();
+                        Some((transactions, next))
+                    }
+                    Err(e) => Some((
+                        vec![Err(AccountManagerError::ClientError {
+                            message: e.to_string(),
+                        })],
+                        None,
+                    )),
+                }
+            }
+        });
+        Ok(pages.flat_map(stream::iter))
+    }
+
+    /// [`Self::ensure_funded`] from the dispenser of the environment: the account of the
+    /// `DISPENSER_MNEMONIC` environment variable (or the `DISPENSER_SENDER` account rekeyed to it),
+    /// and otherwise on LocalNet the funded account of its default KMD wallet.
+    pub async fn ensure_funded_from_environment(
+        &self,
+        account_to_fund: &Address,
+        min_spending_balance: u64,
+        params: Option<EnsureFundedParams>,
+    ) -> Result<Option<EnsureFundedResult>, AccountManagerError> {
+        let dispenser = self.dispenser_from_environment().await?;
+        self.ensure_funded(account_to_fund, &dispenser, min_spending_balance, params)
+            .await
+    }
+
+    /// [`Self::ensure_funded`] from the TestNet Dispenser API, which only funds TestNet accounts.
+    pub async fn ensure_funded_from_testnet_dispenser(
+        &self,
+        account_to_fund: &Address,
+        dispenser_client: &TestNetDispenserApiClient,
+        min_spending_balance: u64,
+        params: Option<EnsureFundedParams>,
+    ) -> Result<Option<EnsureFundedResult>, AccountManagerError> {
+        let is_testnet = self.client_manager()?.is_testnet().await.map_err(|e| {
+            AccountManagerError::ClientError {
+                message: e.to_string(),
+            }
+        })?;
+        if !is_testnet {
+            return Err(AccountManagerError::FundingError {
+                message: "The TestNet Dispenser API can only fund TestNet accounts".to_string(),
+            });
+        }
+
+        let params = params.unwrap_or_default();
+        let Some(amount) = self
+            .get_amount_to_fund(account_to_fund, min_spending_balance, &params)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let response = dispenser_client
+            .fund(account_to_fund, amount)
+            .await
+            .map_err(|e| AccountManagerError::FundingError {
+                message: e.to_string(),
+            })?;
+
+        Ok(Some(EnsureFundedResult {
+            transaction_id: response.transaction_id,
+            amount_funded: response.amount,
+            transaction: None,
+            confirmation: None,
+        }))
+    }
+
+    /// The amount to fund the account with so its available balance is at least
+    /// `min_spending_balance`, or `None` if it already is.
+    async fn get_amount_to_fund(
+        &self,
+        account_to_fund: &Address,
+        min_spending_balance: u64,
+        params: &EnsureFundedParams,
+    ) -> Result<Option<u64>, AccountManagerError> {
+        let account_information = self
+            .client_manager()?
+            .algod()
+            .account_information(&account_to_fund.to_string(), None, None)
+            .await
+            .map_err(|e| AccountManagerError::ClientError {
+                message: format!(
+                    "Failed to get account information of {}: {}",
+                    account_to_fund, e
+                ),
+            })?;
+
+        let available_balance = account_information
+            .amount
+            .saturating_sub(account_information.min_balance);
+        if available_balance >= min_spending_balance {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            (min_spending_balance - available_balance)
+                .max(params.min_funding_increment.unwrap_or(0)),
+        ))
+    }
+
+    async fn dispenser_from_environment(&self) -> Result<Address, AccountManagerError> {
+        if let Ok(dispenser_mnemonic) = env::var(DISPENSER_MNEMONIC_ENV_VAR) {
+            let signing_account = self.from_mnemonic(&dispenser_mnemonic)?;
+            return match env::var(DISPENSER_SENDER_ENV_VAR) {
+                Ok(sender) => {
+                    let sender: Address =
+                        sender
+                            .parse()
+                            .map_err(|e| AccountManagerError::DispenserNotFound {
+                                message: format!("Invalid {}: {}", DISPENSER_SENDER_ENV_VAR, e),
+                            })?;
+                    self.rekeyed(sender.clone(), &signing_account.address())?;
+                    Ok(sender)
+                }
+                Err(_) => Ok(signing_account.address()),
+            };
+        }
+
+        let client_manager = self.client_manager()?;
+        let is_localnet =
+            client_manager
+                .is_localnet()
+                .await
+                .map_err(|e| AccountManagerError::ClientError {
+                    message: e.to_string(),
+                })?;
+        if !is_localnet {
+            return Err(AccountManagerError::DispenserNotFound {
+                message: format!(
+                    "Set {} to fund accounts outside of LocalNet",
+                    DISPENSER_MNEMONIC_ENV_VAR
+                ),
+            });
+        }
+
+        let kmd_client =
+            client_manager
+                .kmd()
+                .map_err(|e| AccountManagerError::DispenserNotFound {
+                    message: e.to_string(),
+                })?;
+        let dispenser = KmdAccountManager::new(kmd_client, client_manager.algod())
+            .get_localnet_dispenser_account()
+            .await
+            .map_err(|e| AccountManagerError::DispenserNotFound {
+                message: e.to_string(),
+            })?;
+        self.set_signer(dispenser.address.clone(), dispenser.signer);
+        Ok(dispenser.address)
+    }
+
+    fn client_manager(&self) -> Result<&ClientManager, AccountManagerError> {
+        self.client_manager
+            .as_deref()
+            .ok_or_else(|| AccountManagerError::ClientError {
+                message: "Funding, rekeying and looking up the transactions of accounts requires \
+                    an account manager created with a client manager"
+                    .to_string(),
+            })
+    }
+
+    /// A composer for transactions that carry their signers.
+    fn new_composer(&self) -> Result<TransactionComposer, AccountManagerError> {
+        let client_manager = self.client_manager()?;
+        Ok(TransactionComposer::new(TransactionComposerParams {
+            algod_client: client_manager.algod(),
+            // The transactions carry their signers, so the composer doesn't need to resolve signers
+            signer_getter: Arc::new(EmptySigner {}),
+            composer_config: None,
+            suggested_params_cache: Some(client_manager.suggested_params_cache()),
+        }))
+    }
+
+    fn registered_signer(
+        &self,
+        address: &Address,
+    ) -> Result<Arc<dyn TransactionSigner>, AccountManagerError> {
+        self.accounts
+            .read()
+            .unwrap()
+            .get(address)
+            .cloned()
+            .ok_or_else(|| AccountManagerError::SignerNotFound {
+                address: address.to_string(),
+            })
+    }
 }
 
 #[derive(Debug, Snafu)]
 pub enum AccountManagerError {
     #[snafu(display("No signer found for address: {address}"))]
     SignerNotFound { address: String },
+
+    #[snafu(display("Invalid mnemonic: {message}"))]
+    InvalidMnemonic { message: String },
+
+    #[snafu(display("Invalid multisig: {message}"))]
+    InvalidMultisig { message: String },
+
+    #[snafu(display("Client error: {message}"))]
+    ClientError { message: String },
+
+    #[snafu(display("Dispenser not found: {message}"))]
+    DispenserNotFound { message: String },
+
+    #[snafu(display("Funding error: {message}"))]
+    FundingError { message: String },
+
+    #[snafu(display("Rekey failed: {message}"))]
+    RekeyFailed { message: String },
+}
+
+/// Convert a payment or asset transfer from the indexer, `None` for other transaction types.
+fn to_transaction(transaction: &IndexerTransaction) -> Option<Transaction> {
+    let address = |address: &str| address.parse::<Address>().ok();
+    let header = TransactionHeader {
+        sender: address(&transaction.sender)?,
+        fee: Some(transaction.fee),
+        first_valid: transaction.first_valid.into(),
+        last_valid: transaction.last_valid.into(),
+        genesis_hash: transaction
+            .genesis_hash
+            .as_deref()
+            .and_then(|hash| hash.try_into().ok()),
+        genesis_id: transaction.genesis_id.clone(),
+        note: transaction.note.clone(),
+        rekey_to: transaction.rekey_to.as_deref().and_then(address),
+        lease: transaction
+            .lease
+            .as_deref()
+            .and_then(|lease| lease.try_into().ok()),
+        group: transaction
+            .group
+            .as_deref()
+            .and_then(|group| group.try_into().ok()),
+    };
+
+    if let Some(payment) = &transaction.payment_transaction {
+        return Some(Transaction::Payment(PaymentTransactionFields {
+            header,
+            receiver: address(&payment.receiver)?,
+            amount: payment.amount,
+            close_remainder_to: payment.close_remainder_to.as_deref().and_then(address),
+        }));
+    }
+    if let Some(transfer) = &transaction.asset_transfer_transaction {
+        return Some(Transaction::AssetTransfer(AssetTransferTransactionFields {
+            header,
+            asset_id: transfer.asset_id,
+            amount: transfer.amount,
+            receiver: address(&transfer.receiver)?,
+            asset_sender: transfer.sender.as_deref().and_then(address),
+            close_remainder_to: transfer.close_to.as_deref().and_then(address),
+        }));
+    }
+    None
 }
 
 impl TransactionSignerGetter for AccountManager {
@@ -57,3 +702,207 @@ impl TransactionSignerGetter for AccountManager {
         self.get_signer(address).map_err(|e| e.to_string())
     }
 }
+
+/// An ed25519 keypair account that signs with its secret key.
+///
+/// Transactions whose sender isn't the account, e.g. of accounts rekeyed to it, are signed as the
+/// authorizing account.
+#[derive(Debug, Clone)]
+pub struct SigningAccount {
+    secret_key: SecretKey,
+}
+
+impl SigningAccount {
+    pub fn new(secret_key: SecretKey) -> Self {
+        Self { secret_key }
+    }
+
+    /// Generate a new random account.
+    pub fn generate() -> Self {
+        Self::new(SecretKey::from(SigningKey::generate(&mut OsRng).to_bytes()))
+    }
+
+    /// Create the account of a 25 word mnemonic.
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self, MnemonicError> {
+        Ok(Self::new(SecretKey::from(mnemonic::to_key(mnemonic)?)))
+    }
+
+    pub fn address(&self) -> Address {
+        let verifying_key: VerifyingKey = (&self.signing_key()).into();
+        KeyPairAccount::from_pubkey(&verifying_key.to_bytes()).address()
+    }
+
+    /// The 25 word mnemonic of the account's secret key.
+    pub fn mnemonic(&self) -> String {
+        mnemonic::from_key(self.secret_key.as_bytes())
+            .expect("A secret key is always a valid mnemonic key")
+    }
+
+    /// Sign arbitrary bytes with the account's secret key.
+    pub(crate) fn sign_bytes(&self, bytes: &[u8]) -> [u8; ALGORAND_SIGNATURE_BYTE_LENGTH] {
+        self.signing_key().sign(bytes).to_bytes()
+    }
+
+    fn signing_key(&self) -> SigningKey {
+        SigningKey::from_bytes(self.secret_key.as_bytes())
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for SigningAccount {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        let signing_key = self.signing_key();
+        let address = self.address();
+
+        indices
+            .iter()
+            .map(|&index| {
+                let transaction = transactions
+                    .get(index)
+                    .ok_or_else(|| format!("Index {} out of bounds for transactions", index))?;
+                let encoded = transaction
+                    .encode()
+                    .map_err(|e| format!("Failed to encode transaction: {}", e))?;
+                let signature: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH] =
+                    signing_key.sign(&encoded).to_bytes();
+
+                Ok(SignedTransaction {
+                    transaction: transaction.clone(),
+                    signature: Some(signature),
+                    auth_address: (transaction.header().sender != address).then(|| address.clone()),
+                    multisignature: None,
+                    logic_signature: None,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::TransactionMother;
+
+    #[test]
+    fn test_historical_transaction_converts_payments() {
+        let sender = SigningAccount::generate().address();
+        let receiver = SigningAccount::generate().address();
+        let payment = IndexerTransaction {
+            id: Some("TXID".to_string()),
+            tx_type: "pay".to_string(),
+            sender: sender.to_string(),
+            fee: 1000,
+            first_valid: 10,
+            last_valid: 1010,
+            confirmed_round: Some(12),
+            payment_transaction: Some(indexer_client::models::TransactionPayment {
+                amount: 5,
+                receiver: receiver.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let historical = HistoricalTransaction::from(payment);
+        assert_eq!(historical.id, "TXID");
+        assert_eq!(historical.confirmed_round, Some(12));
+        let Some(Transaction::Payment(fields)) = historical.transaction else {
+            panic!("Expected a payment");
+        };
+        assert_eq!(fields.header.sender, sender);
+        assert_eq!(fields.header.last_valid, 1010);
+        assert_eq!(fields.receiver, receiver);
+        assert_eq!(fields.amount, 5);
+
+        let key_registration = IndexerTransaction {
+            tx_type: "keyreg".to_string(),
+            sender: sender.to_string(),
+            ..Default::default()
+        };
+        assert!(
+            HistoricalTransaction::from(key_registration)
+                .transaction
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_from_mnemonic_registers_signer() {
+        let account = SigningAccount::generate();
+        let account_manager = AccountManager::new();
+
+        let registered = account_manager.from_mnemonic(&account.mnemonic()).unwrap();
+        assert_eq!(registered.address(), account.address());
+        assert!(account_manager.get_signer(account.address()).is_ok());
+
+        assert!(matches!(
+            account_manager.from_mnemonic("not a mnemonic"),
+            Err(AccountManagerError::InvalidMnemonic { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rekeyed_signs_as_the_signing_account() {
+        let account_manager = AccountManager::new();
+        let signing_account = account_manager.random();
+        let transaction = TransactionMother::simple_payment().build().unwrap();
+        let sender = transaction.header().sender.clone();
+
+        account_manager
+            .rekeyed(sender.clone(), &signing_account.address())
+            .unwrap();
+        let signed = account_manager
+            .get_signer(sender)
+            .unwrap()
+            .sign_transaction(&transaction)
+            .await
+            .unwrap();
+        assert_eq!(signed.auth_address, Some(signing_account.address()));
+
+        assert!(matches!(
+            account_manager.rekeyed(signing_account.address(), &Address::default()),
+            Err(AccountManagerError::SignerNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multisig_signs_up_to_the_threshold() {
+        let account_manager = AccountManager::new();
+        let participants: Vec<Address> =
+            (0..3).map(|_| account_manager.random().address()).collect();
+
+        let multisig_address = account_manager
+            .multisig(1, 2, participants.clone())
+            .unwrap();
+        let mut transaction = TransactionMother::simple_payment().build().unwrap();
+        transaction.header_mut().sender = multisig_address.clone();
+
+        let signed = account_manager
+            .get_signer(multisig_address)
+            .unwrap()
+            .sign_transaction(&transaction)
+            .await
+            .unwrap();
+        let multisig = signed.multisignature.unwrap();
+        assert_eq!(multisig.participants(), participants);
+        assert_eq!(
+            multisig
+                .subsignatures
+                .iter()
+                .filter(|subsig| subsig.signature.is_some())
+                .count(),
+            2
+        );
+        assert_eq!(signed.auth_address, None);
+
+        let unknown = SigningAccount::generate().address();
+        assert!(matches!(
+            account_manager.multisig(1, 2, vec![participants[0].clone(), unknown]),
+            Err(AccountManagerError::InvalidMultisig { .. })
+        ));
+    }
+}
@@ -0,0 +1,486 @@
+use algod_client::apis::Error as AlgodError;
+use algokit_http_client::HttpError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use snafu::Snafu;
+
+lazy_static! {
+    static ref TXID_RE: Regex = Regex::new(r"transaction (?P<txid>[A-Z2-7]{52})").unwrap();
+    static ref ALREADY_IN_LEDGER_RE: Regex =
+        Regex::new(r"transaction already in ledger: (?P<txid>[A-Z2-7]{52})").unwrap();
+    static ref LEASE_IN_USE_RE: Regex =
+        Regex::new(r"transaction (?P<txid>[A-Z2-7]{52}) using an overlapping lease").unwrap();
+    static ref TXN_DEAD_RE: Regex = Regex::new(
+        r"txn dead: round (?P<round>[0-9]+) outside of (?P<first_valid>[0-9]+)--(?P<last_valid>[0-9]+)"
+    )
+    .unwrap();
+    static ref FEE_TOO_SMALL_RE: Regex =
+        Regex::new(r"fee too small|in fees, which is less than the minimum").unwrap();
+    static ref OVERSPEND_RE: Regex = Regex::new(
+        r"overspend \(account (?P<address>[A-Z2-7]{58}).*tried to spend \{(?P<required>[0-9]+)\}"
+    )
+    .unwrap();
+    static ref MICRO_ALGOS_RE: Regex =
+        Regex::new(r"MicroAlgos:\{Raw:(?P<balance>[0-9]+)\}").unwrap();
+    static ref BELOW_MIN_BALANCE_RE: Regex = Regex::new(
+        r"account (?P<address>[A-Z2-7]{58}) balance (?P<balance>[0-9]+) below min (?P<required>[0-9]+)"
+    )
+    .unwrap();
+    static ref LOGIC_EVAL_RE: Regex =
+        Regex::new(r"logic eval error: (?P<message>.*?)(\. Details: (?P<details>.*))?$").unwrap();
+    static ref APP_ID_RE: Regex = Regex::new(r"app=(?P<app_id>[0-9]+)").unwrap();
+    static ref PC_RE: Regex = Regex::new(r"pc=(?P<pc>[0-9]+)").unwrap();
+}
+
+/// An error of algod, parsed from its JSON error payload (`{"message": ...}`) into the rejections
+/// callers commonly handle.
+///
+/// Each variant keeps the message of algod, which is also its display.
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+pub enum AlgodErrorKind {
+    /// The sender can't cover the amounts and fee of a transaction.
+    #[snafu(display("{message}"))]
+    Overspend {
+        txid: Option<String>,
+        address: String,
+        balance: Option<u64>,
+        required_balance: u64,
+        message: String,
+    },
+    /// A transaction would leave an account below its minimum balance.
+    #[snafu(display("{message}"))]
+    BelowMinBalance {
+        txid: Option<String>,
+        address: String,
+        balance: u64,
+        required_balance: u64,
+        message: String,
+    },
+    /// The round was outside of the validity window of a transaction.
+    #[snafu(display("{message}"))]
+    TxnDead {
+        txid: Option<String>,
+        round: u64,
+        first_valid: u64,
+        last_valid: u64,
+        message: String,
+    },
+    /// The fee of a transaction, or the pooled fees of its group, are below the minimum fee.
+    #[snafu(display("{message}"))]
+    FeeTooSmall {
+        txid: Option<String>,
+        message: String,
+    },
+    /// The transaction was already confirmed.
+    #[snafu(display("{message}"))]
+    AlreadyInLedger { txid: String, message: String },
+    /// Another transaction of the sender with the same lease was confirmed or is pending within
+    /// the validity window of this one.
+    #[snafu(display("{message}"))]
+    LeaseInUse { txid: String, message: String },
+    /// A logic signature or app program rejected a transaction.
+    #[snafu(display("{message}"))]
+    LogicEval {
+        txid: Option<String>,
+        app_id: Option<u64>,
+        pc: Option<u64>,
+        /// The reason of the rejection, e.g. `assert failed pc=885`
+        reason: String,
+        message: String,
+    },
+    /// The requested resource doesn't exist (404), e.g. a transaction which isn't pending yet.
+    #[snafu(display("{message}"))]
+    NotFound { message: String },
+    /// A 5xx response, timeout or network failure, which may succeed when retried.
+    #[snafu(display("{message}"))]
+    Unavailable {
+        status: Option<u16>,
+        message: String,
+    },
+    /// Any other error.
+    #[snafu(display("{message}"))]
+    Other {
+        status: Option<u16>,
+        message: String,
+    },
+}
+
+impl AlgodErrorKind {
+    /// Parse the message of algod, given the status of its response if any.
+    pub fn from_message(status: Option<u16>, message: &str) -> Self {
+        let message = message.to_string();
+        let txid = TXID_RE
+            .captures(&message)
+            .map(|captures| captures["txid"].to_string());
+        let number = |value: &str| value.parse::<u64>().unwrap_or_default();
+
+        if let Some(captures) = LOGIC_EVAL_RE.captures(&message) {
+            let details = captures.name("details").map(|details| details.as_str());
+            return Self::LogicEval {
+                txid,
+                app_id: details
+                    .and_then(|details| APP_ID_RE.captures(details))
+                    .map(|captures| number(&captures["app_id"])),
+                pc: PC_RE
+                    .captures(details.unwrap_or(&captures["message"]))
+                    .map(|captures| number(&captures["pc"])),
+                reason: captures["message"].to_string(),
+                message,
+            };
+        }
+        if let Some(captures) = ALREADY_IN_LEDGER_RE.captures(&message) {
+            return Self::AlreadyInLedger {
+                txid: captures["txid"].to_string(),
+                message,
+            };
+        }
+        if let Some(captures) = LEASE_IN_USE_RE.captures(&message) {
+            return Self::LeaseInUse {
+                txid: captures["txid"].to_string(),
+                message,
+            };
+        }
+        if let Some(captures) = TXN_DEAD_RE.captures(&message) {
+            return Self::TxnDead {
+                txid,
+                round: number(&captures["round"]),
+                first_valid: number(&captures["first_valid"]),
+                last_valid: number(&captures["last_valid"]),
+                message,
+            };
+        }
+        if FEE_TOO_SMALL_RE.is_match(&message) {
+            return Self::FeeTooSmall { txid, message };
+        }
+        if let Some(captures) = OVERSPEND_RE.captures(&message) {
+            return Self::Overspend {
+                txid,
+                address: captures["address"].to_string(),
+                balance: MICRO_ALGOS_RE
+                    .captures(&message)
+                    .map(|balance| number(&balance["balance"])),
+                required_balance: number(&captures["required"]),
+                message,
+            };
+        }
+        if let Some(captures) = BELOW_MIN_BALANCE_RE.captures(&message) {
+            return Self::BelowMinBalance {
+                txid,
+                address: captures["address"].to_string(),
+                balance: number(&captures["balance"]),
+                required_balance: number(&captures["required"]),
+                message,
+            };
+        }
+
+        match status {
+            Some(404) => Self::NotFound { message },
+            Some(status) if status >= 500 => Self::Unavailable {
+                status: Some(status),
+                message,
+            },
+            status => Self::Other { status, message },
+        }
+    }
+
+    /// Whether retrying the request may succeed, i.e. algod was unavailable.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Unavailable { .. })
+    }
+
+    /// Whether algod responded with a 404.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+
+    /// Whether a fee was too small, including the fee of an inner transaction an app program
+    /// submitted.
+    pub fn is_fee_too_small(&self) -> bool {
+        match self {
+            Self::FeeTooSmall { .. } => true,
+            Self::LogicEval { reason, .. } => FEE_TOO_SMALL_RE.is_match(reason),
+            _ => false,
+        }
+    }
+
+    /// The message of algod.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Overspend { message, .. }
+            | Self::BelowMinBalance { message, .. }
+            | Self::TxnDead { message, .. }
+            | Self::FeeTooSmall { message, .. }
+            | Self::AlreadyInLedger { message, .. }
+            | Self::LeaseInUse { message, .. }
+            | Self::LogicEval { message, .. }
+            | Self::NotFound { message }
+            | Self::Unavailable { message, .. }
+            | Self::Other { message, .. } => message,
+        }
+    }
+}
+
+impl From<&AlgodError> for AlgodErrorKind {
+    fn from(error: &AlgodError) -> Self {
+        match error {
+            AlgodError::Http {
+                source: HttpError::Status { code, body, .. },
+            } => {
+                // Algod responds with `{"message": ...}`, possibly with further `data`
+                let message = serde_json::from_slice::<serde_json::Value>(body)
+                    .ok()
+                    .and_then(|payload| payload["message"].as_str().map(str::to_string))
+                    .unwrap_or_else(|| String::from_utf8_lossy(body).into_owned());
+                Self::from_message(Some(*code), &message)
+            }
+            AlgodError::Http { source } => Self::Unavailable {
+                status: None,
+                message: source.to_string(),
+            },
+            AlgodError::Serde { message } => Self::Other {
+                status: None,
+                message: message.clone(),
+            },
+            AlgodError::Api { source } => Self::from_message(None, &source.to_string()),
+        }
+    }
+}
+
+impl From<AlgodError> for AlgodErrorKind {
+    fn from(error: AlgodError) -> Self {
+        Self::from(&error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algod_client::AlgodClient;
+    use algokit_http_client::{HttpClient, HttpMethod, HttpResponse, RequestOptions};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    const TXID: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+    const ADDRESS: &str = "BBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB";
+
+    fn status_error(code: u16, body: &str) -> AlgodError {
+        AlgodError::Http {
+            source: HttpError::Status {
+                code,
+                body: body.as_bytes().to_vec(),
+                headers: HashMap::new(),
+            },
+        }
+    }
+
+    fn payload(message: &str) -> String {
+        serde_json::json!({ "message": message }).to_string()
+    }
+
+    /// Answers every request with a fixed response rather than failing with non-2xx ones, like
+    /// foreign clients may.
+    struct StubHttpClient {
+        status: u16,
+        body: String,
+    }
+
+    #[async_trait]
+    impl HttpClient for StubHttpClient {
+        async fn request(
+            &self,
+            _http_method: HttpMethod,
+            _path: String,
+            _query: Option<HashMap<String, String>>,
+            _body: Option<Vec<u8>>,
+            _headers: Option<HashMap<String, String>>,
+            _options: Option<RequestOptions>,
+        ) -> Result<HttpResponse, HttpError> {
+            Ok(HttpResponse {
+                status: self.status,
+                body: self.body.as_bytes().to_vec(),
+                headers: HashMap::from([(
+                    "content-type".to_string(),
+                    "application/json".to_string(),
+                )]),
+            })
+        }
+    }
+
+    #[test]
+    fn test_parses_overspend() {
+        let message = format!(
+            "TransactionPool.Remember: transaction {TXID}: overspend (account {ADDRESS}, data {{_struct:{{}} Status:Offline MicroAlgos:{{Raw:100000}} RewardsBase:0}}, tried to spend {{1000000}})"
+        );
+
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::Overspend {
+                txid: Some(TXID.to_string()),
+                address: ADDRESS.to_string(),
+                balance: Some(100000),
+                required_balance: 1000000,
+                message,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_below_min_balance() {
+        let message = format!(
+            "TransactionPool.Remember: transaction {TXID}: account {ADDRESS} balance 100000 below min 200000 (1 assets)"
+        );
+
+        assert!(matches!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::BelowMinBalance {
+                balance: 100000,
+                required_balance: 200000,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parses_txn_dead() {
+        let message = format!(
+            "TransactionPool.Remember: transaction {TXID}: txn dead: round 2000 outside of 1000--1999"
+        );
+
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::TxnDead {
+                txid: Some(TXID.to_string()),
+                round: 2000,
+                first_valid: 1000,
+                last_valid: 1999,
+                message,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_lease_in_use() {
+        let message = format!(
+            "TransactionPool.Remember: transaction {TXID} using an overlapping lease (sender, lease):({ADDRESS}, AQID)"
+        );
+
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::LeaseInUse {
+                txid: TXID.to_string(),
+                message,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_fee_too_small() {
+        let message = format!(
+            "transaction {TXID}: logic eval error: fee too small [{{txn: {{...}}}}]. Details: app=1, pc=10"
+        );
+        let error = AlgodErrorKind::from_message(None, &message);
+        assert!(matches!(
+            error,
+            AlgodErrorKind::LogicEval {
+                app_id: Some(1),
+                ..
+            }
+        ));
+        assert!(error.is_fee_too_small());
+
+        let message = format!("TransactionPool.Remember: transaction {TXID}: fee too small");
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::FeeTooSmall {
+                txid: Some(TXID.to_string()),
+                message,
+            }
+        );
+
+        let message = "txgroup had 1000 in fees, which is less than the minimum 2 * 1000";
+        assert!(matches!(
+            AlgodErrorKind::from_message(Some(400), message),
+            AlgodErrorKind::FeeTooSmall { txid: None, .. }
+        ));
+        assert!(!AlgodErrorKind::from_message(Some(400), "overspend").is_fee_too_small());
+    }
+
+    #[test]
+    fn test_parses_already_in_ledger() {
+        let message = format!("TransactionPool.Remember: transaction already in ledger: {TXID}");
+
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, &payload(&message))),
+            AlgodErrorKind::AlreadyInLedger {
+                txid: TXID.to_string(),
+                message,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_logic_eval_error() {
+        let message = format!(
+            "TransactionPool.Remember: transaction {TXID}: logic eval error: assert failed pc=885. Details: app=1001, pc=885, opcodes=intc_0 // 0; assert"
+        );
+
+        let error = AlgodErrorKind::from(&status_error(400, &payload(&message)));
+
+        assert_eq!(
+            error,
+            AlgodErrorKind::LogicEval {
+                txid: Some(TXID.to_string()),
+                app_id: Some(1001),
+                pc: Some(885),
+                reason: "assert failed pc=885".to_string(),
+                message: message.clone(),
+            }
+        );
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[test]
+    fn test_classifies_other_errors_by_status() {
+        assert!(AlgodErrorKind::from(&status_error(404, &payload("box not found"))).is_not_found());
+        assert!(AlgodErrorKind::from(&status_error(503, "unavailable")).is_transient());
+        assert!(
+            AlgodErrorKind::from(&AlgodError::Http {
+                source: HttpError::RequestError {
+                    message: "error sending request".to_string(),
+                },
+            })
+            .is_transient()
+        );
+        assert_eq!(
+            AlgodErrorKind::from(&status_error(400, "not json")),
+            AlgodErrorKind::Other {
+                status: Some(400),
+                message: "not json".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_2xx_responses_of_other_clients_are_errors() {
+        let algod = AlgodClient::new(Arc::new(StubHttpClient {
+            status: 404,
+            body: payload("account not found"),
+        }));
+
+        let error = algod.get_status().await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            AlgodError::Http {
+                source: HttpError::Status { code: 404, .. }
+            }
+        ));
+        assert_eq!(
+            AlgodErrorKind::from(&error),
+            AlgodErrorKind::NotFound {
+                message: "account not found".to_string(),
+            }
+        );
+    }
+}
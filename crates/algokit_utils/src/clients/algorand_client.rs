@@ -1,25 +1,29 @@
 use crate::applications::AppDeployer;
 use crate::clients::app_manager::AppManager;
 use crate::clients::asset_manager::AssetManager;
-use crate::clients::client_manager::ClientManager;
-use crate::clients::network_client::{AlgoConfig, AlgorandService};
+use crate::clients::client_manager::{ClientManager, ClientManagerError};
+use crate::clients::kmd_account_manager::KmdAccountManager;
+use crate::clients::network_client::{AlgoConfig, AlgorandService, NetworkRegistry};
+use crate::clients::node_manager::NodeManager;
 use crate::transactions::{
-    TransactionComposer, TransactionComposerConfig, TransactionComposerParams, TransactionCreator,
-    TransactionSender,
+    RetryPolicy, TransactionComposer, TransactionComposerConfig, TransactionComposerParams,
+    TransactionCreator, TransactionSender,
 };
 use crate::{AccountManager, TransactionSigner};
-use algod_client::models::TransactionParams;
+use algod_client::{AlgodClient, models::TransactionParams};
 use algokit_transact::Address;
-use std::sync::{Arc, Mutex};
+use futures::stream::{self, Stream};
+use std::sync::Arc;
 
 pub struct AlgorandClient {
-    client_manager: ClientManager,
+    client_manager: Arc<ClientManager>,
     asset_manager: AssetManager,
     app_manager: AppManager,
+    node_manager: NodeManager,
     app_deployer: AppDeployer,
     transaction_sender: TransactionSender,
     transaction_creator: TransactionCreator,
-    account_manager: Arc<Mutex<AccountManager>>,
+    account_manager: Arc<AccountManager>,
     default_composer_config: Option<TransactionComposerConfig>,
 }
 
@@ -31,27 +35,34 @@ pub struct AlgorandClientParams {
 
 impl AlgorandClient {
     pub fn new(params: &AlgorandClientParams) -> Self {
-        let client_manager = ClientManager::new(&params.client_config).unwrap();
+        let client_manager = Arc::new(ClientManager::new(&params.client_config).unwrap());
         let algod_client = client_manager.algod();
 
-        let account_manager = Arc::new(Mutex::new(AccountManager::new()));
+        let account_manager = Arc::new(AccountManager::with_client_manager(client_manager.clone()));
 
         let new_composer = {
             let algod_client = algod_client.clone();
             let account_manager = account_manager.clone();
             let default_composer_config = params.composer_config.clone();
+            let suggested_params_cache = client_manager.suggested_params_cache();
             move |composer_config: Option<TransactionComposerConfig>| {
                 TransactionComposer::new(TransactionComposerParams {
                     algod_client: algod_client.clone(),
                     signer_getter: account_manager.clone(),
                     composer_config: composer_config.or_else(|| default_composer_config.clone()),
+                    suggested_params_cache: Some(suggested_params_cache.clone()),
                 })
             }
         };
 
         let algod_client_for_asset = algod_client.clone();
-        let asset_manager = AssetManager::new(algod_client_for_asset.clone(), new_composer.clone());
+        let asset_manager = AssetManager::new(
+            algod_client_for_asset.clone(),
+            client_manager.indexer_if_present(),
+            new_composer.clone(),
+        );
         let app_manager = AppManager::new(algod_client.clone());
+        let node_manager = NodeManager::new(algod_client.clone());
 
         // Create closure for new_composer function
         let transaction_sender =
@@ -63,7 +74,7 @@ impl AlgorandClient {
         let app_deployer = AppDeployer::new(
             app_manager.clone(),
             transaction_sender.clone(),
-            Some(client_manager.indexer().unwrap()),
+            client_manager.indexer_if_present(),
         );
 
         Self {
@@ -71,6 +82,7 @@ impl AlgorandClient {
             account_manager: account_manager.clone(),
             asset_manager,
             app_manager,
+            node_manager,
             app_deployer,
             transaction_sender,
             transaction_creator,
@@ -81,13 +93,18 @@ impl AlgorandClient {
     pub async fn get_suggested_params(
         &self,
     ) -> Result<TransactionParams, Box<dyn std::error::Error>> {
-        Ok(self.client_manager.algod().transaction_params().await?)
+        Ok(self.client_manager.suggested_params().await?)
     }
 
     pub fn client(&self) -> &ClientManager {
         &self.client_manager
     }
 
+    /// Get access to the AccountManager for registering the signers of accounts
+    pub fn account(&self) -> &AccountManager {
+        &self.account_manager
+    }
+
     /// Get access to the AssetManager for asset operations
     pub fn asset(&self) -> &AssetManager {
         &self.asset_manager
@@ -98,6 +115,20 @@ impl AlgorandClient {
         &self.app_manager
     }
 
+    /// Get access to the NodeManager for the status, readiness and catchup of the algod node
+    pub fn node(&self) -> &NodeManager {
+        &self.node_manager
+    }
+
+    /// Get a KmdAccountManager for the wallets of the client's KMD, e.g. to get the funded
+    /// accounts of a LocalNet. Fails when no KMD is configured.
+    pub fn kmd(&self) -> Result<KmdAccountManager, ClientManagerError> {
+        Ok(KmdAccountManager::new(
+            self.client_manager.kmd()?,
+            self.client_manager.algod(),
+        ))
+    }
+
     /// Get access to the TransactionSender for sending transactions
     pub fn send(&self) -> &TransactionSender {
         &self.transaction_sender
@@ -114,6 +145,7 @@ impl AlgorandClient {
             algod_client: self.client_manager.algod().clone(),
             signer_getter: self.account_manager.clone(),
             composer_config: params.or_else(|| self.default_composer_config.clone()),
+            suggested_params_cache: Some(self.client_manager.suggested_params_cache()),
         })
     }
 
@@ -160,6 +192,32 @@ impl AlgorandClient {
         })
     }
 
+    /// Create a client for a network by name: "localnet", "testnet", "mainnet" or the name of
+    /// a network registered with [`NetworkRegistry`] with a config.
+    pub fn from_network_name(
+        name: &str,
+        params: Option<TransactionComposerConfig>,
+    ) -> Result<Self, ClientManagerError> {
+        match name {
+            "localnet" => Ok(Self::default_localnet(params)),
+            "testnet" => Ok(Self::testnet(params)),
+            "mainnet" => Ok(Self::mainnet(params)),
+            _ => {
+                let network =
+                    NetworkRegistry::get(name).ok_or(ClientManagerError::NetworkError {
+                        message: format!("Network {} is not registered", name),
+                    })?;
+                let client_config = network.config.ok_or(ClientManagerError::NetworkError {
+                    message: format!("Network {} is registered without a client config", name),
+                })?;
+                Ok(Self::new(&AlgorandClientParams {
+                    client_config,
+                    composer_config: params,
+                }))
+            }
+        }
+    }
+
     pub fn from_environment(params: Option<TransactionComposerConfig>) -> Self {
         Self::new(&AlgorandClientParams {
             client_config: ClientManager::get_config_from_environment_or_localnet(),
@@ -167,11 +225,55 @@ impl AlgorandClient {
         })
     }
 
+    /// Get the last round the algod node has seen.
+    pub async fn current_round(&self) -> Result<u64, ClientManagerError> {
+        Ok(self.client_manager.algod().get_status().await?.last_round)
+    }
+
+    /// Wait until the algod node has seen `round`, returning the last round it has seen.
+    ///
+    /// Returns immediately if the round has already been reached.
+    pub async fn wait_for_round(&self, round: u64) -> Result<u64, ClientManagerError> {
+        wait_for_round(&self.client_manager.algod(), round).await
+    }
+
+    /// A stream yielding each new round the algod node sees, in order, starting after the
+    /// current round or at `start_round` if given.
+    ///
+    /// Rounds that were reached while the stream wasn't polled are still yielded, one at a
+    /// time. A failed poll yields the error and the stream retries the same round when polled
+    /// again.
+    pub fn block_stream(
+        &self,
+        start_round: Option<u64>,
+    ) -> impl Stream<Item = Result<u64, ClientManagerError>> + use<> {
+        let algod = self.client_manager.algod();
+        stream::unfold(start_round, move |next_round| {
+            let algod = algod.clone();
+            async move {
+                let round = match next_round {
+                    Some(round) => round,
+                    None => match algod.get_status().await {
+                        Ok(status) => status.last_round + 1,
+                        Err(e) => return Some((Err(e.into()), next_round)),
+                    },
+                };
+                match wait_for_round(&algod, round).await {
+                    Ok(_) => Some((Ok(round), Some(round + 1))),
+                    Err(e) => Some((Err(e), Some(round))),
+                }
+            }
+        })
+    }
+
     pub fn set_signer(&mut self, sender: Address, signer: Arc<dyn TransactionSigner>) {
-        self.account_manager
-            .lock()
-            .unwrap()
-            .set_signer(sender, signer);
+        self.account_manager.set_signer(sender, signer);
+    }
+
+    /// Retry sends of [`Self::send`] that fail with transient algod failures, `None` disables
+    /// retries.
+    pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+        self.transaction_sender = self.transaction_sender.with_retry_policy(retry_policy);
     }
 
     /// Get a clone of the persistent AppDeployer (shares cache across clones)
@@ -179,3 +281,12 @@ impl AlgorandClient {
         self.app_deployer.clone()
     }
 }
+
+async fn wait_for_round(algod: &AlgodClient, round: u64) -> Result<u64, ClientManagerError> {
+    let mut last_round = algod.get_status().await?.last_round;
+    while last_round < round {
+        // Algod responds once a block after `last_round` is seen, or after a timeout
+        last_round = algod.wait_for_block(last_round).await?.last_round;
+    }
+    Ok(last_round)
+}
@@ -2,19 +2,60 @@ use algod_client::{
     apis::{AlgodClient, Error as AlgodError},
     models::TealKeyValue,
 };
-use algokit_abi::{ABIMethod, ABIReturn, ABIType, ABIValue};
+use algokit_abi::{ABIMethod, ABIReturn, ABIType, ABIValue, Arc56Contract, ProgramSourceMap};
 use algokit_transact::Address;
 use base64::{Engine, engine::general_purpose::STANDARD as Base64};
 use sha2::{Digest, Sha256};
 use snafu::Snafu;
+
+use super::AlgodErrorKind;
+use super::state_layout::{RawStateValue, StateEntry, StateLayout};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone)]
 pub enum TealTemplateValue {
     Int(u64),
+    /// An integer which must not be negative, as TEAL integers are unsigned
+    SignedInt(i64),
     Bytes(Vec<u8>),
+    /// A string substituted as an integer if it parses as one, otherwise as its UTF-8 bytes
     String(String),
+    /// Base64 encoded bytes
+    Base64(String),
+    /// An address, substituted as its 32 byte public key
+    Address(Address),
+}
+
+impl TealTemplateValue {
+    /// The TEAL literal the value is substituted as: a decimal integer for `pushint`/`int` or a
+    /// `0x` hex byte string for `pushbytes`/`byte`.
+    pub fn to_teal_literal(&self) -> Result<String, AppManagerError> {
+        let invalid = |message: String| AppManagerError::InvalidTemplateValue { message };
+        Ok(match self {
+            TealTemplateValue::Int(i) => i.to_string(),
+            TealTemplateValue::SignedInt(i) => u64::try_from(*i)
+                .map_err(|_| invalid(format!("{} is negative, TEAL integers are unsigned", i)))?
+                .to_string(),
+            TealTemplateValue::String(s) => {
+                if s.parse::<i64>().is_ok() {
+                    s.clone()
+                } else {
+                    format!("0x{}", hex::encode(s.as_bytes()))
+                }
+            }
+            TealTemplateValue::Bytes(b) => format!("0x{}", hex::encode(b)),
+            TealTemplateValue::Base64(b64) => format!(
+                "0x{}",
+                hex::encode(
+                    Base64
+                        .decode(b64)
+                        .map_err(|e| invalid(format!("{} is not base64: {}", b64, e)))?
+                )
+            ),
+            TealTemplateValue::Address(address) => format!("0x{}", hex::encode(address.as_bytes())),
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -31,7 +72,7 @@ pub struct CompiledTeal {
     pub compiled: String,
     pub compiled_hash: String,
     pub compiled_base64_to_bytes: Vec<u8>,
-    pub source_map: Option<serde_json::Value>,
+    pub source_map: Option<ProgramSourceMap>,
 }
 
 #[derive(Debug, Clone)]
@@ -40,20 +81,20 @@ pub struct CompiledPrograms {
     pub clear: CompiledTeal,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AppState {
     Uint(UintAppState),
     Bytes(BytesAppState),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UintAppState {
     pub key_raw: Vec<u8>,
     pub key_base64: String,
     pub value: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BytesAppState {
     pub key_raw: Vec<u8>,
     pub key_base64: String,
@@ -62,6 +103,51 @@ pub struct BytesAppState {
     pub value: String,
 }
 
+/// The key of a state entry that differs between two reads of an app's state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateDiffKey {
+    pub key_raw: Vec<u8>,
+    /// The name of the ARC-56 key, or of the ARC-56 map the key belongs to
+    pub name: Option<String>,
+    /// The key within its ARC-56 map, decoded with the map's key type
+    pub map_key: Option<ABIValue>,
+}
+
+/// A state value, decoded with its ARC-56 value type when the key is declared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateDiffValue {
+    pub raw: AppState,
+    pub decoded: Option<ABIValue>,
+}
+
+/// A difference between two reads of an app's global or local state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateDiff {
+    Added {
+        key: StateDiffKey,
+        value: StateDiffValue,
+    },
+    Changed {
+        key: StateDiffKey,
+        before: StateDiffValue,
+        after: StateDiffValue,
+    },
+    Removed {
+        key: StateDiffKey,
+        value: StateDiffValue,
+    },
+}
+
+impl StateDiff {
+    pub fn key(&self) -> &StateDiffKey {
+        match self {
+            StateDiff::Added { key, .. }
+            | StateDiff::Changed { key, .. }
+            | StateDiff::Removed { key, .. } => key,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AppInformation {
     /// The app ID
@@ -99,6 +185,20 @@ pub struct BoxName {
     pub name: String,
 }
 
+/// A value read from algod, along with the round it was read at.
+///
+/// Algod only serves state at its latest round, so reads can't be pinned to an earlier round;
+/// comparing the rounds of several reads tells whether they observed the same ledger state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueAtRound<T> {
+    pub value: T,
+    /// The round at which the value was read
+    pub round: u64,
+}
+
+/// How many times a multi-value read is retried to read all values at the same round.
+pub const CONSISTENT_READ_ATTEMPTS: usize = 3;
+
 /// Box identifier represented as binary data.
 /// Box identifiers in Algorand are arbitrary binary data that can contain
 /// non-UTF-8 bytes. They are base64-encoded when sent over HTTP APIs as JSON responses.
@@ -112,6 +212,9 @@ pub const DELETABLE_TEMPLATE_NAME: &str = "TMPL_DELETABLE";
 pub struct AppManager {
     algod_client: Arc<AlgodClient>,
     compilation_results: Arc<Mutex<HashMap<String, CompiledTeal>>>,
+    /// The hash of the TEAL each compiled template was substituted to, by template key
+    template_compilation_keys: Arc<Mutex<HashMap<String, String>>>,
+    disassembly_results: Arc<Mutex<HashMap<String, String>>>,
 }
 
 impl AppManager {
@@ -119,15 +222,57 @@ impl AppManager {
         Self {
             algod_client,
             compilation_results: Arc::new(Mutex::new(HashMap::new())),
+            template_compilation_keys: Arc::new(Mutex::new(HashMap::new())),
+            disassembly_results: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    pub(crate) fn algod_client(&self) -> Arc<AlgodClient> {
+        self.algod_client.clone()
+    }
+
     /// Create a SHA256 hash of the TEAL code for use as cache key.
     /// This optimization reduces memory usage by storing a fixed-size hash
     /// instead of the full TEAL code string as the cache key.
     fn hash_teal_code(teal_code: &str) -> String {
+        Self::hash_bytes(teal_code.as_bytes())
+    }
+
+    /// Create a SHA256 hash of a TEAL template with the values it is compiled with, which
+    /// identifies the compiled program without substituting the template.
+    fn hash_teal_template(
+        teal_template_code: &str,
+        template_params: Option<&TealTemplateParams>,
+        deployment_metadata: Option<&DeploymentMetadata>,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        let mut update = |bytes: &[u8]| {
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(bytes);
+        };
+        update(teal_template_code.as_bytes());
+
+        let mut params: Vec<_> = template_params.into_iter().flatten().collect();
+        params.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (name, value) in params {
+            update(name.as_bytes());
+            update(format!("{:?}", value).as_bytes());
+        }
+
+        let flag = |flag: Option<bool>| match flag {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        };
+        if let Some(metadata) = deployment_metadata {
+            update(&[flag(metadata.updatable), flag(metadata.deletable)]);
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    fn hash_bytes(bytes: &[u8]) -> String {
         let mut hasher = Sha256::new();
-        hasher.update(teal_code.as_bytes());
+        hasher.update(bytes);
         hex::encode(hasher.finalize())
     }
 
@@ -153,7 +298,13 @@ impl AppManager {
             compiled: Base64.encode(&compile_response.result),
             compiled_hash: compile_response.hash.clone(),
             compiled_base64_to_bytes: compile_response.result.clone(),
-            source_map: compile_response.sourcemap,
+            source_map: compile_response
+                .sourcemap
+                .map(ProgramSourceMap::from_value)
+                .transpose()
+                .map_err(|e| AppManagerError::DecodingError {
+                    message: format!("Failed to parse source map: {}", e),
+                })?,
         };
 
         // Cache the result
@@ -171,6 +322,12 @@ impl AppManager {
         template_params: Option<&TealTemplateParams>,
         deployment_metadata: Option<&DeploymentMetadata>,
     ) -> Result<CompiledTeal, AppManagerError> {
+        let template_key =
+            Self::hash_teal_template(teal_template_code, template_params, deployment_metadata);
+        if let Some(cached) = self.get_cached_template_result(&template_key) {
+            return Ok(cached);
+        }
+
         let mut teal_code = Self::strip_teal_comments(teal_template_code);
 
         // When deployment metadata is provided, avoid replacing
@@ -200,7 +357,12 @@ impl AppManager {
                 Self::replace_teal_template_deploy_time_control_params(&teal_code, metadata)?;
         }
 
-        self.compile_teal(&teal_code).await
+        let result = self.compile_teal(&teal_code).await?;
+        self.template_compilation_keys
+            .lock()
+            .unwrap()
+            .insert(template_key, Self::hash_teal_code(&teal_code));
+        Ok(result)
     }
 
     pub fn get_compilation_result(&self, teal_code: &str) -> Option<CompiledTeal> {
@@ -209,6 +371,69 @@ impl AppManager {
         cache.get(&cache_key).cloned()
     }
 
+    /// The cached result of a previous [`Self::compile_teal_template`] with the same template,
+    /// template params and deployment metadata, so deploy and update flows can reuse compiled
+    /// programs without substituting the template again.
+    pub fn get_template_compilation_result(
+        &self,
+        teal_template_code: &str,
+        template_params: Option<&TealTemplateParams>,
+        deployment_metadata: Option<&DeploymentMetadata>,
+    ) -> Option<CompiledTeal> {
+        self.get_cached_template_result(&Self::hash_teal_template(
+            teal_template_code,
+            template_params,
+            deployment_metadata,
+        ))
+    }
+
+    fn get_cached_template_result(&self, template_key: &str) -> Option<CompiledTeal> {
+        let cache_key = self
+            .template_compilation_keys
+            .lock()
+            .unwrap()
+            .get(template_key)
+            .cloned()?;
+        let cache = self.compilation_results.lock().unwrap();
+        cache.get(&cache_key).cloned()
+    }
+
+    /// Disassemble program bytecode into TEAL with algod, which requires the developer API to be
+    /// enabled on the node.
+    ///
+    /// Results are cached by the hash of the bytecode, see [`Self::get_disassembly_result`].
+    pub async fn disassemble_teal(&self, bytecode: &[u8]) -> Result<String, AppManagerError> {
+        let cache_key = Self::hash_bytes(bytecode);
+
+        {
+            let cache = self.disassembly_results.lock().unwrap();
+            if let Some(cached) = cache.get(&cache_key) {
+                return Ok(cached.clone());
+            }
+        }
+
+        let teal = self
+            .algod_client
+            .teal_disassemble(bytecode.to_vec())
+            .await
+            .map_err(|e| AppManagerError::AlgodClientError { source: e })?
+            .result;
+
+        {
+            let mut cache = self.disassembly_results.lock().unwrap();
+            cache.insert(cache_key, teal.clone());
+        }
+
+        Ok(teal)
+    }
+
+    /// The cached disassembly of `bytecode`, if it was previously disassembled.
+    pub fn get_disassembly_result(&self, bytecode: &[u8]) -> Option<String> {
+        let cache_key = Self::hash_bytes(bytecode);
+        let cache = self.disassembly_results.lock().unwrap();
+        cache.get(&cache_key).cloned()
+    }
+
     pub async fn get_by_id(&self, app_id: u64) -> Result<AppInformation, AppManagerError> {
         let app = self
             .algod_client
@@ -261,6 +486,33 @@ impl AppManager {
         Ok(app_info.global_state)
     }
 
+    /// Get global state of an application, along with the round it was read at.
+    ///
+    /// The state is read through the app creator's account, which is the only algod endpoint
+    /// reporting the round of the app's global state.
+    pub async fn get_global_state_with_round(
+        &self,
+        app_id: u64,
+    ) -> Result<ValueAtRound<HashMap<Vec<u8>, AppState>>, AppManagerError> {
+        let app_info = self.get_by_id(app_id).await?;
+        let creator_info = self
+            .algod_client
+            .account_application_information(&app_info.creator, app_id, None)
+            .await
+            .map_err(|e| AppManagerError::AlgodClientError { source: e })?;
+
+        let global_state = creator_info
+            .created_app
+            .ok_or(AppManagerError::StateNotFound)?
+            .global_state
+            .unwrap_or_default();
+
+        Ok(ValueAtRound {
+            value: Self::decode_app_state(&global_state)?,
+            round: creator_info.round,
+        })
+    }
+
     /// Get local state for account in an application.
     /// Returns state keys as Vec<u8> for binary data support, matching TypeScript UInt8Array typing.
     pub async fn get_local_state(
@@ -268,6 +520,18 @@ impl AppManager {
         app_id: u64,
         address: &str,
     ) -> Result<HashMap<Vec<u8>, AppState>, AppManagerError> {
+        Ok(self
+            .get_local_state_with_round(app_id, address)
+            .await?
+            .value)
+    }
+
+    /// Get local state for account in an application, along with the round it was read at.
+    pub async fn get_local_state_with_round(
+        &self,
+        app_id: u64,
+        address: &str,
+    ) -> Result<ValueAtRound<HashMap<Vec<u8>, AppState>>, AppManagerError> {
         let app_info = self
             .algod_client
             .account_application_information(address, app_id, None)
@@ -279,7 +543,24 @@ impl AppManager {
             .and_then(|state| state.key_value)
             .ok_or(AppManagerError::StateNotFound)?;
 
-        Self::decode_app_state(&local_state)
+        Ok(ValueAtRound {
+            value: Self::decode_app_state(&local_state)?,
+            round: app_info.round,
+        })
+    }
+
+    /// Check whether an account is opted in to an application.
+    pub async fn is_opted_in(&self, app_id: u64, address: &str) -> Result<bool, AppManagerError> {
+        match self
+            .algod_client
+            .account_application_information(address, app_id, None)
+            .await
+        {
+            Ok(app_info) => Ok(app_info.app_local_state.is_some()),
+            // Algod responds with a 404 when the account has neither created nor opted in to the app
+            Err(e) if AlgodErrorKind::from(&e).is_not_found() => Ok(false),
+            Err(e) => Err(AppManagerError::AlgodClientError { source: e }),
+        }
     }
 
     /// Get names of all boxes for application.
@@ -312,6 +593,22 @@ impl AppManager {
         app_id: u64,
         box_name: &BoxIdentifier,
     ) -> Result<Vec<u8>, AppManagerError> {
+        Ok(self
+            .get_box_value_with_round(app_id, box_name, None)
+            .await?
+            .value)
+    }
+
+    /// Get value stored in box, along with the round it was read at.
+    ///
+    /// When `expected_round` is set, fails with [`AppManagerError::RoundMismatch`] if the value
+    /// was read at a different round, e.g. to check it is consistent with an earlier read.
+    pub async fn get_box_value_with_round(
+        &self,
+        app_id: u64,
+        box_name: &BoxIdentifier,
+        expected_round: Option<u64>,
+    ) -> Result<ValueAtRound<Vec<u8>>, AppManagerError> {
         let (_, name_bytes) = Self::get_box_reference(box_name);
         // Algod expects goal-arg style encoding for box name query param in 'encoding:value'.
         // However our HTTP client decodes base64 automatically into bytes for the Box model fields.
@@ -324,7 +621,19 @@ impl AppManager {
             .await
             .map_err(|e| AppManagerError::AlgodClientError { source: e })?;
 
-        Ok(box_result.value)
+        if let Some(expected) = expected_round {
+            if box_result.round != expected {
+                return Err(AppManagerError::RoundMismatch {
+                    expected,
+                    actual: box_result.round,
+                });
+            }
+        }
+
+        Ok(ValueAtRound {
+            value: box_result.value,
+            round: box_result.round,
+        })
     }
 
     /// Get values for multiple boxes.
@@ -340,6 +649,52 @@ impl AppManager {
         Ok(values)
     }
 
+    /// Get values for multiple boxes, all read at the same round.
+    ///
+    /// The reads are restarted when the node moves to a new round part way through, up to
+    /// [`CONSISTENT_READ_ATTEMPTS`] times before failing with [`AppManagerError::RoundMismatch`].
+    pub async fn get_box_values_with_round(
+        &self,
+        app_id: u64,
+        box_names: &[BoxIdentifier],
+    ) -> Result<ValueAtRound<Vec<Vec<u8>>>, AppManagerError> {
+        let mut attempt = 1;
+        'read: loop {
+            let mut round = None;
+            let mut values = Vec::with_capacity(box_names.len());
+            for box_name in box_names {
+                match self.get_box_value_with_round(app_id, box_name, round).await {
+                    Ok(value) => {
+                        round = Some(value.round);
+                        values.push(value.value);
+                    }
+                    Err(AppManagerError::RoundMismatch { .. })
+                        if attempt < CONSISTENT_READ_ATTEMPTS =>
+                    {
+                        attempt += 1;
+                        continue 'read;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            let round = match round {
+                Some(round) => round,
+                None => {
+                    self.algod_client
+                        .get_status()
+                        .await
+                        .map_err(|e| AppManagerError::AlgodClientError { source: e })?
+                        .last_round
+                }
+            };
+            return Ok(ValueAtRound {
+                value: values,
+                round,
+            });
+        }
+    }
+
     /// Decode box value using ABI type.
     ///
     /// This method takes an ABIType directly and uses it to decode the box value,
@@ -428,7 +783,7 @@ impl AppManager {
     /// Helper function to ensure bytes are decoded from base64 if needed.
     /// When using `Bytes` deserializer with JSON, base64 strings are not decoded
     /// but kept as ASCII bytes of the base64 string. This function detects and fixes that.
-    fn ensure_decoded_bytes(bytes: &[u8]) -> Vec<u8> {
+    pub(crate) fn ensure_decoded_bytes(bytes: &[u8]) -> Vec<u8> {
         // Check if bytes could be a base64 string
         if let Ok(s) = std::str::from_utf8(bytes) {
             // Base64 strings have specific characteristics:
@@ -510,6 +865,86 @@ impl AppManager {
         Ok(state_values)
     }
 
+    /// Diff two reads of an app's global state, e.g. before and after a call.
+    ///
+    /// Entries are ordered by raw key. With `app_spec`, keys and values declared in its global
+    /// `state.keys` and `state.maps` are named and decoded with their ABI types; values that don't
+    /// decode with their declared type are an error, as the app spec doesn't describe the app.
+    pub fn diff_global_state(
+        before: &HashMap<Vec<u8>, AppState>,
+        after: &HashMap<Vec<u8>, AppState>,
+        app_spec: Option<&Arc56Contract>,
+    ) -> Result<Vec<StateDiff>, AppManagerError> {
+        let layout = app_spec
+            .map(|app_spec| {
+                StateLayout::new(
+                    app_spec.get_global_abi_storage_keys()?,
+                    app_spec.get_global_abi_storage_maps()?,
+                )
+            })
+            .unwrap_or_else(|| StateLayout::new(HashMap::new(), HashMap::new()))
+            .map_err(|e| AppManagerError::ABIDecodeError {
+                message: e.to_string(),
+            })?;
+        Self::diff_state(before, after, &layout)
+    }
+
+    /// Diff two reads of an account's local state in an app, see [`Self::diff_global_state`].
+    pub fn diff_local_state(
+        before: &HashMap<Vec<u8>, AppState>,
+        after: &HashMap<Vec<u8>, AppState>,
+        app_spec: Option<&Arc56Contract>,
+    ) -> Result<Vec<StateDiff>, AppManagerError> {
+        let layout = app_spec
+            .map(|app_spec| {
+                StateLayout::new(
+                    app_spec.get_local_abi_storage_keys()?,
+                    app_spec.get_local_abi_storage_maps()?,
+                )
+            })
+            .unwrap_or_else(|| StateLayout::new(HashMap::new(), HashMap::new()))
+            .map_err(|e| AppManagerError::ABIDecodeError {
+                message: e.to_string(),
+            })?;
+        Self::diff_state(before, after, &layout)
+    }
+
+    fn diff_state(
+        before: &HashMap<Vec<u8>, AppState>,
+        after: &HashMap<Vec<u8>, AppState>,
+        layout: &StateLayout,
+    ) -> Result<Vec<StateDiff>, AppManagerError> {
+        let mut keys: Vec<&Vec<u8>> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut diffs = Vec::new();
+        for key_raw in keys {
+            let entry = layout.describe(key_raw);
+            let key = StateDiffKey::new(key_raw, entry.as_ref());
+            let value = |raw| StateDiffValue::new(raw, entry.as_ref());
+            let diff = match (before.get(key_raw), after.get(key_raw)) {
+                (Some(before), Some(after)) if before == after => continue,
+                (Some(before), Some(after)) => StateDiff::Changed {
+                    key,
+                    before: value(before)?,
+                    after: value(after)?,
+                },
+                (None, Some(after)) => StateDiff::Added {
+                    key,
+                    value: value(after)?,
+                },
+                (Some(before), None) => StateDiff::Removed {
+                    key,
+                    value: value(before)?,
+                },
+                (None, None) => continue,
+            };
+            diffs.push(diff);
+        }
+        Ok(diffs)
+    }
+
     /// Replace template variables in TEAL code.
     pub fn replace_template_variables(
         program: &str,
@@ -518,30 +953,81 @@ impl AppManager {
         let mut program_lines: Vec<String> = program.lines().map(|line| line.to_string()).collect();
 
         for (template_variable_name, template_value) in template_values {
-            let token = if template_variable_name.starts_with("TMPL_") {
-                template_variable_name.clone()
-            } else {
-                format!("TMPL_{}", template_variable_name)
-            };
-
-            let value = match template_value {
-                TealTemplateValue::Int(i) => i.to_string(),
-                TealTemplateValue::String(s) => {
-                    if s.parse::<i64>().is_ok() {
-                        s.clone()
-                    } else {
-                        format!("0x{}", hex::encode(s.as_bytes()))
-                    }
-                }
-                TealTemplateValue::Bytes(b) => format!("0x{}", hex::encode(b)),
-            };
-
+            let token = Self::template_token(template_variable_name);
+            let value = template_value.to_teal_literal()?;
             program_lines = Self::replace_template_variable(&program_lines, &token, &value);
         }
 
         Ok(program_lines.join("\n"))
     }
 
+    /// Replace the template variables like [`Self::replace_template_variables`], failing unless
+    /// every `TMPL_` variable of the program is substituted and every template value has a
+    /// variable in the program.
+    pub fn replace_template_variables_strict(
+        program: &str,
+        template_values: &TealTemplateParams,
+    ) -> Result<String, AppManagerError> {
+        let variables = Self::find_template_variables(program);
+        let mut unused: Vec<String> = template_values
+            .keys()
+            .map(|name| Self::template_token(name))
+            .filter(|token| !variables.contains(token))
+            .collect();
+        unused.sort();
+
+        let program = Self::replace_template_variables(program, template_values)?;
+        let leftover = Self::find_template_variables(&program);
+
+        if leftover.is_empty() && unused.is_empty() {
+            return Ok(program);
+        }
+        let mut problems = Vec::new();
+        if !leftover.is_empty() {
+            problems.push(format!("no value for {}", leftover.join(", ")));
+        }
+        if !unused.is_empty() {
+            problems.push(format!("no variable for {}", unused.join(", ")));
+        }
+        Err(AppManagerError::TemplateVariablesMismatch {
+            message: problems.join("; "),
+        })
+    }
+
+    /// The distinct `TMPL_` variables in the code of a program, outside of comments and string
+    /// literals, in order of first appearance.
+    pub fn find_template_variables(program: &str) -> Vec<String> {
+        let mut variables: Vec<String> = Vec::new();
+        for line in program.lines() {
+            let code = &line[..Self::find_unquoted_string(line, "//").unwrap_or(line.len())];
+            let mut index = 0;
+            while let Some(offset) = Self::find_unquoted_string(&code[index..], "TMPL_") {
+                let start = index + offset;
+                let end = code[start..]
+                    .find(|ch| !Self::is_valid_token_character(ch))
+                    .map_or(code.len(), |length| start + length);
+                let valid_start = code[..start]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|ch| !Self::is_valid_token_character(ch));
+                let variable = &code[start..end];
+                if valid_start && !variables.iter().any(|existing| existing == variable) {
+                    variables.push(variable.to_string());
+                }
+                index = end;
+            }
+        }
+        variables
+    }
+
+    fn template_token(template_variable_name: &str) -> String {
+        if template_variable_name.starts_with("TMPL_") {
+            template_variable_name.to_string()
+        } else {
+            format!("TMPL_{}", template_variable_name)
+        }
+    }
+
     /// Replace template variable with proper boundary checking.
     fn replace_template_variable(
         program_lines: &[String],
@@ -702,6 +1188,36 @@ impl AppManager {
     }
 }
 
+impl StateDiffValue {
+    fn new(raw: &AppState, entry: Option<&StateEntry>) -> Result<Self, AppManagerError> {
+        let value = match raw {
+            AppState::Uint(uint) => RawStateValue::Uint(uint.value),
+            AppState::Bytes(bytes) => RawStateValue::Bytes(&bytes.value_raw),
+        };
+        // Values of undeclared keys are left undecoded, as their raw value says it all
+        let decoded = entry
+            .map(|entry| StateLayout::decode_value(Some(entry), value))
+            .transpose()
+            .map_err(|e| AppManagerError::ABIDecodeError {
+                message: e.to_string(),
+            })?;
+        Ok(Self {
+            raw: raw.clone(),
+            decoded,
+        })
+    }
+}
+
+impl StateDiffKey {
+    fn new(key_raw: &[u8], entry: Option<&StateEntry>) -> Self {
+        Self {
+            key_raw: key_raw.to_vec(),
+            name: entry.map(|entry| entry.name.to_string()),
+            map_key: entry.and_then(|entry| entry.map_key.clone()),
+        }
+    }
+}
+
 /// Errors that can occur during app manager operations.
 #[derive(Debug, Snafu)]
 pub enum AppManagerError {
@@ -711,6 +1227,12 @@ pub enum AppManagerError {
     #[snafu(display("Template variable not found: {message}"))]
     TemplateVariableNotFound { message: String },
 
+    #[snafu(display("Invalid template value: {message}"))]
+    InvalidTemplateValue { message: String },
+
+    #[snafu(display("Template variables don't match the template values: {message}"))]
+    TemplateVariablesMismatch { message: String },
+
     #[snafu(display("Decoding error: {message}"))]
     DecodingError { message: String },
 
@@ -719,4 +1241,7 @@ pub enum AppManagerError {
 
     #[snafu(display("ABI decode error: {message}"))]
     ABIDecodeError { message: String },
+
+    #[snafu(display("Value was read at round {actual} rather than the expected round {expected}"))]
+    RoundMismatch { expected: u64, actual: u64 },
 }
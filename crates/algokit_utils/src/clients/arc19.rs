@@ -0,0 +1,315 @@
+//! [ARC-19](https://arc.algorand.foundation/ARCs/arc-0019) content identifiers and URLs.
+//!
+//! An ARC-19 asset stores the sha2-256 digest of an IPFS content identifier (CID) as its reserve
+//! address, and has a `template-ipfs://{ipfscid:<version>:<codec>:reserve:sha2-256}` URL from
+//! which the CID is rebuilt. The metadata is updated by changing the reserve address.
+//!
+//! To create an ARC-19 asset, parse the CID of its metadata and use the derived URL and reserve:
+//!
+//! ```
+//! use algokit_utils::clients::arc19::Arc19Cid;
+//!
+//! let cid: Arc19Cid = "QmQZyq4b89RfaUw8GESPd2re4hJqB8bnm4kVHNtyQrHnnK".parse().unwrap();
+//! assert_eq!(cid.template_url(""), "template-ipfs://{ipfscid:0:dag-pb:reserve:sha2-256}");
+//! assert_eq!(
+//!     cid.reserve_address().to_string(),
+//!     "EEQYWGGBHRDAMTEVDPVOSDVX3HJQIG6K6IVNR3RXHYOHV64ZWAEISS4CTI"
+//! );
+//! ```
+
+use algokit_transact::Address;
+use num_bigint::BigUint;
+use snafu::Snafu;
+use std::fmt;
+use std::str::FromStr;
+
+pub const ARC19_TEMPLATE_PREFIX: &str = "template-ipfs://";
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const SHA2_256_MULTIHASH_PREFIX: [u8; 2] = [0x12, 0x20];
+
+#[derive(Debug, Snafu)]
+pub enum Arc19Error {
+    #[snafu(display("Invalid ARC-19 CID: {message}"))]
+    InvalidCid { message: String },
+    #[snafu(display("Invalid ARC-19 template URL: {message}"))]
+    InvalidTemplate { message: String },
+}
+
+/// The multicodec of the content an ARC-19 CID identifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arc19Codec {
+    Raw,
+    DagPb,
+}
+
+impl Arc19Codec {
+    fn code(self) -> u8 {
+        match self {
+            Arc19Codec::Raw => 0x55,
+            Arc19Codec::DagPb => 0x70,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Arc19Codec::Raw => "raw",
+            Arc19Codec::DagPb => "dag-pb",
+        }
+    }
+}
+
+/// An IPFS CID with a sha2-256 digest, which can be stored in the reserve address of an asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arc19Cid {
+    /// The CID version, 0 or 1. Version 0 CIDs are always dag-pb.
+    pub version: u8,
+    pub codec: Arc19Codec,
+    /// The sha2-256 digest of the content
+    pub digest: [u8; 32],
+}
+
+impl Arc19Cid {
+    /// The CID stored in a reserve address.
+    pub fn from_reserve_address(
+        reserve: &Address,
+        version: u8,
+        codec: Arc19Codec,
+    ) -> Result<Self, Arc19Error> {
+        match (version, codec) {
+            (0, Arc19Codec::DagPb) | (1, _) => Ok(Self {
+                version,
+                codec,
+                digest: *reserve.as_bytes(),
+            }),
+            _ => Err(Arc19Error::InvalidCid {
+                message: format!(
+                    "Version {} CIDs can't have the {} codec",
+                    version,
+                    codec.name()
+                ),
+            }),
+        }
+    }
+
+    /// The reserve address that stores this CID.
+    pub fn reserve_address(&self) -> Address {
+        Address(self.digest)
+    }
+
+    /// The ARC-19 template URL of this CID followed by `path`, e.g. `/metadata.json#arc3`.
+    pub fn template_url(&self, path: &str) -> String {
+        format!(
+            "{}{{ipfscid:{}:{}:reserve:sha2-256}}{}",
+            ARC19_TEMPLATE_PREFIX,
+            self.version,
+            self.codec.name(),
+            path
+        )
+    }
+
+    fn multihash(&self) -> Vec<u8> {
+        let mut multihash = SHA2_256_MULTIHASH_PREFIX.to_vec();
+        multihash.extend_from_slice(&self.digest);
+        multihash
+    }
+}
+
+impl fmt::Display for Arc19Cid {
+    /// The CID in its canonical encoding: base58btc for version 0, base32 for version 1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.version == 0 {
+            return write!(f, "{}", base58_encode(&self.multihash()));
+        }
+        let mut bytes = vec![0x01, self.codec.code()];
+        bytes.extend(self.multihash());
+        write!(
+            f,
+            "b{}",
+            base32::encode(base32::Alphabet::Rfc4648Lower { padding: false }, &bytes)
+        )
+    }
+}
+
+impl FromStr for Arc19Cid {
+    type Err = Arc19Error;
+
+    /// Parse a base58btc version 0 CID (`Qm...`) or a base32 version 1 CID (`b...`).
+    fn from_str(cid: &str) -> Result<Self, Self::Err> {
+        let invalid = |message: &str| Arc19Error::InvalidCid {
+            message: format!("{}: {}", message, cid),
+        };
+
+        let (version, codec, multihash) = if cid.starts_with("Qm") {
+            let bytes = base58_decode(cid).ok_or_else(|| invalid("Invalid base58"))?;
+            (0, Arc19Codec::DagPb, bytes)
+        } else if let Some(encoded) = cid.strip_prefix('b') {
+            let bytes = base32::decode(base32::Alphabet::Rfc4648Lower { padding: false }, encoded)
+                .ok_or_else(|| invalid("Invalid base32"))?;
+            let codec = match bytes.get(..2) {
+                Some([0x01, 0x55]) => Arc19Codec::Raw,
+                Some([0x01, 0x70]) => Arc19Codec::DagPb,
+                _ => return Err(invalid("Only version 1 raw and dag-pb CIDs are supported")),
+            };
+            (1, codec, bytes[2..].to_vec())
+        } else {
+            return Err(invalid("Unsupported CID encoding"));
+        };
+
+        let digest = multihash
+            .strip_prefix(&SHA2_256_MULTIHASH_PREFIX)
+            .and_then(|digest| <[u8; 32]>::try_from(digest).ok())
+            .ok_or_else(|| invalid("Only sha2-256 CIDs can be stored in a reserve address"))?;
+
+        Ok(Self {
+            version,
+            codec,
+            digest,
+        })
+    }
+}
+
+/// Whether the URL is an ARC-19 template.
+pub fn is_arc19_url(url: &str) -> bool {
+    url.starts_with(ARC19_TEMPLATE_PREFIX)
+}
+
+/// Parse the CID of an ARC-19 template URL from the asset's reserve address, returning it with
+/// the path that follows the template.
+pub fn parse_arc19_url<'a>(
+    url: &'a str,
+    reserve: &Address,
+) -> Result<(Arc19Cid, &'a str), Arc19Error> {
+    let invalid = |message: String| Arc19Error::InvalidTemplate { message };
+
+    let (placeholder, path) = url
+        .strip_prefix(ARC19_TEMPLATE_PREFIX)
+        .and_then(|template| template.strip_prefix('{'))
+        .and_then(|template| template.split_once('}'))
+        .ok_or_else(|| invalid(format!("{} has no ARC-19 placeholder", url)))?;
+
+    let parts: Vec<&str> = placeholder.split(':').collect();
+    let ["ipfscid", version, codec, "reserve", "sha2-256"] = parts[..] else {
+        return Err(invalid(format!(
+            "Unsupported placeholder {{{}}}, expected {{ipfscid:<version>:<codec>:reserve:sha2-256}}",
+            placeholder
+        )));
+    };
+    let version = version
+        .parse::<u8>()
+        .map_err(|_| invalid(format!("Invalid CID version {}", version)))?;
+    let codec = match codec {
+        "raw" => Arc19Codec::Raw,
+        "dag-pb" => Arc19Codec::DagPb,
+        _ => return Err(invalid(format!("Unsupported multicodec {}", codec))),
+    };
+
+    Ok((
+        Arc19Cid::from_reserve_address(reserve, version, codec)?,
+        path,
+    ))
+}
+
+/// Resolve an ARC-19 template URL to the `ipfs://` URL of the CID stored in the reserve address.
+pub fn resolve_arc19_url(url: &str, reserve: &Address) -> Result<String, Arc19Error> {
+    let (cid, path) = parse_arc19_url(url, reserve)?;
+    Ok(format!("ipfs://{}{}", cid, path))
+}
+
+fn base58_encode(bytes: &[u8]) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let digits = BigUint::from_bytes_be(bytes).to_radix_be(58);
+    let digits = digits.iter().skip_while(|&&digit| digit == 0);
+    std::iter::repeat_n('1', leading_zeros)
+        .chain(digits.map(|&digit| BASE58_ALPHABET[digit as usize] as char))
+        .collect()
+}
+
+fn base58_decode(encoded: &str) -> Option<Vec<u8>> {
+    let digits = encoded
+        .bytes()
+        .map(|char| {
+            BASE58_ALPHABET
+                .iter()
+                .position(|&digit| digit == char)
+                .map(|digit| digit as u8)
+        })
+        .collect::<Option<Vec<u8>>>()?;
+    let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    let mut bytes = vec![0; leading_zeros];
+    if leading_zeros < digits.len() {
+        bytes.extend(BigUint::from_radix_be(&digits, 58)?.to_bytes_be());
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of ARC-19
+    const RESERVE: &str = "EEQYWGGBHRDAMTEVDPVOSDVX3HJQIG6K6IVNR3RXHYOHV64ZWAEISS4CTI";
+    const CID_V0: &str = "QmQZyq4b89RfaUw8GESPd2re4hJqB8bnm4kVHNtyQrHnnK";
+    const CID_V1_RAW: &str = "bafkreibbegfrrqj4iydezfi35luq5n6z2mcbxsxsflmo4nz6dr5pxgnqba";
+
+    #[test]
+    fn test_cid_round_trip() {
+        let reserve: Address = RESERVE.parse().unwrap();
+
+        let cid: Arc19Cid = CID_V0.parse().unwrap();
+        assert_eq!(cid.reserve_address(), reserve);
+        assert_eq!(cid.to_string(), CID_V0);
+        assert_eq!(
+            Arc19Cid::from_reserve_address(&reserve, 0, Arc19Codec::DagPb).unwrap(),
+            cid
+        );
+
+        let cid: Arc19Cid = CID_V1_RAW.parse().unwrap();
+        assert_eq!((cid.version, cid.codec), (1, Arc19Codec::Raw));
+        assert_eq!(cid.reserve_address(), reserve);
+        assert_eq!(cid.to_string(), CID_V1_RAW);
+
+        assert!(Arc19Cid::from_reserve_address(&reserve, 0, Arc19Codec::Raw).is_err());
+        assert!(
+            "zb2rhe5P4gXftAwvA4eXQ5HJwsER2owDyS9sKaQRRVQPn93bA"
+                .parse::<Arc19Cid>()
+                .is_err()
+        );
+        assert!("Qm0invalid".parse::<Arc19Cid>().is_err());
+    }
+
+    #[test]
+    fn test_template_url_round_trip() {
+        let reserve: Address = RESERVE.parse().unwrap();
+        let cid: Arc19Cid = CID_V0.parse().unwrap();
+
+        let url = cid.template_url("/arc3.json#arc3");
+        assert_eq!(
+            url,
+            "template-ipfs://{ipfscid:0:dag-pb:reserve:sha2-256}/arc3.json#arc3"
+        );
+        assert!(is_arc19_url(&url));
+        assert_eq!(
+            parse_arc19_url(&url, &reserve).unwrap(),
+            (cid, "/arc3.json#arc3")
+        );
+        assert_eq!(
+            resolve_arc19_url(&url, &reserve).unwrap(),
+            format!("ipfs://{}/arc3.json#arc3", CID_V0)
+        );
+        assert_eq!(
+            resolve_arc19_url("template-ipfs://{ipfscid:1:raw:reserve:sha2-256}", &reserve)
+                .unwrap(),
+            format!("ipfs://{}", CID_V1_RAW)
+        );
+
+        for url in [
+            "template-ipfs://{ipfscid:0:raw:reserve:sha2-256}",
+            "template-ipfs://{ipfscid:1:raw:manager:sha2-256}",
+            "template-ipfs://{ipfs:1:raw:reserve:sha2-256}",
+            "ipfs://bafkrei",
+        ] {
+            assert!(resolve_arc19_url(url, &reserve).is_err(), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! [ARC-26](https://arc.algorand.foundation/ARCs/arc-0026) `algorand://` URIs, e.g. for payment
+//! request QR codes.
+//!
+//! ```
+//! use algokit_utils::clients::arc26::Arc26Uri;
+//!
+//! let uri: Arc26Uri =
+//!     "algorand://TMTAD6N22HCS2LKH7677L2KFLT3PAQWY6M4JFQFXQS32ECBFC23F57RYX4?amount=150500000&asset=45&xnote=Invoice%20%2342"
+//!         .parse()
+//!         .unwrap();
+//! assert_eq!(uri.amount, Some(150_500_000));
+//! assert_eq!(uri.asset_id, Some(45));
+//! assert_eq!(uri.xnote.as_deref(), Some("Invoice #42"));
+//! ```
+
+use crate::transactions::{AssetTransferParams, ComposerTransaction, PaymentParams};
+use crate::{AlgoAmount, AssetAmount};
+use algokit_transact::Address;
+use snafu::Snafu;
+use std::fmt;
+use std::str::FromStr;
+
+pub const ARC26_SCHEME: &str = "algorand://";
+
+#[derive(Debug, Snafu)]
+pub enum Arc26Error {
+    #[snafu(display("Invalid ARC-26 URI: {message}"))]
+    InvalidUri { message: String },
+}
+
+/// An ARC-26 URI requesting a payment or asset transfer to an address, or just sharing it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Arc26Uri {
+    pub receiver: Address,
+    /// The amount in microAlgos, or in base units of the asset when `asset_id` is set
+    pub amount: Option<u64>,
+    /// The asset to transfer instead of Algo
+    pub asset_id: Option<u64>,
+    /// A label for the receiver, e.g. the name of the merchant
+    pub label: Option<String>,
+    /// A note the sender may edit before sending
+    pub note: Option<String>,
+    /// A note the sender must not edit, e.g. an invoice reference
+    pub xnote: Option<String>,
+}
+
+impl Arc26Uri {
+    /// A URI sharing the address only.
+    pub fn new(receiver: Address) -> Self {
+        Self {
+            receiver,
+            ..Default::default()
+        }
+    }
+
+    /// The payment or asset transfer this URI requests from `sender`, with the fixed note
+    /// preferred over the editable one.
+    ///
+    /// A URI without an amount requests a zero amount, e.g. an asset opt-in when the receiver is
+    /// the sender.
+    pub fn to_transaction(&self, sender: Address) -> ComposerTransaction {
+        let note = self
+            .xnote
+            .as_ref()
+            .or(self.note.as_ref())
+            .map(|note| note.as_bytes().to_vec());
+        let amount = self.amount.unwrap_or_default();
+        match self.asset_id {
+            Some(asset_id) => ComposerTransaction::AssetTransfer(AssetTransferParams {
+                sender,
+                note,
+                asset_id,
+                amount: AssetAmount::base_units(amount),
+                receiver: self.receiver.clone(),
+                ..Default::default()
+            }),
+            None => ComposerTransaction::Payment(PaymentParams {
+                sender,
+                note,
+                receiver: self.receiver.clone(),
+                amount: AlgoAmount::micro_algos(amount),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Arc26Error> {
+        if self.note.is_some() && self.xnote.is_some() {
+            return Err(Arc26Error::InvalidUri {
+                message: "A URI can't have both a note and an xnote".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Arc26Uri {
+    /// The URI with its query parameters in a fixed order and values percent-encoded.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", ARC26_SCHEME, self.receiver)?;
+
+        let params = [
+            ("amount", self.amount.map(|amount| amount.to_string())),
+            ("asset", self.asset_id.map(|asset_id| asset_id.to_string())),
+            ("label", self.label.clone()),
+            ("note", self.note.clone()),
+            ("xnote", self.xnote.clone()),
+        ];
+        let mut separator = '?';
+        for (name, value) in params {
+            if let Some(value) = value {
+                write!(f, "{}{}={}", separator, name, percent_encode(&value))?;
+                separator = '&';
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Arc26Uri {
+    type Err = Arc26Error;
+
+    /// Parse a URI, ignoring unknown query parameters.
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid = |message: String| Arc26Error::InvalidUri { message };
+
+        let rest = uri
+            .strip_prefix(ARC26_SCHEME)
+            .ok_or_else(|| invalid(format!("{} doesn't start with {}", uri, ARC26_SCHEME)))?;
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let receiver = address
+            .trim_end_matches('/')
+            .parse::<Address>()
+            .map_err(|e| invalid(format!("Invalid address {}: {}", address, e)))?;
+
+        let mut parsed = Self::new(receiver);
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            let value = percent_decode(value)
+                .ok_or_else(|| invalid(format!("Invalid percent-encoding in {}", param)))?;
+            let parse_u64 = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| invalid(format!("Invalid {} {}", name, value)))
+            };
+            match name {
+                "amount" => parsed.amount = Some(parse_u64(&value)?),
+                "asset" => parsed.asset_id = Some(parse_u64(&value)?),
+                "label" => parsed.label = Some(value),
+                "note" => parsed.note = Some(value),
+                "xnote" => parsed.xnote = Some(value),
+                _ => {}
+            }
+        }
+
+        parsed.validate()?;
+        Ok(parsed)
+    }
+}
+
+/// Percent-encode everything but the unreserved characters of RFC 3986.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(index + 1..index + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::AccountMother;
+
+    #[test]
+    fn test_uri_round_trip() {
+        let receiver = AccountMother::account().address();
+        let uri = Arc26Uri {
+            amount: Some(1_000_000),
+            label: Some("Café & Co".to_string()),
+            xnote: Some("Order 42/7".to_string()),
+            ..Arc26Uri::new(receiver.clone())
+        };
+
+        let encoded = uri.to_string();
+        assert_eq!(
+            encoded,
+            format!(
+                "algorand://{}?amount=1000000&label=Caf%C3%A9%20%26%20Co&xnote=Order%2042%2F7",
+                receiver
+            )
+        );
+        assert_eq!(encoded.parse::<Arc26Uri>().unwrap(), uri);
+        assert_eq!(
+            Arc26Uri::new(receiver.clone()).to_string(),
+            format!("algorand://{}", receiver)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_uris() {
+        let receiver = AccountMother::account().address();
+        for uri in [
+            format!("bitcoin://{}", receiver),
+            "algorand://NOTANADDRESS".to_string(),
+            format!("algorand://{}?amount=-1", receiver),
+            format!("algorand://{}?note=%ZZ", receiver),
+            format!("algorand://{}?note=a&xnote=b", receiver),
+        ] {
+            assert!(uri.parse::<Arc26Uri>().is_err(), "{}", uri);
+        }
+        assert!(
+            format!("algorand://{}?unknown=1", receiver)
+                .parse::<Arc26Uri>()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_to_transaction() {
+        let sender = AccountMother::neil().address();
+        let receiver = AccountMother::account().address();
+        let uri: Arc26Uri = format!("algorand://{}?amount=5&asset=10&note=hi", receiver)
+            .parse()
+            .unwrap();
+
+        let ComposerTransaction::AssetTransfer(params) = uri.to_transaction(sender.clone()) else {
+            panic!("Expected an asset transfer");
+        };
+        assert_eq!(params.asset_id, 10);
+        assert_eq!(params.amount, AssetAmount::base_units(5));
+        assert_eq!(params.receiver, receiver);
+        assert_eq!(params.note, Some(b"hi".to_vec()));
+
+        assert!(matches!(
+            Arc26Uri::new(receiver).to_transaction(sender),
+            ComposerTransaction::Payment(PaymentParams { amount, .. }) if amount == AlgoAmount::micro_algos(0)
+        ));
+    }
+}
@@ -0,0 +1,279 @@
+//! [ARC-3](https://arc.algorand.foundation/ARCs/arc-0003) asset metadata.
+//!
+//! The metadata of an ARC-3 asset is a JSON file its URL points to, which the asset can commit to
+//! with its metadata hash:
+//!
+//! ```
+//! use algokit_utils::clients::arc3::{Arc3Metadata, arc3_integrity};
+//!
+//! let image = b"<image bytes>";
+//! let metadata = Arc3Metadata {
+//!     name: Some("My NFT".to_string()),
+//!     decimals: Some(0),
+//!     image: Some("ipfs://bafkreih5aznjvttude6c3wbvqeebb6rlx5wkbzyppv7garjiubll2ceym4".to_string()),
+//!     image_integrity: Some(arc3_integrity(image)),
+//!     image_mimetype: Some("image/png".to_string()),
+//!     ..Default::default()
+//! };
+//! // Upload `json` and create the asset with the hash as its `metadata_hash`
+//! let json = metadata.to_json().unwrap();
+//! let metadata_hash = metadata.metadata_hash().unwrap();
+//! ```
+
+use super::asset_manager::AssetInformation;
+use base64::{Engine, prelude::BASE64_STANDARD};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512_256};
+
+const INTEGRITY_PREFIX: &str = "sha256-";
+
+/// ARC-3 JSON metadata.
+///
+/// Fields are serialized in declaration order and the keys of `properties` and `localization`
+/// are sorted, so the same metadata always serializes to the same JSON and hash.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Arc3Metadata {
+    /// The name of the asset, which the asset name should be a prefix of
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of decimals, which must match the decimals of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A URI pointing to an image of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// The `sha256-<base64>` integrity of the image, see [`arc3_integrity`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_integrity: Option<String>,
+    /// The MIME type of the image, e.g. `image/png`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_mimetype: Option<String>,
+    /// The background color to display the asset with, as 6 hex digits without `#`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    /// A URI pointing to an external website presenting the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_url_integrity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_url_mimetype: Option<String>,
+    /// A URI pointing to a multimedia file of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_url_integrity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_url_mimetype: Option<String>,
+    /// Arbitrary properties of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
+    /// Base64 encoded extra metadata, which is part of the metadata hash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localization: Option<serde_json::Value>,
+}
+
+impl Arc3Metadata {
+    /// Validate the metadata: every integrity must be a `sha256-<base64>` digest of a URI that
+    /// is set, and the extra metadata must be base64.
+    pub fn validate(&self) -> Result<(), String> {
+        for (field, uri, integrity) in [
+            ("image", &self.image, &self.image_integrity),
+            (
+                "external_url",
+                &self.external_url,
+                &self.external_url_integrity,
+            ),
+            (
+                "animation_url",
+                &self.animation_url,
+                &self.animation_url_integrity,
+            ),
+        ] {
+            let Some(integrity) = integrity else {
+                continue;
+            };
+            if uri.is_none() {
+                return Err(format!("{}_integrity is set without {}", field, field));
+            }
+            let digest = integrity
+                .strip_prefix(INTEGRITY_PREFIX)
+                .and_then(|digest| BASE64_STANDARD.decode(digest).ok());
+            if digest.is_none_or(|digest| digest.len() != 32) {
+                return Err(format!(
+                    "{}_integrity must be a base64 sha256 digest prefixed with {}, got {}",
+                    field, INTEGRITY_PREFIX, integrity
+                ));
+            }
+        }
+
+        if let Some(mimetype) = &self.image_mimetype {
+            if !mimetype.starts_with("image/") {
+                return Err(format!(
+                    "image_mimetype must be an image type, got {}",
+                    mimetype
+                ));
+            }
+        }
+
+        self.extra_metadata_bytes()?;
+        Ok(())
+    }
+
+    /// The decoded extra metadata.
+    pub fn extra_metadata_bytes(&self) -> Result<Option<Vec<u8>>, String> {
+        self.extra_metadata
+            .as_deref()
+            .map(|extra_metadata| {
+                BASE64_STANDARD
+                    .decode(extra_metadata)
+                    .map_err(|e| format!("extra_metadata must be base64: {}", e))
+            })
+            .transpose()
+    }
+
+    /// Validate and serialize the metadata to the JSON to upload.
+    pub fn to_json(&self) -> Result<Vec<u8>, String> {
+        self.validate()?;
+        serde_json::to_vec(self).map_err(|e| format!("Failed to serialize ARC-3 metadata: {}", e))
+    }
+
+    /// The metadata hash of the serialized metadata, for the `metadata_hash` of the asset.
+    pub fn metadata_hash(&self) -> Result<[u8; 32], String> {
+        let json = self.to_json()?;
+        Ok(arc3_metadata_hash(
+            &json,
+            self.extra_metadata_bytes()?.as_deref(),
+        ))
+    }
+}
+
+/// The ARC-3 metadata hash of a JSON metadata file.
+///
+/// Without extra metadata this is the SHA-256 of the JSON, otherwise it is
+/// `SHA-512/256("arc0003/am" || SHA-512/256("arc0003/amj" || json) || extra_metadata)`.
+pub fn arc3_metadata_hash(json: &[u8], extra_metadata: Option<&[u8]>) -> [u8; 32] {
+    match extra_metadata {
+        None => Sha256::digest(json).into(),
+        Some(extra_metadata) => {
+            let json_hash = Sha512_256::new()
+                .chain_update(b"arc0003/amj")
+                .chain_update(json)
+                .finalize();
+            Sha512_256::new()
+                .chain_update(b"arc0003/am")
+                .chain_update(json_hash)
+                .chain_update(extra_metadata)
+                .finalize()
+                .into()
+        }
+    }
+}
+
+/// The `sha256-<base64>` integrity of a file referenced by ARC-3 metadata.
+pub fn arc3_integrity(content: &[u8]) -> String {
+    format!(
+        "{}{}",
+        INTEGRITY_PREFIX,
+        BASE64_STANDARD.encode(Sha256::digest(content))
+    )
+}
+
+/// Whether the asset follows ARC-3, i.e. its URL ends with `#arc3` or its name is `arc3` or
+/// ends with `@arc3`.
+pub fn is_arc3(asset: &AssetInformation) -> bool {
+    asset
+        .url
+        .as_deref()
+        .is_some_and(|url| url.ends_with("#arc3"))
+        || asset
+            .asset_name
+            .as_deref()
+            .is_some_and(|name| name == "arc3" || name.ends_with("@arc3"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> Arc3Metadata {
+        Arc3Metadata {
+            name: Some("Test".to_string()),
+            decimals: Some(0),
+            image: Some("ipfs://image".to_string()),
+            image_integrity: Some(arc3_integrity(b"image")),
+            image_mimetype: Some("image/png".to_string()),
+            properties: Some(serde_json::json!({"b": 2, "a": 1})),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_to_json_is_deterministic() {
+        let json = metadata().to_json().unwrap();
+        assert_eq!(
+            String::from_utf8(json.clone()).unwrap(),
+            format!(
+                r#"{{"name":"Test","decimals":0,"image":"ipfs://image","image_integrity":"{}","image_mimetype":"image/png","properties":{{"a":1,"b":2}}}}"#,
+                arc3_integrity(b"image")
+            )
+        );
+        assert_eq!(
+            metadata().metadata_hash().unwrap(),
+            <[u8; 32]>::from(Sha256::digest(&json))
+        );
+        assert_eq!(
+            serde_json::from_slice::<Arc3Metadata>(&json).unwrap(),
+            metadata()
+        );
+    }
+
+    #[test]
+    fn test_metadata_hash_with_extra_metadata() {
+        let metadata = Arc3Metadata {
+            extra_metadata: Some(BASE64_STANDARD.encode(b"extra")),
+            ..metadata()
+        };
+        let json = metadata.to_json().unwrap();
+        let hash = metadata.metadata_hash().unwrap();
+
+        assert_eq!(hash, arc3_metadata_hash(&json, Some(b"extra")));
+        assert_ne!(hash, arc3_metadata_hash(&json, None));
+        assert_ne!(hash, arc3_metadata_hash(&json, Some(b"other")));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(metadata().validate().is_ok());
+
+        let invalid = [
+            Arc3Metadata {
+                image: None,
+                ..metadata()
+            },
+            Arc3Metadata {
+                image_integrity: Some("md5-abc".to_string()),
+                ..metadata()
+            },
+            Arc3Metadata {
+                image_integrity: Some(format!("sha256-{}", BASE64_STANDARD.encode(b"short"))),
+                ..metadata()
+            },
+            Arc3Metadata {
+                image_mimetype: Some("video/mp4".to_string()),
+                ..metadata()
+            },
+            Arc3Metadata {
+                extra_metadata: Some("not base64!".to_string()),
+                ..metadata()
+            },
+        ];
+        for metadata in invalid {
+            assert!(metadata.to_json().is_err(), "{:?}", metadata);
+        }
+    }
+}
@@ -1,13 +1,26 @@
 use algod_client::apis::{AlgodClient, Error as AlgodError};
 use algod_client::models::{AccountAssetInformation as AlgodAccountAssetInformation, Asset};
+use algokit_http_client::{DefaultHttpClient, HttpClient, HttpMethod, HttpResponse};
 use algokit_transact::Address;
+use indexer_client::apis::parameter_enums::TxType;
+use indexer_client::{IndexerClient, apis::Error as IndexerError};
 use snafu::Snafu;
+use std::collections::HashMap;
+use std::sync::Mutex;
 use std::{str::FromStr, sync::Arc};
 
+use super::arc3::is_arc3;
+use super::arc19::is_arc19_url;
+use super::asset_metadata::{
+    AssetMetadata, DEFAULT_IPFS_GATEWAY, gateway_url, parse_arc3_metadata, parse_arc69_metadata,
+    resolve_asset_url,
+};
+use crate::config::{ProgressOperation, ProgressReporter};
 use crate::transactions::{
     AssetOptInParams, AssetOptOutParams, ComposerError, TransactionComposer,
     TransactionComposerConfig,
 };
+use crate::{AmountError, AssetAmount};
 
 #[derive(Debug, Clone)]
 pub struct BulkAssetOptInOutResult {
@@ -144,6 +157,37 @@ impl From<Asset> for AssetInformation {
     }
 }
 
+/// An account holding an asset, as captured in an [`AssetHoldersSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetHolder {
+    /// The address of the holding account.
+    pub address: String,
+    /// The amount of the asset held, in the smallest divisible (decimal) units.
+    pub amount: u64,
+}
+
+/// A point in time view of the accounts holding an asset.
+#[derive(Debug, Clone)]
+pub struct AssetHoldersSnapshot {
+    /// The ID of the asset.
+    pub asset_id: u64,
+    /// The round at which indexer computed the first page of balances.
+    pub round: u64,
+    /// The accounts holding the asset, in the order returned by indexer.
+    pub holders: Vec<AssetHolder>,
+}
+
+/// Options for filtering the accounts included in an [`AssetHoldersSnapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct AssetHoldersSnapshotOptions {
+    /// Exclude the asset creator account from the snapshot.
+    pub exclude_creator: bool,
+    /// Exclude the asset reserve account (if set) from the snapshot.
+    ///
+    /// The reserve account typically holds the uncirculated supply of the asset.
+    pub exclude_reserve: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct AssetValidationError {
     pub asset_id: u64,
@@ -154,17 +198,51 @@ pub struct AssetValidationError {
 #[derive(Clone)]
 pub struct AssetManager {
     algod_client: Arc<AlgodClient>,
+    indexer_client: Option<Arc<IndexerClient>>,
     new_composer: Arc<dyn Fn(Option<TransactionComposerConfig>) -> TransactionComposer>,
+    metadata_http_client: Arc<dyn HttpClient>,
+    ipfs_gateway: String,
+    decimals: Arc<Mutex<HashMap<u64, u8>>>,
 }
 
 impl AssetManager {
+    /// Creates a new `AssetManager`.
+    ///
+    /// # Arguments
+    /// * `algod_client` - The `AlgodClient` used for asset lookups
+    /// * `indexer_client` - An optional `IndexerClient`, required for holder snapshots
+    /// * `new_composer` - A factory for the composers used to send asset transactions
     pub fn new(
         algod_client: Arc<AlgodClient>,
+        indexer_client: Option<Arc<IndexerClient>>,
         new_composer: impl Fn(Option<TransactionComposerConfig>) -> TransactionComposer + 'static,
     ) -> Self {
         Self {
             algod_client,
+            indexer_client,
             new_composer: Arc::new(new_composer),
+            metadata_http_client: Arc::new(DefaultHttpClient::new("")),
+            ipfs_gateway: DEFAULT_IPFS_GATEWAY.to_string(),
+            decimals: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Create an asset manager that fetches off-chain metadata with the given HTTP client.
+    ///
+    /// The client is sent absolute URLs as the request path.
+    pub fn with_metadata_http_client(&self, metadata_http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            metadata_http_client,
+            ..self.clone()
+        }
+    }
+
+    /// Create an asset manager that fetches `ipfs://` metadata through the given gateway,
+    /// defaults to [`DEFAULT_IPFS_GATEWAY`].
+    pub fn with_ipfs_gateway(&self, ipfs_gateway: &str) -> Self {
+        Self {
+            ipfs_gateway: ipfs_gateway.to_string(),
+            ..self.clone()
         }
     }
 
@@ -180,6 +258,129 @@ impl AssetManager {
         Ok(asset.into())
     }
 
+    /// Get the number of decimals of an asset.
+    ///
+    /// The decimals of an asset can't be changed, so with `use_cache` they are only looked up
+    /// the first time.
+    pub async fn get_decimals(
+        &self,
+        asset_id: u64,
+        use_cache: bool,
+    ) -> Result<u8, AssetManagerError> {
+        if use_cache {
+            if let Some(decimals) = self.decimals.lock().unwrap().get(&asset_id) {
+                return Ok(*decimals);
+            }
+        }
+
+        let decimals = self.get_by_id(asset_id).await?.decimals as u8;
+        self.decimals.lock().unwrap().insert(asset_id, decimals);
+        Ok(decimals)
+    }
+
+    /// Convert an amount of whole units of an asset, e.g. `"1.5"`, to base units, failing when
+    /// it has more fractional digits than the asset has decimals.
+    pub async fn to_base_units(
+        &self,
+        asset_id: u64,
+        amount: &str,
+        use_cache: bool,
+    ) -> Result<AssetAmount, AssetManagerError> {
+        let decimals = self.get_decimals(asset_id, use_cache).await?;
+        AssetAmount::parse(amount, decimals)
+            .map_err(|source| AssetManagerError::InvalidAmount { asset_id, source })
+    }
+
+    /// Get the metadata of an asset following ARC-3, ARC-19 and/or ARC-69.
+    ///
+    /// ARC-19 URLs are resolved from the reserve address, ARC-3 metadata is fetched from the
+    /// resolved URL and validated against the asset, and ARC-69 metadata is parsed from the note
+    /// of the latest asset config transaction. ARC-69 metadata is only looked up when an indexer
+    /// client is configured.
+    pub async fn get_asset_metadata(
+        &self,
+        asset_id: u64,
+    ) -> Result<AssetMetadata, AssetManagerError> {
+        let asset = self.get_by_id(asset_id).await?;
+        let invalid_metadata =
+            |message: String| AssetManagerError::InvalidMetadata { asset_id, message };
+
+        let url = resolve_asset_url(&asset).map_err(invalid_metadata)?;
+        let is_arc19 = asset.url.as_deref().is_some_and(is_arc19_url);
+
+        let arc3 = match &url {
+            Some(url) if is_arc3(&asset) => {
+                let json = self.fetch_metadata(url).await?;
+                Some(parse_arc3_metadata(&json, &asset).map_err(invalid_metadata)?)
+            }
+            _ => None,
+        };
+
+        let arc69 = match &self.indexer_client {
+            Some(indexer) => {
+                let mut latest_note = None;
+                let mut next_token: Option<String> = None;
+                loop {
+                    let response = indexer
+                        .lookup_asset_transactions(
+                            None,
+                            next_token.as_deref(),
+                            None,
+                            Some(TxType::Acfg),
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            asset_id,
+                            None,
+                        )
+                        .await
+                        .map_err(|e| AssetManagerError::IndexerClientError { source: e })?;
+
+                    // Transactions are returned oldest first
+                    if let Some(transaction) = response.transactions.into_iter().last() {
+                        latest_note = Some(transaction.note.unwrap_or_default());
+                    }
+                    match response.next_token {
+                        Some(token) if !token.is_empty() => next_token = Some(token),
+                        _ => break,
+                    }
+                }
+                latest_note.and_then(|note| parse_arc69_metadata(&note))
+            }
+            None => None,
+        };
+
+        Ok(AssetMetadata {
+            asset_id,
+            url,
+            is_arc19,
+            arc3,
+            arc69,
+        })
+    }
+
+    async fn fetch_metadata(&self, url: &str) -> Result<Vec<u8>, AssetManagerError> {
+        let url = gateway_url(url.trim_end_matches("#arc3"), &self.ipfs_gateway);
+        self.metadata_http_client
+            .request(HttpMethod::Get, url.clone(), None, None, None, None)
+            .await
+            .and_then(HttpResponse::error_for_status)
+            .map(|response| response.body)
+            .map_err(|e| AssetManagerError::MetadataFetchError {
+                url,
+                message: e.to_string(),
+            })
+    }
+
     /// Get account's asset information.
     /// Returns the raw algod AccountAssetInformation type.
     /// Access asset holding via `account_info.asset_holding` and asset params via `account_info.asset_params`.
@@ -194,6 +395,85 @@ impl AssetManager {
             .map_err(|e| AssetManagerError::AlgodClientError { source: e })
     }
 
+    /// Get a snapshot of the accounts holding at least `min_balance` of an asset.
+    ///
+    /// Balances are retrieved from indexer and all pages of results are collected.
+    /// Accounts that have closed out of the asset are not included.
+    /// Useful for airdrops and governance snapshots.
+    ///
+    /// # Arguments
+    /// * `asset_id` - The ID of the asset
+    /// * `min_balance` - The minimum (inclusive) amount an account must hold to be included
+    /// * `options` - Optional filters for excluding the creator and/or reserve accounts
+    pub async fn get_holders_snapshot(
+        &self,
+        asset_id: u64,
+        min_balance: u64,
+        options: Option<AssetHoldersSnapshotOptions>,
+    ) -> Result<AssetHoldersSnapshot, AssetManagerError> {
+        let indexer = self
+            .indexer_client
+            .as_ref()
+            .ok_or(AssetManagerError::IndexerClientNotConfigured)?;
+        let options = options.unwrap_or_default();
+
+        let mut excluded_addresses = Vec::new();
+        if options.exclude_creator || options.exclude_reserve {
+            let asset_info = self.get_by_id(asset_id).await?;
+            if options.exclude_creator {
+                excluded_addresses.push(asset_info.creator);
+            }
+            if options.exclude_reserve {
+                excluded_addresses.extend(asset_info.reserve);
+            }
+        }
+
+        // Indexer filters on a strictly greater than basis
+        let currency_greater_than = min_balance.checked_sub(1);
+
+        let mut progress = ProgressReporter::new(ProgressOperation::AssetHoldersSnapshot, None);
+        let mut snapshot_round = None;
+        let mut holders = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = indexer
+                .lookup_asset_balances(
+                    Some(false),
+                    None,
+                    next_token.as_deref(),
+                    currency_greater_than,
+                    None,
+                    asset_id,
+                )
+                .await
+                .map_err(|e| AssetManagerError::IndexerClientError { source: e })?;
+
+            snapshot_round.get_or_insert(response.current_round);
+            progress.advance(response.balances.len() as u64).await;
+            holders.extend(
+                response
+                    .balances
+                    .into_iter()
+                    .filter(|holding| !excluded_addresses.contains(&holding.address))
+                    .map(|holding| AssetHolder {
+                        address: holding.address,
+                        amount: holding.amount,
+                    }),
+            );
+
+            match response.next_token {
+                Some(token) if !token.is_empty() => next_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(AssetHoldersSnapshot {
+            asset_id,
+            round: snapshot_round.unwrap_or_default(),
+            holders,
+        })
+    }
+
     pub async fn bulk_opt_in(
         &self,
         account: &Address,
@@ -203,6 +483,12 @@ impl AssetManager {
             return Ok(Vec::new());
         }
 
+        let mut progress = ProgressReporter::new(
+            ProgressOperation::BulkAssetOptIn,
+            Some(asset_ids.len() as u64),
+        );
+        progress.report().await;
+
         let mut composer = (self.new_composer)(None);
 
         // Add asset opt-in transactions for each asset
@@ -223,6 +509,7 @@ impl AssetManager {
             .send(Default::default())
             .await
             .map_err(|e| AssetManagerError::ComposerError { source: e })?;
+        progress.advance(composer_result.results.len() as u64).await;
 
         // Map transaction IDs back to assets
         let bulk_results: Vec<BulkAssetOptInOutResult> = asset_ids
@@ -247,6 +534,12 @@ impl AssetManager {
             return Ok(Vec::new());
         }
 
+        let mut progress = ProgressReporter::new(
+            ProgressOperation::BulkAssetOptOut,
+            Some(asset_ids.len() as u64),
+        );
+        progress.report().await;
+
         let should_check_balance = ensure_zero_balance.unwrap_or(false);
 
         // If we need to check balances, verify they are all zero
@@ -298,6 +591,7 @@ impl AssetManager {
             .send(Default::default())
             .await
             .map_err(|e| AssetManagerError::ComposerError { source: e })?;
+        progress.advance(composer_result.results.len() as u64).await;
 
         // Map transaction IDs back to assets
         let bulk_results: Vec<BulkAssetOptInOutResult> = asset_ids
@@ -318,6 +612,12 @@ pub enum AssetManagerError {
     #[snafu(display("Algod client error: {source}"))]
     AlgodClientError { source: AlgodError },
 
+    #[snafu(display("Indexer client error: {source}"))]
+    IndexerClientError { source: IndexerError },
+
+    #[snafu(display("An indexer client is required for this operation, but none was configured"))]
+    IndexerClientNotConfigured,
+
     #[snafu(display("Composer error: {source}"))]
     ComposerError { source: ComposerError },
 
@@ -340,6 +640,15 @@ pub enum AssetManagerError {
     #[snafu(display("Asset {asset_id} is frozen for account {address}"))]
     AssetFrozen { address: String, asset_id: u64 },
 
+    #[snafu(display("Invalid amount of asset {asset_id}: {source}"))]
+    InvalidAmount { asset_id: u64, source: AmountError },
+
+    #[snafu(display("Invalid metadata for asset {asset_id}: {message}"))]
+    InvalidMetadata { asset_id: u64, message: String },
+
+    #[snafu(display("Failed to fetch asset metadata from {url}: {message}"))]
+    MetadataFetchError { url: String, message: String },
+
     #[snafu(display("Method '{method}' not implemented: {reason}"))]
     NotImplemented { method: String, reason: String },
 }
@@ -0,0 +1,231 @@
+//! Parsing of asset metadata following the ARC-3, ARC-19 and ARC-69 conventions.
+//!
+//! - [ARC-3](https://arc.algorand.foundation/ARCs/arc-0003): the asset URL points to a JSON
+//!   metadata file, optionally committed to by the metadata hash of the asset
+//! - [ARC-19](https://arc.algorand.foundation/ARCs/arc-0019): the asset URL is a template of an
+//!   IPFS URL whose content identifier is stored in the reserve address, so the metadata can be
+//!   updated by changing the reserve
+//! - [ARC-69](https://arc.algorand.foundation/ARCs/arc-0069): the metadata is the JSON note of the
+//!   latest asset config transaction
+
+use super::arc3::{Arc3Metadata, arc3_metadata_hash};
+use super::arc19::{is_arc19_url, resolve_arc19_url};
+use super::asset_manager::AssetInformation;
+use algokit_transact::Address;
+use serde::Deserialize;
+
+/// The IPFS gateway `ipfs://` URLs are fetched through by default.
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+const IPFS_PREFIX: &str = "ipfs://";
+
+/// The metadata of an asset, combining whichever of ARC-3, ARC-19 and ARC-69 it follows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetMetadata {
+    pub asset_id: u64,
+    /// The asset URL with any ARC-19 template resolved and the `{id}` placeholder replaced
+    pub url: Option<String>,
+    /// Whether the URL is an ARC-19 template resolved from the reserve address
+    pub is_arc19: bool,
+    /// The ARC-3 JSON metadata the URL points to
+    pub arc3: Option<Arc3Metadata>,
+    /// The ARC-69 metadata from the note of the latest asset config transaction
+    pub arc69: Option<Arc69Metadata>,
+}
+
+impl AssetMetadata {
+    /// The description from the ARC-3 metadata, falling back to the ARC-69 metadata.
+    pub fn description(&self) -> Option<&str> {
+        self.arc3
+            .as_ref()
+            .and_then(|arc3| arc3.description.as_deref())
+            .or_else(|| self.arc69.as_ref()?.description.as_deref())
+    }
+
+    /// The media of the asset: the ARC-3 image, falling back to the ARC-69 media URL and then
+    /// the asset URL itself, e.g. for ARC-69 assets which point their URL at the media.
+    pub fn media_url(&self) -> Option<&str> {
+        self.arc3
+            .as_ref()
+            .and_then(|arc3| arc3.image.as_deref().or(arc3.animation_url.as_deref()))
+            .or_else(|| self.arc69.as_ref()?.media_url.as_deref())
+            .or(self.url.as_deref().filter(|_| self.arc3.is_none()))
+    }
+
+    /// The properties from the ARC-3 metadata, falling back to the ARC-69 metadata.
+    pub fn properties(&self) -> Option<&serde_json::Value> {
+        self.arc3
+            .as_ref()
+            .and_then(|arc3| arc3.properties.as_ref())
+            .or_else(|| self.arc69.as_ref()?.properties.as_ref())
+    }
+}
+
+/// ARC-69 JSON metadata.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Arc69Metadata {
+    pub standard: String,
+    pub description: Option<String>,
+    pub external_url: Option<String>,
+    pub media_url: Option<String>,
+    pub properties: Option<serde_json::Value>,
+    pub mime_type: Option<String>,
+    pub attributes: Option<serde_json::Value>,
+}
+
+/// Resolve the URL of an asset: an ARC-19 template is resolved from the reserve address and the
+/// ARC-3 `{id}` placeholder is replaced with the asset ID.
+pub fn resolve_asset_url(asset: &AssetInformation) -> Result<Option<String>, String> {
+    let Some(url) = asset.url.as_deref().filter(|url| !url.is_empty()) else {
+        return Ok(None);
+    };
+
+    let url = if is_arc19_url(url) {
+        let reserve = asset
+            .reserve
+            .as_deref()
+            .ok_or("An ARC-19 asset must have a reserve address")?
+            .parse::<Address>()
+            .map_err(|e| e.to_string())?;
+        resolve_arc19_url(url, &reserve).map_err(|e| e.to_string())?
+    } else {
+        url.to_string()
+    };
+
+    Ok(Some(url.replace("{id}", &asset.asset_id.to_string())))
+}
+
+/// The HTTP URL an asset URL is fetched from, `ipfs://` URLs are fetched through `ipfs_gateway`.
+pub fn gateway_url(url: &str, ipfs_gateway: &str) -> String {
+    match url.strip_prefix(IPFS_PREFIX) {
+        Some(path) => format!("{}/{}", ipfs_gateway.trim_end_matches('/'), path),
+        None => url.to_string(),
+    }
+}
+
+/// Parse and validate ARC-3 JSON metadata against the asset it was fetched for.
+///
+/// The decimals must match the asset's, and when the asset has a metadata hash it must be the
+/// [`arc3_metadata_hash`] of the JSON.
+pub fn parse_arc3_metadata(json: &[u8], asset: &AssetInformation) -> Result<Arc3Metadata, String> {
+    let metadata: Arc3Metadata =
+        serde_json::from_slice(json).map_err(|e| format!("Invalid ARC-3 metadata JSON: {}", e))?;
+
+    if let Some(decimals) = metadata.decimals {
+        if decimals != asset.decimals {
+            return Err(format!(
+                "ARC-3 metadata has {} decimals, but the asset has {}",
+                decimals, asset.decimals
+            ));
+        }
+    }
+
+    if let Some(metadata_hash) = &asset.metadata_hash {
+        let extra_metadata = metadata.extra_metadata_bytes()?;
+        if metadata_hash.as_slice() != arc3_metadata_hash(json, extra_metadata.as_deref()) {
+            return Err("The asset metadata hash doesn't match the ARC-3 metadata".to_string());
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Parse the ARC-69 metadata from an asset config transaction note, if it has any.
+pub fn parse_arc69_metadata(note: &[u8]) -> Option<Arc69Metadata> {
+    serde_json::from_slice::<Arc69Metadata>(note)
+        .ok()
+        .filter(|metadata| metadata.standard == "arc69")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::arc3::is_arc3;
+    use sha2::{Digest, Sha256};
+
+    fn asset(url: &str, reserve: Option<&str>) -> AssetInformation {
+        AssetInformation {
+            asset_id: 1234,
+            creator: String::new(),
+            total: 1,
+            decimals: 0,
+            default_frozen: None,
+            manager: None,
+            reserve: reserve.map(str::to_string),
+            freeze: None,
+            clawback: None,
+            unit_name: None,
+            unit_name_b64: None,
+            asset_name: Some("Test@arc3".to_string()),
+            asset_name_b64: None,
+            url: Some(url.to_string()),
+            url_b64: None,
+            metadata_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_asset_url() {
+        let resolved = resolve_asset_url(&asset("https://example.com/{id}.json#arc3", None));
+        assert_eq!(
+            resolved.unwrap().as_deref(),
+            Some("https://example.com/1234.json#arc3")
+        );
+        assert!(
+            resolve_asset_url(&asset(
+                "template-ipfs://{ipfscid:1:raw:reserve:sha2-256}",
+                None
+            ))
+            .is_err()
+        );
+        assert_eq!(
+            gateway_url("ipfs://bafkrei/arc3.json", "https://gateway.example/ipfs/"),
+            "https://gateway.example/ipfs/bafkrei/arc3.json"
+        );
+    }
+
+    #[test]
+    fn test_parse_arc3_metadata() {
+        let json = br#"{"name":"Test","decimals":0,"image":"ipfs://image","properties":{"a":1}}"#;
+        let mut asset = asset("https://example.com/metadata.json#arc3", None);
+        assert!(is_arc3(&asset));
+
+        let metadata = parse_arc3_metadata(json, &asset).unwrap();
+        assert_eq!(metadata.image.as_deref(), Some("ipfs://image"));
+
+        asset.metadata_hash = Some(Sha256::digest(json).to_vec());
+        assert!(parse_arc3_metadata(json, &asset).is_ok());
+        asset.metadata_hash = Some(vec![0; 32]);
+        assert!(parse_arc3_metadata(json, &asset).is_err());
+
+        asset.metadata_hash = None;
+        asset.decimals = 6;
+        assert!(parse_arc3_metadata(json, &asset).is_err());
+    }
+
+    #[test]
+    fn test_parse_arc69_metadata() {
+        let metadata = parse_arc69_metadata(
+            br#"{"standard":"arc69","description":"A test","media_url":"https://example.com/a.png","properties":{"trait":"x"}}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("A test"));
+
+        let combined = AssetMetadata {
+            asset_id: 1,
+            url: Some("https://example.com/a.png".to_string()),
+            is_arc19: false,
+            arc3: None,
+            arc69: Some(metadata),
+        };
+        assert_eq!(combined.description(), Some("A test"));
+        assert_eq!(combined.media_url(), Some("https://example.com/a.png"));
+        assert_eq!(
+            combined.properties(),
+            Some(&serde_json::json!({"trait": "x"}))
+        );
+
+        assert!(parse_arc69_metadata(br#"{"standard":"arc3"}"#).is_none());
+        assert!(parse_arc69_metadata(b"not json").is_none());
+    }
+}
@@ -3,19 +3,21 @@ use crate::applications::app_client::{
     AppClient, AppClientError, AppClientParams, AppSourceMaps, CompilationParams,
 };
 use crate::applications::app_factory::{AppFactory, AppFactoryParams};
+use crate::clients::genesis::GenesisDetails;
 use crate::clients::network_client::{
     AlgoClientConfig, AlgoConfig, AlgorandService, NetworkDetails, TokenHeader,
     genesis_id_is_localnet,
 };
+use crate::clients::suggested_params_cache::{DEFAULT_SUGGESTED_PARAMS_TTL, SuggestedParamsCache};
 use crate::transactions::{TransactionComposerConfig, TransactionSigner};
-use algod_client::{AlgodClient, apis::Error as AlgodError};
+use algod_client::{AlgodClient, apis::Error as AlgodError, models::TransactionParams};
 use algokit_abi::Arc56Contract;
 use algokit_http_client::DefaultHttpClient;
 use base64::{Engine, engine::general_purpose};
 use indexer_client::IndexerClient;
 use kmd_client::KmdClient;
 use snafu::Snafu;
-use std::{env, sync::Arc};
+use std::{env, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 
 #[derive(Debug, Snafu)]
@@ -34,6 +36,12 @@ pub enum ClientManagerError {
 
     #[snafu(display("Algod client error: {source}"))]
     AlgodClientError { source: AlgodError },
+
+    #[snafu(display("Genesis Error: {message}"))]
+    GenesisError { message: String },
+
+    #[snafu(display("Network Error: {message}"))]
+    NetworkError { message: String },
 }
 
 impl From<AlgodError> for ClientManagerError {
@@ -47,12 +55,19 @@ pub struct ClientManager {
     indexer: Option<Arc<IndexerClient>>,
     kmd: Option<Arc<KmdClient>>,
     cached_network_details: RwLock<Option<Arc<NetworkDetails>>>,
+    cached_genesis: RwLock<Option<Arc<GenesisDetails>>>,
+    suggested_params: Arc<SuggestedParamsCache>,
 }
 
 impl ClientManager {
     pub fn new(config: &AlgoConfig) -> Result<Self, ClientManagerError> {
+        let algod = Arc::new(Self::get_algod_client(&config.algod_config)?);
         Ok(Self {
-            algod: Arc::new(Self::get_algod_client(&config.algod_config)?),
+            suggested_params: Arc::new(SuggestedParamsCache::new(
+                algod.clone(),
+                DEFAULT_SUGGESTED_PARAMS_TTL,
+            )),
+            algod,
             indexer: match config.indexer_config.as_ref() {
                 Some(indexer_config) => Some(Arc::new(Self::get_indexer_client(indexer_config)?)),
                 None => None,
@@ -62,6 +77,7 @@ impl ClientManager {
                 None => None,
             },
             cached_network_details: RwLock::new(None),
+            cached_genesis: RwLock::new(None),
         })
     }
 
@@ -124,6 +140,49 @@ impl ClientManager {
         Ok(network_details)
     }
 
+    /// Get the typed genesis information of the connected network.
+    ///
+    /// The result is cached after the first successful call.
+    pub async fn genesis(&self) -> Result<Arc<GenesisDetails>, ClientManagerError> {
+        {
+            let cached = self.cached_genesis.read().await;
+            if let Some(ref genesis) = *cached {
+                return Ok(Arc::clone(genesis));
+            }
+        }
+
+        let mut cached = self.cached_genesis.write().await;
+        if let Some(ref genesis) = *cached {
+            return Ok(Arc::clone(genesis));
+        }
+
+        let genesis = Arc::new(GenesisDetails::try_from(self.algod().get_genesis().await?)?);
+        *cached = Some(Arc::clone(&genesis));
+        Ok(genesis)
+    }
+
+    /// Get the suggested params of the connected network.
+    ///
+    /// The params are cached for [`ClientManager::suggested_params_ttl`] and shared with the
+    /// composers created by the client.
+    pub async fn suggested_params(&self) -> Result<TransactionParams, ClientManagerError> {
+        Ok(self.suggested_params.get().await?)
+    }
+
+    /// How long suggested params are reused for, [`DEFAULT_SUGGESTED_PARAMS_TTL`] by default.
+    pub fn suggested_params_ttl(&self) -> Duration {
+        self.suggested_params.ttl()
+    }
+
+    /// Set how long suggested params are reused for, a zero TTL disables caching.
+    pub fn set_suggested_params_ttl(&self, ttl: Duration) {
+        self.suggested_params.set_ttl(ttl);
+    }
+
+    pub(crate) fn suggested_params_cache(&self) -> Arc<SuggestedParamsCache> {
+        Arc::clone(&self.suggested_params)
+    }
+
     pub fn genesis_id_is_localnet(genesis_id: &str) -> bool {
         genesis_id_is_localnet(genesis_id)
     }
@@ -140,37 +199,65 @@ impl ClientManager {
         Ok(self.network().await?.is_mainnet)
     }
 
+    /// Create a client manager configured from the environment, see
+    /// [`Self::get_config_from_environment_or_localnet`].
+    pub fn from_environment() -> Result<Self, ClientManagerError> {
+        Self::new(&Self::get_config_from_environment_or_localnet())
+    }
+
+    /// Resolve the client config from the `ALGOD_*`, `INDEXER_*` and `KMD_*` environment
+    /// variables (`SERVER`, `PORT` and `TOKEN`), like the other AlgoKit SDKs.
+    ///
+    /// Without `ALGOD_SERVER` the default LocalNet config is used. Otherwise indexer is only
+    /// configured when `INDEXER_SERVER` is set, and KMD when `KMD_SERVER` is set or algod isn't
+    /// a MainNet or TestNet node, in which case KMD defaults to the algod server and token on
+    /// port 4002.
     pub fn get_config_from_environment_or_localnet() -> AlgoConfig {
-        match Self::get_algod_config_from_environment() {
-            Ok(algod_config) => {
-                let kmd_config = if !algod_config.server.contains("mainnet")
-                    && !algod_config.server.contains("testnet")
-                {
-                    Some(AlgoClientConfig {
-                        port: env::var("KMD_PORT")
-                            .ok()
-                            .and_then(|p| p.parse().ok())
-                            .or(Some(4002)),
-                        ..algod_config.clone()
-                    })
-                } else {
-                    None
-                };
-
-                AlgoConfig {
-                    algod_config,
-                    indexer_config: match Self::get_indexer_config_from_environment() {
-                        Ok(indexer_config) => Some(indexer_config),
-                        Err(_) => None,
-                    },
-                    kmd_config,
-                }
-            }
-            Err(_) => AlgoConfig {
+        Self::config_from_lookup(|name| env::var(name).ok())
+    }
+
+    fn config_from_lookup(lookup: impl Fn(&str) -> Option<String>) -> AlgoConfig {
+        let service_config = |prefix: &str| {
+            lookup(&format!("{}_SERVER", prefix)).map(|server| AlgoClientConfig {
+                server,
+                port: lookup(&format!("{}_PORT", prefix)).and_then(|p| p.parse().ok()),
+                token: lookup(&format!("{}_TOKEN", prefix)).map(TokenHeader::String),
+                rate_limiter: None,
+            })
+        };
+
+        let Some(algod_config) = service_config("ALGOD") else {
+            return AlgoConfig {
                 algod_config: Self::get_default_localnet_config(AlgorandService::Algod),
                 indexer_config: Some(Self::get_default_localnet_config(AlgorandService::Indexer)),
                 kmd_config: Some(Self::get_default_localnet_config(AlgorandService::Kmd)),
-            },
+            };
+        };
+
+        let kmd_config = service_config("KMD")
+            .map(|kmd_config| AlgoClientConfig {
+                port: kmd_config.port.or(Some(4002)),
+                ..kmd_config
+            })
+            .or_else(|| {
+                (!algod_config.server.contains("mainnet")
+                    && !algod_config.server.contains("testnet"))
+                .then(|| AlgoClientConfig {
+                    server: algod_config.server.clone(),
+                    port: lookup("KMD_PORT")
+                        .and_then(|p| p.parse().ok())
+                        .or(Some(4002)),
+                    token: lookup("KMD_TOKEN")
+                        .map(TokenHeader::String)
+                        .or_else(|| algod_config.token.clone()),
+                    rate_limiter: None,
+                })
+            });
+
+        AlgoConfig {
+            indexer_config: service_config("INDEXER"),
+            kmd_config,
+            algod_config,
         }
     }
 
@@ -186,6 +273,7 @@ impl ClientManager {
             server,
             port,
             token,
+            rate_limiter: None,
         })
     }
 
@@ -201,6 +289,7 @@ impl ClientManager {
             server,
             port,
             token,
+            rate_limiter: None,
         })
     }
 
@@ -226,6 +315,7 @@ impl ClientManager {
             server,
             port,
             token,
+            rate_limiter: None,
         })
     }
 
@@ -240,6 +330,7 @@ impl ClientManager {
             server: format!("https://{}-{}.4160.nodely.dev", network, subdomain),
             port: Some(443),
             token: None,
+            rate_limiter: None,
         }
     }
 
@@ -256,45 +347,24 @@ impl ClientManager {
             token: Some(TokenHeader::String(
                 "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
             )),
+            rate_limiter: None,
         }
     }
 
     pub fn get_algod_client(config: &AlgoClientConfig) -> Result<AlgodClient, ClientManagerError> {
-        let base_url = if let Some(port) = config.port {
-            format!("{}:{}", config.server, port)
-        } else {
-            config.server.clone()
-        };
-
-        let http_client = match &config.token {
-            Some(TokenHeader::String(token)) => Arc::new(
-                DefaultHttpClient::with_header(&base_url, "X-Algo-API-Token", token).map_err(
-                    |e| ClientManagerError::HttpClientError {
-                        message: format!("Failed to create HTTP client with token header: {:?}", e),
-                    },
-                )?,
-            ),
-            Some(TokenHeader::Headers(headers)) => {
-                let (header_name, header_value) = headers
+        let header = match &config.token {
+            Some(TokenHeader::String(token)) => Some(("X-Algo-API-Token", token.as_str())),
+            Some(TokenHeader::Headers(headers)) => Some(
+                headers
                     .iter()
                     .next()
                     .map(|(k, v)| (k.as_str(), v.as_str()))
-                    .unwrap_or(("X-Algo-API-Token", ""));
-                Arc::new(
-                    DefaultHttpClient::with_header(&base_url, header_name, header_value).map_err(
-                        |e| ClientManagerError::HttpClientError {
-                            message: format!(
-                                "Failed to create HTTP client with custom header: {:?}",
-                                e
-                            ),
-                        },
-                    )?,
-                )
-            }
-            None => Arc::new(DefaultHttpClient::new(&base_url)),
+                    .unwrap_or(("X-Algo-API-Token", "")),
+            ),
+            None => None,
         };
 
-        Ok(AlgodClient::new(http_client))
+        Ok(AlgodClient::new(Self::http_client(config, header)?))
     }
 
     pub fn get_algod_client_from_environment() -> Result<AlgodClient, ClientManagerError> {
@@ -305,41 +375,19 @@ impl ClientManager {
     pub fn get_indexer_client(
         config: &AlgoClientConfig,
     ) -> Result<IndexerClient, ClientManagerError> {
-        let base_url = if let Some(port) = config.port {
-            format!("{}:{}", config.server, port)
-        } else {
-            config.server.clone()
-        };
-
-        let http_client = match &config.token {
-            Some(TokenHeader::String(token)) => Arc::new(
-                DefaultHttpClient::with_header(&base_url, "X-Indexer-API-Token", token).map_err(
-                    |e| ClientManagerError::HttpClientError {
-                        message: format!("Failed to create HTTP client with token header: {:?}", e),
-                    },
-                )?,
-            ),
-            Some(TokenHeader::Headers(headers)) => {
-                let (header_name, header_value) = headers
+        let header = match &config.token {
+            Some(TokenHeader::String(token)) => Some(("X-Indexer-API-Token", token.as_str())),
+            Some(TokenHeader::Headers(headers)) => Some(
+                headers
                     .iter()
                     .next()
                     .map(|(k, v)| (k.as_str(), v.as_str()))
-                    .unwrap_or(("X-Indexer-API-Token", ""));
-                Arc::new(
-                    DefaultHttpClient::with_header(&base_url, header_name, header_value).map_err(
-                        |e| ClientManagerError::HttpClientError {
-                            message: format!(
-                                "Failed to create HTTP client with custom header: {:?}",
-                                e
-                            ),
-                        },
-                    )?,
-                )
-            }
-            None => Arc::new(DefaultHttpClient::new(&base_url)),
+                    .unwrap_or(("X-Indexer-API-Token", "")),
+            ),
+            None => None,
         };
 
-        Ok(IndexerClient::new(http_client))
+        Ok(IndexerClient::new(Self::http_client(config, header)?))
     }
 
     pub fn get_indexer_client_from_environment() -> Result<IndexerClient, ClientManagerError> {
@@ -348,12 +396,6 @@ impl ClientManager {
     }
 
     pub fn get_kmd_client(config: &AlgoClientConfig) -> Result<KmdClient, ClientManagerError> {
-        let base_url = if let Some(port) = config.port {
-            format!("{}:{}", config.server, port)
-        } else {
-            config.server.clone()
-        };
-
         let token_value = match &config.token {
             Some(TokenHeader::String(token)) => token.clone(),
             Some(TokenHeader::Headers(headers)) => {
@@ -361,22 +403,36 @@ impl ClientManager {
             }
             None => String::new(),
         };
+        let header = (!token_value.is_empty()).then_some(("X-KMD-API-Token", token_value.as_str()));
+
+        Ok(KmdClient::new(Self::http_client(config, header)?))
+    }
 
-        let http_client = if token_value.is_empty() {
-            Arc::new(DefaultHttpClient::new(&base_url))
+    /// The HTTP client of the configured server, with the API token header, if any, and the rate
+    /// limiter of the config.
+    fn http_client(
+        config: &AlgoClientConfig,
+        header: Option<(&str, &str)>,
+    ) -> Result<Arc<DefaultHttpClient>, ClientManagerError> {
+        let base_url = if let Some(port) = config.port {
+            format!("{}:{}", config.server, port)
         } else {
-            Arc::new(
-                DefaultHttpClient::with_header(&base_url, "X-KMD-API-Token", &token_value)
-                    .map_err(|e| ClientManagerError::HttpClientError {
-                        message: format!(
-                            "Failed to create HTTP client with KMD token header: {:?}",
-                            e
-                        ),
-                    })?,
-            )
+            config.server.clone()
         };
 
-        Ok(KmdClient::new(http_client))
+        let mut builder = DefaultHttpClient::builder(&base_url);
+        if let Some((header_name, header_value)) = header {
+            builder = builder.header(header_name, header_value);
+        }
+        if let Some(rate_limiter) = &config.rate_limiter {
+            builder = builder.rate_limiter(rate_limiter.clone());
+        }
+        let http_client = builder
+            .build()
+            .map_err(|e| ClientManagerError::HttpClientError {
+                message: format!("Failed to create HTTP client: {}", e),
+            })?;
+        Ok(Arc::new(http_client))
     }
 
     pub fn get_kmd_client_from_environment() -> Result<KmdClient, ClientManagerError> {
@@ -493,6 +549,7 @@ mod tests {
     use super::*;
     use crate::clients::network_client::AlgorandService;
     use rstest::rstest;
+    use std::collections::HashMap;
 
     #[test]
     fn test_cache_initially_empty() {
@@ -501,11 +558,13 @@ mod tests {
                 server: "http://localhost:4001".to_string(),
                 port: None,
                 token: None,
+                rate_limiter: None,
             },
             indexer_config: Some(AlgoClientConfig {
                 server: "http://localhost:8980".to_string(),
                 port: None,
                 token: None,
+                rate_limiter: None,
             }),
             kmd_config: None,
         };
@@ -523,11 +582,13 @@ mod tests {
                 server: "http://invalid-host:65534".to_string(),
                 port: Some(65534),
                 token: None,
+                rate_limiter: None,
             },
             indexer_config: Some(AlgoClientConfig {
                 server: "http://invalid-host:65535".to_string(),
                 port: Some(65535),
                 token: None,
+                rate_limiter: None,
             }),
             kmd_config: None,
         };
@@ -542,12 +603,76 @@ mod tests {
         assert!(cache.is_none());
     }
 
+    fn lookup_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_config_from_environment_defaults_to_localnet() {
+        let config = ClientManager::config_from_lookup(lookup_from(&[]));
+
+        assert_eq!(config.algod_config.port, Some(4001));
+        assert_eq!(config.indexer_config.unwrap().port, Some(8980));
+        assert_eq!(config.kmd_config.unwrap().port, Some(4002));
+    }
+
+    #[test]
+    fn test_config_from_environment_kmd_defaults_to_algod_server() {
+        let config = ClientManager::config_from_lookup(lookup_from(&[
+            ("ALGOD_SERVER", "http://algod.internal"),
+            ("ALGOD_PORT", "8080"),
+            ("ALGOD_TOKEN", "algod-token"),
+        ]));
+
+        assert_eq!(config.algod_config.server, "http://algod.internal");
+        assert_eq!(config.algod_config.port, Some(8080));
+        assert!(config.indexer_config.is_none());
+        let kmd_config = config.kmd_config.unwrap();
+        assert_eq!(kmd_config.server, "http://algod.internal");
+        assert_eq!(kmd_config.port, Some(4002));
+        assert!(matches!(kmd_config.token, Some(TokenHeader::String(t)) if t == "algod-token"));
+    }
+
+    #[test]
+    fn test_config_from_environment_public_network() {
+        let config = ClientManager::config_from_lookup(lookup_from(&[
+            ("ALGOD_SERVER", "https://testnet-api.4160.nodely.dev"),
+            ("INDEXER_SERVER", "https://testnet-idx.4160.nodely.dev"),
+            ("INDEXER_PORT", "443"),
+        ]));
+
+        assert!(config.kmd_config.is_none());
+        let indexer_config = config.indexer_config.unwrap();
+        assert_eq!(indexer_config.server, "https://testnet-idx.4160.nodely.dev");
+        assert_eq!(indexer_config.port, Some(443));
+        assert!(indexer_config.token.is_none());
+    }
+
+    #[test]
+    fn test_config_from_environment_explicit_kmd() {
+        let config = ClientManager::config_from_lookup(lookup_from(&[
+            ("ALGOD_SERVER", "https://mainnet-api.4160.nodely.dev"),
+            ("KMD_SERVER", "http://kmd.internal"),
+            ("KMD_TOKEN", "kmd-token"),
+        ]));
+
+        let kmd_config = config.kmd_config.unwrap();
+        assert_eq!(kmd_config.server, "http://kmd.internal");
+        assert_eq!(kmd_config.port, Some(4002));
+        assert!(matches!(kmd_config.token, Some(TokenHeader::String(t)) if t == "kmd-token"));
+    }
+
     #[test]
     fn test_client_config_builder() {
         let config = AlgoClientConfig {
             server: "http://localhost".to_string(),
             port: Some(4001),
             token: Some(TokenHeader::String("test-token".to_string())),
+            rate_limiter: None,
         };
 
         assert_eq!(config.server, "http://localhost");
@@ -620,12 +745,14 @@ mod tests {
                 server: "http://localhost".to_string(),
                 port: Some(4001),
                 token: None,
+                rate_limiter: None,
             },
             indexer_config: None,
             kmd_config: Some(AlgoClientConfig {
                 server: "http://localhost".to_string(),
                 port: Some(4002),
                 token: Some(TokenHeader::String("kmd-token".to_string())),
+                rate_limiter: None,
             }),
         };
 
@@ -641,6 +768,7 @@ mod tests {
                 server: "http://localhost".to_string(),
                 port: Some(4001),
                 token: None,
+                rate_limiter: None,
             },
             indexer_config: None,
             kmd_config: None,
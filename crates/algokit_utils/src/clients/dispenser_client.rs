@@ -0,0 +1,294 @@
+use algokit_http_client::{DefaultHttpClient, HttpClient, HttpMethod, HttpResponse};
+use algokit_transact::Address;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The base URL of the TestNet Dispenser API.
+pub const TESTNET_DISPENSER_API_URL: &str = "https://api.dispenser.algorandfoundation.tools";
+
+/// The environment variable holding the access token of the TestNet Dispenser API, as issued by
+/// `algokit dispenser login --ci`.
+pub const DISPENSER_ACCESS_TOKEN_ENV_VAR: &str = "ALGOKIT_DISPENSER_ACCESS_TOKEN";
+
+/// How long a request to the TestNet Dispenser API may take by default.
+pub const DEFAULT_DISPENSER_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Snafu)]
+pub enum DispenserError {
+    #[snafu(display("Dispenser configuration error: {message}"))]
+    ConfigError { message: String },
+
+    #[snafu(display("Dispenser request failed: {message}"))]
+    RequestError { message: String },
+
+    #[snafu(display("Dispenser request to {path} timed out after {timeout:?}"))]
+    Timeout { path: String, timeout: Duration },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TestNetDispenserApiClientParams {
+    /// The access token, defaults to the `ALGOKIT_DISPENSER_ACCESS_TOKEN` environment variable
+    pub auth_token: Option<String>,
+    /// Defaults to [`DEFAULT_DISPENSER_REQUEST_TIMEOUT`]
+    pub request_timeout: Option<Duration>,
+}
+
+/// The response of funding an account from the dispenser.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DispenserFundResponse {
+    #[serde(rename = "txID")]
+    pub transaction_id: String,
+    /// The amount funded, in microALGO
+    pub amount: u64,
+}
+
+/// The amount the dispenser will fund an account with.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DispenserLimitResponse {
+    /// The amount, in microALGO
+    pub amount: u64,
+}
+
+#[derive(Serialize)]
+struct FundRequest<'a> {
+    receiver: &'a str,
+    amount: u64,
+    #[serde(rename = "assetID")]
+    asset_id: u64,
+}
+
+#[derive(Serialize)]
+struct RefundRequest<'a> {
+    #[serde(rename = "refundTransactionID")]
+    refund_transaction_id: &'a str,
+}
+
+/// A client for the [TestNet Dispenser API](https://github.com/algorandfoundation/algokit/blob/main/docs/testnet_api.md),
+/// which funds TestNet accounts with ALGO.
+#[derive(Clone)]
+pub struct TestNetDispenserApiClient {
+    http_client: Arc<dyn HttpClient>,
+    request_timeout: Duration,
+}
+
+impl TestNetDispenserApiClient {
+    /// Create a client authenticated with a bearer token.
+    ///
+    /// Fails when no token is given and the `ALGOKIT_DISPENSER_ACCESS_TOKEN` environment variable
+    /// isn't set.
+    pub fn new(params: Option<TestNetDispenserApiClientParams>) -> Result<Self, DispenserError> {
+        let params = params.unwrap_or_default();
+        let auth_token = match params.auth_token {
+            Some(auth_token) => auth_token,
+            None => env::var(DISPENSER_ACCESS_TOKEN_ENV_VAR).map_err(|_| {
+                DispenserError::ConfigError {
+                    message: format!(
+                        "No auth token given and the {} environment variable isn't set",
+                        DISPENSER_ACCESS_TOKEN_ENV_VAR
+                    ),
+                }
+            })?,
+        };
+
+        let http_client = DefaultHttpClient::with_header(
+            TESTNET_DISPENSER_API_URL,
+            "Authorization",
+            &format!("Bearer {}", auth_token),
+        )
+        .map_err(|e| DispenserError::ConfigError {
+            message: e.to_string(),
+        })?;
+
+        Ok(Self::new_with_http_client(
+            Arc::new(http_client),
+            params.request_timeout,
+        ))
+    }
+
+    /// Create a client sending its requests with the given HTTP client, which must target the
+    /// dispenser and add the authorization header.
+    pub fn new_with_http_client(
+        http_client: Arc<dyn HttpClient>,
+        request_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            http_client,
+            request_timeout: request_timeout.unwrap_or(DEFAULT_DISPENSER_REQUEST_TIMEOUT),
+        }
+    }
+
+    /// Send the given amount of microALGO to the receiver.
+    pub async fn fund(
+        &self,
+        receiver: &Address,
+        amount: u64,
+    ) -> Result<DispenserFundResponse, DispenserError> {
+        let body = FundRequest {
+            receiver: &receiver.to_string(),
+            amount,
+            asset_id: 0,
+        };
+        self.request(HttpMethod::Post, "/fund/0", Some(Self::encode(&body)?))
+            .await
+    }
+
+    /// Return the ALGO of a funding transaction to the dispenser, which restores the limit of the
+    /// funded account.
+    pub async fn refund(&self, refund_transaction_id: &str) -> Result<(), DispenserError> {
+        let body = RefundRequest {
+            refund_transaction_id,
+        };
+        self.send(HttpMethod::Post, "/refund", Some(Self::encode(&body)?))
+            .await
+            .map(|_| ())
+    }
+
+    /// Get the amount the dispenser currently funds the authenticated user with.
+    pub async fn get_limit(&self) -> Result<DispenserLimitResponse, DispenserError> {
+        self.request(HttpMethod::Get, "/fund/0/limit", None).await
+    }
+
+    fn encode<T: Serialize>(body: &T) -> Result<Vec<u8>, DispenserError> {
+        serde_json::to_vec(body).map_err(|e| DispenserError::RequestError {
+            message: e.to_string(),
+        })
+    }
+
+    async fn request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Vec<u8>>,
+    ) -> Result<T, DispenserError> {
+        let response = self.send(method, path, body).await?;
+        serde_json::from_slice(&response).map_err(|e| DispenserError::RequestError {
+            message: format!("Unexpected response from {}: {}", path, e),
+        })
+    }
+
+    async fn send(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, DispenserError> {
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let request =
+            self.http_client
+                .request(method, path.to_string(), None, body, Some(headers), None);
+        let response = tokio::time::timeout(self.request_timeout, request)
+            .await
+            .map_err(|_| DispenserError::Timeout {
+                path: path.to_string(),
+                timeout: self.request_timeout,
+            })?
+            .and_then(HttpResponse::error_for_status)
+            .map_err(|e| DispenserError::RequestError {
+                message: e.to_string(),
+            })?;
+        Ok(response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_http_client::{HttpError, RequestOptions};
+    use algokit_transact::test_utils::AccountMother;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Records the requests it receives and answers them with a fixed body.
+    struct RecordingHttpClient {
+        response: &'static str,
+        requests: Mutex<Vec<(String, Option<serde_json::Value>)>>,
+    }
+
+    #[async_trait]
+    impl HttpClient for RecordingHttpClient {
+        async fn request(
+            &self,
+            _http_method: HttpMethod,
+            path: String,
+            _query: Option<HashMap<String, String>>,
+            body: Option<Vec<u8>>,
+            _headers: Option<HashMap<String, String>>,
+            _options: Option<RequestOptions>,
+        ) -> Result<HttpResponse, HttpError> {
+            let body = body.map(|body| serde_json::from_slice(&body).unwrap());
+            self.requests.lock().unwrap().push((path, body));
+            Ok(HttpResponse {
+                status: 200,
+                body: self.response.as_bytes().to_vec(),
+                headers: HashMap::new(),
+            })
+        }
+    }
+
+    fn client(response: &'static str) -> (TestNetDispenserApiClient, Arc<RecordingHttpClient>) {
+        let http_client = Arc::new(RecordingHttpClient {
+            response,
+            requests: Mutex::new(Vec::new()),
+        });
+        (
+            TestNetDispenserApiClient::new_with_http_client(http_client.clone(), None),
+            http_client,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fund() {
+        let (client, http_client) = client(r#"{"txID": "TXID", "amount": 1000000}"#);
+        let receiver = AccountMother::account().address();
+
+        let response = client.fund(&receiver, 1_000_000).await.unwrap();
+
+        assert_eq!(
+            response,
+            DispenserFundResponse {
+                transaction_id: "TXID".to_string(),
+                amount: 1_000_000,
+            }
+        );
+        let requests = http_client.requests.lock().unwrap();
+        assert_eq!(requests[0].0, "/fund/0");
+        assert_eq!(
+            requests[0].1,
+            Some(serde_json::json!({
+                "receiver": receiver.to_string(),
+                "amount": 1_000_000,
+                "assetID": 0,
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refund_and_limit() {
+        let (client, http_client) = client(r#"{"amount": 5000000}"#);
+
+        client.refund("TXID").await.unwrap();
+        assert_eq!(client.get_limit().await.unwrap().amount, 5_000_000);
+
+        let requests = http_client.requests.lock().unwrap();
+        assert_eq!(requests[0].0, "/refund");
+        assert_eq!(
+            requests[0].1,
+            Some(serde_json::json!({ "refundTransactionID": "TXID" }))
+        );
+        assert_eq!(requests[1], ("/fund/0/limit".to_string(), None));
+    }
+
+    #[test]
+    fn test_new_uses_given_auth_token() {
+        let client = TestNetDispenserApiClient::new(Some(TestNetDispenserApiClientParams {
+            auth_token: Some("token".to_string()),
+            request_timeout: Some(Duration::from_secs(1)),
+        }))
+        .unwrap();
+        assert_eq!(client.request_timeout, Duration::from_secs(1));
+    }
+}
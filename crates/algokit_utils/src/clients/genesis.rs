@@ -0,0 +1,214 @@
+use crate::clients::client_manager::ClientManagerError;
+use algod_client::models::{Genesis, GenesisAllocation};
+use algokit_transact::Address;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// The participation status of an account allocated in the genesis block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GenesisAccountStatus {
+    /// The account is offline (does not participate in consensus).
+    #[default]
+    Offline,
+    /// The account is online and participates in consensus.
+    Online,
+    /// The account is marked as not participating (e.g. the fee sink and rewards pool).
+    NotParticipating,
+}
+
+impl From<u64> for GenesisAccountStatus {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => Self::Online,
+            2 => Self::NotParticipating,
+            _ => Self::Offline,
+        }
+    }
+}
+
+/// An account allocated in the genesis block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisAccount {
+    pub address: Address,
+    pub comment: String,
+    /// The initial balance of the account in microAlgos.
+    pub balance: u64,
+    pub status: GenesisAccountStatus,
+}
+
+/// Typed representation of a network's genesis file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisDetails {
+    /// The genesis id of the network, e.g. `mainnet-v1.0`.
+    pub id: String,
+    /// The network name, e.g. `mainnet`.
+    pub network: String,
+    /// The consensus protocol the network started with.
+    pub proto: String,
+    /// The address of the fee sink account.
+    pub fee_sink: Address,
+    /// The address of the rewards pool account.
+    pub rewards_pool: Address,
+    pub timestamp: u64,
+    pub devmode: bool,
+    pub comment: Option<String>,
+    pub allocations: Vec<GenesisAccount>,
+}
+
+impl GenesisDetails {
+    /// The protocol owned accounts, i.e. the fee sink and rewards pool.
+    pub fn protocol_accounts(&self) -> [&Address; 2] {
+        [&self.fee_sink, &self.rewards_pool]
+    }
+
+    /// Whether the address is a protocol owned account (fee sink or rewards pool).
+    pub fn is_protocol_account(&self, address: &Address) -> bool {
+        self.protocol_accounts().contains(&address)
+    }
+
+    /// Get the genesis allocation for the given address, if any.
+    pub fn get_allocation(&self, address: &Address) -> Option<&GenesisAccount> {
+        self.allocations.iter().find(|a| &a.address == address)
+    }
+
+    /// The total amount of microAlgos allocated in the genesis block.
+    pub fn total_allocated(&self) -> u64 {
+        self.allocations
+            .iter()
+            .fold(0u64, |total, a| total.saturating_add(a.balance))
+    }
+}
+
+/// The subset of the genesis account state that is surfaced on [`GenesisAccount`].
+#[derive(Deserialize, Default)]
+struct GenesisAccountState {
+    #[serde(default)]
+    algo: u64,
+    #[serde(default)]
+    onl: u64,
+}
+
+fn parse_address(address: &str, field: &str) -> Result<Address, ClientManagerError> {
+    Address::from_str(address).map_err(|e| ClientManagerError::GenesisError {
+        message: format!("Invalid {} address {}: {}", field, address, e),
+    })
+}
+
+fn parse_allocation(allocation: &GenesisAllocation) -> Result<GenesisAccount, ClientManagerError> {
+    let state_error = |e: serde_json::Error| ClientManagerError::GenesisError {
+        message: format!(
+            "Invalid state for genesis allocation {}: {}",
+            allocation.addr, e
+        ),
+    };
+    // The state is a string of raw JSON when the ffi feature is enabled
+    let state: GenesisAccountState =
+        match serde_json::to_value(&allocation.state).map_err(state_error)? {
+            serde_json::Value::String(raw) => serde_json::from_str(&raw).map_err(state_error)?,
+            serde_json::Value::Null => GenesisAccountState::default(),
+            value => serde_json::from_value(value).map_err(state_error)?,
+        };
+
+    Ok(GenesisAccount {
+        address: parse_address(&allocation.addr, "allocation")?,
+        comment: allocation.comment.clone(),
+        balance: state.algo,
+        status: state.onl.into(),
+    })
+}
+
+impl TryFrom<Genesis> for GenesisDetails {
+    type Error = ClientManagerError;
+
+    fn try_from(genesis: Genesis) -> Result<Self, Self::Error> {
+        let allocations = genesis
+            .alloc
+            .iter()
+            .map(parse_allocation)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            fee_sink: parse_address(&genesis.fees, "fee sink")?,
+            rewards_pool: parse_address(&genesis.rwd, "rewards pool")?,
+            id: genesis.id,
+            network: genesis.network,
+            proto: genesis.proto,
+            timestamp: genesis.timestamp,
+            devmode: genesis.devmode.unwrap_or(false),
+            comment: genesis.comment,
+            allocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEE_SINK: &str = "A7NMWS3NT3IUDMLVO26ULGXGIIOUQ3ND2TXSER6EBGRZNOBOUIQXHIBGDE";
+    const REWARDS_POOL: &str = "7777777777777777777777777777777777777777777777777774MSJUVU";
+
+    fn genesis_json() -> serde_json::Value {
+        serde_json::json!({
+            "alloc": [
+                {
+                    "addr": REWARDS_POOL,
+                    "comment": "RewardsPool",
+                    "state": { "algo": 125000000000000u64, "onl": 2 }
+                },
+                {
+                    "addr": FEE_SINK,
+                    "comment": "FeeSink",
+                    "state": { "algo": 100000, "onl": 2 }
+                },
+                {
+                    "addr": "3Y62HTJ4WYSIEKC74XE3F2JFCS7774EN3CYNUHQCEFIN7QBYFAWLKE5MFY",
+                    "comment": "Wallet1",
+                    "state": { "algo": 1000000000000000u64, "onl": 1 }
+                }
+            ],
+            "fees": FEE_SINK,
+            "id": "v1",
+            "network": "devnet",
+            "proto": "future",
+            "rwd": REWARDS_POOL,
+            "timestamp": 0
+        })
+    }
+
+    #[test]
+    fn test_parse_genesis() {
+        let genesis: Genesis = serde_json::from_value(genesis_json()).unwrap();
+        let details = GenesisDetails::try_from(genesis).unwrap();
+
+        let fee_sink = Address::from_str(FEE_SINK).unwrap();
+        let rewards_pool = Address::from_str(REWARDS_POOL).unwrap();
+        assert_eq!(details.fee_sink, fee_sink);
+        assert_eq!(details.rewards_pool, rewards_pool);
+        assert!(details.is_protocol_account(&fee_sink));
+        assert!(details.is_protocol_account(&rewards_pool));
+        assert!(!details.devmode);
+
+        assert_eq!(details.allocations.len(), 3);
+        let wallet = &details.allocations[2];
+        assert!(!details.is_protocol_account(&wallet.address));
+        assert_eq!(wallet.status, GenesisAccountStatus::Online);
+        assert_eq!(
+            details.get_allocation(&fee_sink).unwrap().status,
+            GenesisAccountStatus::NotParticipating
+        );
+        assert_eq!(details.total_allocated(), 1_125_000_000_100_000);
+    }
+
+    #[test]
+    fn test_parse_genesis_invalid_address() {
+        let mut json = genesis_json();
+        json["fees"] = serde_json::Value::String("not-an-address".to_string());
+        let genesis: Genesis = serde_json::from_value(json).unwrap();
+
+        assert!(matches!(
+            GenesisDetails::try_from(genesis),
+            Err(ClientManagerError::GenesisError { .. })
+        ));
+    }
+}
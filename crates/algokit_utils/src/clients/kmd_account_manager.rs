@@ -0,0 +1,380 @@
+use crate::TransactionSigner;
+use algod_client::AlgodClient;
+use algokit_transact::{Address, AlgorandMsgpack, SignedTransaction, Transaction};
+use async_trait::async_trait;
+use kmd_client::KmdClient;
+use kmd_client::models::{
+    ExportKeyRequest, InitWalletHandleTokenRequest, ListKeysRequest,
+    ReleaseWalletHandleTokenRequest, SignTransactionRequest,
+};
+use log::warn;
+use snafu::Snafu;
+use std::future::Future;
+use std::sync::Arc;
+
+/// The name of the wallet holding the funded accounts of a LocalNet.
+pub const LOCALNET_DEFAULT_WALLET_NAME: &str = "unencrypted-default-wallet";
+
+#[derive(Debug, Snafu)]
+pub enum KmdAccountManagerError {
+    #[snafu(display("KMD error: {message}"))]
+    KmdError { message: String },
+
+    #[snafu(display("Wallet not found: {wallet_name}"))]
+    WalletNotFound { wallet_name: String },
+
+    #[snafu(display("No matching account found in wallet {wallet_name}"))]
+    AccountNotFound { wallet_name: String },
+
+    #[snafu(display("Algod error: {message}"))]
+    AlgodError { message: String },
+}
+
+/// A wallet managed by KMD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KmdWallet {
+    pub id: String,
+    pub name: String,
+}
+
+/// An account held in a KMD wallet, with a signer that signs through KMD.
+#[derive(Clone)]
+pub struct KmdAccount {
+    pub address: Address,
+    pub signer: Arc<KmdSigner>,
+}
+
+/// Provides access to the wallets and accounts of a KMD instance, e.g. the pre-funded accounts
+/// of a LocalNet.
+#[derive(Clone)]
+pub struct KmdAccountManager {
+    kmd_client: Arc<KmdClient>,
+    algod_client: Arc<AlgodClient>,
+    wallet_password: Option<String>,
+}
+
+impl KmdAccountManager {
+    pub fn new(kmd_client: Arc<KmdClient>, algod_client: Arc<AlgodClient>) -> Self {
+        Self {
+            kmd_client,
+            algod_client,
+            wallet_password: None,
+        }
+    }
+
+    /// A copy of the manager that unlocks wallets with the given password.
+    pub fn with_wallet_password(&self, wallet_password: &str) -> Self {
+        Self {
+            wallet_password: Some(wallet_password.to_string()),
+            ..self.clone()
+        }
+    }
+
+    /// List the wallets of the KMD instance.
+    pub async fn list_wallets(&self) -> Result<Vec<KmdWallet>, KmdAccountManagerError> {
+        let response =
+            self.kmd_client
+                .list_wallets()
+                .await
+                .map_err(|e| KmdAccountManagerError::KmdError {
+                    message: format!("Failed to list wallets: {}", e),
+                })?;
+
+        Ok(response
+            .wallets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|wallet| {
+                Some(KmdWallet {
+                    id: wallet.id?,
+                    name: wallet.name?,
+                })
+            })
+            .collect())
+    }
+
+    /// Get the wallet with the given name.
+    pub async fn get_wallet(&self, wallet_name: &str) -> Result<KmdWallet, KmdAccountManagerError> {
+        self.list_wallets()
+            .await?
+            .into_iter()
+            .find(|wallet| wallet.name == wallet_name)
+            .ok_or_else(|| KmdAccountManagerError::WalletNotFound {
+                wallet_name: wallet_name.to_string(),
+            })
+    }
+
+    /// List the addresses of the accounts in the wallet with the given name.
+    pub async fn get_wallet_addresses(
+        &self,
+        wallet_name: &str,
+    ) -> Result<Vec<Address>, KmdAccountManagerError> {
+        let wallet = self.get_wallet(wallet_name).await?;
+        self.wallet_addresses(&wallet).await
+    }
+
+    async fn wallet_addresses(
+        &self,
+        wallet: &KmdWallet,
+    ) -> Result<Vec<Address>, KmdAccountManagerError> {
+        let kmd_client = &self.kmd_client;
+        with_wallet_handle(
+            kmd_client,
+            &wallet.id,
+            self.wallet_password.as_deref(),
+            |wallet_handle_token| async move {
+                let response = kmd_client
+                    .list_keys_in_wallet(ListKeysRequest {
+                        wallet_handle_token: Some(wallet_handle_token),
+                    })
+                    .await
+                    .map_err(|e| KmdAccountManagerError::KmdError {
+                        message: format!("Failed to list keys: {}", e),
+                    })?;
+
+                response
+                    .addresses
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|address| {
+                        address
+                            .parse()
+                            .map_err(|e| KmdAccountManagerError::KmdError {
+                                message: format!("Invalid address {}: {}", address, e),
+                            })
+                    })
+                    .collect()
+            },
+        )
+        .await
+    }
+
+    /// Export the 64 byte ed25519 private key of an account in the wallet with the given name.
+    pub async fn export_key(
+        &self,
+        wallet_name: &str,
+        address: &Address,
+    ) -> Result<Vec<u8>, KmdAccountManagerError> {
+        let wallet = self.get_wallet(wallet_name).await?;
+        let kmd_client = &self.kmd_client;
+        let wallet_password = self.wallet_password.clone();
+        with_wallet_handle(
+            kmd_client,
+            &wallet.id,
+            self.wallet_password.as_deref(),
+            |wallet_handle_token| async move {
+                let response = kmd_client
+                    .export_key(ExportKeyRequest {
+                        address: Some(address.to_string()),
+                        wallet_handle_token: Some(wallet_handle_token),
+                        wallet_password,
+                    })
+                    .await
+                    .map_err(|e| KmdAccountManagerError::KmdError {
+                        message: format!("Failed to export key of {}: {}", address, e),
+                    })?;
+
+                response
+                    .private_key
+                    .ok_or_else(|| KmdAccountManagerError::KmdError {
+                        message: format!("KMD returned no private key for {}", address),
+                    })
+            },
+        )
+        .await
+    }
+
+    /// Get an account of the wallet with the given name, whose transactions are signed by KMD
+    /// without exporting its key.
+    ///
+    /// # Arguments
+    /// * `wallet_name` - The name of the wallet holding the account
+    /// * `predicate` - Selects the account, the first account of the wallet is used when `None`
+    pub async fn get_wallet_account(
+        &self,
+        wallet_name: &str,
+        predicate: Option<&(dyn Fn(&Address) -> bool + Sync)>,
+    ) -> Result<KmdAccount, KmdAccountManagerError> {
+        let wallet = self.get_wallet(wallet_name).await?;
+        let address = self
+            .wallet_addresses(&wallet)
+            .await?
+            .into_iter()
+            .find(|address| predicate.is_none_or(|predicate| predicate(address)))
+            .ok_or_else(|| KmdAccountManagerError::AccountNotFound {
+                wallet_name: wallet_name.to_string(),
+            })?;
+
+        Ok(self.account(&wallet, address))
+    }
+
+    /// Get the funded account of a LocalNet, i.e. the account of its default wallet with the
+    /// highest balance.
+    pub async fn get_localnet_dispenser_account(
+        &self,
+    ) -> Result<KmdAccount, KmdAccountManagerError> {
+        let wallet = self.get_wallet(LOCALNET_DEFAULT_WALLET_NAME).await?;
+
+        let mut dispenser: Option<(Address, u64)> = None;
+        for address in self.wallet_addresses(&wallet).await? {
+            let account_information = self
+                .algod_client
+                .account_information(&address.to_string(), None, None)
+                .await
+                .map_err(|e| KmdAccountManagerError::AlgodError {
+                    message: format!("Failed to get account information of {}: {}", address, e),
+                })?;
+
+            if dispenser
+                .as_ref()
+                .is_none_or(|(_, balance)| account_information.amount > *balance)
+            {
+                dispenser = Some((address, account_information.amount));
+            }
+        }
+
+        let (address, _) = dispenser.ok_or_else(|| KmdAccountManagerError::AccountNotFound {
+            wallet_name: LOCALNET_DEFAULT_WALLET_NAME.to_string(),
+        })?;
+
+        Ok(self.account(&wallet, address))
+    }
+
+    fn account(&self, wallet: &KmdWallet, address: Address) -> KmdAccount {
+        KmdAccount {
+            signer: Arc::new(KmdSigner {
+                kmd_client: self.kmd_client.clone(),
+                wallet_id: wallet.id.clone(),
+                wallet_password: self.wallet_password.clone(),
+                address: address.clone(),
+            }),
+            address,
+        }
+    }
+}
+
+/// A [`TransactionSigner`] that signs with the key of an account held in a KMD wallet.
+///
+/// Transactions whose sender isn't the account, e.g. of accounts rekeyed to it, are signed as the
+/// authorizing account.
+#[derive(Clone)]
+pub struct KmdSigner {
+    kmd_client: Arc<KmdClient>,
+    wallet_id: String,
+    wallet_password: Option<String>,
+    address: Address,
+}
+
+impl KmdSigner {
+    /// The address of the account the signer signs with.
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for KmdSigner {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        let kmd_client = &self.kmd_client;
+        with_wallet_handle(
+            kmd_client,
+            &self.wallet_id,
+            self.wallet_password.as_deref(),
+            |wallet_handle_token| async move {
+                let mut signed_transactions = Vec::with_capacity(indices.len());
+                for &index in indices {
+                    let transaction = transactions.get(index).ok_or_else(|| {
+                        KmdAccountManagerError::KmdError {
+                            message: format!("Index {} out of bounds for transactions", index),
+                        }
+                    })?;
+
+                    let encoded =
+                        transaction
+                            .encode_raw()
+                            .map_err(|e| KmdAccountManagerError::KmdError {
+                                message: format!("Failed to encode transaction: {}", e),
+                            })?;
+
+                    let public_key = (transaction.header().sender != self.address)
+                        .then(|| self.address.as_bytes().to_vec());
+
+                    let response = kmd_client
+                        .sign_transaction(SignTransactionRequest {
+                            public_key,
+                            transaction: Some(encoded),
+                            wallet_handle_token: Some(wallet_handle_token.clone()),
+                            wallet_password: self.wallet_password.clone(),
+                        })
+                        .await
+                        .map_err(|e| KmdAccountManagerError::KmdError {
+                            message: format!("Failed to sign transaction: {}", e),
+                        })?;
+
+                    let signed_transaction = response
+                        .signed_transaction
+                        .ok_or_else(|| KmdAccountManagerError::KmdError {
+                            message: "KMD returned no signed transaction".to_string(),
+                        })
+                        .and_then(|bytes| {
+                            SignedTransaction::decode(&bytes).map_err(|e| {
+                                KmdAccountManagerError::KmdError {
+                                    message: format!("Failed to decode signed transaction: {}", e),
+                                }
+                            })
+                        })?;
+                    signed_transactions.push(signed_transaction);
+                }
+                Ok(signed_transactions)
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
+
+/// Run `f` with a handle token of the wallet, releasing the token afterwards.
+async fn with_wallet_handle<T, F, Fut>(
+    kmd_client: &KmdClient,
+    wallet_id: &str,
+    wallet_password: Option<&str>,
+    f: F,
+) -> Result<T, KmdAccountManagerError>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = Result<T, KmdAccountManagerError>>,
+{
+    let wallet_handle_token = kmd_client
+        .init_wallet_handle_token(InitWalletHandleTokenRequest {
+            wallet_id: Some(wallet_id.to_string()),
+            wallet_password: wallet_password.map(str::to_string),
+        })
+        .await
+        .map_err(|e| KmdAccountManagerError::KmdError {
+            message: format!("Failed to unlock wallet {}: {}", wallet_id, e),
+        })?
+        .wallet_handle_token
+        .ok_or_else(|| KmdAccountManagerError::KmdError {
+            message: format!("KMD returned no handle token for wallet {}", wallet_id),
+        })?;
+
+    let result = f(wallet_handle_token.clone()).await;
+
+    if let Err(e) = kmd_client
+        .release_wallet_handle_token(ReleaseWalletHandleTokenRequest {
+            wallet_handle_token: Some(wallet_handle_token),
+        })
+        .await
+    {
+        warn!(
+            "Failed to release the handle token of wallet {}: {}",
+            wallet_id, e
+        );
+    }
+
+    result
+}
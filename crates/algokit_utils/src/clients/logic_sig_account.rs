@@ -0,0 +1,129 @@
+use crate::TransactionSigner;
+use crate::clients::account_manager::SigningAccount;
+use algokit_transact::{Address, LogicSignature, SignedTransaction, Transaction};
+use async_trait::async_trait;
+
+/// An account whose transactions are authorized by a logic signature.
+///
+/// Without a delegator the account is the escrow address of the program. Once delegated, the
+/// account is the delegating account, and transactions whose sender isn't the delegator, e.g. of
+/// accounts rekeyed to it, are authorized as the delegator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicSigAccount {
+    logic_signature: LogicSignature,
+    delegator: Option<Address>,
+}
+
+impl LogicSigAccount {
+    /// Create an escrow account for the compiled program, evaluated with the given arguments.
+    pub fn new(logic: Vec<u8>, args: Option<Vec<Vec<u8>>>) -> Self {
+        Self {
+            logic_signature: LogicSignature::new(logic, args),
+            delegator: None,
+        }
+    }
+
+    /// Delegate the account's transactions to the program by signing it with `account`.
+    pub fn delegate(mut self, account: &SigningAccount) -> Self {
+        self.logic_signature.signature =
+            Some(account.sign_bytes(&self.logic_signature.bytes_to_sign()));
+        self.logic_signature.multisignature = None;
+        self.delegator = Some(account.address());
+        self
+    }
+
+    /// The address the logic signature authorizes transactions for: the delegator when
+    /// delegated, else the escrow address of the program.
+    pub fn address(&self) -> Address {
+        self.delegator
+            .clone()
+            .unwrap_or_else(|| self.logic_signature.address())
+    }
+
+    pub fn is_delegated(&self) -> bool {
+        self.delegator.is_some()
+    }
+
+    pub fn logic_signature(&self) -> &LogicSignature {
+        &self.logic_signature
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for LogicSigAccount {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        let address = self.address();
+
+        indices
+            .iter()
+            .map(|&index| {
+                let transaction = transactions
+                    .get(index)
+                    .ok_or_else(|| format!("Index {} out of bounds for transactions", index))?;
+
+                Ok(SignedTransaction {
+                    transaction: transaction.clone(),
+                    signature: None,
+                    auth_address: (transaction.header().sender != address).then(|| address.clone()),
+                    multisignature: None,
+                    logic_signature: Some(self.logic_signature.clone()),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::TransactionMother;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    // `#pragma version 2; int 1`
+    const PROGRAM: [u8; 5] = [0x02, 0x20, 0x01, 0x01, 0x22];
+
+    #[tokio::test]
+    async fn test_escrow_signs_with_the_program() {
+        let account = LogicSigAccount::new(PROGRAM.to_vec(), None);
+        assert_eq!(account.address(), account.logic_signature().address());
+
+        let mut transaction = TransactionMother::simple_payment().build().unwrap();
+        transaction.header_mut().sender = account.address();
+        let signed = account.sign_transaction(&transaction).await.unwrap();
+
+        assert_eq!(signed.signature, None);
+        assert_eq!(signed.auth_address, None);
+        assert_eq!(
+            signed.logic_signature.as_ref(),
+            Some(account.logic_signature())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delegated_signs_as_the_delegator() {
+        let delegator = SigningAccount::generate();
+        let account =
+            LogicSigAccount::new(PROGRAM.to_vec(), Some(vec![vec![1]])).delegate(&delegator);
+        assert!(account.is_delegated());
+        assert_eq!(account.address(), delegator.address());
+
+        let logic_signature = account.logic_signature();
+        let verifying_key = VerifyingKey::from_bytes(delegator.address().as_bytes()).unwrap();
+        let signature = Signature::from_bytes(&logic_signature.signature.unwrap());
+        assert!(
+            verifying_key
+                .verify(&logic_signature.bytes_to_sign(), &signature)
+                .is_ok()
+        );
+
+        // A transaction of another sender, e.g. an account rekeyed to the delegator
+        let transaction = TransactionMother::simple_payment().build().unwrap();
+        let signed = account.sign_transaction(&transaction).await.unwrap();
+        assert_eq!(signed.auth_address, Some(delegator.address()));
+        assert_eq!(signed.logic_signature.as_ref(), Some(logic_signature));
+    }
+}
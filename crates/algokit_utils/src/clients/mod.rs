@@ -1,19 +1,57 @@
 pub mod account_manager;
+pub mod algod_error;
 pub mod algorand_client;
 pub mod app_manager;
+pub mod arc19;
+pub mod arc26;
+pub mod arc3;
 pub mod asset_manager;
+pub mod asset_metadata;
 pub mod client_manager;
+pub mod dispenser_client;
+pub mod genesis;
+pub mod kmd_account_manager;
+pub mod logic_sig_account;
+pub mod mnemonic;
+pub mod multisig_signer;
 pub mod network_client;
+pub mod node_manager;
+pub(crate) mod state_layout;
+pub mod suggested_params_cache;
 
 // Re-export commonly used client types
-pub use account_manager::AccountManager;
+pub use account_manager::{
+    AccountManager, EnsureFundedParams, EnsureFundedResult, HistoricalTransaction,
+    RekeyAccountParams, RekeyAccountResult, SigningAccount, TransactionHistoryFilters,
+};
+pub use algod_error::AlgodErrorKind;
 pub use algorand_client::AlgorandClient;
 pub use app_manager::{AppManager, AppManagerError};
+pub use arc3::Arc3Metadata;
+pub use arc19::{Arc19Cid, Arc19Codec, Arc19Error};
+pub use arc26::{Arc26Error, Arc26Uri};
 pub use asset_manager::{
-    AssetInformation, AssetManager, AssetManagerError, BulkAssetOptInOutResult,
+    AssetHolder, AssetHoldersSnapshot, AssetHoldersSnapshotOptions, AssetInformation, AssetManager,
+    AssetManagerError, BulkAssetOptInOutResult,
 };
+pub use asset_metadata::{Arc69Metadata, AssetMetadata};
 pub use client_manager::ClientManager;
+pub use dispenser_client::{
+    DEFAULT_DISPENSER_REQUEST_TIMEOUT, DispenserError, DispenserFundResponse,
+    DispenserLimitResponse, TestNetDispenserApiClient, TestNetDispenserApiClientParams,
+};
+pub use genesis::{GenesisAccount, GenesisAccountStatus, GenesisDetails};
+pub use kmd_account_manager::{
+    KmdAccount, KmdAccountManager, KmdAccountManagerError, KmdSigner, KmdWallet,
+    LOCALNET_DEFAULT_WALLET_NAME,
+};
+pub use logic_sig_account::LogicSigAccount;
+pub use multisig_signer::MultisigSigner;
 pub use network_client::{
-    AlgoClientConfig, AlgoConfig, AlgorandNetwork, AlgorandService, NetworkDetails, TokenHeader,
-    genesis_id_is_localnet,
+    AlgoClientConfig, AlgoConfig, AlgorandNetwork, AlgorandService, ConsensusVersion,
+    NetworkDefinition, NetworkDetails, NetworkRegistry, TokenHeader, genesis_id_is_localnet,
+};
+pub use node_manager::{
+    CATCHUP_POLL_INTERVAL, CatchpointProgress, NodeManager, NodeManagerError, NodeStatus,
 };
+pub use suggested_params_cache::{DEFAULT_SUGGESTED_PARAMS_TTL, SuggestedParamsCache};
@@ -0,0 +1,309 @@
+use crate::TransactionSigner;
+use algokit_transact::{Address, MultisigSignature, SignedTransaction, Transaction};
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Signs the transactions of a multisig account with the signers of its participants.
+///
+/// Each participant signer signs the transactions, and the subsignatures it returns are merged
+/// into the multisignature. By default the signers are asked one after the other until the
+/// threshold is met; with concurrent signing all of them are asked at once. A participant signer
+/// that fails doesn't fail the signing as long as the others meet the threshold.
+#[derive(Clone)]
+pub struct MultisigSigner {
+    multisig: MultisigSignature,
+    signers: Vec<(Address, Arc<dyn TransactionSigner>)>,
+    concurrent: bool,
+}
+
+impl MultisigSigner {
+    /// Create a signer for the multisig account, which signs with each of the participant
+    /// `signers`.
+    pub fn new(
+        multisig: MultisigSignature,
+        signers: Vec<(Address, Arc<dyn TransactionSigner>)>,
+    ) -> Self {
+        Self {
+            multisig,
+            signers,
+            concurrent: false,
+        }
+    }
+
+    /// A copy of the signer that asks all the participant signers concurrently, rather than
+    /// sequentially until the threshold is met.
+    pub fn with_concurrent_signing(&self, concurrent: bool) -> Self {
+        Self {
+            concurrent,
+            ..self.clone()
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        self.multisig.clone().into()
+    }
+
+    /// The number of subsignatures a participant provides, which is more than one for weighted
+    /// multisigs where a participant appears several times.
+    fn weight(&self, participant: &Address) -> usize {
+        self.multisig
+            .subsignatures
+            .iter()
+            .filter(|subsig| subsig.address == *participant)
+            .count()
+    }
+
+    async fn collect_signatures<'a>(
+        &'a self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Vec<(&'a Address, Result<Vec<SignedTransaction>, String>)> {
+        if self.concurrent {
+            return join_all(self.signers.iter().map(|(participant, signer)| async move {
+                (
+                    participant,
+                    signer.sign_transactions(transactions, indices).await,
+                )
+            }))
+            .await;
+        }
+
+        let mut results = Vec::with_capacity(self.signers.len());
+        let mut signed_weight = 0;
+        for (participant, signer) in &self.signers {
+            if signed_weight >= self.multisig.threshold as usize {
+                break;
+            }
+            let result = signer.sign_transactions(transactions, indices).await;
+            let signed_all = result.as_ref().is_ok_and(|signed_transactions| {
+                signed_transactions.len() == indices.len()
+                    && signed_transactions.iter().all(|s| s.signature.is_some())
+            });
+            if signed_all {
+                signed_weight += self.weight(participant);
+            }
+            results.push((participant, result));
+        }
+        results
+    }
+
+    /// The multisignatures holding the subsignatures of `participant` from its signed
+    /// transactions.
+    fn participant_multisigs(
+        &self,
+        participant: &Address,
+        signed_transactions: Vec<SignedTransaction>,
+        expected: usize,
+    ) -> Result<Vec<MultisigSignature>, String> {
+        if signed_transactions.len() != expected {
+            return Err(format!(
+                "returned {} signed transactions instead of {}",
+                signed_transactions.len(),
+                expected
+            ));
+        }
+        signed_transactions
+            .into_iter()
+            .map(|signed_transaction| {
+                let signature = signed_transaction
+                    .signature
+                    .ok_or_else(|| "didn't return a signature".to_string())?;
+                self.multisig
+                    .apply_subsignature(participant.clone(), signature)
+                    .map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    fn threshold_not_met(
+        &self,
+        multisig: &MultisigSignature,
+        failures: &HashMap<&Address, String>,
+    ) -> String {
+        let mut unsigned: Vec<&Address> = Vec::new();
+        for subsig in multisig
+            .subsignatures
+            .iter()
+            .filter(|s| s.signature.is_none())
+        {
+            if !unsigned.contains(&&subsig.address) {
+                unsigned.push(&subsig.address);
+            }
+        }
+        let unsigned = unsigned
+            .into_iter()
+            .map(|participant| {
+                let reason = match failures.get(participant) {
+                    Some(failure) => failure.clone(),
+                    None if self
+                        .signers
+                        .iter()
+                        .any(|(address, _)| address == participant) =>
+                    {
+                        "not asked to sign".to_string()
+                    }
+                    None => "no signer".to_string(),
+                };
+                format!("{} ({})", participant, reason)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "Multisig {} requires {} signatures but only {} were collected; participants that didn't sign: {}",
+            self.address(),
+            multisig.threshold,
+            signed_weight(multisig),
+            unsigned
+        )
+    }
+}
+
+fn signed_weight(multisig: &MultisigSignature) -> usize {
+    multisig
+        .subsignatures
+        .iter()
+        .filter(|subsig| subsig.signature.is_some())
+        .count()
+}
+
+#[async_trait]
+impl TransactionSigner for MultisigSigner {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        let transactions_to_sign = indices
+            .iter()
+            .map(|&index| {
+                transactions
+                    .get(index)
+                    .ok_or_else(|| format!("Index {} out of bounds for transactions", index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut multisigs = vec![self.multisig.clone(); indices.len()];
+        let mut failures: HashMap<&Address, String> = HashMap::new();
+        for (participant, result) in self.collect_signatures(transactions, indices).await {
+            let participant_multisigs = result.and_then(|signed_transactions| {
+                self.participant_multisigs(participant, signed_transactions, indices.len())
+            });
+            match participant_multisigs {
+                Ok(participant_multisigs) => {
+                    for (multisig, participant_multisig) in
+                        multisigs.iter_mut().zip(participant_multisigs)
+                    {
+                        *multisig = multisig
+                            .merge(&participant_multisig)
+                            .map_err(|e| e.to_string())?;
+                    }
+                }
+                Err(e) => {
+                    failures.insert(participant, e);
+                }
+            }
+        }
+
+        let address = self.address();
+        transactions_to_sign
+            .into_iter()
+            .zip(multisigs)
+            .map(|(transaction, multisig)| {
+                if signed_weight(&multisig) < multisig.threshold as usize {
+                    return Err(self.threshold_not_met(&multisig, &failures));
+                }
+
+                Ok(SignedTransaction {
+                    transaction: transaction.clone(),
+                    signature: None,
+                    auth_address: (transaction.header().sender != address).then(|| address.clone()),
+                    multisignature: Some(multisig),
+                    logic_signature: None,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::SigningAccount;
+    use algokit_transact::test_utils::TransactionMother;
+
+    struct FailingSigner;
+
+    #[async_trait]
+    impl TransactionSigner for FailingSigner {
+        async fn sign_transactions(
+            &self,
+            _transactions: &[Transaction],
+            _indices: &[usize],
+        ) -> Result<Vec<SignedTransaction>, String> {
+            Err("rejected by the user".to_string())
+        }
+    }
+
+    fn multisig_signer(
+        threshold: u8,
+        accounts: &[SigningAccount],
+        signers: Vec<(Address, Arc<dyn TransactionSigner>)>,
+    ) -> MultisigSigner {
+        let multisig = MultisigSignature::from_participants(
+            1,
+            threshold,
+            accounts.iter().map(SigningAccount::address).collect(),
+        )
+        .unwrap();
+        MultisigSigner::new(multisig, signers)
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_signing_collects_every_signature() {
+        let accounts: Vec<SigningAccount> = (0..3).map(|_| SigningAccount::generate()).collect();
+        let signers = accounts
+            .iter()
+            .map(|account| {
+                (
+                    account.address(),
+                    Arc::new(account.clone()) as Arc<dyn TransactionSigner>,
+                )
+            })
+            .collect();
+        let signer = multisig_signer(2, &accounts, signers);
+        let mut transaction = TransactionMother::simple_payment().build().unwrap();
+        transaction.header_mut().sender = signer.address();
+
+        let sequential = signer.sign_transaction(&transaction).await.unwrap();
+        assert_eq!(signed_weight(&sequential.multisignature.unwrap()), 2);
+
+        let concurrent = signer
+            .with_concurrent_signing(true)
+            .sign_transaction(&transaction)
+            .await
+            .unwrap();
+        assert_eq!(concurrent.auth_address, None);
+        assert_eq!(signed_weight(&concurrent.multisignature.unwrap()), 3);
+    }
+
+    #[tokio::test]
+    async fn test_threshold_not_met_lists_unsigned_participants() {
+        let accounts: Vec<SigningAccount> = (0..3).map(|_| SigningAccount::generate()).collect();
+        let signers: Vec<(Address, Arc<dyn TransactionSigner>)> = vec![
+            (accounts[0].address(), Arc::new(accounts[0].clone())),
+            (accounts[1].address(), Arc::new(FailingSigner)),
+        ];
+        let signer = multisig_signer(2, &accounts, signers);
+        let transaction = TransactionMother::simple_payment().build().unwrap();
+
+        let error = signer.sign_transaction(&transaction).await.unwrap_err();
+
+        assert!(error.contains("requires 2 signatures but only 1 were collected"));
+        assert!(error.contains(&format!("{} (rejected by the user)", accounts[1].address())));
+        assert!(error.contains(&format!("{} (no signer)", accounts[2].address())));
+        assert!(!error.contains(&accounts[0].address().to_string()));
+    }
+}
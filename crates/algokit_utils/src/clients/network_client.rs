@@ -1,4 +1,6 @@
+use algokit_http_client::RateLimiter;
 use std::collections::HashMap;
+use std::sync::RwLock;
 
 #[derive(Debug, Clone)]
 pub enum TokenHeader {
@@ -83,6 +85,9 @@ pub struct AlgoClientConfig {
     pub port: Option<u16>,
     /// Optional token to use for API authentication
     pub token: Option<TokenHeader>,
+    /// Optional limit of the request rate, e.g. of a free tier API provider, which may be shared
+    /// with the clients of other services of the same provider
+    pub rate_limiter: Option<RateLimiter>,
 }
 
 /// Configuration for algod, indexer and kmd clients.
@@ -117,6 +122,8 @@ pub struct NetworkDetails {
     pub is_localnet: bool,
     pub genesis_id: String,
     pub genesis_hash: String,
+    /// The name of the registered network matching the genesis, see [`NetworkRegistry`]
+    pub network_name: Option<String>,
 }
 
 impl NetworkDetails {
@@ -124,6 +131,8 @@ impl NetworkDetails {
         let is_localnet = genesis_id_is_localnet(&genesis_id);
         let is_testnet = genesis_id == "testnet-v1.0";
         let is_mainnet = genesis_id == "mainnet-v1.0";
+        let network_name =
+            NetworkRegistry::find(&genesis_id, &genesis_hash).map(|network| network.name);
 
         Self {
             is_testnet,
@@ -131,10 +140,162 @@ impl NetworkDetails {
             is_localnet,
             genesis_id,
             genesis_hash,
+            network_name,
         }
     }
 }
 
+/// A named network beyond LocalNet, TestNet and MainNet, e.g. BetaNet, FNet, Voi or a private
+/// network, registered with [`NetworkRegistry::register`].
+#[derive(Debug, Clone)]
+pub struct NetworkDefinition {
+    /// The name of the network, which is also looked up in the ARC-56 `networks` of app specs
+    pub name: String,
+    pub genesis_id: String,
+    /// The base64 genesis hash, to tell apart networks that share a genesis ID
+    pub genesis_hash: Option<String>,
+    /// How to connect to the network, to create clients for it by name
+    pub config: Option<AlgoConfig>,
+}
+
+impl NetworkDefinition {
+    /// Whether a network with the given genesis is this network.
+    pub fn matches(&self, genesis_id: &str, genesis_hash: &str) -> bool {
+        self.genesis_id == genesis_id
+            && self
+                .genesis_hash
+                .as_ref()
+                .is_none_or(|hash| hash == genesis_hash)
+    }
+}
+
+static NETWORKS: RwLock<Vec<NetworkDefinition>> = RwLock::new(Vec::new());
+
+/// Global registry of custom networks.
+///
+/// Registered networks are matched by genesis when a client resolves its [`NetworkDetails`],
+/// which clients cache, so register networks before creating the clients that connect to them.
+pub struct NetworkRegistry;
+
+impl NetworkRegistry {
+    /// Register a network, replacing any network registered under the same name.
+    pub fn register(network: NetworkDefinition) {
+        let mut networks = NETWORKS.write().unwrap();
+        networks.retain(|registered| registered.name != network.name);
+        networks.push(network);
+    }
+
+    /// Unregister the network named `name`, returning it if it was registered.
+    pub fn unregister(name: &str) -> Option<NetworkDefinition> {
+        let mut networks = NETWORKS.write().unwrap();
+        let index = networks.iter().position(|network| network.name == name)?;
+        Some(networks.remove(index))
+    }
+
+    pub fn get(name: &str) -> Option<NetworkDefinition> {
+        NETWORKS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|network| network.name == name)
+            .cloned()
+    }
+
+    /// Find the registered network with the given genesis, preferring networks registered with
+    /// a genesis hash over those matching on the genesis ID alone.
+    pub fn find(genesis_id: &str, genesis_hash: &str) -> Option<NetworkDefinition> {
+        let networks = NETWORKS.read().unwrap();
+        networks
+            .iter()
+            .filter(|network| network.matches(genesis_id, genesis_hash))
+            .max_by_key(|network| network.genesis_hash.is_some())
+            .cloned()
+    }
+
+    /// All registered networks, in registration order.
+    pub fn all() -> Vec<NetworkDefinition> {
+        NETWORKS.read().unwrap().clone()
+    }
+}
+
+/// The consensus protocol a network runs, from the `consensus-version` of its suggested params.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsensusVersion {
+    /// The next, unreleased protocol (vNext), which localnet can be configured to run
+    Future,
+    /// A released protocol, identified by its upgrade URL
+    Released(String),
+}
+
+impl ConsensusVersion {
+    /// The consensus version of the next, unreleased protocol.
+    pub const FUTURE: &'static str = "future";
+
+    pub fn new(consensus_version: &str) -> Self {
+        if consensus_version == Self::FUTURE {
+            Self::Future
+        } else {
+            Self::Released(consensus_version.to_string())
+        }
+    }
+
+    /// Returns true if app calls can declare their resources with an access list.
+    ///
+    /// Access lists are only enabled in the next protocol, released protocols use the
+    /// reference arrays.
+    pub fn supports_access_lists(&self) -> bool {
+        matches!(self, Self::Future)
+    }
+}
+
 pub fn genesis_id_is_localnet(genesis_id: &str) -> bool {
     genesis_id == "devnet-v1" || genesis_id == "sandnet-v1" || genesis_id == "dockernet-v1"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network(name: &str, genesis_id: &str, genesis_hash: Option<&str>) -> NetworkDefinition {
+        NetworkDefinition {
+            name: name.to_string(),
+            genesis_id: genesis_id.to_string(),
+            genesis_hash: genesis_hash.map(str::to_string),
+            config: None,
+        }
+    }
+
+    #[test]
+    fn test_registered_network_matches_genesis() {
+        NetworkRegistry::register(network("test-private", "test-private-v1", None));
+        NetworkRegistry::register(network(
+            "test-private-fork",
+            "test-private-v1",
+            Some("Zm9yaw=="),
+        ));
+
+        let details = NetworkDetails::new("test-private-v1".to_string(), "Zm9yaw==".to_string());
+        assert_eq!(details.network_name.as_deref(), Some("test-private-fork"));
+        let details = NetworkDetails::new("test-private-v1".to_string(), "b3RoZXI=".to_string());
+        assert_eq!(details.network_name.as_deref(), Some("test-private"));
+
+        assert!(NetworkRegistry::unregister("test-private-fork").is_some());
+        assert!(NetworkRegistry::unregister("test-private-fork").is_none());
+        let details = NetworkDetails::new("test-private-v1".to_string(), "Zm9yaw==".to_string());
+        assert_eq!(details.network_name.as_deref(), Some("test-private"));
+        NetworkRegistry::unregister("test-private");
+    }
+
+    #[test]
+    fn test_register_replaces_network_with_same_name() {
+        NetworkRegistry::register(network("test-replaced", "first-v1", None));
+        NetworkRegistry::register(network("test-replaced", "second-v1", None));
+
+        assert_eq!(
+            NetworkRegistry::get("test-replaced").unwrap().genesis_id,
+            "second-v1"
+        );
+        assert!(NetworkRegistry::find("first-v1", "").is_none());
+        NetworkRegistry::unregister("test-replaced");
+    }
+}
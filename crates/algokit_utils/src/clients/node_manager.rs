@@ -0,0 +1,266 @@
+use crate::clients::algod_error::AlgodErrorKind;
+use algod_client::AlgodClient;
+use algod_client::apis::Error as AlgodError;
+use algod_client::models::{GetStatus, WaitForBlock};
+use snafu::Snafu;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often [`NodeManager::wait_until_caught_up`] polls the status of the node.
+pub const CATCHUP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Snafu)]
+pub enum NodeManagerError {
+    #[snafu(display("Algod client error: {source}"))]
+    AlgodClientError { source: AlgodErrorKind },
+
+    #[snafu(display("Catchup timeout: {message}"))]
+    CatchupTimeout { message: String },
+
+    #[snafu(display("Unsupported consensus version: {message}"))]
+    UnsupportedConsensusVersion { message: String },
+}
+
+impl From<AlgodError> for NodeManagerError {
+    fn from(error: AlgodError) -> Self {
+        NodeManagerError::AlgodClientError {
+            source: error.into(),
+        }
+    }
+}
+
+/// The progress of a catchpoint catchup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatchpointProgress {
+    /// The catchpoint being caught up to, e.g. `5000000#ABC...`
+    pub catchpoint: String,
+    pub total_accounts: u64,
+    pub processed_accounts: u64,
+    pub verified_accounts: u64,
+    pub total_kvs: u64,
+    pub processed_kvs: u64,
+    pub verified_kvs: u64,
+    pub total_blocks: u64,
+    pub acquired_blocks: u64,
+}
+
+/// The status of an algod node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeStatus {
+    /// The last round the node has seen
+    pub last_round: u64,
+    /// The consensus version of the last round
+    pub last_version: String,
+    /// The consensus version which applies from `next_version_round`
+    pub next_version: String,
+    pub next_version_round: u64,
+    /// Whether the node stopped, as it doesn't support the consensus version of the next round
+    pub stopped_at_unsupported_round: bool,
+    pub time_since_last_round: Duration,
+    /// How long the node has been catching up, zero once it's caught up
+    pub catchup_time: Duration,
+    /// The last catchpoint the node has seen, if any
+    pub last_catchpoint: Option<String>,
+    /// The catchpoint catchup in progress, if any
+    pub catchpoint: Option<CatchpointProgress>,
+}
+
+impl NodeStatus {
+    /// Whether the node is neither syncing rounds nor catching up to a catchpoint.
+    pub fn is_caught_up(&self) -> bool {
+        self.catchup_time.is_zero() && self.catchpoint.is_none()
+    }
+}
+
+// `GetStatus` and `WaitForBlock` are distinct generated models of the same node status
+macro_rules! impl_from_status {
+    ($($status:ty),*) => {
+        $(
+            impl From<$status> for NodeStatus {
+                fn from(status: $status) -> Self {
+                    // Algod responds with empty catchpoints rather than omitting them
+                    let non_empty = |value: Option<String>| value.filter(|value| !value.is_empty());
+                    NodeStatus {
+                        last_round: status.last_round,
+                        last_version: status.last_version,
+                        next_version: status.next_version,
+                        next_version_round: status.next_version_round,
+                        stopped_at_unsupported_round: status.stopped_at_unsupported_round,
+                        time_since_last_round: Duration::from_nanos(status.time_since_last_round),
+                        catchup_time: Duration::from_nanos(status.catchup_time),
+                        last_catchpoint: non_empty(status.last_catchpoint),
+                        catchpoint: non_empty(status.catchpoint).map(|catchpoint| {
+                            CatchpointProgress {
+                                catchpoint,
+                                total_accounts: status.catchpoint_total_accounts.unwrap_or_default(),
+                                processed_accounts: status
+                                    .catchpoint_processed_accounts
+                                    .unwrap_or_default(),
+                                verified_accounts: status
+                                    .catchpoint_verified_accounts
+                                    .unwrap_or_default(),
+                                total_kvs: status.catchpoint_total_kvs.unwrap_or_default(),
+                                processed_kvs: status.catchpoint_processed_kvs.unwrap_or_default(),
+                                verified_kvs: status.catchpoint_verified_kvs.unwrap_or_default(),
+                                total_blocks: status.catchpoint_total_blocks.unwrap_or_default(),
+                                acquired_blocks: status
+                                    .catchpoint_acquired_blocks
+                                    .unwrap_or_default(),
+                            }
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_status!(GetStatus, WaitForBlock);
+
+/// Status, readiness and catchup helpers for an algod node, e.g. to wait for LocalNet to start or
+/// to check the health of a node.
+#[derive(Clone)]
+pub struct NodeManager {
+    algod_client: Arc<AlgodClient>,
+}
+
+impl NodeManager {
+    pub fn new(algod_client: Arc<AlgodClient>) -> Self {
+        Self { algod_client }
+    }
+
+    /// Get the current status of the node.
+    pub async fn status(&self) -> Result<NodeStatus, NodeManagerError> {
+        Ok(self.algod_client.get_status().await?.into())
+    }
+
+    /// Get the status of the node once it has seen a round after `round`.
+    ///
+    /// Algod responds after a timeout of about a minute if no such round is seen, so the returned
+    /// status may still be at `round`.
+    pub async fn status_after_round(&self, round: u64) -> Result<NodeStatus, NodeManagerError> {
+        Ok(self.algod_client.wait_for_block(round).await?.into())
+    }
+
+    /// Whether the node is healthy and caught up, i.e. ready to serve requests.
+    pub async fn ready(&self) -> Result<bool, NodeManagerError> {
+        match self.algod_client.get_ready().await {
+            Ok(()) => Ok(true),
+            Err(e) => match AlgodErrorKind::from(e) {
+                // Algod responds with a 503 until it's caught up
+                AlgodErrorKind::Unavailable {
+                    status: Some(503), ..
+                } => Ok(false),
+                source => Err(NodeManagerError::AlgodClientError { source }),
+            },
+        }
+    }
+
+    /// Start a fast catchup of the node to `catchpoint`, returning the message of algod.
+    ///
+    /// Requires the admin API token of the node.
+    #[cfg(feature = "admin")]
+    pub async fn start_catchup(&self, catchpoint: &str) -> Result<String, NodeManagerError> {
+        Ok(self
+            .algod_client
+            .start_catchup(catchpoint, None)
+            .await?
+            .catchup_message)
+    }
+
+    /// Wait until the node is caught up and ready, returning its status.
+    ///
+    /// Fails if the node isn't ready within `timeout`, or if it stopped at a round with an
+    /// unsupported consensus version, as it won't catch up without an upgrade.
+    pub async fn wait_until_caught_up(
+        &self,
+        timeout: Duration,
+    ) -> Result<NodeStatus, NodeManagerError> {
+        let wait = async {
+            loop {
+                let status = self.status().await?;
+                if status.stopped_at_unsupported_round {
+                    return Err(NodeManagerError::UnsupportedConsensusVersion {
+                        message: format!(
+                            "Stopped at round {} before consensus version {}",
+                            status.last_round, status.next_version
+                        ),
+                    });
+                }
+                if status.is_caught_up() && self.ready().await? {
+                    return Ok(status);
+                }
+                tokio::time::sleep(CATCHUP_POLL_INTERVAL).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait)
+            .await
+            .map_err(|_| NodeManagerError::CatchupTimeout {
+                message: format!("Not caught up within {:?}", timeout),
+            })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn status(overrides: serde_json::Value) -> GetStatus {
+        let mut status = json!({
+            "catchup-time": 0,
+            "last-round": 1000,
+            "last-version": "future",
+            "next-version": "future",
+            "next-version-round": 1001,
+            "next-version-supported": true,
+            "stopped-at-unsupported-round": false,
+            "time-since-last-round": 1_500_000_000u64,
+            "last-catchpoint": "",
+            "catchpoint": "",
+        });
+        status
+            .as_object_mut()
+            .unwrap()
+            .extend(overrides.as_object().unwrap().clone());
+        serde_json::from_value(status).unwrap()
+    }
+
+    #[test]
+    fn test_caught_up_status() {
+        let status = NodeStatus::from(status(json!({})));
+
+        assert!(status.is_caught_up());
+        assert_eq!(status.last_round, 1000);
+        assert_eq!(status.time_since_last_round, Duration::from_millis(1500));
+        assert_eq!(status.last_catchpoint, None);
+        assert_eq!(status.catchpoint, None);
+    }
+
+    #[test]
+    fn test_syncing_status() {
+        let status = NodeStatus::from(status(json!({ "catchup-time": 2_000_000_000u64 })));
+
+        assert!(!status.is_caught_up());
+        assert_eq!(status.catchup_time, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_catchpoint_catchup_status() {
+        let status = NodeStatus::from(status(json!({
+            "catchpoint": "5000000#ABC",
+            "catchpoint-total-accounts": 100,
+            "catchpoint-processed-accounts": 40,
+            "catchpoint-total-blocks": 1000,
+        })));
+
+        assert!(!status.is_caught_up());
+        let progress = status.catchpoint.unwrap();
+        assert_eq!(progress.catchpoint, "5000000#ABC");
+        assert_eq!(progress.total_accounts, 100);
+        assert_eq!(progress.processed_accounts, 40);
+        assert_eq!(progress.verified_accounts, 0);
+        assert_eq!(progress.total_blocks, 1000);
+    }
+}
@@ -0,0 +1,108 @@
+use algokit_abi::arc56_contract::{ABIStorageKey, ABIStorageMap};
+use algokit_abi::{ABIError, ABIType, ABIValue};
+use base64::{Engine, engine::general_purpose::STANDARD as Base64};
+use std::collections::HashMap;
+
+/// The ARC-56 keys and maps of a state, used to name and decode its raw entries.
+///
+/// Values of declared keys must decode with their ARC-56 value type: a value that doesn't is an
+/// error rather than being left undecoded, as it means the app spec doesn't describe the app.
+pub(crate) struct StateLayout {
+    keys: HashMap<Vec<u8>, (String, ABIStorageKey)>,
+    /// Sorted by descending prefix length, so the most specific prefix matches first
+    maps: Vec<(Vec<u8>, String, ABIStorageMap)>,
+}
+
+/// A raw key described by a [`StateLayout`].
+pub(crate) struct StateEntry<'a> {
+    /// The name of the ARC-56 key, or of the ARC-56 map the key belongs to
+    pub name: &'a str,
+    /// The key within its ARC-56 map, decoded with the map's key type
+    pub map_key: Option<ABIValue>,
+    pub value_type: &'a ABIType,
+}
+
+/// A raw state value, before decoding.
+pub(crate) enum RawStateValue<'a> {
+    Uint(u64),
+    Bytes(&'a [u8]),
+}
+
+impl StateLayout {
+    pub fn new(
+        keys: HashMap<String, ABIStorageKey>,
+        maps: HashMap<String, ABIStorageMap>,
+    ) -> Result<Self, ABIError> {
+        let decode_base64 = |value: &str, name: &str| {
+            Base64.decode(value).map_err(|e| ABIError::DecodingError {
+                message: format!("Failed to decode storage key '{}': {}", name, e),
+            })
+        };
+
+        let mut layout = Self {
+            keys: HashMap::new(),
+            maps: Vec::new(),
+        };
+        for (name, storage_key) in keys {
+            let key = decode_base64(&storage_key.key, &name)?;
+            layout.keys.insert(key, (name, storage_key));
+        }
+        for (name, storage_map) in maps {
+            let prefix = match &storage_map.prefix {
+                Some(prefix) => decode_base64(prefix, &name)?,
+                None => Vec::new(),
+            };
+            layout.maps.push((prefix, name, storage_map));
+        }
+        layout
+            .maps
+            .sort_by(|(a, ..), (b, ..)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        Ok(layout)
+    }
+
+    /// The ARC-56 key or map entry of `key_raw`, if it's declared.
+    ///
+    /// Maps whose key type doesn't decode the rest of the key are skipped.
+    pub fn describe(&self, key_raw: &[u8]) -> Option<StateEntry<'_>> {
+        if let Some((name, storage_key)) = self.keys.get(key_raw) {
+            return Some(StateEntry {
+                name,
+                map_key: None,
+                value_type: &storage_key.value_type,
+            });
+        }
+        self.maps.iter().find_map(|(prefix, name, storage_map)| {
+            let tail = key_raw.strip_prefix(prefix.as_slice())?;
+            let map_key = storage_map.key_type.decode(tail).ok()?;
+            Some(StateEntry {
+                name,
+                map_key: Some(map_key),
+                value_type: &storage_map.value_type,
+            })
+        })
+    }
+
+    /// Decode a raw value with the value type of `entry`, or as is if the key isn't declared.
+    ///
+    /// Uint values are always decoded as [`ABIValue::Uint`], as their type is implied by the AVM.
+    pub fn decode_value(
+        entry: Option<&StateEntry>,
+        value: RawStateValue,
+    ) -> Result<ABIValue, ABIError> {
+        match (value, entry) {
+            (RawStateValue::Uint(uint), _) => Ok(ABIValue::from(uint)),
+            (RawStateValue::Bytes(bytes), Some(entry)) => {
+                entry
+                    .value_type
+                    .decode(bytes)
+                    .map_err(|e| ABIError::DecodingError {
+                        message: format!(
+                            "Failed to decode the value of '{}' as {}: {}",
+                            entry.name, entry.value_type, e
+                        ),
+                    })
+            }
+            (RawStateValue::Bytes(bytes), None) => Ok(ABIValue::Bytes(bytes.to_vec())),
+        }
+    }
+}
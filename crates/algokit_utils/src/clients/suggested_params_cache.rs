@@ -0,0 +1,79 @@
+use algod_client::{AlgodClient, apis::Error as AlgodError, models::TransactionParams};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// How long suggested params are reused for by default.
+pub const DEFAULT_SUGGESTED_PARAMS_TTL: Duration = Duration::from_secs(3);
+
+/// A time-bounded cache of the suggested params of a node, shared by the composers of a client
+/// so building many groups doesn't request `/v2/transactions/params` for each of them.
+pub struct SuggestedParamsCache {
+    algod_client: Arc<AlgodClient>,
+    ttl_millis: AtomicU64,
+    cached: Mutex<Option<(Instant, TransactionParams)>>,
+}
+
+impl SuggestedParamsCache {
+    pub fn new(algod_client: Arc<AlgodClient>, ttl: Duration) -> Self {
+        Self {
+            algod_client,
+            ttl_millis: AtomicU64::new(ttl.as_millis() as u64),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// How long fetched params are reused for.
+    pub fn ttl(&self) -> Duration {
+        Duration::from_millis(self.ttl_millis.load(Ordering::Relaxed))
+    }
+
+    /// Set how long fetched params are reused for, a zero TTL disables caching.
+    pub fn set_ttl(&self, ttl: Duration) {
+        self.ttl_millis
+            .store(ttl.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Get the suggested params, fetching them from the node when the cached params are older
+    /// than the TTL.
+    ///
+    /// Concurrent callers wait for a single request rather than each fetching the params.
+    pub async fn get(&self) -> Result<TransactionParams, AlgodError> {
+        let ttl = self.ttl();
+        let mut cached = self.cached.lock().await;
+        if let Some((fetched_at, params)) = cached.as_ref() {
+            if fetched_at.elapsed() < ttl {
+                return Ok(params.clone());
+            }
+        }
+
+        let params = self.algod_client.transaction_params().await?;
+        if !ttl.is_zero() {
+            *cached = Some((Instant::now(), params.clone()));
+        }
+        Ok(params)
+    }
+
+    /// Discard the cached params, so the next call fetches them from the node.
+    pub async fn clear(&self) {
+        *self.cached.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_ttl() {
+        let cache = SuggestedParamsCache::new(
+            Arc::new(AlgodClient::testnet()),
+            DEFAULT_SUGGESTED_PARAMS_TTL,
+        );
+        assert_eq!(cache.ttl(), Duration::from_secs(3));
+
+        cache.set_ttl(Duration::ZERO);
+        assert!(cache.ttl().is_zero());
+    }
+}
@@ -1,6 +1,11 @@
+use algokit_abi::ProgramSourceMap;
+use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
 
 /// Minimal lifecycle event types
@@ -10,14 +15,26 @@ pub enum EventType {
     AppCompiled,
     /// Emitted when a transaction group is simulated (for AVM traces)
     TxnGroupSimulated,
+    /// Emitted as long-running operations make progress (only when progress reporting is enabled)
+    Progress,
+    /// Emitted when an app deployment completes, whatever operation it performed
+    AppDeployed,
+    /// Emitted when a transaction composer builds its group
+    GroupBuilt,
+    /// Emitted when a transaction group is submitted to algod
+    GroupSent,
+    /// Emitted when all transactions of a sent group are confirmed
+    GroupConfirmed,
+    /// Emitted before an operation that failed is retried
+    Retry,
 }
 
 /// Minimal event payloads
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppCompiledEventData {
     pub app_name: Option<String>,
-    pub approval_source_map: Option<serde_json::Value>,
-    pub clear_source_map: Option<serde_json::Value>,
+    pub approval_source_map: Option<ProgramSourceMap>,
+    pub clear_source_map: Option<ProgramSourceMap>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,37 +42,267 @@ pub struct TxnGroupSimulatedEventData {
     pub simulate_response: serde_json::Value,
 }
 
+/// Long-running operations that report progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProgressOperation {
+    /// Opting an account into a set of assets
+    BulkAssetOptIn,
+    /// Opting an account out of a set of assets
+    BulkAssetOptOut,
+    /// Paging through the holders of an asset
+    AssetHoldersSnapshot,
+    /// Waiting for the transactions of a sent group to be confirmed
+    TransactionGroupConfirmation,
+    /// Sending the groups of a batch of transactions
+    BatchSend,
+    /// Syncing the rounds a subscriber is behind the tip
+    SubscriberCatchup,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressEventData {
+    pub operation: ProgressOperation,
+    pub processed: u64,
+    /// The total number of items, if known up front
+    pub total: Option<u64>,
+    pub elapsed: Duration,
+    /// Estimated time remaining, extrapolated from the current rate
+    pub eta: Option<Duration>,
+}
+
+/// The operation performed by an app deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AppDeployOperation {
+    Create,
+    Update,
+    Replace,
+    Nothing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppDeployedEventData {
+    pub app_name: String,
+    pub version: String,
+    pub app_id: u64,
+    pub operation: AppDeployOperation,
+}
+
+/// A transaction group, identified by its base64 group ID (if grouped) and transaction IDs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxnGroupEventData {
+    pub group_id: Option<String>,
+    pub transaction_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxnGroupConfirmedEventData {
+    pub group_id: Option<String>,
+    pub transaction_ids: Vec<String>,
+    /// The highest round any transaction of the group was confirmed in
+    pub confirmed_round: Option<u64>,
+}
+
+/// Operations that are retried after failures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RetryOperation {
+    /// Resending an app create transaction whose outcome is unknown, see
+    /// [`CreateRetryParams`](crate::applications::CreateRetryParams)
+    AppCreate,
+    /// Resending a group after a transient algod failure, see
+    /// [`RetryPolicy`](crate::transactions::RetryPolicy)
+    Send,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryEventData {
+    pub operation: RetryOperation,
+    /// The 1-based number of the retry about to be made
+    pub attempt: u32,
+    pub max_retries: u32,
+    /// The error that caused the retry
+    pub error: String,
+}
+
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum EventData {
     AppCompiled(AppCompiledEventData),
     TxnGroupSimulated(TxnGroupSimulatedEventData),
+    Progress(ProgressEventData),
+    AppDeployed(AppDeployedEventData),
+    GroupBuilt(TxnGroupEventData),
+    GroupSent(TxnGroupEventData),
+    GroupConfirmed(TxnGroupConfirmedEventData),
+    Retry(RetryEventData),
+}
+
+/// An observer of lifecycle events, registered with [`Config::register_observer`].
+///
+/// Observers are called in registration order, on the task emitting the event, so they should
+/// return quickly and hand off slow work (e.g. to a channel).
+#[async_trait]
+pub trait EventObserver: Send + Sync {
+    async fn on_event(&self, event_type: EventType, data: &EventData);
 }
 
-/// Async event emitter using Tokio broadcast
+/// An [`EventObserver`] that forwards events to `tracing` under the `algokit_utils` target:
+/// retries as warnings, progress and simulate traces at debug level and the rest as info.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingObserver;
+
+#[cfg(feature = "tracing")]
+#[async_trait]
+impl EventObserver for TracingObserver {
+    async fn on_event(&self, event_type: EventType, data: &EventData) {
+        match data {
+            EventData::Retry(retry) => tracing::warn!(
+                target: "algokit_utils",
+                event = ?event_type,
+                operation = ?retry.operation,
+                attempt = retry.attempt,
+                max_retries = retry.max_retries,
+                error = %retry.error,
+            ),
+            EventData::Progress(_) | EventData::TxnGroupSimulated(_) => {
+                tracing::debug!(target: "algokit_utils", event = ?event_type, data = ?data)
+            }
+            _ => tracing::info!(target: "algokit_utils", event = ?event_type, data = ?data),
+        }
+    }
+}
+
+/// Identifies a registered [`EventObserver`], to unregister it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObserverId(u64);
+
+type Observers = Vec<(ObserverId, Arc<dyn EventObserver>)>;
+
+/// Async event emitter using Tokio broadcast, which also calls the registered observers
 #[derive(Clone)]
 pub struct AsyncEventEmitter {
     sender: broadcast::Sender<(EventType, EventData)>,
+    observers: Arc<RwLock<Observers>>,
+    next_observer_id: Arc<AtomicU64>,
 }
 
 impl AsyncEventEmitter {
     pub fn new(buffer: usize) -> Self {
         let (sender, _receiver) = broadcast::channel(buffer);
-        Self { sender }
+        Self {
+            sender,
+            observers: Arc::new(RwLock::new(Vec::new())),
+            next_observer_id: Arc::new(AtomicU64::new(0)),
+        }
     }
 
     pub fn subscribe(&self) -> broadcast::Receiver<(EventType, EventData)> {
         self.sender.subscribe()
     }
 
+    pub fn register_observer(&self, observer: Arc<dyn EventObserver>) -> ObserverId {
+        let id = ObserverId(self.next_observer_id.fetch_add(1, Ordering::Relaxed));
+        self.observers.write().unwrap().push((id, observer));
+        id
+    }
+
+    /// Unregister an observer, returning whether it was registered.
+    pub fn unregister_observer(&self, id: ObserverId) -> bool {
+        let mut observers = self.observers.write().unwrap();
+        let count = observers.len();
+        observers.retain(|(observer_id, _)| *observer_id != id);
+        observers.len() != count
+    }
+
+    /// Whether anything receives emitted events, so emitters can skip building event data
+    /// nobody reads.
+    pub fn has_listeners(&self) -> bool {
+        self.sender.receiver_count() > 0 || !self.observers.read().unwrap().is_empty()
+    }
+
     pub async fn emit(&self, event_type: EventType, data: EventData) {
+        // Snapshot the observers so none are held locked across an await
+        let observers: Vec<Arc<dyn EventObserver>> = self
+            .observers
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(_, observer)| observer.clone())
+            .collect();
+        for observer in observers {
+            observer.on_event(event_type, &data).await;
+        }
+
         // Ignore error if there are no subscribers
         let _ = self.sender.send((event_type, data));
     }
 }
 
+impl Default for AsyncEventEmitter {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+/// Tracks and emits progress events for a long-running operation
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    operation: ProgressOperation,
+    processed: u64,
+    total: Option<u64>,
+    started_at: Instant,
+}
+
+impl ProgressReporter {
+    pub fn new(operation: ProgressOperation, total: Option<u64>) -> Self {
+        Self {
+            operation,
+            processed: 0,
+            total,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Emit the current progress without advancing, e.g. when the operation starts.
+    pub async fn report(&self) {
+        if Config::progress() {
+            Config::events()
+                .emit(EventType::Progress, EventData::Progress(self.snapshot()))
+                .await;
+        }
+    }
+
+    /// Record that `count` more items have been processed and emit a progress event.
+    pub async fn advance(&mut self, count: u64) {
+        self.processed = self.processed.saturating_add(count);
+        self.report().await;
+    }
+
+    pub fn snapshot(&self) -> ProgressEventData {
+        let elapsed = self.started_at.elapsed();
+        let eta = match self.total {
+            Some(total) if self.processed > 0 => {
+                let remaining = total.saturating_sub(self.processed);
+                Some(elapsed.mul_f64(remaining as f64 / self.processed as f64))
+            }
+            _ => None,
+        };
+
+        ProgressEventData {
+            operation: self.operation,
+            processed: self.processed,
+            total: self.total,
+            elapsed,
+            eta,
+        }
+    }
+}
+
 /// Global flags and event emitter
 static DEBUG: AtomicBool = AtomicBool::new(false);
 static TRACE_ALL: AtomicBool = AtomicBool::new(false);
+static PROGRESS: AtomicBool = AtomicBool::new(false);
+static PROJECT_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
 static EVENTS: Lazy<AsyncEventEmitter> = Lazy::new(|| AsyncEventEmitter::new(32));
 
 /// Global runtime config singleton
@@ -70,10 +317,39 @@ impl Config {
         TRACE_ALL.load(Ordering::Relaxed)
     }
 
+    pub fn progress() -> bool {
+        PROGRESS.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable emitting `EventType::Progress` events for long-running operations.
+    pub fn configure_progress(enabled: bool) {
+        PROGRESS.store(enabled, Ordering::Relaxed);
+    }
+
+    /// The project root that debugging artifacts are written to, see [`crate::debugging`].
+    pub fn project_root() -> Option<PathBuf> {
+        PROJECT_ROOT.read().unwrap().clone()
+    }
+
+    /// Set the project root to persist source maps and AVM traces to while `debug` is enabled,
+    /// or `None` to stop persisting them.
+    pub fn configure_project_root(project_root: Option<PathBuf>) {
+        *PROJECT_ROOT.write().unwrap() = project_root;
+    }
+
     pub fn events() -> AsyncEventEmitter {
         EVENTS.clone()
     }
 
+    /// Register an observer of all emitted events, see [`EventObserver`].
+    pub fn register_observer(observer: Arc<dyn EventObserver>) -> ObserverId {
+        EVENTS.register_observer(observer)
+    }
+
+    pub fn unregister_observer(id: ObserverId) -> bool {
+        EVENTS.unregister_observer(id)
+    }
+
     pub fn configure(new_debug: Option<bool>, new_trace_all: Option<bool>) {
         if let Some(d) = new_debug {
             DEBUG.store(d, Ordering::Relaxed);
@@ -83,3 +359,80 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_progress_snapshot_eta() {
+        let mut reporter = ProgressReporter::new(ProgressOperation::BulkAssetOptIn, Some(4));
+        assert!(reporter.snapshot().eta.is_none());
+
+        reporter.started_at = Instant::now() - Duration::from_secs(2);
+        reporter.processed = 1;
+        let snapshot = reporter.snapshot();
+        assert_eq!(snapshot.processed, 1);
+        assert_eq!(snapshot.total, Some(4));
+        let eta = snapshot.eta.unwrap();
+        assert!(eta >= Duration::from_secs(6) && eta < Duration::from_secs(7));
+
+        reporter.processed = 4;
+        assert_eq!(reporter.snapshot().eta, Some(Duration::ZERO));
+    }
+
+    struct RecordingObserver {
+        events: Mutex<Vec<EventType>>,
+    }
+
+    #[async_trait]
+    impl EventObserver for RecordingObserver {
+        async fn on_event(&self, event_type: EventType, _data: &EventData) {
+            self.events.lock().unwrap().push(event_type);
+        }
+    }
+
+    fn retry_event() -> EventData {
+        EventData::Retry(RetryEventData {
+            operation: RetryOperation::AppCreate,
+            attempt: 1,
+            max_retries: 3,
+            error: "connection reset".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_observers_receive_events_until_unregistered() {
+        let emitter = AsyncEventEmitter::default();
+        assert!(!emitter.has_listeners());
+
+        let first = Arc::new(RecordingObserver {
+            events: Mutex::new(Vec::new()),
+        });
+        let second = Arc::new(RecordingObserver {
+            events: Mutex::new(Vec::new()),
+        });
+        let first_id = emitter.register_observer(first.clone());
+        emitter.register_observer(second.clone());
+        assert!(emitter.has_listeners());
+
+        emitter.emit(EventType::Retry, retry_event()).await;
+        assert!(emitter.unregister_observer(first_id));
+        assert!(!emitter.unregister_observer(first_id));
+        emitter.emit(EventType::Retry, retry_event()).await;
+
+        assert_eq!(*first.events.lock().unwrap(), vec![EventType::Retry]);
+        assert_eq!(
+            *second.events.lock().unwrap(),
+            vec![EventType::Retry, EventType::Retry]
+        );
+    }
+
+    #[test]
+    fn test_progress_snapshot_unknown_total() {
+        let mut reporter = ProgressReporter::new(ProgressOperation::AssetHoldersSnapshot, None);
+        reporter.processed = 10;
+        assert!(reporter.snapshot().eta.is_none());
+    }
+}
@@ -0,0 +1,346 @@
+//! Persistence of AVM debugging artifacts in the layout the AlgoKit AVM VS Code debugger reads.
+//!
+//! When [`Config::debug`](crate::Config::debug) is enabled and a project root is configured with
+//! [`Config::configure_project_root`](crate::Config::configure_project_root):
+//! - compiled programs are written with their source maps to
+//!   `<project_root>/.algokit/sources/<app_name>/<program>.teal(.map)`, when compiled by an app
+//!   client or deployed by an app factory, and can be read back with [`import_sources`]
+//! - failed groups (and every group when `trace_all` is enabled) are simulated with execution
+//!   tracing and the simulate response is written to
+//!   `<project_root>/debug_traces/<timestamp>_lr<last_round>_<txn_types>.trace.avm.json`
+
+use crate::clients::app_manager::CompiledTeal;
+use algod_client::models::SimulateTransaction;
+use algokit_abi::ProgramSourceMap;
+use algokit_transact::Transaction;
+use log::warn;
+use snafu::Snafu;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const ALGOKIT_DIR: &str = ".algokit";
+pub const SOURCES_DIR: &str = "sources";
+pub const TRACES_DIR: &str = "debug_traces";
+pub const TEAL_FILE_EXT: &str = ".teal";
+pub const TEAL_SOURCE_MAP_EXT: &str = ".teal.map";
+pub const TRACE_FILE_EXT: &str = ".trace.avm.json";
+
+#[derive(Debug, Snafu)]
+pub enum DebuggingError {
+    #[snafu(display("Debugging IO error: {message}"))]
+    IoError { message: String },
+    #[snafu(display("Debugging serialization error: {message}"))]
+    SerializationError { message: String },
+}
+
+/// A compiled TEAL program to persist for the debugger.
+#[derive(Debug, Clone)]
+pub struct DebugSource {
+    pub app_name: String,
+    /// The file name of the program without extension, e.g. `approval`
+    pub file_name: String,
+    pub teal: String,
+    pub source_map: ProgramSourceMap,
+}
+
+/// Write the TEAL and source map of each of `sources` to
+/// `<project_root>/.algokit/sources/<app_name>/`, returning the written source map paths.
+///
+/// The `sources` of each written source map point at the TEAL file next to it, so the debugger
+/// can resolve them regardless of where the program was compiled from.
+pub fn persist_sources(
+    project_root: &Path,
+    sources: &[DebugSource],
+) -> Result<Vec<PathBuf>, DebuggingError> {
+    let mut source_map_paths = Vec::with_capacity(sources.len());
+    for source in sources {
+        let app_dir = project_root
+            .join(ALGOKIT_DIR)
+            .join(SOURCES_DIR)
+            .join(&source.app_name);
+        let teal_file_name = format!("{}{}", source.file_name, TEAL_FILE_EXT);
+
+        let mut source_map = source.source_map.clone();
+        source_map.sources = vec![teal_file_name.clone()];
+        let source_map_json =
+            serde_json::to_string(&source_map).map_err(|e| DebuggingError::SerializationError {
+                message: format!("Failed to serialize source map: {}", e),
+            })?;
+
+        let source_map_path = app_dir.join(format!("{}{}", source.file_name, TEAL_SOURCE_MAP_EXT));
+        write_file(&app_dir.join(teal_file_name), source.teal.as_bytes())?;
+        write_file(&source_map_path, source_map_json.as_bytes())?;
+        source_map_paths.push(source_map_path);
+    }
+    Ok(source_map_paths)
+}
+
+/// Read the sources of `app_name` persisted with [`persist_sources`], in file name order.
+///
+/// Source maps without their TEAL file next to them are skipped.
+pub fn import_sources(
+    project_root: &Path,
+    app_name: &str,
+) -> Result<Vec<DebugSource>, DebuggingError> {
+    let app_dir = project_root
+        .join(ALGOKIT_DIR)
+        .join(SOURCES_DIR)
+        .join(app_name);
+    let io_error = |e: std::io::Error| DebuggingError::IoError {
+        message: format!("Failed to read {}: {}", app_dir.display(), e),
+    };
+
+    let mut file_names = Vec::new();
+    for entry in fs::read_dir(&app_dir).map_err(io_error)? {
+        let file_name = entry.map_err(io_error)?.file_name();
+        if let Some(name) = file_name
+            .to_str()
+            .and_then(|name| name.strip_suffix(TEAL_SOURCE_MAP_EXT))
+        {
+            file_names.push(name.to_string());
+        }
+    }
+    file_names.sort();
+
+    let mut sources = Vec::with_capacity(file_names.len());
+    for file_name in file_names {
+        let teal_path = app_dir.join(format!("{}{}", file_name, TEAL_FILE_EXT));
+        if !teal_path.is_file() {
+            continue;
+        }
+        let source_map_path = app_dir.join(format!("{}{}", file_name, TEAL_SOURCE_MAP_EXT));
+        let source_map = serde_json::from_slice(&read_file(&source_map_path)?).map_err(|e| {
+            DebuggingError::SerializationError {
+                message: format!(
+                    "Failed to parse source map {}: {}",
+                    source_map_path.display(),
+                    e
+                ),
+            }
+        })?;
+        let teal = String::from_utf8(read_file(&teal_path)?).map_err(|e| {
+            DebuggingError::SerializationError {
+                message: format!("{} is not UTF-8: {}", teal_path.display(), e),
+            }
+        })?;
+        sources.push(DebugSource {
+            app_name: app_name.to_string(),
+            file_name,
+            teal,
+            source_map,
+        });
+    }
+    Ok(sources)
+}
+
+/// Persist compiled approval and clear programs for the debugger, logging rather than failing
+/// when they can't be written.
+pub(crate) fn persist_compiled_programs(
+    project_root: &Path,
+    app_name: &str,
+    approval: &CompiledTeal,
+    clear: &CompiledTeal,
+) {
+    let sources: Vec<DebugSource> = [("approval", approval), ("clear", clear)]
+        .into_iter()
+        .filter_map(|(file_name, compiled)| {
+            compiled.source_map.clone().map(|source_map| DebugSource {
+                app_name: app_name.to_string(),
+                file_name: file_name.to_string(),
+                teal: compiled.teal.clone(),
+                source_map,
+            })
+        })
+        .collect();
+
+    if let Err(e) = persist_sources(project_root, &sources) {
+        warn!(
+            "Failed to persist sources of {} for debugging: {}",
+            app_name, e
+        );
+    }
+}
+
+/// Write a simulate response to `<project_root>/debug_traces/`, returning the written path.
+///
+/// `transactions` are the simulated transactions, used to name the trace file after the
+/// transaction types of the group.
+pub fn persist_trace(
+    project_root: &Path,
+    simulate_response: &SimulateTransaction,
+    transactions: &[Transaction],
+) -> Result<PathBuf, DebuggingError> {
+    let trace_json = serde_json::to_string_pretty(simulate_response).map_err(|e| {
+        DebuggingError::SerializationError {
+            message: format!("Failed to serialize simulate response: {}", e),
+        }
+    })?;
+
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let path = project_root.join(TRACES_DIR).join(trace_file_name(
+        seconds,
+        simulate_response.last_round,
+        transactions,
+    ));
+    write_file(&path, trace_json.as_bytes())?;
+    Ok(path)
+}
+
+/// The trace file name, e.g. `20250101_120000_lr1234_2pay_1appl.trace.avm.json`, with the
+/// transaction types in order of first appearance in the group.
+pub fn trace_file_name(unix_seconds: u64, last_round: u64, transactions: &[Transaction]) -> String {
+    let mut type_counts: Vec<(&str, usize)> = Vec::new();
+    for transaction in transactions {
+        let txn_type = transaction_type(transaction);
+        match type_counts.iter_mut().find(|(t, _)| *t == txn_type) {
+            Some((_, count)) => *count += 1,
+            None => type_counts.push((txn_type, 1)),
+        }
+    }
+    let txn_types = type_counts
+        .iter()
+        .map(|(txn_type, count)| format!("{}{}", count, txn_type))
+        .collect::<Vec<_>>()
+        .join("_");
+
+    format!(
+        "{}_lr{}_{}{}",
+        utc_timestamp(unix_seconds),
+        last_round,
+        txn_types,
+        TRACE_FILE_EXT
+    )
+}
+
+fn transaction_type(transaction: &Transaction) -> &'static str {
+    match transaction {
+        Transaction::Payment(_) => "pay",
+        Transaction::AssetTransfer(_) => "axfer",
+        Transaction::AssetConfig(_) => "acfg",
+        Transaction::AppCall(_) => "appl",
+        Transaction::AssetFreeze(_) => "afrz",
+        Transaction::KeyRegistration(_) => "keyreg",
+        Transaction::Heartbeat(_) => "hb",
+        Transaction::StateProof(_) => "stpf",
+    }
+}
+
+/// Format unix seconds as a `YYYYMMDD_HHMMSS` UTC timestamp.
+fn utc_timestamp(unix_seconds: u64) -> String {
+    let days = (unix_seconds / 86_400) as i64;
+    let seconds_of_day = unix_seconds % 86_400;
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}{:02}{:02}_{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, DebuggingError> {
+    fs::read(path).map_err(|e| DebuggingError::IoError {
+        message: format!("Failed to read {}: {}", path.display(), e),
+    })
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), DebuggingError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| DebuggingError::IoError {
+            message: format!("Failed to create {}: {}", parent.display(), e),
+        })?;
+    }
+    fs::write(path, contents).map_err(|e| DebuggingError::IoError {
+        message: format!("Failed to write {}: {}", path.display(), e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::{AppCallTransactionMother, TransactionMother};
+
+    fn temp_project_root() -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "algokit_debugging_{}_{}",
+            std::process::id(),
+            rand::random::<u64>()
+        ))
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(utc_timestamp(0), "19700101_000000");
+        assert_eq!(utc_timestamp(951_782_400), "20000229_000000");
+        assert_eq!(utc_timestamp(1_735_734_896), "20250101_123456");
+    }
+
+    #[test]
+    fn test_trace_file_name_counts_txn_types() {
+        let payment = TransactionMother::simple_payment().build().unwrap();
+        let app_call = AppCallTransactionMother::app_call().build().unwrap();
+
+        let name = trace_file_name(1_735_734_896, 1234, &[payment.clone(), app_call, payment]);
+
+        assert_eq!(name, "20250101_123456_lr1234_2pay_1appl.trace.avm.json");
+    }
+
+    #[test]
+    fn test_persist_sources() {
+        let project_root = temp_project_root();
+        let source_map: ProgramSourceMap = serde_json::from_str(
+            r#"{"version":3,"sources":["<stdin>"],"names":[],"mappings":";AAAA"}"#,
+        )
+        .unwrap();
+
+        let paths = persist_sources(
+            &project_root,
+            &[DebugSource {
+                app_name: "HelloWorld".to_string(),
+                file_name: "approval".to_string(),
+                teal: "#pragma version 10\nint 1\n".to_string(),
+                source_map,
+            }],
+        )
+        .unwrap();
+
+        let app_dir = project_root.join(".algokit/sources/HelloWorld");
+        assert_eq!(paths, vec![app_dir.join("approval.teal.map")]);
+        assert_eq!(
+            fs::read_to_string(app_dir.join("approval.teal")).unwrap(),
+            "#pragma version 10\nint 1\n"
+        );
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&paths[0]).unwrap()).unwrap();
+        assert_eq!(written["sources"], serde_json::json!(["approval.teal"]));
+        assert_eq!(written["mappings"], ";AAAA");
+
+        let imported = import_sources(&project_root, "HelloWorld").unwrap();
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].file_name, "approval");
+        assert_eq!(imported[0].teal, "#pragma version 10\nint 1\n");
+        assert_eq!(imported[0].source_map.sources, vec!["approval.teal"]);
+        assert!(import_sources(&project_root, "Missing").is_err());
+
+        fs::remove_dir_all(project_root).unwrap();
+    }
+}
@@ -1,27 +1,44 @@
+pub mod amount;
 pub mod applications;
 pub mod clients;
 pub mod config;
+pub mod debugging;
+pub mod mbr;
+pub mod subscriber;
 pub mod transactions;
 
 // Re-exports for clean UniFFI surface
+pub use amount::{AlgoAmount, AmountError, AssetAmount};
 pub use clients::{
-    AccountManager, AlgoClientConfig, AlgoConfig, AlgorandClient, AlgorandNetwork, AlgorandService,
-    AppManager, AppManagerError, AssetInformation, AssetManager, AssetManagerError,
-    BulkAssetOptInOutResult, ClientManager, NetworkDetails, TokenHeader, genesis_id_is_localnet,
+    AccountManager, AlgoClientConfig, AlgoConfig, AlgodErrorKind, AlgorandClient, AlgorandNetwork,
+    AlgorandService, AppManager, AppManagerError, AssetInformation, AssetManager,
+    AssetManagerError, AssetMetadata, BulkAssetOptInOutResult, ClientManager, ConsensusVersion,
+    GenesisDetails, KmdAccount, KmdAccountManager, KmdAccountManagerError, KmdSigner,
+    LogicSigAccount, NetworkDefinition, NetworkDetails, NetworkRegistry, NodeManager,
+    NodeManagerError, NodeStatus, SuggestedParamsCache, TestNetDispenserApiClient, TokenHeader,
+    genesis_id_is_localnet,
 };
 // Re-export ABI types for convenience
 pub use algokit_abi::ABIReturn;
 pub use applications::app_client::{AppClient, AppClientError, AppClientParams, AppSourceMaps};
 pub use config::{Config, EventType};
+pub use subscriber::{
+    AlgorandSubscriber, AlgorandSubscriberConfig, FileWatermarkStore, InMemoryWatermarkStore,
+    SubscriberError, TransactionFilter, WatermarkStore, WatermarkStoreError,
+};
 pub use transactions::{
     AccountCloseParams, AppCallMethodCallParams, AppCallParams, AppCreateMethodCallParams,
     AppCreateParams, AppDeleteMethodCallParams, AppDeleteParams, AppMethodCallArg,
     AppUpdateMethodCallParams, AppUpdateParams, AssetClawbackParams, AssetConfigParams,
     AssetCreateParams, AssetDestroyParams, AssetFreezeParams, AssetOptInParams, AssetOptOutParams,
-    AssetTransferParams, AssetUnfreezeParams, ComposerError, ComposerTransaction, EmptySigner,
+    AssetTransferParams, AssetUnfreezeParams, BatchSendParams, BatchSendResult, BatchSender,
+    CancellationToken, ComposerError, ComposerTransaction, DryrunResult, EmptySigner,
+    ExternalSigner, ExternalTransactionSigner, FeePayer, FeePolicy,
     NonParticipationKeyRegistrationParams, OfflineKeyRegistrationParams,
-    OnlineKeyRegistrationParams, PaymentParams, ResourcePopulation, SendAppCreateMethodCallResult,
-    SendAppCreateResult, SendAppMethodCallResult, SendAssetCreateResult, SendParams, SendResult,
-    TransactionComposer, TransactionComposerSendResult, TransactionCreator, TransactionResult,
-    TransactionSender, TransactionSenderError, TransactionSigner, TransactionWithSigner,
+    OnlineKeyRegistrationParams, PaymentParams, ResourcePopulation, RetryPolicy,
+    SendAppCreateMethodCallResult, SendAppCreateResult, SendAppMethodCallResult,
+    SendAssetCreateResult, SendManyResult, SendParams, SendResult, SimulateParams,
+    SimulatedTransaction, TransactionComposer, TransactionComposerSendResult,
+    TransactionComposerSimulateResult, TransactionCreator, TransactionResult, TransactionSender,
+    TransactionSenderError, TransactionSigner, TransactionWithSigner,
 };
@@ -0,0 +1,159 @@
+//! Calculation of the minimum balance requirement (MBR) of accounts.
+//!
+//! Every account must hold a minimum balance that grows with the assets it holds, the apps it
+//! created or opted in to, the state schemas of those apps and the boxes of an app account. The
+//! amounts are those of the current consensus parameters.
+
+use crate::AlgoAmount;
+use crate::transactions::payment::{BOX_BYTE_MIN_BALANCE, BOX_FLAT_MIN_BALANCE};
+use algod_client::models::Account;
+use algokit_transact::StateSchema;
+
+/// The minimum balance of an account, in microALGO.
+pub const ACCOUNT_MIN_BALANCE: u64 = 100_000;
+
+/// The minimum balance per asset held, whether created or opted in to, in microALGO.
+pub const ASSET_MIN_BALANCE: u64 = 100_000;
+
+/// The minimum balance per created app and per extra program page, in microALGO.
+pub const APP_PAGE_MIN_BALANCE: u64 = 100_000;
+
+/// The minimum balance per app opted in to, in microALGO.
+pub const APP_OPT_IN_MIN_BALANCE: u64 = 100_000;
+
+/// The minimum balance per state schema entry, on top of the per type amount, in microALGO.
+pub const SCHEMA_ENTRY_MIN_BALANCE: u64 = 25_000;
+
+/// The minimum balance per uint state schema entry, in microALGO.
+pub const SCHEMA_UINT_MIN_BALANCE: u64 = 3_500;
+
+/// The minimum balance per byte slice state schema entry, in microALGO.
+pub const SCHEMA_BYTE_SLICE_MIN_BALANCE: u64 = 25_000;
+
+/// What an account holds that contributes to its minimum balance requirement.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MinBalanceParams {
+    /// The number of assets held, including those created by the account
+    pub assets: u64,
+    /// The number of apps created by the account
+    pub created_apps: u64,
+    /// The number of extra program pages of the apps created by the account
+    pub extra_app_pages: u64,
+    /// The number of apps the account opted in to
+    pub opted_in_apps: u64,
+    /// The global schemas of the created apps plus the local schemas of the apps opted in to
+    pub total_schema: StateSchema,
+    /// The number of boxes of an app account
+    pub boxes: u64,
+    /// The total length of the names and values of the boxes of an app account
+    pub box_bytes: u64,
+}
+
+impl MinBalanceParams {
+    /// Add an app created by the account, with its global schema.
+    pub fn with_created_app(mut self, global_schema: &StateSchema, extra_pages: u32) -> Self {
+        self.created_apps += 1;
+        self.extra_app_pages += extra_pages as u64;
+        self.total_schema.num_uints += global_schema.num_uints;
+        self.total_schema.num_byte_slices += global_schema.num_byte_slices;
+        self
+    }
+
+    /// Add an app the account opted in to, with its local schema.
+    pub fn with_opted_in_app(mut self, local_schema: &StateSchema) -> Self {
+        self.opted_in_apps += 1;
+        self.total_schema.num_uints += local_schema.num_uints;
+        self.total_schema.num_byte_slices += local_schema.num_byte_slices;
+        self
+    }
+
+    /// Add a box of an app account.
+    pub fn with_box(mut self, name_length: u64, size: u64) -> Self {
+        self.boxes += 1;
+        self.box_bytes += name_length + size;
+        self
+    }
+}
+
+/// What an account holds according to algod.
+impl From<&Account> for MinBalanceParams {
+    fn from(account: &Account) -> Self {
+        let schema = account.apps_total_schema.as_ref();
+        Self {
+            assets: account.total_assets_opted_in,
+            created_apps: account.total_created_apps,
+            extra_app_pages: account.apps_total_extra_pages.unwrap_or(0),
+            opted_in_apps: account.total_apps_opted_in,
+            total_schema: StateSchema {
+                num_uints: schema.map_or(0, |schema| schema.num_uint),
+                num_byte_slices: schema.map_or(0, |schema| schema.num_byte_slice),
+            },
+            boxes: account.total_boxes.unwrap_or(0),
+            box_bytes: account.total_box_bytes.unwrap_or(0),
+        }
+    }
+}
+
+/// The minimum balance requirement of a state schema.
+pub fn schema_min_balance(schema: &StateSchema) -> AlgoAmount {
+    let num_uints = schema.num_uints as u64;
+    let num_byte_slices = schema.num_byte_slices as u64;
+    AlgoAmount::micro_algos(
+        SCHEMA_ENTRY_MIN_BALANCE * (num_uints + num_byte_slices)
+            + SCHEMA_UINT_MIN_BALANCE * num_uints
+            + SCHEMA_BYTE_SLICE_MIN_BALANCE * num_byte_slices,
+    )
+}
+
+/// The minimum balance requirement of an account holding what `params` describe.
+pub fn calculate_min_balance(params: &MinBalanceParams) -> AlgoAmount {
+    AlgoAmount::micro_algos(
+        ACCOUNT_MIN_BALANCE
+            + ASSET_MIN_BALANCE * params.assets
+            + APP_PAGE_MIN_BALANCE * (params.created_apps + params.extra_app_pages)
+            + APP_OPT_IN_MIN_BALANCE * params.opted_in_apps
+            + BOX_FLAT_MIN_BALANCE * params.boxes
+            + BOX_BYTE_MIN_BALANCE * params.box_bytes,
+    ) + schema_min_balance(&params.total_schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_account() {
+        assert_eq!(
+            calculate_min_balance(&MinBalanceParams::default()),
+            AlgoAmount::micro_algos(100_000)
+        );
+    }
+
+    #[test]
+    fn test_account_with_assets_apps_and_boxes() {
+        let params = MinBalanceParams {
+            assets: 2,
+            ..Default::default()
+        }
+        .with_created_app(
+            &StateSchema {
+                num_uints: 1,
+                num_byte_slices: 2,
+            },
+            1,
+        )
+        .with_opted_in_app(&StateSchema {
+            num_uints: 1,
+            num_byte_slices: 0,
+        })
+        .with_box(4, 1024);
+
+        // 100,000 base + 2 * 100,000 assets + 2 * 100,000 app pages + 100,000 opt-in
+        // + 4 * 25,000 schema entries + 2 * 3,500 uints + 2 * 25,000 byte slices
+        // + 2,500 + 400 * 1,028 box
+        assert_eq!(
+            calculate_min_balance(&params),
+            AlgoAmount::micro_algos(600_000 + 100_000 + 7_000 + 50_000 + 413_700)
+        );
+    }
+}
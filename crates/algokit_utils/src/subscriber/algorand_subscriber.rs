@@ -0,0 +1,491 @@
+use super::block_transactions::block_transactions;
+use super::filter::app_id;
+use super::types::{
+    AlgorandSubscriberConfig, EmittedArc28Event, SubscribedTransaction, SubscriptionResult,
+    SyncBehaviour,
+};
+use super::watermark_store::{WatermarkStore, WatermarkStoreError};
+use crate::AlgorandClient;
+use crate::config::{ProgressOperation, ProgressReporter};
+use algod_client::{AlgodClient, apis::Error as AlgodError};
+use async_trait::async_trait;
+use futures::future::{Either, select};
+use futures::{StreamExt, TryStreamExt, stream};
+use indexer_client::models::Transaction as IndexerTransaction;
+use indexer_client::{IndexerClient, apis::Error as IndexerError};
+use log::warn;
+use snafu::Snafu;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+
+#[derive(Debug, Snafu)]
+pub enum SubscriberError {
+    #[snafu(display("Subscriber configuration error: {message}"))]
+    ConfigurationError { message: String },
+    #[snafu(display("Algod error: {source}"))]
+    AlgodError { source: AlgodError },
+    #[snafu(display("Indexer error: {source}"))]
+    IndexerError { source: IndexerError },
+    #[snafu(display("Watermark store error: {source}"))]
+    WatermarkStoreError { source: WatermarkStoreError },
+    #[snafu(display("Sync error: {message}"))]
+    SyncError { message: String },
+    #[snafu(display("ARC-28 event decoding error: {message}"))]
+    EventDecodingError { message: String },
+    #[snafu(display("Subscription handler error: {message}"))]
+    HandlerError { message: String },
+}
+
+/// The number of blocks fetched from algod at once.
+const BLOCK_FETCH_CONCURRENCY: usize = 10;
+
+/// Handles the transactions matching a filter, registered with [`AlgorandSubscriber::on`].
+#[async_trait]
+pub trait SubscriptionHandler: Send + Sync {
+    /// Handle the transactions of a poll that matched the filter, in round order.
+    ///
+    /// Failing stops the poll before the watermark is advanced, so the transactions are
+    /// delivered again by the next poll.
+    async fn handle(&self, transactions: &[SubscribedTransaction]) -> Result<(), String>;
+}
+
+/// Subscribes to the transactions matching a set of filters as the chain progresses.
+///
+/// The chain tip is polled from algod and the transactions of the rounds after the watermark
+/// are read from the blocks of algod. A subscriber that fell further behind than algod keeps
+/// blocks (see [`AlgorandSubscriberConfig::algod_block_retention`]) catches up from indexer, if
+/// one is configured. Each poll syncs at most `max_rounds_to_sync` rounds, and the watermark
+/// only advances once all handlers succeeded, so processing is at least once.
+pub struct AlgorandSubscriber {
+    config: AlgorandSubscriberConfig,
+    algod: Arc<AlgodClient>,
+    indexer: Option<Arc<IndexerClient>>,
+    watermark_store: Arc<dyn WatermarkStore>,
+    handlers: Vec<(String, Arc<dyn SubscriptionHandler>)>,
+}
+
+impl AlgorandSubscriber {
+    pub fn new(
+        config: AlgorandSubscriberConfig,
+        algorand: &AlgorandClient,
+        watermark_store: Arc<dyn WatermarkStore>,
+    ) -> Result<Self, SubscriberError> {
+        if config.max_rounds_to_sync == 0 {
+            return Err(SubscriberError::ConfigurationError {
+                message: "max_rounds_to_sync must be greater than zero".to_string(),
+            });
+        }
+
+        Ok(Self {
+            config,
+            algod: algorand.client().algod(),
+            indexer: algorand.client().indexer_if_present(),
+            watermark_store,
+            handlers: Vec::new(),
+        })
+    }
+
+    pub fn config(&self) -> &AlgorandSubscriberConfig {
+        &self.config
+    }
+
+    /// Register a handler for the transactions matching the filter named `filter_name`.
+    pub fn on(
+        &mut self,
+        filter_name: impl Into<String>,
+        handler: Arc<dyn SubscriptionHandler>,
+    ) -> Result<&mut Self, SubscriberError> {
+        let filter_name = filter_name.into();
+        if !self
+            .config
+            .filters
+            .iter()
+            .any(|filter| filter.name == filter_name)
+        {
+            return Err(SubscriberError::ConfigurationError {
+                message: format!("There is no filter named {}", filter_name),
+            });
+        }
+        self.handlers.push((filter_name, handler));
+        Ok(self)
+    }
+
+    /// Sync the rounds after the watermark and return the matched transactions, without calling
+    /// the handlers or advancing the watermark.
+    pub async fn get_subscribed_transactions(&self) -> Result<SubscriptionResult, SubscriberError> {
+        let starting_watermark = self
+            .watermark_store
+            .get()
+            .await
+            .map_err(|e| SubscriberError::WatermarkStoreError { source: e })?
+            .unwrap_or(0);
+        let current_round = self
+            .algod
+            .get_status()
+            .await
+            .map_err(|e| SubscriberError::AlgodError { source: e })?
+            .last_round;
+
+        let mut result = SubscriptionResult {
+            current_round,
+            starting_watermark,
+            new_watermark: starting_watermark,
+            synced_round_range: None,
+            subscribed_transactions: Vec::new(),
+        };
+        let Some((start_round, end_round)) = sync_range(
+            self.config.sync_behaviour,
+            starting_watermark,
+            current_round,
+            self.config.max_rounds_to_sync,
+        )?
+        else {
+            return Ok(result);
+        };
+
+        let (transactions, end_round) = if current_round - start_round
+            >= self.config.algod_block_retention
+        {
+            let Some(indexer) = &self.indexer else {
+                return Err(SubscriberError::SyncError {
+                    message: format!(
+                        "Round {} is older than the {} rounds algod keeps, catching up on it needs an indexer",
+                        start_round, self.config.algod_block_retention
+                    ),
+                });
+            };
+            let (transactions, indexer_round) =
+                search_transactions(indexer, start_round, end_round).await?;
+            // Only sync what indexer has caught up on, the rest is synced by a later poll
+            (transactions, end_round.min(indexer_round))
+        } else {
+            (
+                self.get_block_transactions(start_round, end_round).await?,
+                end_round,
+            )
+        };
+        if end_round < start_round {
+            return Ok(result);
+        }
+
+        let mut subscribed_transactions = Vec::new();
+        for transaction in transactions
+            .iter()
+            .filter(|transaction| transaction.confirmed_round.unwrap_or(0) <= end_round)
+        {
+            let root_id = transaction.id.clone().unwrap_or_default();
+            let mut inner_count = 0;
+            self.process_transaction(
+                transaction,
+                root_id.clone(),
+                &root_id,
+                true,
+                &mut inner_count,
+                &mut subscribed_transactions,
+            )?;
+        }
+
+        result.new_watermark = end_round;
+        result.synced_round_range = Some((start_round, end_round));
+        result.subscribed_transactions = subscribed_transactions;
+        Ok(result)
+    }
+
+    /// Sync the rounds after the watermark, call the handlers of the matched filters and
+    /// advance the watermark.
+    pub async fn poll_once(&self) -> Result<SubscriptionResult, SubscriberError> {
+        let result = self.get_subscribed_transactions().await?;
+
+        for (filter_name, handler) in &self.handlers {
+            let transactions: Vec<SubscribedTransaction> =
+                result.matches(filter_name).cloned().collect();
+            if transactions.is_empty() {
+                continue;
+            }
+            handler.handle(&transactions).await.map_err(|message| {
+                SubscriberError::HandlerError {
+                    message: format!("Handler of filter {} failed: {}", filter_name, message),
+                }
+            })?;
+        }
+
+        if result.new_watermark != result.starting_watermark {
+            self.watermark_store
+                .set(result.new_watermark)
+                .await
+                .map_err(|e| SubscriberError::WatermarkStoreError { source: e })?;
+        }
+        Ok(result)
+    }
+
+    /// Poll until `stop` completes or a poll fails.
+    ///
+    /// While behind the tip polls follow each other immediately; once caught up the subscriber
+    /// waits for the next block (or `frequency`, see
+    /// [`AlgorandSubscriberConfig::wait_for_block_when_at_tip`]) between polls.
+    ///
+    /// Catching up on the rounds behind the tip reports progress as
+    /// [`ProgressOperation::SubscriberCatchup`], in synced rounds out of the rounds behind.
+    pub async fn start<F: Future<Output = ()>>(&self, stop: F) -> Result<(), SubscriberError> {
+        let mut stop = pin!(stop);
+        let mut catchup: Option<ProgressReporter> = None;
+        loop {
+            let result = self.poll_once().await?;
+            let at_tip = result.new_watermark >= result.current_round;
+            let synced_rounds = result
+                .synced_round_range
+                .map_or(0, |(start_round, end_round)| end_round - start_round + 1);
+            if !at_tip || catchup.is_some() {
+                let progress = catchup.get_or_insert_with(|| {
+                    ProgressReporter::new(
+                        ProgressOperation::SubscriberCatchup,
+                        Some(result.current_round - result.starting_watermark),
+                    )
+                });
+                progress.advance(synced_rounds).await;
+            }
+            if at_tip {
+                catchup = None;
+            }
+            if !at_tip && result.new_watermark > result.starting_watermark {
+                continue;
+            }
+
+            // Wait for a block at the tip, otherwise give a lagging indexer time to catch up
+            let wait = async {
+                if at_tip && self.config.wait_for_block_when_at_tip {
+                    let _ = self.algod.wait_for_block(result.current_round).await;
+                } else {
+                    tokio::time::sleep(self.config.frequency).await;
+                }
+            };
+            if let Either::Left(_) = select(stop.as_mut(), pin!(wait)).await {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Get all transactions confirmed in the round range from the blocks of algod.
+    async fn get_block_transactions(
+        &self,
+        min_round: u64,
+        max_round: u64,
+    ) -> Result<Vec<IndexerTransaction>, SubscriberError> {
+        let blocks: Vec<_> = stream::iter(min_round..=max_round)
+            .map(|round| self.algod.get_block(round, None))
+            .buffered(BLOCK_FETCH_CONCURRENCY)
+            .try_collect()
+            .await
+            .map_err(|e| SubscriberError::AlgodError { source: e })?;
+
+        let mut transactions = Vec::new();
+        for block in blocks {
+            transactions.extend(block_transactions(&block.block).map_err(|e| {
+                SubscriberError::SyncError {
+                    message: format!("Failed to read the transactions of a block: {}", e),
+                }
+            })?);
+        }
+        Ok(transactions)
+    }
+
+    /// Match a transaction and, depth first, its inner transactions against the filters.
+    fn process_transaction(
+        &self,
+        transaction: &IndexerTransaction,
+        id: String,
+        root_id: &str,
+        is_root: bool,
+        inner_count: &mut usize,
+        subscribed_transactions: &mut Vec<SubscribedTransaction>,
+    ) -> Result<(), SubscriberError> {
+        let arc28_events = self.decode_arc28_events(transaction)?;
+        let candidate = SubscribedTransaction {
+            id,
+            parent_transaction_id: (!is_root).then(|| root_id.to_string()),
+            transaction: transaction.clone(),
+            filters_matched: Vec::new(),
+            arc28_events: Vec::new(),
+        };
+        let filters_matched: Vec<String> = self
+            .config
+            .filters
+            .iter()
+            .filter(|named| named.filter.matches(&candidate, &arc28_events))
+            .map(|named| named.name.clone())
+            .collect();
+        if !filters_matched.is_empty() {
+            subscribed_transactions.push(SubscribedTransaction {
+                filters_matched,
+                arc28_events,
+                ..candidate
+            });
+        }
+
+        for inner in transaction.inner_txns.iter().flatten() {
+            *inner_count += 1;
+            self.process_transaction(
+                inner,
+                format!("{}/inner/{}", root_id, inner_count),
+                root_id,
+                false,
+                inner_count,
+                subscribed_transactions,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn decode_arc28_events(
+        &self,
+        transaction: &IndexerTransaction,
+    ) -> Result<Vec<EmittedArc28Event>, SubscriberError> {
+        let Some(logs) = transaction.logs.as_ref().filter(|logs| !logs.is_empty()) else {
+            return Ok(Vec::new());
+        };
+        let app_id = app_id(transaction).unwrap_or(0);
+
+        let mut events = Vec::new();
+        for group in self.config.arc28_events.iter().filter(|group| {
+            group.process_for_app_ids.is_empty() || group.process_for_app_ids.contains(&app_id)
+        }) {
+            for log in logs {
+                match group.app_spec.decode_event(log) {
+                    Ok(Some(event)) => events.push(EmittedArc28Event {
+                        group_name: group.group_name.clone(),
+                        event,
+                    }),
+                    Ok(None) => {}
+                    Err(e) if group.fail_on_error => {
+                        return Err(SubscriberError::EventDecodingError {
+                            message: format!(
+                                "Failed to decode a log of app {} as an event of group {}: {}",
+                                app_id, group.group_name, e
+                            ),
+                        });
+                    }
+                    Err(e) => warn!(
+                        "Skipping a log of app {} that failed to decode as an event of group {}: {}",
+                        app_id, group.group_name, e
+                    ),
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Get all transactions confirmed in the round range from indexer, along with the round
+/// indexer has caught up to.
+async fn search_transactions(
+    indexer: &IndexerClient,
+    min_round: u64,
+    max_round: u64,
+) -> Result<(Vec<IndexerTransaction>, u64), SubscriberError> {
+    let mut transactions = Vec::new();
+    let mut next = None;
+    loop {
+        let page = indexer
+            .search_for_transactions(
+                None,
+                next.as_deref(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(min_round),
+                Some(max_round),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|e| SubscriberError::IndexerError { source: e })?;
+
+        let is_last_page = page.next_token.is_none() || page.transactions.is_empty();
+        transactions.extend(page.transactions);
+        if is_last_page {
+            return Ok((transactions, page.current_round));
+        }
+        next = page.next_token;
+    }
+}
+
+/// The (inclusive) range of rounds to sync after `watermark` given the current round, or `None`
+/// when there are no new rounds.
+pub(crate) fn sync_range(
+    sync_behaviour: SyncBehaviour,
+    watermark: u64,
+    current_round: u64,
+    max_rounds_to_sync: u64,
+) -> Result<Option<(u64, u64)>, SubscriberError> {
+    if current_round <= watermark {
+        return Ok(None);
+    }
+    if sync_behaviour == SyncBehaviour::SyncOldestStartNow && watermark == 0 {
+        return Ok(Some((current_round, current_round)));
+    }
+    if current_round - watermark <= max_rounds_to_sync {
+        return Ok(Some((watermark + 1, current_round)));
+    }
+
+    match sync_behaviour {
+        SyncBehaviour::SkipSyncNewest => Ok(Some((
+            current_round - max_rounds_to_sync + 1,
+            current_round,
+        ))),
+        SyncBehaviour::SyncOldest | SyncBehaviour::SyncOldestStartNow => {
+            Ok(Some((watermark + 1, watermark + max_rounds_to_sync)))
+        }
+        SyncBehaviour::Fail => Err(SubscriberError::SyncError {
+            message: format!(
+                "Invalid round number to subscribe from {}; current round number is {}",
+                watermark, current_round
+            ),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(SyncBehaviour::SyncOldest, 100, 100, None)]
+    #[case(SyncBehaviour::SyncOldest, 100, 105, Some((101, 105)))]
+    #[case(SyncBehaviour::SyncOldest, 100, 200, Some((101, 110)))]
+    #[case(SyncBehaviour::SkipSyncNewest, 100, 200, Some((191, 200)))]
+    #[case(SyncBehaviour::SyncOldestStartNow, 0, 200, Some((200, 200)))]
+    #[case(SyncBehaviour::SyncOldestStartNow, 100, 200, Some((101, 110)))]
+    #[case(SyncBehaviour::Fail, 100, 110, Some((101, 110)))]
+    fn test_sync_range(
+        #[case] sync_behaviour: SyncBehaviour,
+        #[case] watermark: u64,
+        #[case] current_round: u64,
+        #[case] expected: Option<(u64, u64)>,
+    ) {
+        assert_eq!(
+            sync_range(sync_behaviour, watermark, current_round, 10).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_sync_range_fails_when_too_far_behind() {
+        assert!(matches!(
+            sync_range(SyncBehaviour::Fail, 100, 111, 10),
+            Err(SubscriberError::SyncError { .. })
+        ));
+    }
+}
@@ -0,0 +1,346 @@
+use algokit_transact::{
+    AlgoKitTransactError, ApplyData, Block, OnApplicationComplete, SignedTransaction, Transaction,
+};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use indexer_client::models::{
+    AssetParams, BoxReference, OnCompletion, StateSchema, Transaction as IndexerTransaction,
+    TransactionApplication, TransactionAssetConfig, TransactionAssetFreeze,
+    TransactionAssetTransfer, TransactionKeyreg, TransactionPayment, TransactionSignature,
+};
+
+/// The transactions of an algod block in the shape indexer returns them, so transactions read
+/// from algod and from indexer are matched and delivered alike.
+///
+/// Offsets within the round count inner transactions, depth first after their parent, as
+/// indexer does. The state deltas of app calls aren't converted.
+pub(super) fn block_transactions(
+    block: &Block,
+) -> Result<Vec<IndexerTransaction>, AlgoKitTransactError> {
+    let round = block.round.unwrap_or_default();
+    let mut offset = 0;
+    Ok(block
+        .block_transactions()?
+        .into_iter()
+        .map(|transaction| IndexerTransaction {
+            id: Some(transaction.id),
+            ..indexer_transaction(
+                &transaction.signed_transaction,
+                &transaction.apply_data,
+                round,
+                block.timestamp,
+                &mut offset,
+            )
+        })
+        .collect())
+}
+
+fn indexer_transaction(
+    signed_transaction: &SignedTransaction,
+    apply_data: &ApplyData,
+    round: u64,
+    round_time: Option<u64>,
+    offset: &mut u64,
+) -> IndexerTransaction {
+    let transaction = &signed_transaction.transaction;
+    let header = transaction.header();
+    let intra_round_offset = *offset;
+    *offset += 1;
+
+    let eval_delta = apply_data.eval_delta.as_ref();
+    let inner_txns = eval_delta
+        .and_then(|delta| delta.inner_transactions.as_ref())
+        .map(|inner_transactions| {
+            inner_transactions
+                .iter()
+                .map(|inner| {
+                    indexer_transaction(
+                        &inner.signed_transaction,
+                        &inner.apply_data,
+                        round,
+                        round_time,
+                        offset,
+                    )
+                })
+                .collect()
+        });
+
+    let mut indexer_transaction = IndexerTransaction {
+        tx_type: tx_type(transaction).to_string(),
+        sender: header.sender.to_string(),
+        fee: header.fee.unwrap_or_default(),
+        first_valid: header.first_valid as u32,
+        last_valid: header.last_valid as u32,
+        genesis_id: header.genesis_id.clone(),
+        genesis_hash: header.genesis_hash.map(|hash| hash.to_vec()),
+        note: header.note.clone(),
+        group: header.group.map(|group| group.to_vec()),
+        lease: header.lease.map(|lease| lease.to_vec()),
+        rekey_to: header.rekey_to.as_ref().map(ToString::to_string),
+        auth_addr: signed_transaction
+            .auth_address
+            .as_ref()
+            .map(ToString::to_string),
+        signature: signed_transaction
+            .signature
+            .map(|signature| TransactionSignature {
+                sig: Some(signature.to_vec()),
+                ..Default::default()
+            }),
+        confirmed_round: Some(round),
+        round_time,
+        intra_round_offset: Some(intra_round_offset),
+        closing_amount: apply_data.closing_amount,
+        sender_rewards: apply_data.sender_rewards,
+        receiver_rewards: apply_data.receiver_rewards,
+        close_rewards: apply_data.close_rewards,
+        created_asset_index: apply_data.config_asset,
+        created_application_index: apply_data.application_id,
+        logs: eval_delta.and_then(|delta| delta.logs.clone()),
+        inner_txns,
+        ..Default::default()
+    };
+
+    match transaction {
+        Transaction::Payment(payment) => {
+            indexer_transaction.payment_transaction = Some(TransactionPayment {
+                amount: payment.amount,
+                receiver: payment.receiver.to_string(),
+                close_remainder_to: payment.close_remainder_to.as_ref().map(ToString::to_string),
+                close_amount: apply_data.closing_amount,
+            });
+        }
+        Transaction::AssetTransfer(transfer) => {
+            indexer_transaction.asset_transfer_transaction = Some(TransactionAssetTransfer {
+                amount: transfer.amount,
+                asset_id: transfer.asset_id,
+                receiver: transfer.receiver.to_string(),
+                sender: transfer.asset_sender.as_ref().map(ToString::to_string),
+                close_to: transfer
+                    .close_remainder_to
+                    .as_ref()
+                    .map(ToString::to_string),
+                close_amount: apply_data.asset_closing_amount,
+            });
+        }
+        Transaction::AssetConfig(config) => {
+            // Destroying an asset clears all its params
+            let has_params = config.total.is_some()
+                || config.decimals.is_some()
+                || config.default_frozen.is_some()
+                || config.asset_name.is_some()
+                || config.unit_name.is_some()
+                || config.url.is_some()
+                || config.metadata_hash.is_some()
+                || config.manager.is_some()
+                || config.reserve.is_some()
+                || config.freeze.is_some()
+                || config.clawback.is_some();
+            indexer_transaction.asset_config_transaction = Some(TransactionAssetConfig {
+                asset_id: Some(config.asset_id),
+                params: has_params.then(|| AssetParams {
+                    creator: header.sender.to_string(),
+                    total: config.total.unwrap_or_default(),
+                    decimals: config.decimals.unwrap_or_default(),
+                    default_frozen: config.default_frozen,
+                    name: config.asset_name.clone(),
+                    unit_name: config.unit_name.clone(),
+                    url: config.url.clone(),
+                    metadata_hash: config.metadata_hash.map(|hash| hash.to_vec()),
+                    manager: config.manager.as_ref().map(ToString::to_string),
+                    reserve: config.reserve.as_ref().map(ToString::to_string),
+                    freeze: config.freeze.as_ref().map(ToString::to_string),
+                    clawback: config.clawback.as_ref().map(ToString::to_string),
+                    ..Default::default()
+                }),
+            });
+        }
+        Transaction::AssetFreeze(freeze) => {
+            indexer_transaction.asset_freeze_transaction = Some(TransactionAssetFreeze {
+                address: freeze.freeze_target.to_string(),
+                asset_id: freeze.asset_id,
+                new_freeze_status: freeze.frozen,
+            });
+        }
+        Transaction::AppCall(app_call) => {
+            let schema = |schema: &algokit_transact::StateSchema| StateSchema {
+                num_uint: schema.num_uints,
+                num_byte_slice: schema.num_byte_slices,
+            };
+            indexer_transaction.application_transaction = Some(TransactionApplication {
+                application_id: app_call.app_id,
+                on_completion: on_completion(app_call.on_complete),
+                // Indexer returns the arguments base64 encoded
+                application_args: app_call
+                    .args
+                    .as_ref()
+                    .map(|args| args.iter().map(|arg| BASE64_STANDARD.encode(arg)).collect()),
+                accounts: app_call
+                    .account_references
+                    .as_ref()
+                    .map(|accounts| accounts.iter().map(ToString::to_string).collect()),
+                foreign_apps: app_call.app_references.clone(),
+                foreign_assets: app_call.asset_references.clone(),
+                box_references: app_call.box_references.as_ref().map(|boxes| {
+                    boxes
+                        .iter()
+                        .map(|reference| BoxReference {
+                            app: reference.app_id,
+                            name: reference.name.clone(),
+                        })
+                        .collect()
+                }),
+                global_state_schema: app_call.global_state_schema.as_ref().map(schema),
+                local_state_schema: app_call.local_state_schema.as_ref().map(schema),
+                approval_program: app_call.approval_program.clone(),
+                clear_state_program: app_call.clear_state_program.clone(),
+                extra_program_pages: app_call.extra_program_pages,
+                ..Default::default()
+            });
+        }
+        Transaction::KeyRegistration(key_registration) => {
+            indexer_transaction.keyreg_transaction = Some(TransactionKeyreg {
+                non_participation: key_registration.non_participation,
+                selection_participation_key: key_registration.selection_key.map(|key| key.to_vec()),
+                vote_participation_key: key_registration.vote_key.map(|key| key.to_vec()),
+                state_proof_key: key_registration.state_proof_key.map(|key| key.to_vec()),
+                vote_first_valid: key_registration.vote_first,
+                vote_last_valid: key_registration.vote_last,
+                vote_key_dilution: key_registration.vote_key_dilution,
+            });
+        }
+        Transaction::Heartbeat(_) | Transaction::StateProof(_) => {}
+    }
+    indexer_transaction
+}
+
+fn tx_type(transaction: &Transaction) -> &'static str {
+    match transaction {
+        Transaction::Payment(_) => "pay",
+        Transaction::AssetTransfer(_) => "axfer",
+        Transaction::AssetConfig(_) => "acfg",
+        Transaction::AppCall(_) => "appl",
+        Transaction::AssetFreeze(_) => "afrz",
+        Transaction::KeyRegistration(_) => "keyreg",
+        Transaction::Heartbeat(_) => "hb",
+        Transaction::StateProof(_) => "stpf",
+    }
+}
+
+fn on_completion(on_complete: OnApplicationComplete) -> OnCompletion {
+    match on_complete {
+        OnApplicationComplete::NoOp => OnCompletion::Noop,
+        OnApplicationComplete::OptIn => OnCompletion::Optin,
+        OnApplicationComplete::CloseOut => OnCompletion::Closeout,
+        OnApplicationComplete::ClearState => OnCompletion::Clear,
+        OnApplicationComplete::UpdateApplication => OnCompletion::Update,
+        OnApplicationComplete::DeleteApplication => OnCompletion::Delete,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::{TestDataMother, TransactionMother};
+    use algokit_transact::{EvalDelta, SignedTxnInBlock, SignedTxnWithApplyData};
+
+    fn signed(transaction: Transaction) -> SignedTransaction {
+        SignedTransaction {
+            transaction,
+            signature: Some([9; 64]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+        }
+    }
+
+    #[test]
+    fn test_block_transactions_in_indexer_shape() {
+        let payment = TestDataMother::simple_payment();
+        let app_call = TestDataMother::app_call();
+        let inner_payment = TransactionMother::simple_payment().build().unwrap();
+        let block = Block {
+            round: Some(42),
+            timestamp: Some(1_700_000_000),
+            transactions: Some(vec![
+                SignedTxnInBlock {
+                    signed_transaction: signed(payment.transaction.clone()),
+                    apply_data: ApplyData {
+                        closing_amount: Some(5),
+                        ..Default::default()
+                    },
+                    has_genesis_id: false,
+                    has_genesis_hash: false,
+                },
+                SignedTxnInBlock {
+                    signed_transaction: signed(app_call.transaction.clone()),
+                    apply_data: ApplyData {
+                        eval_delta: Some(EvalDelta {
+                            logs: Some(vec![b"log".to_vec()]),
+                            inner_transactions: Some(vec![SignedTxnWithApplyData {
+                                signed_transaction: signed(inner_payment),
+                                apply_data: ApplyData::default(),
+                            }]),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    has_genesis_id: false,
+                    has_genesis_hash: false,
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let transactions = block_transactions(&block).unwrap();
+
+        assert_eq!(transactions.len(), 2);
+        let payment_transaction = &transactions[0];
+        assert_eq!(payment_transaction.id, Some(payment.id));
+        assert_eq!(payment_transaction.tx_type, "pay");
+        assert_eq!(payment_transaction.confirmed_round, Some(42));
+        assert_eq!(payment_transaction.round_time, Some(1_700_000_000));
+        assert_eq!(payment_transaction.intra_round_offset, Some(0));
+        assert_eq!(payment_transaction.closing_amount, Some(5));
+        assert_eq!(
+            payment_transaction.signature.as_ref().unwrap().sig,
+            Some(vec![9; 64])
+        );
+        let Transaction::Payment(payment_fields) = &payment.transaction else {
+            unreachable!()
+        };
+        let indexer_payment = payment_transaction.payment_transaction.as_ref().unwrap();
+        assert_eq!(indexer_payment.amount, payment_fields.amount);
+        assert_eq!(
+            indexer_payment.receiver,
+            payment_fields.receiver.to_string()
+        );
+
+        let app_call_transaction = &transactions[1];
+        assert_eq!(app_call_transaction.id, Some(app_call.id));
+        assert_eq!(app_call_transaction.tx_type, "appl");
+        assert_eq!(app_call_transaction.intra_round_offset, Some(1));
+        assert_eq!(app_call_transaction.logs, Some(vec![b"log".to_vec()]));
+        let Transaction::AppCall(app_call_fields) = &app_call.transaction else {
+            unreachable!()
+        };
+        let application = app_call_transaction
+            .application_transaction
+            .as_ref()
+            .unwrap();
+        assert_eq!(application.application_id, app_call_fields.app_id);
+        assert_eq!(
+            application.application_args,
+            app_call_fields
+                .args
+                .as_ref()
+                .map(|args| args.iter().map(|arg| BASE64_STANDARD.encode(arg)).collect())
+        );
+
+        let inner_transactions = app_call_transaction.inner_txns.as_ref().unwrap();
+        assert_eq!(inner_transactions.len(), 1);
+        assert_eq!(inner_transactions[0].id, None);
+        assert_eq!(inner_transactions[0].tx_type, "pay");
+        assert_eq!(inner_transactions[0].intra_round_offset, Some(2));
+        assert_eq!(inner_transactions[0].confirmed_round, Some(42));
+    }
+}
@@ -0,0 +1,357 @@
+use super::types::{EmittedArc28Event, SubscribedTransaction};
+use algokit_abi::ABIMethod;
+use algokit_transact::Address;
+use base64::{Engine, prelude::BASE64_STANDARD};
+use derive_more::Debug;
+use indexer_client::apis::parameter_enums::TxType;
+use indexer_client::models::Transaction as IndexerTransaction;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Extra logic a [`TransactionFilter`] matches transactions with.
+pub type CustomFilter = Arc<dyn Fn(&SubscribedTransaction) -> bool + Send + Sync>;
+
+/// An ARC-28 event, by the name of its [`Arc28EventGroup`](super::Arc28EventGroup) and event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arc28EventFilter {
+    pub group_name: String,
+    pub event_name: String,
+}
+
+/// Conditions a transaction must all meet to match; unset conditions match any transaction and
+/// list conditions match any of their values.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub txn_type: Option<TxType>,
+    pub sender: Vec<Address>,
+    /// The receiver of a payment or asset transfer
+    pub receiver: Vec<Address>,
+    pub note_prefix: Option<Vec<u8>>,
+    /// The called or created app
+    pub app_id: Vec<u64>,
+    /// Whether the transaction creates an app
+    pub app_create: Option<bool>,
+    /// The transferred, configured, created or frozen asset
+    pub asset_id: Vec<u64>,
+    /// Whether the transaction creates an asset
+    pub asset_create: Option<bool>,
+    /// The minimum amount of a payment or asset transfer
+    pub min_amount: Option<u64>,
+    /// The maximum amount of a payment or asset transfer
+    pub max_amount: Option<u64>,
+    /// The ABI method signature of an app call, e.g. `transfer(address,uint64)void`
+    pub method_signature: Vec<String>,
+    /// The ARC-28 events an app call must emit one of
+    pub arc28_events: Vec<Arc28EventFilter>,
+    /// Extra logic the transaction must satisfy, called after all other conditions matched
+    #[debug(skip)]
+    pub custom_filter: Option<CustomFilter>,
+}
+
+impl TransactionFilter {
+    /// Whether `transaction`, which emitted `arc28_events`, meets all conditions.
+    pub fn matches(
+        &self,
+        transaction: &SubscribedTransaction,
+        arc28_events: &[EmittedArc28Event],
+    ) -> bool {
+        let txn = &transaction.transaction;
+        self.txn_type
+            .is_none_or(|txn_type| txn.tx_type == txn_type.to_string())
+            && (self.sender.is_empty()
+                || self
+                    .sender
+                    .iter()
+                    .any(|sender| sender.to_string() == txn.sender))
+            && (self.receiver.is_empty()
+                || receiver(txn).is_some_and(|receiver| {
+                    self.receiver
+                        .iter()
+                        .any(|address| address.to_string() == receiver)
+                }))
+            && self.note_prefix.as_ref().is_none_or(|prefix| {
+                txn.note
+                    .as_ref()
+                    .is_some_and(|note| note.starts_with(prefix))
+            })
+            && (self.app_id.is_empty() || app_id(txn).is_some_and(|id| self.app_id.contains(&id)))
+            && self
+                .app_create
+                .is_none_or(|create| creates_app(txn) == create)
+            && (self.asset_id.is_empty()
+                || asset_id(txn).is_some_and(|id| self.asset_id.contains(&id)))
+            && self
+                .asset_create
+                .is_none_or(|create| creates_asset(txn) == create)
+            && self
+                .min_amount
+                .is_none_or(|min| amount(txn).is_some_and(|amount| amount >= min))
+            && self
+                .max_amount
+                .is_none_or(|max| amount(txn).is_some_and(|amount| amount <= max))
+            && (self.method_signature.is_empty()
+                || method_selector(txn).is_some_and(|selector| {
+                    self.method_signature.iter().any(|signature| {
+                        ABIMethod::from_str(signature)
+                            .and_then(|method| method.selector())
+                            .is_ok_and(|expected| expected == selector)
+                    })
+                }))
+            && (self.arc28_events.is_empty()
+                || arc28_events.iter().any(|emitted| {
+                    self.arc28_events.iter().any(|filter| {
+                        filter.group_name == emitted.group_name
+                            && filter.event_name == emitted.event.name
+                    })
+                }))
+            && self
+                .custom_filter
+                .as_ref()
+                .is_none_or(|custom_filter| custom_filter(transaction))
+    }
+}
+
+fn receiver(txn: &IndexerTransaction) -> Option<&str> {
+    txn.payment_transaction
+        .as_ref()
+        .map(|payment| payment.receiver.as_str())
+        .or_else(|| {
+            txn.asset_transfer_transaction
+                .as_ref()
+                .map(|transfer| transfer.receiver.as_str())
+        })
+}
+
+fn amount(txn: &IndexerTransaction) -> Option<u64> {
+    txn.payment_transaction
+        .as_ref()
+        .map(|payment| payment.amount)
+        .or_else(|| {
+            txn.asset_transfer_transaction
+                .as_ref()
+                .map(|transfer| transfer.amount)
+        })
+}
+
+pub(super) fn app_id(txn: &IndexerTransaction) -> Option<u64> {
+    txn.created_application_index.or_else(|| {
+        txn.application_transaction
+            .as_ref()
+            .map(|app_call| app_call.application_id)
+            .filter(|&id| id != 0)
+    })
+}
+
+fn creates_app(txn: &IndexerTransaction) -> bool {
+    txn.application_transaction
+        .as_ref()
+        .is_some_and(|app_call| app_call.application_id == 0)
+}
+
+fn asset_id(txn: &IndexerTransaction) -> Option<u64> {
+    txn.created_asset_index
+        .or_else(|| {
+            txn.asset_transfer_transaction
+                .as_ref()
+                .map(|transfer| transfer.asset_id)
+        })
+        .or_else(|| {
+            txn.asset_config_transaction
+                .as_ref()
+                .and_then(|config| config.asset_id)
+                .filter(|&id| id != 0)
+        })
+        .or_else(|| {
+            txn.asset_freeze_transaction
+                .as_ref()
+                .map(|freeze| freeze.asset_id)
+        })
+}
+
+fn creates_asset(txn: &IndexerTransaction) -> bool {
+    txn.asset_config_transaction
+        .as_ref()
+        .is_some_and(|config| config.asset_id.unwrap_or(0) == 0)
+}
+
+/// The ABI method selector of an app call, i.e. the first 4 bytes of its first argument.
+fn method_selector(txn: &IndexerTransaction) -> Option<Vec<u8>> {
+    let first_arg = txn
+        .application_transaction
+        .as_ref()?
+        .application_args
+        .as_ref()?
+        .first()?;
+    let first_arg = BASE64_STANDARD.decode(first_arg).ok()?;
+    first_arg.get(..4).map(<[u8]>::to_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_abi::DecodedEvent;
+    use algokit_transact::test_utils::AccountMother;
+    use indexer_client::models::{
+        TransactionApplication, TransactionAssetTransfer, TransactionPayment,
+    };
+
+    fn subscribed(transaction: IndexerTransaction) -> SubscribedTransaction {
+        SubscribedTransaction {
+            id: "TXID".to_string(),
+            parent_transaction_id: None,
+            transaction,
+            filters_matched: Vec::new(),
+            arc28_events: Vec::new(),
+        }
+    }
+
+    fn payment(sender: &Address, receiver: &Address, amount: u64) -> IndexerTransaction {
+        IndexerTransaction {
+            tx_type: "pay".to_string(),
+            sender: sender.to_string(),
+            note: Some(b"hello world".to_vec()),
+            payment_transaction: Some(TransactionPayment {
+                amount,
+                receiver: receiver.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn app_call(app_id: u64, args: Vec<Vec<u8>>) -> IndexerTransaction {
+        IndexerTransaction {
+            tx_type: "appl".to_string(),
+            application_transaction: Some(TransactionApplication {
+                application_id: app_id,
+                application_args: Some(
+                    args.iter().map(|arg| BASE64_STANDARD.encode(arg)).collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_payment_filters() {
+        let sender = AccountMother::account().address();
+        let receiver = AccountMother::neil().address();
+        let transaction = subscribed(payment(&sender, &receiver, 5_000));
+
+        let matches = |filter: TransactionFilter| filter.matches(&transaction, &[]);
+        assert!(matches(TransactionFilter::default()));
+        assert!(matches(TransactionFilter {
+            txn_type: Some(TxType::Pay),
+            sender: vec![receiver.clone(), sender.clone()],
+            receiver: vec![receiver.clone()],
+            note_prefix: Some(b"hello".to_vec()),
+            min_amount: Some(5_000),
+            max_amount: Some(5_000),
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            txn_type: Some(TxType::Axfer),
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            sender: vec![receiver.clone()],
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            min_amount: Some(5_001),
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            note_prefix: Some(b"world".to_vec()),
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            app_id: vec![1],
+            ..Default::default()
+        }));
+        assert!(!matches(TransactionFilter {
+            custom_filter: Some(Arc::new(|transaction| transaction.id != "TXID")),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_asset_transfer_filters() {
+        let sender = AccountMother::account().address();
+        let transaction = subscribed(IndexerTransaction {
+            tx_type: "axfer".to_string(),
+            sender: sender.to_string(),
+            asset_transfer_transaction: Some(TransactionAssetTransfer {
+                amount: 10,
+                asset_id: 1234,
+                receiver: sender.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert!(
+            TransactionFilter {
+                asset_id: vec![1234],
+                receiver: vec![sender],
+                max_amount: Some(10),
+                asset_create: Some(false),
+                ..Default::default()
+            }
+            .matches(&transaction, &[])
+        );
+        assert!(
+            !TransactionFilter {
+                asset_id: vec![4321],
+                ..Default::default()
+            }
+            .matches(&transaction, &[])
+        );
+    }
+
+    #[test]
+    fn test_app_call_filters() {
+        let method = ABIMethod::from_str("hello(string)string").unwrap();
+        let mut args = vec![method.selector().unwrap()];
+        args.push(b"\x00\x05world".to_vec());
+        let transaction = subscribed(app_call(42, args));
+        let event = EmittedArc28Event {
+            group_name: "greetings".to_string(),
+            event: DecodedEvent {
+                name: "Greeted".to_string(),
+                signature: "Greeted(string)".to_string(),
+                args: Vec::new(),
+            },
+        };
+
+        let filter = TransactionFilter {
+            app_id: vec![42],
+            app_create: Some(false),
+            method_signature: vec!["hello(string)string".to_string()],
+            arc28_events: vec![Arc28EventFilter {
+                group_name: "greetings".to_string(),
+                event_name: "Greeted".to_string(),
+            }],
+            ..Default::default()
+        };
+        assert!(filter.matches(&transaction, std::slice::from_ref(&event)));
+        assert!(!filter.matches(&transaction, &[]));
+        assert!(
+            !TransactionFilter {
+                method_signature: vec!["goodbye(string)string".to_string()],
+                ..Default::default()
+            }
+            .matches(&transaction, &[event])
+        );
+
+        let create = subscribed(app_call(0, Vec::new()));
+        assert!(
+            TransactionFilter {
+                app_create: Some(true),
+                ..Default::default()
+            }
+            .matches(&create, &[])
+        );
+    }
+}
@@ -0,0 +1,15 @@
+pub mod algorand_subscriber;
+mod block_transactions;
+pub mod filter;
+pub mod types;
+pub mod watermark_store;
+
+pub use algorand_subscriber::{AlgorandSubscriber, SubscriberError, SubscriptionHandler};
+pub use filter::{Arc28EventFilter, CustomFilter, TransactionFilter};
+pub use types::{
+    AlgorandSubscriberConfig, Arc28EventGroup, EmittedArc28Event, NamedTransactionFilter,
+    SubscribedTransaction, SubscriptionResult, SyncBehaviour,
+};
+pub use watermark_store::{
+    FileWatermarkStore, InMemoryWatermarkStore, WatermarkStore, WatermarkStoreError,
+};
@@ -0,0 +1,130 @@
+use super::filter::TransactionFilter;
+use algokit_abi::{Arc56Contract, DecodedEvent};
+use indexer_client::models::Transaction as IndexerTransaction;
+use std::time::Duration;
+
+/// How a subscriber catches up when it is more than `max_rounds_to_sync` rounds behind the tip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SyncBehaviour {
+    /// Skip to the newest `max_rounds_to_sync` rounds, dropping the rounds in between
+    SkipSyncNewest,
+    /// Sync the oldest `max_rounds_to_sync` rounds after the watermark, catching up over
+    /// several polls
+    #[default]
+    SyncOldest,
+    /// Like `SyncOldest`, but when nothing has been processed yet (the watermark is 0) start
+    /// at the current round rather than the beginning of the chain
+    SyncOldestStartNow,
+    /// Fail the poll
+    Fail,
+}
+
+/// A filter with the name its matches are reported and dispatched under.
+#[derive(Debug, Clone)]
+pub struct NamedTransactionFilter {
+    pub name: String,
+    pub filter: TransactionFilter,
+}
+
+/// ARC-28 events to decode from the logs of app calls, e.g. to filter on them.
+#[derive(Debug, Clone)]
+pub struct Arc28EventGroup {
+    /// The name filters refer to the events of the group by
+    pub group_name: String,
+    /// The app spec declaring the events
+    pub app_spec: Arc56Contract,
+    /// The apps whose logs are decoded, or empty for all apps
+    pub process_for_app_ids: Vec<u64>,
+    /// Whether a log that matches an event selector but fails to decode fails the poll,
+    /// rather than being skipped
+    pub fail_on_error: bool,
+}
+
+/// An ARC-28 event emitted by a subscribed transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmittedArc28Event {
+    pub group_name: String,
+    pub event: DecodedEvent,
+}
+
+/// Configuration of an [`AlgorandSubscriber`](super::AlgorandSubscriber).
+#[derive(Debug, Clone)]
+pub struct AlgorandSubscriberConfig {
+    /// The filters to match transactions against; a transaction matching several filters is
+    /// delivered once with all their names
+    pub filters: Vec<NamedTransactionFilter>,
+    pub arc28_events: Vec<Arc28EventGroup>,
+    /// The most rounds synced in one poll
+    pub max_rounds_to_sync: u64,
+    pub sync_behaviour: SyncBehaviour,
+    /// How long to wait between polls when caught up and not waiting for blocks
+    pub frequency: Duration,
+    /// When caught up, wait for the next block with algod rather than sleeping `frequency`
+    pub wait_for_block_when_at_tip: bool,
+    /// The number of recent rounds algod keeps the blocks of, e.g. `u64::MAX` for an archival
+    /// node; older rounds are read from indexer
+    pub algod_block_retention: u64,
+}
+
+impl Default for AlgorandSubscriberConfig {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            arc28_events: Vec::new(),
+            max_rounds_to_sync: 500,
+            sync_behaviour: SyncBehaviour::default(),
+            frequency: Duration::from_secs(1),
+            wait_for_block_when_at_tip: true,
+            // Non-archival nodes keep at least the last 1000 blocks
+            algod_block_retention: 1000,
+        }
+    }
+}
+
+/// A transaction that matched at least one filter of a subscriber.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscribedTransaction {
+    /// The transaction ID, or `<root id>/inner/<n>` for the n-th (1-based, depth first) inner
+    /// transaction of a top level transaction
+    pub id: String,
+    /// The ID of the top level transaction of an inner transaction
+    pub parent_transaction_id: Option<String>,
+    /// The transaction in the shape indexer returns it, also when read from an algod block,
+    /// without its inner transactions for inner transactions that matched separately
+    pub transaction: IndexerTransaction,
+    /// The names of the filters the transaction matched
+    pub filters_matched: Vec<String>,
+    pub arc28_events: Vec<EmittedArc28Event>,
+}
+
+/// The outcome of syncing a range of rounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscriptionResult {
+    /// The current round of algod when the poll started
+    pub current_round: u64,
+    /// The watermark before the poll
+    pub starting_watermark: u64,
+    /// The watermark after the poll, i.e. the last round synced
+    pub new_watermark: u64,
+    /// The synced (inclusive) round range, `None` when there was nothing to sync
+    pub synced_round_range: Option<(u64, u64)>,
+    /// The matched transactions, in round and intra-round order
+    pub subscribed_transactions: Vec<SubscribedTransaction>,
+}
+
+impl SubscriptionResult {
+    /// The matched transactions of the filter named `filter_name`.
+    pub fn matches<'a>(
+        &'a self,
+        filter_name: &'a str,
+    ) -> impl Iterator<Item = &'a SubscribedTransaction> + 'a {
+        self.subscribed_transactions
+            .iter()
+            .filter(move |transaction| {
+                transaction
+                    .filters_matched
+                    .iter()
+                    .any(|name| name == filter_name)
+            })
+    }
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use snafu::Snafu;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[derive(Debug, Snafu)]
+pub enum WatermarkStoreError {
+    #[snafu(display("Watermark IO error: {message}"))]
+    IoError { message: String },
+    #[snafu(display("Invalid watermark: {message}"))]
+    InvalidWatermark { message: String },
+}
+
+/// Persistence for the watermark of a transaction subscriber, i.e. the last round it has
+/// fully processed.
+///
+/// The subscriber reads the watermark on start to resume where it stopped and sets it after
+/// each processed batch of rounds. Implement this trait to store the watermark elsewhere, e.g.
+/// in a database alongside the processed data so both are updated atomically.
+#[async_trait]
+pub trait WatermarkStore: Send + Sync {
+    /// Get the last processed round, or `None` if nothing has been processed yet.
+    async fn get(&self) -> Result<Option<u64>, WatermarkStoreError>;
+
+    /// Record `watermark` as the last processed round.
+    async fn set(&self, watermark: u64) -> Result<(), WatermarkStoreError>;
+}
+
+/// A [`WatermarkStore`] that keeps the watermark in memory, so processing restarts from the
+/// beginning on every run.
+#[derive(Debug, Default)]
+pub struct InMemoryWatermarkStore {
+    watermark: Mutex<Option<u64>>,
+}
+
+impl InMemoryWatermarkStore {
+    pub fn new(initial_watermark: Option<u64>) -> Self {
+        Self {
+            watermark: Mutex::new(initial_watermark),
+        }
+    }
+}
+
+#[async_trait]
+impl WatermarkStore for InMemoryWatermarkStore {
+    async fn get(&self) -> Result<Option<u64>, WatermarkStoreError> {
+        Ok(*self.watermark.lock().unwrap())
+    }
+
+    async fn set(&self, watermark: u64) -> Result<(), WatermarkStoreError> {
+        *self.watermark.lock().unwrap() = Some(watermark);
+        Ok(())
+    }
+}
+
+/// A [`WatermarkStore`] that persists the watermark as a decimal round number in a file.
+///
+/// Writes go to a temporary file next to it that then replaces the watermark file, so a crash
+/// mid-write leaves the previous watermark intact.
+#[derive(Debug, Clone)]
+pub struct FileWatermarkStore {
+    path: PathBuf,
+}
+
+impl FileWatermarkStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn temp_path(&self) -> PathBuf {
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".tmp");
+        self.path.with_file_name(file_name)
+    }
+}
+
+#[async_trait]
+impl WatermarkStore for FileWatermarkStore {
+    async fn get(&self) -> Result<Option<u64>, WatermarkStoreError> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(WatermarkStoreError::IoError {
+                    message: format!("Failed to read {}: {}", self.path.display(), e),
+                });
+            }
+        };
+
+        contents
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| WatermarkStoreError::InvalidWatermark {
+                message: format!("{:?} in {}: {}", contents, self.path.display(), e),
+            })
+    }
+
+    async fn set(&self, watermark: u64) -> Result<(), WatermarkStoreError> {
+        let temp_path = self.temp_path();
+        fs::write(&temp_path, watermark.to_string())
+            .and_then(|_| fs::rename(&temp_path, &self.path))
+            .map_err(|e| WatermarkStoreError::IoError {
+                message: format!("Failed to write {}: {}", self.path.display(), e),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_watermark_store() {
+        let store = InMemoryWatermarkStore::default();
+        assert_eq!(store.get().await.unwrap(), None);
+
+        store.set(42).await.unwrap();
+
+        assert_eq!(store.get().await.unwrap(), Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_file_watermark_store_resumes_from_file() {
+        let path = std::env::temp_dir().join(format!(
+            "algokit_watermark_{}_{}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        let store = FileWatermarkStore::new(&path);
+        assert_eq!(store.get().await.unwrap(), None);
+
+        store.set(1000).await.unwrap();
+        store.set(1001).await.unwrap();
+
+        let restarted = FileWatermarkStore::new(&path);
+        assert_eq!(restarted.get().await.unwrap(), Some(1001));
+        assert!(!restarted.temp_path().exists());
+
+        fs::write(&path, "not a round").unwrap();
+        assert!(matches!(
+            restarted.get().await,
+            Err(WatermarkStoreError::InvalidWatermark { .. })
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+}
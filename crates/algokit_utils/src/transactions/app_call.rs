@@ -7,11 +7,13 @@ use crate::{
     TransactionWithSigner, create_transaction_params,
 };
 use algokit_abi::{
-    ABIMethod, ABIMethodArgType, ABIReferenceValue, ABIType, ABIValue, abi_type::BitSize,
+    ABIMethod, ABIMethodArgType, ABIReferenceValue, ABIValue, ForeignReferences,
+    constants::{ARGS_TUPLE_PACKING_THRESHOLD, MAX_UNPACKED_METHOD_ARGS},
+    encode_method_args,
 };
 use algokit_transact::{
-    Address, AppCallTransactionBuilder, AppCallTransactionFields, BoxReference,
-    OnApplicationComplete, StateSchema, Transaction, TransactionHeader,
+    Address, AppCallTransactionBuilder, AppCallTransactionFields, BoxReference, MAX_APP_ARGS,
+    MAX_ARGS_SIZE, OnApplicationComplete, StateSchema, Transaction, TransactionHeader,
 };
 use derive_more::Debug;
 use num_bigint::BigUint;
@@ -512,8 +514,6 @@ where
     pub box_references: Option<Vec<BoxReference>>,
 }
 
-const ARGS_TUPLE_PACKING_THRESHOLD: usize = 14; // 14+ args trigger tuple packing, excluding the method selector  (arg 0)
-
 fn process_app_method_call_args(args: &[AppMethodCallArg]) -> Vec<ProcessedAppMethodCallArg> {
     args.iter()
         .map(|arg| match arg {
@@ -755,222 +755,111 @@ trait AppMethodCallCommonParams {
     fn asset_references(&self) -> Option<&Vec<u64>>;
 }
 
-fn populate_method_args_into_reference_arrays(
-    sender: &Address,
-    app_id: u64,
-    method_args: &[ProcessedAppMethodCallArg],
-    account_references: &mut Vec<Address>,
-    app_references: &mut Vec<u64>,
-    asset_references: &mut Vec<u64>,
-) -> Result<(), ComposerError> {
-    for method_arg in method_args.iter() {
-        if let ProcessedAppMethodCallArg::ABIReference(value) = method_arg {
-            match value {
-                ABIReferenceValue::Account(addr_str) => {
-                    let address = Address::from_str(addr_str).map_err(|_e| {
-                        ComposerError::TransactionError {
-                            message: format!("Invalid address {}", addr_str),
-                        }
-                    })?;
-
-                    if address != *sender && !account_references.contains(&address) {
-                        account_references.push(address);
-                    }
-                }
-                ABIReferenceValue::Asset(asset_id) => {
-                    if !asset_references.contains(asset_id) {
-                        asset_references.push(*asset_id);
-                    }
-                }
-                ABIReferenceValue::Application(app_id_ref) => {
-                    if *app_id_ref != app_id && !app_references.contains(app_id_ref) {
-                        app_references.push(*app_id_ref);
-                    }
-                }
-            }
-        }
-    }
-
-    Ok(())
-}
-
-fn calculate_method_arg_reference_array_index(
-    ref_value: &ABIReferenceValue,
-    sender: &Address,
-    app_id: u64,
-    account_references: &[Address],
-    app_references: &[u64],
-    asset_references: &[u64],
-) -> Result<u8, ComposerError> {
-    match ref_value {
-        ABIReferenceValue::Account(addr_str) => {
-            let address =
-                Address::from_str(addr_str).map_err(|_e| ComposerError::TransactionError {
-                    message: format!("Invalid address {}", addr_str),
-                })?;
-
-            if address == *sender {
-                // If address is the same as sender, use index 0
-                Ok(0)
-            } else if let Some(existing_index) = account_references
-                .iter()
-                .position(|ref_addr| *ref_addr == address)
-            {
-                // If address already exists in account_references, use existing index + 1
-                Ok((existing_index + 1) as u8)
-            } else {
-                Err(ComposerError::ABIEncodingError {
-                    message: format!("Account {} not found in reference array", addr_str),
-                })
-            }
-        }
-        ABIReferenceValue::Asset(asset_id) => {
-            if let Some(existing_index) = asset_references
-                .iter()
-                .position(|&ref_id| ref_id == *asset_id)
-            {
-                // If asset already exists in asset_references, use existing index
-                Ok(existing_index as u8)
-            } else {
-                Err(ComposerError::ABIEncodingError {
-                    message: format!("Asset {} not found in reference array", asset_id),
-                })
-            }
-        }
-        ABIReferenceValue::Application(app_id_ref) => {
-            if *app_id_ref == app_id {
-                // If app ID is the same as the current app, use index 0
-                Ok(0)
-            } else if let Some(existing_index) = app_references
-                .iter()
-                .position(|&ref_id| ref_id == *app_id_ref)
-            {
-                // If app already exists in app_references, use existing index + 1
-                Ok((existing_index + 1) as u8)
-            } else {
-                Err(ComposerError::ABIEncodingError {
-                    message: format!("Application {} not found in reference array", app_id_ref),
-                })
-            }
-        }
-    }
-}
-
 fn encode_method_arguments(
     method: &ABIMethod,
     args: &[ProcessedAppMethodCallArg],
-    sender: &Address,
+    sender: &str,
     app_id: u64,
-    account_references: &[Address],
-    app_references: &[u64],
-    asset_references: &[u64],
+    foreign_references: &ForeignReferences,
 ) -> Result<Vec<Vec<u8>>, ComposerError> {
-    let mut encoded_args = Vec::<Vec<u8>>::new();
+    let abi_values: Vec<ABIValue> =
+        args.iter()
+            .filter_map(|arg_value| -> Option<Result<ABIValue, ComposerError>> {
+                match arg_value {
+                    ProcessedAppMethodCallArg::ABIReference(value) => {
+                        let foreign_index = foreign_references
+                            .index_of(value, sender, app_id)
+                            .map_err(|e| ComposerError::ABIEncodingError {
+                                message: e.to_string(),
+                            });
+                        Some(foreign_index.map(|index| ABIValue::Uint(BigUint::from(index))))
+                    }
+                    ProcessedAppMethodCallArg::ABIValue(value) => Some(Ok(value.clone())),
+                    ProcessedAppMethodCallArg::TransactionPlaceholder => None,
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-    // Insert method selector at the front
-    let method_selector = method
-        .selector()
-        .map_err(|e| ComposerError::ABIEncodingError {
-            message: format!("Failed to get method selector: {}", e),
+    let encoded_args =
+        encode_method_args(method, &abi_values).map_err(|e| ComposerError::ABIEncodingError {
+            message: format!("Failed to encode method arguments: {}", e),
         })?;
-    encoded_args.push(method_selector);
 
-    let abi_types = method
+    let arg_labels = method
         .args
         .iter()
-        .filter_map(|arg| {
-            match &arg.arg_type {
-                ABIMethodArgType::Value(abi_type) => Some(abi_type.clone()),
-                // Reference and transaction types encoded as uint8 indexes
-                ABIMethodArgType::Reference(_) => Some(ABIType::Uint(
-                    BitSize::new(8).expect("8 should always be a valid BitSize"),
-                )),
-                ABIMethodArgType::Transaction(_) => None,
-            }
-        })
+        .enumerate()
+        .filter(|(_, arg)| !matches!(arg.arg_type, ABIMethodArgType::Transaction(_)))
+        .map(|(index, arg)| method_arg_label(arg.name.as_deref(), index))
         .collect::<Vec<_>>();
 
-    let abi_values: Vec<ABIValue> = args
-        .iter()
-        .filter_map(|arg_value| -> Option<Result<ABIValue, ComposerError>> {
-            match arg_value {
-                ProcessedAppMethodCallArg::ABIReference(value) => {
-                    let foreign_index = calculate_method_arg_reference_array_index(
-                        value,
-                        sender,
-                        app_id,
-                        account_references,
-                        app_references,
-                        asset_references,
-                    );
-                    Some(foreign_index.map(|index| ABIValue::Uint(BigUint::from(index))))
-                }
-                ProcessedAppMethodCallArg::ABIValue(value) => Some(Ok(value.clone())),
-                ProcessedAppMethodCallArg::TransactionPlaceholder => None,
-            }
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    if abi_values.len() != abi_types.len() {
-        return Err(ComposerError::ABIEncodingError {
-            message: "Mismatch in length of non-transaction arguments".to_string(),
-        });
-    }
-
-    // Apply ARC-4 tuple packing for methods with more than 14 arguments
-    // 14 instead of 15 in the ARC-4 because the first argument (method selector) is added later on
-    if abi_types.len() > ARGS_TUPLE_PACKING_THRESHOLD {
-        encoded_args.extend(encode_args_with_tuple_packing(&abi_types, &abi_values)?);
+    let mut encoded_arg_labels = vec!["method selector".to_string()];
+    if arg_labels.len() > MAX_UNPACKED_METHOD_ARGS {
+        encoded_arg_labels.extend_from_slice(&arg_labels[..ARGS_TUPLE_PACKING_THRESHOLD]);
+        encoded_arg_labels.push(format!(
+            "packed tuple of {}",
+            arg_labels[ARGS_TUPLE_PACKING_THRESHOLD..].join(", ")
+        ));
     } else {
-        encoded_args.extend(encode_args_individually(&abi_types, &abi_values)?);
+        encoded_arg_labels.extend(arg_labels);
     }
 
+    validate_encoded_args_size(&method.name, &encoded_args, &encoded_arg_labels)?;
+
     Ok(encoded_args)
 }
 
-fn encode_args_with_tuple_packing(
-    abi_types: &[ABIType],
-    abi_values: &[ABIValue],
-) -> Result<Vec<Vec<u8>>, ComposerError> {
-    // Encode first 14 arguments individually
-    let first_14_abi_types = &abi_types[..ARGS_TUPLE_PACKING_THRESHOLD];
-    let first_14_abi_values = &abi_values[..ARGS_TUPLE_PACKING_THRESHOLD];
-    let encoded_args: &mut Vec<Vec<u8>> =
-        &mut encode_args_individually(first_14_abi_types, first_14_abi_values)?;
-
-    // Pack remaining arguments into tuple at position 14
-    let remaining_abi_types = &abi_types[ARGS_TUPLE_PACKING_THRESHOLD..];
-    let remaining_abi_values = &abi_values[ARGS_TUPLE_PACKING_THRESHOLD..];
-    let tuple_type = ABIType::Tuple(remaining_abi_types.to_vec());
-    let tuple_value = ABIValue::Array(remaining_abi_values.to_vec());
-    let tuple_encoded =
-        tuple_type
-            .encode(&tuple_value)
-            .map_err(|e| ComposerError::ABIEncodingError {
-                message: format!("Failed to encode ABI value: {}", e),
-            })?;
-
-    encoded_args.push(tuple_encoded);
-
-    Ok(encoded_args.to_vec())
+fn method_arg_label(name: Option<&str>, index: usize) -> String {
+    match name {
+        Some(name) => format!("argument {} ({})", index, name),
+        None => format!("argument {}", index),
+    }
 }
 
-fn encode_args_individually(
-    abi_types: &[ABIType],
-    abi_values: &[ABIValue],
-) -> Result<Vec<Vec<u8>>, ComposerError> {
-    let encoded_args: &mut Vec<Vec<u8>> = &mut Vec::new();
-
-    for (abi_value, abi_type) in abi_values.iter().zip(abi_types.iter()) {
-        let encoded = abi_type
-            .encode(abi_value)
-            .map_err(|e| ComposerError::ABIEncodingError {
-                message: format!("Failed to encode ABI value: {}", e),
-            })?;
-        encoded_args.push(encoded);
+/// Validates the encoded app args against the protocol limits, so an oversized method call
+/// is reported against the offending ABI argument rather than being rejected by the node.
+fn validate_encoded_args_size(
+    method_name: &str,
+    encoded_args: &[Vec<u8>],
+    labels: &[String],
+) -> Result<(), ComposerError> {
+    if encoded_args.len() > MAX_APP_ARGS {
+        return Err(ComposerError::ABIEncodingError {
+            message: format!(
+                "Method {} requires {} app args after encoding, which exceeds the max of {}",
+                method_name,
+                encoded_args.len(),
+                MAX_APP_ARGS
+            ),
+        });
     }
 
-    Ok(encoded_args.to_vec())
+    let mut total_size = 0;
+    for (encoded_arg, label) in encoded_args.iter().zip(labels.iter()) {
+        if encoded_arg.len() > MAX_ARGS_SIZE {
+            return Err(ComposerError::ABIEncodingError {
+                message: format!(
+                    "{} of method {} is {} bytes after encoding, which exceeds the max app arg size of {} bytes",
+                    label,
+                    method_name,
+                    encoded_arg.len(),
+                    MAX_ARGS_SIZE
+                ),
+            });
+        }
+
+        total_size += encoded_arg.len();
+        if total_size > MAX_ARGS_SIZE {
+            return Err(ComposerError::ABIEncodingError {
+                message: format!(
+                    "{} of method {} brings the total app args size to {} bytes, which exceeds the max of {} bytes",
+                    label, method_name, total_size, MAX_ARGS_SIZE
+                ),
+            });
+        }
+    }
+
+    Ok(())
 }
 
 pub fn build_app_call(
@@ -1021,6 +910,7 @@ pub fn build_app_create_call(params: &AppCreateParams, header: TransactionHeader
         app_references: params.app_references.clone(),
         asset_references: params.asset_references.clone(),
         box_references: params.box_references.clone(),
+        access_references: None,
     })
 }
 
@@ -1039,6 +929,7 @@ pub fn build_app_update_call(params: &AppUpdateParams, header: TransactionHeader
         app_references: params.app_references.clone(),
         asset_references: params.asset_references.clone(),
         box_references: params.box_references.clone(),
+        access_references: None,
     })
 }
 
@@ -1057,6 +948,7 @@ pub fn build_app_delete_call(params: &AppDeleteParams, header: TransactionHeader
         app_references: params.app_references.clone(),
         asset_references: params.asset_references.clone(),
         box_references: params.box_references.clone(),
+        access_references: None,
     })
 }
 
@@ -1069,29 +961,46 @@ where
     T: AppMethodCallCommonParams,
     F: FnOnce(TransactionHeader, Vec<Address>, Vec<u64>, Vec<u64>, Vec<Vec<u8>>) -> Transaction,
 {
-    let mut account_references = params.account_references().cloned().unwrap_or_default();
-    let mut app_references = params.app_references().cloned().unwrap_or_default();
-    let mut asset_references = params.asset_references().cloned().unwrap_or_default();
-
-    populate_method_args_into_reference_arrays(
-        &header.sender,
+    let sender = header.sender.to_string();
+    let mut foreign_references = ForeignReferences {
+        accounts: params
+            .account_references()
+            .map(|accounts| accounts.iter().map(Address::to_string).collect())
+            .unwrap_or_default(),
+        apps: params.app_references().cloned().unwrap_or_default(),
+        assets: params.asset_references().cloned().unwrap_or_default(),
+    };
+    foreign_references.add_references(
+        params.args().iter().filter_map(|arg| match arg {
+            ProcessedAppMethodCallArg::ABIReference(value) => Some(value),
+            _ => None,
+        }),
+        &sender,
         params.app_id(),
-        params.args(),
-        &mut account_references,
-        &mut app_references,
-        &mut asset_references,
-    )?;
+    );
 
     let encoded_args = encode_method_arguments(
         params.method(),
         params.args(),
-        &header.sender,
+        &sender,
         params.app_id(),
-        &account_references,
-        &app_references,
-        &asset_references,
+        &foreign_references,
     )?;
 
+    let ForeignReferences {
+        accounts,
+        apps: app_references,
+        assets: asset_references,
+    } = foreign_references;
+    let account_references = accounts
+        .iter()
+        .map(|address| {
+            Address::from_str(address).map_err(|_e| ComposerError::TransactionError {
+                message: format!("Invalid address {}", address),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     Ok(transaction_builder(
         header,
         account_references,
@@ -1123,6 +1032,7 @@ pub fn build_app_call_method_call(
                 app_references: Some(app_refs),
                 asset_references: Some(asset_refs),
                 box_references: params.box_references.clone(),
+                access_references: None,
             })
         },
     )
@@ -1150,6 +1060,7 @@ pub fn build_app_create_method_call(
                 app_references: Some(app_refs),
                 asset_references: Some(asset_refs),
                 box_references: params.box_references.clone(),
+                access_references: None,
             })
         },
     )
@@ -1185,6 +1096,7 @@ pub fn build_app_update_method_call(
                 app_references: Some(app_refs),
                 asset_references: Some(asset_refs),
                 box_references: params.box_references.clone(),
+                access_references: None,
             })
         },
     )
@@ -1212,7 +1124,89 @@ pub fn build_app_delete_method_call(
                 app_references: Some(app_refs),
                 asset_references: Some(asset_refs),
                 box_references: params.box_references.clone(),
+                access_references: None,
             })
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::{AccountMother, TransactionHeaderMother};
+
+    fn method_call_params(
+        signature: &str,
+        args: Vec<ProcessedAppMethodCallArg>,
+    ) -> AppCallMethodCallParams<ProcessedAppMethodCallArg> {
+        AppCallMethodCallParams {
+            sender: AccountMother::account().address(),
+            app_id: 1234,
+            method: ABIMethod::from_str(signature).unwrap(),
+            args,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_method_call_args_within_size_budget() {
+        let params = method_call_params(
+            "store(string,byte[])void",
+            vec![
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from("a".repeat(500))),
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::Array(vec![
+                    ABIValue::from_byte(1);
+                    500
+                ])),
+            ],
+        );
+
+        let result = build_app_call_method_call(
+            &params,
+            TransactionHeaderMother::simple_testnet().build().unwrap(),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_method_call_arg_exceeding_max_size_names_argument() {
+        let params = method_call_params(
+            "store(uint64,string)void",
+            vec![
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from(1u64)),
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from("a".repeat(MAX_ARGS_SIZE))),
+            ],
+        );
+
+        let result = build_app_call_method_call(
+            &params,
+            TransactionHeaderMother::simple_testnet().build().unwrap(),
+        );
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("argument 1 (arg1) of method store"));
+        assert!(message.contains("exceeds the max app arg size"));
+    }
+
+    #[test]
+    fn test_method_call_args_exceeding_total_size_names_argument() {
+        let params = method_call_params(
+            "store(string,string,string)void",
+            vec![
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from("a".repeat(1000))),
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from("b".repeat(1000))),
+                ProcessedAppMethodCallArg::ABIValue(ABIValue::from("c".repeat(1000))),
+            ],
+        );
+
+        let result = build_app_call_method_call(
+            &params,
+            TransactionHeaderMother::simple_testnet().build().unwrap(),
+        );
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("argument 2 (arg2) of method store"));
+        assert!(message.contains("total app args size"));
+    }
+}
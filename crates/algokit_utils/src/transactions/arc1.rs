@@ -0,0 +1,328 @@
+//! The [ARC-1](https://arc.algorand.foundation/ARCs/arc-0001) `signTxns` request and response
+//! JSON, which wallets connected through WalletConnect or [ARC-25](https://arc.algorand.foundation/ARCs/arc-0025)
+//! providers sign transactions from.
+
+use algokit_transact::{
+    AlgorandMsgpack, MultisigSignature, MultisigSubsignature, SignedTransaction, Transaction,
+};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Arc1Error {
+    #[snafu(display("Failed to encode a wallet transaction: {message}"))]
+    EncodeError { message: String },
+    #[snafu(display("Failed to decode a wallet transaction: {message}"))]
+    DecodeError { message: String },
+}
+
+/// The multisig account a transaction is signed for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigMetadata {
+    pub version: u8,
+    pub threshold: u8,
+    /// The addresses of the participants, in order
+    pub addrs: Vec<String>,
+}
+
+impl From<&MultisigSignature> for MultisigMetadata {
+    fn from(multisig: &MultisigSignature) -> Self {
+        Self {
+            version: multisig.version,
+            threshold: multisig.threshold,
+            addrs: multisig
+                .subsignatures
+                .iter()
+                .map(|subsignature| subsignature.address.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&MultisigMetadata> for MultisigSignature {
+    type Error = Arc1Error;
+
+    /// An unsigned multisig signature of the metadata's account.
+    fn try_from(metadata: &MultisigMetadata) -> Result<Self, Self::Error> {
+        let subsignatures = metadata
+            .addrs
+            .iter()
+            .map(|address| {
+                Ok(MultisigSubsignature {
+                    address: address.parse().map_err(|e| Arc1Error::DecodeError {
+                        message: format!("Invalid multisig address {}: {}", address, e),
+                    })?,
+                    signature: None,
+                })
+            })
+            .collect::<Result<Vec<_>, Arc1Error>>()?;
+        Ok(MultisigSignature {
+            version: metadata.version,
+            threshold: metadata.threshold,
+            subsignatures,
+        })
+    }
+}
+
+/// A transaction of a `signTxns` request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletTransaction {
+    /// The base64 msgpack encoded unsigned transaction
+    pub txn: String,
+    /// The address the sender is rekeyed to, which the wallet signs with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_addr: Option<String>,
+    /// The multisig account the transaction is signed for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msig: Option<MultisigMetadata>,
+    /// The addresses to sign with, an empty list means the wallet must not sign the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<String>>,
+    /// The base64 msgpack encoded signed transaction, for a transaction that isn't to be signed
+    /// by the wallet but was already signed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stxn: Option<String>,
+    /// A message explaining the transaction to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// A message explaining the group of the transaction to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_message: Option<String>,
+}
+
+impl WalletTransaction {
+    /// A transaction for the wallet to sign.
+    pub fn new(transaction: &Transaction) -> Result<Self, Arc1Error> {
+        Ok(Self {
+            txn: encode(transaction)?,
+            auth_addr: None,
+            msig: None,
+            signers: None,
+            stxn: None,
+            message: None,
+            group_message: None,
+        })
+    }
+
+    /// A transaction of the group the wallet must not sign, optionally with its signature.
+    pub fn not_to_sign(
+        transaction: &Transaction,
+        signed_transaction: Option<&SignedTransaction>,
+    ) -> Result<Self, Arc1Error> {
+        Ok(Self {
+            signers: Some(Vec::new()),
+            stxn: signed_transaction.map(encode).transpose()?,
+            ..Self::new(transaction)?
+        })
+    }
+
+    /// The decoded unsigned transaction.
+    pub fn transaction(&self) -> Result<Transaction, Arc1Error> {
+        decode(&self.txn)
+    }
+
+    /// The decoded signed transaction, if any.
+    pub fn signed_transaction(&self) -> Result<Option<SignedTransaction>, Arc1Error> {
+        self.stxn.as_deref().map(decode).transpose()
+    }
+
+    /// Whether the wallet is asked to sign the transaction.
+    pub fn is_to_sign(&self) -> bool {
+        self.signers
+            .as_ref()
+            .is_none_or(|signers| !signers.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignTxnsOpts {
+    /// A message explaining the request to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// The parameters of an ARC-1 `signTxns` call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignTxnsRequest {
+    pub txns: Vec<WalletTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opts: Option<SignTxnsOpts>,
+}
+
+impl SignTxnsRequest {
+    /// A request to sign the transactions of a group at `indices`, with the rest of the group
+    /// included for the wallet to show but not sign.
+    pub fn for_group(transactions: &[Transaction], indices: &[usize]) -> Result<Self, Arc1Error> {
+        if let Some(index) = indices.iter().find(|&&index| index >= transactions.len()) {
+            return Err(Arc1Error::EncodeError {
+                message: format!("Index {} out of bounds for transactions", index),
+            });
+        }
+
+        let txns = transactions
+            .iter()
+            .enumerate()
+            .map(|(index, transaction)| match indices.contains(&index) {
+                true => WalletTransaction::new(transaction),
+                false => WalletTransaction::not_to_sign(transaction, None),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { txns, opts: None })
+    }
+
+    /// Decode the `signTxns` response, which has the base64 signed transaction of each
+    /// transaction the wallet signed and `null` for the others.
+    ///
+    /// The signed transactions are checked to be the transactions of the request.
+    pub fn decode_response(
+        &self,
+        response: &[Option<String>],
+    ) -> Result<Vec<Option<SignedTransaction>>, Arc1Error> {
+        if response.len() != self.txns.len() {
+            return Err(Arc1Error::DecodeError {
+                message: format!(
+                    "The wallet returned {} results for {} transactions",
+                    response.len(),
+                    self.txns.len()
+                ),
+            });
+        }
+
+        self.txns
+            .iter()
+            .zip(response)
+            .enumerate()
+            .map(|(index, (wallet_transaction, signed))| {
+                let Some(signed) = signed else {
+                    return Ok(None);
+                };
+                let signed_transaction: SignedTransaction = decode(signed)?;
+                if signed_transaction.transaction != wallet_transaction.transaction()? {
+                    return Err(Arc1Error::DecodeError {
+                        message: format!(
+                            "The wallet signed a different transaction at index {}",
+                            index
+                        ),
+                    });
+                }
+                Ok(Some(signed_transaction))
+            })
+            .collect()
+    }
+}
+
+fn encode<T: AlgorandMsgpack>(value: &T) -> Result<String, Arc1Error> {
+    let bytes = value.encode_raw().map_err(|e| Arc1Error::EncodeError {
+        message: e.to_string(),
+    })?;
+    Ok(BASE64_STANDARD.encode(bytes))
+}
+
+fn decode<T: AlgorandMsgpack>(encoded: &str) -> Result<T, Arc1Error> {
+    let bytes = BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|e| Arc1Error::DecodeError {
+            message: format!("Invalid base64: {}", e),
+        })?;
+    T::decode(&bytes).map_err(|e| Arc1Error::DecodeError {
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::{AccountMother, TransactionMother};
+
+    fn signed(transaction: &Transaction) -> SignedTransaction {
+        SignedTransaction {
+            transaction: transaction.clone(),
+            signature: Some([1; 64]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+        }
+    }
+
+    #[test]
+    fn test_sign_txns_request_json() {
+        let transactions = vec![
+            TransactionMother::simple_payment().build().unwrap(),
+            TransactionMother::simple_asset_transfer().build().unwrap(),
+        ];
+        let mut request = SignTxnsRequest::for_group(&transactions, &[1]).unwrap();
+        request.txns[1].auth_addr = Some(AccountMother::neil().address().to_string());
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["txns"][0]["signers"], serde_json::json!([]));
+        assert_eq!(
+            json["txns"][1]["authAddr"],
+            AccountMother::neil().address().to_string()
+        );
+        assert!(json["txns"][1].get("signers").is_none());
+        assert!(json.get("opts").is_none());
+
+        let parsed: SignTxnsRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, request);
+        assert_eq!(parsed.txns[0].transaction().unwrap(), transactions[0]);
+        assert!(!parsed.txns[0].is_to_sign());
+        assert!(parsed.txns[1].is_to_sign());
+    }
+
+    #[test]
+    fn test_decode_response() {
+        let transactions = vec![
+            TransactionMother::simple_payment().build().unwrap(),
+            TransactionMother::simple_asset_transfer().build().unwrap(),
+        ];
+        let request = SignTxnsRequest::for_group(&transactions, &[1]).unwrap();
+        let signed_transaction = signed(&transactions[1]);
+
+        let response = vec![None, Some(encode(&signed_transaction).unwrap())];
+        assert_eq!(
+            request.decode_response(&response).unwrap(),
+            vec![None, Some(signed_transaction)]
+        );
+
+        let wrong_transaction = vec![None, Some(encode(&signed(&transactions[0])).unwrap())];
+        assert!(request.decode_response(&wrong_transaction).is_err());
+        assert!(request.decode_response(&[None]).is_err());
+    }
+
+    #[test]
+    fn test_multisig_metadata() {
+        let multisig = MultisigSignature {
+            version: 1,
+            threshold: 2,
+            subsignatures: vec![
+                MultisigSubsignature {
+                    address: AccountMother::account().address(),
+                    signature: Some([2; 64]),
+                },
+                MultisigSubsignature {
+                    address: AccountMother::neil().address(),
+                    signature: None,
+                },
+            ],
+        };
+
+        let metadata = MultisigMetadata::from(&multisig);
+        assert_eq!(
+            metadata.addrs,
+            vec![
+                AccountMother::account().address().to_string(),
+                AccountMother::neil().address().to_string()
+            ]
+        );
+        let unsigned = MultisigSignature::try_from(&metadata).unwrap();
+        assert_eq!(unsigned.threshold, 2);
+        assert!(
+            unsigned
+                .subsignatures
+                .iter()
+                .all(|subsignature| subsignature.signature.is_none())
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::AssetAmount;
+use crate::clients::asset_manager::{AssetManager, AssetManagerError};
 use crate::create_transaction_params;
 use algokit_transact::{Address, AssetTransferTransactionFields, Transaction, TransactionHeader};
 
@@ -7,8 +9,8 @@ create_transaction_params! {
     pub struct AssetTransferParams {
         /// ID of the asset to transfer.
         pub asset_id: u64,
-        /// The amount of the asset to transfer (in smallest divisible (decimal) units).
-        pub amount: u64,
+        /// The amount of the asset to transfer.
+        pub amount: AssetAmount,
         /// The address of the account that will receive the asset unit(s).
         pub receiver: Address,
     }
@@ -42,8 +44,8 @@ create_transaction_params! {
     pub struct AssetClawbackParams {
         /// ID of the asset to clawback.
         pub asset_id: u64,
-        /// Amount of the asset to transfer (in smallest divisible (decimal) units).
-        pub amount: u64,
+        /// Amount of the asset to transfer.
+        pub amount: AssetAmount,
         /// The address of the account that will receive the asset unit(s).
         pub receiver: Address,
         /// Address of an account to clawback the asset from.
@@ -54,6 +56,68 @@ create_transaction_params! {
         pub clawback_target: Address,
     }
 }
+impl AssetTransferParams {
+    /// A zero amount transfer of `asset_id` from `sender` to itself, which opts the sender in
+    /// to the asset.
+    pub fn opt_in(sender: Address, asset_id: u64) -> Self {
+        Self {
+            receiver: sender.clone(),
+            sender,
+            asset_id,
+            amount: AssetAmount::ZERO,
+            ..Default::default()
+        }
+    }
+
+    /// Set the amount in whole units of the asset, e.g. `"1.5"`, converted to base units with
+    /// the decimals of the asset, which are cached by the asset manager.
+    ///
+    /// Fails when the amount has more fractional digits than the asset has decimals, rather than
+    /// rounding it.
+    pub async fn with_whole_units(
+        self,
+        amount: &str,
+        asset_manager: &AssetManager,
+    ) -> Result<Self, AssetManagerError> {
+        let amount = asset_manager
+            .to_base_units(self.asset_id, amount, true)
+            .await?;
+        Ok(Self { amount, ..self })
+    }
+}
+
+impl AssetOptInParams {
+    /// An opt-in of `sender` to `asset_id`.
+    pub fn new(sender: Address, asset_id: u64) -> Self {
+        Self {
+            sender,
+            asset_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl AssetClawbackParams {
+    /// A clawback of `amount` units of `asset_id` from `clawback_target` back to the asset
+    /// `creator`, sent by the `clawback` account of the asset.
+    pub fn to_creator(
+        clawback: Address,
+        asset_id: u64,
+        amount: AssetAmount,
+        clawback_target: Address,
+        creator: Address,
+    ) -> Self {
+        Self {
+            sender: clawback,
+            asset_id,
+            amount,
+            receiver: creator,
+            clawback_target,
+            ..Default::default()
+        }
+    }
+}
+
 pub fn build_asset_transfer(
     params: &AssetTransferParams,
     header: TransactionHeader,
@@ -61,7 +125,7 @@ pub fn build_asset_transfer(
     Transaction::AssetTransfer(AssetTransferTransactionFields {
         header,
         asset_id: params.asset_id,
-        amount: params.amount,
+        amount: params.amount.as_base_units(),
         receiver: params.receiver.clone(),
         asset_sender: None,
         close_remainder_to: None,
@@ -99,7 +163,7 @@ pub fn build_asset_clawback(
     Transaction::AssetTransfer(AssetTransferTransactionFields {
         header,
         asset_id: params.asset_id,
-        amount: params.amount,
+        amount: params.amount.as_base_units(),
         receiver: params.receiver.clone(),
         asset_sender: Some(params.clawback_target.clone()),
         close_remainder_to: None,
@@ -0,0 +1,201 @@
+use super::composer::{
+    ComposerError, ComposerTransaction, SendParams, TransactionComposer,
+    TransactionComposerSendResult, TransactionResult,
+};
+use crate::config::{ProgressOperation, ProgressReporter};
+use crate::transactions::TransactionComposerConfig;
+use algokit_transact::MAX_TX_GROUP_SIZE;
+use futures::stream::{self, StreamExt};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// How many groups a [`BatchSender`] sends concurrently by default.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Default)]
+pub struct BatchSendParams {
+    /// The maximum number of transactions per group, defaults to the largest group the composer
+    /// allows. Use 1 to send every transaction on its own.
+    pub group_size: Option<usize>,
+    /// The maximum number of groups sent concurrently, defaults to [`DEFAULT_BATCH_CONCURRENCY`]
+    pub max_concurrency: Option<usize>,
+    pub send_params: Option<SendParams>,
+}
+
+/// The outcome of sending one group of a batch.
+#[derive(Debug)]
+pub struct BatchGroupResult {
+    /// The indexes of the batch's transactions that were sent in this group
+    pub transaction_indexes: Range<usize>,
+    pub result: Result<TransactionComposerSendResult, ComposerError>,
+}
+
+#[derive(Debug)]
+pub struct BatchSendResult {
+    /// The outcome of each group, in the order of the batch's transactions
+    pub groups: Vec<BatchGroupResult>,
+}
+
+impl BatchSendResult {
+    /// Whether every group of the batch was sent and confirmed.
+    pub fn is_success(&self) -> bool {
+        self.groups.iter().all(|group| group.result.is_ok())
+    }
+
+    /// The results of the transactions in the groups that were confirmed, in batch order.
+    pub fn results(&self) -> Vec<&TransactionResult> {
+        self.groups
+            .iter()
+            .filter_map(|group| group.result.as_ref().ok())
+            .flat_map(|result| result.results.iter())
+            .collect()
+    }
+
+    /// The groups that failed to be sent or confirmed.
+    pub fn failures(&self) -> Vec<&BatchGroupResult> {
+        self.groups
+            .iter()
+            .filter(|group| group.result.is_err())
+            .collect()
+    }
+}
+
+/// Sends any number of transactions by splitting them into groups, e.g. for airdrops or mass
+/// opt-ins.
+///
+/// Each group is atomic, but the groups are sent independently: a failed group doesn't stop or
+/// roll back the other groups, its error is reported in the [`BatchSendResult`] instead.
+#[derive(Clone)]
+pub struct BatchSender {
+    new_composer: Arc<dyn Fn(Option<TransactionComposerConfig>) -> TransactionComposer>,
+}
+
+impl BatchSender {
+    /// Create a new batch sender.
+    ///
+    /// # Arguments
+    /// * `new_composer` - Factory function for creating the composers of the groups
+    pub fn new(
+        new_composer: impl Fn(Option<TransactionComposerConfig>) -> TransactionComposer + 'static,
+    ) -> Self {
+        Self {
+            new_composer: Arc::new(new_composer),
+        }
+    }
+
+    /// Send the transactions in groups of at most `group_size` transactions.
+    ///
+    /// Fails without sending anything if a transaction can't be added to a group, e.g. a method
+    /// call, whose transaction arguments can't be split across groups.
+    pub async fn send(
+        &self,
+        transactions: Vec<ComposerTransaction>,
+        params: Option<BatchSendParams>,
+    ) -> Result<BatchSendResult, ComposerError> {
+        let params = params.unwrap_or_default();
+        let group_size = params.group_size.unwrap_or(MAX_TX_GROUP_SIZE);
+        if group_size == 0 || group_size > MAX_TX_GROUP_SIZE {
+            return Err(ComposerError::TransactionError {
+                message: format!(
+                    "Batch group size must be between 1 and {}, got {}",
+                    MAX_TX_GROUP_SIZE, group_size
+                ),
+            });
+        }
+
+        let total = transactions.len();
+        let mut groups = Vec::new();
+        let mut composer = (self.new_composer)(None);
+        let mut group_start = 0;
+        for (index, transaction) in transactions.into_iter().enumerate() {
+            if composer.count() >= group_size || composer.remaining_capacity() == 0 {
+                let full_composer = std::mem::replace(&mut composer, (self.new_composer)(None));
+                groups.push((group_start..index, full_composer));
+                group_start = index;
+            }
+            composer.add_composer_transaction(transaction)?;
+        }
+        if composer.count() > 0 {
+            groups.push((group_start..total, composer));
+        }
+
+        let mut progress = ProgressReporter::new(ProgressOperation::BatchSend, Some(total as u64));
+        progress.report().await;
+
+        let mut sends = stream::iter(groups.into_iter().map(
+            |(transaction_indexes, mut composer)| {
+                let send_params = params.send_params.clone();
+                async move {
+                    BatchGroupResult {
+                        transaction_indexes,
+                        result: composer.send(send_params).await,
+                    }
+                }
+            },
+        ))
+        .buffered(
+            params
+                .max_concurrency
+                .unwrap_or(DEFAULT_BATCH_CONCURRENCY)
+                .max(1),
+        );
+
+        let mut group_results = Vec::new();
+        while let Some(group_result) = sends.next().await {
+            progress
+                .advance(group_result.transaction_indexes.len() as u64)
+                .await;
+            group_results.push(group_result);
+        }
+
+        Ok(BatchSendResult {
+            groups: group_results,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlgoAmount;
+    use crate::transactions::{EmptySigner, PaymentParams, TransactionComposerParams};
+    use algod_client::AlgodClient;
+    use algokit_transact::test_utils::AccountMother;
+
+    fn batch_sender() -> BatchSender {
+        BatchSender::new(|composer_config| {
+            TransactionComposer::new(TransactionComposerParams {
+                algod_client: Arc::new(AlgodClient::testnet()),
+                signer_getter: Arc::new(EmptySigner {}),
+                composer_config,
+                suggested_params_cache: None,
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_invalid_group_size() {
+        let payment = ComposerTransaction::Payment(PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::account().address(),
+            amount: AlgoAmount::micro_algos(1000),
+            ..Default::default()
+        });
+
+        for group_size in [0, MAX_TX_GROUP_SIZE + 1] {
+            let result = batch_sender()
+                .send(
+                    vec![payment.clone()],
+                    Some(BatchSendParams {
+                        group_size: Some(group_size),
+                        ..Default::default()
+                    }),
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(ComposerError::TransactionError { .. })
+            ));
+        }
+    }
+}
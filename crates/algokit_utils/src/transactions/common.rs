@@ -49,6 +49,7 @@ impl TransactionSigner for EmptySigner {
                         signature: Some(EMPTY_SIGNATURE),
                         auth_address: None,
                         multisignature: None,
+                        logic_signature: None,
                     })
                 } else {
                     Err(format!("Index {} out of bounds for transactions", idx))
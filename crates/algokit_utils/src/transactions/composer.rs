@@ -1,6 +1,10 @@
-use crate::config::{Config, EventData, EventType, TxnGroupSimulatedEventData};
+use crate::config::{
+    Config, EventData, EventType, ProgressOperation, ProgressReporter, TxnGroupConfirmedEventData,
+    TxnGroupEventData, TxnGroupSimulatedEventData,
+};
+use crate::debugging::persist_trace;
 use crate::{
-    genesis_id_is_localnet,
+    AlgoAmount, AlgodErrorKind, ConsensusVersion, SuggestedParamsCache, genesis_id_is_localnet,
     transactions::{
         common::TransactionSignerGetter,
         key_registration::{
@@ -16,19 +20,32 @@ use algod_client::{
     apis::{Error as AlgodError, Format},
     models::{
         ApplicationLocalReference, AssetHoldingReference, BoxReference, PendingTransactionResponse,
-        SimulateRequest, SimulateRequestTransactionGroup, SimulateUnnamedResourcesAccessed,
+        SimulateRequest, SimulateRequestTransactionGroup, SimulateTransactionGroupResult,
+        SimulateUnnamedResourcesAccessed, SimulationEvalOverrides, SimulationTransactionExecTrace,
         TransactionParams,
     },
 };
-use algokit_abi::{ABIError, ABIMethod, ABIReturn};
+use algokit_abi::{
+    ABIError, ABIMethod, ABIMethodArgType, ABIReturn, ABITransactionType, Arc56Contract,
+    DecodedEvent,
+};
 use algokit_transact::{
-    Address, AlgoKitTransactError, AlgorandMsgpack, Byte32, EMPTY_SIGNATURE, FeeParams,
+    AccessReference, Address, AlgoKitTransactError, AlgorandMsgpack, AppCallTransactionFields,
+    Byte32, EMPTY_SIGNATURE, FeeParams, HoldingReference, LocalsReference, MAX_ACCESS_REFERENCES,
     MAX_ACCOUNT_REFERENCES, MAX_OVERALL_REFERENCES, MAX_TX_GROUP_SIZE, SignedTransaction,
     Transaction, TransactionHeader, TransactionId, Transactions,
 };
+use async_trait::async_trait;
+use base64::{Engine, prelude::BASE64_STANDARD};
 use derive_more::Debug;
+use futures::future::join_all;
+use log::warn;
+use sha2::{Digest, Sha512_256};
 use snafu::Snafu;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     AppMethodCallArg,
@@ -59,11 +76,14 @@ use super::asset_transfer::{
     build_asset_clawback, build_asset_opt_in, build_asset_opt_out, build_asset_transfer,
 };
 use super::common::TransactionSigner;
+use super::dryrun::{DryrunResult, create_dryrun_request};
+use super::group_graph::GroupGraph;
 use super::key_registration::{
     NonParticipationKeyRegistrationParams, OfflineKeyRegistrationParams,
     OnlineKeyRegistrationParams,
 };
 use super::payment::{AccountCloseParams, PaymentParams};
+use super::state_diff::{AppCallStateDiff, compute_state_diffs};
 
 // ABI return values are stored in logs with the prefix 0x151f7c75
 const ABI_RETURN_PREFIX: &[u8] = &[0x15, 0x1f, 0x7c, 0x75];
@@ -94,6 +114,21 @@ impl ResourcePopulation {
     }
 }
 
+impl ResourcePopulation {
+    /// Returns the resource population to use on a network running `consensus_version`, which
+    /// falls back to the reference arrays when access lists aren't supported.
+    pub fn for_consensus(&self, consensus_version: &ConsensusVersion) -> Self {
+        match self {
+            ResourcePopulation::Enabled {
+                use_access_list: true,
+            } if !consensus_version.supports_access_lists() => ResourcePopulation::Enabled {
+                use_access_list: false,
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
 impl Default for ResourcePopulation {
     fn default() -> Self {
         ResourcePopulation::Enabled {
@@ -106,6 +141,13 @@ trait HasTxnSigner {
     fn signer_mut(&mut self) -> &mut Option<Arc<dyn TransactionSigner>>;
 }
 
+fn txn_group_event_data(group: Option<Byte32>, transaction_ids: &[String]) -> TxnGroupEventData {
+    TxnGroupEventData {
+        group_id: group.map(|group| BASE64_STANDARD.encode(group)),
+        transaction_ids: transaction_ids.to_vec(),
+    }
+}
+
 fn set_method_signer_if_missing(
     params: &mut impl HasTxnSigner,
     method_signer: &Option<Arc<dyn TransactionSigner>>,
@@ -207,6 +249,26 @@ pub enum ComposerError {
     ABIEncodingError { message: String },
     #[snafu(display("ABI argument decoding error: {message}"))]
     ABIDecodingError { message: String },
+    #[snafu(display("Fee policy error: {message}"))]
+    FeePolicyError { message: String },
+    #[snafu(display("Cancelled: {message}"))]
+    Cancelled { message: String },
+    /// Algod rejected the group, e.g. for an overspend or a failed logic eval.
+    #[snafu(display("Failed to submit transaction(s): {source}"))]
+    SubmitError { source: AlgodErrorKind },
+    #[snafu(display("Failed to get status: {source}"))]
+    StatusError { source: AlgodErrorKind },
+}
+
+impl ComposerError {
+    /// The parsed error of algod this error was caused by, if any.
+    pub fn algod_error(&self) -> Option<AlgodErrorKind> {
+        match self {
+            Self::SubmitError { source } | Self::StatusError { source } => Some(source.clone()),
+            Self::AlgodClientError { source } => Some(AlgodErrorKind::from(source)),
+            _ => None,
+        }
+    }
 }
 
 impl From<AlgodError> for ComposerError {
@@ -227,12 +289,54 @@ pub struct TransactionResult {
     pub transaction_id: String,
     pub confirmation: PendingTransactionResponse,
     pub abi_return: Option<ABIReturn>,
+    /// The ARC-28 events emitted by the transaction, populated when the app spec is known,
+    /// e.g. for calls sent by an app client
+    pub events: Vec<DecodedEvent>,
+    /// The params the transaction was added to the composer with, `None` for a transaction the
+    /// composer added itself, e.g. the fee payment
+    pub params: Option<ComposerTransaction>,
+}
+
+impl TransactionResult {
+    /// Decode the ARC-28 events in the logs of the transaction using the events of `app_spec`.
+    pub fn decode_events(&self, app_spec: &Arc56Contract) -> Result<Vec<DecodedEvent>, ABIError> {
+        match &self.confirmation.logs {
+            Some(logs) => app_spec.decode_events(logs),
+            None => Ok(Vec::new()),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct TransactionComposerSendResult {
     pub group: Option<Byte32>,
     pub results: Vec<TransactionResult>,
+    /// The result of the fee payment appended to the group when a fee payer is configured
+    pub fee_payment: Option<TransactionResult>,
+}
+
+impl TransactionComposerSendResult {
+    /// The base64 encoded group ID, `None` for a single transaction sent without a group.
+    pub fn group_id_base64(&self) -> Option<String> {
+        self.group.map(|group| BASE64_STANDARD.encode(group))
+    }
+
+    /// The round the group was confirmed in.
+    pub fn confirmed_round(&self) -> Option<u64> {
+        self.results
+            .iter()
+            .chain(&self.fee_payment)
+            .filter_map(|result| result.confirmation.confirmed_round)
+            .max()
+    }
+
+    /// The IDs of the transactions, in the order they were added.
+    pub fn transaction_ids(&self) -> Vec<&str> {
+        self.results
+            .iter()
+            .map(|result| result.transaction_id.as_str())
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -244,19 +348,140 @@ pub struct SimulateParams {
     pub exec_trace_config: Option<algod_client::models::SimulateTraceConfig>,
     pub simulation_round: Option<u64>,
     pub skip_signatures: bool,
+    /// Whether to simulate with the signers the transactions need, e.g. for rekeyed senders,
+    /// defaults to `true`
+    pub fix_signers: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TransactionComposerSimulateResult {
     pub group: Option<Byte32>,
     pub results: Vec<TransactionResult>,
+    /// The result of the fee payment appended to the group when a fee payer is configured
+    pub fee_payment: Option<TransactionResult>,
     pub simulate_response: SimulateTransaction,
+    /// Per app call state changes, populated when simulating with state change tracing enabled
+    pub state_diffs: Vec<AppCallStateDiff>,
+    /// The execution details of each transaction in the group, including the fee payment
+    pub transactions: Vec<SimulatedTransaction>,
+    /// The extra app budget added to the group by the simulation
+    pub app_budget_added: Option<u64>,
+    /// The total app budget consumed by the group
+    pub app_budget_consumed: Option<u64>,
+    /// The resources accessed by the group that don't belong to a particular transaction,
+    /// populated when simulating with `allow_unnamed_resources`
+    pub unnamed_resources_accessed: Option<SimulateUnnamedResourcesAccessed>,
+    /// The round the group was simulated at
+    pub simulation_round: u64,
+    /// The evaluation overrides algod applied, e.g. the extra opcode budget and log limits
+    pub eval_overrides: Option<SimulationEvalOverrides>,
+}
+
+/// The execution details of a simulated transaction.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedTransaction {
+    /// The app budget consumed by the transaction, for app calls
+    pub app_budget_consumed: Option<u32>,
+    /// The budget consumed by the logic signature, for logic signature transactions
+    pub logic_sig_budget_consumed: Option<u32>,
+    /// The execution trace, populated when simulating with an `exec_trace_config`
+    pub exec_trace: Option<SimulationTransactionExecTrace>,
+    /// The resources accessed by the transaction that weren't in its reference arrays,
+    /// populated when simulating with `allow_unnamed_resources`
+    pub unnamed_resources_accessed: Option<SimulateUnnamedResourcesAccessed>,
+}
+
+impl SimulatedTransaction {
+    fn from_group_result(group_result: &SimulateTransactionGroupResult) -> Vec<Self> {
+        group_result
+            .txn_results
+            .iter()
+            .map(|result| Self {
+                app_budget_consumed: result.app_budget_consumed,
+                logic_sig_budget_consumed: result.logic_sig_budget_consumed,
+                exec_trace: result.exec_trace.clone(),
+                unnamed_resources_accessed: result.unnamed_resources_accessed.clone(),
+            })
+            .collect()
+    }
+}
+
+/// An account that pays the fees of a transaction group on behalf of the transaction senders.
+#[derive(Debug, Clone)]
+pub struct FeePayer {
+    pub address: Address,
+    /// The signer for the fee payment, resolved from the composer's signer getter when not set
+    #[debug(skip)]
+    pub signer: Option<Arc<dyn TransactionSigner>>,
+}
+
+/// Limits on the fees of built groups, which protect against paying congestion fees by
+/// accident. Building a group that breaks a limit fails with [`ComposerError::FeePolicyError`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeePolicy {
+    /// The maximum fee of a transaction in microALGO, including the fee payment of a fee payer
+    pub max_transaction_fee: Option<u64>,
+    /// The maximum total fee of the group in microALGO
+    pub max_group_fee: Option<u64>,
+    /// The maximum fee per byte of the suggested params, checked before anything is built
+    pub max_fee_per_byte: Option<u64>,
+}
+
+impl FeePolicy {
+    fn check_fee_per_byte(&self, fee_per_byte: u64) -> Result<(), ComposerError> {
+        match self.max_fee_per_byte {
+            Some(max) if fee_per_byte > max => Err(ComposerError::FeePolicyError {
+                message: format!(
+                    "The suggested fee per byte of {} µALGO exceeds the maximum of {} µALGO",
+                    fee_per_byte, max
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_fees(&self, transactions: &[Transaction]) -> Result<(), ComposerError> {
+        let fees = transactions
+            .iter()
+            .map(|transaction| transaction.header().fee.unwrap_or(0));
+        if let Some(max) = self.max_transaction_fee {
+            if let Some((index, fee)) = fees.clone().enumerate().find(|(_, fee)| *fee > max) {
+                return Err(ComposerError::FeePolicyError {
+                    message: format!(
+                        "The fee of {} µALGO of transaction {} exceeds the maximum of {} µALGO",
+                        fee, index, max
+                    ),
+                });
+            }
+        }
+        if let Some(max) = self.max_group_fee {
+            let total = fees.fold(0u64, u64::saturating_add);
+            if total > max {
+                return Err(ComposerError::FeePolicyError {
+                    message: format!(
+                        "The total fee of {} µALGO of the group exceeds the maximum of {} µALGO",
+                        total, max
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TransactionComposerConfig {
     pub cover_app_call_inner_transaction_fees: bool,
     pub populate_app_call_resources: ResourcePopulation,
+    /// When set, the group is sponsored: a zero amount payment from the fee payer is appended
+    /// to the group and carries the fees of all the composed transactions.
+    pub fee_payer: Option<FeePayer>,
+    /// Limits on the fees of the group, checked when it is built
+    pub fee_policy: Option<FeePolicy>,
+    /// The options of the simulation that populates resources and inner transaction fees, e.g.
+    /// an extra opcode budget or the round to simulate at. Unnamed resources and empty
+    /// signatures are always allowed.
+    pub analysis_simulate_params: Option<SimulateParams>,
 }
 
 #[derive(Clone)]
@@ -264,11 +489,74 @@ pub struct TransactionComposerParams {
     pub algod_client: Arc<AlgodClient>,
     pub signer_getter: Arc<dyn TransactionSignerGetter>,
     pub composer_config: Option<TransactionComposerConfig>,
+    /// A cache of suggested params shared between composers, when not set the params are
+    /// fetched for every group that is built
+    pub suggested_params_cache: Option<Arc<SuggestedParamsCache>>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct SendParams {
     pub max_rounds_to_wait_for_confirmation: Option<u32>,
+    /// A caller chosen key, e.g. an order ID, from which a lease is derived for every transaction
+    /// of the group without one, see [`idempotency_lease`].
+    ///
+    /// Sending a group with the same key again while the first one is within its validity window
+    /// is rejected by the network, so retries after a crash or timeout can't double spend. The
+    /// key must be set before the group is built.
+    pub idempotency_key: Option<String>,
+    /// Stops waiting for the confirmation of the group when cancelled, failing the send with
+    /// [`ComposerError::Cancelled`].
+    ///
+    /// A group is never cancelled once submitted, so a send cancelled while waiting may still be
+    /// confirmed, see [`TransactionComposer::resume_send`]. A send cancelled before submitting
+    /// doesn't submit the group.
+    pub cancellation_token: Option<CancellationToken>,
+    /// The time to wait for the confirmation of the group, after which the send fails with
+    /// [`ComposerError::Cancelled`], on top of `max_rounds_to_wait_for_confirmation`
+    pub confirmation_timeout: Option<Duration>,
+}
+
+/// Run the future until it completes, the token is cancelled or the timeout expires.
+///
+/// A cancelled future is dropped, which cancels its in-flight algod requests, e.g. the long poll
+/// of `wait_for_block`.
+async fn until_cancelled<T>(
+    future: impl Future<Output = Result<T, ComposerError>>,
+    cancellation_token: Option<&CancellationToken>,
+    timeout: Option<Duration>,
+) -> Result<T, ComposerError> {
+    let with_timeout = async {
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future).await.map_err(|_| {
+                ComposerError::Cancelled {
+                    message: format!("Not confirmed within {:?}", timeout),
+                }
+            })?,
+            None => future.await,
+        }
+    };
+    let Some(cancellation_token) = cancellation_token else {
+        return with_timeout.await;
+    };
+
+    futures::pin_mut!(with_timeout);
+    let cancelled = cancellation_token.cancelled();
+    futures::pin_mut!(cancelled);
+    match futures::future::select(with_timeout, cancelled).await {
+        futures::future::Either::Left((result, _)) => result,
+        futures::future::Either::Right(_) => Err(ComposerError::Cancelled {
+            message: "Waiting for confirmation was cancelled".to_string(),
+        }),
+    }
+}
+
+/// The lease derived from an idempotency key for the transaction at the given group index: the
+/// SHA-512/256 hash of the key followed by the index as a big-endian u64.
+pub fn idempotency_lease(key: &str, group_index: usize) -> [u8; 32] {
+    let mut hasher = Sha512_256::new();
+    hasher.update(key.as_bytes());
+    hasher.update((group_index as u64).to_be_bytes());
+    hasher.finalize().into()
 }
 
 #[derive(Debug)]
@@ -356,6 +644,9 @@ enum FeePriority {
 pub enum ComposerTransaction {
     Transaction(Transaction),
     TransactionWithSigner(TransactionWithSigner),
+    /// A transaction that has already been signed, see
+    /// [`TransactionComposer::add_signed_transaction`]
+    SignedTransaction(SignedTransaction),
     Payment(PaymentParams),
     AccountClose(AccountCloseParams),
     AssetTransfer(AssetTransferParams),
@@ -408,6 +699,7 @@ macro_rules! get_composer_transaction_field {
                 $crate::transactions::composer::ComposerTransaction::NonParticipationKeyRegistration(params) => $get_expr(&params.$field),
                 $crate::transactions::composer::ComposerTransaction::Transaction(_) => $default_expr,
                 $crate::transactions::composer::ComposerTransaction::TransactionWithSigner(_) => $default_expr,
+                $crate::transactions::composer::ComposerTransaction::SignedTransaction(_) => $default_expr,
             }
         }
     };
@@ -442,6 +734,63 @@ impl ComposerTransaction {
     get_composer_transaction_field!(first_valid_round, Option<u64>, |x: &Option<u64>| *x, None);
     get_composer_transaction_field!(last_valid_round, Option<u64>, |x: &Option<u64>| *x, None);
 
+    /// Clear the validity rounds so the transaction is valid from `last_round` when rebuilt,
+    /// keeping the length of its validity window.
+    fn reset_validity_rounds(&mut self, last_round: u64) {
+        macro_rules! reset_params {
+            ($params:expr) => {{
+                let params = $params;
+                if let (Some(first), Some(last)) =
+                    (params.first_valid_round, params.last_valid_round)
+                {
+                    params.validity_window = params
+                        .validity_window
+                        .or_else(|| u32::try_from(last.saturating_sub(first)).ok());
+                }
+                params.first_valid_round = None;
+                params.last_valid_round = None;
+            }};
+        }
+
+        match self {
+            ComposerTransaction::Transaction(transaction)
+            | ComposerTransaction::TransactionWithSigner(TransactionWithSigner {
+                transaction,
+                ..
+            }) => {
+                let header = transaction.header_mut();
+                let window = header.last_valid.saturating_sub(header.first_valid);
+                header.first_valid = last_round;
+                header.last_valid = last_round + window;
+                header.group = None;
+            }
+            // The validity of a signed transaction can't change
+            ComposerTransaction::SignedTransaction(_) => {}
+            ComposerTransaction::Payment(params) => reset_params!(params),
+            ComposerTransaction::AccountClose(params) => reset_params!(params),
+            ComposerTransaction::AssetTransfer(params) => reset_params!(params),
+            ComposerTransaction::AssetOptIn(params) => reset_params!(params),
+            ComposerTransaction::AssetOptOut(params) => reset_params!(params),
+            ComposerTransaction::AssetClawback(params) => reset_params!(params),
+            ComposerTransaction::AssetCreate(params) => reset_params!(params),
+            ComposerTransaction::AssetConfig(params) => reset_params!(params),
+            ComposerTransaction::AssetDestroy(params) => reset_params!(params),
+            ComposerTransaction::AssetFreeze(params) => reset_params!(params),
+            ComposerTransaction::AssetUnfreeze(params) => reset_params!(params),
+            ComposerTransaction::AppCall(params) => reset_params!(params),
+            ComposerTransaction::AppCreateCall(params) => reset_params!(params),
+            ComposerTransaction::AppUpdateCall(params) => reset_params!(params),
+            ComposerTransaction::AppDeleteCall(params) => reset_params!(params),
+            ComposerTransaction::AppCallMethodCall(params) => reset_params!(params),
+            ComposerTransaction::AppCreateMethodCall(params) => reset_params!(params),
+            ComposerTransaction::AppUpdateMethodCall(params) => reset_params!(params),
+            ComposerTransaction::AppDeleteMethodCall(params) => reset_params!(params),
+            ComposerTransaction::OnlineKeyRegistration(params) => reset_params!(params),
+            ComposerTransaction::OfflineKeyRegistration(params) => reset_params!(params),
+            ComposerTransaction::NonParticipationKeyRegistration(params) => reset_params!(params),
+        }
+    }
+
     /// Get the logical maximum fee based on static_fee and max_fee
     pub fn logical_max_fee(&self) -> Option<u64> {
         let max_fee = self.max_fee();
@@ -468,8 +817,44 @@ impl ComposerTransaction {
                     transaction: Transaction::AppCall(_),
                     ..
                 })
+                | ComposerTransaction::SignedTransaction(SignedTransaction {
+                    transaction: Transaction::AppCall(_),
+                    ..
+                })
         )
     }
+
+    fn is_signed(&self) -> bool {
+        matches!(self, ComposerTransaction::SignedTransaction(_))
+    }
+}
+
+/// Signs a pre-signed transaction of a group with its existing signature.
+struct PreSignedTransactionSigner {
+    signed_transaction: SignedTransaction,
+}
+
+#[async_trait]
+impl TransactionSigner for PreSignedTransactionSigner {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        indices
+            .iter()
+            .map(|&index| match transactions.get(index) {
+                Some(transaction) if transaction == &self.signed_transaction.transaction => {
+                    Ok(self.signed_transaction.clone())
+                }
+                Some(_) => Err(format!(
+                    "Transaction {} doesn't match the pre-signed transaction",
+                    index
+                )),
+                None => Err(format!("Index {} out of bounds for transactions", index)),
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone)]
@@ -477,9 +862,67 @@ pub struct TransactionComposer {
     algod_client: Arc<AlgodClient>,
     signer_getter: Arc<dyn TransactionSignerGetter>,
     composer_config: TransactionComposerConfig,
+    suggested_params_cache: Option<Arc<SuggestedParamsCache>>,
     transactions: Vec<ComposerTransaction>,
     built_group: Option<Vec<TransactionWithSigner>>,
     signed_group: Option<Vec<SignedTransaction>>,
+    idempotency_key: Option<String>,
+}
+
+impl ComposerTransaction {
+    /// Whether this transaction can satisfy a method argument of the given ABI transaction type.
+    fn matches_abi_transaction_type(&self, transaction_type: &ABITransactionType) -> bool {
+        let transaction = match self {
+            ComposerTransaction::Transaction(transaction) => Some(transaction),
+            ComposerTransaction::TransactionWithSigner(transaction) => {
+                Some(&transaction.transaction)
+            }
+            ComposerTransaction::SignedTransaction(transaction) => Some(&transaction.transaction),
+            _ => None,
+        };
+
+        match transaction_type {
+            ABITransactionType::Txn => true,
+            ABITransactionType::Payment => {
+                matches!(
+                    self,
+                    ComposerTransaction::Payment(_) | ComposerTransaction::AccountClose(_)
+                ) || matches!(transaction, Some(Transaction::Payment(_)))
+            }
+            ABITransactionType::AssetTransfer => {
+                matches!(
+                    self,
+                    ComposerTransaction::AssetTransfer(_)
+                        | ComposerTransaction::AssetOptIn(_)
+                        | ComposerTransaction::AssetOptOut(_)
+                        | ComposerTransaction::AssetClawback(_)
+                ) || matches!(transaction, Some(Transaction::AssetTransfer(_)))
+            }
+            ABITransactionType::AssetConfig => {
+                matches!(
+                    self,
+                    ComposerTransaction::AssetCreate(_)
+                        | ComposerTransaction::AssetConfig(_)
+                        | ComposerTransaction::AssetDestroy(_)
+                ) || matches!(transaction, Some(Transaction::AssetConfig(_)))
+            }
+            ABITransactionType::AssetFreeze => {
+                matches!(
+                    self,
+                    ComposerTransaction::AssetFreeze(_) | ComposerTransaction::AssetUnfreeze(_)
+                ) || matches!(transaction, Some(Transaction::AssetFreeze(_)))
+            }
+            ABITransactionType::KeyRegistration => {
+                matches!(
+                    self,
+                    ComposerTransaction::OnlineKeyRegistration(_)
+                        | ComposerTransaction::OfflineKeyRegistration(_)
+                        | ComposerTransaction::NonParticipationKeyRegistration(_)
+                ) || matches!(transaction, Some(Transaction::KeyRegistration(_)))
+            }
+            ABITransactionType::AppCall => self.is_app_call(),
+        }
+    }
 }
 
 impl TransactionComposer {
@@ -488,10 +931,30 @@ impl TransactionComposer {
             algod_client: params.algod_client,
             signer_getter: params.signer_getter,
             composer_config: params.composer_config.unwrap_or_default(),
+            suggested_params_cache: params.suggested_params_cache,
             transactions: Vec::new(),
             built_group: None,
             signed_group: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the account that pays the fees for the group, see [`TransactionComposerConfig::fee_payer`].
+    ///
+    /// This has no effect on a group that has already been built.
+    pub fn set_fee_payer(&mut self, fee_payer: Option<FeePayer>) {
+        self.composer_config.fee_payer = fee_payer;
+    }
+
+    /// Set the key the leases of the group are derived from, see [`SendParams::idempotency_key`].
+    fn set_idempotency_key(&mut self, key: String) -> Result<(), ComposerError> {
+        if self.built_group.is_some() && self.idempotency_key.as_ref() != Some(&key) {
+            return Err(ComposerError::StateError {
+                message: "Cannot set an idempotency key after building".to_string(),
+            });
         }
+        self.idempotency_key = Some(key);
+        Ok(())
     }
 
     fn push(&mut self, txn: ComposerTransaction) -> Result<(), ComposerError> {
@@ -696,6 +1159,10 @@ impl TransactionComposer {
                     composer_transactions.push(ComposerTransaction::AppDeleteCall(params.clone()));
                 }
                 AppMethodCallArg::AppCallMethodCall(params) => {
+                    let params = AppCallMethodCallParams {
+                        args: Self::wire_transaction_args(&params.method, &params.args),
+                        ..params.clone()
+                    };
                     let nested_composer_transactions =
                         Self::extract_composer_transactions_from_app_method_call_params(
                             &params.args,
@@ -704,9 +1171,13 @@ impl TransactionComposer {
                     composer_transactions.extend(nested_composer_transactions);
 
                     composer_transactions
-                        .push(ComposerTransaction::AppCallMethodCall(params.into()));
+                        .push(ComposerTransaction::AppCallMethodCall((&params).into()));
                 }
                 AppMethodCallArg::AppCreateMethodCall(params) => {
+                    let params = AppCreateMethodCallParams {
+                        args: Self::wire_transaction_args(&params.method, &params.args),
+                        ..params.clone()
+                    };
                     let nested_composer_transactions =
                         Self::extract_composer_transactions_from_app_method_call_params(
                             &params.args,
@@ -715,9 +1186,13 @@ impl TransactionComposer {
                     composer_transactions.extend(nested_composer_transactions);
 
                     composer_transactions
-                        .push(ComposerTransaction::AppCreateMethodCall(params.into()));
+                        .push(ComposerTransaction::AppCreateMethodCall((&params).into()));
                 }
                 AppMethodCallArg::AppUpdateMethodCall(params) => {
+                    let params = AppUpdateMethodCallParams {
+                        args: Self::wire_transaction_args(&params.method, &params.args),
+                        ..params.clone()
+                    };
                     let nested_composer_transactions =
                         Self::extract_composer_transactions_from_app_method_call_params(
                             &params.args,
@@ -726,9 +1201,13 @@ impl TransactionComposer {
                     composer_transactions.extend(nested_composer_transactions);
 
                     composer_transactions
-                        .push(ComposerTransaction::AppUpdateMethodCall(params.into()));
+                        .push(ComposerTransaction::AppUpdateMethodCall((&params).into()));
                 }
                 AppMethodCallArg::AppDeleteMethodCall(params) => {
+                    let params = AppDeleteMethodCallParams {
+                        args: Self::wire_transaction_args(&params.method, &params.args),
+                        ..params.clone()
+                    };
                     let nested_composer_transactions =
                         Self::extract_composer_transactions_from_app_method_call_params(
                             &params.args,
@@ -737,7 +1216,7 @@ impl TransactionComposer {
                     composer_transactions.extend(nested_composer_transactions);
 
                     composer_transactions
-                        .push(ComposerTransaction::AppDeleteMethodCall(params.into()));
+                        .push(ComposerTransaction::AppDeleteMethodCall((&params).into()));
                 }
                 _ => {}
             };
@@ -746,6 +1225,115 @@ impl TransactionComposer {
         composer_transactions
     }
 
+    /// Fill in the transaction arguments omitted from `args` with
+    /// [`AppMethodCallArg::TransactionPlaceholder`], aligning the given arguments with the
+    /// method signature by their transaction types.
+    ///
+    /// This lets a transaction argument that is satisfied by the transaction arguments of a
+    /// nested method call be left out, e.g. `[nested_call]` for a method taking `(pay,appl)` where
+    /// the nested call takes the payment, rather than `[TransactionPlaceholder, nested_call]`.
+    /// The placeholders are resolved against the group positions when the call is added.
+    fn wire_transaction_args(
+        method: &ABIMethod,
+        args: &[AppMethodCallArg],
+    ) -> Vec<AppMethodCallArg> {
+        let Some(mut missing) = method.args.len().checked_sub(args.len()) else {
+            return args.to_vec();
+        };
+
+        let mut provided = args.iter().peekable();
+        let mut wired = Vec::with_capacity(method.args.len());
+        for method_arg in &method.args {
+            if let ABIMethodArgType::Transaction(transaction_type) = &method_arg.arg_type {
+                let fits = provided
+                    .peek()
+                    .is_some_and(|arg| Self::arg_matches_transaction_type(arg, transaction_type));
+                if !fits && missing > 0 {
+                    wired.push(AppMethodCallArg::TransactionPlaceholder);
+                    missing -= 1;
+                    continue;
+                }
+            }
+            if let Some(arg) = provided.next() {
+                wired.push(arg.clone());
+            }
+        }
+        wired
+    }
+
+    /// Whether a method call argument can satisfy a transaction argument of the given type.
+    ///
+    /// A nested method call satisfies an `appl` argument with the call itself, which follows its
+    /// own transaction arguments in the group.
+    fn arg_matches_transaction_type(
+        arg: &AppMethodCallArg,
+        transaction_type: &ABITransactionType,
+    ) -> bool {
+        let transaction = match arg {
+            AppMethodCallArg::TransactionPlaceholder => return true,
+            AppMethodCallArg::ABIValue(_)
+            | AppMethodCallArg::ABIReference(_)
+            | AppMethodCallArg::DefaultValue => return false,
+            AppMethodCallArg::Transaction(transaction) => Some(transaction),
+            AppMethodCallArg::TransactionWithSigner(transaction) => Some(&transaction.transaction),
+            _ => None,
+        };
+
+        match transaction_type {
+            ABITransactionType::Txn => true,
+            ABITransactionType::Payment => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::Payment(_) | AppMethodCallArg::AccountClose(_)
+                ) || matches!(transaction, Some(Transaction::Payment(_)))
+            }
+            ABITransactionType::AssetTransfer => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::AssetTransfer(_)
+                        | AppMethodCallArg::AssetOptIn(_)
+                        | AppMethodCallArg::AssetOptOut(_)
+                        | AppMethodCallArg::AssetClawback(_)
+                ) || matches!(transaction, Some(Transaction::AssetTransfer(_)))
+            }
+            ABITransactionType::AssetConfig => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::AssetCreate(_)
+                        | AppMethodCallArg::AssetConfig(_)
+                        | AppMethodCallArg::AssetDestroy(_)
+                ) || matches!(transaction, Some(Transaction::AssetConfig(_)))
+            }
+            ABITransactionType::AssetFreeze => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::AssetFreeze(_) | AppMethodCallArg::AssetUnfreeze(_)
+                ) || matches!(transaction, Some(Transaction::AssetFreeze(_)))
+            }
+            ABITransactionType::KeyRegistration => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::OnlineKeyRegistration(_)
+                        | AppMethodCallArg::OfflineKeyRegistration(_)
+                        | AppMethodCallArg::NonParticipationKeyRegistration(_)
+                ) || matches!(transaction, Some(Transaction::KeyRegistration(_)))
+            }
+            ABITransactionType::AppCall => {
+                matches!(
+                    arg,
+                    AppMethodCallArg::AppCall(_)
+                        | AppMethodCallArg::AppCreateCall(_)
+                        | AppMethodCallArg::AppUpdateCall(_)
+                        | AppMethodCallArg::AppDeleteCall(_)
+                        | AppMethodCallArg::AppCallMethodCall(_)
+                        | AppMethodCallArg::AppCreateMethodCall(_)
+                        | AppMethodCallArg::AppUpdateMethodCall(_)
+                        | AppMethodCallArg::AppDeleteMethodCall(_)
+                ) || matches!(transaction, Some(Transaction::AppCall(_)))
+            }
+        }
+    }
+
     fn add_app_method_call_internal(
         &mut self,
         args: &[AppMethodCallArg],
@@ -761,9 +1349,22 @@ impl TransactionComposer {
             return Err(ComposerError::GroupSizeError);
         }
 
+        let group = self
+            .transactions
+            .iter()
+            .chain(composer_transactions.iter())
+            .collect::<Vec<_>>();
+        for index in starting_index..group.len() {
+            Self::validate_method_call_transaction_args(&group, index)?;
+        }
+
+        let outer_offset = composer_transactions.len() - 1;
         for (offset, composer_transaction) in composer_transactions.into_iter().enumerate() {
-            // If this is a method call with a signer set, attach it directly to preceding bare txn args
+            // If this is the outer method call with a signer set, attach it directly to the
+            // preceding txn args without a signer. The args of nested method calls already have
+            // the signer of their own call, which mustn't leak to the args around them.
             let maybe_signer: Option<Arc<dyn TransactionSigner>> = match &composer_transaction {
+                _ if offset != outer_offset => None,
                 ComposerTransaction::AppCallMethodCall(p) => p.signer.clone(),
                 ComposerTransaction::AppCreateMethodCall(p) => p.signer.clone(),
                 ComposerTransaction::AppUpdateMethodCall(p) => p.signer.clone(),
@@ -826,10 +1427,102 @@ impl TransactionComposer {
         Ok(())
     }
 
+    /// Validate that the transaction arguments of the method call at `index` are satisfied.
+    ///
+    /// Following ARC-4, the transaction arguments of a method call are the transactions
+    /// immediately preceding it in the group, in argument order. Transaction arguments can be
+    /// provided directly, as the transaction of a nested method call (whose own arguments are
+    /// placed before it), or as [`AppMethodCallArg::TransactionPlaceholder`] when the
+    /// transaction is already in the group at the expected position, e.g. because it is an
+    /// argument of a nested method call that is passed later in the argument list.
+    fn validate_method_call_transaction_args(
+        group: &[&ComposerTransaction],
+        index: usize,
+    ) -> Result<(), ComposerError> {
+        let (method, args) = match group[index] {
+            ComposerTransaction::AppCallMethodCall(params) => (&params.method, &params.args),
+            ComposerTransaction::AppCreateMethodCall(params) => (&params.method, &params.args),
+            ComposerTransaction::AppUpdateMethodCall(params) => (&params.method, &params.args),
+            ComposerTransaction::AppDeleteMethodCall(params) => (&params.method, &params.args),
+            _ => return Ok(()),
+        };
+
+        if args.len() != method.args.len() {
+            return Err(ComposerError::ABIEncodingError {
+                message: format!(
+                    "Method {} expects {} arguments but {} were provided",
+                    method.name,
+                    method.args.len(),
+                    args.len()
+                ),
+            });
+        }
+
+        let transaction_args = method
+            .args
+            .iter()
+            .zip(args.iter())
+            .enumerate()
+            .filter_map(
+                |(arg_index, (method_arg, arg))| match &method_arg.arg_type {
+                    ABIMethodArgType::Transaction(transaction_type) => {
+                        Some((arg_index, transaction_type, arg))
+                    }
+                    _ => None,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        for (position, (arg_index, transaction_type, arg)) in transaction_args.iter().enumerate() {
+            if !matches!(arg, ProcessedAppMethodCallArg::TransactionPlaceholder) {
+                return Err(ComposerError::ABIEncodingError {
+                    message: format!(
+                        "Argument {} of method {} must be a {} transaction",
+                        arg_index, method.name, transaction_type
+                    ),
+                });
+            }
+
+            let offset = transaction_args.len() - position;
+            let transaction = index
+                .checked_sub(offset)
+                .map(|transaction_index| group[transaction_index]);
+            match transaction {
+                Some(transaction) if transaction.matches_abi_transaction_type(transaction_type) => {
+                }
+                Some(_) => {
+                    return Err(ComposerError::ABIEncodingError {
+                        message: format!(
+                            "Argument {} of method {} must be a {} transaction, but the transaction at group index {} is of a different type",
+                            arg_index,
+                            method.name,
+                            transaction_type,
+                            index - offset
+                        ),
+                    });
+                }
+                None => {
+                    return Err(ComposerError::ABIEncodingError {
+                        message: format!(
+                            "Argument {} of method {} must be a {} transaction, but there is no transaction at the expected position in the group",
+                            arg_index, method.name, transaction_type
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn add_app_call_method_call(
         &mut self,
         params: AppCallMethodCallParams,
     ) -> Result<(), ComposerError> {
+        let params = AppCallMethodCallParams {
+            args: Self::wire_transaction_args(&params.method, &params.args),
+            ..params
+        };
         self.add_app_method_call_internal(
             &params.args,
             ComposerTransaction::AppCallMethodCall((&params).into()),
@@ -841,6 +1534,10 @@ impl TransactionComposer {
         &mut self,
         params: AppCreateMethodCallParams,
     ) -> Result<(), ComposerError> {
+        let params = AppCreateMethodCallParams {
+            args: Self::wire_transaction_args(&params.method, &params.args),
+            ..params
+        };
         self.add_app_method_call_internal(
             &params.args,
             ComposerTransaction::AppCreateMethodCall((&params).into()),
@@ -852,6 +1549,10 @@ impl TransactionComposer {
         &mut self,
         params: AppUpdateMethodCallParams,
     ) -> Result<(), ComposerError> {
+        let params = AppUpdateMethodCallParams {
+            args: Self::wire_transaction_args(&params.method, &params.args),
+            ..params
+        };
         self.add_app_method_call_internal(
             &params.args,
             ComposerTransaction::AppUpdateMethodCall((&params).into()),
@@ -863,6 +1564,10 @@ impl TransactionComposer {
         &mut self,
         params: AppDeleteMethodCallParams,
     ) -> Result<(), ComposerError> {
+        let params = AppDeleteMethodCallParams {
+            args: Self::wire_transaction_args(&params.method, &params.args),
+            ..params
+        };
         self.add_app_method_call_internal(
             &params.args,
             ComposerTransaction::AppDeleteMethodCall((&params).into()),
@@ -870,6 +1575,37 @@ impl TransactionComposer {
         )
     }
 
+    /// Join each transaction of the group with its ID, confirmation, ABI return and the params
+    /// it was added with.
+    fn build_results(
+        &self,
+        transactions: Vec<Transaction>,
+        transaction_ids: Vec<String>,
+        confirmations: Vec<PendingTransactionResponse>,
+    ) -> Vec<TransactionResult> {
+        let abi_returns = self.parse_abi_return_values(&confirmations);
+
+        transactions
+            .into_iter()
+            .zip(transaction_ids)
+            .zip(confirmations)
+            .zip(abi_returns)
+            .enumerate()
+            .map(
+                |(index, (((transaction, transaction_id), confirmation), abi_return))| {
+                    TransactionResult {
+                        transaction,
+                        transaction_id,
+                        confirmation,
+                        abi_return,
+                        events: Vec::new(),
+                        params: self.transactions.get(index).cloned(),
+                    }
+                },
+            )
+            .collect()
+    }
+
     fn parse_abi_return_values(
         &self,
         confirmations: &[PendingTransactionResponse],
@@ -961,6 +1697,37 @@ impl TransactionComposer {
         }
     }
 
+    /// Add a transaction given as a [`ComposerTransaction`], e.g. one of a list of transactions
+    /// of different types.
+    ///
+    /// Method calls can't be added this way, as their transaction arguments have already been
+    /// resolved against the group they were first added to.
+    pub fn add_composer_transaction(
+        &mut self,
+        transaction: ComposerTransaction,
+    ) -> Result<(), ComposerError> {
+        match transaction {
+            ComposerTransaction::Transaction(transaction) => {
+                self.add_transaction(transaction, None)
+            }
+            ComposerTransaction::TransactionWithSigner(TransactionWithSigner {
+                transaction,
+                signer,
+            }) => self.add_transaction(transaction, Some(signer)),
+            ComposerTransaction::SignedTransaction(signed_transaction) => {
+                self.add_signed_transaction(signed_transaction)
+            }
+            ComposerTransaction::AppCallMethodCall(_)
+            | ComposerTransaction::AppCreateMethodCall(_)
+            | ComposerTransaction::AppUpdateMethodCall(_)
+            | ComposerTransaction::AppDeleteMethodCall(_) => Err(ComposerError::TransactionError {
+                message: "Method calls must be added with the method call specific functions"
+                    .to_string(),
+            }),
+            transaction => self.push(transaction),
+        }
+    }
+
     pub fn add_transaction(
         &mut self,
         transaction: Transaction,
@@ -984,6 +1751,32 @@ impl TransactionComposer {
         }
     }
 
+    /// Add a transaction that has already been signed, e.g. by a partner or a hardware wallet.
+    ///
+    /// The transaction is sent as it was signed: its fee isn't adjusted, no resources are
+    /// populated into it and it isn't signed again. Unless it's sent on its own, it must have
+    /// been signed with the group ID of the composed group, which is checked when the group is
+    /// built.
+    pub fn add_signed_transaction(
+        &mut self,
+        signed_transaction: SignedTransaction,
+    ) -> Result<(), ComposerError> {
+        let group = signed_transaction.transaction.header().group;
+        let conflicting_group = self.transactions.iter().any(|ctxn| match ctxn {
+            ComposerTransaction::SignedTransaction(other) => {
+                other.transaction.header().group != group
+            }
+            _ => false,
+        });
+        if conflicting_group {
+            return Err(ComposerError::TransactionError {
+                message: "Pre-signed transactions in a group must be signed with the same group ID"
+                    .to_string(),
+            });
+        }
+        self.push(ComposerTransaction::SignedTransaction(signed_transaction))
+    }
+
     async fn analyze_group_requirements(
         &self,
         suggested_params: &TransactionParams,
@@ -993,7 +1786,7 @@ impl TransactionComposer {
         let mut app_call_indexes_without_max_fees = Vec::new();
 
         let built_transactions = &self
-            .build_transactions(suggested_params, default_validity_window, None)
+            .build_transactions(suggested_params, default_validity_window, None, None)
             .await?;
 
         let mut transactions_to_simulate = built_transactions
@@ -1047,18 +1840,26 @@ impl TransactionComposer {
                 signature: Some(EMPTY_SIGNATURE),
                 auth_address: None,
                 multisignature: None,
+                logic_signature: None,
             })
             .collect();
 
         let txn_group = SimulateRequestTransactionGroup {
             txns: signed_transactions,
         };
+        let analysis_params = composer_config
+            .analysis_simulate_params
+            .clone()
+            .unwrap_or_default();
         let simulate_request = SimulateRequest {
             txn_groups: vec![txn_group],
+            round: analysis_params.simulation_round,
             allow_unnamed_resources: Some(true),
             allow_empty_signatures: Some(true),
-            fix_signers: Some(true),
-            ..Default::default()
+            allow_more_logging: analysis_params.allow_more_logging,
+            extra_opcode_budget: analysis_params.extra_opcode_budget,
+            exec_trace_config: analysis_params.exec_trace_config,
+            fix_signers: analysis_params.fix_signers.or(Some(true)),
         };
 
         let response: algod_client::models::SimulateTransaction = self
@@ -1071,7 +1872,7 @@ impl TransactionComposer {
         // Handle any simulation failures
         if let Some(failure_message) = &group_response.failure_message {
             if composer_config.cover_app_call_inner_transaction_fees
-                && failure_message.contains("fee too small")
+                && AlgodErrorKind::from_message(None, failure_message).is_fee_too_small()
             {
                 return Err(ComposerError::StateError {
                     message: "Fees were too small to analyze group requirements via simulate. You may need to increase an app call transaction max fee.".to_string(),
@@ -1238,7 +2039,10 @@ impl TransactionComposer {
     }
 
     pub async fn get_suggested_params(&self) -> Result<TransactionParams, ComposerError> {
-        Ok(self.algod_client.transaction_params().await?)
+        match &self.suggested_params_cache {
+            Some(cache) => Ok(cache.get().await?),
+            None => Ok(self.algod_client.transaction_params().await?),
+        }
     }
 
     async fn build_transactions(
@@ -1246,102 +2050,127 @@ impl TransactionComposer {
         suggested_params: &TransactionParams,
         default_validity_window: &u32,
         group_analysis: Option<GroupAnalysis>,
+        fee_payer: Option<&FeePayer>,
     ) -> Result<Vec<Transaction>, ComposerError> {
         let mut transactions = self
             .transactions
             .iter()
-            .map(|ctxn| -> Result<Transaction, ComposerError> {
-                let header = self.build_transaction_header(
-                    ctxn,
-                    suggested_params,
-                    *default_validity_window,
-                )?;
-                let mut calculate_fee = header.fee.is_none();
-
-                let mut transaction = match ctxn {
-                    ComposerTransaction::Transaction(tx) => {
-                        calculate_fee = false;
-                        tx.clone()
-                    }
-                    ComposerTransaction::TransactionWithSigner(tx_with_signer) => {
-                        calculate_fee = false;
-                        tx_with_signer.transaction.clone()
-                    }
-                    ComposerTransaction::Payment(params) => build_payment(params, header),
-                    ComposerTransaction::AccountClose(params) => {
-                        build_account_close(params, header)
-                    }
-                    ComposerTransaction::AssetTransfer(params) => {
-                        build_asset_transfer(params, header)
-                    }
-                    ComposerTransaction::AssetOptIn(params) => build_asset_opt_in(params, header),
-                    ComposerTransaction::AssetOptOut(params) => build_asset_opt_out(params, header),
-                    ComposerTransaction::AssetClawback(params) => {
-                        build_asset_clawback(params, header)
-                    }
-                    ComposerTransaction::AssetCreate(params) => build_asset_create(params, header)
-                        .map_err(|e| ComposerError::TransactionError {
-                            message: e.to_string(),
-                        })?,
-                    ComposerTransaction::AssetConfig(params) => build_asset_config(params, header),
-                    ComposerTransaction::AssetDestroy(params) => {
-                        build_asset_destroy(params, header)
-                    }
-                    ComposerTransaction::AssetFreeze(params) => build_asset_freeze(params, header),
-                    ComposerTransaction::AssetUnfreeze(params) => {
-                        build_asset_unfreeze(params, header)
-                    }
-                    ComposerTransaction::AppCall(params) => build_app_call(params, header)
-                        .map_err(|e| ComposerError::TransactionError {
-                            message: e.to_string(),
-                        })?,
-                    ComposerTransaction::AppCreateCall(params) => {
-                        build_app_create_call(params, header)
-                    }
-                    ComposerTransaction::AppUpdateCall(params) => {
-                        build_app_update_call(params, header)
-                    }
-                    ComposerTransaction::AppDeleteCall(params) => {
-                        build_app_delete_call(params, header)
-                    }
-                    ComposerTransaction::AppCallMethodCall(method_call_params) => {
-                        build_app_call_method_call(method_call_params, header)?
-                    }
-                    ComposerTransaction::AppCreateMethodCall(create_method_call_params) => {
-                        build_app_create_method_call(create_method_call_params, header)?
-                    }
-                    ComposerTransaction::AppUpdateMethodCall(update_method_call_params) => {
-                        build_app_update_method_call(update_method_call_params, header)?
-                    }
-                    ComposerTransaction::AppDeleteMethodCall(delete_method_call_params) => {
-                        build_app_delete_method_call(delete_method_call_params, header)?
-                    }
-                    ComposerTransaction::OnlineKeyRegistration(params) => {
-                        build_online_key_registration(params, header)
-                    }
-                    ComposerTransaction::OfflineKeyRegistration(params) => {
-                        build_offline_key_registration(params, header)
-                    }
-                    ComposerTransaction::NonParticipationKeyRegistration(params) => {
-                        build_non_participation_key_registration(params, header)
+            .enumerate()
+            .map(
+                |(group_index, ctxn)| -> Result<Transaction, ComposerError> {
+                    let mut header = self.build_transaction_header(
+                        ctxn,
+                        suggested_params,
+                        *default_validity_window,
+                    )?;
+                    if let (None, Some(key)) = (header.lease, &self.idempotency_key) {
+                        header.lease = Some(idempotency_lease(key, group_index));
                     }
-                };
+                    let mut calculate_fee = header.fee.is_none();
 
-                if calculate_fee {
-                    transaction = transaction
-                        .assign_fee(FeeParams {
-                            fee_per_byte: suggested_params.fee,
-                            min_fee: suggested_params.min_fee,
-                            extra_fee: ctxn.extra_fee(),
-                            max_fee: ctxn.max_fee(),
-                        })
-                        .map_err(|e| ComposerError::TransactionError {
-                            message: e.to_string(),
-                        })?;
-                }
+                    let mut transaction = match ctxn {
+                        ComposerTransaction::Transaction(tx) => {
+                            calculate_fee = false;
+                            tx.clone()
+                        }
+                        ComposerTransaction::TransactionWithSigner(tx_with_signer) => {
+                            calculate_fee = false;
+                            tx_with_signer.transaction.clone()
+                        }
+                        ComposerTransaction::SignedTransaction(signed_transaction) => {
+                            calculate_fee = false;
+                            // The group is assigned again and checked against the signed one
+                            let mut transaction = signed_transaction.transaction.clone();
+                            transaction.header_mut().group = None;
+                            transaction
+                        }
+                        ComposerTransaction::Payment(params) => build_payment(params, header),
+                        ComposerTransaction::AccountClose(params) => {
+                            build_account_close(params, header)
+                        }
+                        ComposerTransaction::AssetTransfer(params) => {
+                            build_asset_transfer(params, header)
+                        }
+                        ComposerTransaction::AssetOptIn(params) => {
+                            build_asset_opt_in(params, header)
+                        }
+                        ComposerTransaction::AssetOptOut(params) => {
+                            build_asset_opt_out(params, header)
+                        }
+                        ComposerTransaction::AssetClawback(params) => {
+                            build_asset_clawback(params, header)
+                        }
+                        ComposerTransaction::AssetCreate(params) => {
+                            build_asset_create(params, header).map_err(|e| {
+                                ComposerError::TransactionError {
+                                    message: e.to_string(),
+                                }
+                            })?
+                        }
+                        ComposerTransaction::AssetConfig(params) => {
+                            build_asset_config(params, header)
+                        }
+                        ComposerTransaction::AssetDestroy(params) => {
+                            build_asset_destroy(params, header)
+                        }
+                        ComposerTransaction::AssetFreeze(params) => {
+                            build_asset_freeze(params, header)
+                        }
+                        ComposerTransaction::AssetUnfreeze(params) => {
+                            build_asset_unfreeze(params, header)
+                        }
+                        ComposerTransaction::AppCall(params) => build_app_call(params, header)
+                            .map_err(|e| ComposerError::TransactionError {
+                                message: e.to_string(),
+                            })?,
+                        ComposerTransaction::AppCreateCall(params) => {
+                            build_app_create_call(params, header)
+                        }
+                        ComposerTransaction::AppUpdateCall(params) => {
+                            build_app_update_call(params, header)
+                        }
+                        ComposerTransaction::AppDeleteCall(params) => {
+                            build_app_delete_call(params, header)
+                        }
+                        ComposerTransaction::AppCallMethodCall(method_call_params) => {
+                            build_app_call_method_call(method_call_params, header)?
+                        }
+                        ComposerTransaction::AppCreateMethodCall(create_method_call_params) => {
+                            build_app_create_method_call(create_method_call_params, header)?
+                        }
+                        ComposerTransaction::AppUpdateMethodCall(update_method_call_params) => {
+                            build_app_update_method_call(update_method_call_params, header)?
+                        }
+                        ComposerTransaction::AppDeleteMethodCall(delete_method_call_params) => {
+                            build_app_delete_method_call(delete_method_call_params, header)?
+                        }
+                        ComposerTransaction::OnlineKeyRegistration(params) => {
+                            build_online_key_registration(params, header)
+                        }
+                        ComposerTransaction::OfflineKeyRegistration(params) => {
+                            build_offline_key_registration(params, header)
+                        }
+                        ComposerTransaction::NonParticipationKeyRegistration(params) => {
+                            build_non_participation_key_registration(params, header)
+                        }
+                    };
+
+                    if calculate_fee {
+                        transaction = transaction
+                            .assign_fee(FeeParams {
+                                fee_per_byte: suggested_params.fee,
+                                min_fee: suggested_params.min_fee,
+                                extra_fee: ctxn.extra_fee(),
+                                max_fee: ctxn.max_fee(),
+                            })
+                            .map_err(|e| ComposerError::TransactionError {
+                                message: e.to_string(),
+                            })?;
+                    }
 
-                Ok(transaction)
-            })
+                    Ok(transaction)
+                },
+            )
             .collect::<Result<Vec<Transaction>, ComposerError>>()?;
 
         if let Some(mut group_analysis) = group_analysis {
@@ -1451,6 +2280,15 @@ impl TransactionComposer {
                 }
 
                 if let Some(resources_accessed) = resources_accessed {
+                    if self.transactions[group_index].is_signed() {
+                        return Err(ComposerError::TransactionError {
+                            message: format!(
+                                "Pre-signed transaction {} accesses resources it doesn't reference",
+                                group_index
+                            ),
+                        });
+                    }
+
                     // Apply the transaction level resource population logic
                     if let Transaction::AppCall(ref mut app_call) = transactions[group_index] {
                         // Check for unexpected resources at transaction level
@@ -1474,6 +2312,15 @@ impl TransactionComposer {
                             });
                         }
 
+                        if self.uses_access_list(app_call) {
+                            Self::populate_transaction_access_references(
+                                app_call,
+                                resources_accessed,
+                                group_index,
+                            )?;
+                            continue;
+                        }
+
                         let mut accounts_count = 0;
                         let mut apps_count = 0;
                         let mut assets_count = 0;
@@ -1548,10 +2395,44 @@ impl TransactionComposer {
 
             // Apply the group level resource population logic
             if let Some(group_resources) = group_analysis.unnamed_resources_accessed.take() {
-                TransactionComposer::populate_group_resources(&mut transactions, group_resources)?;
+                // Resources can't be added to pre-signed transactions
+                let (group_indexes, mut unsigned_transactions): (Vec<usize>, Vec<Transaction>) =
+                    transactions
+                        .iter()
+                        .enumerate()
+                        .filter(|(group_index, _)| !self.transactions[*group_index].is_signed())
+                        .map(|(group_index, txn)| (group_index, txn.clone()))
+                        .unzip();
+                if self
+                    .composer_config
+                    .populate_app_call_resources
+                    .use_access_list()
+                {
+                    self.populate_group_access_references(
+                        &mut unsigned_transactions,
+                        group_resources,
+                    )?;
+                } else {
+                    TransactionComposer::populate_group_resources(
+                        &mut unsigned_transactions,
+                        group_resources,
+                    )?;
+                }
+                for (group_index, txn) in group_indexes.into_iter().zip(unsigned_transactions) {
+                    transactions[group_index] = txn;
+                }
             }
         }
 
+        if let Some(fee_payer) = fee_payer {
+            self.sponsor_group_fees(
+                &mut transactions,
+                fee_payer,
+                suggested_params,
+                *default_validity_window,
+            )?;
+        }
+
         if transactions.len() > 1 {
             transactions =
                 transactions
@@ -1564,6 +2445,269 @@ impl TransactionComposer {
         Ok(transactions)
     }
 
+    /// Move the fees of the composed transactions onto a zero amount payment from the fee payer,
+    /// which is appended to the group. Transactions added pre-built keep their own fee.
+    fn sponsor_group_fees(
+        &self,
+        transactions: &mut Vec<Transaction>,
+        fee_payer: &FeePayer,
+        suggested_params: &TransactionParams,
+        default_validity_window: u32,
+    ) -> Result<(), ComposerError> {
+        if transactions.len() >= MAX_TX_GROUP_SIZE {
+            return Err(ComposerError::GroupSizeError);
+        }
+
+        let mut sponsored_fees: u64 = 0;
+        for (ctxn, txn) in self.transactions.iter().zip(transactions.iter_mut()) {
+            if matches!(
+                ctxn,
+                ComposerTransaction::Transaction(_)
+                    | ComposerTransaction::TransactionWithSigner(_)
+                    | ComposerTransaction::SignedTransaction(_)
+            ) {
+                continue;
+            }
+            let txn_header = txn.header_mut();
+            sponsored_fees += txn_header.fee.unwrap_or(0);
+            txn_header.fee = Some(0);
+        }
+
+        let fee_payment_params = PaymentParams {
+            sender: fee_payer.address.clone(),
+            receiver: fee_payer.address.clone(),
+            amount: AlgoAmount::ZERO,
+            ..Default::default()
+        };
+        let header = self.build_transaction_header(
+            &ComposerTransaction::Payment(fee_payment_params.clone()),
+            suggested_params,
+            default_validity_window,
+        )?;
+        let fee_payment = build_payment(&fee_payment_params, header)
+            .assign_fee(FeeParams {
+                fee_per_byte: suggested_params.fee,
+                min_fee: suggested_params.min_fee,
+                extra_fee: Some(sponsored_fees),
+                max_fee: None,
+            })
+            .map_err(|e| ComposerError::TransactionError {
+                message: e.to_string(),
+            })?;
+
+        transactions.push(fee_payment);
+        Ok(())
+    }
+
+    /// Whether the resources of an app call are populated into its access list, which is the case
+    /// when access lists are enabled or the app call already has one, unless it uses the
+    /// reference arrays.
+    fn uses_access_list(&self, app_call: &AppCallTransactionFields) -> bool {
+        let has_reference_arrays = [
+            app_call.account_references.as_ref().map(Vec::len),
+            app_call.app_references.as_ref().map(Vec::len),
+            app_call.asset_references.as_ref().map(Vec::len),
+            app_call.box_references.as_ref().map(Vec::len),
+        ]
+        .iter()
+        .any(|len| len.unwrap_or(0) > 0);
+        let has_access_list = app_call
+            .access_references
+            .as_ref()
+            .is_some_and(|refs| !refs.is_empty());
+
+        !has_reference_arrays
+            && (has_access_list
+                || self
+                    .composer_config
+                    .populate_app_call_resources
+                    .use_access_list())
+    }
+
+    /// Populate the transaction-level resources of an app call into its access list
+    fn populate_transaction_access_references(
+        app_call: &mut AppCallTransactionFields,
+        resources_accessed: &SimulateUnnamedResourcesAccessed,
+        group_index: usize,
+    ) -> Result<(), ComposerError> {
+        let mut references = Vec::new();
+        for account in resources_accessed.accounts.iter().flatten() {
+            let address =
+                account
+                    .parse::<Address>()
+                    .map_err(|e| ComposerError::TransactionError {
+                        message: format!("Invalid account address: {}", e),
+                    })?;
+            references.push(AccessReference::Address(address));
+        }
+        references.extend(
+            resources_accessed
+                .apps
+                .iter()
+                .flatten()
+                .map(|app_id| AccessReference::App(*app_id)),
+        );
+        references.extend(
+            resources_accessed
+                .assets
+                .iter()
+                .flatten()
+                .map(|asset_id| AccessReference::Asset(*asset_id)),
+        );
+
+        let access_references = app_call.access_references.get_or_insert_with(Vec::new);
+        for reference in references {
+            if !access_references.contains(&reference) {
+                access_references.push(reference);
+            }
+        }
+
+        if access_references.len() > MAX_ACCESS_REFERENCES {
+            return Err(ComposerError::TransactionError {
+                message: format!(
+                    "Access reference limit of {} exceeded in transaction {}",
+                    MAX_ACCESS_REFERENCES, group_index
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Populate group-level resources into the access lists of the app calls of the group.
+    ///
+    /// Resources in the access list of any transaction are available to the whole group, so each
+    /// resource is added to the app call that needs the fewest new entries for it, e.g. a holding
+    /// goes to the app call that already has its account and asset.
+    fn populate_group_access_references(
+        &self,
+        transactions: &mut [Transaction],
+        group_resources: SimulateUnnamedResourcesAccessed,
+    ) -> Result<(), ComposerError> {
+        let parse_address = |account: &str| {
+            account
+                .parse::<Address>()
+                .map_err(|e| ComposerError::TransactionError {
+                    message: format!("Invalid account address: {}", e),
+                })
+        };
+
+        let mut remaining_accounts = group_resources.accounts.unwrap_or_default();
+        let mut remaining_apps = group_resources.apps.unwrap_or_default();
+        let mut remaining_assets = group_resources.assets.unwrap_or_default();
+        let mut resources = Vec::new();
+
+        // Cross-reference resources first, as they also make their account and asset or app
+        // available to the group
+        for app_local in group_resources.app_locals.unwrap_or_default() {
+            remaining_accounts.retain(|account| account != &app_local.account);
+            remaining_apps.retain(|app| *app != app_local.app);
+            resources.push(AccessReference::Locals(LocalsReference {
+                address: parse_address(&app_local.account)?,
+                app_id: app_local.app,
+            }));
+        }
+        for asset_holding in group_resources.asset_holdings.unwrap_or_default() {
+            remaining_accounts.retain(|account| account != &asset_holding.account);
+            remaining_assets.retain(|asset| *asset != asset_holding.asset);
+            resources.push(AccessReference::Holding(HoldingReference {
+                address: parse_address(&asset_holding.account)?,
+                asset_id: asset_holding.asset,
+            }));
+        }
+        for box_ref in group_resources.boxes.unwrap_or_default() {
+            remaining_apps.retain(|app| *app != box_ref.app);
+            resources.push(AccessReference::Box(algokit_transact::BoxReference {
+                app_id: box_ref.app,
+                name: box_ref.name,
+            }));
+        }
+        for account in remaining_accounts {
+            resources.push(AccessReference::Address(parse_address(&account)?));
+        }
+        resources.extend(remaining_assets.into_iter().map(AccessReference::Asset));
+        resources.extend(remaining_apps.into_iter().map(AccessReference::App));
+        for _ in 0..group_resources.extra_box_refs.unwrap_or(0) {
+            resources.push(AccessReference::Box(algokit_transact::BoxReference {
+                app_id: 0,
+                name: Vec::new(),
+            }));
+        }
+
+        for resource in resources {
+            let is_extra_box_ref = matches!(
+                &resource,
+                AccessReference::Box(box_ref) if box_ref.app_id == 0 && box_ref.name.is_empty()
+            );
+
+            // Find the app call with room that needs the fewest new entries for the resource
+            let candidate = transactions
+                .iter()
+                .enumerate()
+                .filter_map(|(group_index, txn)| match txn {
+                    Transaction::AppCall(app_call) if self.uses_access_list(app_call) => {
+                        let existing = app_call.access_references.as_deref().unwrap_or(&[]);
+                        let new_entries = Self::access_entries_for(app_call, &resource)
+                            .into_iter()
+                            .filter(|entry| is_extra_box_ref || !existing.contains(entry))
+                            .collect::<Vec<_>>();
+                        (existing.len() + new_entries.len() <= MAX_ACCESS_REFERENCES)
+                            .then_some((group_index, new_entries))
+                    }
+                    _ => None,
+                })
+                .min_by_key(|(_, new_entries)| new_entries.len());
+
+            let (group_index, new_entries) =
+                candidate.ok_or_else(|| ComposerError::TransactionError {
+                    message:
+                        "No more transactions below reference limit. Add another app call to the group."
+                            .to_string(),
+                })?;
+
+            if let Transaction::AppCall(ref mut app_call) = transactions[group_index] {
+                app_call
+                    .access_references
+                    .get_or_insert_with(Vec::new)
+                    .extend(new_entries);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The access list entries an app call needs for a resource: the resource itself and the
+    /// account, asset or app it refers to, unless that's the sender or the called app.
+    fn access_entries_for(
+        app_call: &AppCallTransactionFields,
+        resource: &AccessReference,
+    ) -> Vec<AccessReference> {
+        let is_called_app = |app_id: u64| app_id == 0 || app_id == app_call.app_id;
+        let mut entries = Vec::new();
+        match resource {
+            AccessReference::Holding(holding) => {
+                if holding.address != app_call.header.sender {
+                    entries.push(AccessReference::Address(holding.address.clone()));
+                }
+                entries.push(AccessReference::Asset(holding.asset_id));
+            }
+            AccessReference::Locals(locals) => {
+                if locals.address != app_call.header.sender {
+                    entries.push(AccessReference::Address(locals.address.clone()));
+                }
+                if !is_called_app(locals.app_id) {
+                    entries.push(AccessReference::App(locals.app_id));
+                }
+            }
+            AccessReference::Box(box_ref) if !is_called_app(box_ref.app_id) => {
+                entries.push(AccessReference::App(box_ref.app_id));
+            }
+            _ => {}
+        }
+        entries.push(resource.clone());
+        entries
+    }
+
     /// Populate group-level resources for app call transactions
     fn populate_group_resources(
         transactions: &mut [Transaction],
@@ -2004,22 +3148,92 @@ impl TransactionComposer {
         }
     }
 
+    /// Rebuild the group with fresh suggested params and validity rounds, e.g. to resend it after
+    /// its validity window has passed.
+    ///
+    /// The built and signed group is discarded. Explicit validity rounds are replaced by a
+    /// validity window of the same length starting at the latest round, other fields, including
+    /// leases and notes, are kept.
+    pub async fn rebuild(&mut self) -> Result<&Vec<TransactionWithSigner>, ComposerError> {
+        if let Some(cache) = &self.suggested_params_cache {
+            cache.clear().await;
+        }
+        let last_round = self.get_suggested_params().await?.last_round;
+
+        for transaction in self.transactions.iter_mut() {
+            transaction.reset_validity_rounds(last_round);
+        }
+        self.built_group = None;
+        self.signed_group = None;
+
+        self.build().await
+    }
+
+    /// Clone the composer and rebuild the clone, see [`TransactionComposer::rebuild`].
+    pub async fn clone_for_resend(&self) -> Result<Self, ComposerError> {
+        let mut composer = self.clone();
+        composer.rebuild().await?;
+        Ok(composer)
+    }
+
     pub async fn build(&mut self) -> Result<&Vec<TransactionWithSigner>, ComposerError> {
         if let Some(ref group) = self.built_group {
             return Ok(group);
         }
 
         let suggested_params = self.get_suggested_params().await?;
+        self.build_with_suggested_params(suggested_params, true)
+            .await
+    }
+
+    /// Build the group with the given suggested params and without contacting algod, e.g. to
+    /// export the group for signing in an air-gapped environment.
+    ///
+    /// App call resources and inner transaction fees can't be populated without simulating the
+    /// group, so they need to be declared on the transactions. Once built, the group can be
+    /// signed with [`Self::gather_signatures`] as long as the signers don't need the network.
+    pub async fn build_offline(
+        &mut self,
+        suggested_params: TransactionParams,
+    ) -> Result<&Vec<TransactionWithSigner>, ComposerError> {
+        if let Some(ref group) = self.built_group {
+            return Ok(group);
+        }
+
+        self.build_with_suggested_params(suggested_params, false)
+            .await
+    }
+
+    /// Build the group, simulating it to populate resources and cover inner transaction fees when
+    /// `analyze` is set and the composer config asks for either.
+    async fn build_with_suggested_params(
+        &mut self,
+        suggested_params: TransactionParams,
+        analyze: bool,
+    ) -> Result<&Vec<TransactionWithSigner>, ComposerError> {
+        if let Some(fee_policy) = &self.composer_config.fee_policy {
+            fee_policy.check_fee_per_byte(suggested_params.fee)?;
+        }
         let default_validity_window =
             Self::get_default_validity_window(&suggested_params.genesis_id);
+        self.composer_config.populate_app_call_resources = self
+            .composer_config
+            .populate_app_call_resources
+            .for_consensus(&ConsensusVersion::new(&suggested_params.consensus_version));
 
-        let group_analysis = if (self.composer_config.cover_app_call_inner_transaction_fees
+        let requires_analysis = (self.composer_config.cover_app_call_inner_transaction_fees
             || self
                 .composer_config
                 .populate_app_call_resources
                 .is_enabled())
-            && self.transactions.iter().any(|ctxn| ctxn.is_app_call())
-        {
+            && self.transactions.iter().any(|ctxn| ctxn.is_app_call());
+        if requires_analysis && !analyze {
+            warn!(
+                "Building the group offline, app call resources and inner transaction fees aren't populated"
+            );
+        }
+
+        let group_analysis = if requires_analysis && analyze {
             Some(
                 self.analyze_group_requirements(
                     &suggested_params,
@@ -2033,13 +3247,74 @@ impl TransactionComposer {
         };
 
         let transactions = self
-            .build_transactions(&suggested_params, &default_validity_window, group_analysis)
+            .build_transactions(
+                &suggested_params,
+                &default_validity_window,
+                group_analysis,
+                self.composer_config.fee_payer.as_ref(),
+            )
             .await?;
+        if let Some(fee_policy) = &self.composer_config.fee_policy {
+            fee_policy.check_fees(&transactions)?;
+        }
+        self.check_signed_transactions(&transactions)?;
 
         let transactions_with_signers = self.gather_signers(transactions);
 
-        self.built_group = Some(transactions_with_signers?);
-        Ok(self.built_group.as_ref().unwrap())
+        let built_group = self.built_group.insert(transactions_with_signers?);
+
+        let events = Config::events();
+        if events.has_listeners() {
+            let transaction_ids: Vec<String> = built_group
+                .iter()
+                .filter_map(|txn_with_signer| txn_with_signer.transaction.id().ok())
+                .collect();
+            let group = built_group
+                .first()
+                .and_then(|txn_with_signer| txn_with_signer.transaction.header().group);
+            let data = txn_group_event_data(group, &transaction_ids);
+            events
+                .emit(EventType::GroupBuilt, EventData::GroupBuilt(data))
+                .await;
+        }
+
+        Ok(built_group)
+    }
+
+    /// Build the group and describe it as a [`GroupGraph`], with the ABI transaction arguments
+    /// and fee pooling between its transactions as edges.
+    pub async fn export_group_graph(&mut self) -> Result<GroupGraph, ComposerError> {
+        let min_fee = self.get_suggested_params().await?.min_fee;
+        self.build().await?;
+        let built_group = self.built_group.as_ref().ok_or(ComposerError::StateError {
+            message: "No transactions available".to_string(),
+        })?;
+
+        let methods = self
+            .transactions
+            .iter()
+            .map(|transaction| self.get_method_from_transaction(transaction))
+            .collect::<Vec<_>>();
+
+        GroupGraph::from_group(built_group, &methods, min_fee)
+    }
+
+    /// Check that the pre-signed transactions are unchanged by building the group, i.e. that they
+    /// were signed with the ID of the composed group.
+    fn check_signed_transactions(&self, transactions: &[Transaction]) -> Result<(), ComposerError> {
+        for (group_index, (ctxn, txn)) in self.transactions.iter().zip(transactions).enumerate() {
+            if let ComposerTransaction::SignedTransaction(signed_transaction) = ctxn {
+                if &signed_transaction.transaction != txn {
+                    return Err(ComposerError::TransactionError {
+                        message: format!(
+                            "Pre-signed transaction {} wasn't signed for this group, its group ID must match the composed group",
+                            group_index
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
     }
 
     fn gather_signers(
@@ -2050,21 +3325,32 @@ impl TransactionComposer {
             .into_iter()
             .enumerate()
             .map(|(group_index, txn)| {
-                let ctxn = &self.transactions[group_index];
-                let signer = match ctxn {
-                    ComposerTransaction::TransactionWithSigner(tx_with_signer) => {
-                        tx_with_signer.signer.clone()
+                let transaction_signer = match self.transactions.get(group_index) {
+                    Some(ComposerTransaction::TransactionWithSigner(tx_with_signer)) => {
+                        Some(tx_with_signer.signer.clone())
                     }
-                    _ => {
-                        if let Some(transaction_signer) = ctxn.signer() {
-                            transaction_signer
-                        } else {
-                            let sender_address = txn.header().sender.clone();
-                            self.signer_getter
-                                .get_signer(sender_address.clone())
-                                .map_err(|e| ComposerError::SigningError { message: e })?
-                        }
+                    Some(ComposerTransaction::SignedTransaction(signed_transaction)) => {
+                        let signer: Arc<dyn TransactionSigner> =
+                            Arc::new(PreSignedTransactionSigner {
+                                signed_transaction: signed_transaction.clone(),
+                            });
+                        Some(signer)
                     }
+                    Some(ctxn) => ctxn.signer(),
+                    // The fee payment appended to a sponsored group
+                    None => self
+                        .composer_config
+                        .fee_payer
+                        .as_ref()
+                        .and_then(|fee_payer| fee_payer.signer.clone()),
+                };
+                let signer = if let Some(transaction_signer) = transaction_signer {
+                    transaction_signer
+                } else {
+                    let sender_address = txn.header().sender.clone();
+                    self.signer_getter
+                        .get_signer(sender_address.clone())
+                        .map_err(|e| ComposerError::SigningError { message: e })?
                 };
                 Ok(TransactionWithSigner {
                     transaction: txn,
@@ -2108,19 +3394,42 @@ impl TransactionComposer {
         let mut signed_transactions: Vec<Option<SignedTransaction>> =
             vec![None; transactions_with_signers.len()];
 
-        for (signer, indices) in signer_groups {
-            // Sign all transactions for this signer
-            let signed_txns: Vec<SignedTransaction> = signer
-                .sign_transactions(&transactions, &indices)
-                .await
-                .map_err(|e| ComposerError::SigningError {
-                    message: e.to_string(),
-                })?;
-
-            for (i, &index) in indices.iter().enumerate() {
-                signed_transactions[index] = Some(signed_txns[i].to_owned());
+        // Sign the transactions of each signer concurrently, e.g. so a wallet prompt doesn't wait
+        // on another
+        let transactions = &transactions;
+        let sign_results = join_all(signer_groups.iter().map(|(signer, indices)| async move {
+            (
+                indices,
+                signer.sign_transactions(transactions, indices).await,
+            )
+        }))
+        .await;
+
+        let mut failures = Vec::new();
+        for (indices, result) in sign_results {
+            match result {
+                Ok(signed_txns) if signed_txns.len() == indices.len() => {
+                    for (signed_txn, &index) in signed_txns.into_iter().zip(indices) {
+                        signed_transactions[index] = Some(signed_txn);
+                    }
+                }
+                Ok(signed_txns) => failures.push(format!(
+                    "A signer returned {} signed transactions for the {} transactions at indexes {:?}",
+                    signed_txns.len(),
+                    indices.len(),
+                    indices
+                )),
+                Err(e) => failures.push(format!(
+                    "Failed to sign the transactions at indexes {:?}: {}",
+                    indices, e
+                )),
             }
         }
+        if !failures.is_empty() {
+            return Err(ComposerError::SigningError {
+                message: failures.join("; "),
+            });
+        }
 
         let (signed_transactions, unsigned_indexes) =
             signed_transactions.into_iter().enumerate().fold(
@@ -2154,13 +3463,11 @@ impl TransactionComposer {
         tx_id: &str,
         max_rounds_to_wait: u32,
     ) -> Result<PendingTransactionResponse, ComposerError> {
-        let status =
-            self.algod_client
-                .get_status()
-                .await
-                .map_err(|e| ComposerError::TransactionError {
-                    message: format!("Failed to get status: {:?}", e),
-                })?;
+        let status = self
+            .algod_client
+            .get_status()
+            .await
+            .map_err(|e| ComposerError::StatusError { source: e.into() })?;
 
         let start_round = status.last_round + 1;
         let mut current_round = start_round;
@@ -2191,16 +3498,7 @@ impl TransactionComposer {
                 Err(error) => {
                     // Only retry for 404 errors (transaction not found yet)
                     // All other errors indicate permanent issues and should fail fast
-                    let is_retryable = matches!(
-                        &error,
-                        algod_client::apis::Error::Api {
-                            source: algod_client::apis::AlgodApiError::PendingTransactionInformation {
-                                error: algod_client::apis::pending_transaction_information::PendingTransactionInformationError::Status404(_)
-                            }
-                        }
-                    ) || error.to_string().contains("404");
-
-                    if is_retryable {
+                    if AlgodErrorKind::from(&error).is_not_found() {
                         current_round += 1;
                         continue;
                     } else {
@@ -2225,6 +3523,9 @@ impl TransactionComposer {
         &mut self,
         params: Option<SendParams>,
     ) -> Result<TransactionComposerSendResult, ComposerError> {
+        if let Some(key) = params.as_ref().and_then(|p| p.idempotency_key.clone()) {
+            self.set_idempotency_key(key)?;
+        }
         self.gather_signatures().await?;
 
         let signed_transactions = self
@@ -2258,99 +3559,290 @@ impl TransactionComposer {
             encoded_bytes.extend_from_slice(&encoded_txn);
         }
 
-        let last_valid_max = signed_transactions
-            .iter()
-            .map(|signed_transaction| signed_transaction.transaction.header().last_valid)
-            .max()
-            .ok_or(ComposerError::StateError {
-                message: "Failed to calculate last valid round".to_string(),
-            })?;
+        let wait_rounds = self
+            .rounds_to_wait(signed_transactions, params.as_ref())
+            .await?;
 
-        let wait_rounds = if let Some(max_rounds_to_wait_for_confirmation) =
-            params.and_then(|p| p.max_rounds_to_wait_for_confirmation)
+        if params
+            .as_ref()
+            .and_then(|p| p.cancellation_token.as_ref())
+            .is_some_and(CancellationToken::is_cancelled)
         {
-            max_rounds_to_wait_for_confirmation
-        } else {
-            let suggested_params = self.get_suggested_params().await?;
-            let first_round: u64 = suggested_params.last_round; // The last round seen, so is the first round valid
-            ((last_valid_max - first_round) + 1)
-                .try_into()
-                .map_err(|e| ComposerError::TransactionError {
-                    message: format!("Failed to calculate rounds to wait: {}", e),
-                })?
-        };
+            return Err(ComposerError::Cancelled {
+                message: "The send was cancelled before the group was submitted".to_string(),
+            });
+        }
 
         // If debugging with full tracing enabled, emit a simulate event before submission for AVM debugging
-        if Config::debug() && Config::trace_all() {
-            let simulate_params = SimulateParams {
-                allow_more_logging: Some(true),
-                allow_empty_signatures: Some(true),
-                allow_unnamed_resources: Some(true),
-                extra_opcode_budget: None,
-                exec_trace_config: Some(algod_client::models::SimulateTraceConfig {
-                    enable: Some(true),
-                    stack_change: Some(true),
-                    scratch_change: Some(true),
-                    state_change: Some(true),
-                }),
-                simulation_round: None,
-                skip_signatures: true,
-            };
+        let traced = Config::debug() && Config::trace_all();
+        if traced {
+            self.simulate_for_debugging(signed_transactions).await;
+        }
 
-            if let Ok(simulated) = self.simulate(Some(simulate_params)).await {
-                let payload = serde_json::to_value(&simulated.simulate_response)
-                    .unwrap_or_else(|_| serde_json::json!({}));
-                Config::events()
-                    .emit(
-                        EventType::TxnGroupSimulated,
-                        EventData::TxnGroupSimulated(TxnGroupSimulatedEventData {
-                            simulate_response: payload,
-                        }),
-                    )
-                    .await;
+        if let Err(e) = self.algod_client.raw_transaction(encoded_bytes).await {
+            // Trace the failed group for the AVM debugger, unless it was already traced above
+            if Config::debug() && !traced {
+                self.simulate_for_debugging(signed_transactions).await;
             }
+            return Err(ComposerError::SubmitError { source: e.into() });
         }
 
-        let _ = self
-            .algod_client
-            .raw_transaction(encoded_bytes)
-            .await
-            .map_err(|e| ComposerError::TransactionError {
-                message: format!("Failed to submit transaction(s): {:?}", e),
-            })?;
-
-        let mut confirmations = Vec::new();
-        for id in &transaction_ids {
-            let confirmation = self.wait_for_confirmation(id, wait_rounds).await?;
-            confirmations.push(confirmation);
+        let events = Config::events();
+        if events.has_listeners() {
+            events
+                .emit(
+                    EventType::GroupSent,
+                    EventData::GroupSent(txn_group_event_data(group, &transaction_ids)),
+                )
+                .await;
         }
 
-        // Parse ABI return values from the confirmations
-        let abi_returns = self.parse_abi_return_values(&confirmations);
+        self.confirm_group(transactions, transaction_ids, wait_rounds, params.as_ref())
+            .await
+    }
 
-        // Build results with 1:1 correspondence
-        let results = transactions
-            .into_iter()
-            .zip(transaction_ids)
-            .zip(confirmations)
-            .zip(abi_returns)
-            .map(
-                |(((transaction, transaction_id), confirmation), abi_return)| TransactionResult {
-                    transaction,
-                    transaction_id,
-                    confirmation,
-                    abi_return,
-                },
-            )
+    /// Resume a [`Self::send`] that failed after the group may have been submitted, e.g. when the
+    /// connection to algod dropped while waiting for confirmation.
+    ///
+    /// The transaction IDs of the signed group are looked up in algod: when all of them are
+    /// confirmed or pending, the group is awaited and its result returned; when any of them is
+    /// unknown to algod, `None` is returned and the group needs submitting again.
+    pub async fn resume_send(
+        &self,
+        params: Option<SendParams>,
+    ) -> Result<Option<TransactionComposerSendResult>, ComposerError> {
+        let signed_transactions = self
+            .signed_group
+            .as_ref()
+            .filter(|&stxns| !stxns.is_empty())
+            .ok_or(ComposerError::StateError {
+                message: "The group hasn't been signed".to_string(),
+            })?;
+
+        let transactions: Vec<Transaction> = signed_transactions
+            .iter()
+            .map(|txn| txn.transaction.clone())
             .collect();
+        let transaction_ids: Vec<String> = transactions
+            .iter()
+            .map(|txn| txn.id())
+            .collect::<Result<Vec<String>, _>>()?;
+
+        for id in &transaction_ids {
+            match self.algod_client.pending_transaction_information(id).await {
+                Ok(response) if response.pool_error.is_empty() => {}
+                Ok(_) => return Ok(None),
+                Err(e) if AlgodErrorKind::from(&e).is_not_found() => return Ok(None),
+                Err(e) => return Err(ComposerError::AlgodClientError { source: e }),
+            }
+        }
+
+        let wait_rounds = self
+            .rounds_to_wait(signed_transactions, params.as_ref())
+            .await?;
+        self.confirm_group(transactions, transaction_ids, wait_rounds, params.as_ref())
+            .await
+            .map(Some)
+    }
+
+    /// The number of rounds to wait for the confirmation of a submitted group, by default until
+    /// the last of its transactions expires.
+    async fn rounds_to_wait(
+        &self,
+        signed_transactions: &[SignedTransaction],
+        params: Option<&SendParams>,
+    ) -> Result<u32, ComposerError> {
+        if let Some(max_rounds_to_wait_for_confirmation) =
+            params.and_then(|p| p.max_rounds_to_wait_for_confirmation)
+        {
+            return Ok(max_rounds_to_wait_for_confirmation);
+        }
+
+        let last_valid_max = signed_transactions
+            .iter()
+            .map(|signed_transaction| signed_transaction.transaction.header().last_valid)
+            .max()
+            .ok_or(ComposerError::StateError {
+                message: "Failed to calculate last valid round".to_string(),
+            })?;
+        let suggested_params = self.get_suggested_params().await?;
+        let first_round: u64 = suggested_params.last_round; // The last round seen, so is the first round valid
+        (last_valid_max.saturating_sub(first_round) + 1)
+            .try_into()
+            .map_err(|e| ComposerError::TransactionError {
+                message: format!("Failed to calculate rounds to wait: {}", e),
+            })
+    }
+
+    /// Wait for the confirmation of each transaction of a submitted group and build the results.
+    async fn confirm_group(
+        &self,
+        transactions: Vec<Transaction>,
+        transaction_ids: Vec<String>,
+        wait_rounds: u32,
+        params: Option<&SendParams>,
+    ) -> Result<TransactionComposerSendResult, ComposerError> {
+        let group = transactions[0].header().group;
+        let events = Config::events();
+
+        let confirmations = until_cancelled(
+            async {
+                let mut progress = ProgressReporter::new(
+                    ProgressOperation::TransactionGroupConfirmation,
+                    Some(transaction_ids.len() as u64),
+                );
+                let mut confirmations = Vec::new();
+                for id in &transaction_ids {
+                    let confirmation = self.wait_for_confirmation(id, wait_rounds).await?;
+                    confirmations.push(confirmation);
+                    progress.advance(1).await;
+                }
+                Ok(confirmations)
+            },
+            params.and_then(|p| p.cancellation_token.as_ref()),
+            params.and_then(|p| p.confirmation_timeout),
+        )
+        .await?;
+
+        if events.has_listeners() {
+            let TxnGroupEventData {
+                group_id,
+                transaction_ids,
+            } = txn_group_event_data(group, &transaction_ids);
+            let confirmed_round = confirmations
+                .iter()
+                .filter_map(|confirmation| confirmation.confirmed_round)
+                .max();
+            events
+                .emit(
+                    EventType::GroupConfirmed,
+                    EventData::GroupConfirmed(TxnGroupConfirmedEventData {
+                        group_id,
+                        transaction_ids,
+                        confirmed_round,
+                    }),
+                )
+                .await;
+        }
+
+        let mut results = self.build_results(transactions, transaction_ids, confirmations);
+
+        let fee_payment = self.split_fee_payment(&mut results);
 
-        Ok(TransactionComposerSendResult { group, results })
+        Ok(TransactionComposerSendResult {
+            group,
+            results,
+            fee_payment,
+        })
     }
 
     pub fn count(&self) -> usize {
         self.transactions.len()
     }
 
+    /// The number of transactions that can still be added, keeping room for the fee payment
+    /// appended to the group when a fee payer is configured.
+    pub fn remaining_capacity(&self) -> usize {
+        let reserved = usize::from(self.composer_config.fee_payer.is_some());
+        MAX_TX_GROUP_SIZE.saturating_sub(self.transactions.len() + reserved)
+    }
+
+    /// Remove the result of the fee payment of a sponsored group, so the remaining results
+    /// correspond to the composed transactions.
+    fn split_fee_payment(&self, results: &mut Vec<TransactionResult>) -> Option<TransactionResult> {
+        if results.len() > self.transactions.len() {
+            results.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Simulate the signed group with execution tracing, emit the simulate response as a
+    /// `TxnGroupSimulated` event and, when a project root is configured, persist it as an AVM
+    /// debugger trace.
+    ///
+    /// Unlike [`Self::simulate`] this keeps the response of a failing group, which is the one
+    /// worth debugging.
+    async fn simulate_for_debugging(&self, signed_transactions: &[SignedTransaction]) {
+        let simulate_request = SimulateRequest {
+            txn_groups: vec![SimulateRequestTransactionGroup {
+                txns: signed_transactions.to_vec(),
+            }],
+            round: None,
+            allow_empty_signatures: Some(true),
+            allow_more_logging: Some(true),
+            allow_unnamed_resources: Some(true),
+            extra_opcode_budget: None,
+            exec_trace_config: Some(algod_client::models::SimulateTraceConfig {
+                enable: Some(true),
+                stack_change: Some(true),
+                scratch_change: Some(true),
+                state_change: Some(true),
+            }),
+            fix_signers: Some(true),
+        };
+
+        let simulate_response = match self
+            .algod_client
+            .simulate_transaction(simulate_request, Some(Format::Msgpack))
+            .await
+        {
+            Ok(simulate_response) => simulate_response,
+            Err(e) => {
+                warn!("Failed to simulate transaction group for debugging: {}", e);
+                return;
+            }
+        };
+
+        if let Some(project_root) = Config::project_root() {
+            let transactions: Vec<Transaction> = signed_transactions
+                .iter()
+                .map(|signed_transaction| signed_transaction.transaction.clone())
+                .collect();
+            if let Err(e) = persist_trace(&project_root, &simulate_response, &transactions) {
+                warn!("Failed to persist simulate trace for debugging: {}", e);
+            }
+        }
+
+        let payload =
+            serde_json::to_value(&simulate_response).unwrap_or_else(|_| serde_json::json!({}));
+        Config::events()
+            .emit(
+                EventType::TxnGroupSimulated,
+                EventData::TxnGroupSimulated(TxnGroupSimulatedEventData {
+                    simulate_response: payload,
+                }),
+            )
+            .await;
+    }
+
+    /// Run the group through the legacy dryrun endpoint, for nodes or providers that don't
+    /// return simulate execution traces, see [`create_dryrun_request`].
+    ///
+    /// With `skip_signatures` the group is dryrun without gathering signatures, which dryrun only
+    /// checks for logic signatures.
+    pub async fn dryrun(&mut self, skip_signatures: bool) -> Result<DryrunResult, ComposerError> {
+        let signed_transactions = if skip_signatures {
+            self.build()
+                .await?
+                .iter()
+                .map(|txn_with_signer| SignedTransaction {
+                    transaction: txn_with_signer.transaction.clone(),
+                    signature: Some(EMPTY_SIGNATURE),
+                    auth_address: None,
+                    multisignature: None,
+                    logic_signature: None,
+                })
+                .collect()
+        } else {
+            self.gather_signatures().await?.to_vec()
+        };
+
+        let request = create_dryrun_request(&self.algod_client, signed_transactions).await?;
+        let response = self.algod_client.teal_dryrun(Some(request)).await?;
+        DryrunResult::try_from(response)
+    }
+
     pub async fn simulate(
         &mut self,
         simulate_params: Option<SimulateParams>,
@@ -2374,6 +3866,7 @@ impl TransactionComposer {
                     signature: Some(EMPTY_SIGNATURE),
                     auth_address: None,
                     multisignature: None,
+                    logic_signature: None,
                 })
                 .collect(),
             false => self.gather_signatures().await?.to_vec(),
@@ -2403,7 +3896,7 @@ impl TransactionComposer {
             allow_unnamed_resources: simulate_params.allow_unnamed_resources,
             extra_opcode_budget: simulate_params.extra_opcode_budget,
             exec_trace_config: simulate_params.exec_trace_config,
-            fix_signers: Some(true),
+            fix_signers: simulate_params.fix_signers.or(Some(true)),
         };
 
         // Call simulate endpoint
@@ -2453,23 +3946,7 @@ impl TransactionComposer {
             .map(|r| r.txn_result.clone())
             .collect();
 
-        let abi_returns = self.parse_abi_return_values(&confirmations);
-
-        // Build results with 1:1 correspondence
-        let results = transactions
-            .into_iter()
-            .zip(transaction_ids)
-            .zip(confirmations)
-            .zip(abi_returns)
-            .map(
-                |(((transaction, transaction_id), confirmation), abi_return)| TransactionResult {
-                    transaction,
-                    transaction_id,
-                    confirmation,
-                    abi_return,
-                },
-            )
-            .collect();
+        let mut results = self.build_results(transactions, transaction_ids, confirmations);
 
         if Config::debug() && Config::trace_all() {
             let payload =
@@ -2484,10 +3961,28 @@ impl TransactionComposer {
                 .await;
         }
 
+        let state_diffs = compute_state_diffs(&simulate_response);
+        let fee_payment = self.split_fee_payment(&mut results);
+        let simulated_group_result = &simulate_response.txn_groups[0];
+        let transactions = SimulatedTransaction::from_group_result(simulated_group_result);
+        let app_budget_added = simulated_group_result.app_budget_added;
+        let app_budget_consumed = simulated_group_result.app_budget_consumed;
+        let unnamed_resources_accessed = simulated_group_result.unnamed_resources_accessed.clone();
+        let simulation_round = simulate_response.last_round;
+        let eval_overrides = simulate_response.eval_overrides.clone();
+
         Ok(TransactionComposerSimulateResult {
             group,
             results,
+            fee_payment,
             simulate_response,
+            state_diffs,
+            transactions,
+            app_budget_added,
+            app_budget_consumed,
+            unnamed_resources_accessed,
+            simulation_round,
+            eval_overrides,
         })
     }
 }
@@ -2496,8 +3991,12 @@ impl TransactionComposer {
 mod tests {
     use super::*;
     use crate::EmptySigner;
-    use algokit_transact::test_utils::{AccountMother, TransactionMother};
+    use algokit_transact::test_utils::{
+        AccountMother, AppCallTransactionMother, TransactionMother,
+    };
     use base64::{Engine, prelude::BASE64_STANDARD};
+    use rstest::rstest;
+    use std::str::FromStr;
 
     fn test_composer_params() -> TransactionComposerParams {
         TransactionComposerParams {
@@ -2506,10 +4005,40 @@ mod tests {
             composer_config: Some(TransactionComposerConfig {
                 populate_app_call_resources: ResourcePopulation::Disabled,
                 cover_app_call_inner_transaction_fees: false,
+                fee_payer: None,
+                fee_policy: None,
+                analysis_simulate_params: None,
             }),
+            suggested_params_cache: None,
         }
     }
 
+    #[tokio::test]
+    async fn test_until_cancelled() {
+        let completed = until_cancelled(async { Ok(1) }, None, None).await;
+        assert_eq!(completed.unwrap(), 1);
+
+        let timed_out = until_cancelled(
+            std::future::pending::<Result<(), ComposerError>>(),
+            None,
+            Some(Duration::from_millis(10)),
+        )
+        .await;
+        assert!(matches!(timed_out, Err(ComposerError::Cancelled { .. })));
+
+        let cancellation_token = CancellationToken::new();
+        let waiting = until_cancelled(
+            std::future::pending::<Result<(), ComposerError>>(),
+            Some(&cancellation_token),
+            None,
+        );
+        cancellation_token.cancel();
+        assert!(matches!(
+            waiting.await,
+            Err(ComposerError::Cancelled { message }) if message.contains("cancelled")
+        ));
+    }
+
     #[test]
     fn test_add_transaction() {
         let mut composer = TransactionComposer::new(test_composer_params());
@@ -2557,7 +4086,7 @@ mod tests {
             first_valid_round: None,
             last_valid_round: None,
             receiver: AccountMother::account().address(),
-            amount: 1000,
+            amount: AlgoAmount::micro_algos(1000),
         };
         assert!(composer.add_payment(payment_params).is_ok());
     }
@@ -2579,7 +4108,7 @@ mod tests {
             first_valid_round: None,
             last_valid_round: None,
             receiver: AccountMother::account().address(),
-            amount: 1000,
+            amount: AlgoAmount::micro_algos(1000),
         };
         composer.add_payment(payment_params).unwrap();
 
@@ -2603,7 +4132,7 @@ mod tests {
             first_valid_round: None,
             last_valid_round: None,
             receiver: AccountMother::account().address(),
-            amount: 1000,
+            amount: AlgoAmount::micro_algos(1000),
         };
         composer.add_payment(payment_params).unwrap();
 
@@ -2616,6 +4145,307 @@ mod tests {
         assert!(built_group[0].transaction.header().group.is_none());
     }
 
+    fn offline_suggested_params() -> TransactionParams {
+        TransactionParams {
+            consensus_version: "future".to_string(),
+            fee: 0,
+            genesis_hash: vec![1; 32],
+            genesis_id: "testnet-v1.0".to_string(),
+            last_round: 1000,
+            min_fee: 1000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_offline() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let payment_params = PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(1000),
+            ..Default::default()
+        };
+        composer.add_payment(payment_params.clone()).unwrap();
+        composer.add_payment(payment_params).unwrap();
+
+        // The composer's algod client is unreachable, so building only succeeds offline
+        let built_group = composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap();
+
+        assert_eq!(built_group.len(), 2);
+        for transaction in built_group {
+            let header = transaction.transaction.header();
+            assert_eq!(header.first_valid, 1000);
+            assert_eq!(header.last_valid, 1010);
+            assert_eq!(header.fee, Some(1000));
+            assert_eq!(header.genesis_id.as_deref(), Some("testnet-v1.0"));
+            assert!(header.group.is_some());
+        }
+    }
+
+    #[rstest]
+    #[case(FeePolicy { max_transaction_fee: Some(1000), max_group_fee: Some(2000), max_fee_per_byte: Some(0) }, 0, None)]
+    #[case(FeePolicy { max_transaction_fee: Some(999), ..Default::default() }, 0, Some("The fee of 1000 µALGO of transaction 0 exceeds the maximum of 999 µALGO"))]
+    #[case(FeePolicy { max_group_fee: Some(1999), ..Default::default() }, 0, Some("The total fee of 2000 µALGO of the group exceeds the maximum of 1999 µALGO"))]
+    #[case(FeePolicy { max_fee_per_byte: Some(5), ..Default::default() }, 10, Some("The suggested fee per byte of 10 µALGO exceeds the maximum of 5 µALGO"))]
+    #[tokio::test]
+    async fn test_build_enforces_fee_policy(
+        #[case] fee_policy: FeePolicy,
+        #[case] fee_per_byte: u64,
+        #[case] expected_error: Option<&str>,
+    ) {
+        let mut params = test_composer_params();
+        params.composer_config = Some(TransactionComposerConfig {
+            fee_policy: Some(fee_policy),
+            ..Default::default()
+        });
+        let mut composer = TransactionComposer::new(params);
+        let payment_params = PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(1000),
+            ..Default::default()
+        };
+        composer.add_payment(payment_params.clone()).unwrap();
+        composer.add_payment(payment_params).unwrap();
+        let suggested_params = TransactionParams {
+            fee: fee_per_byte,
+            ..offline_suggested_params()
+        };
+
+        let result = composer.build_offline(suggested_params).await;
+
+        match expected_error {
+            None => assert!(result.is_ok()),
+            Some(expected) => assert!(matches!(
+                result,
+                Err(ComposerError::FeePolicyError { ref message }) if message == expected
+            )),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_result_joins_params_and_summarizes_group() {
+        let payment = |amount| PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(amount),
+            ..Default::default()
+        };
+
+        let mut composer = TransactionComposer::new(test_composer_params());
+        composer.add_payment(payment(1)).unwrap();
+        composer.add_payment(payment(2)).unwrap();
+        let transactions: Vec<Transaction> = composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap()
+            .iter()
+            .map(|txn| txn.transaction.clone())
+            .collect();
+        let transaction_ids = transactions.iter().map(|txn| txn.id().unwrap()).collect();
+        let confirmations = transactions
+            .iter()
+            .zip([10, 11])
+            .map(|(transaction, round)| PendingTransactionResponse {
+                confirmed_round: Some(round),
+                ..PendingTransactionResponse::new(
+                    String::new(),
+                    SignedTransaction {
+                        transaction: transaction.clone(),
+                        signature: None,
+                        auth_address: None,
+                        multisignature: None,
+                        logic_signature: None,
+                    },
+                )
+            })
+            .collect();
+
+        let group = transactions[0].header().group;
+        let result = TransactionComposerSendResult {
+            group,
+            results: composer.build_results(transactions, transaction_ids, confirmations),
+            fee_payment: None,
+        };
+
+        assert_eq!(
+            result.group_id_base64(),
+            Some(BASE64_STANDARD.encode(group.unwrap()))
+        );
+        assert_eq!(result.confirmed_round(), Some(11));
+        assert_eq!(result.transaction_ids().len(), 2);
+        assert!(matches!(
+            &result.results[1].params,
+            Some(ComposerTransaction::Payment(params)) if params.amount == AlgoAmount::micro_algos(2)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_derives_leases() {
+        let payment = |lease| PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(1),
+            lease,
+            ..Default::default()
+        };
+
+        let mut composer = TransactionComposer::new(test_composer_params());
+        composer.add_payment(payment(None)).unwrap();
+        composer.add_payment(payment(Some([1; 32]))).unwrap();
+        composer.add_payment(payment(None)).unwrap();
+        composer
+            .set_idempotency_key("order-42".to_string())
+            .unwrap();
+        let group = composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap();
+
+        let leases: Vec<_> = group
+            .iter()
+            .map(|txn| txn.transaction.header().lease)
+            .collect();
+        assert_eq!(
+            leases,
+            vec![
+                Some(idempotency_lease("order-42", 0)),
+                Some([1; 32]),
+                Some(idempotency_lease("order-42", 2)),
+            ]
+        );
+        assert_ne!(
+            idempotency_lease("order-42", 0),
+            idempotency_lease("order-43", 0)
+        );
+
+        // Sending again with the same key is allowed, e.g. when retrying
+        assert!(composer.set_idempotency_key("order-42".to_string()).is_ok());
+        assert!(matches!(
+            composer.set_idempotency_key("order-43".to_string()),
+            Err(ComposerError::StateError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_add_signed_transaction_to_group() {
+        let payment = |amount| PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(amount),
+            ..Default::default()
+        };
+
+        // The partner builds the same group and signs their transaction
+        let mut partner_composer = TransactionComposer::new(test_composer_params());
+        partner_composer.add_payment(payment(1)).unwrap();
+        partner_composer.add_payment(payment(2)).unwrap();
+        let partner_group = partner_composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap();
+        let signed_transaction = SignedTransaction {
+            transaction: partner_group[0].transaction.clone(),
+            signature: Some([7; 64]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+        };
+
+        let mut composer = TransactionComposer::new(test_composer_params());
+        composer
+            .add_signed_transaction(signed_transaction.clone())
+            .unwrap();
+        composer.add_payment(payment(2)).unwrap();
+        composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap();
+        let signed_group = composer.gather_signatures().await.unwrap();
+
+        assert_eq!(signed_group[0], signed_transaction);
+        assert_eq!(signed_group[1].signature, Some(EMPTY_SIGNATURE));
+        assert_eq!(
+            signed_group[1].transaction.header().group,
+            signed_transaction.transaction.header().group
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_signed_transaction_for_other_group_fails() {
+        let payment = PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(1),
+            ..Default::default()
+        };
+        let signed_transaction = SignedTransaction {
+            transaction: TransactionMother::simple_payment().build().unwrap(),
+            signature: Some([7; 64]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+        };
+
+        let mut composer = TransactionComposer::new(test_composer_params());
+        composer.add_signed_transaction(signed_transaction).unwrap();
+        composer.add_payment(payment).unwrap();
+        let result = composer.build_offline(offline_suggested_params()).await;
+
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Pre-signed transaction 0 wasn't signed for this group")
+        );
+    }
+
+    struct RejectingSigner;
+
+    #[async_trait]
+    impl TransactionSigner for RejectingSigner {
+        async fn sign_transactions(
+            &self,
+            _transactions: &[Transaction],
+            _indices: &[usize],
+        ) -> Result<Vec<SignedTransaction>, String> {
+            Err("User rejected the request".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gather_signatures_reports_failing_signer() {
+        let payment = |signer: Arc<dyn TransactionSigner>| PaymentParams {
+            sender: AccountMother::account().address(),
+            signer: Some(signer),
+            receiver: AccountMother::neil().address(),
+            amount: AlgoAmount::micro_algos(1),
+            ..Default::default()
+        };
+
+        let mut composer = TransactionComposer::new(test_composer_params());
+        composer
+            .add_payment(payment(Arc::new(EmptySigner {})))
+            .unwrap();
+        composer
+            .add_payment(payment(Arc::new(RejectingSigner)))
+            .unwrap();
+        composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap();
+
+        let error = composer.gather_signatures().await.unwrap_err();
+        assert!(matches!(
+            error,
+            ComposerError::SigningError { ref message }
+                if message == "Failed to sign the transactions at indexes [1]: User rejected the request"
+        ));
+    }
+
     #[tokio::test]
     async fn test_multiple_transactions_have_group() {
         let mut composer = TransactionComposer::new(test_composer_params());
@@ -2634,7 +4464,7 @@ mod tests {
                 first_valid_round: None,
                 last_valid_round: None,
                 receiver: AccountMother::account().address(),
-                amount: 1000,
+                amount: AlgoAmount::micro_algos(1000),
             };
             composer.add_payment(payment_params).unwrap();
         }
@@ -2666,17 +4496,13 @@ mod tests {
 
     #[test]
     fn test_error_recoverability_logic() {
-        // Test string-based 404 detection (the primary retry mechanism)
-        let error_404_string = "Request failed with status 404: Transaction not found";
-        let error_500_string = "Request failed with status 500: Server error";
+        // Only a 404 of a pending transaction (not found yet) is waited out
+        let error_404 = AlgodErrorKind::from_message(Some(404), "Transaction not found");
+        let error_500 = AlgodErrorKind::from_message(Some(500), "Server error");
 
-        // The main retry logic relies on string matching
+        assert!(error_404.is_not_found(), "404 errors should be retryable");
         assert!(
-            error_404_string.contains("404"),
-            "404 errors should be retryable"
-        );
-        assert!(
-            !error_500_string.contains("404"),
+            !error_500.is_not_found(),
             "500 errors should not be retryable"
         );
     }
@@ -2740,6 +4566,182 @@ mod tests {
         assert_eq!(priorities[4], FeePriority::Covered);
     }
 
+    #[test]
+    fn test_resource_population_for_consensus() {
+        let access_list = ResourcePopulation::Enabled {
+            use_access_list: true,
+        };
+
+        assert!(
+            access_list
+                .for_consensus(&ConsensusVersion::new("future"))
+                .use_access_list()
+        );
+        let released = access_list.for_consensus(&ConsensusVersion::new(
+            "https://github.com/algorandfoundation/specs/tree/953304de35264fc3ef91bcd05c123242015eeaed",
+        ));
+        assert!(released.is_enabled());
+        assert!(!released.use_access_list());
+        assert!(
+            !ResourcePopulation::Disabled
+                .for_consensus(&ConsensusVersion::Future)
+                .is_enabled()
+        );
+    }
+
+    #[test]
+    fn test_populate_group_access_references() {
+        let mut params = test_composer_params();
+        params.composer_config = Some(TransactionComposerConfig {
+            populate_app_call_resources: ResourcePopulation::Enabled {
+                use_access_list: true,
+            },
+            ..Default::default()
+        });
+        let composer = TransactionComposer::new(params);
+
+        let account = AccountMother::neil().address();
+        let mut transactions = vec![
+            AppCallTransactionMother::app_call_example()
+                .app_references(vec![54321])
+                .build()
+                .unwrap(),
+            AppCallTransactionMother::app_call_example()
+                .build()
+                .unwrap(),
+        ];
+        let group_resources = SimulateUnnamedResourcesAccessed {
+            accounts: Some(vec![account.to_string()]),
+            assets: Some(vec![1001]),
+            boxes: Some(vec![
+                BoxReference {
+                    app: 12345,
+                    name: b"b1".to_vec(),
+                },
+                BoxReference {
+                    app: 777,
+                    name: b"b2".to_vec(),
+                },
+            ]),
+            extra_box_refs: Some(1),
+            asset_holdings: Some(vec![AssetHoldingReference {
+                account: account.to_string(),
+                asset: 1001,
+            }]),
+            ..Default::default()
+        };
+
+        composer
+            .populate_group_access_references(&mut transactions, group_resources)
+            .unwrap();
+
+        let Transaction::AppCall(legacy_app_call) = &transactions[0] else {
+            panic!("Expected AppCall transaction type");
+        };
+        assert_eq!(legacy_app_call.access_references, None);
+        let Transaction::AppCall(app_call) = &transactions[1] else {
+            panic!("Expected AppCall transaction type");
+        };
+        assert_eq!(
+            app_call.access_references,
+            Some(vec![
+                AccessReference::Address(account.clone()),
+                AccessReference::Asset(1001),
+                AccessReference::Holding(HoldingReference {
+                    address: account,
+                    asset_id: 1001,
+                }),
+                AccessReference::Box(algokit_transact::BoxReference {
+                    app_id: 12345,
+                    name: b"b1".to_vec(),
+                }),
+                AccessReference::App(777),
+                AccessReference::Box(algokit_transact::BoxReference {
+                    app_id: 777,
+                    name: b"b2".to_vec(),
+                }),
+                AccessReference::Box(algokit_transact::BoxReference {
+                    app_id: 0,
+                    name: vec![],
+                }),
+            ])
+        );
+        assert!(transactions[1].encode().is_ok());
+    }
+
+    #[test]
+    fn test_populate_group_access_references_limit() {
+        let mut params = test_composer_params();
+        params.composer_config = Some(TransactionComposerConfig {
+            populate_app_call_resources: ResourcePopulation::Enabled {
+                use_access_list: true,
+            },
+            ..Default::default()
+        });
+        let composer = TransactionComposer::new(params);
+
+        let mut transactions = vec![
+            AppCallTransactionMother::app_call_example()
+                .build()
+                .unwrap(),
+        ];
+        let group_resources = SimulateUnnamedResourcesAccessed {
+            assets: Some((1..=(MAX_ACCESS_REFERENCES as u64 + 1)).collect()),
+            ..Default::default()
+        };
+
+        let error = composer
+            .populate_group_access_references(&mut transactions, group_resources)
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("No more transactions below reference limit")
+        );
+    }
+
+    #[test]
+    fn test_reset_validity_rounds() {
+        let mut payment = ComposerTransaction::Payment(PaymentParams {
+            sender: AccountMother::account().address(),
+            signer: None,
+            rekey_to: None,
+            note: Some(b"note".to_vec()),
+            lease: Some([1u8; 32]),
+            static_fee: None,
+            extra_fee: None,
+            max_fee: None,
+            validity_window: None,
+            first_valid_round: Some(100),
+            last_valid_round: Some(150),
+            receiver: AccountMother::account().address(),
+            amount: AlgoAmount::micro_algos(1000),
+        });
+        payment.reset_validity_rounds(1000);
+
+        let ComposerTransaction::Payment(params) = &payment else {
+            panic!("Expected a payment");
+        };
+        assert_eq!(params.first_valid_round, None);
+        assert_eq!(params.last_valid_round, None);
+        assert_eq!(params.validity_window, Some(50));
+        assert_eq!(params.lease, Some([1u8; 32]));
+        assert_eq!(params.note, Some(b"note".to_vec()));
+
+        let mut txn = TransactionMother::simple_payment().build().unwrap();
+        let window = txn.header().last_valid - txn.header().first_valid;
+        txn.header_mut().group = Some([1u8; 32]);
+        let mut raw = ComposerTransaction::Transaction(txn);
+        raw.reset_validity_rounds(1000);
+
+        let ComposerTransaction::Transaction(txn) = &raw else {
+            panic!("Expected a transaction");
+        };
+        assert_eq!(txn.header().first_valid, 1000);
+        assert_eq!(txn.header().last_valid, 1000 + window);
+        assert_eq!(txn.header().group, None);
+    }
+
     #[test]
     fn test_transaction_composer_config_default() {
         let params = TransactionComposerConfig::default();
@@ -2783,4 +4785,236 @@ mod tests {
             assert!(message.contains("Cannot add new transactions after building"));
         }
     }
+
+    fn nested_method_call_params(args: Vec<AppMethodCallArg>) -> AppCallMethodCallParams {
+        let sender = AccountMother::account().address();
+        AppCallMethodCallParams {
+            sender: sender.clone(),
+            app_id: 1234,
+            method: ABIMethod::from_str("nested_method_call(string,pay,appl)byte[]").unwrap(),
+            args: std::iter::once(AppMethodCallArg::ABIValue(algokit_abi::ABIValue::String(
+                "test".to_string(),
+            )))
+            .chain(args)
+            .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn get_pay_txn_amount_call() -> AppMethodCallArg {
+        let sender = AccountMother::account().address();
+        AppMethodCallArg::AppCallMethodCall(AppCallMethodCallParams {
+            sender: sender.clone(),
+            app_id: 1234,
+            method: ABIMethod::from_str("get_pay_txn_amount(pay)uint64").unwrap(),
+            args: vec![AppMethodCallArg::Payment(PaymentParams {
+                sender: sender.clone(),
+                receiver: sender,
+                amount: AlgoAmount::micro_algos(1000),
+                ..Default::default()
+            })],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_add_nested_method_call_with_transaction_placeholder() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let params = nested_method_call_params(vec![
+            AppMethodCallArg::TransactionPlaceholder,
+            get_pay_txn_amount_call(),
+        ]);
+
+        composer.add_app_call_method_call(params).unwrap();
+
+        // Transaction order: [payment, get_pay_txn_amount, nested_method_call]
+        assert_eq!(composer.count(), 3);
+    }
+
+    #[test]
+    fn test_add_nested_method_call_with_mismatched_transaction_type_fails() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let payment = AppMethodCallArg::Payment(PaymentParams {
+            sender: AccountMother::account().address(),
+            receiver: AccountMother::account().address(),
+            amount: AlgoAmount::micro_algos(1000),
+            ..Default::default()
+        });
+        let params = nested_method_call_params(vec![payment.clone(), payment]);
+
+        let result = composer.add_app_call_method_call(params);
+
+        assert!(matches!(
+            result,
+            Err(ComposerError::ABIEncodingError { ref message })
+                if message.contains("Argument 2 of method nested_method_call must be a appl transaction")
+        ));
+        assert_eq!(composer.count(), 0);
+    }
+
+    #[test]
+    fn test_add_method_call_with_unsatisfied_transaction_placeholder_fails() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let params = nested_method_call_params(vec![
+            AppMethodCallArg::TransactionPlaceholder,
+            AppMethodCallArg::TransactionPlaceholder,
+        ]);
+
+        let result = composer.add_app_call_method_call(params);
+
+        assert!(matches!(
+            result,
+            Err(ComposerError::ABIEncodingError { ref message })
+                if message.contains("no transaction at the expected position")
+        ));
+    }
+
+    #[test]
+    fn test_add_method_call_with_wrong_argument_count_fails() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let params = nested_method_call_params(vec![
+            AppMethodCallArg::TransactionPlaceholder,
+            get_pay_txn_amount_call(),
+            AppMethodCallArg::ABIValue(algokit_abi::ABIValue::String("extra".to_string())),
+        ]);
+
+        let result = composer.add_app_call_method_call(params);
+
+        assert!(matches!(
+            result,
+            Err(ComposerError::ABIEncodingError { ref message })
+                if message.contains("expects 3 arguments but 4 were provided")
+        ));
+    }
+
+    #[test]
+    fn test_add_nested_method_call_wires_omitted_transaction_args() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        let params = nested_method_call_params(vec![get_pay_txn_amount_call()]);
+
+        composer.add_app_call_method_call(params).unwrap();
+
+        // Transaction order: [payment, get_pay_txn_amount, nested_method_call]
+        assert_eq!(composer.count(), 3);
+        let ComposerTransaction::AppCallMethodCall(outer) = &composer.transactions[2] else {
+            panic!("Expected the outer method call last");
+        };
+        assert!(matches!(
+            outer.args[1],
+            ProcessedAppMethodCallArg::TransactionPlaceholder
+        ));
+    }
+
+    #[test]
+    fn test_add_method_call_with_omitted_unsatisfiable_transaction_arg_fails() {
+        let mut composer = TransactionComposer::new(test_composer_params());
+        // The nested call takes no payment, so nothing precedes it to satisfy the outer payment
+        let nested = AppMethodCallArg::AppCallMethodCall(AppCallMethodCallParams {
+            sender: AccountMother::account().address(),
+            app_id: 1234,
+            method: ABIMethod::from_str("hello(string)string").unwrap(),
+            args: vec![AppMethodCallArg::ABIValue(algokit_abi::ABIValue::String(
+                "world".to_string(),
+            ))],
+            ..Default::default()
+        });
+        let params = nested_method_call_params(vec![nested]);
+
+        let result = composer.add_app_call_method_call(params);
+
+        assert!(matches!(
+            result,
+            Err(ComposerError::ABIEncodingError { ref message })
+                if message.contains("Argument 1 of method nested_method_call must be a pay transaction")
+        ));
+        assert_eq!(composer.count(), 0);
+    }
+
+    /// Hands out the same signer for every address, so tests can tell it apart.
+    struct FixedSignerGetter(Arc<dyn TransactionSigner>);
+
+    impl TransactionSignerGetter for FixedSignerGetter {
+        fn get_signer(&self, _address: Address) -> Result<Arc<dyn TransactionSigner>, String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    async fn built_signers(
+        signer_getter: Arc<dyn TransactionSigner>,
+        params: AppCallMethodCallParams,
+    ) -> Vec<Arc<dyn TransactionSigner>> {
+        let mut composer = TransactionComposer::new(TransactionComposerParams {
+            signer_getter: Arc::new(FixedSignerGetter(signer_getter)),
+            ..test_composer_params()
+        });
+        composer.add_app_call_method_call(params).unwrap();
+        composer
+            .build_offline(offline_suggested_params())
+            .await
+            .unwrap()
+            .iter()
+            .map(|transaction| transaction.signer.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_arg_signers_take_precedence_over_method_and_composer_signers() {
+        let getter_signer: Arc<dyn TransactionSigner> = Arc::new(EmptySigner {});
+        let method_signer: Arc<dyn TransactionSigner> = Arc::new(EmptySigner {});
+        let arg_signer: Arc<dyn TransactionSigner> = Arc::new(EmptySigner {});
+        let other_sender = AccountMother::neil().address();
+        let payment = |signer: Option<Arc<dyn TransactionSigner>>| {
+            AppMethodCallArg::Payment(PaymentParams {
+                sender: other_sender.clone(),
+                signer,
+                receiver: other_sender.clone(),
+                amount: AlgoAmount::micro_algos(1000),
+                ..Default::default()
+            })
+        };
+        let nested_call =
+            |signer: Option<Arc<dyn TransactionSigner>>,
+             payment_signer: Option<Arc<dyn TransactionSigner>>| {
+                AppMethodCallArg::AppCallMethodCall(AppCallMethodCallParams {
+                    sender: AccountMother::account().address(),
+                    signer,
+                    app_id: 1234,
+                    method: ABIMethod::from_str("get_pay_txn_amount(pay)uint64").unwrap(),
+                    args: vec![payment(payment_signer)],
+                    ..Default::default()
+                })
+            };
+
+        // Transaction order: [payment, nested payment, nested call, outer call]
+        let signers = built_signers(
+            getter_signer.clone(),
+            AppCallMethodCallParams {
+                signer: Some(method_signer.clone()),
+                ..nested_method_call_params(vec![
+                    payment(Some(arg_signer.clone())),
+                    nested_call(None, None),
+                ])
+            },
+        )
+        .await;
+        assert!(Arc::ptr_eq(&signers[0], &arg_signer));
+        // The method signer signs the args without a signer, whoever sends them
+        assert!(Arc::ptr_eq(&signers[1], &method_signer));
+        // A nested method call is signed by its own signer, or the composer's
+        assert!(Arc::ptr_eq(&signers[2], &getter_signer));
+        assert!(Arc::ptr_eq(&signers[3], &method_signer));
+
+        let signers = built_signers(
+            getter_signer.clone(),
+            nested_method_call_params(vec![
+                payment(None),
+                nested_call(Some(method_signer.clone()), Some(arg_signer.clone())),
+            ]),
+        )
+        .await;
+        assert!(Arc::ptr_eq(&signers[0], &getter_signer));
+        assert!(Arc::ptr_eq(&signers[1], &arg_signer));
+        assert!(Arc::ptr_eq(&signers[2], &method_signer));
+        assert!(Arc::ptr_eq(&signers[3], &getter_signer));
+    }
 }
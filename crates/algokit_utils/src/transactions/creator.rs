@@ -36,10 +36,12 @@ impl TransactionCreator {
     {
         let mut composer = (self.new_composer)(None);
         composer_method(&mut composer)?;
+        let transaction_count = composer.count();
         let built_transactions = composer.build().await?;
 
+        // A sponsored group ends with the fee payment, so select the last composed transaction
         built_transactions
-            .last()
+            .get(transaction_count.saturating_sub(1))
             .map(|tx_with_signer| tx_with_signer.transaction.clone())
             .ok_or(ComposerError::StateError {
                 message: "No transactions were built by the composer".to_string(),
@@ -0,0 +1,259 @@
+use super::composer::ComposerError;
+use algod_client::AlgodClient;
+use algod_client::models::{DryrunRequest, DryrunState, DryrunTxnResult, TealDryrun};
+use algokit_transact::{AccessReference, Address, SignedTransaction, Transaction};
+
+/// The applications and accounts whose state a dryrun of the transactions needs.
+///
+/// These are the called and referenced applications, and the senders, referenced accounts and
+/// application accounts of the transactions. Applications being created aren't included.
+pub fn dryrun_references(transactions: &[SignedTransaction]) -> (Vec<u64>, Vec<Address>) {
+    let mut app_ids = Vec::new();
+    let mut addresses = Vec::new();
+    let add_app = |app_ids: &mut Vec<u64>, app_id: u64| {
+        if app_id != 0 && !app_ids.contains(&app_id) {
+            app_ids.push(app_id);
+        }
+    };
+    let add_address = |addresses: &mut Vec<Address>, address: &Address| {
+        if !addresses.contains(address) {
+            addresses.push(address.clone());
+        }
+    };
+
+    for signed_transaction in transactions {
+        let transaction = &signed_transaction.transaction;
+        add_address(&mut addresses, &transaction.header().sender);
+
+        let Transaction::AppCall(app_call) = transaction else {
+            continue;
+        };
+        add_app(&mut app_ids, app_call.app_id);
+        for app_id in app_call.app_references.iter().flatten() {
+            add_app(&mut app_ids, *app_id);
+        }
+        for box_reference in app_call.box_references.iter().flatten() {
+            add_app(&mut app_ids, box_reference.app_id);
+        }
+        for address in app_call.account_references.iter().flatten() {
+            add_address(&mut addresses, address);
+        }
+        for reference in app_call.access_references.iter().flatten() {
+            match reference {
+                AccessReference::Address(address) => add_address(&mut addresses, address),
+                AccessReference::App(app_id) => add_app(&mut app_ids, *app_id),
+                AccessReference::Holding(holding) => add_address(&mut addresses, &holding.address),
+                AccessReference::Locals(locals) => {
+                    add_address(&mut addresses, &locals.address);
+                    add_app(&mut app_ids, locals.app_id);
+                }
+                AccessReference::Box(box_reference) => add_app(&mut app_ids, box_reference.app_id),
+                AccessReference::Asset(_) => {}
+            }
+        }
+    }
+
+    for app_id in &app_ids {
+        add_address(&mut addresses, &Address::from_app_id(app_id));
+    }
+
+    (app_ids, addresses)
+}
+
+/// Create a legacy dryrun request for the transactions, with the current state of the
+/// applications and accounts they reference fetched from algod.
+///
+/// Dryrun predates simulate and is only useful against nodes or providers that don't return
+/// simulate execution traces. The node must have `EnableDeveloperAPI` set to serve dryruns.
+pub async fn create_dryrun_request(
+    algod_client: &AlgodClient,
+    transactions: Vec<SignedTransaction>,
+) -> Result<DryrunRequest, ComposerError> {
+    let (app_ids, mut addresses) = dryrun_references(&transactions);
+
+    let mut apps = Vec::with_capacity(app_ids.len());
+    for app_id in app_ids {
+        let app = algod_client.get_application_by_id(app_id).await?;
+        let creator = app.params.creator.parse::<Address>()?;
+        if !addresses.contains(&creator) {
+            addresses.push(creator);
+        }
+        apps.push(app);
+    }
+
+    let mut accounts = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        accounts.push(
+            algod_client
+                .account_information(&address.to_string(), None, None)
+                .await?,
+        );
+    }
+
+    Ok(DryrunRequest {
+        txns: transactions,
+        accounts,
+        apps,
+        ..Default::default()
+    })
+}
+
+/// The outcome of a single transaction in a dryrun.
+#[derive(Debug, Clone)]
+pub struct DryrunTransactionResult {
+    /// Whether the approval or clear state program of the app call approved it, `None` if the
+    /// transaction isn't an app call
+    pub app_call_passed: Option<bool>,
+    /// Whether the logic signature of the transaction approved it, `None` if it isn't signed by one
+    pub logic_sig_passed: Option<bool>,
+    /// The first error raised by a program of the transaction
+    pub error: Option<String>,
+    pub logs: Vec<Vec<u8>>,
+    pub budget_consumed: Option<u32>,
+    /// The raw result returned by algod, including the execution traces
+    pub raw: DryrunTxnResult,
+}
+
+/// The decoded response of a legacy dryrun.
+#[derive(Debug, Clone)]
+pub struct DryrunResult {
+    pub protocol_version: String,
+    pub transactions: Vec<DryrunTransactionResult>,
+}
+
+impl DryrunResult {
+    /// Whether every program run by the dryrun approved its transaction.
+    pub fn passed(&self) -> bool {
+        self.transactions.iter().all(|transaction| {
+            transaction.app_call_passed != Some(false)
+                && transaction.logic_sig_passed != Some(false)
+        })
+    }
+}
+
+impl TryFrom<TealDryrun> for DryrunResult {
+    type Error = ComposerError;
+
+    fn try_from(response: TealDryrun) -> Result<Self, Self::Error> {
+        if !response.error.is_empty() {
+            return Err(ComposerError::TransactionError {
+                message: format!("Dryrun failed: {}", response.error),
+            });
+        }
+
+        Ok(Self {
+            protocol_version: response.protocol_version,
+            transactions: response
+                .txns
+                .into_iter()
+                .map(|result| DryrunTransactionResult {
+                    app_call_passed: program_passed(result.app_call_messages.as_deref()),
+                    logic_sig_passed: program_passed(result.logic_sig_messages.as_deref()),
+                    error: trace_error(result.logic_sig_trace.as_deref())
+                        .or_else(|| trace_error(result.app_call_trace.as_deref())),
+                    logs: result.logs.clone().unwrap_or_default(),
+                    budget_consumed: result.budget_consumed,
+                    raw: result,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Whether a program passed according to its dryrun messages, which end with `PASS` or `REJECT`.
+fn program_passed(messages: Option<&[String]>) -> Option<bool> {
+    messages?
+        .iter()
+        .rev()
+        .find_map(|message| match message.as_str() {
+            "PASS" => Some(true),
+            "REJECT" => Some(false),
+            _ => None,
+        })
+}
+
+fn trace_error(trace: Option<&[DryrunState]>) -> Option<String> {
+    trace?
+        .iter()
+        .find_map(|state| state.error.clone().filter(|error| !error.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::{AccountMother, AppCallTransactionMother};
+
+    fn unsigned(transaction: Transaction) -> SignedTransaction {
+        SignedTransaction {
+            transaction,
+            signature: None,
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+        }
+    }
+
+    #[test]
+    fn test_dryrun_references() {
+        let transaction = AppCallTransactionMother::app_call()
+            .app_references(vec![1, 84366825])
+            .account_references(vec![AccountMother::neil().address()])
+            .build()
+            .unwrap();
+        let sender = transaction.header().sender.clone();
+
+        let (app_ids, addresses) = dryrun_references(&[unsigned(transaction)]);
+
+        assert_eq!(app_ids, vec![84366825, 1]);
+        assert_eq!(
+            addresses,
+            vec![
+                sender,
+                AccountMother::neil().address(),
+                Address::from_app_id(&84366825),
+                Address::from_app_id(&1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dryrun_result_from_response() {
+        let mut rejected = DryrunTxnResult::new(Vec::new());
+        rejected.app_call_messages =
+            Some(vec!["ApprovalProgram".to_string(), "REJECT".to_string()]);
+        rejected.app_call_trace = Some(vec![
+            DryrunState::new(1, 1, Vec::new()),
+            DryrunState {
+                error: Some("assert failed pc=3".to_string()),
+                ..DryrunState::new(2, 3, Vec::new())
+            },
+        ]);
+        let mut passed = DryrunTxnResult::new(Vec::new());
+        passed.logic_sig_messages = Some(vec!["PASS".to_string()]);
+
+        let result = DryrunResult::try_from(TealDryrun::new(
+            vec![rejected, passed],
+            String::new(),
+            "future".to_string(),
+        ))
+        .unwrap();
+
+        assert!(!result.passed());
+        assert_eq!(result.transactions[0].app_call_passed, Some(false));
+        assert_eq!(
+            result.transactions[0].error.as_deref(),
+            Some("assert failed pc=3")
+        );
+        assert_eq!(result.transactions[1].app_call_passed, None);
+        assert_eq!(result.transactions[1].logic_sig_passed, Some(true));
+
+        assert!(matches!(
+            DryrunResult::try_from(TealDryrun::new(
+                Vec::new(),
+                "no such app".to_string(),
+                String::new()
+            )),
+            Err(ComposerError::TransactionError { .. })
+        ));
+    }
+}
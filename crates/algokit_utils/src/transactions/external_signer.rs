@@ -0,0 +1,169 @@
+use crate::transactions::common::TransactionSigner;
+use algokit_transact::{AlgorandMsgpack, SignedTransaction, Transaction, TransactionId};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A signer living outside of the library, e.g. a Ledger, a browser wallet or an HSM, that signs
+/// encoded transactions.
+///
+/// Wrap it in an [`ExternalTransactionSigner`] to use it wherever a [`TransactionSigner`] is
+/// accepted, e.g. as the signer of the transactions of a composer.
+#[async_trait]
+pub trait ExternalSigner: Send + Sync {
+    /// Sign the transactions at `indices` of a transaction group.
+    ///
+    /// # Arguments
+    /// * `unsigned_transactions` - The whole group, as msgpack encoded unsigned transactions,
+    ///   so that the signer can show the user the context of what they sign
+    /// * `indices` - The indices of the transactions to sign
+    ///
+    /// # Returns
+    /// The msgpack encoded signed transactions, one per index and in the order of `indices`
+    async fn sign_transaction_group(
+        &self,
+        unsigned_transactions: Vec<Vec<u8>>,
+        indices: Vec<usize>,
+    ) -> Result<Vec<Vec<u8>>, String>;
+}
+
+/// A [`TransactionSigner`] that signs through an [`ExternalSigner`].
+///
+/// The signed transactions returned by the external signer are checked to be the transactions
+/// that were requested to be signed.
+#[derive(Clone)]
+pub struct ExternalTransactionSigner {
+    external_signer: Arc<dyn ExternalSigner>,
+}
+
+impl ExternalTransactionSigner {
+    pub fn new(external_signer: Arc<dyn ExternalSigner>) -> Self {
+        Self { external_signer }
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for ExternalTransactionSigner {
+    async fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+        indices: &[usize],
+    ) -> Result<Vec<SignedTransaction>, String> {
+        if let Some(index) = indices.iter().find(|&&index| index >= transactions.len()) {
+            return Err(format!("Index {} out of bounds for transactions", index));
+        }
+
+        let unsigned_transactions = transactions
+            .iter()
+            .map(|transaction| {
+                transaction
+                    .encode_raw()
+                    .map_err(|e| format!("Failed to encode transaction: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let signed_blobs = self
+            .external_signer
+            .sign_transaction_group(unsigned_transactions, indices.to_vec())
+            .await?;
+        if signed_blobs.len() != indices.len() {
+            return Err(format!(
+                "The external signer returned {} signed transactions instead of {}",
+                signed_blobs.len(),
+                indices.len()
+            ));
+        }
+
+        indices
+            .iter()
+            .zip(signed_blobs)
+            .map(|(&index, signed_blob)| {
+                let signed_transaction = SignedTransaction::decode(&signed_blob).map_err(|e| {
+                    format!(
+                        "Failed to decode the signed transaction at index {}: {}",
+                        index, e
+                    )
+                })?;
+                let expected_id = transactions[index].id().map_err(|e| e.to_string())?;
+                let signed_id = signed_transaction.id().map_err(|e| e.to_string())?;
+                if signed_id != expected_id {
+                    return Err(format!(
+                        "The external signer returned transaction {} for index {}, expected {}",
+                        signed_id, index, expected_id
+                    ));
+                }
+                Ok(signed_transaction)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::SigningAccount;
+    use algokit_transact::test_utils::TransactionMother;
+
+    /// Decodes the requested transactions and signs them with an account, like a wallet would.
+    struct WalletSigner {
+        account: SigningAccount,
+        tamper: bool,
+    }
+
+    #[async_trait]
+    impl ExternalSigner for WalletSigner {
+        async fn sign_transaction_group(
+            &self,
+            unsigned_transactions: Vec<Vec<u8>>,
+            indices: Vec<usize>,
+        ) -> Result<Vec<Vec<u8>>, String> {
+            let mut transactions = unsigned_transactions
+                .iter()
+                .map(|bytes| Transaction::decode(bytes).map_err(|e| e.to_string()))
+                .collect::<Result<Vec<_>, _>>()?;
+            if self.tamper {
+                transactions[0].header_mut().fee = Some(1_000_000);
+            }
+            self.account
+                .sign_transactions(&transactions, &indices)
+                .await?
+                .iter()
+                .map(|signed| signed.encode().map_err(|e| e.to_string()))
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signs_through_the_external_signer() {
+        let account = SigningAccount::generate();
+        let signer = ExternalTransactionSigner::new(Arc::new(WalletSigner {
+            account: account.clone(),
+            tamper: false,
+        }));
+        let transactions = vec![
+            TransactionMother::simple_payment().build().unwrap(),
+            TransactionMother::simple_payment()
+                .amount(1)
+                .build()
+                .unwrap(),
+        ];
+
+        let signed = signer.sign_transactions(&transactions, &[1]).await.unwrap();
+
+        assert_eq!(signed.len(), 1);
+        assert_eq!(signed[0].transaction, transactions[1]);
+        assert!(signed[0].signature.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_a_different_transaction() {
+        let signer = ExternalTransactionSigner::new(Arc::new(WalletSigner {
+            account: SigningAccount::generate(),
+            tamper: true,
+        }));
+        let transaction = TransactionMother::simple_payment().build().unwrap();
+
+        let error = signer.sign_transaction(&transaction).await.unwrap_err();
+
+        assert!(error.contains("expected"));
+    }
+}
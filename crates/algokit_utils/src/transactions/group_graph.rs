@@ -0,0 +1,270 @@
+use crate::transactions::common::TransactionWithSigner;
+use crate::transactions::composer::ComposerError;
+use algokit_abi::{ABIMethod, ABIMethodArgType};
+use algokit_transact::{Transaction, TransactionId};
+use serde::{Deserialize, Serialize};
+
+/// A transaction in a [`GroupGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupGraphNode {
+    /// The index of the transaction in the group
+    pub index: usize,
+    pub transaction_id: String,
+    /// The transaction type, e.g. `pay` or `appl`
+    pub transaction_type: String,
+    pub sender: String,
+    pub fee: u64,
+    /// The app being called, for app calls (0 for app creation)
+    pub app_id: Option<u64>,
+    /// The ABI method signature, for method calls
+    pub method: Option<String>,
+    /// Whether this is the payment added to cover the group fees of a fee payer
+    pub fee_payment: bool,
+}
+
+/// The relationship between two transactions in a [`GroupGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GroupGraphEdgeKind {
+    /// The source transaction is a transaction argument of the target method call.
+    TransactionArgument {
+        arg_index: usize,
+        arg_name: Option<String>,
+    },
+    /// The source transaction pays `amount` microAlgos of the minimum fee of the target
+    /// transaction.
+    FeePooling { amount: u64 },
+}
+
+/// An edge between two transactions in a [`GroupGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupGraphEdge {
+    /// The group index of the source transaction
+    pub from: usize,
+    /// The group index of the target transaction
+    pub to: usize,
+    #[serde(flatten)]
+    pub kind: GroupGraphEdgeKind,
+}
+
+/// A graph description of a built transaction group, for rendering by external tools.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupGraph {
+    pub nodes: Vec<GroupGraphNode>,
+    pub edges: Vec<GroupGraphEdge>,
+}
+
+impl GroupGraph {
+    /// Describe a built group.
+    ///
+    /// `methods` holds the ABI method of each transaction added to the composer, transactions
+    /// after those are the fee payment added for a fee payer. Fee pooling is attributed in group
+    /// order, with each transaction paying more than `min_fee` covering the earliest transactions
+    /// paying less.
+    pub(crate) fn from_group(
+        transactions: &[TransactionWithSigner],
+        methods: &[Option<&ABIMethod>],
+        min_fee: u64,
+    ) -> Result<Self, ComposerError> {
+        let nodes = transactions
+            .iter()
+            .enumerate()
+            .map(|(index, txn)| {
+                let transaction = &txn.transaction;
+                let method = methods.get(index).copied().flatten();
+                Ok(GroupGraphNode {
+                    index,
+                    transaction_id: transaction.id()?,
+                    transaction_type: transaction_type(transaction).to_string(),
+                    sender: transaction.header().sender.to_string(),
+                    fee: transaction.header().fee.unwrap_or_default(),
+                    app_id: match transaction {
+                        Transaction::AppCall(fields) => Some(fields.app_id),
+                        _ => None,
+                    },
+                    method: method.map(ABIMethod::signature).transpose().map_err(|e| {
+                        ComposerError::ABIEncodingError {
+                            message: e.to_string(),
+                        }
+                    })?,
+                    fee_payment: index >= methods.len(),
+                })
+            })
+            .collect::<Result<Vec<_>, ComposerError>>()?;
+
+        let mut edges = transaction_argument_edges(methods);
+        edges.extend(fee_pooling_edges(&nodes, min_fee));
+
+        Ok(Self { nodes, edges })
+    }
+
+    /// Serialize the graph to pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, ComposerError> {
+        serde_json::to_string_pretty(self).map_err(|e| ComposerError::DecodeError {
+            message: format!("Failed to serialize group graph: {}", e),
+        })
+    }
+}
+
+fn transaction_type(transaction: &Transaction) -> &'static str {
+    match transaction {
+        Transaction::Payment(_) => "pay",
+        Transaction::AssetTransfer(_) => "axfer",
+        Transaction::AssetConfig(_) => "acfg",
+        Transaction::AppCall(_) => "appl",
+        Transaction::AssetFreeze(_) => "afrz",
+        Transaction::KeyRegistration(_) => "keyreg",
+        Transaction::Heartbeat(_) => "hb",
+        Transaction::StateProof(_) => "stpf",
+    }
+}
+
+fn transaction_argument_edges(methods: &[Option<&ABIMethod>]) -> Vec<GroupGraphEdge> {
+    let mut edges = Vec::new();
+    for (index, method) in methods.iter().enumerate() {
+        let Some(method) = method else {
+            continue;
+        };
+        let transaction_args = method
+            .args
+            .iter()
+            .enumerate()
+            .filter(|(_, arg)| matches!(arg.arg_type, ABIMethodArgType::Transaction(_)))
+            .collect::<Vec<_>>();
+
+        // Transaction arguments immediately precede the method call in argument order
+        for (position, (arg_index, arg)) in transaction_args.iter().enumerate() {
+            if let Some(from) = index.checked_sub(transaction_args.len() - position) {
+                edges.push(GroupGraphEdge {
+                    from,
+                    to: index,
+                    kind: GroupGraphEdgeKind::TransactionArgument {
+                        arg_index: *arg_index,
+                        arg_name: arg.name.clone(),
+                    },
+                });
+            }
+        }
+    }
+    edges
+}
+
+fn fee_pooling_edges(nodes: &[GroupGraphNode], min_fee: u64) -> Vec<GroupGraphEdge> {
+    let mut surpluses = nodes
+        .iter()
+        .filter(|node| node.fee > min_fee)
+        .map(|node| (node.index, node.fee - min_fee))
+        .collect::<Vec<_>>();
+
+    let mut edges = Vec::new();
+    for node in nodes.iter().filter(|node| node.fee < min_fee) {
+        let mut deficit = min_fee - node.fee;
+        for (from, surplus) in surpluses.iter_mut().filter(|(_, surplus)| *surplus > 0) {
+            if deficit == 0 {
+                break;
+            }
+            let amount = deficit.min(*surplus);
+            *surplus -= amount;
+            deficit -= amount;
+            edges.push(GroupGraphEdge {
+                from: *from,
+                to: node.index,
+                kind: GroupGraphEdgeKind::FeePooling { amount },
+            });
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmptySigner;
+    use algokit_transact::test_utils::TransactionMother;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    fn payment_with_fee(fee: u64) -> TransactionWithSigner {
+        let mut transaction = TransactionMother::simple_payment().build().unwrap();
+        transaction.header_mut().fee = Some(fee);
+        TransactionWithSigner {
+            transaction,
+            signer: Arc::new(EmptySigner {}),
+        }
+    }
+
+    #[test]
+    fn test_group_graph_edges() {
+        let method = ABIMethod::from_str("get_pay_txns_amount_sum(pay,pay,appl)uint64").unwrap();
+        let transactions = vec![
+            payment_with_fee(3000),
+            payment_with_fee(0),
+            payment_with_fee(0),
+            payment_with_fee(1000),
+        ];
+        let methods = vec![None, None, None, Some(&method)];
+
+        let graph = GroupGraph::from_group(&transactions, &methods, 1000).unwrap();
+
+        assert_eq!(graph.nodes.len(), 4);
+        assert_eq!(
+            graph.nodes[3].method.as_deref(),
+            Some("get_pay_txns_amount_sum(pay,pay,appl)uint64")
+        );
+        assert!(graph.nodes.iter().all(|node| !node.fee_payment));
+        assert_eq!(
+            graph.edges,
+            vec![
+                GroupGraphEdge {
+                    from: 0,
+                    to: 3,
+                    kind: GroupGraphEdgeKind::TransactionArgument {
+                        arg_index: 0,
+                        arg_name: Some("arg0".to_string())
+                    },
+                },
+                GroupGraphEdge {
+                    from: 1,
+                    to: 3,
+                    kind: GroupGraphEdgeKind::TransactionArgument {
+                        arg_index: 1,
+                        arg_name: Some("arg1".to_string())
+                    },
+                },
+                GroupGraphEdge {
+                    from: 2,
+                    to: 3,
+                    kind: GroupGraphEdgeKind::TransactionArgument {
+                        arg_index: 2,
+                        arg_name: Some("arg2".to_string())
+                    },
+                },
+                GroupGraphEdge {
+                    from: 0,
+                    to: 1,
+                    kind: GroupGraphEdgeKind::FeePooling { amount: 1000 },
+                },
+                GroupGraphEdge {
+                    from: 0,
+                    to: 2,
+                    kind: GroupGraphEdgeKind::FeePooling { amount: 1000 },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_graph_json() {
+        let transactions = vec![payment_with_fee(0), payment_with_fee(2000)];
+
+        let graph = GroupGraph::from_group(&transactions, &[None], 1000).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["nodes"][1]["fee_payment"], true);
+        assert_eq!(json["nodes"][1]["transaction_type"], "pay");
+        assert_eq!(
+            json["edges"][0],
+            serde_json::json!({ "from": 1, "to": 0, "kind": "fee_pooling", "amount": 1000 })
+        );
+    }
+}
@@ -1,13 +1,20 @@
 pub mod app_call;
+pub mod arc1;
 pub mod asset_config;
 pub mod asset_freeze;
 pub mod asset_transfer;
+pub mod batch_sender;
 pub mod common;
 pub mod composer;
 pub mod creator;
+pub mod dryrun;
+pub mod external_signer;
+pub mod group_graph;
 pub mod key_registration;
 pub mod payment;
+pub mod retry_policy;
 pub mod sender;
+pub mod state_diff;
 
 // Re-export commonly used transaction types
 pub use app_call::{
@@ -15,24 +22,35 @@ pub use app_call::{
     AppDeleteMethodCallParams, AppDeleteParams, AppMethodCallArg, AppUpdateMethodCallParams,
     AppUpdateParams,
 };
+pub use arc1::{Arc1Error, MultisigMetadata, SignTxnsOpts, SignTxnsRequest, WalletTransaction};
 pub use asset_config::{AssetConfigParams, AssetCreateParams, AssetDestroyParams};
 pub use asset_freeze::{AssetFreezeParams, AssetUnfreezeParams};
 pub use asset_transfer::{
     AssetClawbackParams, AssetOptInParams, AssetOptOutParams, AssetTransferParams,
 };
+pub use batch_sender::{
+    BatchGroupResult, BatchSendParams, BatchSendResult, BatchSender, DEFAULT_BATCH_CONCURRENCY,
+};
 pub use common::{EmptySigner, TransactionSigner, TransactionWithSigner};
 pub use composer::{
-    ComposerError, ComposerTransaction, ResourcePopulation, SendParams, TransactionComposer,
-    TransactionComposerConfig, TransactionComposerParams, TransactionComposerSendResult,
-    TransactionResult,
+    ComposerError, ComposerTransaction, FeePayer, FeePolicy, ResourcePopulation, SendParams,
+    SimulateParams, SimulatedTransaction, TransactionComposer, TransactionComposerConfig,
+    TransactionComposerParams, TransactionComposerSendResult, TransactionComposerSimulateResult,
+    TransactionResult, idempotency_lease,
 };
 pub use creator::TransactionCreator;
+pub use dryrun::{DryrunResult, DryrunTransactionResult, create_dryrun_request, dryrun_references};
+pub use external_signer::{ExternalSigner, ExternalTransactionSigner};
+pub use group_graph::{GroupGraph, GroupGraphEdge, GroupGraphEdgeKind, GroupGraphNode};
 pub use key_registration::{
     NonParticipationKeyRegistrationParams, OfflineKeyRegistrationParams,
     OnlineKeyRegistrationParams,
 };
-pub use payment::{AccountCloseParams, PaymentParams};
+pub use payment::{AccountCloseParams, PaymentParams, box_min_balance};
+pub use retry_policy::{RetryClassifier, RetryPolicy};
 pub use sender::{
     SendAppCreateMethodCallResult, SendAppCreateResult, SendAppMethodCallResult,
-    SendAssetCreateResult, SendResult, TransactionSender, TransactionSenderError,
+    SendAssetCreateResult, SendManyResult, SendResult, TransactionSender, TransactionSenderError,
 };
+pub use state_diff::{AppCallStateDiff, AppStateChange, AppStateType, AppStateValue};
+pub use tokio_util::sync::CancellationToken;
@@ -1,3 +1,4 @@
+use crate::AlgoAmount;
 use crate::create_transaction_params;
 use algokit_transact::{Address, PaymentTransactionFields, Transaction, TransactionHeader};
 
@@ -7,10 +8,8 @@ create_transaction_params! {
     pub struct PaymentParams {
         /// The address of the account receiving the ALGO payment.
         pub receiver: Address,
-        /// The amount of microALGO to send.
-        ///
-        /// Specified in microALGO (1 ALGO = 1,000,000 microALGO).
-        pub amount: u64,
+        /// The amount of ALGO to send.
+        pub amount: AlgoAmount,
     }
 }
 
@@ -25,11 +24,35 @@ create_transaction_params! {
     }
 }
 
+/// The flat minimum balance requirement of a box, in microALGO.
+pub const BOX_FLAT_MIN_BALANCE: u64 = 2500;
+
+/// The minimum balance requirement per byte of a box name and value, in microALGO.
+pub const BOX_BYTE_MIN_BALANCE: u64 = 400;
+
+/// Returns the minimum balance an app account needs to hold for a box, in microALGO.
+pub fn box_min_balance(name_length: u64, size: u64) -> u64 {
+    BOX_FLAT_MIN_BALANCE + BOX_BYTE_MIN_BALANCE * (name_length + size)
+}
+
+impl PaymentParams {
+    /// A payment of `amount` microALGO from `sender` to the account of app `app_id`, to cover
+    /// an increase of its minimum balance requirement, e.g. from [`box_min_balance`].
+    pub fn app_mbr_top_up(sender: Address, app_id: u64, amount: u64) -> Self {
+        Self {
+            sender,
+            receiver: Address::from_app_id(&app_id),
+            amount: AlgoAmount::micro_algos(amount),
+            ..Default::default()
+        }
+    }
+}
+
 pub fn build_payment(params: &PaymentParams, header: TransactionHeader) -> Transaction {
     Transaction::Payment(PaymentTransactionFields {
         header,
         receiver: params.receiver.clone(),
-        amount: params.amount,
+        amount: params.amount.as_micro_algos(),
         close_remainder_to: None,
     })
 }
@@ -43,3 +66,19 @@ pub fn build_account_close(params: &AccountCloseParams, header: TransactionHeade
         close_remainder_to: Some(params.close_remainder_to.clone()),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algokit_transact::test_utils::AccountMother;
+
+    #[test]
+    fn test_app_mbr_top_up() {
+        let sender = AccountMother::account().address();
+        let params = PaymentParams::app_mbr_top_up(sender.clone(), 1234, box_min_balance(4, 1024));
+
+        assert_eq!(params.sender, sender);
+        assert_eq!(params.receiver, Address::from_app_id(&1234));
+        assert_eq!(params.amount, AlgoAmount::micro_algos(2500 + 400 * 1028));
+    }
+}
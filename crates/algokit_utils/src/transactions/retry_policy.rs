@@ -0,0 +1,141 @@
+use super::composer::ComposerError;
+use algokit_http_client::exponential_backoff;
+use derive_more::Debug;
+use std::{sync::Arc, time::Duration};
+
+/// Decides whether a failed send is retried.
+pub type RetryClassifier = Arc<dyn Fn(&ComposerError) -> bool + Send + Sync>;
+
+/// How [`TransactionSender`](super::TransactionSender) retries sends that fail with transient
+/// algod failures, e.g. dropped connections or 5xx responses from a load balanced node.
+///
+/// Before each retry the IDs of the signed group are looked up in algod: a group that reached
+/// the node despite the error is awaited rather than submitted again. Retries resubmit the same
+/// signed transactions, so a group can never be confirmed twice.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// The delay before the first retry
+    pub initial_backoff: Duration,
+    /// The factor the delay is multiplied by after each retry
+    pub backoff_multiplier: f64,
+    /// The upper bound of the delay between retries
+    pub max_backoff: Duration,
+    /// Which errors are retried, defaults to [`RetryPolicy::is_transient_error`]
+    #[debug(skip)]
+    pub retry_on: Option<RetryClassifier>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            backoff_multiplier: 2.0,
+            max_backoff: Duration::from_secs(10),
+            retry_on: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether the given error is retried under this policy.
+    pub fn should_retry(&self, error: &ComposerError) -> bool {
+        match &self.retry_on {
+            Some(retry_on) => retry_on(error),
+            None => Self::is_transient_error(error),
+        }
+    }
+
+    /// The delay before the given 1-based retry, see [`exponential_backoff`].
+    pub fn backoff(&self, retry: u32) -> Duration {
+        exponential_backoff(
+            self.initial_backoff,
+            self.backoff_multiplier,
+            self.max_backoff,
+            retry,
+        )
+    }
+
+    /// Whether the error is a network failure or a 5xx response from algod while submitting or
+    /// awaiting a group. Rejected transactions and expired groups aren't transient.
+    pub fn is_transient_error(error: &ComposerError) -> bool {
+        error
+            .algod_error()
+            .is_some_and(|algod_error| algod_error.is_transient())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AlgodErrorKind;
+
+    fn submit_error(status: Option<u16>, message: &str) -> ComposerError {
+        ComposerError::SubmitError {
+            source: match status {
+                Some(status) => AlgodErrorKind::from_message(Some(status), message),
+                None => AlgodErrorKind::Unavailable {
+                    status: None,
+                    message: message.to_string(),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_is_transient_error() {
+        assert!(RetryPolicy::is_transient_error(&submit_error(
+            None,
+            "HttpError: error sending request"
+        )));
+        assert!(RetryPolicy::is_transient_error(&submit_error(
+            Some(503),
+            "unavailable"
+        )));
+        assert!(!RetryPolicy::is_transient_error(&submit_error(
+            Some(400),
+            "overspend"
+        )));
+        assert!(!RetryPolicy::is_transient_error(
+            &ComposerError::MaxWaitRoundExpired {
+                message: "Transaction unconfirmed after 10 rounds".to_string(),
+            }
+        ));
+        assert!(!RetryPolicy::is_transient_error(
+            &ComposerError::StateError {
+                message: "No transactions available".to_string(),
+            }
+        ));
+    }
+
+    #[test]
+    fn test_custom_classifier() {
+        let policy = RetryPolicy {
+            retry_on: Some(Arc::new(|error| {
+                matches!(error, ComposerError::MaxWaitRoundExpired { .. })
+            })),
+            ..Default::default()
+        };
+        assert!(policy.should_retry(&ComposerError::MaxWaitRoundExpired {
+            message: String::new(),
+        }));
+        assert!(!policy.should_retry(&submit_error(None, "error sending request")));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            backoff_multiplier: 3.0,
+            max_backoff: Duration::from_secs(1),
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(300));
+        assert_eq!(policy.backoff(3), Duration::from_millis(900));
+        assert_eq!(policy.backoff(4), Duration::from_secs(1));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+}
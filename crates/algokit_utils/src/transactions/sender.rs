@@ -8,18 +8,28 @@ use super::{
     asset_transfer::{
         AssetClawbackParams, AssetOptInParams, AssetOptOutParams, AssetTransferParams,
     },
-    composer::{ComposerError, SendParams, TransactionComposer, TransactionResult},
+    batch_sender::BatchSender,
+    composer::{
+        ComposerError, FeePayer, SendParams, TransactionComposer, TransactionComposerSendResult,
+        TransactionResult,
+    },
     key_registration::{
         NonParticipationKeyRegistrationParams, OfflineKeyRegistrationParams,
         OnlineKeyRegistrationParams,
     },
     payment::{AccountCloseParams, PaymentParams},
+    retry_policy::RetryPolicy,
 };
+use crate::clients::AlgodErrorKind;
 use crate::clients::asset_manager::{AssetManager, AssetManagerError};
+use crate::config::{Config, EventData, EventType, RetryEventData, RetryOperation};
 use crate::{clients::app_manager::AppManagerError, transactions::TransactionComposerConfig};
-use algod_client::apis::AlgodApiError;
+use algod_client::apis::{AlgodApiError, Error as AlgodError};
 use algod_client::models::PendingTransactionResponse;
+use algokit_abi::ABIReturn;
 use algokit_transact::{Address, Byte32, Transaction};
+use futures::stream::{self, StreamExt};
+use log::{info, warn};
 use snafu::Snafu;
 
 use std::{str::FromStr, sync::Arc};
@@ -27,7 +37,7 @@ use std::{str::FromStr, sync::Arc};
 #[derive(Debug, Snafu)]
 pub enum TransactionSenderError {
     #[snafu(display("Algod client error: {source}"))]
-    AlgodClientError { source: AlgodApiError },
+    AlgodClientError { source: AlgodError },
     #[snafu(display("Composer error: {source}"))]
     ComposerError { source: ComposerError },
     #[snafu(display("Asset manager error: {source}"))]
@@ -40,9 +50,28 @@ pub enum TransactionSenderError {
     ValidationError { message: String },
 }
 
+impl TransactionSenderError {
+    /// The parsed error of algod this error was caused by, if any, e.g. to handle an overspend.
+    pub fn algod_error(&self) -> Option<AlgodErrorKind> {
+        match self {
+            Self::AlgodClientError { source } => Some(AlgodErrorKind::from(source)),
+            Self::ComposerError { source } => source.algod_error(),
+            _ => None,
+        }
+    }
+}
+
+impl From<AlgodError> for TransactionSenderError {
+    fn from(e: AlgodError) -> Self {
+        Self::AlgodClientError { source: e }
+    }
+}
+
 impl From<AlgodApiError> for TransactionSenderError {
     fn from(e: AlgodApiError) -> Self {
-        Self::AlgodClientError { source: e }
+        Self::AlgodClientError {
+            source: AlgodError::Api { source: e },
+        }
     }
 }
 
@@ -129,11 +158,69 @@ pub struct SendAppCreateMethodCallResult {
     pub app_address: Address,
 }
 
+/// The ABI returns of the method calls that precede the primary transaction in the group,
+/// in group order, e.g. those of method calls passed as arguments to the primary method call.
+fn nested_abi_returns(group_results: &[TransactionResult]) -> Vec<&ABIReturn> {
+    group_results
+        .split_last()
+        .map(|(_, nested)| nested)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|result| result.abi_return.as_ref())
+        .collect()
+}
+
+impl SendAppMethodCallResult {
+    /// The ABI returns of the method calls sent ahead of the primary method call, in group order.
+    pub fn nested_abi_returns(&self) -> Vec<&ABIReturn> {
+        nested_abi_returns(&self.group_results)
+    }
+}
+
+impl SendAppCreateMethodCallResult {
+    /// The ABI returns of the method calls sent ahead of the primary method call, in group order.
+    pub fn nested_abi_returns(&self) -> Vec<&ABIReturn> {
+        nested_abi_returns(&self.group_results)
+    }
+}
+
+/// The outcome of sending independent groups with [`TransactionSender::send_many`].
+#[derive(Debug)]
+pub struct SendManyResult {
+    /// The outcome of each group, in the order the composers were given
+    pub groups: Vec<Result<TransactionComposerSendResult, ComposerError>>,
+}
+
+impl SendManyResult {
+    /// Whether every group was sent and confirmed.
+    pub fn is_success(&self) -> bool {
+        self.groups.iter().all(Result::is_ok)
+    }
+
+    /// The results of the groups that were confirmed, in the order the composers were given.
+    pub fn confirmed(&self) -> Vec<&TransactionComposerSendResult> {
+        self.groups
+            .iter()
+            .filter_map(|group| group.as_ref().ok())
+            .collect()
+    }
+
+    /// The errors of the groups that failed, with the index of their composer.
+    pub fn failures(&self) -> Vec<(usize, &ComposerError)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .filter_map(|(index, group)| group.as_ref().err().map(|error| (index, error)))
+            .collect()
+    }
+}
+
 /// Sends transactions and groups with validation and result processing.
 #[derive(Clone)]
 pub struct TransactionSender {
     asset_manager: AssetManager,
     new_composer: Arc<dyn Fn(Option<TransactionComposerConfig>) -> TransactionComposer>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl TransactionSender {
@@ -152,9 +239,59 @@ impl TransactionSender {
         Self {
             asset_manager,
             new_composer: Arc::new(new_composer),
+            retry_policy: None,
         }
     }
 
+    /// Create a batch sender, which sends any number of transactions split into groups.
+    ///
+    /// The groups are built by this sender's composers, e.g. sponsored by its fee payer.
+    pub fn batch(&self) -> BatchSender {
+        let new_composer = self.new_composer.clone();
+        BatchSender::new(move |composer_config| new_composer(composer_config))
+    }
+
+    /// Create a transaction sender that sends every group sponsored by the given fee payer.
+    ///
+    /// # Arguments
+    /// * `fee_payer` - The account that pays the fees on behalf of the transaction senders
+    ///
+    /// # Returns
+    /// A new `TransactionSender` instance
+    pub fn with_fee_payer(&self, fee_payer: FeePayer) -> Self {
+        let new_composer = self.new_composer.clone();
+        Self {
+            retry_policy: self.retry_policy.clone(),
+            ..Self::new(
+                move |composer_config| {
+                    let mut composer = new_composer(composer_config);
+                    composer.set_fee_payer(Some(fee_payer.clone()));
+                    composer
+                },
+                self.asset_manager.clone(),
+            )
+        }
+    }
+
+    /// Create a transaction sender that retries sends failing with transient algod failures.
+    ///
+    /// # Arguments
+    /// * `retry_policy` - When and how often to retry, `None` disables retries
+    ///
+    /// # Returns
+    /// A new `TransactionSender` instance
+    pub fn with_retry_policy(&self, retry_policy: Option<RetryPolicy>) -> Self {
+        Self {
+            retry_policy,
+            ..self.clone()
+        }
+    }
+
+    /// The retry policy of sends, if any.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
     /// Create a new transaction composer group.
     ///
     /// # Arguments
@@ -166,6 +303,84 @@ impl TransactionSender {
         (self.new_composer)(params)
     }
 
+    /// Send a composer, retrying transient failures according to the retry policy.
+    async fn send_composer(
+        &self,
+        composer: &mut TransactionComposer,
+        send_params: Option<SendParams>,
+    ) -> Result<TransactionComposerSendResult, ComposerError> {
+        let Some(retry_policy) = &self.retry_policy else {
+            return composer.send(send_params).await;
+        };
+
+        let max_retries = retry_policy.max_attempts.saturating_sub(1);
+        let mut retries = 0;
+        loop {
+            let error = match composer.send(send_params.clone()).await {
+                Ok(result) => return Ok(result),
+                Err(e) if retries < max_retries && retry_policy.should_retry(&e) => e,
+                Err(e) => return Err(e),
+            };
+
+            retries += 1;
+            tokio::time::sleep(retry_policy.backoff(retries)).await;
+
+            // The group may have reached algod before the failure, in which case it's awaited
+            // rather than submitted again
+            if let Ok(Some(result)) = composer.resume_send(send_params.clone()).await {
+                info!(
+                    "Transaction group was accepted despite the send error: {}",
+                    error
+                );
+                return Ok(result);
+            }
+
+            warn!(
+                "Sending the transaction group failed, retrying ({}/{}): {}",
+                retries, max_retries, error
+            );
+            let events = Config::events();
+            if events.has_listeners() {
+                let data = RetryEventData {
+                    operation: RetryOperation::Send,
+                    attempt: retries,
+                    max_retries,
+                    error: error.to_string(),
+                };
+                events.emit(EventType::Retry, EventData::Retry(data)).await;
+            }
+        }
+    }
+
+    /// Send independent transaction groups concurrently, e.g. for minting pipelines.
+    ///
+    /// Each composer is sent as its own group with the retry policy of this sender. A failed
+    /// group doesn't stop the other groups, its error is reported in the [`SendManyResult`].
+    ///
+    /// # Arguments
+    /// * `composers` - The composers of the groups to send
+    /// * `concurrency` - The maximum number of groups being sent at once, at least 1
+    /// * `send_params` - Optional parameters for sending every group
+    ///
+    /// # Returns
+    /// The outcome of each group, in the order the composers were given
+    pub async fn send_many(
+        &self,
+        composers: Vec<TransactionComposer>,
+        concurrency: usize,
+        send_params: Option<SendParams>,
+    ) -> SendManyResult {
+        let groups = stream::iter(composers.into_iter().map(|mut composer| {
+            let send_params = send_params.clone();
+            async move { self.send_composer(&mut composer, send_params).await }
+        }))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+        SendManyResult { groups }
+    }
+
     async fn send_single_transaction<F>(
         &self,
         add_transaction: F,
@@ -176,7 +391,7 @@ impl TransactionSender {
     {
         let mut composer = self.new_composer(None);
         add_transaction(&mut composer)?;
-        let composer_results = composer.send(send_params).await?;
+        let composer_results = self.send_composer(&mut composer, send_params).await?;
 
         let result =
             composer_results
@@ -219,7 +434,7 @@ impl TransactionSender {
     {
         let mut composer = self.new_composer(None);
         add_transaction(&mut composer)?;
-        let composer_results = composer.send(send_params).await?;
+        let composer_results = self.send_composer(&mut composer, send_params).await?;
 
         let result = composer_results
             .results
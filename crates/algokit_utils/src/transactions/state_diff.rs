@@ -0,0 +1,372 @@
+use crate::clients::app_manager::AppManager;
+use algod_client::models::{
+    ApplicationKvStorage, AvmValue, PendingTransactionResponse, SimulateTransaction,
+    SimulationOpcodeTraceUnit, SimulationTransactionExecTrace,
+};
+use algokit_transact::Transaction;
+use std::collections::HashMap;
+
+/// The kind of application storage a state change applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppStateType {
+    Global,
+    Local { account: String },
+    Box,
+}
+
+/// A raw application state value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppStateValue {
+    Bytes(Vec<u8>),
+    Uint(u64),
+}
+
+/// The value of a single application state key before and after an app call.
+///
+/// `before` is `None` when the key did not exist, `after` is `None` when the key was deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppStateChange {
+    pub state_type: AppStateType,
+    pub key: Vec<u8>,
+    pub before: Option<AppStateValue>,
+    pub after: Option<AppStateValue>,
+}
+
+/// The state changes made by a single (possibly inner) app call in a simulated group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppCallStateDiff {
+    /// The index of the transaction in the group, followed by the indexes of any inner transactions.
+    pub path: Vec<usize>,
+    pub app_id: u64,
+    /// The changes in the order the keys were first written.
+    pub changes: Vec<AppStateChange>,
+}
+
+type StateLocation = (u64, AppStateType, Vec<u8>);
+
+/// Compute the per app call state diffs of the first group in a simulate response.
+///
+/// This requires the simulation to have been run with `exec_trace_config.state_change` enabled,
+/// otherwise no state changes are reported and an empty list is returned.
+pub fn compute_state_diffs(simulate_response: &SimulateTransaction) -> Vec<AppCallStateDiff> {
+    let Some(group) = simulate_response.txn_groups.first() else {
+        return Vec::new();
+    };
+
+    let mut current_state = initial_state(simulate_response);
+    let mut diffs = Vec::new();
+    for (index, txn_result) in group.txn_results.iter().enumerate() {
+        if let Some(exec_trace) = &txn_result.exec_trace {
+            collect_trace_diffs(
+                vec![index],
+                &txn_result.txn_result,
+                exec_trace,
+                &mut current_state,
+                &mut diffs,
+            );
+        }
+    }
+    diffs
+}
+
+fn initial_state(
+    simulate_response: &SimulateTransaction,
+) -> HashMap<StateLocation, Option<AppStateValue>> {
+    let mut state = HashMap::new();
+    let app_states = simulate_response
+        .initial_states
+        .as_ref()
+        .and_then(|s| s.app_initial_states.as_ref());
+
+    let mut insert = |app_id: u64, state_type: AppStateType, storage: &ApplicationKvStorage| {
+        for kv in &storage.kvs {
+            state.insert(
+                (app_id, state_type.clone(), kv.key.clone()),
+                to_state_value(&kv.value),
+            );
+        }
+    };
+
+    for app_state in app_states.into_iter().flatten() {
+        if let Some(globals) = &app_state.app_globals {
+            insert(app_state.id, AppStateType::Global, globals);
+        }
+        if let Some(boxes) = &app_state.app_boxes {
+            insert(app_state.id, AppStateType::Box, boxes);
+        }
+        for locals in app_state.app_locals.iter().flatten() {
+            if let Some(account) = &locals.account {
+                let state_type = AppStateType::Local {
+                    account: account.clone(),
+                };
+                insert(app_state.id, state_type, locals);
+            }
+        }
+    }
+    state
+}
+
+fn collect_trace_diffs(
+    path: Vec<usize>,
+    txn_result: &PendingTransactionResponse,
+    exec_trace: &SimulationTransactionExecTrace,
+    current_state: &mut HashMap<StateLocation, Option<AppStateValue>>,
+    diffs: &mut Vec<AppCallStateDiff>,
+) {
+    let app_id = match &txn_result.txn.transaction {
+        Transaction::AppCall(fields) if fields.app_id != 0 => fields.app_id,
+        Transaction::AppCall(_) => txn_result.app_id.unwrap_or_default(),
+        _ => return,
+    };
+
+    let rolled_back = exec_trace.clear_state_rollback.unwrap_or(false);
+    let traces = exec_trace.approval_program_trace.iter().chain(
+        exec_trace
+            .clear_state_program_trace
+            .iter()
+            .filter(|_| !rolled_back),
+    );
+
+    let mut changes: Vec<AppStateChange> = Vec::new();
+    let mut inner_diffs = Vec::new();
+    for unit in traces.flatten() {
+        apply_state_changes(app_id, unit, current_state, &mut changes);
+
+        for &inner_index in unit.spawned_inners.iter().flatten() {
+            let inner_index = inner_index as usize;
+            let inner_trace = exec_trace
+                .inner_trace
+                .as_ref()
+                .and_then(|t| t.get(inner_index));
+            let inner_result = txn_result
+                .inner_txns
+                .as_ref()
+                .and_then(|t| t.get(inner_index));
+            if let (Some(inner_trace), Some(inner_result)) = (inner_trace, inner_result) {
+                let mut inner_path = path.clone();
+                inner_path.push(inner_index);
+                collect_trace_diffs(
+                    inner_path,
+                    inner_result,
+                    inner_trace,
+                    current_state,
+                    &mut inner_diffs,
+                );
+            }
+        }
+    }
+
+    changes.retain(|change| change.before != change.after);
+    if !changes.is_empty() {
+        diffs.push(AppCallStateDiff {
+            path,
+            app_id,
+            changes,
+        });
+    }
+    diffs.extend(inner_diffs);
+}
+
+fn apply_state_changes(
+    app_id: u64,
+    unit: &SimulationOpcodeTraceUnit,
+    current_state: &mut HashMap<StateLocation, Option<AppStateValue>>,
+    changes: &mut Vec<AppStateChange>,
+) {
+    for operation in unit.state_changes.iter().flatten() {
+        let state_type = match operation.app_state_type.as_str() {
+            "g" => AppStateType::Global,
+            "b" => AppStateType::Box,
+            "l" => AppStateType::Local {
+                account: operation.account.clone().unwrap_or_default(),
+            },
+            _ => continue,
+        };
+        let new_value = match operation.operation.as_str() {
+            "w" => operation.new_value.as_ref().and_then(to_state_value),
+            "d" => None,
+            _ => continue,
+        };
+
+        let location = (app_id, state_type.clone(), operation.key.clone());
+        let previous_value = current_state.insert(location, new_value.clone()).flatten();
+
+        match changes
+            .iter_mut()
+            .find(|c| c.state_type == state_type && c.key == operation.key)
+        {
+            Some(change) => change.after = new_value,
+            None => changes.push(AppStateChange {
+                state_type,
+                key: operation.key.clone(),
+                before: previous_value,
+                after: new_value,
+            }),
+        }
+    }
+}
+
+fn to_state_value(value: &AvmValue) -> Option<AppStateValue> {
+    match value.r#type {
+        1 => Some(AppStateValue::Bytes(AppManager::ensure_decoded_bytes(
+            value.bytes.as_deref().unwrap_or_default().as_bytes(),
+        ))),
+        2 => Some(AppStateValue::Uint(value.uint.unwrap_or_default())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algod_client::models::{
+        ApplicationInitialStates, ApplicationStateOperation, AvmKeyValue, SimulateInitialStates,
+        SimulateTransactionGroupResult, SimulateTransactionResult,
+    };
+    use algokit_transact::SignedTransaction;
+    use algokit_transact::test_utils::AppCallTransactionMother;
+
+    fn uint_value(value: u64) -> AvmValue {
+        AvmValue {
+            r#type: 2,
+            bytes: None,
+            uint: Some(value),
+        }
+    }
+
+    fn write(app_state_type: &str, key: &[u8], value: AvmValue) -> ApplicationStateOperation {
+        ApplicationStateOperation {
+            operation: "w".to_string(),
+            app_state_type: app_state_type.to_string(),
+            key: key.to_vec(),
+            new_value: Some(value),
+            account: None,
+        }
+    }
+
+    fn app_call_result(app_id: u64) -> PendingTransactionResponse {
+        let mut transaction = AppCallTransactionMother::app_call().build().unwrap();
+        if let Transaction::AppCall(fields) = &mut transaction {
+            fields.app_id = app_id;
+        }
+        PendingTransactionResponse {
+            txn: SignedTransaction {
+                transaction,
+                signature: None,
+                auth_address: None,
+                multisignature: None,
+                logic_signature: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn trace(changes: Vec<Vec<ApplicationStateOperation>>) -> SimulationTransactionExecTrace {
+        SimulationTransactionExecTrace {
+            approval_program_trace: Some(
+                changes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(pc, state_changes)| SimulationOpcodeTraceUnit {
+                        pc: pc as u64,
+                        state_changes: Some(state_changes),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compute_state_diffs() {
+        let response = SimulateTransaction {
+            txn_groups: vec![SimulateTransactionGroupResult {
+                txn_results: vec![
+                    SimulateTransactionResult {
+                        txn_result: app_call_result(1234),
+                        exec_trace: Some(trace(vec![
+                            vec![write("g", b"counter", uint_value(2))],
+                            vec![
+                                write("g", b"counter", uint_value(3)),
+                                write("b", b"box", uint_value(1)),
+                            ],
+                        ])),
+                        ..Default::default()
+                    },
+                    SimulateTransactionResult {
+                        txn_result: app_call_result(1234),
+                        exec_trace: Some(trace(vec![vec![
+                            write("g", b"counter", uint_value(4)),
+                            write("g", b"unchanged", uint_value(7)),
+                        ]])),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            initial_states: Some(SimulateInitialStates {
+                app_initial_states: Some(vec![ApplicationInitialStates {
+                    id: 1234,
+                    app_globals: Some(ApplicationKvStorage {
+                        kvs: vec![
+                            AvmKeyValue {
+                                key: b"counter".to_vec(),
+                                value: uint_value(1),
+                            },
+                            AvmKeyValue {
+                                key: b"unchanged".to_vec(),
+                                value: uint_value(7),
+                            },
+                        ],
+                        account: None,
+                    }),
+                    app_locals: None,
+                    app_boxes: None,
+                }]),
+            }),
+            ..Default::default()
+        };
+
+        let diffs = compute_state_diffs(&response);
+
+        assert_eq!(
+            diffs,
+            vec![
+                AppCallStateDiff {
+                    path: vec![0],
+                    app_id: 1234,
+                    changes: vec![
+                        AppStateChange {
+                            state_type: AppStateType::Global,
+                            key: b"counter".to_vec(),
+                            before: Some(AppStateValue::Uint(1)),
+                            after: Some(AppStateValue::Uint(3)),
+                        },
+                        AppStateChange {
+                            state_type: AppStateType::Box,
+                            key: b"box".to_vec(),
+                            before: None,
+                            after: Some(AppStateValue::Uint(1)),
+                        },
+                    ],
+                },
+                AppCallStateDiff {
+                    path: vec![1],
+                    app_id: 1234,
+                    changes: vec![AppStateChange {
+                        state_type: AppStateType::Global,
+                        key: b"counter".to_vec(),
+                        before: Some(AppStateValue::Uint(3)),
+                        after: Some(AppStateValue::Uint(4)),
+                    }],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_state_diffs_without_traces() {
+        assert!(compute_state_diffs(&SimulateTransaction::default()).is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_utils::AlgoAmount;
+use algokit_utils::transactions::PaymentParams;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn test_get_block_decodes_confirmed_transactions(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+    let receiver = algorand_fixture.generate_account(None).await?;
+
+    let result = algorand_fixture
+        .algorand_client
+        .send()
+        .payment(
+            PaymentParams {
+                sender: algorand_fixture.test_account.account().address(),
+                receiver: receiver.account().address(),
+                amount: AlgoAmount::micro_algos(1_000_000),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+    let round = result.confirmation.confirmed_round.unwrap();
+
+    let block = algorand_fixture.algod.get_block(round, None).await?.block;
+
+    assert_eq!(block.round, Some(round));
+    let block_transaction = block
+        .block_transactions()?
+        .into_iter()
+        .find(|block_transaction| block_transaction.id == result.transaction_id)
+        .expect("The block should contain the payment");
+    assert_eq!(
+        block_transaction.signed_transaction.transaction,
+        result.transaction
+    );
+
+    let header = algorand_fixture
+        .algod
+        .get_block(round, Some(true))
+        .await?
+        .block;
+    assert_eq!(header.round, Some(round));
+    assert!(header.transactions.is_none());
+
+    Ok(())
+}
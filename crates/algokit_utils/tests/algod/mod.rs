@@ -1,3 +1,4 @@
+pub mod get_block;
 pub mod pending_transaction_information;
 pub mod raw_transaction;
 pub mod simulate_transactions;
@@ -28,12 +28,14 @@ async fn test_simulate_transactions() {
             signature: None,
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         },
         SignedTransaction {
             transaction: transaction2,
             signature: None,
             auth_address: None,
             multisignature: None,
+            logic_signature: None,
         },
     ];
 
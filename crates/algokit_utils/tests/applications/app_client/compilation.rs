@@ -4,6 +4,7 @@ use algokit_utils::applications::app_client::CompilationParams;
 use algokit_utils::clients::app_manager::TealTemplateValue;
 use algokit_utils::config::{AppCompiledEventData, EventData, EventType};
 use rstest::*;
+use tokio::sync::broadcast::error::RecvError;
 
 #[rstest]
 #[tokio::test]
@@ -28,24 +29,30 @@ async fn compile_applies_template_params_and_emits_event(
     };
     client.compile(&compilation_params).await?;
 
-    if let Ok((event_type, data)) =
-        tokio::time::timeout(std::time::Duration::from_millis(5000), events.recv()).await?
-    {
-        assert_eq!(event_type, EventType::AppCompiled);
-        match data {
-            EventData::AppCompiled(AppCompiledEventData {
-                app_name,
-                approval_source_map,
-                clear_source_map,
-            }) => {
-                assert!(app_name.is_none() || app_name.as_deref() == Some("TestingApp"));
-                assert!(approval_source_map.is_some());
-                assert!(clear_source_map.is_some());
+    // Other tests emit events concurrently, so skip everything but the compile event
+    let data = tokio::time::timeout(std::time::Duration::from_millis(5000), async {
+        loop {
+            match events.recv().await {
+                Ok((EventType::AppCompiled, data)) => return Some(data),
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
             }
-            _ => return Err("unexpected event data".into()),
         }
-    } else {
-        return Err("expected AppCompiled event".into());
+    })
+    .await?
+    .ok_or("expected AppCompiled event")?;
+
+    match data {
+        EventData::AppCompiled(AppCompiledEventData {
+            app_name,
+            approval_source_map,
+            clear_source_map,
+        }) => {
+            assert!(app_name.is_none() || app_name.as_deref() == Some("TestingApp"));
+            assert!(approval_source_map.is_some());
+            assert!(clear_source_map.is_some());
+        }
+        _ => return Err("unexpected event data".into()),
     }
 
     Ok(())
@@ -64,6 +64,60 @@ async fn test_default_value_from_literal(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_omitted_args_use_default_values(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let client = f.client;
+    let sender = f.sender_address;
+
+    let omitted = client
+        .send()
+        .call(
+            AppClientMethodCallParams {
+                method: "default_value".to_string(),
+                args: vec![],
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+            None,
+        )
+        .await?;
+    let omitted_ret = omitted
+        .result
+        .abi_return
+        .and_then(|r| r.return_value)
+        .expect("Expected ABI return value");
+    assert_eq!(omitted_ret, ABIValue::from("default value"));
+
+    // Arguments without a default value can't be omitted
+    let err = client
+        .send()
+        .call(
+            AppClientMethodCallParams {
+                method: "call_abi".to_string(),
+                args: vec![],
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+            None,
+        )
+        .await
+        .expect_err("Expected validation error due to missing args");
+    assert!(
+        err.to_string()
+            .contains("The number of provided arguments is 0"),
+        "Unexpected error message: {}",
+        err
+    );
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_default_value_from_method(
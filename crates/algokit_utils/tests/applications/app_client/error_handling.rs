@@ -28,6 +28,11 @@ async fn test_exposing_logic_error_without_sourcemaps(
 
     if let AppClientError::LogicError { logic, .. } = &error_response {
         assert!(logic.message.contains("assert failed pc=885"));
+        assert_eq!(logic.pc, Some(885));
+        assert_eq!(logic.app_id, Some(client.app_id()));
+        assert!(logic.transaction_id.is_some());
+        assert!(logic.details.as_deref().unwrap().contains("pc=885"));
+        assert!(logic.to_string().contains(". at:"));
     }
 
     Ok(())
@@ -2,6 +2,7 @@ use crate::common::TestResult;
 use crate::common::app_fixture::{sandbox_app_fixture, testing_app_fixture};
 use algokit_abi::ABIValue;
 use algokit_transact::BoxReference;
+use algokit_utils::AlgoAmount;
 use algokit_utils::applications::app_client::AppClientBareCallParams;
 use algokit_utils::applications::app_client::{AppClientMethodCallParams, FundAppAccountParams};
 use algokit_utils::{AppMethodCallArg, PaymentParams};
@@ -157,11 +158,11 @@ async fn params_build_bare_and_fund_payment(
     assert_eq!(bare.box_references.as_ref().unwrap()[0].name, b"1".to_vec());
 
     let pay: PaymentParams = client.params().fund_app_account(&FundAppAccountParams {
-        amount: 200_000,
+        amount: AlgoAmount::micro_algos(200_000),
         sender: Some(sender.to_string()),
         ..Default::default()
     })?;
-    assert_eq!(pay.amount, 200_000);
+    assert_eq!(pay.amount, AlgoAmount::micro_algos(200_000));
     assert_eq!(pay.receiver, client.app_address());
 
     Ok(())
@@ -189,7 +190,7 @@ async fn params_construct_txn_with_abi_tx_arg_and_return(
         first_valid_round: None,
         last_valid_round: None,
         receiver: sender.clone(),
-        amount: 123,
+        amount: AlgoAmount::micro_algos(123),
     };
 
     let result = client
@@ -2,6 +2,7 @@ use crate::common::app_fixture::{sandbox_app_fixture, testing_app_fixture, testi
 use crate::common::{TestResult, nested_contract_fixture};
 use algokit_abi::{ABIMethod, ABIValue};
 use algokit_transact::{BoxReference, SignedTransaction, Transaction};
+use algokit_utils::AlgoAmount;
 use algokit_utils::applications::app_client::AppClientMethodCallParams;
 use algokit_utils::transactions::{PaymentParams, TransactionSigner, TransactionWithSigner};
 use algokit_utils::{AppCallMethodCallParams, AppManager, AppMethodCallArg};
@@ -44,6 +45,91 @@ async fn test_create_then_call_app(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_new_group_sends_calls_and_payment_atomically(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let client = f.client;
+    let sender = f.sender_address;
+    let call = |value: &str| AppClientMethodCallParams {
+        method: "call_abi".to_string(),
+        args: vec![AppMethodCallArg::ABIValue(ABIValue::from(value))],
+        sender: Some(sender.to_string()),
+        ..Default::default()
+    };
+
+    let result = client
+        .new_group()
+        .call(call("first"))
+        .add_payment(PaymentParams {
+            sender: sender.clone(),
+            receiver: client.app_address(),
+            amount: AlgoAmount::micro_algos(1_000_000),
+            ..Default::default()
+        })
+        .call(call("second"))
+        .send(None)
+        .await?;
+
+    assert_eq!(result.results.len(), 3);
+    assert!(result.group.is_some());
+    assert!(result.confirmed_round().is_some());
+    let returns: Vec<_> = result
+        .results
+        .iter()
+        .map(|result| {
+            result
+                .abi_return
+                .as_ref()
+                .and_then(|abi_return| abi_return.return_value.clone())
+        })
+        .collect();
+    assert_eq!(
+        returns,
+        vec![
+            Some(ABIValue::from("Hello, first")),
+            None,
+            Some(ABIValue::from("Hello, second"))
+        ]
+    );
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_call_readonly_does_not_submit(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let client = f.client;
+    let sender = f.sender_address;
+
+    let result = client
+        .send()
+        .call_readonly(
+            AppClientMethodCallParams {
+                method: "call_abi".to_string(),
+                args: vec![AppMethodCallArg::ABIValue(ABIValue::from("test"))],
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+
+    let abi_return = result.result.abi_return.expect("Expected ABI return");
+    match abi_return.return_value {
+        Some(ABIValue::String(s)) => assert_eq!(s, "Hello, test"),
+        _ => return Err("Expected string ABI return".into()),
+    }
+    assert!(result.result.confirmation.confirmed_round.is_none());
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_construct_transaction_with_abi_encoding_including_transaction(
@@ -63,7 +149,7 @@ async fn test_construct_transaction_with_abi_encoding_including_transaction(
         .payment(PaymentParams {
             sender: funded_addr.clone(),
             receiver: funded_addr.clone(),
-            amount,
+            amount: AlgoAmount::micro_algos(amount),
             ..Default::default()
         })
         .await?;
@@ -184,7 +270,7 @@ async fn test_call_app_with_rekey(
                 sender: sender.clone(),
                 signer: Some(Arc::new(rekey_to_account.clone())),
                 receiver: sender.clone(),
-                amount: 0,
+                amount: AlgoAmount::micro_algos(0),
                 ..Default::default()
             },
             None,
@@ -215,7 +301,7 @@ async fn test_sign_all_transactions_in_group_with_abi_call_with_transaction_arg(
         .payment(PaymentParams {
             sender: funded_addr.clone(),
             receiver: funded_addr.clone(),
-            amount,
+            amount: AlgoAmount::micro_algos(amount),
             ..Default::default()
         })
         .await?;
@@ -292,7 +378,7 @@ async fn test_sign_transaction_in_group_with_different_signer_if_provided(
         .payment(PaymentParams {
             sender: new_addr.clone(),
             receiver: new_addr.clone(),
-            amount: 2_000,
+            amount: AlgoAmount::micro_algos(2_000),
             ..Default::default()
         })
         .await?;
@@ -342,7 +428,7 @@ async fn test_sign_nested_transactions_in_group_with_different_signers(
             sender: bob_addr.clone(),
             signer: Some(Arc::new(bob_account.clone())),
             receiver: bob_addr.clone(),
-            amount: 2_000,
+            amount: AlgoAmount::micro_algos(2_000),
             ..Default::default()
         })
         .await?;
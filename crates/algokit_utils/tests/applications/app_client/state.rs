@@ -4,10 +4,16 @@ use crate::common::app_fixture::{
 };
 use algokit_abi::{ABIType, ABIValue};
 use algokit_transact::BoxReference;
+use algokit_utils::AlgoAmount;
 // client params not needed with fixtures
+use algod_client::models::SimulateTraceConfig;
 use algokit_utils::AppMethodCallArg;
-use algokit_utils::applications::app_client::{AppClientMethodCallParams, FundAppAccountParams};
-use algokit_utils::clients::app_manager::{AppState, BoxName};
+use algokit_utils::applications::app_client::{
+    AppClientMethodCallParams, FundAppAccountParams, LocalStateOptIn,
+};
+use algokit_utils::clients::app_manager::{AppManagerError, AppState, BoxName};
+use algokit_utils::transactions::AppStateType;
+use algokit_utils::transactions::composer::SimulateParams;
 use base64::{Engine, engine::general_purpose::STANDARD as Base64};
 use num_bigint::BigUint;
 use rstest::*;
@@ -88,6 +94,52 @@ async fn test_global_state_retrieval(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_local_state_opt_in_required(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let sender = f.sender_address;
+    let client = f.client;
+    let set_local_params = AppClientMethodCallParams {
+        method: "set_local".to_string(),
+        args: vec![
+            AppMethodCallArg::ABIValue(ABIValue::from(1u64)),
+            AppMethodCallArg::ABIValue(ABIValue::from(2u64)),
+            AppMethodCallArg::ABIValue(ABIValue::from("asdf")),
+            AppMethodCallArg::ABIValue(ABIValue::Array(vec![ABIValue::from_byte(1); 4])),
+        ],
+        sender: Some(sender.to_string()),
+        local_state_opt_in: Some(LocalStateOptIn::Require),
+        ..Default::default()
+    };
+
+    let error = client
+        .send()
+        .call(set_local_params.clone(), None, None)
+        .await
+        .expect_err("Call should fail before opting in");
+    assert!(error.to_string().contains("is not opted in to app"));
+
+    client
+        .send()
+        .opt_in(
+            AppClientMethodCallParams {
+                method: "opt_in".to_string(),
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+    assert!(client.is_opted_in(&sender.to_string()).await?);
+
+    client.send().call(set_local_params, None, None).await?;
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_local_state_retrieval(
@@ -193,7 +245,7 @@ async fn test_box_retrieval(
     client
         .fund_app_account(
             FundAppAccountParams {
-                amount: 1_000_000,
+                amount: AlgoAmount::micro_algos(1_000_000),
                 sender: Some(sender.to_string()),
                 ..Default::default()
             },
@@ -277,6 +329,22 @@ async fn test_box_retrieval(
         .expect("box2 should exist");
     assert_eq!(box2.value, b"value2");
 
+    let app_manager = client.algorand().app();
+    let values_at_round = app_manager
+        .get_box_values_with_round(client.app_id(), &[box_name1.clone(), box_name2.clone()])
+        .await?;
+    assert_eq!(
+        values_at_round.value,
+        vec![b"value1".to_vec(), b"value2".to_vec()]
+    );
+    let stale_read = app_manager
+        .get_box_value_with_round(client.app_id(), &box_name1, Some(values_at_round.round - 1))
+        .await;
+    assert!(matches!(
+        stale_read,
+        Err(AppManagerError::RoundMismatch { .. })
+    ));
+
     let expected_value_decoded = "1234524352";
     let expected_value = format!("\x00\n{}", expected_value_decoded);
 
@@ -346,7 +414,7 @@ async fn test_box_maps(
     app_client
         .fund_app_account(
             FundAppAccountParams {
-                amount: 1_000_000,
+                amount: AlgoAmount::micro_algos(1_000_000),
                 sender: Some(sender.to_string()),
                 ..Default::default()
             },
@@ -383,7 +451,79 @@ async fn test_box_maps(
         .box_storage()
         .get_map_value("bMap", &ABIValue::Uint(BigUint::from(1u64)))
         .await?;
-    assert_eq!(box_map_value, Some(expected_value));
+    assert_eq!(box_map_value, Some(expected_value.clone()));
+
+    let b_map = app_client.state().box_map("bMap")?;
+    assert_eq!(b_map.get(&key).await?, Some(expected_value));
+    assert_eq!(b_map.get(&ABIValue::Uint(BigUint::from(2u64))).await?, None);
+    assert_eq!(b_map.keys().await?, vec![key]);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_box_map_pages_and_min_balance(
+    #[future] boxmap_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = boxmap_app_fixture.await?;
+    let sender = f.sender_address;
+    let app_client = f.client;
+
+    app_client
+        .fund_app_account(
+            FundAppAccountParams {
+                amount: AlgoAmount::micro_algos(1_000_000),
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+
+    for (key, value) in [(3u64, "baz"), (1, "foo"), (2, "barbar")] {
+        app_client
+            .send()
+            .call(
+                AppClientMethodCallParams {
+                    method: "setValue".to_string(),
+                    args: vec![
+                        AppMethodCallArg::ABIValue(ABIValue::from(key)),
+                        AppMethodCallArg::ABIValue(ABIValue::from(value)),
+                    ],
+                    sender: Some(sender.to_string()),
+                    ..Default::default()
+                },
+                None,
+                None,
+            )
+            .await?;
+    }
+
+    let b_map = app_client.state().box_map("bMap")?;
+
+    let first_page = b_map.page(None, 2).await?;
+    let first_keys: Vec<ABIValue> = first_page.entries.iter().map(|e| e.key.clone()).collect();
+    assert_eq!(first_keys, vec![ABIValue::from(1u64), ABIValue::from(2u64)]);
+    assert!(first_page.next.is_some());
+
+    let second_page = b_map.page(first_page.next.as_deref(), 2).await?;
+    assert_eq!(second_page.entries.len(), 1);
+    assert_eq!(second_page.entries[0].key, ABIValue::from(3u64));
+    assert_eq!(second_page.entries[0].value, ABIValue::from("baz"));
+    assert_eq!(second_page.next, None);
+
+    // The "b" prefix, the uint64 key and the ABI encoded string
+    let entry = &first_page.entries[1];
+    assert_eq!(entry.size, 2 + 6);
+    assert_eq!(
+        entry.min_balance(),
+        AlgoAmount::micro_algos(2500 + 400 * (9 + 8))
+    );
+    assert_eq!(
+        b_map.min_balance(&ABIValue::from(2u64), 8)?,
+        entry.min_balance()
+    );
 
     Ok(())
 }
@@ -400,7 +540,7 @@ async fn box_methods_with_manually_encoded_abi_args(
     client
         .fund_app_account(
             FundAppAccountParams {
-                amount: 1_000_000,
+                amount: AlgoAmount::micro_algos(1_000_000),
                 sender: Some(sender.to_string()),
                 ..Default::default()
             },
@@ -493,7 +633,7 @@ async fn box_methods_with_arc4_returns_parametrized(
     client
         .fund_app_account(
             FundAppAccountParams {
-                amount: 1_000_000,
+                amount: AlgoAmount::micro_algos(1_000_000),
                 sender: Some(sender.to_string()),
                 ..Default::default()
             },
@@ -607,3 +747,131 @@ async fn box_methods_with_arc4_returns_parametrized(
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_simulate_state_diff(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let sender = f.sender_address;
+    let client = f.client;
+
+    let params = client
+        .params()
+        .call(
+            AppClientMethodCallParams {
+                method: "set_global".to_string(),
+                args: vec![
+                    AppMethodCallArg::ABIValue(ABIValue::from(1u64)),
+                    AppMethodCallArg::ABIValue(ABIValue::from(2u64)),
+                    AppMethodCallArg::ABIValue(ABIValue::from("asdf")),
+                    AppMethodCallArg::ABIValue(ABIValue::Array(vec![
+                        ABIValue::from_byte(1),
+                        ABIValue::from_byte(2),
+                        ABIValue::from_byte(3),
+                        ABIValue::from_byte(4),
+                    ])),
+                ],
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+
+    let mut composer = client.algorand().new_composer(None);
+    composer.add_app_call_method_call(params)?;
+    let simulate_result = composer
+        .simulate(Some(SimulateParams {
+            skip_signatures: true,
+            exec_trace_config: Some(SimulateTraceConfig {
+                enable: Some(true),
+                state_change: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+        .await?;
+
+    assert_eq!(simulate_result.state_diffs.len(), 1);
+    let diff = client.decode_state_diff(&simulate_result.state_diffs[0])?;
+    assert_eq!(diff.app_id, client.app_id());
+    assert_eq!(diff.path, vec![0]);
+
+    let int1 = diff
+        .changes
+        .iter()
+        .find(|c| c.key == b"int1")
+        .ok_or("Expected int1 to change")?;
+    assert_eq!(int1.state_type, AppStateType::Global);
+    assert_eq!(int1.after, Some(ABIValue::from(1u64)));
+
+    // Nothing was sent, so the app state is unchanged
+    let global_state = client.get_global_state().await?;
+    assert!(!global_state.contains_key("int1".as_bytes()));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_app_account_mbr_matches_algod(
+    #[future] testing_app_fixture: crate::common::AppFixtureResult,
+) -> TestResult {
+    let f = testing_app_fixture.await?;
+    let sender = f.sender_address;
+    let client = f.client;
+
+    assert_eq!(
+        client.get_app_account_mbr().await?,
+        AlgoAmount::micro_algos(100_000)
+    );
+
+    client
+        .fund_app_account(
+            FundAppAccountParams {
+                amount: AlgoAmount::algos(1),
+                sender: Some(sender.to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+    let box_name: Vec<u8> = vec![0, 0, 0, 1];
+    client
+        .send()
+        .call(
+            AppClientMethodCallParams {
+                method: "set_box".to_string(),
+                args: vec![
+                    AppMethodCallArg::ABIValue(ABIValue::Array(
+                        box_name.iter().copied().map(ABIValue::from_byte).collect(),
+                    )),
+                    AppMethodCallArg::ABIValue(ABIValue::from("value")),
+                ],
+                sender: Some(sender.to_string()),
+                box_references: Some(vec![BoxReference {
+                    app_id: 0,
+                    name: box_name,
+                }]),
+                ..Default::default()
+            },
+            None,
+            None,
+        )
+        .await?;
+
+    let account = client
+        .algorand()
+        .client()
+        .algod()
+        .account_information(&client.app_address().to_string(), None, None)
+        .await?;
+    assert_eq!(
+        client.get_app_account_mbr().await?,
+        AlgoAmount::micro_algos(account.min_balance)
+    );
+
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use algokit_abi::ABIMethod;
 use algokit_test_artifacts::{abi_create_and_delete, testing_app};
 use algokit_transact::{Address, OnApplicationComplete};
+use algokit_utils::AlgoAmount;
 use algokit_utils::applications::{
-    AppDeployMetadata, AppDeployParams, AppDeployResult, AppDeployer, AppProgram, CreateParams,
-    DeleteParams, DeployAppCreateMethodCallParams, DeployAppCreateParams,
-    DeployAppDeleteMethodCallParams, DeployAppDeleteParams, DeployAppUpdateParams, OnSchemaBreak,
-    OnUpdate, UpdateParams,
+    AppDeployError, AppDeployMetadata, AppDeployParams, AppDeployResult, AppDeployer, AppMigration,
+    AppProgram, CreateParams, CreateRetryParams, DeleteParams, DeployAppCreateMethodCallParams,
+    DeployAppCreateParams, DeployAppDeleteMethodCallParams, DeployAppDeleteParams,
+    DeployAppUpdateParams, FundsHandling, OnSchemaBreak, OnUpdate, UpdateParams,
 };
 use algokit_utils::clients::app_manager::{AppManager, DeploymentMetadata, TealTemplateValue};
 use algokit_utils::{AppCreateParams, AppMethodCallArg, PaymentParams, TransactionSender};
@@ -15,6 +16,7 @@ use rstest::*;
 use serde_json;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use crate::common::{AlgorandFixture, AlgorandFixtureResult, TestResult, algorand_fixture};
 
@@ -26,7 +28,7 @@ async fn fixture(#[future] algorand_fixture: AlgorandFixtureResult) -> FixtureRe
     let indexer_client = algorand_fixture.indexer.clone();
 
     let composer = algorand_fixture.algorand_client.new_composer(None);
-    let asset_manager = AssetManager::new(algod_client.clone(), {
+    let asset_manager = AssetManager::new(algod_client.clone(), Some(indexer_client.clone()), {
         let new_composer = composer.clone();
         move |_params| new_composer.clone()
     });
@@ -110,6 +112,61 @@ async fn test_created_app_is_retrieved_by_name_with_deployment_metadata(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_created_app_is_retrieved_by_name_without_indexer(
+    #[future] fixture: FixtureResult,
+) -> TestResult {
+    let Fixture {
+        test_account,
+        app_manager,
+        transaction_sender,
+        ..
+    } = fixture.await?;
+
+    let creation_metadata = get_metadata(AppDeployMetadataParams {
+        name: Some(String::from("MY_APP")),
+        version: Some(String::from("1.0")),
+        ..Default::default()
+    });
+    let create_params =
+        get_testing_app_create_params(&app_manager, &test_account, &creation_metadata).await?;
+    let result = transaction_sender.app_create(create_params, None).await?;
+    let created_round = result.confirmation.confirmed_round.unwrap();
+
+    let app_deployer = AppDeployer::new(app_manager, transaction_sender, None);
+    let apps = app_deployer
+        .with_creation_round_hints(vec![created_round])
+        .get_creator_apps_by_name(&test_account, None)
+        .await?;
+
+    let app = &apps.apps["MY_APP"];
+    assert_eq!(app.app_id, result.app_id);
+    assert_eq!(app.created_round, created_round);
+    assert_eq!(app.created_metadata, creation_metadata);
+    assert!(!app.deleted);
+
+    // Without a hint of the create round the metadata of the app can't be found, which fails the
+    // lookup unless the app is ignored
+    let error = app_deployer
+        .clone()
+        .get_creator_apps_by_name_from_algod(&test_account, &[])
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, AppDeployError::DeploymentLookupFailed { ref message }
+            if message.contains(&result.app_id.to_string())),
+        "unexpected error: {error}"
+    );
+    let apps = app_deployer
+        .with_unresolved_apps_ignored(true)
+        .get_creator_apps_by_name_from_algod(&test_account, &[])
+        .await?;
+    assert!(apps.apps.is_empty());
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_latest_created_app_is_retrieved(#[future] fixture: FixtureResult) -> TestResult {
@@ -333,6 +390,48 @@ async fn test_deploy_new_app(#[future] fixture: FixtureResult) -> TestResult {
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_deploy_new_app_recovers_from_unconfirmed_create(
+    #[future] fixture: FixtureResult,
+) -> TestResult {
+    let Fixture {
+        test_account,
+        app_deployer,
+        algorand_fixture,
+        ..
+    } = fixture.await?;
+
+    let metadata = get_metadata(AppDeployMetadataParams {
+        ..Default::default()
+    });
+    let mut deployment =
+        get_testing_app_deploy_params(&test_account, &metadata, None, None, None, None).await?;
+    // Don't wait for confirmation, so sending the create always times out
+    deployment.send_params.max_rounds_to_wait_for_confirmation = Some(0);
+    let mut app_deployer = app_deployer.with_create_retry(CreateRetryParams { max_retries: 1 });
+
+    let result = app_deployer.deploy(deployment).await?;
+    let (app, create_result) = match &result {
+        AppDeployResult::Create {
+            app, create_result, ..
+        } => (app, create_result),
+        _ => return Err("Expected Create result".into()),
+    };
+
+    assert!(create_result.transaction.header().lease.is_some());
+    assert_eq!(app.app_id, create_result.confirmation.app_id.unwrap());
+    algorand_fixture
+        .wait_for_indexer_transaction(&create_result.transaction_id)
+        .await?;
+    let apps = app_deployer
+        .get_creator_apps_by_name(&test_account, Some(true))
+        .await?;
+    assert_eq!(apps.apps["MY_APP"].app_id, app.app_id);
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_fail_to_deploy_immutable_app_without_tmpl_updatable(
@@ -647,6 +746,181 @@ async fn test_deploy_replacement_to_deletable_updated_app(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_deploy_replacement_of_funded_app_fails_when_funds_handling_fail(
+    #[future] fixture: FixtureResult,
+) -> TestResult {
+    let Fixture {
+        test_account,
+        mut app_deployer,
+        algorand_fixture,
+        transaction_sender,
+        ..
+    } = fixture.await?;
+
+    let metadata = get_metadata(AppDeployMetadataParams {
+        deletable: Some(true),
+        ..Default::default()
+    });
+    let deployment_1 =
+        get_testing_app_deploy_params(&test_account, &metadata, None, None, None, None).await?;
+    let AppDeployResult::Create {
+        app, create_result, ..
+    } = app_deployer.deploy(deployment_1).await?
+    else {
+        return Err("Expected Create result".into());
+    };
+    transaction_sender
+        .payment(
+            PaymentParams {
+                sender: test_account.clone(),
+                receiver: app.app_address.clone(),
+                amount: AlgoAmount::micro_algos(200_000),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+    algorand_fixture
+        .wait_for_indexer_transaction(&create_result.transaction_id)
+        .await?;
+
+    let metadata_2 = get_metadata(AppDeployMetadataParams {
+        version: Some(String::from("2.0")),
+        deletable: Some(true),
+        ..Default::default()
+    });
+    let deployment_2 = AppDeployParams {
+        on_replace_funds: Some(FundsHandling::Fail),
+        ..get_testing_app_deploy_params(
+            &test_account,
+            &metadata_2,
+            Some(2),
+            None,
+            Some(OnUpdate::Replace),
+            None,
+        )
+        .await?
+    };
+
+    let error = app_deployer.deploy(deployment_2).await.unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("holds 200000 µALGO and 0 assets, which are stranded")
+    );
+    let apps = app_deployer
+        .get_creator_apps_by_name(&test_account, Some(true))
+        .await?;
+    assert_eq!(apps.apps[&metadata.name].app_id, app.app_id);
+
+    Ok(())
+}
+
+/// Records the app IDs it is called with, failing when `fail` is set.
+#[derive(Default)]
+struct RecordingMigration {
+    calls: Mutex<Vec<(u64, u64)>>,
+    fail: bool,
+}
+
+#[async_trait::async_trait(?Send)]
+impl AppMigration for RecordingMigration {
+    async fn migrate(&self, old_app_id: u64, new_app_id: u64) -> Result<(), String> {
+        self.calls.lock().unwrap().push((old_app_id, new_app_id));
+        if self.fail {
+            return Err("state copy failed".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[rstest]
+#[case(false)]
+#[case(true)]
+#[tokio::test]
+async fn test_deploy_replacement_with_migration(
+    #[future] fixture: FixtureResult,
+    #[case] fail: bool,
+) -> TestResult {
+    let Fixture {
+        test_account,
+        mut app_deployer,
+        algorand_fixture,
+        app_manager,
+        ..
+    } = fixture.await?;
+
+    let metadata = get_metadata(AppDeployMetadataParams {
+        deletable: Some(true),
+        ..Default::default()
+    });
+    let deployment_1 =
+        get_testing_app_deploy_params(&test_account, &metadata, None, None, None, None).await?;
+    let result_1 = app_deployer.deploy(deployment_1).await?;
+    let (app_1_id, create_transaction_id) = match &result_1 {
+        AppDeployResult::Create {
+            app, create_result, ..
+        } => (app.app_id, create_result.transaction_id.clone()),
+        _ => return Err("Expected Create result".into()),
+    };
+    algorand_fixture
+        .wait_for_indexer_transaction(&create_transaction_id)
+        .await?;
+
+    let migration = Arc::new(RecordingMigration {
+        fail,
+        ..Default::default()
+    });
+    let mut app_deployer = app_deployer.with_migration(migration.clone());
+
+    let metadata_2 = get_metadata(AppDeployMetadataParams {
+        version: Some(String::from("2.0")),
+        deletable: Some(true),
+        ..Default::default()
+    });
+    let deployment_2 = get_testing_app_deploy_params(
+        &test_account,
+        &metadata_2,
+        Some(2),
+        None,
+        Some(OnUpdate::Replace),
+        None,
+    )
+    .await?;
+    let result_2 = app_deployer.deploy(deployment_2).await;
+
+    let calls = migration.calls.lock().unwrap().clone();
+    assert_eq!(calls.len(), 1);
+    let (old_app_id, new_app_id) = calls[0];
+    assert_eq!(old_app_id, app_1_id);
+    assert_ne!(new_app_id, app_1_id);
+
+    if fail {
+        let error_message = result_2.unwrap_err().to_string();
+        assert!(error_message.contains("neither app was deleted"));
+        // The old app is still there
+        app_manager.get_by_id(app_1_id).await?;
+    } else {
+        match result_2? {
+            AppDeployResult::Replace {
+                app,
+                delete_result,
+                group,
+                ..
+            } => {
+                assert_eq!(app.app_id, new_app_id);
+                assert!(delete_result.confirmation.confirmed_round.is_some());
+                assert_eq!(group, None);
+            }
+            _ => return Err("Expected Replace result".into()),
+        }
+    }
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_deploy_failure_for_replacement_of_permanent_updated_app(
@@ -1130,6 +1404,7 @@ async fn get_testing_app_deploy_params(
         deploy_time_params: Some(template_params),
         on_schema_break,
         on_update,
+        on_replace_funds: None,
         create_params: CreateParams::AppCreateCall(DeployAppCreateParams {
             sender: sender.clone(),
             approval_program: AppProgram::Teal(approval_program),
@@ -1153,7 +1428,9 @@ async fn get_testing_app_deploy_params(
         ignore_cache: None,
         send_params: SendParams {
             max_rounds_to_wait_for_confirmation: Some(100),
+            ..Default::default()
         },
+        spec_diff: None,
     })
 }
 
@@ -1264,6 +1541,7 @@ async fn get_deploy_params_for_replacing_app_using_abi_methods(
         deploy_time_params: None,
         on_schema_break: Some(OnSchemaBreak::Replace),
         on_update: Some(OnUpdate::Replace),
+        on_replace_funds: None,
         create_params: CreateParams::AppCreateMethodCall(DeployAppCreateMethodCallParams {
             sender: sender.clone(),
             method: ABIMethod::from_str("create(pay)string")?,
@@ -1277,7 +1555,7 @@ async fn get_deploy_params_for_replacing_app_using_abi_methods(
             args: vec![AppMethodCallArg::Payment(PaymentParams {
                 sender: sender.clone(),
                 receiver: sender.clone(),
-                amount: 1000,
+                amount: AlgoAmount::micro_algos(1000),
                 ..Default::default()
             })],
             ..Default::default()
@@ -1292,7 +1570,7 @@ async fn get_deploy_params_for_replacing_app_using_abi_methods(
             args: vec![AppMethodCallArg::Payment(PaymentParams {
                 sender: sender.clone(),
                 receiver: sender.clone(),
-                amount: 2000,
+                amount: AlgoAmount::micro_algos(2000),
                 ..Default::default()
             })],
             ..Default::default()
@@ -1301,7 +1579,9 @@ async fn get_deploy_params_for_replacing_app_using_abi_methods(
         ignore_cache: None,
         send_params: SendParams {
             max_rounds_to_wait_for_confirmation: Some(100),
+            ..Default::default()
         },
+        spec_diff: None,
     })
 }
 
@@ -5,6 +5,7 @@ use crate::common::{
 use algokit_abi::{ABIValue, Arc56Contract};
 use algokit_transact::Address;
 use algokit_transact::OnApplicationComplete;
+use algokit_utils::AlgoAmount;
 use algokit_utils::applications::app_client::{AppClientMethodCallParams, CompilationParams};
 use algokit_utils::applications::app_factory::{
     AppFactory, AppFactoryCreateMethodCallParams, AppFactoryParams,
@@ -12,7 +13,7 @@ use algokit_utils::applications::app_factory::{
 use algokit_utils::applications::app_factory::{AppFactoryCreateParams, DeployArgs};
 use algokit_utils::applications::{AppDeployResult, OnSchemaBreak, OnUpdate};
 use algokit_utils::clients::app_manager::{TealTemplateParams, TealTemplateValue};
-use algokit_utils::transactions::TransactionComposerConfig;
+use algokit_utils::transactions::{FeePayer, PaymentParams, TransactionComposerConfig};
 use algokit_utils::{AlgorandClient, AppMethodCallArg};
 use rstest::*;
 use std::collections::HashMap;
@@ -420,7 +421,7 @@ async fn call_app_with_rekey(#[future] algorand_fixture: AlgorandFixtureResult)
         // but after rekey the auth address must be rekey_to's signer. Use explicit signer.
         signer: Some(Arc::new(rekey_to.clone())),
         receiver: sender.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     let _ = algorand_client.send().payment(pay, None).await?;
@@ -1205,3 +1206,133 @@ async fn deploy_app_replace_abi(#[future] algorand_fixture: AlgorandFixtureResul
     }
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn fee_payer_covers_create_and_call_fees(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut fixture = algorand_fixture.await?;
+    let fee_payer = fixture.generate_account(None).await?;
+    let fee_payer_address = fee_payer.account().address();
+    let sender = fixture.test_account.account().address();
+    let algod = fixture.algod.clone();
+    let (algorand_client, test_account) = into_factory_inputs(fixture);
+
+    let factory = build_testing_app_factory(
+        algorand_client,
+        test_account,
+        AppFactoryOptions {
+            updatable: Some(true),
+            transaction_composer_config: Some(TransactionComposerConfig {
+                fee_payer: Some(FeePayer {
+                    address: fee_payer_address.clone(),
+                    signer: Some(Arc::new(fee_payer)),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let sender_balance = algod
+        .account_information(&sender.to_string(), None, None)
+        .await?
+        .amount;
+    let fee_payer_balance = algod
+        .account_information(&fee_payer_address.to_string(), None, None)
+        .await?
+        .amount;
+
+    let (client, create_result) = factory
+        .send()
+        .bare()
+        .create(None, None, Some(compilation_params(1, true, true)))
+        .await?;
+    assert_eq!(create_result.transaction.header().fee, Some(0));
+
+    let call_result = client
+        .send()
+        .call(
+            AppClientMethodCallParams {
+                method: "call_abi(string)string".to_string(),
+                args: vec![abi_str_arg("test")],
+                ..Default::default()
+            },
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(call_result.group_results.len(), 1);
+    assert_eq!(call_result.result.transaction.header().fee, Some(0));
+    assert!(call_result.result.abi_return.is_some());
+
+    // Each group pays the min fee for the sponsored transaction and the fee payment
+    assert_eq!(
+        algod
+            .account_information(&sender.to_string(), None, None)
+            .await?
+            .amount,
+        sender_balance
+    );
+    assert_eq!(
+        algod
+            .account_information(&fee_payer_address.to_string(), None, None)
+            .await?
+            .amount,
+        fee_payer_balance - 4_000
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn create_grouped_with_payment_in_external_composer(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut fixture = algorand_fixture.await?;
+    let receiver = fixture.generate_account(None).await?.account().address();
+    let sender = fixture.test_account.account().address();
+    let (algorand_client, test_account) = into_factory_inputs(fixture);
+
+    let factory = build_testing_app_factory(
+        algorand_client.clone(),
+        test_account.clone(),
+        AppFactoryOptions::default(),
+    )
+    .await;
+
+    let mut composer = algorand_client.new_composer(None);
+    composer.add_payment(PaymentParams {
+        sender: sender.clone(),
+        signer: Some(Arc::new(test_account)),
+        receiver,
+        amount: AlgoAmount::algos(1),
+        ..Default::default()
+    })?;
+    factory
+        .create_transaction()
+        .bare()
+        .add_create_to_composer(
+            &mut composer,
+            None,
+            Some(compilation_params(1, false, false)),
+        )
+        .await?;
+
+    let result = composer.send(None).await?;
+
+    assert!(result.group.is_some());
+    assert_eq!(result.results.len(), 2);
+    let app_id = result.results[1]
+        .confirmation
+        .app_id
+        .expect("the app should have been created");
+    assert!(app_id > 0);
+    assert_eq!(
+        result.results[0].confirmation.confirmed_round,
+        result.results[1].confirmation.confirmed_round
+    );
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_transact::Transaction;
+use algokit_utils::clients::RekeyAccountParams;
+use algokit_utils::clients::{EnsureFundedParams, TransactionHistoryFilters};
+use algokit_utils::{AlgoAmount, PaymentParams};
+use futures::TryStreamExt;
+use rstest::*;
+
+#[rstest]
+#[tokio::test]
+async fn test_ensure_funded_from_environment_tops_up_once(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let account_manager = algorand_fixture.algorand_client.account();
+    let account = account_manager.random().address();
+
+    let result = account_manager
+        .ensure_funded_from_environment(
+            &account,
+            1_000_000,
+            Some(EnsureFundedParams {
+                min_funding_increment: Some(2_000_000),
+                ..Default::default()
+            }),
+        )
+        .await?
+        .expect("A new account should be funded");
+    assert_eq!(result.amount_funded, 2_000_000);
+    assert!(result.confirmation.is_some());
+
+    let account_information = algorand_fixture
+        .algod
+        .account_information(&account.to_string(), None, None)
+        .await?;
+    assert_eq!(account_information.amount, 2_000_000);
+
+    let result = account_manager
+        .ensure_funded_from_environment(&account, 1_000_000, None)
+        .await?;
+    assert!(result.is_none(), "A funded account shouldn't be topped up");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_transaction_history_pages_through_payments(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+    let sender = algorand_fixture
+        .generate_account(None)
+        .await?
+        .account()
+        .address();
+    let receiver = algorand_fixture
+        .algorand_client
+        .account()
+        .random()
+        .address();
+
+    let mut last_transaction_id = String::new();
+    for amount in 1..=3 {
+        let result = algorand_fixture
+            .algorand_client
+            .send()
+            .payment(
+                PaymentParams {
+                    sender: sender.clone(),
+                    receiver: receiver.clone(),
+                    amount: AlgoAmount::algos(amount),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+        last_transaction_id = result.transaction_id;
+    }
+    algorand_fixture
+        .wait_for_indexer_transaction(&last_transaction_id)
+        .await?;
+
+    let history: Vec<_> = algorand_fixture
+        .algorand_client
+        .account()
+        .get_transaction_history(
+            &receiver,
+            TransactionHistoryFilters {
+                page_size: Some(2),
+                ..Default::default()
+            },
+        )?
+        .try_collect()
+        .await?;
+
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].id, last_transaction_id);
+    let amounts: Vec<_> = history
+        .iter()
+        .map(|historical| match &historical.transaction {
+            Some(Transaction::Payment(fields)) => fields.amount,
+            _ => panic!("Expected a payment"),
+        })
+        .collect();
+    assert_eq!(amounts, vec![3_000_000, 2_000_000, 1_000_000]);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_rekey_account_registers_the_new_signer(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+    let account = algorand_fixture
+        .generate_account(None)
+        .await?
+        .account()
+        .address();
+    let account_manager = algorand_fixture.algorand_client.account();
+    let rekey_to = account_manager.random().address();
+
+    let result = account_manager
+        .rekey_account(
+            &account,
+            &rekey_to,
+            Some(RekeyAccountParams {
+                note: Some(b"rekey".to_vec()),
+                ..Default::default()
+            }),
+        )
+        .await?;
+    assert_eq!(result.transaction.header().rekey_to, Some(rekey_to.clone()));
+
+    let account_information = algorand_fixture
+        .algod
+        .account_information(&account.to_string(), None, None)
+        .await?;
+    assert_eq!(account_information.auth_addr, Some(rekey_to.to_string()));
+
+    // The account is now signed for by the signer of the account it was rekeyed to
+    algorand_fixture
+        .algorand_client
+        .send()
+        .payment(
+            PaymentParams {
+                sender: account.clone(),
+                receiver: rekey_to,
+                amount: AlgoAmount::micro_algos(100_000),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
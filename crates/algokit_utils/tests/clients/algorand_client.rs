@@ -1,4 +1,8 @@
-use algokit_utils::AlgorandClient;
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_utils::transactions::PaymentParams;
+use algokit_utils::{AlgoAmount, AlgorandClient};
+use futures::StreamExt;
+use rstest::*;
 
 /// Test basic functionality of AlgorandClient
 #[tokio::test]
@@ -64,3 +68,44 @@ async fn test_algorand_client_with_fixture() -> Result<(), Box<dyn std::error::E
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_wait_for_round_and_block_stream(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let client = &algorand_fixture.algorand_client;
+    let sender = algorand_fixture.test_account.account().address();
+    let send_payment = || {
+        client.send().payment(
+            PaymentParams {
+                sender: sender.clone(),
+                receiver: sender.clone(),
+                amount: AlgoAmount::micro_algos(0),
+                ..Default::default()
+            },
+            None,
+        )
+    };
+
+    let current_round = client.current_round().await?;
+    assert!(client.wait_for_round(current_round).await? >= current_round);
+
+    // LocalNet only produces blocks for transactions, so send one while waiting
+    let (waited, payment) = tokio::join!(client.wait_for_round(current_round + 1), send_payment());
+    assert!(waited? > current_round);
+    payment?;
+
+    let mut blocks = Box::pin(client.block_stream(None));
+    let (first, payment) = tokio::join!(blocks.next(), send_payment());
+    payment?;
+    let first = first.expect("block stream never ends")?;
+    assert!(first > current_round + 1);
+
+    send_payment().await?;
+    let second = blocks.next().await.expect("block stream never ends")?;
+    assert_eq!(second, first + 1);
+
+    Ok(())
+}
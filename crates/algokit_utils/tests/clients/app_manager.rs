@@ -1,4 +1,4 @@
-use algokit_abi::{ABIType, abi_type::BitSize};
+use algokit_abi::{ABIType, ABIValue, abi_type::BitSize};
 use algokit_test_artifacts::template_variables;
 use algokit_utils::clients::app_manager::*;
 use base64::prelude::*;
@@ -21,6 +21,12 @@ use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
 #[case("TMPL_X TMPL_X TMPL_X",
        &[("X", TealTemplateValue::String("test".to_string()))],
        "0x74657374 0x74657374 0x74657374")]
+#[case("pushint TMPL_SIGNED\npushbytes TMPL_B64",
+       &[("SIGNED", TealTemplateValue::SignedInt(7)), ("B64", TealTemplateValue::Base64("AQL/".to_string()))],
+       "pushint 7\npushbytes 0x0102ff")]
+#[case("pushbytes TMPL_ADDRESS",
+       &[("ADDRESS", TealTemplateValue::Address(algokit_transact::Address([0xab; 32])))],
+       "pushbytes 0xabababababababababababababababababababababababababababababababab")]
 fn test_template_variable_replacement_behavior(
     #[case] teal_code: &str,
     #[case] template_vars: &[(&str, TealTemplateValue)],
@@ -35,6 +41,47 @@ fn test_template_variable_replacement_behavior(
     assert_eq!(result.trim(), expected.trim());
 }
 
+/// Test invalid template values and strict substitution
+#[test]
+fn test_strict_template_variable_replacement() {
+    for value in [
+        TealTemplateValue::SignedInt(-1),
+        TealTemplateValue::Base64("not base64!".to_string()),
+    ] {
+        let template_values = HashMap::from([("X".to_string(), value)]);
+        assert!(matches!(
+            AppManager::replace_template_variables("pushint TMPL_X", &template_values),
+            Err(AppManagerError::InvalidTemplateValue { .. })
+        ));
+    }
+
+    let program = "pushint TMPL_A // TMPL_C\npushbytes \"TMPL_D\"\npushint TMPL_B";
+    assert_eq!(
+        AppManager::find_template_variables(program),
+        vec!["TMPL_A", "TMPL_B"]
+    );
+
+    let all = HashMap::from([
+        ("A".to_string(), TealTemplateValue::Int(1)),
+        ("TMPL_B".to_string(), TealTemplateValue::Int(2)),
+    ]);
+    assert_eq!(
+        AppManager::replace_template_variables_strict(program, &all).unwrap(),
+        "pushint 1 // TMPL_C\npushbytes \"TMPL_D\"\npushint 2"
+    );
+
+    let mismatched = HashMap::from([
+        ("A".to_string(), TealTemplateValue::Int(1)),
+        ("E".to_string(), TealTemplateValue::Int(5)),
+    ]);
+    let error = AppManager::replace_template_variables_strict(program, &mismatched).unwrap_err();
+    assert!(matches!(
+        &error,
+        AppManagerError::TemplateVariablesMismatch { message }
+            if message == "no value for TMPL_B; no variable for TMPL_E"
+    ));
+}
+
 /// Test comprehensive comment stripping behavior with all edge cases
 #[test]
 fn test_comprehensive_comment_stripping() {
@@ -137,6 +184,34 @@ async fn test_teal_compilation(#[future] algorand_fixture: AlgorandFixtureResult
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_teal_disassembly(#[future] algorand_fixture: AlgorandFixtureResult) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let app_manager = algorand_fixture.algorand_client.app();
+    let bytecode = [3, 129, 1, 67];
+
+    assert_eq!(app_manager.get_disassembly_result(&bytecode), None);
+
+    let teal = app_manager.disassemble_teal(&bytecode).await?;
+
+    assert_eq!(teal.trim_end(), "#pragma version 3\npushint 1\nreturn");
+    assert_eq!(
+        app_manager.get_disassembly_result(&bytecode),
+        Some(teal.clone())
+    );
+    // The disassembly compiles back to the same program
+    assert_eq!(
+        app_manager
+            .compile_teal(&teal)
+            .await?
+            .compiled_base64_to_bytes,
+        bytecode
+    );
+
+    Ok(())
+}
+
 /// Test template compilation
 #[rstest]
 #[tokio::test]
@@ -161,6 +236,26 @@ async fn test_template_compilation(
     // Check deterministic compilation results for template with int 42
     assert_eq!(result.compiled_base64_to_bytes, vec![3, 129, 42, 67]);
 
+    // The result is cached by template, params and metadata
+    let cached = app_manager
+        .get_template_compilation_result(
+            "#pragma version 3\npushint TMPL_VALUE\nreturn",
+            Some(&template_params),
+            None,
+        )
+        .unwrap();
+    assert_eq!(cached.compiled_hash, result.compiled_hash);
+    let other_params = HashMap::from([("VALUE".to_string(), TealTemplateValue::Int(43))]);
+    assert!(
+        app_manager
+            .get_template_compilation_result(
+                "#pragma version 3\npushint TMPL_VALUE\nreturn",
+                Some(&other_params),
+                None,
+            )
+            .is_none()
+    );
+
     Ok(())
 }
 
@@ -502,3 +597,94 @@ fn test_abi_type_box_value_methods() {
     // This unit test validates the correct approach for box data decoding
     println!("ABIType approach for box data: Storage type -> ABIValue");
 }
+
+fn uint_state(key: &[u8], value: u64) -> (Vec<u8>, AppState) {
+    (
+        key.to_vec(),
+        AppState::Uint(UintAppState {
+            key_raw: key.to_vec(),
+            key_base64: BASE64_STANDARD.encode(key),
+            value,
+        }),
+    )
+}
+
+fn bytes_state(key: &[u8], value: &[u8]) -> (Vec<u8>, AppState) {
+    (
+        key.to_vec(),
+        AppState::Bytes(BytesAppState {
+            key_raw: key.to_vec(),
+            key_base64: BASE64_STANDARD.encode(key),
+            value_raw: value.to_vec(),
+            value_base64: BASE64_STANDARD.encode(value),
+            value: String::from_utf8_lossy(value).to_string(),
+        }),
+    )
+}
+
+#[test]
+fn test_diff_global_state() {
+    let app_spec = algokit_abi::Arc56Contract::from_json(
+        algokit_test_artifacts::arc56_struct_operations::APPLICATION_ARC56,
+    )
+    .unwrap();
+    // The `globalMap` key "a": the "p" prefix followed by the ABI encoded string
+    let map_key = b"p\x00\x01a";
+    let before = HashMap::from([
+        uint_state(b"globalKey", 1),
+        uint_state(b"unchanged", 7),
+        bytes_state(b"removed", b"bye"),
+    ]);
+    let after = HashMap::from([
+        uint_state(b"globalKey", 2),
+        uint_state(b"unchanged", 7),
+        bytes_state(map_key, &[0, 1, 0, 2]),
+    ]);
+
+    let diffs = AppManager::diff_global_state(&before, &after, Some(&app_spec)).unwrap();
+
+    assert_eq!(diffs.len(), 3);
+    let StateDiff::Changed {
+        key,
+        before: value_before,
+        after: value_after,
+    } = &diffs[0]
+    else {
+        panic!("expected a changed entry, got {:?}", diffs[0]);
+    };
+    assert_eq!(key.name.as_deref(), Some("globalKey"));
+    assert_eq!(value_before.decoded, Some(ABIValue::from(1u64)));
+    assert_eq!(value_after.decoded, Some(ABIValue::from(2u64)));
+
+    let StateDiff::Added { key, value } = &diffs[1] else {
+        panic!("expected an added entry, got {:?}", diffs[1]);
+    };
+    assert_eq!(key.key_raw, map_key);
+    assert_eq!(key.name.as_deref(), Some("globalMap"));
+    assert_eq!(key.map_key, Some(ABIValue::String("a".to_string())));
+    assert!(value.decoded.is_some());
+
+    let StateDiff::Removed { key, value } = &diffs[2] else {
+        panic!("expected a removed entry, got {:?}", diffs[2]);
+    };
+    assert_eq!(key.key_raw, b"removed");
+    assert_eq!(key.name, None);
+    assert_eq!(value.decoded, None);
+
+    // Without an app spec nothing is decoded
+    let diffs = AppManager::diff_local_state(&before, &after, None).unwrap();
+    assert_eq!(diffs.len(), 3);
+    assert!(diffs.iter().all(|diff| diff.key().name.is_none()));
+    assert_eq!(
+        AppManager::diff_global_state(&after, &after, None).unwrap(),
+        vec![]
+    );
+
+    // A value that doesn't decode with its declared type is an error
+    let invalid = HashMap::from([bytes_state(b"globalKey", &[1, 2, 3])]);
+    let error = AppManager::diff_global_state(&before, &invalid, Some(&app_spec)).unwrap_err();
+    assert!(
+        matches!(error, AppManagerError::ABIDecodeError { ref message } if message.contains("globalKey")),
+        "unexpected error: {error}"
+    );
+}
@@ -1,7 +1,8 @@
 use algokit_transact::Address;
+use algokit_utils::AssetAmount;
 use algokit_utils::{
-    clients::asset_manager::AssetManagerError,
-    transactions::{AssetCreateParams, AssetOptInParams},
+    clients::asset_manager::{AssetHolder, AssetHoldersSnapshotOptions, AssetManagerError},
+    transactions::{AssetCreateParams, AssetOptInParams, AssetTransferParams},
 };
 use rstest::*;
 use std::sync::Arc;
@@ -48,6 +49,59 @@ async fn test_get_asset_by_id_nonexistent(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_asset_transfer_with_whole_units(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+    let creator = algorand_fixture.generate_account(None).await?;
+    let creator_address = creator.account().address();
+    let asset_id = algorand_fixture
+        .algorand_client
+        .send()
+        .asset_create(
+            AssetCreateParams {
+                sender: creator_address.clone(),
+                signer: Some(Arc::new(creator.clone())),
+                total: 1_000_000,
+                decimals: Some(2),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?
+        .asset_id;
+    let asset_manager = algorand_fixture.algorand_client.asset();
+    let transfer = AssetTransferParams {
+        sender: creator_address.clone(),
+        signer: Some(Arc::new(creator.clone())),
+        asset_id,
+        receiver: creator_address.clone(),
+        ..Default::default()
+    };
+
+    let params = transfer
+        .clone()
+        .with_whole_units("1.5", asset_manager)
+        .await?;
+    assert_eq!(params.amount, AssetAmount::with_decimals(150, 2));
+    algorand_fixture
+        .algorand_client
+        .send()
+        .asset_transfer(params, None)
+        .await?;
+
+    let result = transfer.with_whole_units("1.234", asset_manager).await;
+    assert!(matches!(
+        result,
+        Err(AssetManagerError::InvalidAmount { asset_id: id, .. }) if id == asset_id
+    ));
+    assert_eq!(asset_manager.get_decimals(asset_id, true).await?, 2);
+
+    Ok(())
+}
+
 /// Test account asset information retrieval
 #[rstest]
 #[tokio::test]
@@ -103,6 +157,84 @@ async fn test_get_account_information_not_opted_in(
     Ok(())
 }
 
+/// Test holders snapshot retrieval from indexer
+#[rstest]
+#[tokio::test]
+async fn test_get_holders_snapshot(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+
+    let (asset_id, creator_address) = create_test_asset_with_creator(&mut algorand_fixture).await?;
+    let large_holder = algorand_fixture.generate_account(None).await?;
+    let small_holder = algorand_fixture.generate_account(None).await?;
+
+    let mut last_transaction_id = String::new();
+    for (holder, amount) in [(&large_holder, 100), (&small_holder, 5)] {
+        let holder_address = holder.account().address();
+        algorand_fixture
+            .algorand_client
+            .send()
+            .asset_opt_in(
+                AssetOptInParams {
+                    sender: holder_address.clone(),
+                    signer: Some(Arc::new(holder.clone())),
+                    asset_id,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+        let transfer_result = algorand_fixture
+            .algorand_client
+            .send()
+            .asset_transfer(
+                AssetTransferParams {
+                    sender: creator_address.clone(),
+                    asset_id,
+                    amount: AssetAmount::base_units(amount),
+                    receiver: holder_address,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+        last_transaction_id = transfer_result.transaction_id;
+    }
+    algorand_fixture
+        .wait_for_indexer_transaction(&last_transaction_id)
+        .await?;
+
+    let asset_manager = algorand_fixture.algorand_client.asset();
+    let snapshot = asset_manager
+        .get_holders_snapshot(
+            asset_id,
+            10,
+            Some(AssetHoldersSnapshotOptions {
+                exclude_creator: true,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    assert_eq!(snapshot.asset_id, asset_id);
+    assert!(snapshot.round > 0);
+    assert_eq!(
+        snapshot.holders,
+        vec![AssetHolder {
+            address: large_holder.account().address().to_string(),
+            amount: 100,
+        }]
+    );
+
+    let snapshot = asset_manager
+        .get_holders_snapshot(asset_id, 0, None)
+        .await?;
+    assert_eq!(snapshot.holders.len(), 3);
+
+    Ok(())
+}
+
 /// Helper function to create a test asset and return both asset ID and creator address
 async fn create_test_asset_with_creator(
     fixture: &mut AlgorandFixture,
@@ -111,3 +111,27 @@ async fn test_network_details_localnet() {
         "Should detect localnet for local config"
     );
 }
+
+#[tokio::test]
+async fn test_genesis_localnet() {
+    init_test_logging();
+
+    let config = ClientManager::get_config_from_environment_or_localnet();
+    let manager = ClientManager::new(&config).unwrap();
+
+    let genesis = manager.genesis().await.unwrap();
+    let network = manager.network().await.unwrap();
+
+    assert_eq!(
+        format!("{}-{}", genesis.network, genesis.id),
+        network.genesis_id
+    );
+    assert!(genesis.is_protocol_account(&genesis.fee_sink));
+    assert!(genesis.is_protocol_account(&genesis.rewards_pool));
+    assert!(genesis.get_allocation(&genesis.fee_sink).is_some());
+    assert!(genesis.total_allocated() > 0);
+
+    // Cached after the first call
+    let cached = manager.genesis().await.unwrap();
+    assert!(Arc::ptr_eq(&genesis, &cached));
+}
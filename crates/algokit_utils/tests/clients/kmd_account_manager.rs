@@ -0,0 +1,73 @@
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_utils::AlgoAmount;
+use algokit_utils::clients::LOCALNET_DEFAULT_WALLET_NAME;
+use algokit_utils::transactions::PaymentParams;
+use algokit_utils::{KmdAccountManagerError, TransactionSigner};
+use rstest::*;
+
+#[rstest]
+#[tokio::test]
+async fn test_localnet_dispenser_signs_through_kmd(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+    let kmd = algorand_fixture.algorand_client.kmd()?;
+
+    let wallets = kmd.list_wallets().await?;
+    assert!(
+        wallets
+            .iter()
+            .any(|wallet| wallet.name == LOCALNET_DEFAULT_WALLET_NAME)
+    );
+
+    let dispenser = kmd.get_localnet_dispenser_account().await?;
+    assert!(
+        kmd.get_wallet_addresses(LOCALNET_DEFAULT_WALLET_NAME)
+            .await?
+            .contains(&dispenser.address)
+    );
+    let private_key = kmd
+        .export_key(LOCALNET_DEFAULT_WALLET_NAME, &dispenser.address)
+        .await?;
+    assert_eq!(private_key.len(), 64);
+
+    let receiver = algorand_fixture.generate_account(None).await?;
+    let signer: std::sync::Arc<dyn TransactionSigner> = dispenser.signer.clone();
+    algorand_fixture
+        .algorand_client
+        .set_signer(dispenser.address.clone(), signer);
+
+    let result = algorand_fixture
+        .algorand_client
+        .send()
+        .payment(
+            PaymentParams {
+                sender: dispenser.address.clone(),
+                receiver: receiver.account().address(),
+                amount: AlgoAmount::micro_algos(1_000_000),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?;
+    assert!(result.confirmation.confirmed_round.is_some());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_wallet_account_fails_for_unknown_wallet(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let kmd = algorand_fixture.algorand_client.kmd()?;
+
+    let result = kmd.get_wallet_account("not-a-wallet", None).await;
+    assert!(matches!(
+        result,
+        Err(KmdAccountManagerError::WalletNotFound { .. })
+    ));
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
+pub mod account_manager;
 pub mod algorand_client;
 pub mod app_manager;
 pub mod asset_manager;
 pub mod client_manager;
+pub mod kmd_account_manager;
+pub mod node_manager;
@@ -0,0 +1,36 @@
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use rstest::*;
+use std::time::Duration;
+
+#[rstest]
+#[tokio::test]
+async fn test_localnet_is_caught_up_and_ready(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let node = algorand_fixture.algorand_client.node();
+
+    let status = node.wait_until_caught_up(Duration::from_secs(10)).await?;
+
+    assert!(status.is_caught_up());
+    assert!(status.last_round > 0);
+    assert!(!status.last_version.is_empty());
+    assert!(node.ready().await?);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_status_after_round(#[future] algorand_fixture: AlgorandFixtureResult) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let node = algorand_fixture.algorand_client.node();
+    let last_round = node.status().await?.last_round;
+
+    // A round after the previous one has already been seen
+    let status = node.status_after_round(last_round - 1).await?;
+
+    assert!(status.last_round >= last_round);
+
+    Ok(())
+}
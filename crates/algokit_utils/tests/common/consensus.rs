@@ -0,0 +1,82 @@
+use super::fixture::{AlgorandFixture, AlgorandFixtureResult};
+use algod_client::AlgodClient;
+use algokit_utils::clients::algorand_client::AlgorandClientParams;
+use algokit_utils::transactions::TransactionComposerConfig;
+use algokit_utils::transactions::composer::ResourcePopulation;
+use algokit_utils::{ClientManager, ConsensusVersion};
+use rstest::*;
+
+/// The consensus protocol localnet is expected to run for a test suite run.
+///
+/// The composer test suite is run once per target, against localnet configured for that
+/// protocol, by setting `ALGOKIT_TEST_CONSENSUS` to `current` (the default) or `vnext`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsensusTarget {
+    /// The latest released protocol
+    Current,
+    /// The next protocol, with access lists
+    VNext,
+}
+
+impl ConsensusTarget {
+    pub const ENV_VAR: &'static str = "ALGOKIT_TEST_CONSENSUS";
+
+    pub fn from_environment() -> Self {
+        match std::env::var(Self::ENV_VAR).as_deref() {
+            Ok("vnext") => Self::VNext,
+            _ => Self::Current,
+        }
+    }
+
+    pub fn matches(&self, consensus_version: &ConsensusVersion) -> bool {
+        match self {
+            Self::Current => !matches!(consensus_version, ConsensusVersion::Future),
+            Self::VNext => matches!(consensus_version, ConsensusVersion::Future),
+        }
+    }
+
+    /// The composer config exercising the version dependent behaviour of the target.
+    pub fn composer_config(&self) -> TransactionComposerConfig {
+        TransactionComposerConfig {
+            populate_app_call_resources: ResourcePopulation::Enabled {
+                use_access_list: *self == Self::VNext,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+pub async fn detect_consensus_version(
+    algod: &AlgodClient,
+) -> Result<ConsensusVersion, Box<dyn std::error::Error + Send + Sync>> {
+    let params = algod.transaction_params().await?;
+    Ok(ConsensusVersion::new(&params.consensus_version))
+}
+
+/// An [`AlgorandFixture`] for the consensus target of the test run.
+///
+/// Fails when localnet runs a different protocol than the target, so a misconfigured localnet
+/// doesn't silently run the suite against the wrong version.
+#[fixture]
+pub async fn consensus_algorand_fixture(
+    #[default(ConsensusTarget::from_environment())] target: ConsensusTarget,
+) -> AlgorandFixtureResult {
+    let fixture = AlgorandFixture::new(&AlgorandClientParams {
+        client_config: ClientManager::get_config_from_environment_or_localnet(),
+        composer_config: Some(target.composer_config()),
+    })
+    .await?;
+
+    let consensus_version = detect_consensus_version(&fixture.algod).await?;
+    if !target.matches(&consensus_version) {
+        return Err(format!(
+            "Localnet runs consensus version {:?}, which doesn't match the {:?} target set by {}",
+            consensus_version,
+            target,
+            ConsensusTarget::ENV_VAR
+        )
+        .into());
+    }
+
+    Ok(fixture)
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::common::LocalNetDispenser;
 use crate::common::logging::init_test_logging;
@@ -55,6 +56,11 @@ impl AlgorandFixture {
         );
 
         let mut algorand_client = AlgorandClient::new(params);
+        algorand_client
+            .node()
+            .wait_until_caught_up(Duration::from_secs(60))
+            .await
+            .map_err(|e| format!("LocalNet isn't ready: {}", e))?;
 
         let test_account = Self::generate_account_internal(
             algod.clone(),
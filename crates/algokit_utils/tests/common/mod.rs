@@ -1,11 +1,11 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 pub mod app_fixture;
+pub mod consensus;
 pub mod fixture;
 pub mod indexer_helpers;
 pub mod local_net_dispenser;
 pub mod logging;
-pub mod mnemonic;
 pub mod test_account;
 
 use algokit_abi::Arc56Contract;
@@ -23,6 +23,7 @@ pub use app_fixture::{
     nested_contract_fixture, sandbox_app_fixture, sandbox_spec, testing_app_fixture,
     testing_app_puya_fixture, testing_app_puya_spec, testing_app_spec,
 };
+pub use consensus::{ConsensusTarget, consensus_algorand_fixture, detect_consensus_version};
 pub use fixture::{AlgorandFixture, AlgorandFixtureResult, algorand_fixture};
 pub use indexer_helpers::{
     IndexerWaitConfig, IndexerWaitError, wait_for_indexer, wait_for_indexer_transaction,
@@ -1,6 +1,6 @@
 use algokit_transact::{
-    ALGORAND_SECRET_KEY_BYTE_LENGTH, ALGORAND_SIGNATURE_BYTE_LENGTH, AlgorandMsgpack,
-    KeyPairAccount, SignedTransaction, Transaction,
+    ALGORAND_SIGNATURE_BYTE_LENGTH, AlgorandMsgpack, KeyPairAccount, SecretKey, SignedTransaction,
+    Transaction,
 };
 use algokit_utils::TransactionSigner;
 use async_trait::async_trait;
@@ -8,7 +8,7 @@ use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
 use hex;
 use rand::rngs::OsRng;
 
-use super::mnemonic::{from_key, to_key};
+use algokit_utils::clients::mnemonic::{from_key, to_key};
 
 /// Test account configuration
 #[derive(Debug, Clone)]
@@ -44,7 +44,7 @@ pub enum NetworkType {
 #[derive(Debug, Clone)]
 pub struct TestAccount {
     /// The ed25519 secret key used for signing transactions
-    secret_key: [u8; ALGORAND_SECRET_KEY_BYTE_LENGTH],
+    secret_key: SecretKey,
 }
 
 #[async_trait]
@@ -54,7 +54,7 @@ impl TransactionSigner for TestAccount {
         txns: &[Transaction],
         indices: &[usize],
     ) -> Result<Vec<SignedTransaction>, String> {
-        let signing_key = SigningKey::from_bytes(&self.secret_key);
+        let signing_key = SigningKey::from_bytes(self.secret_key.as_bytes());
         let verifying_key: VerifyingKey = (&signing_key).into();
         let signer_account = KeyPairAccount::from_pubkey(&verifying_key.to_bytes());
         let signer_address = signer_account.address();
@@ -81,6 +81,7 @@ impl TransactionSigner for TestAccount {
                         signature: Some(sig),
                         auth_address,
                         multisignature: None,
+                        logic_signature: None,
                     })
                 } else {
                     Err(format!("Index {} out of bounds for transactions", idx))
@@ -97,7 +98,7 @@ impl TestAccount {
         let signing_key = SigningKey::generate(&mut OsRng);
 
         Ok(Self {
-            secret_key: signing_key.to_bytes(),
+            secret_key: SecretKey::from(signing_key.to_bytes()),
         })
     }
 
@@ -109,49 +110,32 @@ impl TestAccount {
         let secret_key =
             to_key(mnemonic_str).map_err(|e| format!("Failed to parse mnemonic: {}", e))?;
 
-        Ok(Self { secret_key })
+        Ok(Self {
+            secret_key: SecretKey::from(secret_key),
+        })
     }
 
     /// Create an account directly from a 64-byte secret key (private + public key material)
     pub fn from_secret_key(
         secret_key: &[u8],
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let key_slice = match secret_key.len() {
-            ALGORAND_SECRET_KEY_BYTE_LENGTH => secret_key,
-            len if len == ALGORAND_SECRET_KEY_BYTE_LENGTH * 2 => {
-                &secret_key[..ALGORAND_SECRET_KEY_BYTE_LENGTH]
-            }
-            other => {
-                return Err(format!(
-                    "Secret key must be {} or {} bytes, got {}",
-                    ALGORAND_SECRET_KEY_BYTE_LENGTH,
-                    ALGORAND_SECRET_KEY_BYTE_LENGTH * 2,
-                    other
-                )
-                .into());
-            }
-        };
-
-        let mut key_bytes = [0u8; ALGORAND_SECRET_KEY_BYTE_LENGTH];
-        key_bytes.copy_from_slice(key_slice);
-
         Ok(Self {
-            secret_key: key_bytes,
+            secret_key: SecretKey::try_from(secret_key)?,
         })
     }
 
     /// Get the account's address using algokit_transact
     pub fn account(&self) -> KeyPairAccount {
-        let signing_key = SigningKey::from_bytes(&self.secret_key);
+        let signing_key = SigningKey::from_bytes(self.secret_key.as_bytes());
         let public_key: VerifyingKey = (&signing_key).into();
         KeyPairAccount::from_pubkey(&public_key.to_bytes())
     }
 
     /// Get the account's mnemonic (proper Algorand 25-word mnemonic)
     pub fn mnemonic(&self) -> String {
-        from_key(&self.secret_key).unwrap_or_else(|_| {
+        from_key(self.secret_key.as_bytes()).unwrap_or_else(|_| {
             // Fallback to hex for debugging if mnemonic generation fails
-            hex::encode(self.secret_key)
+            hex::encode(self.secret_key.as_bytes())
         })
     }
 }
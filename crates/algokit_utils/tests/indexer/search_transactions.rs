@@ -1,5 +1,6 @@
 use algokit_http_client::DefaultHttpClient;
 use algokit_transact::TransactionId;
+use algokit_utils::AlgoAmount;
 use algokit_utils::{ClientManager, PaymentParams};
 use indexer_client::IndexerClient;
 use rstest::rstest;
@@ -23,7 +24,7 @@ async fn finds_sent_transaction(#[future] algorand_fixture: AlgorandFixtureResul
     let payment_params = PaymentParams {
         sender: sender.clone(),
         receiver: receiver.account().address(),
-        amount: 500_000,
+        amount: AlgoAmount::micro_algos(500_000),
         ..Default::default()
     };
 
@@ -0,0 +1,102 @@
+use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_utils::AlgoAmount;
+use algokit_utils::subscriber::{
+    AlgorandSubscriber, AlgorandSubscriberConfig, InMemoryWatermarkStore, NamedTransactionFilter,
+    SubscribedTransaction, SubscriptionHandler, TransactionFilter, WatermarkStore,
+};
+use algokit_utils::transactions::PaymentParams;
+use async_trait::async_trait;
+use rstest::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct RecordingHandler {
+    transaction_ids: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl SubscriptionHandler for RecordingHandler {
+    async fn handle(&self, transactions: &[SubscribedTransaction]) -> Result<(), String> {
+        self.transaction_ids.lock().unwrap().extend(
+            transactions
+                .iter()
+                .map(|transaction| transaction.id.clone()),
+        );
+        Ok(())
+    }
+}
+
+/// Rounds within the retention are read from algod blocks, older ones from indexer.
+#[rstest]
+#[case::from_algod_blocks(1000)]
+#[case::from_indexer(0)]
+#[tokio::test]
+async fn test_poll_once_delivers_matching_payments(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+    #[case] algod_block_retention: u64,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let client = &algorand_fixture.algorand_client;
+    let sender = algorand_fixture.test_account.account().address();
+    let send_payment = |note: &str| {
+        client.send().payment(
+            PaymentParams {
+                sender: sender.clone(),
+                receiver: sender.clone(),
+                amount: AlgoAmount::micro_algos(0),
+                note: Some(note.as_bytes().to_vec()),
+                ..Default::default()
+            },
+            None,
+        )
+    };
+
+    let matching = send_payment("subscriber:match").await?;
+    let other = send_payment("subscriber:other").await?;
+    if algod_block_retention == 0 {
+        algorand_fixture
+            .wait_for_indexer_transaction(&other.transaction_id)
+            .await?;
+    }
+    let first_round = matching.confirmation.confirmed_round.unwrap();
+    let last_round = other.confirmation.confirmed_round.unwrap();
+
+    let watermark_store = Arc::new(InMemoryWatermarkStore::new(Some(first_round - 1)));
+    let mut subscriber = AlgorandSubscriber::new(
+        AlgorandSubscriberConfig {
+            filters: vec![NamedTransactionFilter {
+                name: "matches".to_string(),
+                filter: TransactionFilter {
+                    sender: vec![sender.clone()],
+                    note_prefix: Some(b"subscriber:match".to_vec()),
+                    ..Default::default()
+                },
+            }],
+            algod_block_retention,
+            ..Default::default()
+        },
+        client,
+        watermark_store.clone(),
+    )?;
+    let handler = Arc::new(RecordingHandler::default());
+    subscriber.on("matches", handler.clone())?;
+
+    let result = subscriber.poll_once().await?;
+
+    assert_eq!(result.starting_watermark, first_round - 1);
+    assert!(result.new_watermark >= last_round);
+    assert_eq!(
+        result
+            .matches("matches")
+            .map(|transaction| transaction.id.as_str())
+            .collect::<Vec<_>>(),
+        vec![matching.transaction_id.as_str()]
+    );
+    assert_eq!(
+        *handler.transaction_ids.lock().unwrap(),
+        vec![matching.transaction_id.clone()]
+    );
+    assert_eq!(watermark_store.get().await?, Some(result.new_watermark));
+
+    Ok(())
+}
@@ -0,0 +1 @@
+pub mod algorand_subscriber;
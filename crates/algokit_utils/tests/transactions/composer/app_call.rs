@@ -3,11 +3,12 @@ use crate::common::{
     deploy_arc56_contract,
 };
 use algokit_abi::{ABIMethod, ABIReferenceValue, ABIReturn, ABIValue, Arc56Contract};
-use algokit_test_artifacts::{nested_contract, sandbox};
+use algokit_test_artifacts::{nested_contract, nested_contract_calls, sandbox};
 use algokit_transact::{
     Address, OnApplicationComplete, PaymentTransactionFields, StateSchema, Transaction,
     TransactionHeader, TransactionId,
 };
+use algokit_utils::AlgoAmount;
 use algokit_utils::transactions::composer::SimulateParams;
 use algokit_utils::{AppCallMethodCallParams, AssetCreateParams, ComposerError};
 use algokit_utils::{
@@ -587,7 +588,7 @@ async fn test_get_pay_txn_amount_app_call_method_call(
     let args = vec![AppMethodCallArg::Payment(PaymentParams {
         sender: sender_address.clone(),
         receiver: receiver_addr,
-        amount: payment_amount,
+        amount: AlgoAmount::micro_algos(payment_amount),
         ..Default::default()
     })];
 
@@ -646,7 +647,7 @@ async fn test_get_pay_txn_amount_app_call_method_call_using_a_different_signer(
         sender: alice_addr.clone(),
         signer: Some(alice_signer),
         receiver: receiver_addr,
-        amount: payment_amount,
+        amount: AlgoAmount::micro_algos(payment_amount),
         ..Default::default()
     })];
 
@@ -709,7 +710,7 @@ async fn test_get_returned_value_of_app_call_txn_app_call_method_call(
         args: vec![AppMethodCallArg::Payment(PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_addr,
-            amount: payment_amount,
+            amount: AlgoAmount::micro_algos(payment_amount),
             ..Default::default()
         })],
         ..Default::default()
@@ -775,7 +776,7 @@ async fn test_get_returned_value_of_nested_app_call_method_calls(
         args: vec![AppMethodCallArg::Payment(PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_addr.clone(),
-            amount: payment_amount,
+            amount: AlgoAmount::micro_algos(payment_amount),
             ..Default::default()
         })],
         ..Default::default()
@@ -789,7 +790,7 @@ async fn test_get_returned_value_of_nested_app_call_method_calls(
             AppMethodCallArg::Payment(PaymentParams {
                 sender: sender_address.clone(),
                 receiver: receiver_addr.clone(),
-                amount: payment_amount,
+                amount: AlgoAmount::micro_algos(payment_amount),
                 note: Some("second txn".as_bytes().to_vec()),
                 ..Default::default()
             }),
@@ -819,6 +820,81 @@ async fn test_get_returned_value_of_nested_app_call_method_calls(
     }
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_nested_method_call_with_transaction_placeholder(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let algorand_fixture = algorand_fixture.await?;
+    let sender_address = algorand_fixture.test_account.account().address();
+
+    let arc56_contract: Arc56Contract =
+        serde_json::from_str(nested_contract_calls::APPLICATION_ARC56)?;
+    let app_id = deploy_arc56_contract(
+        &algorand_fixture,
+        &sender_address,
+        &arc56_contract,
+        None,
+        None,
+        None,
+    )
+    .await?;
+
+    let payment_amount = 5_000u64;
+    let get_pay_txn_amount_method_call_params = AppCallMethodCallParams {
+        sender: sender_address.clone(),
+        app_id,
+        method: get_abi_method(&arc56_contract, "get_pay_txn_amount")?,
+        args: vec![AppMethodCallArg::Payment(PaymentParams {
+            sender: sender_address.clone(),
+            receiver: sender_address.clone(),
+            amount: AlgoAmount::micro_algos(payment_amount),
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    // The pay argument is satisfied by the payment of the nested method call
+    let nested_method_call_params = AppCallMethodCallParams {
+        sender: sender_address.clone(),
+        app_id,
+        method: get_abi_method(&arc56_contract, "nested_method_call")?,
+        args: vec![
+            AppMethodCallArg::ABIValue(ABIValue::String("test".to_string())),
+            AppMethodCallArg::TransactionPlaceholder,
+            AppMethodCallArg::AppCallMethodCall(get_pay_txn_amount_method_call_params),
+        ],
+        ..Default::default()
+    };
+
+    let result = algorand_fixture
+        .algorand_client
+        .send()
+        .app_call_method_call(nested_method_call_params, None)
+        .await?;
+
+    // Transaction order: [payment, get_pay_txn_amount, nested_method_call]
+    assert_eq!(result.group_results.len(), 3);
+
+    let nested_returns = result.nested_abi_returns();
+    assert_eq!(nested_returns.len(), 1);
+    assert_eq!(
+        nested_returns[0].return_value,
+        Some(ABIValue::Uint(BigUint::from(payment_amount)))
+    );
+
+    let nested_call_id = result.group_results[1].transaction.id_raw()?;
+    let abi_return = ensure_abi_return(&result.result.abi_return)?;
+    assert_eq!(
+        abi_return.return_value,
+        Some(ABIValue::Array(
+            nested_call_id.iter().map(|b| ABIValue::Byte(*b)).collect()
+        ))
+    );
+
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn group_simulate_matches_send(
@@ -852,7 +928,7 @@ async fn group_simulate_matches_send(
     let payment = PaymentParams {
         sender: sender.clone(),
         receiver: sender.clone(),
-        amount: 10_000,
+        amount: AlgoAmount::micro_algos(10_000),
         ..Default::default()
     };
     composer.add_payment(payment)?;
@@ -873,9 +949,19 @@ async fn group_simulate_matches_send(
     let simulate = composer
         .simulate(Some(SimulateParams {
             skip_signatures: true,
+            extra_opcode_budget: Some(700),
+            allow_more_logging: Some(true),
             ..Default::default()
         }))
         .await?;
+    assert_eq!(simulate.transactions.len(), 3);
+    assert!(simulate.simulation_round > 0);
+    let eval_overrides = simulate.eval_overrides.expect("Expected eval overrides");
+    assert_eq!(eval_overrides.extra_opcode_budget, Some(700));
+    assert!(eval_overrides.max_log_calls.is_some());
+    assert!(simulate.transactions[0].app_budget_consumed.is_some());
+    assert!(simulate.transactions[1].app_budget_consumed.is_none());
+    assert!(simulate.app_budget_consumed.is_some());
     let send = composer.send(None).await?;
 
     for (simulate_result, send_result) in simulate.results.iter().zip(send.results.iter()) {
@@ -1310,7 +1396,7 @@ async fn test_double_nested(#[future] algorand_fixture: AlgorandFixtureResult) -
         args: vec![AppMethodCallArg::Payment(PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_address.clone(),
-            amount: 2_500_000u64,
+            amount: AlgoAmount::micro_algos(2_500_000u64),
             ..Default::default()
         })],
         ..Default::default()
@@ -1324,7 +1410,7 @@ async fn test_double_nested(#[future] algorand_fixture: AlgorandFixtureResult) -
         args: vec![AppMethodCallArg::Payment(PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_address.clone(),
-            amount: 1_500_000u64,
+            amount: AlgoAmount::micro_algos(1_500_000u64),
             ..Default::default()
         })],
         ..Default::default()
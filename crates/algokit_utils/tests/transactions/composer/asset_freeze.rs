@@ -1,5 +1,6 @@
 use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
 use algokit_transact::Transaction;
+use algokit_utils::AssetAmount;
 use algokit_utils::transactions::AssetOptInParams;
 use algokit_utils::transactions::{
     AssetCreateParams, AssetFreezeParams, AssetTransferParams, AssetUnfreezeParams,
@@ -92,7 +93,7 @@ async fn test_asset_freeze_unfreeze(
     let asset_transfer_params = AssetTransferParams {
         sender: asset_creator_addr.clone(),
         asset_id,
-        amount: 1000,
+        amount: AssetAmount::base_units(1000),
         receiver: target_addr.clone(),
         ..Default::default()
     };
@@ -167,7 +168,7 @@ async fn test_asset_freeze_unfreeze(
     let attempt_transfer_params = AssetTransferParams {
         sender: target_addr.clone(),
         asset_id,
-        amount: 100,
+        amount: AssetAmount::base_units(100),
         receiver: asset_creator_addr.clone(),
         ..Default::default()
     };
@@ -248,7 +249,7 @@ async fn test_asset_freeze_unfreeze(
     let test_transfer_params = AssetTransferParams {
         sender: target_addr.clone(),
         asset_id,
-        amount: 100,
+        amount: AssetAmount::base_units(100),
         receiver: asset_creator_addr.clone(),
         ..Default::default()
     };
@@ -1,4 +1,5 @@
 use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
+use algokit_utils::AssetAmount;
 use algokit_utils::AssetCreateParams;
 use algokit_utils::{AssetOptInParams, AssetTransferParams};
 use rstest::*;
@@ -41,7 +42,7 @@ async fn test_asset_transfer_transaction(
         sender: asset_creator_address.clone(),
         asset_id,
         receiver: asset_receive_address.clone(),
-        amount: 1,
+        amount: AssetAmount::base_units(1),
         ..Default::default()
     })?;
 
@@ -5,6 +5,7 @@ use algokit_abi::abi_type::BitSize;
 use algokit_abi::{ABIMethod, ABIType, ABIValue};
 use algokit_test_artifacts::{inner_fee_contract, nested_contract};
 use algokit_transact::{Address, TransactionId};
+use algokit_utils::AlgoAmount;
 use algokit_utils::TransactionComposer;
 use algokit_utils::transactions::TransactionComposerConfig;
 use algokit_utils::transactions::composer::ResourcePopulation;
@@ -129,6 +130,9 @@ async fn test_errors_when_inner_fees_not_covered_and_fee_coverage_disabled(
                 populate_app_call_resources: ResourcePopulation::Enabled {
                     use_access_list: false,
                 }, // Ensure the same behaviour when simulating due to resource population
+                fee_payer: None,
+                fee_policy: None,
+                analysis_simulate_params: None,
             }));
     let (app_id_1, app_id_2, app_id_3) = (app_ids[0], app_ids[1], app_ids[2]);
 
@@ -774,7 +778,7 @@ async fn test_does_not_alter_fee_when_group_covers_inner_fees(
         sender: sender_address.clone(),
         static_fee: Some(txn_1_expected_fee),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_1_params)?;
@@ -838,7 +842,7 @@ async fn test_alters_fee_nested_abi_method_call(
         sender: sender_address.clone(),
         static_fee: Some(1500),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_1_params.clone())?;
@@ -911,7 +915,7 @@ async fn test_errors_when_nested_max_fee_below_calculated(
     let txn_1_params = PaymentParams {
         sender: sender_address.clone(),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_1_params)?;
@@ -1006,7 +1010,7 @@ async fn test_alters_fee_allocating_surplus_to_most_constrained(
         sender: sender_address.clone(),
         static_fee: Some(7500),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_2_params)?;
@@ -1016,7 +1020,7 @@ async fn test_alters_fee_allocating_surplus_to_most_constrained(
         sender: sender_address.clone(),
         static_fee: Some(0),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_3_params)?;
@@ -1479,7 +1483,7 @@ async fn test_errors_when_static_fee_too_low_for_non_app_call(
         sender: sender_address.clone(),
         static_fee: Some(500),
         receiver: sender_address.clone(),
-        amount: 0,
+        amount: AlgoAmount::micro_algos(0),
         ..Default::default()
     };
     composer.add_payment(txn_3_params)?;
@@ -1724,6 +1728,9 @@ const COVER_FEES_GROUP_PARAMS: Option<TransactionComposerConfig> =
     Some(TransactionComposerConfig {
         cover_app_call_inner_transaction_fees: true,
         populate_app_call_resources: ResourcePopulation::Disabled,
+        fee_payer: None,
+        fee_policy: None,
+        analysis_simulate_params: None,
     });
 
 fn get_inner_fee_teal_programs()
@@ -1858,6 +1865,9 @@ async fn assert_min_fee(
     let mut composer = new_composer(Some(TransactionComposerConfig {
         cover_app_call_inner_transaction_fees: false, // Run without fee coverage to confirm it fails
         populate_app_call_resources: ResourcePopulation::default(),
+        fee_payer: None,
+        fee_policy: None,
+        analysis_simulate_params: None,
     }));
 
     composer
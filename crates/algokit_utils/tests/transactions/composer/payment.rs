@@ -1,3 +1,4 @@
+use algokit_utils::AlgoAmount;
 use algokit_utils::{AccountCloseParams, PaymentParams};
 use rstest::*;
 use std::sync::Arc;
@@ -18,7 +19,7 @@ async fn test_basic_payment_transaction(
     let payment_params = PaymentParams {
         sender: sender_address,
         receiver: receiver_account.address(),
-        amount: 500_000, // 0.5 ALGO
+        amount: AlgoAmount::micro_algos(500_000), // 0.5 ALGO
         ..Default::default()
     };
 
@@ -108,7 +109,7 @@ async fn test_payment_transactions_with_signers(
             sender: sender_addr.clone(),
             signer: Some(signer.clone()),
             receiver: receiver_addr.clone(),
-            amount: 50_000 + (i * 10_000),
+            amount: AlgoAmount::micro_algos(50_000 + (i * 10_000)),
             ..Default::default()
         };
         composer.add_payment(payment_params)?;
@@ -1,5 +1,6 @@
 use algokit_transact::test_utils::TransactionGroupMother;
 use algokit_transact::{MAX_TX_GROUP_SIZE, test_utils::TransactionMother};
+use algokit_utils::AlgoAmount;
 use algokit_utils::{AssetCreateParams, PaymentParams};
 use rstest::*;
 
@@ -19,7 +20,7 @@ async fn test_payment_and_asset_create_group(
     let payment_params = PaymentParams {
         sender: sender_address.clone(),
         receiver: receiver_addr,
-        amount: 1_000_000,
+        amount: AlgoAmount::micro_algos(1_000_000),
         ..Default::default()
     };
 
@@ -151,7 +152,7 @@ async fn test_add_transactions_to_group_max_size(
         let payment_params = PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_addr.clone(),
-            amount: i as u64,
+            amount: AlgoAmount::micro_algos(i as u64),
             ..Default::default()
         };
 
@@ -193,7 +194,7 @@ async fn test_add_transaction_to_group_too_big(
         let payment_params = PaymentParams {
             sender: sender_address.clone(),
             receiver: receiver_addr.clone(),
-            amount: i as u64,
+            amount: AlgoAmount::micro_algos(i as u64),
             ..Default::default()
         };
 
@@ -1,6 +1,8 @@
 use crate::common::{AlgorandFixtureResult, TestResult, algorand_fixture};
 use algokit_abi::{ABIMethod, ABIType, abi_type::BitSize};
 use algokit_transact::{Address, OnApplicationComplete, Transaction};
+use algokit_utils::AlgoAmount;
+use algokit_utils::AssetAmount;
 use algokit_utils::transactions::{
     AppCallMethodCallParams, AppCallParams, AppCreateParams, AppDeleteParams, AppUpdateParams,
     AssetClawbackParams, AssetCreateParams, AssetDestroyParams, AssetFreezeParams,
@@ -14,6 +16,9 @@ const GROUP_ANALYSIS_DISABLED: Option<TransactionComposerConfig> =
     Some(TransactionComposerConfig {
         cover_app_call_inner_transaction_fees: false,
         populate_app_call_resources: ResourcePopulation::Disabled,
+        fee_payer: None,
+        fee_policy: None,
+        analysis_simulate_params: None,
     });
 
 #[rstest]
@@ -35,7 +40,7 @@ async fn payment_transaction(
     let params = PaymentParams {
         sender: sender_address.clone(),
         receiver: receiver_address.clone(),
-        amount,
+        amount: AlgoAmount::micro_algos(amount),
         ..Default::default()
     };
 
@@ -98,7 +103,7 @@ async fn asset_operations(
                 sender: sender_address.clone(),
                 receiver: receiver_address.clone(),
                 asset_id: 1,
-                amount: 100,
+                amount: AssetAmount::base_units(100),
                 ..Default::default()
             };
             let tx = algorand_fixture
@@ -216,7 +221,7 @@ async fn asset_operations(
                 asset_id: 1,
                 clawback_target: target_address.clone(),
                 receiver: receiver_address.clone(),
-                amount: 50,
+                amount: AssetAmount::base_units(50),
                 ..Default::default()
             };
             let tx = algorand_fixture
@@ -479,7 +484,7 @@ async fn transaction_creator_accepts_all_parameters(
         sender: sender_address,
         asset_id: u64::MAX,           // This is valid for transaction creation
         receiver: Address::default(), // Zero address is also valid for creation
-        amount: 1,
+        amount: AssetAmount::base_units(1),
         ..Default::default()
     };
     let result = algorand_fixture
@@ -515,7 +520,7 @@ async fn transaction_has_valid_defaults(
     let params = PaymentParams {
         sender: sender_address,
         receiver: receiver_address,
-        amount: 1_000_000,
+        amount: AlgoAmount::micro_algos(1_000_000),
         ..Default::default()
     };
 
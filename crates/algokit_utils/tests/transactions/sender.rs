@@ -5,10 +5,12 @@ use crate::common::{
 use algokit_abi::{ABIValue, Arc56Contract};
 use algokit_test_artifacts::sandbox;
 use algokit_transact::{Address, OnApplicationComplete};
+use algokit_utils::AlgoAmount;
+use algokit_utils::AssetAmount;
 use algokit_utils::transactions::{
     AppCallMethodCallParams, AppCreateParams, AppMethodCallArg, AssetCreateParams,
-    AssetOptInParams, AssetOptOutParams, AssetTransferParams, PaymentParams,
-    TransactionSenderError,
+    AssetOptInParams, AssetOptOutParams, AssetTransferParams, ComposerError, ComposerTransaction,
+    PaymentParams, TransactionSenderError,
 };
 use rstest::*;
 use std::sync::Arc;
@@ -26,7 +28,7 @@ async fn test_payment_returns_rich_result(
     let params = PaymentParams {
         sender: sender_address,
         receiver: receiver.account().address(),
-        amount: 1_000_000,
+        amount: AlgoAmount::micro_algos(1_000_000),
         ..Default::default()
     };
 
@@ -55,7 +57,7 @@ async fn test_zero_amount_payment_allowed(
     let params = PaymentParams {
         sender: sender_address,
         receiver: receiver.account().address(),
-        amount: 0, // Zero amount should be allowed
+        amount: AlgoAmount::micro_algos(0), // Zero amount should be allowed
         ..Default::default()
     };
 
@@ -257,7 +259,7 @@ async fn test_asset_opt_out_with_balance_validation(
     let transfer_params = AssetTransferParams {
         sender: sender_address.clone(),
         asset_id,
-        amount: 10,
+        amount: AssetAmount::base_units(10),
         receiver: opt_out_address.clone(),
         ..Default::default()
     };
@@ -342,7 +344,7 @@ async fn test_transaction_confirmation_integration(
     let params = PaymentParams {
         sender: sender_address,
         receiver: receiver.account().address(),
-        amount: 1_000_000,
+        amount: AlgoAmount::micro_algos(1_000_000),
         ..Default::default()
     };
 
@@ -372,6 +374,113 @@ async fn test_new_composer_creates_composer(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_batch_sends_more_than_a_group(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+
+    let sender_address = algorand_fixture.test_account.account().address();
+    let receiver = algorand_fixture.generate_account(None).await?;
+
+    let payments = (0..20)
+        .map(|i| {
+            ComposerTransaction::Payment(PaymentParams {
+                sender: sender_address.clone(),
+                receiver: receiver.account().address(),
+                amount: AlgoAmount::micro_algos(1_000 + i),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    let result = algorand_fixture
+        .algorand_client
+        .send()
+        .batch()
+        .send(payments, None)
+        .await?;
+
+    assert!(result.is_success());
+    assert_eq!(
+        result
+            .groups
+            .iter()
+            .map(|group| group.transaction_indexes.clone())
+            .collect::<Vec<_>>(),
+        vec![0..16, 16..20]
+    );
+    let amounts = result
+        .results()
+        .iter()
+        .map(|result| match &result.transaction {
+            algokit_transact::Transaction::Payment(payment) => Ok(payment.amount),
+            _ => Err("Expected payment transaction"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(amounts, (1_000..1_020).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_send_many_sends_independent_groups(
+    #[future] algorand_fixture: AlgorandFixtureResult,
+) -> TestResult {
+    let mut algorand_fixture = algorand_fixture.await?;
+
+    let sender_address = algorand_fixture.test_account.account().address();
+    let receiver = algorand_fixture.generate_account(None).await?;
+    let sender = algorand_fixture.algorand_client.send();
+
+    let mut composers = (0..5)
+        .map(|i| {
+            let mut composer = sender.new_composer(None);
+            composer.add_payment(PaymentParams {
+                sender: sender_address.clone(),
+                receiver: receiver.account().address(),
+                amount: AlgoAmount::micro_algos(1_000 + i),
+                ..Default::default()
+            })?;
+            Ok(composer)
+        })
+        .collect::<Result<Vec<_>, ComposerError>>()?;
+    // An overspending group fails without affecting the others
+    composers[2] = sender.new_composer(None);
+    composers[2].add_payment(PaymentParams {
+        sender: receiver.account().address(),
+        signer: Some(Arc::new(receiver.clone())),
+        receiver: sender_address.clone(),
+        amount: AlgoAmount::algos(1_000),
+        ..Default::default()
+    })?;
+
+    let result = sender.send_many(composers, 2, None).await;
+
+    assert!(!result.is_success());
+    assert_eq!(
+        result
+            .failures()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+    let amounts = result
+        .confirmed()
+        .iter()
+        .map(|group| match &group.results[0].transaction {
+            algokit_transact::Transaction::Payment(payment) => Ok(payment.amount),
+            _ => Err("Expected payment transaction"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(amounts, vec![1_000, 1_001, 1_003, 1_004]);
+
+    Ok(())
+}
+
 async fn create_test_asset(
     algorand_fixture: &AlgorandFixture,
     sender_address: &Address,
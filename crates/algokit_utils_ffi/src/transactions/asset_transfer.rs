@@ -1,6 +1,7 @@
 use crate::create_transaction_params;
 use crate::transactions::common::UtilsError;
 
+use algokit_utils::AssetAmount;
 use algokit_utils::transactions::{
     AssetClawbackParams as RustAssetClawbackParams, AssetOptInParams as RustAssetOptInParams,
     AssetOptOutParams as RustAssetOptOutParams, AssetTransferParams as RustAssetTransferParams,
@@ -89,7 +90,7 @@ impl TryFrom<AssetTransferParams> for RustAssetTransferParams {
             first_valid_round: params.first_valid_round,
             last_valid_round: params.last_valid_round,
             asset_id: params.asset_id,
-            amount: params.amount,
+            amount: AssetAmount::base_units(params.amount),
             receiver: params
                 .receiver
                 .parse()
@@ -212,7 +213,7 @@ impl TryFrom<AssetClawbackParams> for RustAssetClawbackParams {
             first_valid_round: params.first_valid_round,
             last_valid_round: params.last_valid_round,
             asset_id: params.asset_id,
-            amount: params.amount,
+            amount: AssetAmount::base_units(params.amount),
             receiver: params
                 .receiver
                 .parse()
@@ -270,7 +271,7 @@ impl From<RustAssetTransferParams> for AssetTransferParams {
             first_valid_round: params.first_valid_round,
             last_valid_round: params.last_valid_round,
             asset_id: params.asset_id,
-            amount: params.amount,
+            amount: params.amount.as_base_units(),
             receiver: params.receiver.to_string(),
         }
     }
@@ -339,7 +340,7 @@ impl From<RustAssetClawbackParams> for AssetClawbackParams {
             first_valid_round: params.first_valid_round,
             last_valid_round: params.last_valid_round,
             asset_id: params.asset_id,
-            amount: params.amount,
+            amount: params.amount.as_base_units(),
             receiver: params.receiver.to_string(),
             clawback_target: params.clawback_target.to_string(),
         }
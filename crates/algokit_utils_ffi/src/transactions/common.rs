@@ -7,6 +7,8 @@ use algokit_transact_ffi::{SignedTransaction, Transaction};
 use algokit_utils::transactions::common::TransactionSigner as RustTransactionSigner;
 use algokit_utils::transactions::common::TransactionSignerGetter as RustTransactionSignerGetter;
 use algokit_utils::transactions::common::TransactionWithSigner as RustTransactionWithSigner;
+use algokit_utils::transactions::external_signer::ExternalSigner as RustExternalSigner;
+use algokit_utils::transactions::external_signer::ExternalTransactionSigner as RustExternalTransactionSigner;
 
 use async_trait::async_trait;
 use derive_more::Debug;
@@ -105,6 +107,56 @@ impl TransactionSigner for FfiTransactionSignerFromRust {
     }
 }
 
+/// A signer implemented in foreign code, e.g. backed by a Ledger, a browser wallet or an HSM,
+/// that signs msgpack encoded transactions.
+#[uniffi::export(with_foreign)]
+#[async_trait]
+pub trait ExternalSigner: Send + Sync {
+    /// Sign the transactions at `indices` of the group of msgpack encoded unsigned transactions,
+    /// returning one msgpack encoded signed transaction per index.
+    async fn sign_transaction_group(
+        &self,
+        unsigned_transactions: Vec<Vec<u8>>,
+        indices: Vec<u32>,
+    ) -> Result<Vec<Vec<u8>>, UtilsError>;
+}
+
+pub struct RustExternalSignerFromFfi {
+    pub ffi_external_signer: Arc<dyn ExternalSigner>,
+}
+
+#[async_trait]
+impl RustExternalSigner for RustExternalSignerFromFfi {
+    async fn sign_transaction_group(
+        &self,
+        unsigned_transactions: Vec<Vec<u8>>,
+        indices: Vec<usize>,
+    ) -> Result<Vec<Vec<u8>>, String> {
+        self.ffi_external_signer
+            .sign_transaction_group(
+                unsigned_transactions,
+                indices.into_iter().map(|i| i as u32).collect(),
+            )
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Create a transaction signer that signs through the external signer, to be used as the signer
+/// of transactions sent by a composer.
+#[uniffi::export]
+pub fn external_transaction_signer(
+    external_signer: Arc<dyn ExternalSigner>,
+) -> Arc<dyn TransactionSigner> {
+    Arc::new(FfiTransactionSignerFromRust {
+        rust_signer: Arc::new(RustExternalTransactionSigner::new(Arc::new(
+            RustExternalSignerFromFfi {
+                ffi_external_signer: external_signer,
+            },
+        ))),
+    })
+}
+
 #[uniffi::export(with_foreign)]
 pub trait TransactionSignerGetter: Send + Sync {
     fn get_signer(&self, address: String) -> Result<Arc<dyn TransactionSigner>, UtilsError>;
@@ -77,6 +77,7 @@ impl Composer {
                 algod_client: Arc::new(rust_algod_client.clone()),
                 signer_getter: Arc::new(rust_signer_getter),
                 composer_config: None,
+                suggested_params_cache: None,
             })
         };
 
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::create_transaction_params;
 use crate::transactions::common::UtilsError;
 
+use algokit_utils::AlgoAmount;
 use algokit_utils::transactions::{
     AccountCloseParams as RustAccountCloseParams, PaymentParams as RustPaymentParams,
 };
@@ -59,7 +60,7 @@ impl TryFrom<PaymentParams> for RustPaymentParams {
                 .map_err(|_| UtilsError::UtilsError {
                     message: "Invalid receiver address".to_string(),
                 })?,
-            amount: params.amount,
+            amount: AlgoAmount::micro_algos(params.amount),
         })
     }
 }
@@ -83,7 +84,7 @@ impl From<RustPaymentParams> for PaymentParams {
             first_valid_round: params.first_valid_round,
             last_valid_round: params.last_valid_round,
             receiver: params.receiver.to_string(),
-            amount: params.amount,
+            amount: params.amount.as_micro_algos(),
         }
     }
 }
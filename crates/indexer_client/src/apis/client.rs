@@ -19,7 +19,12 @@ use crate::models::{
     SearchForApplications, SearchForAssets, SearchForBlockHeaders, SearchForTransactions,
     UnknownJsonValue,
 };
-use algokit_http_client::{DefaultHttpClient, HttpClient};
+#[cfg(any(
+    feature = "default_client",
+    all(feature = "wasm_client", target_arch = "wasm32")
+))]
+use algokit_http_client::DefaultHttpClient;
+use algokit_http_client::HttpClient;
 use std::sync::Arc;
 
 /// The main Indexer API client.
@@ -27,6 +32,8 @@ use std::sync::Arc;
 /// This client provides convenient access to all Indexer API endpoints.
 /// It wraps the lower-level endpoint functions with a more ergonomic interface.
 /// All methods return a unified `Error` type that can represent any endpoint error.
+///
+/// A call is cancelled by dropping its future, e.g. on a timeout, which cancels its request.
 #[derive(Clone)]
 #[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Object))]
 pub struct IndexerClient {
@@ -42,7 +49,10 @@ impl IndexerClient {
     }
 
     /// Create a new IndexerClient for Algorand TestNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn testnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new(
@@ -52,7 +62,10 @@ impl IndexerClient {
     }
 
     /// Create a new IndexerClient for Algorand MainNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn mainnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new(
@@ -62,7 +75,10 @@ impl IndexerClient {
     }
 
     /// Create a new IndexerClient for a local localnet environment.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn localnet() -> Self {
         let http_client = Arc::new(
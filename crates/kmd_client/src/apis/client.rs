@@ -26,7 +26,12 @@ use crate::models::{
     SignProgramMultisigRequest, SignProgramRequest, SignTransactionRequest, VersionsRequest,
     VersionsResponse, WalletInfoRequest,
 };
-use algokit_http_client::{DefaultHttpClient, HttpClient};
+#[cfg(any(
+    feature = "default_client",
+    all(feature = "wasm_client", target_arch = "wasm32")
+))]
+use algokit_http_client::DefaultHttpClient;
+use algokit_http_client::HttpClient;
 use std::sync::Arc;
 
 /// The main Kmd API client.
@@ -34,6 +39,8 @@ use std::sync::Arc;
 /// This client provides convenient access to all Kmd API endpoints.
 /// It wraps the lower-level endpoint functions with a more ergonomic interface.
 /// All methods return a unified `Error` type that can represent any endpoint error.
+///
+/// A call is cancelled by dropping its future, e.g. on a timeout, which cancels its request.
 #[derive(Clone)]
 #[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Object))]
 pub struct KmdClient {
@@ -49,7 +56,10 @@ impl KmdClient {
     }
 
     /// Create a new KmdClient for Algorand TestNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn testnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new("http://localhost:7833"));
@@ -57,7 +67,10 @@ impl KmdClient {
     }
 
     /// Create a new KmdClient for Algorand MainNet.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn mainnet() -> Self {
         let http_client = Arc::new(DefaultHttpClient::new("http://localhost:7833"));
@@ -65,7 +78,10 @@ impl KmdClient {
     }
 
     /// Create a new KmdClient for a local localnet environment.
-    #[cfg(feature = "default_client")]
+    #[cfg(any(
+        feature = "default_client",
+        all(feature = "wasm_client", target_arch = "wasm32")
+    ))]
     #[cfg_attr(feature = "ffi_uniffi", uniffi::constructor)]
     pub fn localnet() -> Self {
         let http_client = Arc::new(
@@ -36,6 +36,7 @@ class PythonAlgodClient(AlgodClientTrait):  # type: ignore
                 query=None,
                 body=txn_bytes,
                 headers={"Content-Type": "application/x-binary"},
+                options=None,
             )
 
             result = json.loads(response.body.decode("utf-8"))
@@ -52,6 +53,7 @@ class PythonAlgodClient(AlgodClientTrait):  # type: ignore
                 query=None,
                 body=None,
                 headers=None,
+                options=None,
             )
 
             data = json.loads(response.body.decode("utf-8"))
@@ -76,6 +78,7 @@ class PythonAlgodClient(AlgodClientTrait):  # type: ignore
                 query=None,
                 body=None,
                 headers=None,
+                options=None,
             )
 
             data = json.loads(response.body.decode("utf-8"))
@@ -88,6 +91,7 @@ class PythonAlgodClient(AlgodClientTrait):  # type: ignore
                     query=None,
                     body=None,
                     headers=None,
+                    options=None,
                 )
 
                 data = json.loads(response.body.decode("utf-8"))
@@ -125,6 +129,7 @@ class PythonAlgodClient(AlgodClientTrait):  # type: ignore
                 query=None,
                 body=None,
                 headers=None,
+                options=None,
             )
 
             data = json.loads(response.body.decode("utf-8"))
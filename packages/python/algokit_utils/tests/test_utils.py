@@ -1,6 +1,11 @@
 from typing import override
 import typing
-from algokit_utils.algokit_http_client import HttpClient, HttpMethod, HttpResponse
+from algokit_utils.algokit_http_client import (
+    HttpClient,
+    HttpMethod,
+    HttpResponse,
+    RequestOptions,
+)
 from algokit_transact import (
     OnApplicationComplete,
     SignedTransaction,
@@ -103,13 +108,18 @@ class HttpClientImpl(HttpClient):
         query: typing.Optional[dict[str, str]],
         body: typing.Optional[bytes],
         headers: typing.Optional[dict[str, str]],
+        options: typing.Optional[RequestOptions],
     ) -> HttpResponse:
         headers = headers or {}
         headers["X-Algo-API-Token"] = "a" * 64
+        timeout = options.timeout.total_seconds() if options and options.timeout else None
 
         if method == HttpMethod.GET:
             res = requests.get(
-                f"http://localhost:4001/{path}", params=query, headers=headers
+                f"http://localhost:4001/{path}",
+                params=query,
+                headers=headers,
+                timeout=timeout,
             )
         elif method == HttpMethod.POST:
             res = requests.post(
@@ -117,6 +127,7 @@ class HttpClientImpl(HttpClient):
                 params=query,
                 data=body,
                 headers=headers,
+                timeout=timeout,
             )
         else:
             raise NotImplementedError(
@@ -133,7 +144,7 @@ class HttpClientImpl(HttpClient):
         # NOTE: Headers needing to be lowercase was a bit surprising, so we need to make sure we document that
         headers = {k.lower(): v for k, v in res.headers.items()}
 
-        return HttpResponse(body=res.content, headers=headers)
+        return HttpResponse(status=res.status_code, body=res.content, headers=headers)
 
 
 @pytest.mark.skip(reason="Will be refactored later. Keeping test for reference")